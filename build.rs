@@ -5,6 +5,7 @@ fn main() {
         .qt_module("Network")
         .qt_module("Multimedia")
         .qt_module("Widgets")  // Required for QApplication and system tray
+        .qt_module("Quick")    // QQuickImageProvider for cached media
         .qrc("resources/resources.qrc")
         .qml_module(QmlModule {
             uri: "com.plebclient",
@@ -15,6 +16,16 @@ fn main() {
                 "src/bridge/notification_bridge.rs",
                 "src/bridge/profile_bridge.rs",
                 "src/bridge/search_bridge.rs",
+                "src/bridge/wallet_bridge.rs",
+                "src/bridge/communities_bridge.rs",
+                "src/bridge/channels_bridge.rs",
+                "src/bridge/live_bridge.rs",
+                "src/bridge/discovery_bridge.rs",
+                "src/bridge/onboarding_bridge.rs",
+                "src/bridge/media_bridge.rs",
+                "src/bridge/signer_bridge.rs",
+                "src/bridge/image_provider.rs",
+                "src/bridge/variant.rs",
             ],
             qml_files: &[
                 "qml/Main.qml",
@@ -25,12 +36,18 @@ fn main() {
                 "qml/components/EmbeddedProfile.qml",
                 "qml/components/LinkPreview.qml",
                 "qml/components/VideoPlayer.qml",
-                "qml/components/YouTubePlayer.qml",
                 "qml/components/FountainPlayer.qml",
                 "qml/components/ImageViewer.qml",
                 "qml/components/ZapDialog.qml",
                 "qml/components/ReactionPicker.qml",
                 "qml/components/ComposeDialog.qml",
+                "qml/components/ReportDialog.qml",
+                "qml/components/HighlightDialog.qml",
+                "qml/components/FollowSetDialog.qml",
+                "qml/components/HashtagsDialog.qml",
+                "qml/components/FollowQueueDialog.qml",
+                "qml/components/OperationToast.qml",
+                "qml/components/SignerRequestDialog.qml",
                 "qml/screens/FeedScreen.qml",
                 "qml/screens/ThreadScreen.qml",
                 "qml/screens/LoginScreen.qml",
@@ -42,11 +59,24 @@ fn main() {
                 "qml/screens/RelaysScreen.qml",
                 "qml/screens/ReadsScreen.qml",
                 "qml/screens/ArticleScreen.qml",
+                "qml/screens/LiveScreen.qml",
+                "qml/screens/ExploreScreen.qml",
+                "qml/screens/OnboardingScreen.qml",
                 "qml/components/ArticleCard.qml",
                 "qml/components/ArticleComposer.qml",
                 "qml/components/GifPicker.qml",
+                "qml/components/MediaImage.qml",
+                "qml/components/MediaGrid.qml",
             ],
             ..Default::default()
         })
+        .cc_builder(|cc| {
+            cc.include("cpp");
+            cc.file("cpp/media_image_provider.cpp");
+        })
         .build();
+    
+    println!("cargo:rerun-if-changed=cpp/media_image_provider.h");
+    println!("cargo:rerun-if-changed=cpp/media_image_provider.cpp");
+    println!("cargo:rerun-if-changed=cpp/variant_helpers.h");
 }
@@ -0,0 +1,64 @@
+#include "media_image_provider.h"
+
+#include <QtGui/QImage>
+#include <QtQuick/QQuickImageProvider>
+
+#include "pleb_client_qt/src/bridge/image_provider.cxxqt.h"
+
+namespace {
+
+// Loads on Qt's image loader threads; the Rust side blocks on downloads
+class MediaImageProvider : public QQuickImageProvider
+{
+public:
+    MediaImageProvider()
+        : QQuickImageProvider(QQuickImageProvider::Image,
+                              QQmlImageProviderBase::ForceAsynchronousImageLoading)
+    {
+    }
+
+    QImage requestImage(const QString& id, QSize* size, const QSize& requestedSize) override
+    {
+        const QByteArray utf8 = id.toUtf8();
+        const MediaImage result = media_image_load(
+            rust::Str(utf8.constData(), static_cast<std::size_t>(utf8.size())),
+            requestedSize.width(),
+            requestedSize.height());
+
+        QImage image;
+        if (result.rgba) {
+            const QImage pixels(result.data.data(), result.width, result.height,
+                                result.width * 4, QImage::Format_RGBA8888);
+            image = pixels.copy();  // the Rust buffer is freed on return
+        } else if (!result.data.empty()) {
+            image.loadFromData(result.data.data(), static_cast<int>(result.data.size()));
+        }
+
+        // Only ever scale down; sourceSize asks for a display size, not an upscale
+        if (!image.isNull() && !result.rgba) {
+            if (requestedSize.width() > 0 && requestedSize.height() > 0
+                && (image.width() > requestedSize.width() || image.height() > requestedSize.height())) {
+                image = image.scaled(requestedSize, Qt::KeepAspectRatio, Qt::SmoothTransformation);
+            } else if (requestedSize.width() > 0 && requestedSize.height() <= 0
+                       && image.width() > requestedSize.width()) {
+                image = image.scaledToWidth(requestedSize.width(), Qt::SmoothTransformation);
+            } else if (requestedSize.height() > 0 && requestedSize.width() <= 0
+                       && image.height() > requestedSize.height()) {
+                image = image.scaledToHeight(requestedSize.height(), Qt::SmoothTransformation);
+            }
+        }
+
+        if (size) {
+            *size = image.size();
+        }
+        return image;
+    }
+};
+
+} // namespace
+
+void register_media_image_provider(QQmlApplicationEngine& engine)
+{
+    // The engine takes ownership of the provider
+    engine.addImageProvider(QStringLiteral("media"), new MediaImageProvider);
+}
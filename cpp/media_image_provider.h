@@ -0,0 +1,9 @@
+// Image provider serving image://media/... from the Rust media cache
+// (see src/bridge/image_provider.rs)
+
+#pragma once
+
+#include <QtQml/QQmlApplicationEngine>
+
+// Register the "media" image provider on the engine; call before loading QML
+void register_media_image_provider(QQmlApplicationEngine& engine);
@@ -0,0 +1,19 @@
+// Wrap QVariantMap / QVariantList into a QVariant for the Rust side
+// (see src/bridge/variant.rs); cxx-qt-lib has no QVariant for them
+
+#pragma once
+
+#include <QtCore/QList>
+#include <QtCore/QMap>
+#include <QtCore/QString>
+#include <QtCore/QVariant>
+
+inline QVariant variant_from_map(const QMap<QString, QVariant>& map)
+{
+    return QVariant(map);
+}
+
+inline QVariant variant_from_list(const QList<QVariant>& list)
+{
+    return QVariant(list);
+}
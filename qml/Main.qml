@@ -21,14 +21,11 @@ ApplicationWindow {
     color: "#0a0a0a"
     
     // System tray settings
-    property bool closeToTray: false  // Disabled by default since system tray requires QApplication
+    // The tray icon is a D-Bus StatusNotifierItem, so it only exists when a tray host is running
+    property bool closeToTray: appController.get_close_to_tray()
     property bool showNotificationBadge: false
     property int unreadCount: 0
     
-    // Note: System tray requires QApplication (not QGuiApplication)
-    // For now, we skip the system tray implementation
-    // The close-to-tray feature is disabled until we switch to QApplication
-    
     // Global keyboard shortcuts
     Shortcut {
         sequence: "Ctrl+1"
@@ -78,12 +75,45 @@ ApplicationWindow {
             if (appController.public_key.toString() !== "") {
                 feedController.initialize(appController.public_key)
                 feedController.load_feed("following")
-                // Initialize notification controller
+                // Notifications and DMs poll in the background from here on
                 notificationController.initialize(appController.public_key)
+                dmController.initialize(appController.public_key)
+                signerController.initialize()
+                appController.enable_deep_links()
             }
         }
+        
+        // Respects the opt-out setting
+        appController.check_for_updates(false)
+        
+        appController.enable_desktop_notifications()
+        appController.enable_single_instance()
+        appController.set_window_active(window.active)
+        
+        // Only fetches rates when a fiat currency is configured
+        appController.start_exchange_rate_updates()
+        
+        appController.enable_tray()
+        appController.set_window_visible(window.visible)
+        
+        // One jittered clock for feed/stats polling
+        appController.start_refresh_scheduler()
+        window.updateRefreshTasks()
+    }
+    
+    // Periodic feed refreshes only run while the feed is on screen
+    // (notifications and DMs are polled in the background by their controllers)
+    function updateRefreshTasks() {
+        var screen = appController.logged_in ? appController.current_screen.toString() : ""
+        appController.set_refresh_active("feed", screen === "feed")
+        appController.set_refresh_active("stats", screen === "feed")
     }
     
+    onVisibleChanged: appController.set_window_visible(visible)
+    
+    // Desktop notifications are only shown while the window is in the background
+    onActiveChanged: appController.set_window_active(active)
+    
     // App controller from Rust
     AppController {
         id: appController
@@ -91,9 +121,98 @@ ApplicationWindow {
         onCurrent_screenChanged: {
             console.log("[DEBUG] Screen changed to:", current_screen)
             console.log("[DEBUG] StackLayout currentIndex should be:", getScreenIndex(current_screen))
+            window.updateRefreshTasks()
         }
         onLogged_inChanged: {
             console.log("[DEBUG] Logged in changed to:", logged_in)
+            window.updateRefreshTasks()
+        }
+        
+        onRefresh_due: function(task) {
+            if (task === "feed") {
+                if (!feedController.is_loading) feedController.check_for_new()
+            } else if (task === "stats") {
+                feedScreen.refreshVisibleStats()
+            }
+        }
+        
+        // Previous account's state is gone - let controllers initialize again on next login
+        onSession_reset: {
+            feedController.reset()
+            dmController.reset()
+            notificationController.reset()
+            liveController.reset()
+            discoveryController.reset()
+            signerController.reset()
+        }
+        
+        // Desktop notification clicked - bring the window up and open its target
+        onNotification_activated: function(kind, target) {
+            window.show()
+            window.raise()
+            window.requestActivate()
+            if (!appController.logged_in || target.toString() === "") return
+            
+            if (kind === "dm") {
+                appController.navigate_to("messages")
+                dmController.select_conversation(target)
+            } else {
+                window.previousScreen = appController.current_screen
+                window.threadNoteId = target
+                appController.navigate_to("thread")
+            }
+        }
+        
+        // Launched again while running - come to the front instead
+        onActivation_requested: {
+            window.show()
+            window.raise()
+            window.requestActivate()
+        }
+        
+        // nostr: links from the command line or a second launch (only delivered while logged in)
+        onDeep_link_opened: function(kind, target) {
+            window.show()
+            window.raise()
+            window.requestActivate()
+            
+            if (kind === "signer") {
+                signerController.handle_uri(target)
+                return
+            }
+            
+            window.previousScreen = appController.current_screen
+            if (kind === "profile") {
+                window.viewingProfilePubkey = target
+                appController.navigate_to("profile")
+            } else if (kind === "thread") {
+                window.threadNoteId = target
+                appController.navigate_to("thread")
+            } else if (kind === "article") {
+                window.articleNoteId = target
+                appController.navigate_to("article")
+            }
+        }
+        
+        // Tray icon clicks and menu picks
+        onTray_action_requested: function(action) {
+            if (action === "quit") {
+                Qt.quit()
+                return
+            }
+            if (action === "hide") {
+                window.hide()
+                return
+            }
+            
+            window.show()
+            window.raise()
+            window.requestActivate()
+            if (!appController.logged_in) return
+            
+            if (action === "notifications" || action === "messages") {
+                appController.navigate_to(action)
+            }
         }
         
         onLogin_complete: function(success, error) {
@@ -106,14 +225,33 @@ ApplicationWindow {
                 feedController.initialize(public_key)
                 // Note: Don't call load_feed here - initialize() now does it with proper async
                 
-                // Initialize notification controller
+                // Initialize notification and DM controllers (both start background polling)
                 console.log("[DEBUG] Initializing notification controller for:", public_key)
                 notificationController.initialize(public_key)
+                dmController.initialize(public_key)
+                liveController.initialize(public_key)
+                signerController.initialize()
+                appController.enable_deep_links()
+                
+                if (window.onboardingPending) {
+                    onboardingController.initialize(public_key)
+                    appController.navigate_to("onboarding")
+                }
             }
         }
     }
     
     // Forward feedController loading_status to appController for login screen display
+    // Keys in the system keyring are unlocked with the desktop session
+    Connections {
+        target: appController
+        function onSaved_credentials_checked(hasCredentials, inKeyring) {
+            if (inKeyring && !appController.logged_in) {
+                appController.login_with_keyring()
+            }
+        }
+    }
+    
     Connections {
         target: feedController
         function onLoading_statusChanged() {
@@ -130,11 +268,11 @@ ApplicationWindow {
             }
         }
         
-        // When user's profile is loaded, update appController
+        // appController loads the profile itself after login; only take what the feed found
         function onUser_profile_loaded(display_name, picture) {
             console.log("[DEBUG] User profile loaded:", display_name, picture ? "has picture" : "no picture")
-            appController.display_name = display_name || "Anonymous"
-            appController.profile_picture = picture || ""
+            if (display_name) appController.display_name = display_name
+            if (picture) appController.profile_picture = picture
         }
     }
     
@@ -152,6 +290,9 @@ ApplicationWindow {
             case "relays": return 8
             case "reads": return 9
             case "article": return 10
+            case "live": return 11
+            case "onboarding": return 12
+            case "explore": return 13
             default: return 0
         }
     }
@@ -168,6 +309,9 @@ ApplicationWindow {
     // Track the previous screen for back navigation
     property string previousScreen: "feed"
     
+    // A freshly created account goes through onboarding after its first login
+    property bool onboardingPending: false
+    
     // Feed controller from Rust
     FeedController {
         id: feedController
@@ -187,10 +331,34 @@ ApplicationWindow {
     SearchController {
         id: searchController
     }
-
+    
+    // Live streams controller from Rust
+    LiveController {
+        id: liveController
+    }
+    
+    OnboardingController {
+        id: onboardingController
+    }
+    
+    // Trending notes and hashtags for the Explore screen
+    DiscoveryController {
+        id: discoveryController
+    }
+    
+    // Signer service for other Nostr apps
+    SignerRequestsController {
+        id: signerController
+    }
+    
     // Handle window close
     onClosing: function(close) {
-        // Close to tray is disabled since it requires QApplication
+        // Hide instead of quitting only when there's a tray icon to restore from
+        if (window.closeToTray && appController.tray_available) {
+            close.accepted = false
+            window.hide()
+            return
+        }
         close.accepted = true
     }
     
@@ -218,14 +386,16 @@ ApplicationWindow {
             id: sidebar
             Layout.fillHeight: true
             Layout.preferredWidth: 240
-            visible: appController.logged_in
+            visible: appController.logged_in && appController.current_screen !== "onboarding"
             
             currentScreen: appController.current_screen
             displayName: appController.display_name
             profilePicture: appController.profile_picture
             walletBalance: appController.wallet_balance_sats
+            walletBalanceFiat: appController.wallet_balance_fiat
             unreadNotifications: notificationController.unread_count
             unreadMessages: dmController.unread_count
+            liveCount: liveController.live_count
             
             onNavigate: function(screen) {
                 // Clear viewing profile pubkey when navigating to own profile via sidebar
@@ -310,12 +480,14 @@ ApplicationWindow {
                         console.log("Account created:", npub)
                         loginScreen.generatedNsec = nsec
                         loginScreen.generatedNpub = npub
+                        window.onboardingPending = true
                     }
                 }
             }
             
             // Feed screen
             FeedScreen {
+                id: feedScreen
                 feedController: feedController
                 appController: appController
                 
@@ -393,12 +565,19 @@ ApplicationWindow {
                     window.viewingProfilePubkey = ""
                     appController.navigate_to(window.previousScreen)
                 }
+                
+                onOpenThread: function(noteId) {
+                    window.previousScreen = "profile"
+                    window.threadNoteId = noteId
+                    appController.navigate_to("thread")
+                }
             }
             
             // Settings screen
             SettingsScreen {
                 appController: appController
                 feedController: feedController
+                signerController: signerController
                 closeToTray: window.closeToTray
                 onLogout: appController.logout()
                 onConnectNwc: function(uri) {
@@ -412,6 +591,7 @@ ApplicationWindow {
                 }
                 onCloseToTrayToggled: function(value) {
                     window.closeToTray = value
+                    appController.set_close_to_tray(value)
                 }
             }
             
@@ -477,6 +657,46 @@ ApplicationWindow {
                     appController.navigate_to("profile")
                 }
             }
+            
+            // Live streams screen
+            LiveScreen {
+                liveController: liveController
+                
+                onOpenProfile: function(pubkey) {
+                    window.previousScreen = "live"
+                    window.viewingProfilePubkey = pubkey
+                    appController.navigate_to("profile")
+                }
+            }
+            
+            // First-run setup for new accounts
+            OnboardingScreen {
+                onboardingController: onboardingController
+                
+                onFinished: {
+                    window.onboardingPending = false
+                    feedController.refresh()
+                    appController.navigate_to("feed")
+                }
+            }
+            
+            // Trending notes and hashtags
+            ExploreScreen {
+                discoveryController: discoveryController
+                feedController: feedController
+                
+                onOpenThread: function(noteId) {
+                    window.previousScreen = "explore"
+                    window.threadNoteId = noteId
+                    appController.navigate_to("thread")
+                }
+                
+                onOpenProfile: function(pubkey) {
+                    window.previousScreen = "explore"
+                    window.viewingProfilePubkey = pubkey
+                    appController.navigate_to("profile")
+                }
+            }
         }
     }
     
@@ -485,6 +705,11 @@ ApplicationWindow {
         id: imageViewer
     }
     
+    // Approval prompts for the signer service
+    SignerRequestDialog {
+        signerController: signerController
+    }
+    
     // Keyboard shortcuts help popup
     Popup {
         id: shortcutsPopup
@@ -653,6 +878,190 @@ ApplicationWindow {
         }
     }
     
+    // Failed operations with a Retry button (likes, posts, messages, ...)
+    Column {
+        anchors.bottom: parent.bottom
+        anchors.horizontalCenter: parent.horizontalCenter
+        anchors.bottomMargin: 24
+        spacing: 8
+        z: 1000
+        
+        OperationToast {
+            controller: feedController
+        }
+        
+        OperationToast {
+            controller: dmController
+        }
+    }
+    
+    // Reminder to back up a key generated in the app
+    Rectangle {
+        anchors.top: parent.top
+        anchors.horizontalCenter: parent.horizontalCenter
+        anchors.topMargin: 16
+        width: backupReminderRow.implicitWidth + 32
+        height: 52
+        radius: 10
+        color: "#1a1a1a"
+        border.color: "#f59e0b"
+        border.width: 1
+        z: 1000
+        visible: appController.backup_reminder_due && appController.logged_in
+                 && appController.current_screen !== "onboarding"
+
+        RowLayout {
+            id: backupReminderRow
+            anchors.centerIn: parent
+            spacing: 12
+
+            Text {
+                text: "🔑 Have you backed up your private key?"
+                color: "#ffffff"
+                font.pixelSize: 14
+            }
+
+            Button {
+                text: "Back up now"
+                onClicked: appController.navigate_to("settings")
+
+                background: Rectangle {
+                    color: parent.pressed ? "#7c22ce" : "#9333ea"
+                    radius: 6
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: "#ffffff"
+                    font.pixelSize: 13
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+
+            Button {
+                text: "Later"
+                onClicked: appController.snooze_backup_reminder()
+
+                background: Rectangle {
+                    color: parent.hovered ? "#333333" : "transparent"
+                    radius: 6
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: "#888888"
+                    font.pixelSize: 13
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+        }
+    }
+
+    // Outcome of a nostrconnect:// or nostrsigner: URI
+    Rectangle {
+        id: signerUriNotice
+        anchors.top: parent.top
+        anchors.horizontalCenter: parent.horizontalCenter
+        anchors.topMargin: 16
+        width: Math.min(signerUriRow.implicitWidth + 32, parent.width - 40)
+        height: 52
+        radius: 10
+        color: "#1a1a1a"
+        border.color: success ? "#9333ea" : "#7f1d1d"
+        border.width: 1
+        z: 1000
+        visible: message !== ""
+
+        property bool success: true
+        property string message: ""
+        property string result: ""
+
+        Connections {
+            target: signerController
+            function onUri_handled(success, message, result, callback_url) {
+                // Web apps get their answer through the callback
+                if (callback_url.toString() !== "") {
+                    Qt.openUrlExternally(callback_url)
+                    result = ""
+                }
+                signerUriNotice.success = success
+                signerUriNotice.message = message
+                signerUriNotice.result = result
+                signerUriTimer.restart()
+            }
+        }
+
+        Timer {
+            id: signerUriTimer
+            interval: 15000
+            onTriggered: signerUriNotice.message = ""
+        }
+
+        // Holds the result for copying
+        TextEdit {
+            id: signerUriResultText
+            visible: false
+            text: signerUriNotice.result
+        }
+
+        RowLayout {
+            id: signerUriRow
+            anchors.centerIn: parent
+            spacing: 12
+
+            Text {
+                text: signerUriNotice.message
+                color: signerUriNotice.success ? "#ffffff" : "#ef4444"
+                font.pixelSize: 14
+                elide: Text.ElideRight
+                Layout.maximumWidth: 420
+            }
+
+            Button {
+                text: "Copy result"
+                visible: signerUriNotice.result !== ""
+                onClicked: {
+                    signerUriResultText.selectAll()
+                    signerUriResultText.copy()
+                    signerUriNotice.message = ""
+                }
+
+                background: Rectangle {
+                    color: parent.pressed ? "#7c22ce" : "#9333ea"
+                    radius: 6
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: "#ffffff"
+                    font.pixelSize: 13
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+
+            Button {
+                text: "Dismiss"
+                onClicked: signerUriNotice.message = ""
+
+                background: Rectangle {
+                    color: parent.hovered ? "#333333" : "transparent"
+                    radius: 6
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: "#888888"
+                    font.pixelSize: 13
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+            }
+        }
+    }
+
     // Loading overlay
     Rectangle {
         anchors.fill: parent
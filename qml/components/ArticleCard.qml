@@ -43,9 +43,9 @@ Rectangle {
             radius: 8
             clip: true
             
-            Image {
+            MediaImage {
                 anchors.fill: parent
-                source: root.image
+                url: root.image
                 fillMode: Image.PreserveAspectCrop
                 asynchronous: true
             }
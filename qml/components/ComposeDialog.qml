@@ -12,10 +12,27 @@ Popup {
     property string replyToId: ""  // If set, this is a reply
     property string replyToAuthor: ""  // Author name of note being replied to
     property string replyToContent: ""  // Content preview of note being replied to
+    property string contentWarning: ""  // NIP-36 reason; empty means no warning
+    property string draftId: ""  // Draft being edited; set by loadDraft or autosave
     
     // Track attached media URLs
     property var attachedMedia: []
+    // Resolved mentions in the text: [{uri, pubkey, name}]
+    property var mentions: []
     property bool isUploading: false
+    // File being uploaded; kept as a local attachment if the upload fails
+    property string uploadingPath: ""
+    
+    // Poll mode: the text becomes the question (NIP-88, kind 1068)
+    property bool pollMode: false
+    property var pollOptions: ["", ""]
+    property bool pollMultipleChoice: false
+    property int pollDuration: 86400  // Seconds; 0 keeps the poll open
+    readonly property int maxPollOptions: 12
+    
+    function pollOptionCount() {
+        return pollOptions.filter(function(o) { return o.trim().length > 0 }).length
+    }
     
     signal posted()
     
@@ -34,19 +51,123 @@ Popup {
         border.width: 1
     }
     
+    // Restore a draft from feedController.list_drafts() before opening
+    function loadDraft(draft) {
+        pendingDraft = draft
+    }
+    property var pendingDraft: null
+    
     // Reset state when opened
     onOpened: {
-        composeInput.text = ""
-        attachedMedia = []
+        if (pendingDraft) {
+            draftId = pendingDraft.id || ""
+            replyToId = pendingDraft.replyTo || ""
+            replyToAuthor = pendingDraft.replyToAuthor || ""
+            replyToContent = pendingDraft.replyToContent || ""
+            contentWarning = pendingDraft.contentWarning || ""
+            attachedMedia = pendingDraft.mediaPaths || []
+            composeInput.text = pendingDraft.content || ""
+            pendingDraft = null
+        } else {
+            draftId = ""
+            composeInput.text = ""
+            contentWarning = ""
+            attachedMedia = []
+        }
         composeInput.forceActiveFocus()
     }
     
-    // Reset on close
+    // Reset on close (the draft, if any, stays on disk)
     onClosed: {
+        draftAutosave.stop()
+        saveDraft()
+        draftId = ""
         replyToId = ""
         replyToAuthor = ""
         replyToContent = ""
         attachedMedia = []
+        mentions = []
+        pollMode = false
+        pollOptions = ["", ""]
+        pollMultipleChoice = false
+        pollDuration = 86400
+        pollDurationBox.currentIndex = 1
+    }
+    
+    // Persist the composer so a crash or accidental close never loses a note
+    function saveDraft() {
+        if (!feedController || composeInput.text.trim() === "" && attachedMedia.length === 0) {
+            return
+        }
+        var saved = JSON.parse(feedController.save_draft(JSON.stringify({
+            id: draftId,
+            content: composeInput.text,
+            replyTo: replyToId || null,
+            replyToAuthor: replyToAuthor || null,
+            replyToContent: replyToContent || null,
+            mediaPaths: attachedMedia,
+            contentWarning: contentWarning || null
+        })))
+        if (saved.id) {
+            draftId = saved.id
+        }
+    }
+    
+    Timer {
+        id: draftAutosave
+        interval: 1500
+        onTriggered: root.saveDraft()
+    }
+    
+    // Rewrite pasted npub/nprofile as nostr: URIs and ask for their names
+    function preprocessMentions() {
+        if (!feedController) return
+        var text = composeInput.text
+        var processed = "" + feedController.preprocess_composer(text)
+        if (processed !== text) {
+            var cursor = composeInput.cursorPosition
+            composeInput.text = processed
+            composeInput.cursorPosition = Math.min(processed.length, cursor + processed.length - text.length)
+        }
+        mentions = mentions.filter(function(m) { return processed.indexOf(m.uri) !== -1 })
+    }
+    
+    // Attachments that are still local files (uploaded when the note is published)
+    function isLocalMedia(source) {
+        return !/^https?:\/\//.test(source)
+    }
+    
+    function hasLocalMedia() {
+        return attachedMedia.some(isLocalMedia)
+    }
+    
+    function attach(source) {
+        var media = attachedMedia.slice()
+        media.push(source)
+        attachedMedia = media
+    }
+    
+    function removeMention(uri) {
+        composeInput.text = composeInput.text.split(uri).join("").replace(/  +/g, " ")
+        composeInput.forceActiveFocus()
+    }
+    
+    Timer {
+        id: mentionCheck
+        interval: 300
+        onTriggered: root.preprocessMentions()
+    }
+    
+    Connections {
+        target: feedController
+        ignoreUnknownSignals: true
+        
+        function onMention_resolved(uri, pubkey, displayName) {
+            if (composeInput.text.indexOf(uri) === -1) return
+            var updated = root.mentions.filter(function(m) { return m.uri !== uri })
+            updated.push({ uri: uri, pubkey: pubkey, name: displayName })
+            root.mentions = updated
+        }
     }
     
     ColumnLayout {
@@ -154,6 +275,63 @@ Popup {
                 
                 // Character count
                 property int maxChars: 1000
+                
+                onTextChanged: {
+                    if (root.opened) {
+                        draftAutosave.restart()
+                        mentionCheck.restart()
+                    }
+                }
+            }
+        }
+        
+        // Resolved mentions - Tab to a chip, Delete/Backspace removes it from the text
+        Flow {
+            Layout.fillWidth: true
+            Layout.leftMargin: 16
+            Layout.rightMargin: 16
+            spacing: 6
+            visible: root.mentions.length > 0
+            
+            Repeater {
+                model: root.mentions
+                
+                delegate: Rectangle {
+                    width: mentionLabel.implicitWidth + 16
+                    height: 26
+                    radius: 13
+                    color: activeFocus ? "#9333ea" : "#2a2a2a"
+                    activeFocusOnTab: true
+                    
+                    Accessible.role: Accessible.Button
+                    Accessible.name: "Mention " + modelData.name + ", press Delete to remove"
+                    
+                    Keys.onDeletePressed: root.removeMention(modelData.uri)
+                    Keys.onPressed: function(event) {
+                        if (event.key === Qt.Key_Backspace) {
+                            root.removeMention(modelData.uri)
+                            event.accepted = true
+                        }
+                    }
+                    
+                    Text {
+                        id: mentionLabel
+                        anchors.centerIn: parent
+                        text: "@" + modelData.name
+                        color: "#ffffff"
+                        font.pixelSize: 12
+                    }
+                    
+                    ToolTip.visible: mentionMouse.containsMouse
+                    ToolTip.text: modelData.uri
+                    
+                    MouseArea {
+                        id: mentionMouse
+                        anchors.fill: parent
+                        hoverEnabled: true
+                        onClicked: parent.forceActiveFocus()
+                    }
+                }
             }
         }
         
@@ -186,7 +364,7 @@ Popup {
                     
                     Image {
                         anchors.fill: parent
-                        source: modelData
+                        source: root.isLocalMedia(modelData) ? "file://" + modelData : modelData
                         fillMode: Image.PreserveAspectCrop
                         asynchronous: true
                         visible: !modelData.match(/\.(mp4|webm|mov)$/i)
@@ -205,6 +383,27 @@ Popup {
                         }
                     }
                     
+                    // Not uploaded yet - goes up when the note is published
+                    Rectangle {
+                        anchors.left: parent.left
+                        anchors.bottom: parent.bottom
+                        anchors.margins: 4
+                        width: pendingLabel.implicitWidth + 8
+                        height: 16
+                        radius: 4
+                        color: "#000000"
+                        opacity: 0.7
+                        visible: root.isLocalMedia(modelData)
+                        
+                        Text {
+                            id: pendingLabel
+                            anchors.centerIn: parent
+                            text: "pending"
+                            color: "#fbbf24"
+                            font.pixelSize: 10
+                        }
+                    }
+                    
                     // Remove button
                     Rectangle {
                         anchors.top: parent.top
@@ -237,6 +436,79 @@ Popup {
             }
         }
         
+        // Poll options
+        ColumnLayout {
+            Layout.fillWidth: true
+            Layout.leftMargin: 16
+            Layout.rightMargin: 16
+            spacing: 6
+            visible: root.pollMode && !root.replyToId
+            
+            Repeater {
+                model: root.pollOptions.length
+                
+                delegate: RowLayout {
+                    Layout.fillWidth: true
+                    spacing: 6
+                    
+                    TextField {
+                        Layout.fillWidth: true
+                        placeholderText: "Option " + (index + 1)
+                        text: root.pollOptions[index]
+                        onTextEdited: {
+                            var options = root.pollOptions.slice()
+                            options[index] = text
+                            root.pollOptions = options
+                        }
+                    }
+                    
+                    Button {
+                        text: "✕"
+                        visible: root.pollOptions.length > 2
+                        onClicked: {
+                            var options = root.pollOptions.slice()
+                            options.splice(index, 1)
+                            root.pollOptions = options
+                        }
+                    }
+                }
+            }
+            
+            RowLayout {
+                Layout.fillWidth: true
+                spacing: 8
+                
+                Button {
+                    text: "+ Option"
+                    enabled: root.pollOptions.length < root.maxPollOptions
+                    onClicked: root.pollOptions = root.pollOptions.concat([""])
+                }
+                
+                CheckBox {
+                    text: "Multiple choice"
+                    checked: root.pollMultipleChoice
+                    onToggled: root.pollMultipleChoice = checked
+                }
+                
+                Item { Layout.fillWidth: true }
+                
+                ComboBox {
+                    id: pollDurationBox
+                    textRole: "label"
+                    valueRole: "seconds"
+                    model: [
+                        { label: "1 hour", seconds: 3600 },
+                        { label: "1 day", seconds: 86400 },
+                        { label: "3 days", seconds: 259200 },
+                        { label: "1 week", seconds: 604800 },
+                        { label: "No end", seconds: 0 }
+                    ]
+                    currentIndex: 1
+                    onActivated: root.pollDuration = currentValue
+                }
+            }
+        }
+        
         Rectangle {
             Layout.fillWidth: true
             height: 1
@@ -309,6 +581,33 @@ Popup {
                     onClicked: gifPicker.open()
                 }
                 
+                // Poll button
+                Button {
+                    implicitWidth: 44
+                    implicitHeight: 44
+                    visible: !root.replyToId
+                    enabled: root.attachedMedia.length === 0
+                    
+                    ToolTip.visible: hovered
+                    ToolTip.text: root.pollMode ? "Remove poll" : "Create poll"
+                    ToolTip.delay: 500
+                    
+                    background: Rectangle {
+                        color: root.pollMode ? "#3b1a5c" : (parent.pressed ? "#333333" : (parent.hovered ? "#252525" : "transparent"))
+                        radius: 8
+                    }
+                    
+                    contentItem: Text {
+                        text: "📊"
+                        font.pixelSize: 20
+                        color: "#ffffff"
+                        horizontalAlignment: Text.AlignHCenter
+                        verticalAlignment: Text.AlignVCenter
+                    }
+                    
+                    onClicked: root.pollMode = !root.pollMode
+                }
+                
                 // Emoji button (placeholder)
                 Button {
                     implicitWidth: 44
@@ -356,7 +655,8 @@ Popup {
                     implicitHeight: 44
                     enabled: composeInput.text.trim().length > 0 && 
                              composeInput.text.length <= composeInput.maxChars &&
-                             !root.isUploading
+                             !root.isUploading &&
+                             (!root.pollMode || root.pollOptionCount() >= 2)
                     
                     background: Rectangle {
                         color: parent.enabled ? (parent.pressed ? "#7c22c9" : "#9333ea") : "#333333"
@@ -378,15 +678,29 @@ Popup {
                             
                             if (replyToId) {
                                 // Reply
-                                root.feedController.reply_to_note(replyToId, content)
+                                root.feedController.reply_to_note(replyToId, content, root.contentWarning)
+                            } else if (root.pollMode) {
+                                // Poll: the text is the question
+                                root.feedController.create_poll(content, JSON.stringify(root.pollOptions), root.pollDuration, root.pollMultipleChoice)
+                            } else if (root.hasLocalMedia()) {
+                                // Uploads and publishing happen in the background, also after reconnecting
+                                root.feedController.queue_note_with_media(content, JSON.stringify(media), root.contentWarning)
                             } else if (media.length > 0) {
                                 // Post with media
-                                root.feedController.post_note_with_media(content, JSON.stringify(media))
+                                root.feedController.post_note_with_media(content, JSON.stringify(media), root.contentWarning)
                             } else {
                                 // Simple post
-                                root.feedController.post_note(content)
+                                root.feedController.post_note(content, root.contentWarning)
                             }
                             
+                            // Posted - the draft is no longer needed
+                            draftAutosave.stop()
+                            if (root.draftId) {
+                                root.feedController.delete_draft(root.draftId)
+                            }
+                            composeInput.text = ""
+                            root.attachedMedia = []
+                            
                             root.posted()
                             root.close()
                         }
@@ -410,9 +724,15 @@ Popup {
                 path = path.substring(7)
             }
             if (path) {
-                // Upload to Blossom
-                root.isUploading = true
-                root.feedController.upload_media(path)
+                if (root.feedController.is_online()) {
+                    // Upload to Blossom
+                    root.isUploading = true
+                    root.uploadingPath = path
+                    root.feedController.upload_media(path)
+                } else {
+                    // Offline - keep the file and upload it when the note goes out
+                    root.attach(path)
+                }
             }
         }
     }
@@ -438,15 +758,19 @@ Popup {
         target: feedController
         function onMedia_uploaded(url) {
             root.isUploading = false
+            root.uploadingPath = ""
             if (url) {
-                var media = root.attachedMedia.slice()
-                media.push(url)
-                root.attachedMedia = media
+                root.attach(url)
             }
         }
         function onMedia_upload_failed(error) {
             root.isUploading = false
             console.log("Upload failed:", error)
+            // Keep the file; it is uploaded again when the note is published
+            if (root.uploadingPath) {
+                root.attach(root.uploadingPath)
+                root.uploadingPath = ""
+            }
         }
     }
 }
@@ -18,6 +18,8 @@ Rectangle {
     property var feedController: null
     property int retryCount: 0
     property int maxRetries: 5
+    property string parentId: ""  // Note containing this embed (for cycle detection)
+    property int depth: 0  // Nesting level; nested embeds come pre-resolved in noteData.embeds
     
     signal clicked(string noteId)
     
@@ -54,7 +56,7 @@ Rectangle {
         isLoading = true
         hasError = false
         
-        var result = feedController.fetch_embedded_event(nostrUri)
+        var result = feedController.fetch_embedded_event(nostrUri, parentId)
         if (result && result !== "{}") {
             try {
                 noteData = JSON.parse(result)
@@ -145,10 +147,10 @@ Rectangle {
                     radius: 12
                     color: "#3a3a3a"
                     
-                    Image {
+                    MediaImage {
                         anchors.fill: parent
                         anchors.margins: 0
-                        source: noteData?.authorPicture || ""
+                        url: noteData?.authorPicture || ""
                         fillMode: Image.PreserveAspectCrop
                         visible: status === Image.Ready
                         layer.enabled: true
@@ -200,6 +202,111 @@ Rectangle {
                 color: "#666666"
                 font.pixelSize: 11
             }
+            
+            // Nested quotes (resolved up to the depth limit) and placeholders
+            Repeater {
+                model: noteData && noteData.embeds ? noteData.embeds : []
+                
+                delegate: Loader {
+                    id: embedLoader
+                    Layout.fillWidth: true
+                    property var embed: modelData
+                    
+                    Component.onCompleted: {
+                        if (embed.placeholder) {
+                            sourceComponent = placeholderComponent
+                        } else {
+                            // Already resolved - EmbeddedNote.qml loaded by URL since a
+                            // component can't instantiate itself directly
+                            setSource("EmbeddedNote.qml", {
+                                noteData: embed,
+                                feedController: root.feedController,
+                                depth: root.depth + 1
+                            })
+                        }
+                    }
+                    
+                    onLoaded: {
+                        if (embed.placeholder) {
+                            item.embed = embed
+                        } else {
+                            item.clicked.connect(root.clicked)
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
+    // Quote that wasn't resolved: too deep (expandable), a cycle, or missing
+    Component {
+        id: placeholderComponent
+        
+        Rectangle {
+            id: placeholder
+            width: parent ? parent.width : 0
+            implicitHeight: expanded ? expandedLoader.implicitHeight : placeholderRow.implicitHeight + 12
+            color: "#1e1e1e"
+            radius: 6
+            border.color: "#333333"
+            border.width: 1
+            
+            property var embed: null
+            property bool expanded: false
+            
+            RowLayout {
+                id: placeholderRow
+                anchors.fill: parent
+                anchors.margins: 6
+                visible: !placeholder.expanded
+                spacing: 6
+                
+                Text {
+                    text: placeholder.embed && placeholder.embed.placeholder === "cycle"
+                        ? "↻ Quotes a note earlier in this chain"
+                        : placeholder.embed && placeholder.embed.placeholder === "deep"
+                            ? "💬 More quoted notes"
+                            : "📝 Quoted note unavailable"
+                    color: "#777777"
+                    font.pixelSize: 12
+                    font.italic: true
+                    Layout.fillWidth: true
+                }
+                
+                Text {
+                    text: "Show"
+                    color: "#9333ea"
+                    font.pixelSize: 12
+                    visible: placeholder.embed && placeholder.embed.placeholder === "deep"
+                }
+            }
+            
+            MouseArea {
+                anchors.fill: parent
+                visible: !placeholder.expanded
+                cursorShape: Qt.PointingHandCursor
+                onClicked: {
+                    if (!placeholder.embed) return
+                    if (placeholder.embed.placeholder === "deep") {
+                        // Resolve as a new root with a fresh depth budget
+                        placeholder.expanded = true
+                        expandedLoader.setSource("EmbeddedNote.qml", {
+                            nostrUri: placeholder.embed.uri,
+                            feedController: root.feedController,
+                            parentId: root.noteData ? root.noteData.id : ""
+                        })
+                    } else if (placeholder.embed.id) {
+                        root.clicked(placeholder.embed.id)
+                    }
+                }
+            }
+            
+            Loader {
+                id: expandedLoader
+                width: parent.width
+                visible: placeholder.expanded
+                onLoaded: item.clicked.connect(root.clicked)
+            }
         }
     }
     
@@ -143,9 +143,9 @@ Rectangle {
                     radius: 24
                     color: "#3a3a3a"
                     
-                    Image {
+                    MediaImage {
                         anchors.fill: parent
-                        source: profileData?.picture || ""
+                        url: profileData?.picture || ""
                         fillMode: Image.PreserveAspectCrop
                         visible: status === Image.Ready
                         layer.enabled: true
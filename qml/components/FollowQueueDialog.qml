@@ -0,0 +1,236 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+// Review queue for suggested follows - approve in batch with one contact list update
+Popup {
+    id: root
+
+    property var profileController: null
+
+    property var queue: []
+    // pubkey -> true for checked rows
+    property var selected: ({})
+    property int selectedCount: 0
+
+    modal: true
+    dim: true
+    anchors.centerIn: Overlay.overlay
+    width: 460
+    height: Math.min(620, contentColumn.implicitHeight + 48)
+    padding: 24
+
+    function reload() {
+        if (!profileController) return
+        try {
+            queue = JSON.parse(profileController.get_follow_queue())
+        } catch (e) {
+            queue = []
+        }
+        // Keep selections that are still queued; new entries start checked
+        var next = {}
+        for (var i = 0; i < queue.length; i++) {
+            var pk = queue[i].pubkey
+            next[pk] = selected.hasOwnProperty(pk) ? selected[pk] : true
+        }
+        selected = next
+        countSelected()
+    }
+
+    function countSelected() {
+        var n = 0
+        for (var pk in selected) if (selected[pk]) n++
+        selectedCount = n
+    }
+
+    function selectedPubkeys() {
+        var list = []
+        for (var pk in selected) if (selected[pk]) list.push(pk)
+        return list
+    }
+
+    function toggle(pubkey) {
+        var next = Object.assign({}, selected)
+        next[pubkey] = !next[pubkey]
+        selected = next
+        countSelected()
+    }
+
+    onOpened: {
+        selected = ({})
+        reload()
+    }
+
+    Connections {
+        target: root.profileController
+        ignoreUnknownSignals: true
+        function onFollow_queue_changed(count) {
+            if (root.opened) root.reload()
+        }
+    }
+
+    background: Rectangle {
+        color: "#1a1a1a"
+        radius: 16
+        border.color: "#333333"
+        border.width: 1
+    }
+
+    ColumnLayout {
+        id: contentColumn
+        anchors.fill: parent
+        spacing: 16
+
+        Text {
+            text: "Review follows"
+            color: "#ffffff"
+            font.pixelSize: 18
+            font.weight: Font.Bold
+        }
+
+        Text {
+            Layout.fillWidth: true
+            text: root.queue.length > 0
+                  ? "People you saved for later. Approving publishes your follow list once for the whole batch."
+                  : "Nothing to review. Use \"Save for later\" on profiles and search results to collect people here."
+            color: "#888888"
+            font.pixelSize: 12
+            wrapMode: Text.WordWrap
+        }
+
+        ListView {
+            id: queueList
+            Layout.fillWidth: true
+            Layout.preferredHeight: Math.min(contentHeight, 360)
+            visible: root.queue.length > 0
+            clip: true
+            spacing: 4
+            model: root.queue
+
+            ScrollBar.vertical: ScrollBar { policy: ScrollBar.AsNeeded }
+
+            delegate: Rectangle {
+                width: queueList.width
+                height: 56
+                radius: 8
+                color: rowMouse.containsMouse ? "#252525" : "#202020"
+
+                MouseArea {
+                    id: rowMouse
+                    anchors.fill: parent
+                    hoverEnabled: true
+                    cursorShape: Qt.PointingHandCursor
+                    onClicked: root.toggle(modelData.pubkey)
+                }
+
+                RowLayout {
+                    anchors.fill: parent
+                    anchors.margins: 8
+                    spacing: 10
+
+                    CheckBox {
+                        checked: !!root.selected[modelData.pubkey]
+                        onClicked: root.toggle(modelData.pubkey)
+                    }
+
+                    ProfileAvatar {
+                        Layout.preferredWidth: 36
+                        Layout.preferredHeight: 36
+                        imageUrl: modelData.picture || ""
+                        name: modelData.name || "?"
+                    }
+
+                    ColumnLayout {
+                        Layout.fillWidth: true
+                        spacing: 2
+
+                        Text {
+                            Layout.fillWidth: true
+                            text: modelData.name || (modelData.npub.substring(0, 16) + "...")
+                            color: "#ffffff"
+                            font.pixelSize: 14
+                            elide: Text.ElideRight
+                        }
+
+                        Text {
+                            Layout.fillWidth: true
+                            text: (modelData.nip05 ? modelData.nip05 + " · " : "") + "from " + modelData.source
+                            color: "#888888"
+                            font.pixelSize: 11
+                            elide: Text.ElideRight
+                        }
+                    }
+                }
+            }
+        }
+
+        RowLayout {
+            Layout.fillWidth: true
+            spacing: 12
+
+            Button {
+                text: "Dismiss"
+                enabled: root.selectedCount > 0
+                onClicked: {
+                    root.profileController.dismiss_follows(JSON.stringify(root.selectedPubkeys()))
+                    root.reload()
+                }
+
+                background: Rectangle {
+                    color: parent.pressed ? "#333333" : "#2a2a2a"
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: parent.enabled ? "#ef4444" : "#666666"
+                    font.pixelSize: 14
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+
+            Item { Layout.fillWidth: true }
+
+            Button {
+                text: "Close"
+                onClicked: root.close()
+
+                background: Rectangle {
+                    color: parent.pressed ? "#333333" : "#2a2a2a"
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: "#ffffff"
+                    font.pixelSize: 14
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+
+            Button {
+                text: root.selectedCount > 0 ? "Follow " + root.selectedCount : "Follow"
+                enabled: root.selectedCount > 0
+
+                onClicked: {
+                    root.profileController.approve_follows(JSON.stringify(root.selectedPubkeys()))
+                    root.reload()
+                    if (root.queue.length === 0) root.close()
+                }
+
+                background: Rectangle {
+                    color: !parent.enabled ? "#3a3a3a" : (parent.pressed ? "#7e22ce" : "#9333ea")
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: parent.enabled ? "#ffffff" : "#888888"
+                    font.pixelSize: 14
+                    font.weight: Font.Medium
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+        }
+    }
+}
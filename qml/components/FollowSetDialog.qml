@@ -0,0 +1,208 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+// Create or edit a NIP-51 follow set (a named list of people with its own feed)
+Popup {
+    id: root
+
+    property string identifier: ""      // Empty when creating a new list
+    property var feedController: null
+
+    // Identifier of a list created from this dialog, until it's published
+    property string createdIdentifier: ""
+
+    property bool confirmDelete: false
+
+    modal: true
+    dim: true
+    anchors.centerIn: Overlay.overlay
+    width: 420
+    height: contentColumn.implicitHeight + 48
+    padding: 24
+
+    // Open for an existing set (from get_follow_sets) or null to create one
+    function openFor(set) {
+        identifier = set ? set.identifier : ""
+        titleField.text = set ? set.title : ""
+        descriptionField.text = set ? set.description : ""
+        membersArea.text = set ? set.npubs.join("\n") : ""
+        confirmDelete = false
+        open()
+    }
+
+    function members() {
+        return membersArea.text.split(/[\s,]+/).filter(function(m) { return m.length > 0 })
+    }
+
+    background: Rectangle {
+        color: "#1a1a1a"
+        radius: 16
+        border.color: "#333333"
+        border.width: 1
+    }
+
+    ColumnLayout {
+        id: contentColumn
+        anchors.fill: parent
+        spacing: 16
+
+        Text {
+            text: root.identifier ? "Edit list" : "New list"
+            color: "#ffffff"
+            font.pixelSize: 18
+            font.weight: Font.Bold
+        }
+
+        Text {
+            Layout.fillWidth: true
+            text: "Lists get their own feed tab with notes from just these people. They're published to your relays, so other clients can use them too."
+            color: "#888888"
+            font.pixelSize: 12
+            wrapMode: Text.WordWrap
+        }
+
+        TextField {
+            id: titleField
+            Layout.fillWidth: true
+            placeholderText: "Name (e.g. Bitcoin devs)"
+            placeholderTextColor: "#666666"
+            color: "#ffffff"
+            font.pixelSize: 14
+
+            background: Rectangle {
+                color: "#2a2a2a"
+                radius: 8
+                border.color: titleField.activeFocus ? "#9333ea" : "#444444"
+                border.width: 1
+            }
+        }
+
+        TextField {
+            id: descriptionField
+            Layout.fillWidth: true
+            placeholderText: "Description (optional)"
+            placeholderTextColor: "#666666"
+            color: "#ffffff"
+            font.pixelSize: 14
+
+            background: Rectangle {
+                color: "#2a2a2a"
+                radius: 8
+                border.color: descriptionField.activeFocus ? "#9333ea" : "#444444"
+                border.width: 1
+            }
+        }
+
+        ScrollView {
+            Layout.fillWidth: true
+            Layout.preferredHeight: 160
+
+            TextArea {
+                id: membersArea
+                placeholderText: "People to include - one npub per line"
+                placeholderTextColor: "#666666"
+                color: "#ffffff"
+                font.pixelSize: 13
+                font.family: "monospace"
+                wrapMode: TextArea.WrapAnywhere
+
+                background: Rectangle {
+                    color: "#2a2a2a"
+                    radius: 8
+                    border.color: membersArea.activeFocus ? "#9333ea" : "#444444"
+                    border.width: 1
+                }
+            }
+        }
+
+        Text {
+            text: root.members().length + (root.members().length === 1 ? " person" : " people")
+            color: "#888888"
+            font.pixelSize: 12
+        }
+
+        RowLayout {
+            Layout.fillWidth: true
+            spacing: 12
+
+            Button {
+                visible: root.identifier !== ""
+                text: root.confirmDelete ? "Confirm delete" : "Delete"
+
+                onClicked: {
+                    if (!root.confirmDelete) {
+                        root.confirmDelete = true
+                        return
+                    }
+                    if (root.feedController) {
+                        root.feedController.delete_follow_set(root.identifier)
+                    }
+                    root.close()
+                }
+
+                background: Rectangle {
+                    color: parent.pressed ? "#b91c1c" : (root.confirmDelete ? "#dc2626" : "#2a2a2a")
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: root.confirmDelete ? "#ffffff" : "#ef4444"
+                    font.pixelSize: 14
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+
+            Item { Layout.fillWidth: true }
+
+            Button {
+                text: "Cancel"
+                onClicked: root.close()
+
+                background: Rectangle {
+                    color: parent.pressed ? "#333333" : "#2a2a2a"
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: "#ffffff"
+                    font.pixelSize: 14
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+
+            Button {
+                text: "Save"
+                enabled: titleField.text.trim().length > 0
+
+                onClicked: {
+                    if (!root.feedController) return
+                    var isNew = root.identifier === ""
+                    var id = root.feedController.save_follow_set(
+                        root.identifier,
+                        titleField.text,
+                        descriptionField.text,
+                        JSON.stringify(root.members()))
+                    if (!id) return    // Invalid members; error_occurred explains why
+                    if (isNew) root.createdIdentifier = id
+                    root.close()
+                }
+
+                background: Rectangle {
+                    color: !parent.enabled ? "#3a3a3a" : (parent.pressed ? "#7e22ce" : "#9333ea")
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: parent.enabled ? "#ffffff" : "#888888"
+                    font.pixelSize: 14
+                    font.weight: Font.Medium
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+        }
+    }
+}
@@ -11,6 +11,10 @@ Popup {
     property bool isBridging: false
     property string searchQuery: ""
     property var gifResults: []
+    // Position token for the next page of the current listing; "" when there is none
+    property string nextPos: ""
+    property bool loadingMore: false
+    property var categories: []
     property string selectedGifUrl: ""
     
     signal gifSelected(string url)
@@ -33,6 +37,7 @@ Popup {
     onOpened: {
         searchInput.text = ""
         gifResults = []
+        nextPos = ""
         isBridging = false
         selectedGifUrl = ""
         searchInput.forceActiveFocus()
@@ -43,21 +48,37 @@ Popup {
         }
     }
     
-    function loadTrending() {
-        if (!feedController) return
-        
-        var result = feedController.get_trending_gifs()
+    // Show a page of results; append adds to the current listing
+    function applyPage(result, append) {
         try {
             var data = JSON.parse(result)
             if (data.error) {
                 console.log("Tenor error:", data.error)
-                gifResults = []
-            } else {
-                gifResults = data
+                if (!append) gifResults = []
+                nextPos = ""
+                return
             }
+            gifResults = append ? gifResults.concat(data.results) : data.results
+            nextPos = data.next || ""
         } catch (e) {
-            console.log("Failed to parse trending:", e)
-            gifResults = []
+            console.log("Failed to parse GIF results:", e)
+            if (!append) gifResults = []
+            nextPos = ""
+        }
+    }
+    
+    function loadTrending() {
+        if (!feedController) return
+        applyPage(feedController.trending_gifs(""), false)
+        if (categories.length === 0) loadCategories()
+    }
+    
+    function loadCategories() {
+        try {
+            var data = JSON.parse(feedController.gif_categories())
+            categories = data.error ? [] : data
+        } catch (e) {
+            categories = []
         }
     }
     
@@ -66,20 +87,18 @@ Popup {
             loadTrending()
             return
         }
-        
-        var result = feedController.search_gifs(searchQuery)
-        try {
-            var data = JSON.parse(result)
-            if (data.error) {
-                console.log("Tenor search error:", data.error)
-                gifResults = []
-            } else {
-                gifResults = data
-            }
-        } catch (e) {
-            console.log("Failed to parse search results:", e)
-            gifResults = []
-        }
+        applyPage(feedController.search_gifs(searchQuery, ""), false)
+    }
+    
+    // Next page of whatever is showing (search results or trending)
+    function loadMore() {
+        if (!feedController || !nextPos || loadingMore) return
+        loadingMore = true
+        var result = searchQuery.trim().length > 0
+            ? feedController.search_gifs(searchQuery, nextPos)
+            : feedController.trending_gifs(nextPos)
+        applyPage(result, true)
+        loadingMore = false
     }
     
     function selectGif(gif) {
@@ -265,6 +284,43 @@ Popup {
             }
         }
         
+        // Featured categories (when not searching)
+        ListView {
+            Layout.fillWidth: true
+            Layout.preferredHeight: 32
+            Layout.leftMargin: 12
+            Layout.rightMargin: 12
+            Layout.topMargin: 8
+            orientation: ListView.Horizontal
+            spacing: 6
+            clip: true
+            visible: searchQuery.length === 0 && categories.length > 0
+            model: categories
+            
+            delegate: Rectangle {
+                width: categoryText.implicitWidth + 20
+                height: 28
+                radius: 14
+                color: categoryMouse.containsMouse ? "#333333" : "#252525"
+                
+                Text {
+                    id: categoryText
+                    anchors.centerIn: parent
+                    text: modelData.name
+                    color: "#cccccc"
+                    font.pixelSize: 12
+                }
+                
+                MouseArea {
+                    id: categoryMouse
+                    anchors.fill: parent
+                    hoverEnabled: true
+                    cursorShape: Qt.PointingHandCursor
+                    onClicked: searchInput.text = modelData.searchterm
+                }
+            }
+        }
+        
         // GIF grid
         ScrollView {
             Layout.fillWidth: true
@@ -279,6 +335,11 @@ Popup {
                 cellHeight: cellWidth
                 model: gifResults
                 
+                // Fetch the next page as the end comes into view
+                onAtYEndChanged: {
+                    if (atYEnd && contentHeight > height) root.loadMore()
+                }
+                
                 delegate: Rectangle {
                     width: gifGrid.cellWidth - 4
                     height: gifGrid.cellHeight - 4
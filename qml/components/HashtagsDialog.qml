@@ -0,0 +1,174 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+// Follow and unfollow hashtags for the Topics feed (NIP-51 interest list)
+Popup {
+    id: root
+
+    property var feedController: null
+    property var hashtags: []          // Followed tags, lowercase without '#'
+
+    modal: true
+    dim: true
+    anchors.centerIn: Overlay.overlay
+    width: 420
+    height: contentColumn.implicitHeight + 48
+    padding: 24
+
+    function followInput() {
+        var tag = tagField.text.trim().replace(/^#+/, "")
+        if (!tag || !root.feedController) return
+        root.feedController.follow_hashtag(tag, true)
+        tagField.text = ""
+    }
+
+    background: Rectangle {
+        color: "#1a1a1a"
+        radius: 16
+        border.color: "#333333"
+        border.width: 1
+    }
+
+    ColumnLayout {
+        id: contentColumn
+        anchors.fill: parent
+        spacing: 16
+
+        Text {
+            text: "Followed hashtags"
+            color: "#ffffff"
+            font.pixelSize: 18
+            font.weight: Font.Bold
+        }
+
+        Text {
+            Layout.fillWidth: true
+            text: "The Topics tab shows recent notes with any of these hashtags, from anyone. The list is published to your relays, so other clients can use it too."
+            color: "#888888"
+            font.pixelSize: 12
+            wrapMode: Text.WordWrap
+        }
+
+        RowLayout {
+            Layout.fillWidth: true
+            spacing: 8
+
+            TextField {
+                id: tagField
+                Layout.fillWidth: true
+                placeholderText: "#hashtag"
+                placeholderTextColor: "#666666"
+                color: "#ffffff"
+                font.pixelSize: 14
+
+                background: Rectangle {
+                    color: "#2a2a2a"
+                    radius: 8
+                    border.color: tagField.activeFocus ? "#9333ea" : "#444444"
+                    border.width: 1
+                }
+
+                onAccepted: root.followInput()
+            }
+
+            Button {
+                text: "Follow"
+                enabled: tagField.text.trim().replace(/^#+/, "").length > 0
+                onClicked: root.followInput()
+
+                background: Rectangle {
+                    color: !parent.enabled ? "#3a3a3a" : (parent.pressed ? "#7e22ce" : "#9333ea")
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: parent.enabled ? "#ffffff" : "#888888"
+                    font.pixelSize: 14
+                    font.weight: Font.Medium
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+        }
+
+        Flow {
+            Layout.fillWidth: true
+            spacing: 8
+
+            Repeater {
+                model: root.hashtags
+
+                delegate: Rectangle {
+                    width: chipRow.implicitWidth + 20
+                    height: 30
+                    radius: 15
+                    color: "#2a2a2a"
+                    border.color: "#444444"
+                    border.width: 1
+
+                    Row {
+                        id: chipRow
+                        anchors.centerIn: parent
+                        spacing: 6
+
+                        Text {
+                            text: "#" + modelData
+                            color: "#ffffff"
+                            font.pixelSize: 13
+                        }
+
+                        Text {
+                            text: "✕"
+                            color: removeArea.containsMouse ? "#ef4444" : "#888888"
+                            font.pixelSize: 12
+
+                            MouseArea {
+                                id: removeArea
+                                anchors.fill: parent
+                                anchors.margins: -4
+                                cursorShape: Qt.PointingHandCursor
+                                hoverEnabled: true
+                                onClicked: {
+                                    if (root.feedController) {
+                                        root.feedController.follow_hashtag(modelData, false)
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Text {
+            visible: root.hashtags.length === 0
+            text: "You don't follow any hashtags yet"
+            color: "#666666"
+            font.pixelSize: 13
+        }
+
+        RowLayout {
+            Layout.fillWidth: true
+
+            Item { Layout.fillWidth: true }
+
+            Button {
+                text: "Done"
+                onClicked: root.close()
+
+                background: Rectangle {
+                    color: parent.pressed ? "#333333" : "#2a2a2a"
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: "#ffffff"
+                    font.pixelSize: 14
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+        }
+    }
+}
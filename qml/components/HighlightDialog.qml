@@ -0,0 +1,121 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+// Highlight dialog (NIP-84): select part of a note and publish it as a highlight
+Popup {
+    id: root
+
+    property string noteId: ""
+    property string content: ""
+    property var feedController: null
+
+    modal: true
+    dim: true
+    anchors.centerIn: Overlay.overlay
+    width: Math.min(520, Overlay.overlay ? Overlay.overlay.width - 40 : 520)
+    height: contentColumn.implicitHeight + 48
+    padding: 24
+
+    onOpened: {
+        sourceText.text = root.content
+        sourceText.deselect()
+    }
+
+    background: Rectangle {
+        color: "#1a1a1a"
+        radius: 16
+        border.color: "#333333"
+        border.width: 1
+    }
+
+    ColumnLayout {
+        id: contentColumn
+        anchors.fill: parent
+        spacing: 16
+
+        Text {
+            text: "Highlight"
+            color: "#ffffff"
+            font.pixelSize: 18
+            font.weight: Font.Bold
+        }
+
+        Text {
+            Layout.fillWidth: true
+            text: "Select the passage to highlight. It is published to your relays with a link to the note."
+            color: "#888888"
+            font.pixelSize: 12
+            wrapMode: Text.WordWrap
+        }
+
+        ScrollView {
+            Layout.fillWidth: true
+            Layout.preferredHeight: Math.min(260, sourceText.implicitHeight + 24)
+
+            TextArea {
+                id: sourceText
+                readOnly: true
+                selectByMouse: true
+                wrapMode: TextEdit.Wrap
+                color: "#ffffff"
+                selectionColor: "#ca8a04"
+                selectedTextColor: "#000000"
+                font.pixelSize: 14
+
+                background: Rectangle {
+                    color: "#2a2a2a"
+                    radius: 8
+                }
+            }
+        }
+
+        RowLayout {
+            Layout.fillWidth: true
+            spacing: 12
+
+            Item { Layout.fillWidth: true }
+
+            Button {
+                text: "Cancel"
+                onClicked: root.close()
+
+                background: Rectangle {
+                    color: parent.pressed ? "#333333" : "#2a2a2a"
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: "#ffffff"
+                    font.pixelSize: 14
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+
+            Button {
+                text: "Highlight"
+                enabled: sourceText.selectedText.trim().length > 0
+
+                onClicked: {
+                    if (!root.feedController) return
+                    root.feedController.highlight_note(root.noteId, sourceText.selectedText)
+                    root.close()
+                }
+
+                background: Rectangle {
+                    color: !parent.enabled ? "#3a3a3a" : (parent.pressed ? "#a16207" : "#ca8a04")
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: parent.enabled ? "#ffffff" : "#888888"
+                    font.pixelSize: 14
+                    font.weight: Font.Medium
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+        }
+    }
+}
@@ -66,9 +66,9 @@ Popup {
             
             boundsMovement: Flickable.StopAtBounds
             
-            Image {
+            MediaImage {
                 id: image
-                source: root.imageUrl
+                url: root.imageUrl
                 anchors.centerIn: parent
                 fillMode: Image.PreserveAspectFit
                 
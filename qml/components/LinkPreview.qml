@@ -3,6 +3,7 @@ import QtQuick.Controls
 import QtQuick.Layouts
 
 // Link preview card for displaying OpenGraph metadata from URLs
+// Pages with an embed (YouTube, X, Spotify, oEmbed providers) show a player card instead
 Rectangle {
     id: root
     color: "#252525"
@@ -17,7 +18,11 @@ Rectangle {
     property bool hasError: false
     property var feedController: null
     property int retryCount: 0
-    property int maxRetries: 5
+    property int maxRetries: 16
+    property bool playing: false
+    readonly property var embed: previewData ? (previewData.embed || null) : null
+    // Instant embed previews have no title yet; keep polling for the full one
+    readonly property bool awaitingDetails: !!embed && !previewData.title
     
     Component.onCompleted: {
         if (url && !previewData) {
@@ -27,6 +32,7 @@ Rectangle {
     
     onUrlChanged: {
         previewData = null
+        playing = false
         retryCount = 0
         hasError = false
         if (url) {
@@ -39,7 +45,7 @@ Rectangle {
         id: retryTimer
         interval: 500
         repeat: true
-        running: isLoading && retryCount < maxRetries
+        running: (isLoading || awaitingDetails) && retryCount < maxRetries
         onTriggered: {
             retryCount++
             loadPreview()
@@ -64,7 +70,7 @@ Rectangle {
             try {
                 previewData = JSON.parse(result)
                 isLoading = false
-                retryTimer.stop()
+                if (!awaitingDetails) retryTimer.stop()
                 
                 // Check if we got useful data
                 if (!previewData.title && !previewData.description && !previewData.image) {
@@ -115,10 +121,146 @@ Rectangle {
             }
         }
         
+        // Embed player card
+        ColumnLayout {
+            Layout.fillWidth: true
+            visible: !!embed && !isLoading
+            spacing: 0
+            
+            Rectangle {
+                id: embedMedia
+                Layout.fillWidth: true
+                Layout.preferredHeight: {
+                    var ratio = embed ? embed.aspectRatio : 0
+                    if (!(previewData?.image) && !(embed?.streamUrl)) return 0
+                    return ratio > 0 ? Math.min(width / ratio, 400) : 200
+                }
+                color: "#000000"
+                radius: 8
+                clip: true
+                visible: Layout.preferredHeight > 0
+                
+                MediaImage {
+                    anchors.fill: parent
+                    url: previewData?.image || ""
+                    fillMode: Image.PreserveAspectFit
+                    asynchronous: true
+                    visible: !playing
+                }
+                
+                // Play / listen button
+                Rectangle {
+                    anchors.centerIn: parent
+                    width: 56
+                    height: 56
+                    radius: 28
+                    color: embedArea.containsMouse ? "#cc9333ea" : "#aa000000"
+                    visible: !playing && !!embed && embed.type !== "rich"
+                    
+                    Text {
+                        anchors.centerIn: parent
+                        text: embed && embed.type === "audio" ? "♫" : "▶"
+                        color: "#ffffff"
+                        font.pixelSize: 22
+                    }
+                }
+                
+                // Provider badge
+                Rectangle {
+                    anchors.top: parent.top
+                    anchors.left: parent.left
+                    anchors.margins: 8
+                    width: providerText.implicitWidth + 12
+                    height: 20
+                    radius: 4
+                    color: "#aa000000"
+                    visible: !playing && providerText.text !== ""
+                    
+                    Text {
+                        id: providerText
+                        anchors.centerIn: parent
+                        text: embed ? embed.provider : ""
+                        color: "#ffffff"
+                        font.pixelSize: 11
+                        font.weight: Font.Medium
+                    }
+                }
+                
+                // Files Qt can decode play inline, everything else opens in the browser
+                Loader {
+                    anchors.fill: parent
+                    active: playing
+                    sourceComponent: VideoPlayer {
+                        source: embed ? (embed.streamUrl || "") : ""
+                        autoPlay: true
+                    }
+                }
+                
+                MouseArea {
+                    id: embedArea
+                    anchors.fill: parent
+                    visible: !playing
+                    hoverEnabled: true
+                    cursorShape: Qt.PointingHandCursor
+                    onClicked: {
+                        if (embed && embed.streamUrl) {
+                            playing = true
+                        } else {
+                            Qt.openUrlExternally(url)
+                        }
+                    }
+                }
+            }
+            
+            ColumnLayout {
+                Layout.fillWidth: true
+                Layout.margins: 10
+                spacing: 4
+                
+                Text {
+                    text: previewData?.title || ""
+                    color: "#ffffff"
+                    font.pixelSize: 13
+                    font.weight: Font.Medium
+                    wrapMode: Text.WordWrap
+                    Layout.fillWidth: true
+                    maximumLineCount: 2
+                    elide: Text.ElideRight
+                    visible: text !== ""
+                }
+                
+                Text {
+                    text: {
+                        if (!embed) return ""
+                        var parts = []
+                        if (embed.author) parts.push(embed.author)
+                        parts.push(embed.provider || extractDomain(url))
+                        return parts.join(" · ")
+                    }
+                    color: "#888888"
+                    font.pixelSize: 11
+                    Layout.fillWidth: true
+                    elide: Text.ElideRight
+                }
+                
+                // Post text for rich embeds (tweets)
+                Text {
+                    text: previewData?.description || ""
+                    color: "#cccccc"
+                    font.pixelSize: 12
+                    wrapMode: Text.WordWrap
+                    Layout.fillWidth: true
+                    maximumLineCount: 6
+                    elide: Text.ElideRight
+                    visible: text !== "" && !!embed && embed.type === "rich"
+                }
+            }
+        }
+        
         // Preview content
         ColumnLayout {
             Layout.fillWidth: true
-            visible: previewData && !isLoading
+            visible: previewData && !embed && !isLoading
             spacing: 0
             
             // Preview image - uses aspect ratio fit to avoid cropping
@@ -132,12 +274,12 @@ Rectangle {
                 visible: !!(previewData?.image)
                 clip: true
                 
-                Image {
+                MediaImage {
                     id: previewImage
                     anchors.centerIn: parent
                     width: parent.width
                     height: parent.height
-                    source: previewData?.image || ""
+                    url: previewData?.image || ""
                     fillMode: Image.PreserveAspectFit
                     asynchronous: true
                     
@@ -0,0 +1,115 @@
+import QtQuick
+import QtQuick.Controls
+
+// Square media tiles for notes with images or videos (feed Media tab, profile grid)
+// Tiles come from get_media_note(index) on the owning controller
+GridView {
+    id: root
+
+    property int tileCount: 0
+    property var getTile: null          // function(index) -> {id, thumbnail, thumbnailBlurhash, isVideo, mediaCount, ...}
+    property int columns: Math.max(2, Math.floor(width / 220))
+
+    signal tileClicked(string noteId)
+    signal nearEnd()
+
+    clip: true
+    cellWidth: Math.floor(width / columns)
+    cellHeight: cellWidth
+    boundsBehavior: Flickable.StopAtBounds
+    reuseItems: true
+    model: tileCount
+
+    ScrollBar.vertical: ScrollBar {
+        policy: ScrollBar.AsNeeded
+    }
+
+    onContentYChanged: {
+        if (interactive && contentHeight > height && contentHeight - (contentY + height) < cellHeight * 2) {
+            root.nearEnd()
+        }
+    }
+
+    delegate: Item {
+        id: tile
+        width: root.cellWidth
+        height: root.cellHeight
+
+        property int displayIndex: index
+        property var note: ({})
+
+        function loadData() {
+            note = root.getTile ? root.getTile(displayIndex) : ({})
+        }
+
+        Component.onCompleted: loadData()
+        onDisplayIndexChanged: loadData()
+        GridView.onReused: loadData()
+
+        Rectangle {
+            anchors.fill: parent
+            anchors.margins: 2
+            color: "#1a1a1a"
+            clip: true
+
+            MediaImage {
+                id: thumb
+                anchors.fill: parent
+                url: tile.note.isVideo || tile.note.hasContentWarning || tile.note.autoLoadMedia === false ? "" : (tile.note.thumbnail || "")
+                blurhash: tile.note.thumbnailBlurhash || ""
+                fillMode: Image.PreserveAspectCrop
+                sourceSize.width: root.cellWidth * 2
+                sourceSize.height: root.cellHeight * 2
+            }
+
+            // Videos, sensitive media and data saver show a placeholder instead of loading
+            Text {
+                anchors.centerIn: parent
+                visible: tile.note.isVideo || tile.note.hasContentWarning || tile.note.autoLoadMedia === false || false
+                text: tile.note.hasContentWarning ? "⚠️" : (tile.note.isVideo ? "▶" : "🖼️")
+                color: "#ffffff"
+                font.pixelSize: 32
+            }
+
+            // More than one item in the note
+            Rectangle {
+                anchors.top: parent.top
+                anchors.right: parent.right
+                anchors.margins: 6
+                width: countText.implicitWidth + 10
+                height: 20
+                radius: 10
+                color: "#aa000000"
+                visible: (tile.note.mediaCount || 0) > 1
+
+                Text {
+                    id: countText
+                    anchors.centerIn: parent
+                    text: "⧉ " + (tile.note.mediaCount || 0)
+                    color: "#ffffff"
+                    font.pixelSize: 11
+                }
+            }
+
+            Rectangle {
+                anchors.fill: parent
+                color: "#ffffff"
+                opacity: tileArea.containsMouse ? 0.08 : 0
+            }
+
+            MouseArea {
+                id: tileArea
+                anchors.fill: parent
+                hoverEnabled: true
+                cursorShape: Qt.PointingHandCursor
+                onClicked: {
+                    if (tile.note.id) root.tileClicked(tile.note.id)
+                }
+            }
+
+            ToolTip.visible: tileArea.containsMouse && (tile.note.authorName || "") !== ""
+            ToolTip.text: tile.note.authorName || ""
+            ToolTip.delay: 800
+        }
+    }
+}
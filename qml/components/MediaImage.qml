@@ -0,0 +1,31 @@
+import QtQuick
+
+// Image that loads remote URLs through the on-disk media cache
+// (image://media provider) and shows a blurhash placeholder until ready
+Image {
+    id: root
+    
+    property string url: ""
+    property string blurhash: ""
+    
+    source: {
+        if (!url) return ""
+        if (url.startsWith("http://") || url.startsWith("https://")) {
+            return "image://media/" + encodeURIComponent(url)
+        }
+        return url  // Local files, qrc and data URLs load directly
+    }
+    asynchronous: true
+    
+    // Blurhash placeholder while loading, and in place of a failed image
+    Image {
+        anchors.fill: parent
+        visible: root.blurhash !== "" && root.status !== Image.Ready
+        source: root.blurhash !== "" ? "image://media/blurhash/" + encodeURIComponent(root.blurhash) : ""
+        sourceSize.width: 32
+        sourceSize.height: 32
+        fillMode: Image.Stretch
+        smooth: true
+        asynchronous: true
+    }
+}
@@ -12,6 +12,11 @@ Rectangle {
     layer.enabled: false  // Disable layer unless needed
     antialiasing: false   // Disable antialiasing for rectangles
     
+    // Demoted by web-of-trust filtering (author outside the trust radius)
+    opacity: outsideTrust && !trustHover.hovered ? 0.5 : 1.0
+    
+    HoverHandler { id: trustHover }
+    
     property string noteId: ""
     property string authorPubkey: ""
     property string authorName: ""
@@ -22,9 +27,18 @@ Rectangle {
     property int likes: 0
     property int reposts: 0
     property int replies: 0
+    property int quotes: 0  // Notes quoting this one
     property int zapAmount: 0
+    property string zapAmountFiat: ""  // e.g. "≈ $1.40", empty when fiat display is off
     property int zapCount: 0  // Number of zaps
     property var reactions: ({})  // Emoji -> count map
+    property var reactionEmoji: ({})  // ":shortcode:" reaction -> image URL (NIP-30)
+    property bool hasLiked: false  // The user reacted to this note
+    property string myReaction: ""  // The user's reaction content ("+" for a like)
+    property bool hasReposted: false
+    property int zappedSats: 0  // Sats the user zapped this note
+    property var emojis: ({})  // Shortcode -> image URL for :shortcodes: in the content (NIP-30)
+    property var blurhashes: ({})  // Image URL -> blurhash placeholder (NIP-92 imeta)
     property var images: []
     property var videos: []
     property bool isReply: false
@@ -34,12 +48,30 @@ Rectangle {
     property string repostAuthorName: ""
     property string repostAuthorPicture: ""
     property var feedController: null  // For embedded content fetching
+    property bool outsideTrust: false
+    property var extras: ({})  // Display data from renderer hooks, keyed by hook name
+    property var poll: extras && extras.poll ? extras.poll : null
+    property var pollResults: null  // Tallied votes, see feedController.fetch_poll_results
+    property var pollSelection: []  // Options ticked on a multiple choice poll
+    property var highlight: extras && extras.highlight ? extras.highlight : null
+    property var zapGoal: extras && extras.zapGoal ? extras.zapGoal : null
+    property var goalProgress: null  // Zaps summed towards the goal, see feedController.fetch_zap_goal_progress
+    property var unknownKind: extras && extras.handler ? extras.handler : null  // A kind this app doesn't render (NIP-89)
+    property var kindHandlers: null  // Apps that can open it, see feedController.fetch_kind_handlers
+    property var collapsed: extras && extras.collapsed ? extras.collapsed : null  // Global feed spam folded into this note
+    property bool autoLoadMedia: true  // false in data saver mode (note.autoLoadMedia)
+    property bool mediaRequested: false  // Data saver: the user tapped to load this note's media
+    readonly property bool loadMedia: autoLoadMedia || mediaRequested
     
     // Track if stats have been loaded
     property bool statsLoaded: false
     // Track if stats fetch is in progress
     property bool statsLoading: false
     
+    // Operation id of a failed like/reaction/repost/reply on this note
+    property string failedOpId: ""
+    property string failedOpError: ""
+    
     signal likeClicked()
     signal repostClicked()
     signal replyClicked()
@@ -49,11 +81,78 @@ Rectangle {
     
     // Async stats fetching - non-blocking
     onNoteIdChanged: {
+        failedOpId = ""
+        mediaRequested = false
         if (noteId && feedController && visible && !statsLoaded && !statsLoading) {
             fetchStatsTimer.restart()
         }
     }
     
+    Connections {
+        target: root.feedController
+        ignoreUnknownSignals: true
+        enabled: root.noteId !== ""
+        
+        function onOperation_failed(op_id, kind, error) {
+            if (op_id === kind + ":" + root.noteId) {
+                root.failedOpId = op_id
+                root.failedOpError = error
+            }
+        }
+        function onOperation_started(op_id, kind) {
+            if (op_id === root.failedOpId) root.failedOpId = ""
+        }
+        // Stats fetched in the background are pushed by the controller
+        function onNote_stats_ready(note_id, stats_json) {
+            if (note_id !== root.noteId) return
+            try {
+                applyStats(JSON.parse(stats_json))
+            } catch (e) {
+                root.statsLoading = false
+            }
+        }
+        // The author edited their profile while this card was shown
+        function onNote_author_updated(pubkey, author_name, author_picture) {
+            if (pubkey !== root.authorPubkey) return
+            root.authorName = author_name
+            root.authorPicture = author_picture
+            if (root.isRepost) {
+                root.repostAuthorName = author_name
+                root.repostAuthorPicture = author_picture
+            }
+        }
+        function onPoll_results_ready(poll_id, results_json) {
+            if (poll_id !== root.noteId) return
+            try {
+                root.pollResults = JSON.parse(results_json)
+                root.pollSelection = []
+            } catch (e) {}
+        }
+        function onZap_goal_progress_ready(goal_id, progress_json) {
+            if (goal_id !== root.noteId) return
+            try {
+                root.goalProgress = JSON.parse(progress_json)
+            } catch (e) {}
+        }
+        function onKind_handlers_ready(note_id, handlers_json) {
+            if (note_id !== root.noteId) return
+            try {
+                root.kindHandlers = JSON.parse(handlers_json)
+            } catch (e) {}
+        }
+        // A contribution to a goal shows up in its progress once the receipt is published
+        function onZap_success(note_id, amount_sats) {
+            if (note_id === root.noteId && root.zapGoal) goalRefreshTimer.restart()
+        }
+    }
+    
+    Timer {
+        id: goalRefreshTimer
+        interval: 3000
+        repeat: false
+        onTriggered: root.feedController.fetch_zap_goal_progress(root.noteId)
+    }
+    
     onVisibleChanged: {
         if (visible && noteId && feedController && !statsLoaded && !statsLoading) {
             fetchStatsTimer.restart()
@@ -67,51 +166,25 @@ Rectangle {
         repeat: false
         onTriggered: {
             if (root.noteId && root.feedController && !root.statsLoaded) {
+                if (root.poll && !root.pollResults) {
+                    root.feedController.fetch_poll_results(root.noteId)
+                }
+                if (root.zapGoal && !root.goalProgress) {
+                    root.feedController.fetch_zap_goal_progress(root.noteId)
+                }
+                if (root.unknownKind && !root.kindHandlers) {
+                    root.feedController.fetch_kind_handlers(root.noteId, root.unknownKind.kind, root.unknownKind.bech32)
+                }
                 root.statsLoading = true
                 var result = root.feedController.fetch_note_stats(root.noteId)
                 try {
                     var stats = JSON.parse(result)
-                    if (stats.loading) {
-                        // Data is being fetched, start polling
-                        statsPollTimer.start()
-                    } else {
-                        // Data was cached, apply immediately
-                        applyStats(stats)
-                    }
-                } catch (e) {
-                    root.statsLoading = false
-                }
-            }
-        }
-    }
-    
-    // Poll timer for checking async stats results
-    Timer {
-        id: statsPollTimer
-        interval: 200  // Check every 200ms
-        repeat: true
-        property int pollCount: 0
-        onTriggered: {
-            pollCount++
-            if (pollCount > 25) {  // Give up after 5 seconds
-                stop()
-                pollCount = 0
-                root.statsLoading = false
-                return
-            }
-            
-            if (root.noteId && root.feedController) {
-                var result = root.feedController.get_cached_note_stats(root.noteId)
-                try {
-                    var stats = JSON.parse(result)
+                    // While loading, the stats arrive through note_stats_ready
                     if (!stats.loading) {
-                        // Data is ready
-                        stop()
-                        pollCount = 0
                         applyStats(stats)
                     }
                 } catch (e) {
-                    // Continue polling
+                    root.statsLoading = false
                 }
             }
         }
@@ -122,9 +195,15 @@ Rectangle {
         if (stats.reactions && Object.keys(stats.reactions).length > 0) {
             root.reactions = stats.reactions
         }
+        if (stats.reactionEmoji) {
+            root.reactionEmoji = stats.reactionEmoji
+        }
         if (stats.zapAmount !== undefined) {
             root.zapAmount = stats.zapAmount
         }
+        if (stats.zapAmountFiat !== undefined) {
+            root.zapAmountFiat = stats.zapAmountFiat
+        }
         if (stats.zapCount !== undefined) {
             root.zapCount = stats.zapCount
         }
@@ -134,6 +213,19 @@ Rectangle {
         if (stats.repostCount !== undefined) {
             root.reposts = stats.repostCount
         }
+        if (stats.quoteCount !== undefined) {
+            root.quotes = stats.quoteCount
+        }
+        if (stats.hasLiked !== undefined) {
+            root.hasLiked = stats.hasLiked
+            root.myReaction = stats.myReaction || ""
+        }
+        if (stats.hasReposted !== undefined) {
+            root.hasReposted = stats.hasReposted
+        }
+        if (stats.zappedSats !== undefined) {
+            root.zappedSats = stats.zappedSats
+        }
         root.statsLoaded = true
         root.statsLoading = false
     }
@@ -171,6 +263,15 @@ Rectangle {
             }
         }
         
+        // Bridged from another network (NIP-48 proxy tag)
+        Text {
+            Layout.fillWidth: true
+            visible: !!(extras && extras.proxy)
+            text: "🌉 Bridged" + (extras && extras.proxy && extras.proxy.protocol ? " from " + extras.proxy.protocol : "")
+            color: "#666666"
+            font.pixelSize: 12
+        }
+        
         // Reply indicator
         RowLayout {
             Layout.fillWidth: true
@@ -273,6 +374,7 @@ Rectangle {
         
         // Content
         Text {
+            visible: root.highlight === null
             text: formatContent(content)
             color: "#ffffff"
             font.pixelSize: 15
@@ -289,21 +391,460 @@ Rectangle {
             }
         }
         
+        // Highlight (NIP-84): the excerpt, set off from regular note text
+        RowLayout {
+            Layout.fillWidth: true
+            spacing: 12
+            visible: root.highlight !== null
+            
+            Rectangle {
+                Layout.preferredWidth: 4
+                Layout.fillHeight: true
+                radius: 2
+                color: "#ca8a04"
+            }
+            
+            ColumnLayout {
+                Layout.fillWidth: true
+                spacing: 6
+                
+                Text {
+                    Layout.fillWidth: true
+                    text: root.highlight ? root.highlight.comment || "" : ""
+                    color: "#ffffff"
+                    font.pixelSize: 15
+                    wrapMode: Text.WordWrap
+                    visible: text !== ""
+                }
+                
+                Text {
+                    Layout.fillWidth: true
+                    text: root.highlight ? root.highlight.text : ""
+                    color: "#fde68a"
+                    font.pixelSize: 16
+                    font.italic: true
+                    wrapMode: Text.WordWrap
+                    lineHeight: 1.4
+                }
+                
+                Text {
+                    Layout.fillWidth: true
+                    text: root.highlight && root.highlight.context ? root.highlight.context : ""
+                    color: "#888888"
+                    font.pixelSize: 13
+                    wrapMode: Text.WordWrap
+                    maximumLineCount: 4
+                    elide: Text.ElideRight
+                    visible: text !== ""
+                }
+                
+                Text {
+                    text: root.highlight && root.highlight.sourceEventId ? "From a note →"
+                        : (root.highlight && root.highlight.sourceUrl ? root.highlight.sourceUrl : "")
+                    color: "#9333ea"
+                    font.pixelSize: 12
+                    elide: Text.ElideRight
+                    Layout.maximumWidth: 400
+                    visible: text !== ""
+                    
+                    MouseArea {
+                        anchors.fill: parent
+                        cursorShape: Qt.PointingHandCursor
+                        onClicked: {
+                            if (root.highlight.sourceEventId) {
+                                root.noteClicked(root.highlight.sourceEventId)
+                            } else {
+                                Qt.openUrlExternally(root.highlight.sourceUrl)
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        
+        // Poll (kind 1068)
+        ColumnLayout {
+            id: pollBlock
+            Layout.fillWidth: true
+            spacing: 6
+            visible: root.poll !== null
+            
+            property bool voted: !!(root.pollResults && root.pollResults.myVotes.length > 0)
+            property bool closed: !!(root.pollResults && root.pollResults.closed)
+                || !!(root.poll && root.poll.endsAt && root.poll.endsAt * 1000 <= Date.now())
+            property bool showResults: voted || closed
+            
+            Repeater {
+                model: root.poll ? root.poll.options : []
+                
+                delegate: Rectangle {
+                    id: optionRow
+                    Layout.fillWidth: true
+                    Layout.preferredHeight: 36
+                    radius: 8
+                    color: "#1a1a1a"
+                    border.color: chosen ? "#9333ea" : "#2a2a2a"
+                    border.width: 1
+                    clip: true
+                    
+                    property int votes: root.pollResults && root.pollResults.counts[modelData.id] ? root.pollResults.counts[modelData.id] : 0
+                    property int voters: root.pollResults ? root.pollResults.voters : 0
+                    property bool mine: !!(root.pollResults && root.pollResults.myVotes.indexOf(modelData.id) >= 0)
+                    property bool chosen: mine || root.pollSelection.indexOf(modelData.id) >= 0
+                    
+                    // Vote share bar
+                    Rectangle {
+                        anchors.top: parent.top
+                        anchors.bottom: parent.bottom
+                        anchors.left: parent.left
+                        width: optionRow.voters > 0 ? parent.width * optionRow.votes / optionRow.voters : 0
+                        radius: 8
+                        color: optionRow.mine ? "#3b1a5c" : "#262626"
+                        visible: pollBlock.showResults
+                    }
+                    
+                    RowLayout {
+                        anchors.fill: parent
+                        anchors.leftMargin: 12
+                        anchors.rightMargin: 12
+                        spacing: 8
+                        
+                        CheckBox {
+                            visible: root.poll && root.poll.multipleChoice && !pollBlock.showResults
+                            checked: optionRow.chosen
+                            onToggled: {
+                                var selection = root.pollSelection.filter(function(id) { return id !== modelData.id })
+                                if (checked) selection.push(modelData.id)
+                                root.pollSelection = selection
+                            }
+                        }
+                        
+                        Text {
+                            Layout.fillWidth: true
+                            text: modelData.label || modelData.id
+                            color: "#ffffff"
+                            font.pixelSize: 14
+                            elide: Text.ElideRight
+                        }
+                        
+                        Text {
+                            text: optionRow.voters > 0 ? Math.round(100 * optionRow.votes / optionRow.voters) + "%" : "0%"
+                            color: "#aaaaaa"
+                            font.pixelSize: 13
+                            visible: pollBlock.showResults
+                        }
+                    }
+                    
+                    // Single choice polls vote on click
+                    MouseArea {
+                        anchors.fill: parent
+                        enabled: root.poll && !root.poll.multipleChoice && !pollBlock.showResults
+                        cursorShape: enabled ? Qt.PointingHandCursor : Qt.ArrowCursor
+                        onClicked: {
+                            root.pollSelection = [modelData.id]
+                            root.feedController.vote_poll(root.noteId, JSON.stringify([modelData.id]))
+                        }
+                    }
+                }
+            }
+            
+            RowLayout {
+                Layout.fillWidth: true
+                spacing: 8
+                
+                Text {
+                    text: {
+                        var parts = []
+                        if (root.pollResults) {
+                            parts.push(root.pollResults.voters + (root.pollResults.voters === 1 ? " vote" : " votes"))
+                        }
+                        if (pollBlock.closed) {
+                            parts.push("Final results")
+                        } else if (root.poll && root.poll.endsAt) {
+                            parts.push("Ends " + new Date(root.poll.endsAt * 1000).toLocaleString(Qt.locale(), Locale.ShortFormat))
+                        }
+                        return parts.join(" · ")
+                    }
+                    color: "#888888"
+                    font.pixelSize: 12
+                }
+                
+                Item { Layout.fillWidth: true }
+                
+                Button {
+                    text: "Vote"
+                    visible: root.poll && root.poll.multipleChoice && !pollBlock.showResults
+                    enabled: root.pollSelection.length > 0
+                    onClicked: root.feedController.vote_poll(root.noteId, JSON.stringify(root.pollSelection))
+                }
+            }
+        }
+        
+        // Zap goal (kind 9041)
+        Rectangle {
+            id: goalBlock
+            Layout.fillWidth: true
+            Layout.preferredHeight: goalColumn.implicitHeight + 24
+            visible: root.zapGoal !== null
+            radius: 8
+            color: "#1a1a1a"
+            border.color: "#2a2a2a"
+            border.width: 1
+            
+            property int raised: root.goalProgress ? root.goalProgress.raisedSats : 0
+            property int target: root.zapGoal ? root.zapGoal.targetSats : 0
+            property bool closed: !!(root.goalProgress && root.goalProgress.closed)
+                || !!(root.zapGoal && root.zapGoal.closedAt && root.zapGoal.closedAt * 1000 <= Date.now())
+            
+            ColumnLayout {
+                id: goalColumn
+                anchors.fill: parent
+                anchors.margins: 12
+                spacing: 8
+                
+                Image {
+                    Layout.fillWidth: true
+                    Layout.preferredHeight: status === Image.Ready ? Math.min(width * sourceSize.height / Math.max(sourceSize.width, 1), 200) : 0
+                    source: root.zapGoal && root.zapGoal.image && root.loadMedia ? root.zapGoal.image : ""
+                    fillMode: Image.PreserveAspectCrop
+                    asynchronous: true
+                    visible: status === Image.Ready
+                }
+                
+                Text {
+                    Layout.fillWidth: true
+                    text: root.zapGoal && root.zapGoal.summary ? root.zapGoal.summary : ""
+                    color: "#cccccc"
+                    font.pixelSize: 13
+                    wrapMode: Text.Wrap
+                    visible: text !== ""
+                }
+                
+                RowLayout {
+                    Layout.fillWidth: true
+                    spacing: 8
+                    
+                    Text {
+                        text: "⚡ " + formatSats(goalBlock.raised) + " of " + formatSats(goalBlock.target) + " sats"
+                        color: "#ffffff"
+                        font.pixelSize: 14
+                        font.weight: Font.Medium
+                    }
+                    
+                    Item { Layout.fillWidth: true }
+                    
+                    Text {
+                        text: root.goalProgress ? Math.round(100 * goalBlock.raised / Math.max(goalBlock.target, 1)) + "%" : ""
+                        color: "#f59e0b"
+                        font.pixelSize: 13
+                    }
+                }
+                
+                // Progress bar
+                Rectangle {
+                    Layout.fillWidth: true
+                    Layout.preferredHeight: 8
+                    radius: 4
+                    color: "#262626"
+                    
+                    Rectangle {
+                        anchors.top: parent.top
+                        anchors.bottom: parent.bottom
+                        anchors.left: parent.left
+                        width: parent.width * (root.goalProgress ? root.goalProgress.fraction : 0)
+                        radius: 4
+                        color: "#f59e0b"
+                    }
+                }
+                
+                RowLayout {
+                    Layout.fillWidth: true
+                    spacing: 8
+                    
+                    Text {
+                        Layout.fillWidth: true
+                        text: {
+                            var parts = []
+                            if (root.goalProgress) {
+                                var n = root.goalProgress.contributors
+                                parts.push(n + (n === 1 ? " contributor" : " contributors"))
+                            }
+                            if (goalBlock.closed) {
+                                parts.push("Closed")
+                            } else if (root.zapGoal && root.zapGoal.closedAt) {
+                                parts.push("Closes " + new Date(root.zapGoal.closedAt * 1000).toLocaleString(Qt.locale(), Locale.ShortFormat))
+                            }
+                            return parts.join(" · ")
+                        }
+                        color: "#888888"
+                        font.pixelSize: 12
+                        elide: Text.ElideRight
+                    }
+                    
+                    Text {
+                        text: root.zapGoal && root.zapGoal.link && root.zapGoal.link.indexOf("http") === 0 ? "Details →" : ""
+                        color: "#9333ea"
+                        font.pixelSize: 12
+                        visible: text !== ""
+                        
+                        MouseArea {
+                            anchors.fill: parent
+                            cursorShape: Qt.PointingHandCursor
+                            onClicked: Qt.openUrlExternally(root.zapGoal.link)
+                        }
+                    }
+                    
+                    Button {
+                        text: "⚡ Contribute"
+                        visible: !goalBlock.closed
+                        onClicked: root.zapClicked()
+                    }
+                }
+            }
+        }
+        
+        // Kinds this app doesn't render: offer apps that do (NIP-89)
+        ColumnLayout {
+            Layout.fillWidth: true
+            spacing: 6
+            visible: root.unknownKind !== null
+            
+            Text {
+                Layout.fillWidth: true
+                text: {
+                    if (!root.unknownKind) return ""
+                    var what = "Kind " + root.unknownKind.kind + " event"
+                    if (root.kindHandlers === null) return what + " · looking for apps that can show it..."
+                    if (root.kindHandlers.length === 0) return what + " · no app found that can show it"
+                    return what + " · PlebClient can't show it"
+                }
+                color: "#888888"
+                font.pixelSize: 12
+                wrapMode: Text.Wrap
+            }
+            
+            Flow {
+                Layout.fillWidth: true
+                spacing: 8
+                visible: root.kindHandlers !== null && root.kindHandlers.length > 0
+                
+                Repeater {
+                    model: root.kindHandlers || []
+                    
+                    delegate: Rectangle {
+                        width: handlerRow.implicitWidth + 20
+                        height: 30
+                        radius: 15
+                        color: handlerMouse.containsMouse ? "#333333" : "#262626"
+                        border.color: "#9333ea"
+                        border.width: 1
+                        
+                        RowLayout {
+                            id: handlerRow
+                            anchors.centerIn: parent
+                            spacing: 6
+                            
+                            ProfileAvatar {
+                                Layout.preferredWidth: 18
+                                Layout.preferredHeight: 18
+                                name: modelData.name
+                                imageUrl: modelData.picture || ""
+                                visible: !!modelData.picture
+                            }
+                            
+                            Text {
+                                text: "Open in " + modelData.name
+                                color: "#ffffff"
+                                font.pixelSize: 12
+                            }
+                            
+                            Text {
+                                text: modelData.recommendations > 0 ? "· " + modelData.recommendations + " recommend" : ""
+                                color: "#888888"
+                                font.pixelSize: 11
+                                visible: text !== ""
+                            }
+                        }
+                        
+                        MouseArea {
+                            id: handlerMouse
+                            anchors.fill: parent
+                            hoverEnabled: true
+                            cursorShape: Qt.PointingHandCursor
+                            onClicked: Qt.openUrlExternally(modelData.url)
+                        }
+                    }
+                }
+            }
+        }
+        
+        // Repetitive notes folded into this one (global feed)
+        Text {
+            Layout.fillWidth: true
+            visible: root.collapsed !== null
+            text: {
+                if (!root.collapsed) return ""
+                var n = root.collapsed.count
+                var what = n === 1 ? "1 similar note" : n + " similar notes"
+                var reason = root.collapsed.reason === "link" ? "linking the same URL"
+                    : root.collapsed.reason === "burst" ? "posted in a burst" : "with the same text"
+                var from = root.collapsed.authors > 1 ? " from " + root.collapsed.authors + " accounts" : ""
+                return "🔁 " + what + " " + reason + from + " collapsed"
+            }
+            color: "#888888"
+            font.pixelSize: 12
+            elide: Text.ElideRight
+        }
+        
+        // Data saver: media, previews and embeds wait for a tap
+        Rectangle {
+            id: deferredMedia
+            Layout.fillWidth: true
+            Layout.preferredHeight: 36
+            radius: 8
+            color: deferredArea.containsMouse ? "#222222" : "#1a1a1a"
+            border.color: "#333333"
+            border.width: 1
+            visible: !root.loadMedia && summary !== ""
+            
+            property string summary: root.loadMedia ? "" : deferredSummary()
+            
+            Text {
+                anchors.fill: parent
+                anchors.leftMargin: 12
+                anchors.rightMargin: 12
+                verticalAlignment: Text.AlignVCenter
+                text: "📶 " + deferredMedia.summary + " · tap to load"
+                color: "#aaaaaa"
+                font.pixelSize: 12
+                elide: Text.ElideRight
+            }
+            
+            MouseArea {
+                id: deferredArea
+                anchors.fill: parent
+                hoverEnabled: true
+                cursorShape: Qt.PointingHandCursor
+                onClicked: root.mediaRequested = true
+            }
+        }
+        
         // Embedded nostr notes (nevent, naddr, note)
         Repeater {
-            model: extractNostrUris(content)
+            model: root.loadMedia ? extractNostrUris(content) : []
             
             delegate: EmbeddedNote {
                 Layout.fillWidth: true
                 nostrUri: modelData
                 feedController: root.feedController
+                parentId: root.noteId
                 onClicked: (noteId) => root.noteClicked(noteId)
             }
         }
         
         // Embedded nostr profiles (nprofile, npub)
         Repeater {
-            model: extractProfileUris(content)
+            model: root.loadMedia ? extractProfileUris(content) : []
             
             delegate: EmbeddedProfile {
                 Layout.fillWidth: true
@@ -313,20 +854,9 @@ Rectangle {
             }
         }
         
-        // YouTube videos (embedded player)
-        Repeater {
-            model: extractYouTubeUrls(content)
-            
-            delegate: YouTubePlayer {
-                Layout.fillWidth: true
-                Layout.preferredHeight: 280
-                url: modelData
-            }
-        }
-        
         // Fountain.fm podcasts (in-client player)
         Repeater {
-            model: extractFountainUrls(content)
+            model: root.loadMedia ? extractFountainUrls(content) : []
             
             delegate: FountainPlayer {
                 Layout.fillWidth: true
@@ -337,7 +867,7 @@ Rectangle {
         
         // Link previews (non-media URLs)
         Repeater {
-            model: extractPreviewUrls(content)
+            model: root.loadMedia ? extractPreviewUrls(content) : []
             
             delegate: LinkPreview {
                 Layout.fillWidth: true
@@ -350,10 +880,10 @@ Rectangle {
         Flow {
             Layout.fillWidth: true
             spacing: 8
-            visible: images && images.length > 0
+            visible: root.loadMedia && images && images.length > 0
             
             Repeater {
-                model: images || []
+                model: root.loadMedia ? (images || []) : []
                 
                 delegate: Rectangle {
                     id: imageContainer
@@ -380,13 +910,14 @@ Rectangle {
                     clip: true
                     
                     // Static image (for non-GIF)
-                    Image {
+                    MediaImage {
                         id: galleryImage
                         visible: !imageContainer.isGif
                         anchors.centerIn: parent
                         width: parent.width
                         height: parent.height
-                        source: imageContainer.isGif ? "" : modelData
+                        url: imageContainer.isGif ? "" : modelData
+                        blurhash: root.blurhashes[modelData] || ""
                         fillMode: Image.PreserveAspectFit
                         asynchronous: true
                         
@@ -477,7 +1008,7 @@ Rectangle {
         
         // Video player
         Repeater {
-            model: videos || []
+            model: root.loadMedia ? (videos || []) : []
             
             delegate: VideoPlayer {
                 Layout.fillWidth: true
@@ -510,6 +1041,17 @@ Rectangle {
                         Text {
                             text: modelData
                             font.pixelSize: 12
+                            visible: !root.reactionEmoji[modelData]
+                        }
+                        
+                        // Custom emoji reaction (NIP-30)
+                        Image {
+                            Layout.preferredWidth: 16
+                            Layout.preferredHeight: 16
+                            source: root.reactionEmoji[modelData] || ""
+                            fillMode: Image.PreserveAspectFit
+                            asynchronous: true
+                            visible: !!root.reactionEmoji[modelData]
                         }
                         
                         Text {
@@ -526,6 +1068,7 @@ Rectangle {
                             // React with the same emoji
                             reactionPicker.noteId = root.noteId
                             reactionPicker.reactions = root.reactions
+                            reactionPicker.reactionEmoji = root.reactionEmoji
                             reactionPicker.open()
                         }
                     }
@@ -542,27 +1085,35 @@ Rectangle {
             ActionButton {
                 icon: "💬"
                 count: replies
-                tooltipText: "Reply to this note"
+                tooltipText: replies > 0 ? replies + (replies === 1 ? " reply" : " replies") + " · click to reply" : "Reply to this note"
                 onClicked: root.replyClicked()
             }
             
             // Repost
             ActionButton {
                 icon: "🔄"
-                count: reposts
-                tooltipText: "Repost this note"
+                // Reposts and quotes together, as other clients show them
+                count: reposts + quotes
+                highlight: root.hasReposted
+                highlightColor: "#22c55e"
+                tooltipText: (reposts + quotes > 0 ? reposts + " reposts · " + quotes + " quotes\n" : "")
+                    + (root.hasReposted ? "You reposted this note" : "Repost this note")
                 onClicked: root.repostClicked()
             }
             
             // Like/React (opens reaction picker)
             ActionButton {
                 id: likeButton
-                icon: "❤️"
+                // Show the user's own emoji once they've reacted (custom :shortcode: reactions keep the heart)
+                icon: root.hasLiked && root.myReaction !== "+" && root.myReaction.charAt(0) !== ":" ? root.myReaction : "❤️"
                 count: getTotalReactions()
-                tooltipText: "React with emoji"
+                highlight: root.hasLiked
+                highlightColor: "#ec4899"
+                tooltipText: root.hasLiked ? "You reacted - react again" : "React with emoji"
                 onClicked: {
                     reactionPicker.noteId = root.noteId
                     reactionPicker.reactions = root.reactions
+                    reactionPicker.reactionEmoji = root.reactionEmoji
                     reactionPicker.open()
                 }
                 
@@ -579,14 +1130,153 @@ Rectangle {
             // Zap - show total sats and count
             ZapButton {
                 zapAmount: root.zapAmount
+                zapAmountFiat: root.zapAmountFiat
                 zapCount: root.zapCount
+                zappedSats: root.zappedSats
                 onClicked: root.zapClicked()
             }
             
+            // Retry a failed action on this note
+            ActionButton {
+                icon: "⚠️"
+                visible: root.failedOpId !== ""
+                tooltipText: "Failed: " + root.failedOpError + " - click to retry"
+                onClicked: root.feedController.retry_operation(root.failedOpId)
+            }
+            
             Item { Layout.fillWidth: true }
+            
+            // More actions (report)
+            ActionButton {
+                icon: "⋯"
+                tooltipText: "More actions"
+                visible: root.feedController !== null
+                onClicked: moreMenu.open()
+                
+                Menu {
+                    id: moreMenu
+                    y: parent.height + 4
+                    
+                    background: Rectangle {
+                        implicitWidth: 200
+                        color: "#1a1a1a"
+                        border.color: "#333333"
+                        border.width: 1
+                        radius: 8
+                    }
+                    
+                    MenuItem {
+                        text: "✨  Highlight text"
+                        visible: root.content.trim() !== "" && root.highlight === null
+                        height: visible ? implicitHeight : 0
+                        onTriggered: root.openHighlight()
+                        
+                        background: Rectangle {
+                            color: parent.highlighted ? "#333333" : "transparent"
+                            radius: 4
+                        }
+                        
+                        contentItem: Text {
+                            text: parent.text
+                            font.pixelSize: 14
+                            color: "#ffffff"
+                            leftPadding: 8
+                        }
+                    }
+                    
+                    MenuItem {
+                        text: "📡  Broadcast to my relays"
+                        onTriggered: root.feedController.broadcast_note(root.noteId)
+                        
+                        background: Rectangle {
+                            color: parent.highlighted ? "#333333" : "transparent"
+                            radius: 4
+                        }
+                        
+                        contentItem: Text {
+                            text: parent.text
+                            font.pixelSize: 14
+                            color: "#ffffff"
+                            leftPadding: 8
+                        }
+                    }
+                    
+                    MenuItem {
+                        text: "🚩  Report note"
+                        onTriggered: root.openReport(true)
+                        
+                        background: Rectangle {
+                            color: parent.highlighted ? "#333333" : "transparent"
+                            radius: 4
+                        }
+                        
+                        contentItem: Text {
+                            text: parent.text
+                            font.pixelSize: 14
+                            color: "#ffffff"
+                            leftPadding: 8
+                        }
+                    }
+                    
+                    MenuItem {
+                        text: "🚫  Report " + (root.authorName || "user")
+                        onTriggered: root.openReport(false)
+                        
+                        background: Rectangle {
+                            color: parent.highlighted ? "#333333" : "transparent"
+                            radius: 4
+                        }
+                        
+                        contentItem: Text {
+                            text: parent.text
+                            font.pixelSize: 14
+                            color: "#ffffff"
+                            leftPadding: 8
+                            elide: Text.ElideRight
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
+    // Report dialog, created on first use
+    Loader {
+        id: reportLoader
+        active: false
+        sourceComponent: ReportDialog {
+            parent: Overlay.overlay
+            feedController: root.feedController
+        }
+    }
+    
+    function openReport(isNote) {
+        reportLoader.active = true
+        var dialog = reportLoader.item
+        dialog.noteId = isNote ? root.noteId : ""
+        dialog.pubkey = root.authorPubkey
+        dialog.authorName = root.authorName
+        dialog.open()
+    }
+    
+    // Highlight dialog, created on first use
+    Loader {
+        id: highlightLoader
+        active: false
+        sourceComponent: HighlightDialog {
+            parent: Overlay.overlay
+            feedController: root.feedController
         }
     }
     
+    function openHighlight() {
+        highlightLoader.active = true
+        var dialog = highlightLoader.item
+        dialog.noteId = root.noteId
+        dialog.content = root.content
+        dialog.open()
+    }
+    
     // Reaction picker popup
     ReactionPicker {
         id: reactionPicker
@@ -646,6 +1336,12 @@ Rectangle {
             return '<a href="' + url + '" style="color: #9333ea;">' + displayUrl + '</a>'
         })
         
+        // Custom emoji (NIP-30) declared by the note's emoji tags
+        escaped = escaped.replace(/:([a-zA-Z0-9_-]+):/g, function(match, code) {
+            var url = root.emojis[code]
+            return url ? '<img src="' + url + '" width="20" height="20">' : match
+        })
+        
         // Convert newlines
         escaped = escaped.replace(/\n/g, "<br>")
         
@@ -656,6 +1352,21 @@ Rectangle {
     }
     
     // Extract nostr:nevent, nostr:naddr, nostr:note URIs from content
+    // What data saver is holding back, e.g. "2 images, 1 link"
+    function deferredSummary() {
+        var parts = []
+        var plural = function(n, word) { return n + " " + word + (n === 1 ? "" : "s") }
+        var imageCount = (images || []).length
+        var videoCount = (videos || []).length
+        var linkCount = extractPreviewUrls(content).length + extractFountainUrls(content).length
+        var embedCount = extractNostrUris(content).length + extractProfileUris(content).length
+        if (imageCount > 0) parts.push(plural(imageCount, "image"))
+        if (videoCount > 0) parts.push(plural(videoCount, "video"))
+        if (linkCount > 0) parts.push(plural(linkCount, "link preview"))
+        if (embedCount > 0) parts.push(plural(embedCount, "embed"))
+        return parts.join(", ")
+    }
+    
     function extractNostrUris(text) {
         if (!text) return []
         
@@ -687,16 +1398,6 @@ Rectangle {
         return [...new Set(uris)]
     }
     
-    // Check if URL is a YouTube URL
-    function isYouTubeUrl(urlStr) {
-        if (!urlStr) return false
-        var lower = urlStr.toLowerCase()
-        return lower.includes("youtube.com/watch") || 
-               lower.includes("youtu.be/") || 
-               lower.includes("youtube.com/shorts/") ||
-               lower.includes("youtube.com/embed/")
-    }
-    
     // Check if URL is a Fountain.fm URL
     function isFountainUrl(urlStr) {
         if (!urlStr) return false
@@ -704,25 +1405,6 @@ Rectangle {
         return lower.includes("fountain.fm/episode") || lower.includes("fountain.fm/show")
     }
     
-    // Extract YouTube URLs from text
-    function extractYouTubeUrls(text) {
-        if (!text) return []
-        
-        var urls = []
-        var pattern = /https?:\/\/[^\s<>\[\]]+/g
-        var match
-        
-        while ((match = pattern.exec(text)) !== null) {
-            var url = match[0]
-            if (isYouTubeUrl(url)) {
-                urls.push(url)
-            }
-        }
-        
-        // Deduplicate and limit to first 3 videos
-        return [...new Set(urls)].slice(0, 3)
-    }
-    
     // Extract Fountain.fm URLs from text
     function extractFountainUrls(text) {
         if (!text) return []
@@ -742,7 +1424,7 @@ Rectangle {
         return [...new Set(urls)].slice(0, 3)
     }
     
-    // Extract URLs for link previews (non-media, non-nostr, non-youtube, non-fountain)
+    // Extract URLs for link previews and player embeds (non-media, non-nostr, non-fountain)
     function extractPreviewUrls(text) {
         if (!text) return []
         
@@ -759,11 +1441,6 @@ Rectangle {
                 continue
             }
             
-            // Skip YouTube URLs (shown with embedded player)
-            if (isYouTubeUrl(url)) {
-                continue
-            }
-            
             // Skip Fountain.fm URLs (shown with podcast player)
             if (isFountainUrl(url)) {
                 continue
@@ -797,6 +1474,7 @@ Rectangle {
         property int count: 0
         property string suffix: ""
         property bool highlight: false
+        property color highlightColor: "#facc15"
         property string tooltipText: ""
         
         width: row.width
@@ -819,7 +1497,7 @@ Rectangle {
             
             Text {
                 text: count > 0 ? count.toString() + suffix : ""
-                color: highlight ? "#facc15" : "#888888"
+                color: highlight ? highlightColor : "#888888"
                 font.pixelSize: 13
             }
         }
@@ -828,7 +1506,9 @@ Rectangle {
     // Zap button with amount and count display
     component ZapButton: MouseArea {
         property int zapAmount: 0
+        property string zapAmountFiat: ""
         property int zapCount: 0
+        property int zappedSats: 0  // The user's own zaps
         
         width: zapRow.width
         height: zapRow.height
@@ -841,6 +1521,7 @@ Rectangle {
             Text {
                 text: "⚡"
                 font.pixelSize: 16
+                opacity: zappedSats > 0 || zapAmount === 0 ? 1.0 : 0.6
             }
             
             // Sats amount (primary display)
@@ -863,7 +1544,8 @@ Rectangle {
         
         // Tooltip showing exact amount or prompt to zap
         ToolTip.visible: containsMouse
-        ToolTip.text: zapAmount > 0 ? zapAmount.toLocaleString() + " sats from " + zapCount + " zap" + (zapCount !== 1 ? "s" : "") : "Send a zap"
+        ToolTip.text: (zapAmount > 0 ? zapAmount.toLocaleString() + " sats" + (zapAmountFiat ? " (" + zapAmountFiat + ")" : "") + " from " + zapCount + " zap" + (zapCount !== 1 ? "s" : "") : "Send a zap")
+                      + (zappedSats > 0 ? "\nYou zapped " + zappedSats.toLocaleString() + " sats" : "")
         ToolTip.delay: 500
         
         hoverEnabled: true
@@ -0,0 +1,121 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+// Shows the latest failed operation of a controller with a Retry button
+// Works with any controller exposing operation_started/succeeded/failed and retry_operation
+Rectangle {
+    id: root
+
+    property var controller: null
+
+    property string opId: ""
+    property string kind: ""
+    property string error: ""
+
+    property var kindLabels: ({
+        "like": "Like",
+        "react": "Reaction",
+        "repost": "Repost",
+        "reply": "Reply",
+        "post": "Post",
+        "zap": "Zap",
+        "report": "Report",
+        "follow_set": "List update",
+        "contacts": "Follow list update",
+        "send_message": "Message"
+    })
+
+    width: Math.min(480, parent ? parent.width - 40 : 480)
+    height: toastRow.implicitHeight + 20
+    radius: 10
+    color: "#2a1515"
+    border.color: "#7f1d1d"
+    border.width: 1
+    visible: opId !== ""
+    z: 1000
+
+    function dismiss() {
+        opId = ""
+        hideTimer.stop()
+    }
+
+    Timer {
+        id: hideTimer
+        interval: 10000
+        onTriggered: root.dismiss()
+    }
+
+    Connections {
+        target: root.controller
+        ignoreUnknownSignals: true
+
+        function onOperation_failed(op_id, kind, error) {
+            root.opId = op_id
+            root.kind = kind
+            root.error = error
+            hideTimer.restart()
+        }
+        function onOperation_started(op_id, kind) {
+            if (op_id === root.opId) root.dismiss()
+        }
+        function onOperation_succeeded(op_id, kind, result) {
+            if (op_id === root.opId) root.dismiss()
+        }
+    }
+
+    RowLayout {
+        id: toastRow
+        anchors.fill: parent
+        anchors.margins: 10
+        spacing: 12
+
+        Text {
+            Layout.fillWidth: true
+            text: (root.kindLabels[root.kind] || "Action") + " failed: " + root.error
+            color: "#fecaca"
+            font.pixelSize: 13
+            wrapMode: Text.WordWrap
+            maximumLineCount: 3
+            elide: Text.ElideRight
+        }
+
+        Button {
+            text: "Retry"
+            onClicked: {
+                var id = root.opId
+                root.dismiss()
+                if (root.controller && !root.controller.retry_operation(id)) {
+                    console.log("Nothing to retry for", id)
+                }
+            }
+
+            background: Rectangle {
+                color: parent.pressed ? "#7e22ce" : "#9333ea"
+                radius: 6
+            }
+
+            contentItem: Text {
+                text: parent.text
+                color: "#ffffff"
+                font.pixelSize: 13
+                font.weight: Font.Medium
+                horizontalAlignment: Text.AlignHCenter
+                verticalAlignment: Text.AlignVCenter
+            }
+        }
+
+        Text {
+            text: "✕"
+            color: "#888888"
+            font.pixelSize: 14
+
+            MouseArea {
+                anchors.fill: parent
+                anchors.margins: -6
+                cursorShape: Qt.PointingHandCursor
+                onClicked: root.dismiss()
+            }
+        }
+    }
+}
@@ -9,10 +9,10 @@ Rectangle {
     property string name: ""
     property string imageUrl: ""
     
-    Image {
+    MediaImage {
         id: avatarImage
         anchors.fill: parent
-        source: imageUrl
+        url: imageUrl
         visible: imageUrl !== "" && status === Image.Ready
         fillMode: Image.PreserveAspectCrop
         asynchronous: true  // Load images asynchronously for smooth scrolling
@@ -9,6 +9,7 @@ Popup {
     property string noteId: ""
     property var feedController: null
     property var reactions: ({})  // Current reactions on the note: {emoji: count}
+    property var reactionEmoji: ({})  // Images for the note's ":shortcode:" reactions
     
     // Default emoji options
     property var defaultEmoji: ["❤️", "🤙", "🔥", "😂", "🚀"]
@@ -16,8 +17,57 @@ Popup {
     // Custom emoji that user has added (persisted in settings)
     property var customEmoji: []
     
-    // All available emoji (default + custom)
-    property var allEmoji: defaultEmoji.concat(customEmoji)
+    // Usage history from the database: [{emoji, url, count, lastUsed}]
+    property var frequentUsage: []
+    property var recentUsage: []
+    
+    // NIP-30 emoji from the user's emoji list: [{shortcode, url}]
+    property var emojiSet: []
+    
+    // Quick react row: most used first, topped up with the defaults
+    property var allEmoji: {
+        var list = frequentUsage.map(function(u) { return u.emoji })
+        defaultEmoji.concat(customEmoji).forEach(function(e) {
+            if (list.indexOf(e) < 0) list.push(e)
+        })
+        return list.slice(0, Math.max(8, defaultEmoji.length + customEmoji.length))
+    }
+    
+    // Image URL for a ":shortcode:" reaction, "" for unicode emoji
+    function emojiUrl(emoji) {
+        if (reactionEmoji[emoji]) return reactionEmoji[emoji]
+        var usage = frequentUsage.concat(recentUsage)
+        for (var i = 0; i < usage.length; i++) {
+            if (usage[i].emoji === emoji && usage[i].url) return usage[i].url
+        }
+        for (var j = 0; j < emojiSet.length; j++) {
+            if (":" + emojiSet[j].shortcode + ":" === emoji) return emojiSet[j].url
+        }
+        return ""
+    }
+    
+    function loadUsage() {
+        if (!feedController) return
+        try {
+            frequentUsage = JSON.parse(feedController.frequent_reactions(8))
+            recentUsage = JSON.parse(feedController.recent_reactions(8))
+            emojiSet = JSON.parse(feedController.get_custom_emoji())
+        } catch (e) {
+            console.log("[ReactionPicker] Failed to load reaction usage:", e)
+        }
+    }
+    
+    onAboutToShow: loadUsage()
+    
+    Connections {
+        target: root.feedController
+        ignoreUnknownSignals: true
+        function onCustom_emoji_updated(emojiJson) {
+            try {
+                root.emojiSet = JSON.parse(emojiJson)
+            } catch (e) {}
+        }
+    }
     
     signal reactionSelected(string emoji)
     signal reactionRemoved(string emoji)
@@ -87,6 +137,16 @@ Popup {
                         Text {
                             text: modelData
                             font.pixelSize: 16
+                            visible: !root.emojiUrl(modelData)
+                        }
+                        
+                        Image {
+                            Layout.preferredWidth: 20
+                            Layout.preferredHeight: 20
+                            source: root.emojiUrl(modelData)
+                            fillMode: Image.PreserveAspectFit
+                            asynchronous: true
+                            visible: !!root.emojiUrl(modelData)
                         }
                         
                         Text {
@@ -138,11 +198,23 @@ Popup {
                     color: hovered ? "#333333" : "#2a2a2a"
                     
                     property bool hovered: false
+                    property string imageUrl: root.emojiUrl(modelData)
                     
                     Text {
                         anchors.centerIn: parent
                         text: modelData
                         font.pixelSize: 24
+                        visible: !parent.imageUrl
+                    }
+                    
+                    Image {
+                        anchors.centerIn: parent
+                        width: 28
+                        height: 28
+                        source: parent.imageUrl
+                        fillMode: Image.PreserveAspectFit
+                        asynchronous: true
+                        visible: !!parent.imageUrl
                     }
                     
                     // Show count if this emoji has reactions
@@ -181,6 +253,55 @@ Popup {
             }
         }
         
+        // Recently used
+        Text {
+            text: "Recent"
+            color: "#888888"
+            font.pixelSize: 12
+            visible: root.recentUsage.length > 0
+        }
+        
+        Flow {
+            Layout.fillWidth: true
+            spacing: 6
+            visible: root.recentUsage.length > 0
+            
+            Repeater {
+                model: root.recentUsage
+                
+                delegate: EmojiButton {
+                    emoji: modelData.emoji
+                    imageUrl: modelData.url || ""
+                }
+            }
+        }
+        
+        // NIP-30 custom emoji from the user's emoji list
+        Text {
+            text: "My emoji"
+            color: "#888888"
+            font.pixelSize: 12
+            visible: root.emojiSet.length > 0
+        }
+        
+        Flow {
+            Layout.fillWidth: true
+            spacing: 6
+            visible: root.emojiSet.length > 0
+            
+            Repeater {
+                model: root.emojiSet
+                
+                delegate: EmojiButton {
+                    emoji: ":" + modelData.shortcode + ":"
+                    imageUrl: modelData.url
+                    ToolTip.visible: hovered
+                    ToolTip.text: emoji
+                    ToolTip.delay: 500
+                }
+            }
+        }
+        
         // Add custom emoji section
         Rectangle {
             Layout.fillWidth: true
@@ -273,6 +394,47 @@ Popup {
         }
     }
     
+    // Small emoji button used by the recent and custom rows
+    component EmojiButton: Rectangle {
+        property string emoji: ""
+        property string imageUrl: ""
+        property alias hovered: emojiMouse.containsMouse
+        
+        width: 36
+        height: 36
+        radius: 18
+        color: hovered ? "#333333" : "#2a2a2a"
+        
+        Text {
+            anchors.centerIn: parent
+            text: parent.emoji
+            font.pixelSize: 20
+            visible: !parent.imageUrl
+        }
+        
+        Image {
+            anchors.centerIn: parent
+            width: 22
+            height: 22
+            source: parent.imageUrl
+            fillMode: Image.PreserveAspectFit
+            asynchronous: true
+            visible: !!parent.imageUrl
+        }
+        
+        MouseArea {
+            id: emojiMouse
+            anchors.fill: parent
+            cursorShape: Qt.PointingHandCursor
+            hoverEnabled: true
+            
+            onClicked: {
+                root.reactionSelected(parent.emoji)
+                root.close()
+            }
+        }
+    }
+    
     // Custom emoji manager popup (nested)
     Popup {
         id: customEmojiManager
@@ -0,0 +1,159 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+// Report dialog (NIP-56) for a note or a user
+Popup {
+    id: root
+
+    property string noteId: ""      // Empty when reporting a user
+    property string pubkey: ""
+    property string authorName: ""
+    property var feedController: null
+
+    property var reportTypes: [
+        { value: "spam", label: "Spam" },
+        { value: "impersonation", label: "Impersonation" },
+        { value: "profanity", label: "Profanity or hate speech" },
+        { value: "nudity", label: "Nudity" },
+        { value: "illegal", label: "Illegal content" },
+        { value: "malware", label: "Malware or scam" },
+        { value: "other", label: "Other" }
+    ]
+
+    modal: true
+    dim: true
+    anchors.centerIn: Overlay.overlay
+    width: 340
+    height: contentColumn.implicitHeight + 48
+    padding: 24
+
+    onOpened: {
+        typeGroup.checkedButton = null
+        reasonField.text = ""
+    }
+
+    background: Rectangle {
+        color: "#1a1a1a"
+        radius: 16
+        border.color: "#333333"
+        border.width: 1
+    }
+
+    ButtonGroup { id: typeGroup }
+
+    ColumnLayout {
+        id: contentColumn
+        anchors.fill: parent
+        spacing: 16
+
+        Text {
+            text: root.noteId ? "Report note" : "Report " + (root.authorName || "user")
+            color: "#ffffff"
+            font.pixelSize: 18
+            font.weight: Font.Bold
+        }
+
+        Text {
+            Layout.fillWidth: true
+            text: root.noteId
+                ? "The note will be hidden for you and a report is published to your relays."
+                : "Their notes will be hidden for you and a report is published to your relays."
+            color: "#888888"
+            font.pixelSize: 12
+            wrapMode: Text.WordWrap
+        }
+
+        ColumnLayout {
+            Layout.fillWidth: true
+            spacing: 4
+
+            Repeater {
+                model: root.reportTypes
+
+                delegate: RadioButton {
+                    property string value: modelData.value
+                    text: modelData.label
+                    ButtonGroup.group: typeGroup
+
+                    contentItem: Text {
+                        text: parent.text
+                        color: "#ffffff"
+                        font.pixelSize: 13
+                        leftPadding: parent.indicator.width + 8
+                        verticalAlignment: Text.AlignVCenter
+                    }
+                }
+            }
+        }
+
+        TextField {
+            id: reasonField
+            Layout.fillWidth: true
+            placeholderText: "Details (optional)"
+            placeholderTextColor: "#666666"
+            color: "#ffffff"
+            font.pixelSize: 14
+
+            background: Rectangle {
+                color: "#2a2a2a"
+                radius: 8
+                border.color: reasonField.activeFocus ? "#9333ea" : "#444444"
+                border.width: 1
+            }
+        }
+
+        RowLayout {
+            Layout.fillWidth: true
+            spacing: 12
+
+            Item { Layout.fillWidth: true }
+
+            Button {
+                text: "Cancel"
+                onClicked: root.close()
+
+                background: Rectangle {
+                    color: parent.pressed ? "#333333" : "#2a2a2a"
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: "#ffffff"
+                    font.pixelSize: 14
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+
+            Button {
+                text: "Report"
+                enabled: typeGroup.checkedButton !== null
+
+                onClicked: {
+                    if (!root.feedController) return
+                    var type = typeGroup.checkedButton.value
+                    if (root.noteId) {
+                        root.feedController.report_note(root.noteId, reasonField.text.trim(), type)
+                    } else {
+                        root.feedController.report_user(root.pubkey, reasonField.text.trim(), type)
+                    }
+                    root.close()
+                }
+
+                background: Rectangle {
+                    color: !parent.enabled ? "#3a3a3a" : (parent.pressed ? "#b91c1c" : "#dc2626")
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: parent.enabled ? "#ffffff" : "#888888"
+                    font.pixelSize: 14
+                    font.weight: Font.Medium
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+        }
+    }
+}
@@ -10,8 +10,10 @@ Rectangle {
     property string displayName: ""
     property string profilePicture: ""
     property int walletBalance: 0
+    property string walletBalanceFiat: ""
     property int unreadNotifications: 0
     property int unreadMessages: 0
+    property int liveCount: 0
     
     signal navigate(string screen)
     
@@ -50,6 +52,8 @@ Rectangle {
             model: [
                 { icon: "🏠", label: "Feed", screen: "feed" },
                 { icon: "📖", label: "Reads", screen: "reads" },
+                { icon: "📺", label: "Live", screen: "live" },
+                { icon: "🧭", label: "Explore", screen: "explore" },
                 { icon: "🔍", label: "Search", screen: "search" },
                 { icon: "🔔", label: "Notifications", screen: "notifications" },
                 { icon: "✉️", label: "Messages", screen: "messages" },
@@ -68,6 +72,7 @@ Rectangle {
                 property int badgeCount: {
                     if (modelData.screen === "notifications") return root.unreadNotifications
                     if (modelData.screen === "messages") return root.unreadMessages
+                    if (modelData.screen === "live") return root.liveCount
                     return 0
                 }
                 
@@ -160,6 +165,13 @@ Rectangle {
                     font.pixelSize: 14
                     font.weight: Font.Medium
                 }
+                
+                Text {
+                    text: walletBalanceFiat
+                    color: "#888888"
+                    font.pixelSize: 12
+                    visible: walletBalanceFiat !== ""
+                }
             }
         }
         
@@ -196,10 +208,10 @@ Rectangle {
                     color: "#9333ea"
                     clip: true
                     
-                    Image {
+                    MediaImage {
                         id: sidebarProfileImage
                         anchors.fill: parent
-                        source: profilePicture || ""
+                        url: profilePicture || ""
                         visible: (profilePicture || "").length > 0 && status === Image.Ready
                         fillMode: Image.PreserveAspectCrop
                         asynchronous: true
@@ -0,0 +1,172 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+// Approval prompt for requests from other apps using Pleb Client as a signer
+// Shows one request at a time; further requests wait in the controller's queue
+Popup {
+    id: root
+
+    property var signerController: null
+
+    property string requestId: ""
+    property string appId: ""
+    property string method: ""
+    property int kind: -1
+    property string preview: ""
+
+    property var methodLabels: ({
+        "get_public_key": "wants to know your public key",
+        "sign_event": "wants to sign an event",
+        "nip04_encrypt": "wants to encrypt a message (NIP-04)",
+        "nip04_decrypt": "wants to decrypt a message (NIP-04)",
+        "nip44_encrypt": "wants to encrypt a message (NIP-44)",
+        "nip44_decrypt": "wants to decrypt a message (NIP-44)"
+    })
+
+    modal: true
+    dim: true
+    closePolicy: Popup.NoAutoClose
+    anchors.centerIn: Overlay.overlay
+    width: 400
+    height: contentColumn.implicitHeight + 48
+    padding: 24
+
+    background: Rectangle {
+        color: "#1a1a1a"
+        radius: 16
+        border.color: "#333333"
+        border.width: 1
+    }
+
+    // Show the oldest pending request, or close when none are left
+    function showNext() {
+        var pending = root.signerController ? JSON.parse(root.signerController.get_pending_requests()) : []
+        if (pending.length === 0) {
+            root.requestId = ""
+            root.close()
+            return
+        }
+        var request = pending[0]
+        root.requestId = request.id
+        root.appId = request.appId
+        root.method = request.method
+        root.kind = request.kind === null || request.kind === undefined ? -1 : request.kind
+        root.preview = request.preview
+        rememberCheck.checked = false
+        root.open()
+    }
+
+    Connections {
+        target: root.signerController
+        function onRequest_received(request_id, app_id, method, kind, preview) {
+            if (root.requestId === "") root.showNext()
+        }
+    }
+
+    ColumnLayout {
+        id: contentColumn
+        anchors.fill: parent
+        spacing: 16
+
+        Text {
+            text: "Signing request"
+            color: "#ffffff"
+            font.pixelSize: 18
+            font.weight: Font.Bold
+        }
+
+        Text {
+            Layout.fillWidth: true
+            text: "<b>" + root.appId + "</b> " + (root.methodLabels[root.method] || root.method)
+                  + (root.kind >= 0 ? " of kind " + root.kind : "")
+            textFormat: Text.StyledText
+            color: "#cccccc"
+            font.pixelSize: 14
+            wrapMode: Text.WrapAnywhere
+        }
+
+        Rectangle {
+            Layout.fillWidth: true
+            Layout.preferredHeight: Math.min(previewText.implicitHeight + 20, 160)
+            visible: root.preview !== ""
+            color: "#111111"
+            radius: 8
+            clip: true
+
+            Text {
+                id: previewText
+                anchors.fill: parent
+                anchors.margins: 10
+                text: root.preview
+                color: "#aaaaaa"
+                font.pixelSize: 12
+                wrapMode: Text.WrapAnywhere
+                elide: Text.ElideRight
+            }
+        }
+
+        CheckBox {
+            id: rememberCheck
+            text: "Remember my choice for this app"
+
+            contentItem: Text {
+                text: parent.text
+                color: "#888888"
+                font.pixelSize: 13
+                leftPadding: parent.indicator.width + 8
+                verticalAlignment: Text.AlignVCenter
+            }
+        }
+
+        RowLayout {
+            Layout.fillWidth: true
+            spacing: 12
+
+            Item { Layout.fillWidth: true }
+
+            Button {
+                text: "Reject"
+                onClicked: {
+                    root.signerController.reject(root.requestId, rememberCheck.checked)
+                    root.requestId = ""
+                    root.showNext()
+                }
+
+                background: Rectangle {
+                    color: parent.pressed ? "#333333" : "#2a2a2a"
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: "#ffffff"
+                    font.pixelSize: 14
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+
+            Button {
+                text: "Approve"
+                onClicked: {
+                    root.signerController.approve(root.requestId, rememberCheck.checked)
+                    root.requestId = ""
+                    root.showNext()
+                }
+
+                background: Rectangle {
+                    color: parent.pressed ? "#7c22ce" : "#9333ea"
+                    radius: 8
+                }
+
+                contentItem: Text {
+                    text: parent.text
+                    color: "#ffffff"
+                    font.pixelSize: 14
+                    font.weight: Font.Medium
+                    horizontalAlignment: Text.AlignHCenter
+                }
+            }
+        }
+    }
+}
@@ -16,6 +16,7 @@ Rectangle {
     signal openProfile(string pubkey)
     
     property var articleData: null
+    property int fetchRetries: 0
     
     onNoteIdChanged: {
         if (noteId && feedController) {
@@ -44,17 +45,43 @@ Rectangle {
         // For now, we can iterate the current feed to find it if possible.
         var count = feedController.note_count
         for (var i = 0; i < count; i++) {
-            var json = feedController.get_note(i)
-            var note = JSON.parse(json)
+            var note = feedController.get_note(i)
             if (note.id === noteId) {
                 articleData = note
                 return
             }
         }
         
-        // If not found in current feed (e.g. deep link), we need to fetch it.
-        // We'll implement `load_thread` equivalent for articles or just `fetch_note`.
-        // For now, let's just show what we have.
+        // Deep links pass a nostr:naddr URI; fetch it from relays
+        if (noteId.indexOf("nostr:") === 0) {
+            articleData = null
+            fetchRetries = 0
+            fetchArticle()
+        }
+    }
+    
+    function fetchArticle() {
+        var result = feedController.fetch_embedded_event(noteId, "")
+        if (result && result !== "{}") {
+            try {
+                articleData = JSON.parse(result)
+            } catch (e) {
+                console.warn("Failed to parse article:", e)
+            }
+            fetchTimer.stop()
+        } else if (fetchRetries < 10) {
+            fetchTimer.restart()
+        }
+    }
+    
+    // fetch_embedded_event answers from its cache once the fetch finishes
+    Timer {
+        id: fetchTimer
+        interval: 500
+        onTriggered: {
+            root.fetchRetries++
+            root.fetchArticle()
+        }
     }
     
     Keys.onEscapePressed: root.backClicked()
@@ -116,12 +143,12 @@ Rectangle {
                         spacing: 24
                 
                         // Cover Image
-                        Image {
+                        MediaImage {
                             Layout.fillWidth: true
                             Layout.preferredHeight: 400
-                            source: (articleData && articleData.image) || ""
+                            url: (articleData && articleData.image) || ""
                             fillMode: Image.PreserveAspectCrop
-                            visible: source != ""
+                            visible: url != ""
                         }
                 
                         // Title
@@ -309,10 +336,10 @@ Rectangle {
             radius: 8
             clip: true
             
-            Image {
+            MediaImage {
                 id: img
                 anchors.fill: parent
-                source: contentData ? contentData.url : ""
+                url: contentData ? contentData.url : ""
                 fillMode: Image.PreserveAspectFit
                 asynchronous: true
                 
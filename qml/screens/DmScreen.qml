@@ -1,6 +1,7 @@
 import QtQuick
 import QtQuick.Controls
 import QtQuick.Layouts
+import QtQuick.Dialogs
 import "../components"
 
 Rectangle {
@@ -10,6 +11,18 @@ Rectangle {
     property var dmController: null
     property var appController: null
     
+    // Peer currently typing to us (cleared when the indicator expires)
+    property string typingPeer: ""
+    
+    // Attachment link -> local file URL once downloaded/decrypted, or "failed"
+    property var attachmentUrls: ({})
+    
+    Timer {
+        id: typingTimer
+        interval: 15000
+        onTriggered: root.typingPeer = ""
+    }
+    
     // Initialize when visible and controllers are ready
     onVisibleChanged: {
         if (visible && dmController && appController && appController.public_key.toString() !== "") {
@@ -19,6 +32,15 @@ Rectangle {
         }
     }
     
+    function formatTime(timestamp) {
+        var diff = Date.now() / 1000 - timestamp
+        if (diff < 60) return "now"
+        if (diff < 3600) return Math.floor(diff / 60) + "m"
+        if (diff < 86400) return Math.floor(diff / 3600) + "h"
+        if (diff < 604800) return Math.floor(diff / 86400) + "d"
+        return new Date(timestamp * 1000).toLocaleDateString()
+    }
+    
     // Connect to DM controller signals
     Connections {
         target: dmController
@@ -26,6 +48,8 @@ Rectangle {
         
         function onConversations_updated() {
             console.log("[DEBUG] Conversations updated, count:", dmController ? dmController.conversation_count : 0)
+            // Reset first so delegates reload even when only the order changed
+            conversationList.model = 0
             conversationList.model = dmController ? dmController.conversation_count : 0
         }
         
@@ -41,11 +65,56 @@ Rectangle {
             console.log("[DEBUG] Message sent:", messageId)
         }
         
+        function onPeer_typing(peerPubkey) {
+            root.typingPeer = peerPubkey
+            typingTimer.restart()
+        }
+        
+        function onNew_message_received(fromPubkey, preview) {
+            if (root.typingPeer === fromPubkey) root.typingPeer = ""
+        }
+        
+        function onPeer_read(peerPubkey, upTo) {
+            if (dmController && dmController.selected_conversation.toString() === peerPubkey) {
+                messageList.model = JSON.parse(dmController.get_messages())
+            }
+        }
+        
+        function onAttachment_ready(link, localUrl) {
+            var urls = Object.assign({}, root.attachmentUrls)
+            urls[link] = localUrl
+            root.attachmentUrls = urls
+        }
+        
+        function onAttachment_failed(link, error) {
+            var urls = Object.assign({}, root.attachmentUrls)
+            urls[link] = "failed"
+            root.attachmentUrls = urls
+        }
+        
         function onError_occurred(error) {
             console.log("[DEBUG] DM error:", error)
         }
     }
     
+    FileDialog {
+        id: attachmentDialog
+        title: "Send Image"
+        nameFilters: ["Images (*.jpg *.jpeg *.png *.gif *.webp)"]
+        
+        onAccepted: {
+            var path = selectedFile.toString()
+            if (path.startsWith("file://")) {
+                path = path.substring(7)
+            }
+            if (path && dmController) {
+                // Whatever is typed goes along as the caption
+                dmController.send_attachment(path, dmInput.text.trim())
+                dmInput.text = ""
+            }
+        }
+    }
+    
     RowLayout {
         anchors.fill: parent
         spacing: 0
@@ -355,6 +424,18 @@ Rectangle {
                             }
                         }
                         
+                        // New messages or read changes update the row in place
+                        Connections {
+                            target: dmController
+                            ignoreUnknownSignals: true
+                            
+                            function onConversation_updated(peerPubkey) {
+                                if (!convoDelegate.convoData || convoDelegate.convoData.peerPubkey !== peerPubkey) return
+                                var json = dmController.get_conversation_by_pubkey(peerPubkey)
+                                if (json) convoDelegate.convoData = JSON.parse(json)
+                            }
+                        }
+                        
                         MouseArea {
                             anchors.fill: parent
                             hoverEnabled: true
@@ -435,6 +516,14 @@ Rectangle {
                             ColumnLayout {
                                 spacing: 4
                                 
+                                Text {
+                                    Layout.alignment: Qt.AlignRight
+                                    text: convoDelegate.convoData && convoDelegate.convoData.lastMessageAt > 0
+                                        ? root.formatTime(convoDelegate.convoData.lastMessageAt) : ""
+                                    color: convoDelegate.convoData && convoDelegate.convoData.unreadCount > 0 ? "#9333ea" : "#666666"
+                                    font.pixelSize: 11
+                                }
+                                
                                 // Protocol badge
                                 Rectangle {
                                     Layout.preferredHeight: 16
@@ -464,7 +553,7 @@ Rectangle {
                                     
                                     Text {
                                         anchors.centerIn: parent
-                                        text: convoDelegate.convoData ? convoDelegate.convoData.unreadCount : ""
+                                        text: convoDelegate.convoData ? (convoDelegate.convoData.unreadCount > 99 ? "99+" : convoDelegate.convoData.unreadCount) : ""
                                         color: "#ffffff"
                                         font.pixelSize: 10
                                         font.weight: Font.Bold
@@ -517,11 +606,24 @@ Rectangle {
                         anchors.rightMargin: 16
                         spacing: 12
                         
-                        Text {
-                            text: "Chat"
-                            color: "#ffffff"
-                            font.pixelSize: 16
-                            font.weight: Font.Medium
+                        ColumnLayout {
+                            spacing: 2
+                            
+                            Text {
+                                text: "Chat"
+                                color: "#ffffff"
+                                font.pixelSize: 16
+                                font.weight: Font.Medium
+                            }
+                            
+                            Text {
+                                text: "typing…"
+                                color: "#9333ea"
+                                font.pixelSize: 11
+                                font.italic: true
+                                visible: dmController && root.typingPeer !== ""
+                                    && dmController.selected_conversation.toString() === root.typingPeer
+                            }
                         }
                         
                         Item { Layout.fillWidth: true }
@@ -596,21 +698,85 @@ Rectangle {
                     }
                     
                     delegate: Rectangle {
-                        width: Math.min(messageList.width - 100, messageText.implicitWidth + 32)
-                        height: messageText.height + 24
+                        property var attachments: modelData.attachments || []
+                        
+                        width: Math.min(messageList.width - 100, Math.max(messageText.visible ? messageText.implicitWidth : 0, attachments.length > 0 ? 240 : 0, modelData.seen ? 40 : 0) + 32)
+                        height: bubbleContent.height + 24 + (modelData.seen ? 14 : 0)
                         radius: 16
                         color: modelData.isOutgoing ? "#9333ea" : "#1a1a1a"
                         anchors.right: modelData.isOutgoing ? parent.right : undefined
                         anchors.left: modelData.isOutgoing ? undefined : parent.left
                         
-                        Text {
-                            id: messageText
-                            anchors.centerIn: parent
+                        Column {
+                            id: bubbleContent
+                            anchors.top: parent.top
+                            anchors.topMargin: 12
+                            anchors.horizontalCenter: parent.horizontalCenter
                             width: parent.width - 32
-                            text: modelData.content
-                            color: "#ffffff"
-                            font.pixelSize: 14
-                            wrapMode: Text.WordWrap
+                            spacing: 8
+                            
+                            // Inline images, fetched (and decrypted) on demand
+                            Repeater {
+                                model: attachments
+                                
+                                delegate: Item {
+                                    property string localUrl: modelData.localUrl || root.attachmentUrls[modelData.link] || ""
+                                    property bool failed: localUrl === "failed"
+                                    
+                                    width: Math.min(240, bubbleContent.width)
+                                    height: attachmentImage.status === Image.Ready ? attachmentImage.height : 120
+                                    
+                                    Component.onCompleted: {
+                                        if (localUrl === "" && dmController) dmController.fetch_attachment(modelData.link)
+                                    }
+                                    
+                                    Rectangle {
+                                        anchors.fill: parent
+                                        radius: 8
+                                        color: "#33000000"
+                                        visible: attachmentImage.status !== Image.Ready
+                                        
+                                        Text {
+                                            anchors.centerIn: parent
+                                            text: parent.parent.failed ? "Couldn't load image" : (modelData.encrypted ? "Decrypting image..." : "Loading image...")
+                                            color: "#cccccc"
+                                            font.pixelSize: 12
+                                        }
+                                    }
+                                    
+                                    Image {
+                                        id: attachmentImage
+                                        width: parent.width
+                                        height: status === Image.Ready && implicitWidth > 0 ? width * implicitHeight / implicitWidth : 0
+                                        source: parent.localUrl !== "" && !parent.failed ? parent.localUrl : ""
+                                        sourceSize.width: 480
+                                        fillMode: Image.PreserveAspectFit
+                                        asynchronous: true
+                                    }
+                                }
+                            }
+                            
+                            Text {
+                                id: messageText
+                                width: parent.width
+                                text: modelData.text !== undefined ? modelData.text : modelData.content
+                                color: "#ffffff"
+                                font.pixelSize: 14
+                                wrapMode: Text.WordWrap
+                                visible: text !== ""
+                            }
+                        }
+                        
+                        // Read receipt on the newest message the peer has read
+                        Text {
+                            anchors.right: parent.right
+                            anchors.bottom: parent.bottom
+                            anchors.rightMargin: 12
+                            anchors.bottomMargin: 6
+                            text: "Seen"
+                            color: "#e9d5ff"
+                            font.pixelSize: 10
+                            visible: modelData.seen === true
                         }
                     }
                 }
@@ -626,6 +792,31 @@ Rectangle {
                         anchors.margins: 12
                         spacing: 12
                         
+                        Button {
+                            Layout.preferredHeight: parent.height
+                            Layout.preferredWidth: 46
+                            enabled: !dmController || !dmController.is_loading
+                            
+                            ToolTip.visible: hovered
+                            ToolTip.text: "Send image"
+                            ToolTip.delay: 500
+                            
+                            background: Rectangle {
+                                color: parent.pressed ? "#333333" : (parent.hovered ? "#252525" : "transparent")
+                                radius: 8
+                            }
+                            
+                            contentItem: Text {
+                                text: "📷"
+                                font.pixelSize: 20
+                                color: "#ffffff"
+                                horizontalAlignment: Text.AlignHCenter
+                                verticalAlignment: Text.AlignVCenter
+                            }
+                            
+                            onClicked: attachmentDialog.open()
+                        }
+                        
                         TextField {
                             id: dmInput
                             Layout.fillWidth: true
@@ -645,6 +836,7 @@ Rectangle {
                             rightPadding: 16
                             
                             Keys.onReturnPressed: sendButton.clicked()
+                            onTextEdited: if (text.length > 0 && dmController) dmController.notify_typing()
                         }
                         
                         Button {
@@ -0,0 +1,311 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import "../components"
+
+Rectangle {
+    id: root
+    color: "#0a0a0a"
+
+    property var discoveryController: null
+    property var feedController: null
+    property var hashtags: []
+    property var followedHashtags: []
+
+    signal openThread(string noteId)
+    signal openProfile(string pubkey)
+
+    function reload() {
+        if (!discoveryController) return
+        try {
+            hashtags = JSON.parse(discoveryController.get_hashtags())
+        } catch (e) {
+            hashtags = []
+        }
+        noteList.model = 0
+        noteList.model = discoveryController.note_count
+    }
+
+    function loadFollowedHashtags() {
+        if (!feedController) return
+        try {
+            followedHashtags = JSON.parse(feedController.get_followed_hashtags())
+        } catch (e) {
+            followedHashtags = []
+        }
+    }
+
+    function formatSats(sats) {
+        if (sats >= 1000000) return (sats / 1000000).toFixed(1) + "M"
+        if (sats >= 1000) return (sats / 1000).toFixed(1) + "k"
+        return sats.toString()
+    }
+
+    Connections {
+        target: discoveryController
+        ignoreUnknownSignals: true
+
+        function onDiscovery_updated() {
+            root.reload()
+        }
+    }
+
+    Connections {
+        target: feedController
+        ignoreUnknownSignals: true
+
+        function onFollowed_hashtags_updated(hashtagsJson) {
+            try {
+                root.followedHashtags = JSON.parse(hashtagsJson)
+            } catch (e) {
+                console.log("Failed to parse followed hashtags:", e)
+            }
+        }
+    }
+
+    onVisibleChanged: {
+        if (visible && discoveryController) {
+            reload()
+            loadFollowedHashtags()
+            discoveryController.refresh_if_stale()
+        }
+    }
+
+    ColumnLayout {
+        anchors.fill: parent
+        spacing: 0
+
+        // Header
+        Rectangle {
+            Layout.fillWidth: true
+            Layout.preferredHeight: 60
+            color: "#111111"
+
+            RowLayout {
+                anchors.fill: parent
+                anchors.leftMargin: 20
+                anchors.rightMargin: 20
+                spacing: 12
+
+                Text {
+                    text: "Explore"
+                    color: "#ffffff"
+                    font.pixelSize: 20
+                    font.weight: Font.Bold
+                }
+
+                // Time window
+                Row {
+                    spacing: 4
+
+                    Repeater {
+                        model: [1, 6, 24]
+
+                        delegate: Rectangle {
+                            property bool selected: discoveryController && discoveryController.window_hours === modelData
+                            width: windowText.implicitWidth + 24
+                            height: 32
+                            radius: 16
+                            color: selected ? "#9333ea" : (windowArea.containsMouse ? "#1a1a1a" : "transparent")
+                            border.color: selected ? "#9333ea" : "#333333"
+                            border.width: 1
+
+                            Text {
+                                id: windowText
+                                anchors.centerIn: parent
+                                text: modelData + "h"
+                                color: parent.selected ? "#ffffff" : "#aaaaaa"
+                                font.pixelSize: 13
+                                font.weight: Font.Medium
+                            }
+
+                            MouseArea {
+                                id: windowArea
+                                anchors.fill: parent
+                                cursorShape: Qt.PointingHandCursor
+                                hoverEnabled: true
+                                onClicked: {
+                                    if (discoveryController && !discoveryController.is_loading) {
+                                        discoveryController.load(modelData)
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                Item { Layout.fillWidth: true }
+
+                BusyIndicator {
+                    Layout.preferredWidth: 24
+                    Layout.preferredHeight: 24
+                    running: discoveryController && discoveryController.is_loading
+                    visible: running
+                }
+
+                Button {
+                    text: "Refresh"
+                    enabled: discoveryController && !discoveryController.is_loading
+                    onClicked: discoveryController.load(discoveryController.window_hours)
+                }
+            }
+        }
+
+        // Trending hashtags
+        ColumnLayout {
+            Layout.fillWidth: true
+            Layout.margins: 20
+            spacing: 10
+            visible: root.hashtags.length > 0
+
+            Text {
+                text: "Trending hashtags"
+                color: "#ffffff"
+                font.pixelSize: 16
+                font.weight: Font.Bold
+            }
+
+            Flow {
+                Layout.fillWidth: true
+                spacing: 8
+
+                Repeater {
+                    model: root.hashtags
+
+                    delegate: Rectangle {
+                        property bool followed: root.followedHashtags.indexOf(modelData.tag) !== -1
+                        width: tagRow.implicitWidth + 20
+                        height: 30
+                        radius: 15
+                        color: followed ? "#2e1065" : "#1a1a1a"
+                        border.color: followed ? "#9333ea" : "#333333"
+                        border.width: 1
+
+                        Row {
+                            id: tagRow
+                            anchors.centerIn: parent
+                            spacing: 6
+
+                            Text {
+                                text: "#" + modelData.tag
+                                color: "#ffffff"
+                                font.pixelSize: 13
+                            }
+
+                            Text {
+                                text: modelData.authors
+                                color: "#888888"
+                                font.pixelSize: 11
+                                anchors.verticalCenter: parent.verticalCenter
+                            }
+
+                            Text {
+                                text: parent.parent.followed ? "✓" : "+"
+                                color: "#c084fc"
+                                font.pixelSize: 13
+                            }
+                        }
+
+                        MouseArea {
+                            id: tagArea
+                            anchors.fill: parent
+                            cursorShape: Qt.PointingHandCursor
+                            hoverEnabled: true
+                            onClicked: {
+                                if (root.feedController) {
+                                    root.feedController.follow_hashtag(modelData.tag, !parent.followed)
+                                }
+                            }
+                        }
+
+                        ToolTip.visible: tagArea.containsMouse
+                        ToolTip.text: modelData.authors + " people, " + modelData.notes + " notes"
+                            + (followed ? " · click to unfollow" : " · click to follow in Topics")
+                        ToolTip.delay: 500
+                    }
+                }
+            }
+        }
+
+        // Trending notes
+        ListView {
+            id: noteList
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            clip: true
+            spacing: 8
+            leftMargin: 20
+            rightMargin: 20
+            bottomMargin: 20
+            model: discoveryController ? discoveryController.note_count : 0
+
+            delegate: ColumnLayout {
+                width: noteList.width - 40
+                spacing: 4
+
+                property var note: discoveryController ? discoveryController.get_note(index) : ({})
+
+                Text {
+                    Layout.leftMargin: 4
+                    text: {
+                        var parts = []
+                        if (note.reactors) parts.push("❤️ " + note.reactors + (note.reactors === 1 ? " person" : " people"))
+                        if (note.zappers) parts.push("⚡ " + root.formatSats(note.zapSats || 0) + " sats from " + note.zappers)
+                        return parts.join("  ·  ")
+                    }
+                    color: "#888888"
+                    font.pixelSize: 12
+                }
+
+                NoteCard {
+                    Layout.fillWidth: true
+                    feedController: root.feedController
+                    noteId: note.id || ""
+                    authorPubkey: note.pubkey || ""
+                    authorName: note.authorName || "Unknown"
+                    authorPicture: note.authorPicture || ""
+                    authorNip05: note.authorNip05 || ""
+                    content: note.content || ""
+                    createdAt: note.createdAt || 0
+                    images: note.images || []
+                    videos: note.videos || []
+                    isReply: note.isReply || false
+                    replyTo: note.replyTo || ""
+                    replyToAuthorName: note.replyToAuthorName || ""
+                    extras: note.extras || {}
+                    emojis: note.emojis || {}
+                    blurhashes: note.blurhashes || {}
+                    autoLoadMedia: note.autoLoadMedia !== false
+
+                    onNoteClicked: function(id) {
+                        root.openThread(id)
+                    }
+
+                    onAuthorClicked: function(pubkey) {
+                        root.openProfile(pubkey)
+                    }
+
+                    onLikeClicked: {
+                        if (root.feedController) root.feedController.like_note(noteId)
+                    }
+
+                    onRepostClicked: {
+                        if (root.feedController) root.feedController.repost_note(noteId)
+                    }
+                }
+            }
+        }
+
+        // Empty state
+        Text {
+            Layout.alignment: Qt.AlignHCenter
+            Layout.bottomMargin: 40
+            visible: discoveryController && !discoveryController.is_loading && discoveryController.note_count === 0
+            text: discoveryController && discoveryController.error_message.toString() !== ""
+                ? discoveryController.error_message
+                : "Nothing trending in this window yet"
+            color: "#666666"
+            font.pixelSize: 14
+        }
+    }
+}
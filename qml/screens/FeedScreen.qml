@@ -91,13 +91,48 @@ Rectangle {
         if (appController && appController.show_global_feed) {
             types.push("Global")
         }
+        types.push("Media")
         return types
     }
     
+    // The user's NIP-51 follow sets, each shown as a tab after the built-in feeds
+    property var followSets: []
+    
+    // Followed hashtags behind the Topics tab
+    property var followedHashtags: []
+    
+    property var feedTabs: {
+        var tabs = feedTypes.map(function(t) { return { label: t, feed: t.toLowerCase() } })
+        tabs.push({ label: "Topics", feed: "hashtags" })
+        for (var i = 0; i < followSets.length; i++) {
+            tabs.push({ label: followSets[i].title, feed: followSets[i].feed })
+        }
+        return tabs
+    }
+    
+    // Set behind the current feed, if it's a list feed
+    property var currentFollowSet: {
+        if (!feedController) return null
+        var feed = feedController.current_feed.toString()
+        for (var i = 0; i < followSets.length; i++) {
+            if (followSets[i].feed === feed) return followSets[i]
+        }
+        return null
+    }
+    
     // Filter states - all checked by default
     property bool showPictures: true
     property bool showReplies: true
     property bool showReposts: true
+    property bool onlyMedia: false
+    property string languageFilter: ""      // Empty shows all languages
+    property var availableLanguages: []
+    property bool loadingPreferences: false
+    
+    // The Media tab shows a tile grid unless switched to the full note list
+    property bool mediaGridMode: true
+    property bool showMediaGrid: mediaGridMode && feedController !== null
+        && feedController.current_feed.toString() === "media"
     
     // Filtered notes list
     property var filteredIndices: []
@@ -108,18 +143,8 @@ Rectangle {
     // Signal to notify NoteCards of stats updates
     signal statsUpdated(string noteId, var stats)
     
-    // Timer to periodically refresh stats for visible notes
-    Timer {
-        id: statsRefreshTimer
-        interval: 30000  // Refresh every 30 seconds
-        repeat: true
-        running: root.visible && feedController !== null
-        onTriggered: {
-            root.refreshVisibleStats()
-        }
-    }
-    
-    // Function to collect visible note IDs and request refresh
+    // Collect visible note IDs and request a stats refresh
+    // Called periodically by the app's refresh scheduler while the feed is shown
     function refreshVisibleStats() {
         if (!feedController || !feedList.count) return
         
@@ -133,14 +158,9 @@ Rectangle {
         for (var i = firstVisible; i <= lastVisible && i < root.filteredIndices.length; i++) {
             var actualIndex = root.filteredIndices[i]
             if (actualIndex !== undefined && actualIndex >= 0) {
-                var noteJson = feedController.get_note(actualIndex)
-                if (noteJson) {
-                    try {
-                        var note = JSON.parse(noteJson)
-                        if (note.id) {
-                            noteIds.push(note.id)
-                        }
-                    } catch (e) {}
+                var note = feedController.get_note(actualIndex)
+                if (note && note.id) {
+                    noteIds.push(note.id)
                 }
             }
         }
@@ -150,21 +170,27 @@ Rectangle {
         }
     }
     
-    // Timer to fetch stats shortly after feed loads (debounced)
-    Timer {
-        id: initialStatsTimer
-        interval: 500  // Wait 500ms after feed loads
-        repeat: false
-        onTriggered: root.refreshVisibleStats()
+    // Prefetch stats for everything on screen (plus a screen below) in one request
+    // Cached notes are skipped, so this is cheap to call after every scroll
+    function prefetchVisibleStats() {
+        if (!feedController || !feedList.count) return
+        
+        var firstDisplay = feedList.indexAt(0, feedList.contentY + feedList.topMargin)
+        if (firstDisplay < 0) firstDisplay = Math.max(0, Math.floor(feedList.contentY / 150))
+        var lastDisplay = feedList.indexAt(0, feedList.contentY + feedList.height * 2)
+        if (lastDisplay < 0) lastDisplay = firstDisplay + Math.ceil(feedList.height * 2 / 150)
+        lastDisplay = Math.min(lastDisplay, root.filteredIndices.length - 1)
+        if (lastDisplay < firstDisplay) return
+        
+        feedController.fetch_stats_for_range(root.filteredIndices[firstDisplay], root.filteredIndices[lastDisplay])
     }
     
-    // Timer for periodic stats refresh (real-time updates)
+    // Timer to fetch stats shortly after feed loads or scrolling stops (debounced)
     Timer {
-        id: periodicStatsTimer
-        interval: 30000  // Refresh stats every 30 seconds
-        repeat: true
-        running: root.visible && feedController !== null
-        onTriggered: root.refreshVisibleStats()
+        id: statsPrefetchTimer
+        interval: 500  // Wait 500ms after the feed loads or scrolling stops
+        repeat: false
+        onTriggered: root.prefetchVisibleStats()
     }
     
     // Update filtered indices when filters or notes change
@@ -173,9 +199,8 @@ Rectangle {
         var count = feedController ? feedController.note_count : 0
         
         for (var i = 0; i < count; i++) {
-            var noteJson = feedController.get_note(i)
-            if (noteJson) {
-                var note = JSON.parse(noteJson)
+            var note = feedController.get_note(i)
+            if (note && note.id) {
                 var hasImages = note.images && note.images.length > 0
                 var isReply = note.isReply || false
                 var isRepost = note.isRepost || false
@@ -198,6 +223,20 @@ Rectangle {
                     passesFilter = false
                 }
                 
+                if (onlyMedia && !hasImages && !(note.videos && note.videos.length > 0)) {
+                    passesFilter = false
+                }
+                
+                // Notes with an undetected language always pass
+                if (languageFilter && note.language && note.language !== languageFilter) {
+                    passesFilter = false
+                }
+                
+                // Hide notes the user reported (or from users they reported)
+                if (note.reported) {
+                    passesFilter = false
+                }
+                
                 if (passesFilter) {
                     indices.push(i)
                 }
@@ -209,18 +248,78 @@ Rectangle {
     
     // Re-filter when filter states change
     onShowPicturesChanged: updateFilteredNotes()
-    onShowRepliesChanged: updateFilteredNotes()
-    onShowRepostsChanged: updateFilteredNotes()
+    onShowRepliesChanged: saveFeedPreferences(showReplies)
+    onShowRepostsChanged: saveFeedPreferences(showReposts)
+    onOnlyMediaChanged: saveFeedPreferences(!onlyMedia)
+    onLanguageFilterChanged: saveFeedPreferences(languageFilter === "")
+    
+    // Load the current feed's saved reply/repost/media/language preferences
+    function loadFeedPreferences() {
+        if (!feedController) return
+        try {
+            var prefs = JSON.parse(feedController.get_feed_preferences(feedController.current_feed))
+            loadingPreferences = true
+            showReplies = prefs.showReplies
+            showReposts = prefs.showReposts
+            onlyMedia = prefs.onlyMedia
+            languageFilter = prefs.languages.length > 0 ? prefs.languages[0] : ""
+            availableLanguages = prefs.availableLanguages
+        } catch (e) {
+            console.log("Failed to parse feed preferences:", e)
+        }
+        loadingPreferences = false
+        updateFilteredNotes()
+    }
+    
+    // Persist preferences for the current feed
+    // Tightening a filter only hides loaded notes; loosening one needs a
+    // reload since the filtered-out notes were dropped when the feed loaded
+    function saveFeedPreferences(loosened) {
+        updateFilteredNotes()
+        if (loadingPreferences || !feedController) return
+        feedController.set_feed_preferences(feedController.current_feed, JSON.stringify({
+            showReplies: showReplies,
+            showReposts: showReposts,
+            onlyMedia: onlyMedia,
+            languages: languageFilter ? [languageFilter] : []
+        }))
+        if (loosened) feedController.refresh()
+    }
+    
+    Component.onCompleted: {
+        loadFeedPreferences()
+        if (feedController) {
+            try {
+                followSets = JSON.parse(feedController.get_follow_sets())
+            } catch (e) {
+                console.log("Failed to parse follow sets:", e)
+            }
+            try {
+                followedHashtags = JSON.parse(feedController.get_followed_hashtags())
+            } catch (e) {
+                console.log("Failed to parse followed hashtags:", e)
+            }
+        }
+    }
     
     // Helper function for empty message
     function getFeedEmptyMessage() {
         if (!feedController) return "No notes to show"
         var feed = feedController.current_feed.toString()
+        if (feed.indexOf("list:") === 0) {
+            return root.currentFollowSet && root.currentFollowSet.pubkeys.length === 0
+                ? "This list is empty - add people with Edit List"
+                : "No posts from people in this list"
+        }
         switch(feed) {
             case "home":
             case "following": 
             case "replies": return "No posts from people you follow"
             case "global": return "No notes found"
+            case "media": return "No pictures or videos yet"
+            case "hashtags": return root.followedHashtags.length === 0
+                ? "Follow hashtags with Manage Topics in the menu"
+                : "No recent notes for the hashtags you follow"
             default: return "No notes to show"
         }
     }
@@ -232,12 +331,45 @@ Rectangle {
         function onFeed_updated() {
             root.updateFilteredNotes()
             // Fetch stats for visible notes after feed loads
-            initialStatsTimer.restart()
+            statsPrefetchTimer.restart()
+        }
+        function onCurrent_feedChanged() {
+            root.loadFeedPreferences()
+        }
+        function onFollow_sets_updated(setsJson) {
+            try {
+                root.followSets = JSON.parse(setsJson)
+            } catch (e) {
+                console.log("Failed to parse follow sets:", e)
+            }
+            // The list being viewed was deleted
+            var feed = feedController.current_feed.toString()
+            if (feed.indexOf("list:") === 0 && !root.currentFollowSet) {
+                feedController.load_feed("following")
+            }
+        }
+        function onFollowed_hashtags_updated(hashtagsJson) {
+            try {
+                root.followedHashtags = JSON.parse(hashtagsJson)
+            } catch (e) {
+                console.log("Failed to parse followed hashtags:", e)
+            }
+            if (feedController.current_feed.toString() === "hashtags") {
+                feedController.load_feed("hashtags")
+            }
+        }
+        function onFollow_set_saved(identifier, success, error) {
+            if (success && identifier === followSetDialog.createdIdentifier) {
+                followSetDialog.createdIdentifier = ""
+                feedController.load_feed("list:" + identifier)
+            } else if (!success) {
+                console.log("Failed to save list:", error)
+            }
         }
         function onMore_loaded(count) {
             root.updateFilteredNotes()
             // Fetch stats for newly loaded notes
-            initialStatsTimer.restart()
+            statsPrefetchTimer.restart()
         }
         function onNew_notes_found(count) {
             root.updateFilteredNotes()
@@ -245,7 +377,7 @@ Rectangle {
                 // Scroll to top to show new notes
                 feedList.positionViewAtBeginning()
                 // Fetch stats for new notes
-                initialStatsTimer.restart()
+                statsPrefetchTimer.restart()
             }
         }
         function onError_occurred(error) {
@@ -290,28 +422,28 @@ Rectangle {
                     font.weight: Font.Bold
                 }
                 
-                // Feed type tabs (Following / Replies / Global - based on settings)
+                // Feed type tabs (Following / Replies / Global - based on settings, then lists)
                 Row {
                     spacing: 4
                     
                     Repeater {
-                        model: root.feedTypes
+                        model: root.feedTabs
                         
                         delegate: Rectangle {
                             width: tabText.implicitWidth + 24
                             height: 32
                             radius: 16
-                            color: feedController && feedController.current_feed.toString() === modelData.toLowerCase() 
+                            color: feedController && feedController.current_feed.toString() === modelData.feed 
                                 ? "#9333ea" : "transparent"
-                            border.color: feedController && feedController.current_feed.toString() === modelData.toLowerCase()
+                            border.color: feedController && feedController.current_feed.toString() === modelData.feed
                                 ? "#9333ea" : "#333333"
                             border.width: 1
                             
                             Text {
                                 id: tabText
                                 anchors.centerIn: parent
-                                text: modelData
-                                color: feedController && feedController.current_feed.toString() === modelData.toLowerCase()
+                                text: modelData.label
+                                color: feedController && feedController.current_feed.toString() === modelData.feed
                                     ? "#ffffff" : "#aaaaaa"
                                 font.pixelSize: 13
                                 font.weight: Font.Medium
@@ -324,23 +456,52 @@ Rectangle {
                                 
                                 onClicked: {
                                     if (feedController) {
-                                        feedController.load_feed(modelData.toLowerCase())
+                                        feedController.load_feed(modelData.feed)
                                     }
                                 }
                                 
                                 onEntered: {
-                                    if (!(feedController && feedController.current_feed.toString() === modelData.toLowerCase())) {
+                                    if (!(feedController && feedController.current_feed.toString() === modelData.feed)) {
                                         parent.color = "#1a1a1a"
                                     }
                                 }
                                 onExited: {
-                                    if (!(feedController && feedController.current_feed.toString() === modelData.toLowerCase())) {
+                                    if (!(feedController && feedController.current_feed.toString() === modelData.feed)) {
                                         parent.color = "transparent"
                                     }
                                 }
                             }
                         }
                     }
+                    
+                    // New list
+                    Rectangle {
+                        width: 32
+                        height: 32
+                        radius: 16
+                        color: newListArea.containsMouse ? "#1a1a1a" : "transparent"
+                        border.color: "#333333"
+                        border.width: 1
+                        
+                        Text {
+                            anchors.centerIn: parent
+                            text: "+"
+                            color: "#aaaaaa"
+                            font.pixelSize: 16
+                        }
+                        
+                        MouseArea {
+                            id: newListArea
+                            anchors.fill: parent
+                            cursorShape: Qt.PointingHandCursor
+                            hoverEnabled: true
+                            onClicked: followSetDialog.openFor(null)
+                        }
+                        
+                        ToolTip.visible: newListArea.containsMouse
+                        ToolTip.text: "New list"
+                        ToolTip.delay: 500
+                    }
                 }
                 
                 // Separator
@@ -490,10 +651,115 @@ Rectangle {
                             }
                         }
                     }
+                    
+                    // Media-only filter
+                    Row {
+                        spacing: 6
+                        height: 32
+                        
+                        Rectangle {
+                            id: mediaOnlyCheck
+                            width: 18
+                            height: 18
+                            radius: 4
+                            color: root.onlyMedia ? "#9333ea" : "transparent"
+                            border.color: root.onlyMedia ? "#9333ea" : "#555555"
+                            border.width: 1
+                            anchors.verticalCenter: parent.verticalCenter
+                            
+                            Text {
+                                anchors.centerIn: parent
+                                text: "✓"
+                                color: "#ffffff"
+                                font.pixelSize: 11
+                                font.bold: true
+                                visible: root.onlyMedia
+                            }
+                            
+                            MouseArea {
+                                anchors.fill: parent
+                                cursorShape: Qt.PointingHandCursor
+                                onClicked: root.onlyMedia = !root.onlyMedia
+                            }
+                        }
+                        
+                        Text {
+                            text: "Only media"
+                            font.pixelSize: 13
+                            color: root.onlyMedia ? "#ffffff" : "#888888"
+                            anchors.verticalCenter: parent.verticalCenter
+                            
+                            MouseArea {
+                                anchors.fill: parent
+                                cursorShape: Qt.PointingHandCursor
+                                onClicked: root.onlyMedia = !root.onlyMedia
+                            }
+                        }
+                    }
+                    
+                    // Language filter
+                    ComboBox {
+                        id: languageCombo
+                        height: 32
+                        width: 110
+                        model: [""].concat(root.availableLanguages)
+                        currentIndex: Math.max(0, model.indexOf(root.languageFilter))
+                        displayText: root.languageFilter ? root.languageFilter.toUpperCase() : "All languages"
+                        onActivated: function(index) { root.languageFilter = model[index] }
+                        
+                        delegate: ItemDelegate {
+                            width: languageCombo.width
+                            text: modelData ? modelData.toUpperCase() : "All languages"
+                            highlighted: languageCombo.highlightedIndex === index
+                        }
+                        
+                        background: Rectangle {
+                            color: "#1a1a1a"
+                            radius: 6
+                            border.color: "#333333"
+                            border.width: 1
+                        }
+                        
+                        contentItem: Text {
+                            leftPadding: 8
+                            text: languageCombo.displayText
+                            color: root.languageFilter ? "#ffffff" : "#888888"
+                            font.pixelSize: 13
+                            verticalAlignment: Text.AlignVCenter
+                        }
+                    }
                 }
                 
                 Item { Layout.fillWidth: true }
                 
+                // Grid / list toggle for the Media tab
+                Button {
+                    visible: feedController && feedController.current_feed.toString() === "media"
+                    implicitWidth: 42
+                    implicitHeight: 36
+                    
+                    ToolTip.visible: hovered
+                    ToolTip.text: root.mediaGridMode ? "Show as list" : "Show as grid"
+                    ToolTip.delay: 500
+                    
+                    background: Rectangle {
+                        color: parent.pressed ? "#333333" : (parent.hovered ? "#252525" : "#1a1a1a")
+                        radius: 8
+                        border.color: "#333333"
+                        border.width: 1
+                    }
+                    
+                    contentItem: Text {
+                        text: root.mediaGridMode ? "☰" : "▦"
+                        font.pixelSize: 18
+                        color: "#ffffff"
+                        horizontalAlignment: Text.AlignHCenter
+                        verticalAlignment: Text.AlignVCenter
+                    }
+                    
+                    onClicked: root.mediaGridMode = !root.mediaGridMode
+                }
+                
                 // Menu button (☰)
                 Button {
                     id: menuButton
@@ -556,6 +822,41 @@ Rectangle {
                             }
                         }
                         
+                        MenuItem {
+                            text: "✏️  Edit List"
+                            enabled: root.currentFollowSet !== null
+                            onTriggered: followSetDialog.openFor(root.currentFollowSet)
+                            
+                            background: Rectangle {
+                                color: parent.highlighted ? "#333333" : "transparent"
+                                radius: 4
+                            }
+                            
+                            contentItem: Text {
+                                text: parent.text
+                                font.pixelSize: 14
+                                color: parent.enabled ? "#ffffff" : "#666666"
+                                leftPadding: 8
+                            }
+                        }
+                        
+                        MenuItem {
+                            text: "#️⃣  Manage Topics"
+                            onTriggered: hashtagsDialog.open()
+                            
+                            background: Rectangle {
+                                color: parent.highlighted ? "#333333" : "transparent"
+                                radius: 4
+                            }
+                            
+                            contentItem: Text {
+                                text: parent.text
+                                font.pixelSize: 14
+                                color: "#ffffff"
+                                leftPadding: 8
+                            }
+                        }
+                        
                         MenuItem {
                             text: "⬆️  Check for New"
                             onTriggered: {
@@ -791,11 +1092,55 @@ Rectangle {
             }
         }
         
+        // Media tab as a tile grid
+        Item {
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            visible: root.showMediaGrid
+            
+            MediaGrid {
+                id: mediaGrid
+                anchors.fill: parent
+                anchors.margins: 18
+                tileCount: root.showMediaGrid ? root.filteredIndices.length : 0
+                getTile: function(index) {
+                    var noteIndex = root.filteredIndices[index]
+                    return noteIndex !== undefined && feedController ? feedController.get_media_note(noteIndex) : ({})
+                }
+                
+                onTileClicked: function(noteId) {
+                    root.openThread(noteId)
+                }
+                
+                onNearEnd: {
+                    if (feedController && !feedController.is_loading && !feedList.loadMorePending) {
+                        feedList.loadMorePending = true
+                        feedController.load_more()
+                    }
+                }
+            }
+            
+            BusyIndicator {
+                anchors.centerIn: parent
+                running: root.showMediaGrid && feedController && feedController.is_loading && root.filteredIndices.length === 0
+                visible: running
+            }
+            
+            Text {
+                anchors.centerIn: parent
+                visible: feedController && !feedController.is_loading && root.filteredIndices.length === 0
+                text: root.getFeedEmptyMessage()
+                color: "#666666"
+                font.pixelSize: 16
+            }
+        }
+        
         // Feed list
         ListView {
             id: feedList
             Layout.fillWidth: true
             Layout.fillHeight: true
+            visible: !root.showMediaGrid
             clip: true
             spacing: 12
             leftMargin: 20
@@ -822,6 +1167,9 @@ Rectangle {
             
             model: root.filteredIndices.length
             
+            // One batched stats request once scrolling settles
+            onMovementEnded: statsPrefetchTimer.restart()
+            
             delegate: NoteCard {
                 id: noteDelegate
                 width: feedList.width - 40
@@ -834,9 +1182,8 @@ Rectangle {
                 // Function to load note data for the current index
                 function loadNoteData() {
                     if (root.feedController && actualNoteIndex >= 0) {
-                        var noteJson = root.feedController.get_note(actualNoteIndex)
-                        if (noteJson) {
-                            var note = JSON.parse(noteJson)
+                        var note = root.feedController.get_note(actualNoteIndex)
+                        if (note && note.id) {
                             noteId = note.id || ""
                             authorPubkey = note.pubkey || ""
                             authorName = note.authorName || "Unknown"
@@ -848,6 +1195,7 @@ Rectangle {
                             reposts = note.reposts || 0
                             replies = note.replies || 0
                             zapAmount = note.zapAmount || 0
+                            zapAmountFiat = note.zapAmountFiat || ""
                             zapCount = note.zapCount || 0
                             reactions = note.reactions || {}
                             images = note.images || []
@@ -858,6 +1206,11 @@ Rectangle {
                             isRepost = note.isRepost || false
                             repostAuthorName = note.repostAuthorName || ""
                             repostAuthorPicture = note.repostAuthorPicture || ""
+                            outsideTrust = (note.outsideTrust || false) && root.feedController.current_feed.toString() === "global"
+                            extras = note.extras || {}
+                            emojis = note.emojis || {}
+                            blurhashes = note.blurhashes || {}
+                            autoLoadMedia = note.autoLoadMedia !== false
                         }
                     }
                 }
@@ -881,9 +1234,16 @@ Rectangle {
                     videos = []
                     isRepost = false
                     repostAuthorName = ""
+                    outsideTrust = false
+                    extras = {}
+                    emojis = {}
+                    blurhashes = {}
+                    reactionEmoji = {}
                     zapCount = 0
                     zapAmount = 0
+                    zapAmountFiat = ""
                     reactions = {}
+                    quotes = 0
                     // statsLoaded = false  // Disabled - stats fetching causes scroll stutter
                 }
                 
@@ -904,6 +1264,9 @@ Rectangle {
                             if (stats.zapAmount !== undefined) {
                                 noteDelegate.zapAmount = stats.zapAmount
                             }
+                            if (stats.zapAmountFiat !== undefined) {
+                                noteDelegate.zapAmountFiat = stats.zapAmountFiat
+                            }
                             if (stats.zapCount !== undefined) {
                                 noteDelegate.zapCount = stats.zapCount
                             }
@@ -913,6 +1276,9 @@ Rectangle {
                             if (stats.repostCount !== undefined) {
                                 noteDelegate.reposts = stats.repostCount
                             }
+                            if (stats.quoteCount !== undefined) {
+                                noteDelegate.quotes = stats.quoteCount
+                            }
                             // Calculate total likes from reactions (❤️ + 👍 + +)
                             var likeCount = 0
                             if (stats.reactions) {
@@ -1139,6 +1505,17 @@ Rectangle {
         feedController: root.feedController
     }
     
+    FollowSetDialog {
+        id: followSetDialog
+        feedController: root.feedController
+    }
+    
+    HashtagsDialog {
+        id: hashtagsDialog
+        feedController: root.feedController
+        hashtags: root.followedHashtags
+    }
+    
     // Keyboard Shortcuts Popup
     Popup {
         id: shortcutPopup
@@ -1348,6 +1725,234 @@ Rectangle {
         }
     }
     
+    // Posts, reactions and zaps made offline that are waiting to go out
+    Rectangle {
+        id: outboxBanner
+        
+        property int pending: root.feedController ? root.feedController.outbox_count : 0
+        property string status: ""
+        property string lastError: ""
+        
+        anchors.bottom: parent.bottom
+        anchors.horizontalCenter: parent.horizontalCenter
+        anchors.bottomMargin: 20
+        width: outboxRow.implicitWidth + 24
+        height: 36
+        radius: 18
+        color: "#1f1f1f"
+        border.color: lastError ? "#7f1d1d" : "#333333"
+        border.width: 1
+        visible: pending > 0
+        z: 100
+        
+        Connections {
+            target: root.feedController
+            ignoreUnknownSignals: true
+            function onOutbox_changed(count) {
+                if (count === 0) outboxBanner.lastError = ""
+            }
+            function onOutbox_entry_status(entryId, status, detail) {
+                outboxBanner.status = status
+                outboxBanner.lastError = (status === "waiting" || status === "failed") ? detail : ""
+            }
+        }
+        
+        RowLayout {
+            id: outboxRow
+            anchors.centerIn: parent
+            spacing: 10
+            
+            Text {
+                text: {
+                    var label = outboxBanner.pending === 1 ? "1 pending post" : outboxBanner.pending + " pending posts"
+                    if (outboxBanner.status === "uploading") return "Uploading media for " + label + "..."
+                    if (outboxBanner.status === "publishing") return "Publishing " + label + "..."
+                    if (outboxBanner.status === "failed") return label + " could not be sent"
+                    return label + " waiting for connection"
+                }
+                color: outboxBanner.status === "failed" ? "#fecaca" : "#cccccc"
+                font.pixelSize: 13
+                
+                ToolTip.visible: outboxMouse.containsMouse && outboxBanner.lastError !== ""
+                ToolTip.text: outboxBanner.lastError
+                
+                MouseArea {
+                    id: outboxMouse
+                    anchors.fill: parent
+                    hoverEnabled: true
+                }
+            }
+            
+            Text {
+                text: "Retry"
+                color: "#a855f7"
+                font.pixelSize: 13
+                font.weight: Font.Medium
+                
+                MouseArea {
+                    anchors.fill: parent
+                    anchors.margins: -6
+                    cursorShape: Qt.PointingHandCursor
+                    onClicked: root.feedController.retry_outbox()
+                }
+            }
+        }
+    }
+    
+    // Degraded connectivity: relays that dropped and are being retried
+    Rectangle {
+        id: relayBanner
+        
+        property var failing: ({})  // url -> {attempts, retryIn, error}
+        property int count: Object.keys(failing).length
+        
+        anchors.bottom: outboxBanner.visible ? outboxBanner.top : parent.bottom
+        anchors.horizontalCenter: parent.horizontalCenter
+        anchors.bottomMargin: outboxBanner.visible ? 8 : 20
+        width: relayRow.implicitWidth + 24
+        height: 32
+        radius: 16
+        color: "#1f1f1f"
+        border.color: "#78350f"
+        border.width: 1
+        visible: count > 0
+        z: 100
+        
+        Connections {
+            target: root.feedController
+            ignoreUnknownSignals: true
+            function onRelay_failed(url, attempts, retryInSecs, error) {
+                var failing = Object.assign({}, relayBanner.failing)
+                failing[url] = { attempts: attempts, retryIn: retryInSecs, error: error }
+                relayBanner.failing = failing
+            }
+            function onRelay_reconnected(url, attempts) {
+                if (!relayBanner.failing[url]) return
+                var failing = Object.assign({}, relayBanner.failing)
+                delete failing[url]
+                relayBanner.failing = failing
+            }
+        }
+        
+        RowLayout {
+            id: relayRow
+            anchors.centerIn: parent
+            spacing: 8
+            
+            Text {
+                text: relayBanner.count === 1 ? "1 relay unreachable, retrying" : relayBanner.count + " relays unreachable, retrying"
+                color: "#fde68a"
+                font.pixelSize: 12
+                
+                ToolTip.visible: relayMouse.containsMouse
+                ToolTip.text: Object.keys(relayBanner.failing).map(function(url) {
+                    var info = relayBanner.failing[url]
+                    return url + " (attempt " + info.attempts + ", next in " + info.retryIn + "s): " + info.error
+                }).join("\n")
+                
+                MouseArea {
+                    id: relayMouse
+                    anchors.fill: parent
+                    hoverEnabled: true
+                }
+            }
+        }
+    }
+    
+    // Last publish that didn't reach every relay: per-relay results on hover
+    Rectangle {
+        id: publishBanner
+        
+        property var report: null
+        
+        anchors.bottom: relayBanner.visible ? relayBanner.top : (outboxBanner.visible ? outboxBanner.top : parent.bottom)
+        anchors.horizontalCenter: parent.horizontalCenter
+        anchors.bottomMargin: relayBanner.visible || outboxBanner.visible ? 8 : 20
+        width: publishRow.implicitWidth + 24
+        height: 32
+        radius: 16
+        color: "#1f1f1f"
+        border.color: "#333333"
+        border.width: 1
+        visible: report !== null
+        z: 100
+        
+        Timer {
+            id: publishHideTimer
+            interval: 8000
+            onTriggered: publishBanner.report = null
+        }
+        
+        Connections {
+            target: root.feedController
+            ignoreUnknownSignals: true
+            function onPublish_status(eventId, resultsJson) {
+                var report = JSON.parse(resultsJson)
+                // Only speak up when something didn't go through
+                if (report.queued === 0 && report.rejected === 0) {
+                    if (publishBanner.report && publishBanner.report.eventId === eventId
+                            && !publishBanner.report.broadcast) {
+                        publishBanner.report = null
+                    }
+                    return
+                }
+                publishBanner.report = report
+                publishHideTimer.restart()
+            }
+            // Broadcasts were asked for, so always show how they went
+            function onBroadcast_finished(noteId, resultsJson) {
+                var report = JSON.parse(resultsJson)
+                if (report.error) {
+                    report = { eventId: noteId, relays: [], accepted: 0, queued: 0, rejected: 0, error: report.error }
+                }
+                report.broadcast = true
+                publishBanner.report = report
+                publishHideTimer.restart()
+            }
+        }
+        
+        RowLayout {
+            id: publishRow
+            anchors.centerIn: parent
+            spacing: 8
+            
+            Text {
+                text: {
+                    var r = publishBanner.report
+                    if (!r) return ""
+                    if (r.error) return "Broadcast failed: " + r.error
+                    var total = r.relays.length
+                    var parts = [(r.broadcast ? "Broadcast to " : "Published to ") + r.accepted + " of " + total + " relays"]
+                    if (r.queued > 0) parts.push(r.queued + " queued for retry")
+                    if (r.rejected > 0) parts.push(r.rejected + " rejected")
+                    if (r.paymentRequired > 0) parts.push(r.paymentRequired === 1 ? "1 relay wants payment - tap to pay" : r.paymentRequired + " relays want payment - tap to pay")
+                    return parts.join(" · ")
+                }
+                color: publishBanner.report && publishBanner.report.accepted === 0 ? "#fca5a5" : "#cccccc"
+                font.pixelSize: 12
+                
+                ToolTip.visible: publishMouse.containsMouse
+                ToolTip.text: publishBanner.report ? publishBanner.report.relays.map(function(relay) {
+                    return relay.url + ": " + relay.status + (relay.message ? " (" + relay.message + ")" : "")
+                }).join("\n") : ""
+                
+                MouseArea {
+                    id: publishMouse
+                    anchors.fill: parent
+                    hoverEnabled: true
+                    cursorShape: publishBanner.report && publishBanner.report.paymentRequired > 0 ? Qt.PointingHandCursor : Qt.ArrowCursor
+                    // Paid relays are paid from the relay settings
+                    onClicked: {
+                        if (publishBanner.report && publishBanner.report.paymentRequired > 0 && root.appController) {
+                            publishBanner.report = null
+                            root.appController.navigate_to("relays")
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
     // Reusable shortcut row component
     component ShortcutRow: RowLayout {
         property string key: ""
@@ -0,0 +1,296 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+
+Rectangle {
+    id: root
+    color: "#0a0a0a"
+    
+    property var liveController: null
+    property var liveNow: []
+    
+    signal openProfile(string pubkey)
+    
+    function reload() {
+        if (!liveController) return
+        try {
+            liveNow = JSON.parse(liveController.get_live_now())
+        } catch (e) {
+            liveNow = []
+        }
+        streamList.model = 0
+        streamList.model = liveController.stream_count
+    }
+    
+    function formatCount(n) {
+        if (n >= 1000) return (n / 1000).toFixed(1) + "k"
+        return n.toString()
+    }
+    
+    function statusText(stream) {
+        if (stream.isLive) return "LIVE"
+        if (stream.status === "planned") {
+            return stream.starts ? "Starts " + new Date(stream.starts * 1000).toLocaleString(Qt.locale(), Locale.ShortFormat) : "Planned"
+        }
+        return "Ended"
+    }
+    
+    // Ended streams link their recording, if any
+    function watchUrl(stream) {
+        if (stream.isLive) return stream.streamingUrl || ""
+        return stream.recordingUrl || ""
+    }
+    
+    Connections {
+        target: liveController
+        ignoreUnknownSignals: true
+        
+        function onStreams_updated(count) {
+            root.reload()
+        }
+    }
+    
+    onVisibleChanged: if (visible) reload()
+    
+    ColumnLayout {
+        anchors.fill: parent
+        spacing: 0
+        
+        // Header
+        Rectangle {
+            Layout.fillWidth: true
+            Layout.preferredHeight: 60
+            color: "#111111"
+            
+            RowLayout {
+                anchors.fill: parent
+                anchors.leftMargin: 20
+                anchors.rightMargin: 20
+                spacing: 12
+                
+                Text {
+                    text: "Live"
+                    color: "#ffffff"
+                    font.pixelSize: 20
+                    font.weight: Font.Bold
+                }
+                
+                Text {
+                    text: liveController ? liveController.live_count + " live now" : ""
+                    color: "#888888"
+                    font.pixelSize: 13
+                }
+                
+                Item { Layout.fillWidth: true }
+                
+                BusyIndicator {
+                    Layout.preferredWidth: 24
+                    Layout.preferredHeight: 24
+                    running: liveController && liveController.is_loading
+                    visible: running
+                }
+                
+                Button {
+                    text: "Refresh"
+                    enabled: liveController && !liveController.is_loading
+                    onClicked: liveController.load_streams()
+                }
+            }
+        }
+        
+        // Live now strip
+        ColumnLayout {
+            Layout.fillWidth: true
+            Layout.margins: 20
+            spacing: 12
+            visible: root.liveNow.length > 0
+            
+            Text {
+                text: "Live now"
+                color: "#ffffff"
+                font.pixelSize: 16
+                font.weight: Font.Bold
+            }
+            
+            ListView {
+                Layout.fillWidth: true
+                Layout.preferredHeight: 170
+                orientation: ListView.Horizontal
+                spacing: 12
+                clip: true
+                model: root.liveNow
+                
+                delegate: Rectangle {
+                    width: 240
+                    height: 170
+                    radius: 12
+                    color: "#1a1a1a"
+                    border.color: modelData.followed ? "#9333ea" : "#2a2a2a"
+                    border.width: 1
+                    
+                    Image {
+                        id: liveThumb
+                        anchors.top: parent.top
+                        anchors.left: parent.left
+                        anchors.right: parent.right
+                        height: 120
+                        source: modelData.image || ""
+                        fillMode: Image.PreserveAspectCrop
+                        asynchronous: true
+                        clip: true
+                        visible: status === Image.Ready
+                    }
+                    
+                    Rectangle {
+                        anchors.top: parent.top
+                        anchors.left: parent.left
+                        anchors.margins: 8
+                        width: liveBadge.implicitWidth + 12
+                        height: 20
+                        radius: 4
+                        color: "#dc2626"
+                        
+                        Text {
+                            id: liveBadge
+                            anchors.centerIn: parent
+                            text: "LIVE" + (modelData.currentParticipants ? " · " + root.formatCount(modelData.currentParticipants) : "")
+                            color: "#ffffff"
+                            font.pixelSize: 11
+                            font.weight: Font.Bold
+                        }
+                    }
+                    
+                    Text {
+                        anchors.bottom: parent.bottom
+                        anchors.left: parent.left
+                        anchors.right: parent.right
+                        anchors.margins: 10
+                        text: modelData.title || "Untitled stream"
+                        color: "#ffffff"
+                        font.pixelSize: 13
+                        elide: Text.ElideRight
+                    }
+                    
+                    MouseArea {
+                        anchors.fill: parent
+                        cursorShape: Qt.PointingHandCursor
+                        onClicked: {
+                            var url = root.watchUrl(modelData)
+                            if (url) Qt.openUrlExternally(url)
+                        }
+                    }
+                }
+            }
+        }
+        
+        // All streams
+        ListView {
+            id: streamList
+            Layout.fillWidth: true
+            Layout.fillHeight: true
+            clip: true
+            spacing: 8
+            leftMargin: 20
+            rightMargin: 20
+            bottomMargin: 20
+            model: liveController ? liveController.stream_count : 0
+            
+            delegate: Rectangle {
+                property var stream: liveController ? JSON.parse(liveController.get_stream(index)) : ({})
+                
+                width: streamList.width - 40
+                height: 84
+                radius: 10
+                color: "#111111"
+                
+                RowLayout {
+                    anchors.fill: parent
+                    anchors.margins: 12
+                    spacing: 12
+                    
+                    Rectangle {
+                        Layout.preferredWidth: 96
+                        Layout.preferredHeight: 60
+                        radius: 6
+                        color: "#1a1a1a"
+                        clip: true
+                        
+                        Image {
+                            anchors.fill: parent
+                            source: stream.image || ""
+                            fillMode: Image.PreserveAspectCrop
+                            asynchronous: true
+                        }
+                    }
+                    
+                    ColumnLayout {
+                        Layout.fillWidth: true
+                        spacing: 4
+                        
+                        Text {
+                            Layout.fillWidth: true
+                            text: stream.title || "Untitled stream"
+                            color: "#ffffff"
+                            font.pixelSize: 14
+                            font.weight: Font.Medium
+                            elide: Text.ElideRight
+                        }
+                        
+                        Text {
+                            Layout.fillWidth: true
+                            text: stream.summary || ""
+                            color: "#888888"
+                            font.pixelSize: 12
+                            elide: Text.ElideRight
+                            visible: text !== ""
+                        }
+                        
+                        RowLayout {
+                            spacing: 8
+                            
+                            Text {
+                                text: root.statusText(stream)
+                                color: stream.isLive ? "#dc2626" : "#888888"
+                                font.pixelSize: 11
+                                font.weight: Font.Bold
+                            }
+                            
+                            Text {
+                                text: stream.currentParticipants ? root.formatCount(stream.currentParticipants) + " watching" : ""
+                                color: "#888888"
+                                font.pixelSize: 11
+                                visible: stream.isLive && text !== ""
+                            }
+                            
+                            Text {
+                                text: "Following"
+                                color: "#c084fc"
+                                font.pixelSize: 11
+                                visible: stream.followed === true
+                            }
+                        }
+                    }
+                    
+                    Button {
+                        text: stream.isLive ? "Watch" : "Recording"
+                        visible: root.watchUrl(stream) !== ""
+                        onClicked: Qt.openUrlExternally(root.watchUrl(stream))
+                    }
+                    
+                    Button {
+                        text: "Host"
+                        onClicked: root.openProfile(stream.host)
+                    }
+                }
+            }
+            
+            Text {
+                anchors.centerIn: parent
+                text: liveController && liveController.is_loading ? "Looking for streams..." : "No streams in the last day"
+                color: "#666666"
+                font.pixelSize: 14
+                visible: streamList.count === 0
+            }
+        }
+    }
+}
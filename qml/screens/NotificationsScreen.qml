@@ -44,12 +44,12 @@ Rectangle {
         
         function onNotifications_updated() {
             console.log("[DEBUG] Notifications updated, count:", notificationController ? notificationController.notification_count : 0)
-            notificationList.model = notificationController ? notificationController.notification_count : 0
+            notificationList.model = notificationController ? notificationController.group_count : 0
         }
         
         function onMore_loaded(count) {
             console.log("[DEBUG] Loaded more notifications:", count)
-            notificationList.model = notificationController ? notificationController.notification_count : 0
+            notificationList.model = notificationController ? notificationController.group_count : 0
         }
         
         function onError_occurred(error) {
@@ -63,22 +63,7 @@ Rectangle {
         }
     }
     
-    // Timer for polling new notifications when visible
-    Timer {
-        id: notificationPollTimer
-        interval: 30000  // Poll every 30 seconds
-        repeat: true
-        running: root.visible && notificationController !== null
-        
-        onTriggered: {
-            if (notificationController && !notificationController.is_loading) {
-                console.log("[DEBUG] Polling for new notifications...")
-                notificationController.check_for_new()
-            }
-        }
-    }
-    
-    // Also check for new when becoming visible
+    // Periodic polling comes from the app's refresh scheduler; check right away when shown
     onVisibleChanged: {
         if (visible) {
             forceActiveFocus()
@@ -111,6 +96,7 @@ Rectangle {
             case "repost": return "#4caf50"
             case "reply": return "#2196f3"
             case "mention": return "#9c27b0"
+            case "follow": return "#0ea5e9"
             default: return "#9333ea"
         }
     }
@@ -246,7 +232,7 @@ Rectangle {
                 minimumSize: 0.1
             }
             
-            model: notificationController ? notificationController.notification_count : 0
+            model: notificationController ? notificationController.group_count : 0
             
             // Load more when near bottom
             onContentYChanged: {
@@ -260,20 +246,16 @@ Rectangle {
             delegate: Rectangle {
                 id: notificationItem
                 width: notificationList.width - 40
-                height: 80
+                height: 80 + (expanded ? memberColumn.height + 8 : 0)
                 color: notificationData.isRead ? "#111111" : "#1a1a1a"
                 radius: 8
                 
+                // Grouped entries can be expanded to list each interaction
+                property bool expanded: false
+                ListView.onReused: expanded = false
+                
                 // Parse notification data
-                property var notificationData: {
-                    if (!notificationController) return {}
-                    try {
-                        return JSON.parse(notificationController.get_notification(index))
-                    } catch (e) {
-                        console.log("Failed to parse notification:", e)
-                        return {}
-                    }
-                }
+                property var notificationData: notificationController ? notificationController.get_group(index) : ({})
                 
                 // Hover effect
                 Rectangle {
@@ -285,7 +267,10 @@ Rectangle {
                 
                 MouseArea {
                     id: mouseArea
-                    anchors.fill: parent
+                    anchors.left: parent.left
+                    anchors.right: parent.right
+                    anchors.top: parent.top
+                    height: 80
                     hoverEnabled: true
                     cursorShape: Qt.PointingHandCursor
                     
@@ -297,10 +282,16 @@ Rectangle {
                             notificationController.mark_as_read(notificationData.id)
                         }
                         
+                        // New followers open the follower's profile
+                        if (notificationData.type === "follow") {
+                            root.openProfile(notificationData.authorPubkey)
+                            return
+                        }
+                        
                         // Determine which event to open
                         var noteIdToOpen = null
                         
-                        if (notificationData.type === "reaction" || notificationData.type === "zap" || notificationData.type === "repost") {
+                        if (notificationData.type === "reaction" || notificationData.type === "zap" || notificationData.type === "repost" || notificationData.type === "group") {
                             // For reactions/zaps/reposts, open the note that was reacted to
                             noteIdToOpen = notificationData.referencedEventId
                         } else if (notificationData.type === "reply" || notificationData.type === "mention") {
@@ -319,8 +310,11 @@ Rectangle {
                 }
                 
                 RowLayout {
-                    anchors.fill: parent
+                    anchors.left: parent.left
+                    anchors.right: parent.right
+                    anchors.top: parent.top
                     anchors.margins: 12
+                    height: 56
                     spacing: 12
                     
                     // Type icon
@@ -345,9 +339,9 @@ Rectangle {
                         color: "#333333"
                         clip: true
                         
-                        Image {
+                        MediaImage {
                             anchors.fill: parent
-                            source: notificationData.authorPicture || ""
+                            url: notificationData.authorPicture || ""
                             fillMode: Image.PreserveAspectCrop
                             visible: status === Image.Ready
                             
@@ -422,6 +416,76 @@ Rectangle {
                         color: "#666666"
                         font.pixelSize: 12
                     }
+                    
+                    // Expand toggle for grouped entries
+                    Text {
+                        visible: notificationData.grouped === true
+                        text: (notificationItem.expanded ? "▴ " : "▾ ") + (notificationData.count || 0)
+                        color: expandMouseArea.containsMouse ? "#9333ea" : "#aaaaaa"
+                        font.pixelSize: 12
+                        
+                        MouseArea {
+                            id: expandMouseArea
+                            anchors.fill: parent
+                            anchors.margins: -6
+                            cursorShape: Qt.PointingHandCursor
+                            hoverEnabled: true
+                            onClicked: notificationItem.expanded = !notificationItem.expanded
+                        }
+                    }
+                }
+                
+                // Individual interactions in a group
+                Column {
+                    id: memberColumn
+                    visible: notificationItem.expanded
+                    anchors.left: parent.left
+                    anchors.right: parent.right
+                    anchors.top: parent.top
+                    anchors.topMargin: 80
+                    anchors.leftMargin: 68
+                    anchors.rightMargin: 12
+                    spacing: 6
+                    
+                    Repeater {
+                        model: notificationItem.expanded ? (notificationData.members || []) : []
+                        
+                        delegate: RowLayout {
+                            width: memberColumn.width
+                            spacing: 8
+                            
+                            Text {
+                                text: modelData.typeIcon || ""
+                                font.pixelSize: 12
+                            }
+                            
+                            Text {
+                                Layout.fillWidth: true
+                                text: (modelData.authorName || "Someone") + " " + (modelData.contentPreview || "")
+                                color: memberMouseArea.containsMouse ? "#9333ea" : "#cccccc"
+                                font.pixelSize: 12
+                                elide: Text.ElideRight
+                                
+                                MouseArea {
+                                    id: memberMouseArea
+                                    anchors.fill: parent
+                                    cursorShape: Qt.PointingHandCursor
+                                    hoverEnabled: true
+                                    onClicked: {
+                                        if (modelData.authorPubkey) {
+                                            root.openProfile(modelData.authorPubkey)
+                                        }
+                                    }
+                                }
+                            }
+                            
+                            Text {
+                                text: formatTime(modelData.createdAt || 0)
+                                color: "#666666"
+                                font.pixelSize: 11
+                            }
+                        }
+                    }
                 }
                 
                 // Unread indicator
@@ -0,0 +1,243 @@
+import QtQuick
+import QtQuick.Controls
+import QtQuick.Layouts
+import QtQuick.Dialogs
+
+// First-run setup for new accounts: profile, avatar and starter follows
+Rectangle {
+    id: root
+    color: "#0a0a0a"
+    
+    property var onboardingController: null
+    property var starterPacks: []
+    property var selectedPacks: []
+    
+    signal finished()
+    
+    function loadPacks() {
+        if (!onboardingController) return
+        try {
+            starterPacks = JSON.parse(onboardingController.get_starter_packs())
+        } catch (e) {
+            starterPacks = []
+        }
+        // Everything picked by default so the first feed isn't empty
+        selectedPacks = starterPacks.map(function(pack) { return pack.id })
+    }
+    
+    function togglePack(id) {
+        var picks = selectedPacks.filter(function(p) { return p !== id })
+        if (picks.length === selectedPacks.length) picks.push(id)
+        selectedPacks = picks
+    }
+    
+    onVisibleChanged: if (visible) loadPacks()
+    
+    Connections {
+        target: onboardingController
+        ignoreUnknownSignals: true
+        
+        function onOnboarding_finished(follow_count) {
+            root.finished()
+        }
+    }
+    
+    FileDialog {
+        id: avatarDialog
+        title: "Choose an avatar"
+        nameFilters: ["Images (*.jpg *.jpeg *.png *.gif *.webp)"]
+        onAccepted: onboardingController.upload_avatar(selectedFile.toString())
+    }
+    
+    ScrollView {
+        anchors.fill: parent
+        contentWidth: availableWidth
+        
+        ColumnLayout {
+            width: Math.min(560, parent.width - 40)
+            x: Math.round((parent.width - width) / 2)
+            spacing: 24
+            
+            Item { Layout.preferredHeight: 24 }
+            
+            Text {
+                text: "Welcome to Pleb Client"
+                color: "#ffffff"
+                font.pixelSize: 26
+                font.weight: Font.Bold
+            }
+            
+            Text {
+                Layout.fillWidth: true
+                text: "Set up your profile and pick some people to follow. You can change all of this later."
+                color: "#888888"
+                font.pixelSize: 14
+                wrapMode: Text.WordWrap
+            }
+            
+            // Avatar and name
+            RowLayout {
+                Layout.fillWidth: true
+                spacing: 16
+                
+                ProfileAvatar {
+                    Layout.preferredWidth: 72
+                    Layout.preferredHeight: 72
+                    name: nameField.text || "?"
+                    imageUrl: onboardingController ? onboardingController.avatar_url : ""
+                    
+                    MouseArea {
+                        anchors.fill: parent
+                        cursorShape: Qt.PointingHandCursor
+                        enabled: onboardingController && !onboardingController.is_busy
+                        onClicked: avatarDialog.open()
+                    }
+                }
+                
+                ColumnLayout {
+                    Layout.fillWidth: true
+                    spacing: 8
+                    
+                    TextField {
+                        id: nameField
+                        Layout.fillWidth: true
+                        placeholderText: "Username"
+                        placeholderTextColor: "#666666"
+                        color: "#ffffff"
+                        font.pixelSize: 15
+                        maximumLength: 64
+                        
+                        background: Rectangle {
+                            color: "#1a1a1a"
+                            radius: 8
+                            border.color: nameField.activeFocus ? "#9333ea" : "#333333"
+                            border.width: 1
+                        }
+                    }
+                    
+                    Button {
+                        text: onboardingController && onboardingController.avatar_url !== "" ? "Change avatar" : "Upload avatar"
+                        enabled: onboardingController && !onboardingController.is_busy
+                        onClicked: avatarDialog.open()
+                    }
+                }
+            }
+            
+            TextField {
+                id: aboutField
+                Layout.fillWidth: true
+                placeholderText: "About you (optional)"
+                placeholderTextColor: "#666666"
+                color: "#ffffff"
+                font.pixelSize: 14
+                
+                background: Rectangle {
+                    color: "#1a1a1a"
+                    radius: 8
+                    border.color: aboutField.activeFocus ? "#9333ea" : "#333333"
+                    border.width: 1
+                }
+            }
+            
+            // Starter packs
+            Text {
+                text: "Follow some people"
+                color: "#ffffff"
+                font.pixelSize: 18
+                font.weight: Font.Bold
+            }
+            
+            Repeater {
+                model: root.starterPacks
+                
+                delegate: Rectangle {
+                    id: packCard
+                    property bool picked: root.selectedPacks.indexOf(modelData.id) >= 0
+                    
+                    Layout.fillWidth: true
+                    implicitHeight: packRow.implicitHeight + 24
+                    radius: 10
+                    color: "#111111"
+                    border.color: picked ? "#9333ea" : "#2a2a2a"
+                    border.width: 1
+                    
+                    RowLayout {
+                        id: packRow
+                        anchors.fill: parent
+                        anchors.margins: 12
+                        spacing: 12
+                        
+                        CheckBox {
+                            checked: packCard.picked
+                            onToggled: root.togglePack(modelData.id)
+                        }
+                        
+                        ColumnLayout {
+                            Layout.fillWidth: true
+                            spacing: 2
+                            
+                            Text {
+                                text: modelData.title
+                                color: "#ffffff"
+                                font.pixelSize: 15
+                                font.weight: Font.Medium
+                            }
+                            
+                            Text {
+                                Layout.fillWidth: true
+                                text: modelData.description + " · " + modelData.pubkeys.length + " accounts"
+                                color: "#888888"
+                                font.pixelSize: 12
+                                wrapMode: Text.WordWrap
+                            }
+                        }
+                    }
+                    
+                    MouseArea {
+                        anchors.fill: parent
+                        z: -1
+                        cursorShape: Qt.PointingHandCursor
+                        onClicked: root.togglePack(modelData.id)
+                    }
+                }
+            }
+            
+            Text {
+                Layout.fillWidth: true
+                text: onboardingController ? (onboardingController.error_message || onboardingController.status_text) : ""
+                color: onboardingController && onboardingController.error_message !== "" ? "#ef4444" : "#888888"
+                font.pixelSize: 13
+                wrapMode: Text.WordWrap
+                visible: text !== ""
+            }
+            
+            RowLayout {
+                Layout.fillWidth: true
+                spacing: 12
+                
+                Button {
+                    text: "Skip"
+                    enabled: onboardingController && !onboardingController.is_busy
+                    onClicked: root.finished()
+                }
+                
+                Item { Layout.fillWidth: true }
+                
+                BusyIndicator {
+                    Layout.preferredWidth: 28
+                    Layout.preferredHeight: 28
+                    running: onboardingController && onboardingController.is_busy
+                    visible: running
+                }
+                
+                Button {
+                    text: "Get started"
+                    enabled: onboardingController && !onboardingController.is_busy && nameField.text.trim() !== ""
+                    onClicked: onboardingController.finish(nameField.text, aboutField.text, JSON.stringify(root.selectedPacks))
+                }
+            }
+            
+            Item { Layout.preferredHeight: 24 }
+        }
+    }
+}
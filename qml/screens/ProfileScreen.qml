@@ -13,8 +13,10 @@ Rectangle {
     property string displayName: ""
     property var appController: null
     property bool isOtherProfile: false  // True when viewing someone else's profile
+    property int followQueueCount: 0
     
     signal back()
+    signal openThread(string noteId)
     
     // Profile controller
     ProfileController {
@@ -22,6 +24,9 @@ Rectangle {
         
         onProfile_loaded: {
             console.log("[DEBUG] Profile loaded:", profileController.display_name)
+            if (profileController.is_own_profile) {
+                root.followQueueCount = JSON.parse(profileController.get_follow_queue()).length
+            }
         }
         
         onProfile_updated: {
@@ -32,25 +37,62 @@ Rectangle {
             console.log("[DEBUG] Follow status changed to:", profileController.is_following)
         }
         
+        onFollow_queue_changed: function(count) {
+            root.followQueueCount = count
+        }
+        
+        onUser_media_loaded: function(count, nextUntil) {
+            root.mediaNextUntil = nextUntil
+            root.mediaExhausted = nextUntil === 0
+        }
+        
         onError_occurred: function(error) {
             console.error("[DEBUG] Profile error:", error)
         }
     }
     
+    // The previous account's profile, lists and timelines must not show after logout
+    Connections {
+        target: appController
+        function onSession_reset() {
+            profileController.reset()
+            root.lastLoadedKey = ""
+            root.mediaExhausted = false
+            root.mediaNextUntil = 0
+        }
+    }
+    
     // Track if we need to reload when becoming visible
     property bool needsReload: false
     property string lastLoadedKey: ""
     
+    // The relays had nothing older for the last media page
+    property bool mediaExhausted: false
+    // Where the next media page starts (below the relays' last page)
+    property var mediaNextUntil: 0
+    
+    function loadProfile() {
+        // Set logged in user first for follow status checking
+        if (appController && appController.public_key) {
+            profileController.set_logged_in_user(appController.public_key)
+        }
+        profileController.load_profile(publicKey)
+        mediaExhausted = false
+        mediaNextUntil = 0
+        profileController.load_user_media(publicKey, 0)
+        lastLoadedKey = publicKey
+    }
+    
+    function loadMoreMedia() {
+        if (profileController.is_loading_media || mediaExhausted || mediaNextUntil === 0) return
+        profileController.load_user_media(publicKey, mediaNextUntil)
+    }
+    
     // Load profile when pubkey changes, but only if visible
     onPublicKeyChanged: {
         if (publicKey.length > 0) {
             if (visible) {
-                // Set logged in user first for follow status checking
-                if (appController && appController.public_key) {
-                    profileController.set_logged_in_user(appController.public_key)
-                }
-                profileController.load_profile(publicKey)
-                lastLoadedKey = publicKey
+                loadProfile()
             } else {
                 // Defer loading until we become visible
                 needsReload = true
@@ -61,11 +103,7 @@ Rectangle {
     // Load profile when we become visible if needed
     onVisibleChanged: {
         if (visible && needsReload && publicKey.length > 0 && publicKey !== lastLoadedKey) {
-            if (appController && appController.public_key) {
-                profileController.set_logged_in_user(appController.public_key)
-            }
-            profileController.load_profile(publicKey)
-            lastLoadedKey = publicKey
+            loadProfile()
             needsReload = false
         }
     }
@@ -193,10 +231,10 @@ Rectangle {
                         Layout.fillWidth: true
                         Layout.preferredHeight: 200
                         
-                        Image {
+                        MediaImage {
                             id: bannerImage
                             anchors.fill: parent
-                            source: profileController.banner || ""
+                            url: profileController.banner || ""
                             fillMode: Image.PreserveAspectCrop
                             visible: (profileController.banner || "").length > 0 && status === Image.Ready
                             asynchronous: true
@@ -249,11 +287,11 @@ Rectangle {
                                 border.color: "#0a0a0a"
                                 border.width: 4
                                 
-                                Image {
+                                MediaImage {
                                     id: profileAvatarImage
                                     anchors.fill: parent
                                     anchors.margins: 4
-                                    source: profileController.picture || ""
+                                    url: profileController.picture || ""
                                     fillMode: Image.PreserveAspectCrop
                                     visible: (profileController.picture || "").length > 0 && status === Image.Ready
                                     asynchronous: true
@@ -335,6 +373,70 @@ Rectangle {
                                     }
                                 }
                                 
+                                // Save for later review instead of following right away
+                                Button {
+                                    id: saveForLaterButton
+                                    property bool saved: false
+                                    text: saved ? "Saved" : "Save for later"
+                                    visible: !profileController.is_own_profile && !profileController.is_following
+                                    enabled: !saved
+                                    font.pixelSize: 14
+                                    
+                                    ToolTip.visible: hovered
+                                    ToolTip.text: "Add to your follow review queue"
+                                    ToolTip.delay: 500
+                                    
+                                    Connections {
+                                        target: profileController
+                                        function onProfile_loaded() { saveForLaterButton.saved = false }
+                                    }
+                                    
+                                    background: Rectangle {
+                                        color: parent.pressed ? "#333333" : "#1a1a1a"
+                                        radius: 20
+                                        border.color: "#333333"
+                                        border.width: 1
+                                    }
+                                    
+                                    contentItem: Text {
+                                        text: parent.text
+                                        color: parent.enabled ? "#ffffff" : "#888888"
+                                        font: parent.font
+                                        horizontalAlignment: Text.AlignHCenter
+                                        verticalAlignment: Text.AlignVCenter
+                                    }
+                                    
+                                    onClicked: saved = profileController.queue_follow(root.publicKey, "profile")
+                                }
+                                
+                                // Follow review queue (only for own profile)
+                                Button {
+                                    text: "Review follows (" + root.followQueueCount + ")"
+                                    visible: profileController.is_own_profile && root.followQueueCount > 0
+                                    font.pixelSize: 14
+                                    
+                                    ToolTip.visible: hovered
+                                    ToolTip.text: "Approve or dismiss people you saved for later"
+                                    ToolTip.delay: 500
+                                    
+                                    background: Rectangle {
+                                        color: parent.pressed ? "#333333" : "#1a1a1a"
+                                        radius: 20
+                                        border.color: "#9333ea"
+                                        border.width: 1
+                                    }
+                                    
+                                    contentItem: Text {
+                                        text: parent.text
+                                        color: "#ffffff"
+                                        font: parent.font
+                                        horizontalAlignment: Text.AlignHCenter
+                                        verticalAlignment: Text.AlignVCenter
+                                    }
+                                    
+                                    onClicked: followQueueDialog.open()
+                                }
+                                
                                 // Edit button (only for own profile)
                                 Button {
                                     text: "Edit Profile"
@@ -488,17 +590,72 @@ Rectangle {
                         color: "#2a2a2a"
                     }
                     
-                    // User's recent notes would go here
-                    Rectangle {
+                    // Media grid
+                    Text {
+                        Layout.leftMargin: 24
+                        Layout.topMargin: 20
+                        text: "Media"
+                        color: "#ffffff"
+                        font.pixelSize: 16
+                        font.weight: Font.Bold
+                    }
+                    
+                    MediaGrid {
                         Layout.fillWidth: true
-                        Layout.preferredHeight: 200
-                        color: "transparent"
+                        Layout.leftMargin: 22
+                        Layout.rightMargin: 22
+                        Layout.topMargin: 12
+                        Layout.preferredHeight: contentHeight
+                        interactive: false
+                        columns: Math.max(3, Math.floor(width / 200))
+                        tileCount: profileController.user_media_count
+                        getTile: function(index) {
+                            return profileController.get_media_note(index)
+                        }
                         
-                        Text {
-                            anchors.centerIn: parent
-                            text: "User's notes coming soon..."
-                            color: "#666666"
+                        onTileClicked: function(noteId) {
+                            root.openThread(noteId)
+                        }
+                    }
+                    
+                    BusyIndicator {
+                        Layout.alignment: Qt.AlignHCenter
+                        Layout.topMargin: 12
+                        running: profileController.is_loading_media
+                        visible: running
+                    }
+                    
+                    Text {
+                        Layout.alignment: Qt.AlignHCenter
+                        Layout.topMargin: 24
+                        visible: !profileController.is_loading_media && profileController.user_media_count === 0
+                            && root.mediaExhausted
+                        text: "No pictures or videos yet"
+                        color: "#666666"
+                        font.pixelSize: 14
+                    }
+                    
+                    Button {
+                        Layout.alignment: Qt.AlignHCenter
+                        Layout.topMargin: 12
+                        text: "Load more"
+                        visible: !profileController.is_loading_media && !root.mediaExhausted
+                            && root.mediaNextUntil !== 0
+                        onClicked: root.loadMoreMedia()
+                        
+                        background: Rectangle {
+                            color: parent.pressed ? "#333333" : "#1a1a1a"
+                            radius: 8
+                            border.color: "#333333"
+                            border.width: 1
+                        }
+                        
+                        contentItem: Text {
+                            text: parent.text
+                            color: "#ffffff"
                             font.pixelSize: 14
+                            horizontalAlignment: Text.AlignHCenter
+                            verticalAlignment: Text.AlignVCenter
                         }
                     }
                     
@@ -740,4 +897,18 @@ Rectangle {
             font.pixelSize: 14
         }
     }
+    
+    // Suggested follows waiting for approval
+    FollowQueueDialog {
+        id: followQueueDialog
+        profileController: profileController
+    }
+    
+    // Failed follow list publishes with a Retry button
+    OperationToast {
+        controller: profileController
+        anchors.bottom: parent.bottom
+        anchors.horizontalCenter: parent.horizontalCenter
+        anchors.bottomMargin: 24
+    }
 }
@@ -70,8 +70,7 @@ Rectangle {
         var notes = []
         var count = feedController.note_count
         for (var i = 0; i < count; i++) {
-            var json = feedController.get_note(i)
-            var note = JSON.parse(json)
+            var note = feedController.get_note(i)
             note.originalIndex = i
             notes.push(note)
         }
@@ -8,6 +8,7 @@ Rectangle {
     
     property var appController: null
     property var relayList: []
+    property var relayRoles: ({})  // url -> {read, write, dm}
     
     // Load relays from config
     function loadRelays() {
@@ -15,13 +16,24 @@ Rectangle {
             try {
                 var json = appController.get_relays()
                 relayList = JSON.parse(json)
+                relayRoles = JSON.parse(appController.get_relay_roles())
             } catch (e) {
                 console.log("Failed to load relays:", e)
                 relayList = []
+                relayRoles = {}
             }
         }
     }
     
+    // Flip one role of a relay; a relay must keep at least one role
+    function toggleRole(url, role) {
+        var roles = Object.assign({ read: true, write: true, dm: false }, relayRoles[url] || {})
+        roles[role] = !roles[role]
+        if (appController && appController.set_relay_roles(url, roles.read, roles.write, roles.dm)) {
+            loadRelays()
+        }
+    }
+    
     // Load relays when component is ready or appController changes
     Component.onCompleted: loadRelays()
     onAppControllerChanged: loadRelays()
@@ -121,7 +133,7 @@ Rectangle {
                 // Your Relays section
                 RelaySection {
                     title: "Your Relays"
-                    description: "Where your posts are published"
+                    description: "Read relays are fetched from, write relays receive your posts, DM relays receive your messages"
                     
                     ColumnLayout {
                         Layout.fillWidth: true
@@ -133,6 +145,7 @@ Rectangle {
                             
                             delegate: RelayItem {
                                 relayUrl: modelData
+                                roles: root.relayRoles[modelData] || ({ read: true, write: true, dm: false })
                                 onRemove: {
                                     if (root.appController) {
                                         root.appController.remove_relay(modelData)
@@ -427,9 +440,87 @@ Rectangle {
     
     // Relay item component
     component RelayItem: Rectangle {
+        id: relayItem
         property string relayUrl: ""
+        // NIP-11 payment requirement, null for free relays
+        property var payment: null
+        property string paymentStatus: ""
+        property bool paying: false
+        property var roles: ({ read: true, write: true, dm: false })
+        // NIP-11 document and what the relay can't do for us
+        property var info: null
+        property var warnings: []
+        property string infoError: ""
         signal remove()
         
+        readonly property bool paidUp: payment !== null && payment.paidUntil !== null
+            && payment.paidUntil !== undefined && payment.paidUntil * 1000 > Date.now()
+        
+        Component.onCompleted: {
+            if (!root.appController) return
+            root.appController.get_relay_info(relayUrl)
+            root.appController.get_relay_payment_info(relayUrl)
+        }
+        
+        function limitsSummary() {
+            var l = info && info.limitation ? info.limitation : null
+            if (!l) return ""
+            var parts = []
+            if (l.max_limit) parts.push(l.max_limit + " events per query")
+            if (l.max_subscriptions) parts.push(l.max_subscriptions + " subscriptions")
+            if (l.max_message_length) parts.push(Math.round(l.max_message_length / 1024) + " KB messages")
+            if (l.max_content_length) parts.push(l.max_content_length + " character notes")
+            return parts.join(" · ")
+        }
+        
+        function paymentSummary() {
+            if (!payment) return ""
+            var parts = ["Paid relay"]
+            if (payment.admissionSats) parts.push(payment.admissionSats + " sats admission")
+            else if (payment.subscriptionSats) {
+                var days = payment.subscriptionPeriodSecs ? Math.round(payment.subscriptionPeriodSecs / 86400) : 0
+                parts.push(payment.subscriptionSats + " sats" + (days > 0 ? " / " + days + " days" : ""))
+            }
+            if (paidUp) parts.push("paid until " + new Date(payment.paidUntil * 1000).toLocaleDateString())
+            return parts.join(" · ")
+        }
+        
+        Connections {
+            target: root.appController
+            ignoreUnknownSignals: true
+            
+            function onRelay_info_ready(url, infoJson) {
+                if (url !== relayItem.relayUrl) return
+                var result = JSON.parse(infoJson)
+                relayItem.info = result.info || null
+                relayItem.warnings = result.warnings || []
+                relayItem.infoError = result.error || ""
+            }
+            
+            function onRelay_payment_info_ready(url, infoJson) {
+                if (url !== relayItem.relayUrl) return
+                var info = JSON.parse(infoJson)
+                relayItem.payment = info.url ? info : null
+            }
+            
+            function onRelay_payment_finished(url, resultJson) {
+                if (url !== relayItem.relayUrl) return
+                relayItem.paying = false
+                var result = JSON.parse(resultJson)
+                if (result.error) {
+                    relayItem.paymentStatus = result.error
+                } else if (!result.paid && result.paymentsUrl) {
+                    relayItem.paymentStatus = "Opened the relay's payment page"
+                    Qt.openUrlExternally(result.paymentsUrl)
+                } else if (result.paid) {
+                    relayItem.paymentStatus = result.canWrite
+                        ? "Paid - write access confirmed" + (result.resent > 0 ? ", resent " + result.resent + " held notes" : "")
+                        : "Paid - relay still rejects writes: " + (result.writeError || "unknown error")
+                    root.appController.get_relay_payment_info(relayItem.relayUrl)
+                }
+            }
+        }
+        
         Layout.fillWidth: true
         height: 52
         color: relayMouseArea.containsMouse ? "#1f1f1f" : "#1a1a1a"
@@ -481,9 +572,131 @@ Rectangle {
                 }
                 
                 Text {
-                    text: relayUrl.startsWith("wss://") ? "Secure WebSocket" : "WebSocket"
-                    color: "#666666"
+                    Layout.fillWidth: true
+                    text: relayItem.paymentStatus || relayItem.paymentSummary()
+                        || (relayItem.info && relayItem.info.name ? relayItem.info.name : "")
+                        || (relayUrl.startsWith("wss://") ? "Secure WebSocket" : "WebSocket")
+                    color: relayItem.payment && !relayItem.paidUp ? "#f59e0b" : "#666666"
                     font.pixelSize: 11
+                    elide: Text.ElideRight
+                }
+            }
+            
+            // Capability warnings from NIP-11
+            Text {
+                visible: relayItem.warnings.length > 0
+                text: "⚠"
+                color: "#f59e0b"
+                font.pixelSize: 16
+                
+                MouseArea {
+                    id: warningArea
+                    anchors.fill: parent
+                    hoverEnabled: true
+                    cursorShape: Qt.PointingHandCursor
+                    onClicked: infoPopup.open()
+                }
+                
+                ToolTip.visible: warningArea.containsMouse
+                ToolTip.text: relayItem.warnings.join("\n")
+                ToolTip.delay: 300
+            }
+            
+            // Relay details (NIP-11)
+            Button {
+                implicitWidth: 32
+                implicitHeight: 32
+                text: "ⓘ"
+                
+                ToolTip.visible: hovered
+                ToolTip.text: "Relay details"
+                ToolTip.delay: 500
+                
+                background: Rectangle {
+                    color: parent.hovered ? "#2a2a2a" : "transparent"
+                    radius: 8
+                }
+                
+                contentItem: Text {
+                    text: parent.text
+                    color: parent.hovered ? "#ffffff" : "#888888"
+                    font.pixelSize: 14
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+                
+                onClicked: infoPopup.open()
+            }
+            
+            // Roles: fetch from (read), publish to (write), receive DMs (NIP-17)
+            Repeater {
+                model: [
+                    { role: "read", label: "Read", tip: "Fetch notes from this relay" },
+                    { role: "write", label: "Write", tip: "Publish your notes to this relay" },
+                    { role: "dm", label: "DM", tip: "Receive direct messages here (NIP-17 inbox)" }
+                ]
+                
+                delegate: Rectangle {
+                    property bool active: relayItem.roles[modelData.role] === true
+                    
+                    implicitWidth: roleLabel.implicitWidth + 16
+                    implicitHeight: 24
+                    radius: 12
+                    color: active ? "#9333ea30" : "transparent"
+                    border.color: active ? "#9333ea" : "#333333"
+                    border.width: 1
+                    
+                    Text {
+                        id: roleLabel
+                        anchors.centerIn: parent
+                        text: modelData.label
+                        color: parent.active ? "#c084fc" : "#666666"
+                        font.pixelSize: 11
+                        font.weight: Font.Medium
+                    }
+                    
+                    MouseArea {
+                        id: roleMouseArea
+                        anchors.fill: parent
+                        hoverEnabled: true
+                        cursorShape: Qt.PointingHandCursor
+                        onClicked: root.toggleRole(relayItem.relayUrl, modelData.role)
+                    }
+                    
+                    ToolTip.visible: roleMouseArea.containsMouse
+                    ToolTip.text: modelData.tip
+                    ToolTip.delay: 500
+                }
+            }
+            
+            Button {
+                visible: relayItem.payment !== null && !relayItem.paidUp
+                enabled: !relayItem.paying
+                implicitHeight: 32
+                text: relayItem.paying ? "Paying..." : "Pay"
+                
+                ToolTip.visible: hovered
+                ToolTip.text: "Pay this relay with your connected wallet"
+                ToolTip.delay: 500
+                
+                background: Rectangle {
+                    color: parent.down ? "#7c22c9" : "#9333ea"
+                    radius: 8
+                    opacity: parent.enabled ? 1.0 : 0.5
+                }
+                
+                contentItem: Text {
+                    text: parent.text
+                    color: "#ffffff"
+                    font.pixelSize: 12
+                    horizontalAlignment: Text.AlignHCenter
+                    verticalAlignment: Text.AlignVCenter
+                }
+                
+                onClicked: {
+                    relayItem.paying = true
+                    relayItem.paymentStatus = ""
+                    root.appController.pay_relay(relayItem.relayUrl)
                 }
             }
             
@@ -512,5 +725,147 @@ Rectangle {
                 onClicked: remove()
             }
         }
+        
+        Popup {
+            id: infoPopup
+            parent: Overlay.overlay
+            anchors.centerIn: parent
+            width: Math.min(480, root.width - 40)
+            modal: true
+            padding: 20
+            
+            background: Rectangle {
+                color: "#1a1a1a"
+                radius: 12
+                border.color: "#333333"
+            }
+            
+            contentItem: ColumnLayout {
+                spacing: 10
+                
+                Text {
+                    Layout.fillWidth: true
+                    text: relayItem.info && relayItem.info.name ? relayItem.info.name : relayItem.relayUrl
+                    color: "#ffffff"
+                    font.pixelSize: 16
+                    font.weight: Font.Bold
+                    elide: Text.ElideRight
+                }
+                
+                Text {
+                    Layout.fillWidth: true
+                    text: relayItem.relayUrl
+                    color: "#666666"
+                    font.pixelSize: 12
+                    elide: Text.ElideMiddle
+                }
+                
+                Text {
+                    Layout.fillWidth: true
+                    visible: relayItem.infoError !== ""
+                    text: "No relay information available: " + relayItem.infoError
+                    color: "#888888"
+                    font.pixelSize: 13
+                    wrapMode: Text.WordWrap
+                }
+                
+                Text {
+                    Layout.fillWidth: true
+                    visible: text !== ""
+                    text: relayItem.info && relayItem.info.description ? relayItem.info.description : ""
+                    color: "#cccccc"
+                    font.pixelSize: 13
+                    wrapMode: Text.WordWrap
+                    maximumLineCount: 6
+                    elide: Text.ElideRight
+                }
+                
+                Repeater {
+                    model: relayItem.warnings
+                    
+                    delegate: Text {
+                        Layout.fillWidth: true
+                        text: "⚠ " + modelData
+                        color: "#f59e0b"
+                        font.pixelSize: 12
+                        wrapMode: Text.WordWrap
+                    }
+                }
+                
+                GridLayout {
+                    Layout.fillWidth: true
+                    visible: relayItem.info !== null
+                    columns: 2
+                    columnSpacing: 12
+                    rowSpacing: 6
+                    
+                    Text { text: "Software"; color: "#888888"; font.pixelSize: 12 }
+                    Text {
+                        Layout.fillWidth: true
+                        text: relayItem.info && relayItem.info.software
+                            ? relayItem.info.software.replace(/^https?:\/\//, "") + (relayItem.info.version ? " " + relayItem.info.version : "")
+                            : "Unknown"
+                        color: "#ffffff"
+                        font.pixelSize: 12
+                        elide: Text.ElideRight
+                    }
+                    
+                    Text { text: "NIPs"; color: "#888888"; font.pixelSize: 12 }
+                    Text {
+                        Layout.fillWidth: true
+                        text: relayItem.info && relayItem.info.supportedNips && relayItem.info.supportedNips.length > 0
+                            ? relayItem.info.supportedNips.join(", ") : "Not listed"
+                        color: "#ffffff"
+                        font.pixelSize: 12
+                        wrapMode: Text.WordWrap
+                    }
+                    
+                    Text { text: "Limits"; color: "#888888"; font.pixelSize: 12 }
+                    Text {
+                        Layout.fillWidth: true
+                        text: relayItem.limitsSummary() || "None advertised"
+                        color: "#ffffff"
+                        font.pixelSize: 12
+                        wrapMode: Text.WordWrap
+                    }
+                    
+                    Text { text: "Access"; color: "#888888"; font.pixelSize: 12 }
+                    Text {
+                        Layout.fillWidth: true
+                        text: {
+                            if (!relayItem.info) return ""
+                            var parts = []
+                            parts.push(relayItem.info.paymentRequired ? "Paid" : "Free")
+                            if (relayItem.info.authRequired) parts.push("sign-in required")
+                            return parts.join(" · ")
+                        }
+                        color: "#ffffff"
+                        font.pixelSize: 12
+                    }
+                    
+                    Text {
+                        text: "Contact"
+                        color: "#888888"
+                        font.pixelSize: 12
+                        visible: contactText.text !== ""
+                    }
+                    Text {
+                        id: contactText
+                        Layout.fillWidth: true
+                        text: relayItem.info && relayItem.info.contact ? relayItem.info.contact : ""
+                        visible: text !== ""
+                        color: "#ffffff"
+                        font.pixelSize: 12
+                        elide: Text.ElideRight
+                    }
+                }
+                
+                Button {
+                    Layout.alignment: Qt.AlignRight
+                    text: "Close"
+                    onClicked: infoPopup.close()
+                }
+            }
+        }
     }
 }
@@ -255,6 +255,38 @@ Rectangle {
                             }
                         }
                         
+                        Button {
+                            text: "Network"
+                            checkable: true
+                            checked: searchController && searchController.rank_by_proximity
+                            visible: root.localSearchType === "users"
+                            implicitHeight: 44
+                            
+                            ToolTip.visible: hovered
+                            ToolTip.text: "Rank people followed by your follows first"
+                            ToolTip.delay: 500
+                            
+                            background: Rectangle {
+                                color: parent.checked ? "#9333ea" : (parent.hovered ? "#252525" : "#1a1a1a")
+                                radius: 8
+                            }
+                            
+                            contentItem: Text {
+                                text: parent.text
+                                color: "#ffffff"
+                                font.pixelSize: 13
+                                horizontalAlignment: Text.AlignHCenter
+                                verticalAlignment: Text.AlignVCenter
+                            }
+                            
+                            onClicked: {
+                                searchController.rank_by_proximity = !searchController.rank_by_proximity
+                                if (searchInput.text.trim().length > 0) {
+                                    searchController.search_users(searchInput.text.trim())
+                                }
+                            }
+                        }
+                        
                         Button {
                             text: "Notes"
                             checkable: true
@@ -524,6 +556,16 @@ Rectangle {
                                 Layout.fillWidth: true
                             }
                             
+                            Text {
+                                visible: userDelegate.userData && userDelegate.userData.followedByCount > 0
+                                text: userDelegate.userData
+                                    ? "Followed by " + userDelegate.userData.followedByCount + " you follow"
+                                    : ""
+                                color: "#888888"
+                                font.pixelSize: 12
+                                Layout.fillWidth: true
+                            }
+                            
                             Text {
                                 visible: userDelegate.userData && userDelegate.userData.about
                                 text: ((userDelegate.userData && userDelegate.userData.about) || "").replace(/\n/g, " ")
@@ -535,6 +577,41 @@ Rectangle {
                             }
                         }
                         
+                        // Save to the follow review queue
+                        Button {
+                            id: saveButton
+                            property bool saved: false
+                            text: saved ? "Saved" : "Save"
+                            implicitHeight: 32
+                            visible: userDelegate.userData && !userDelegate.userData.isFollowing
+                            enabled: !saved
+                            
+                            ToolTip.visible: hovered
+                            ToolTip.text: "Save for later - review and follow in one batch from your profile"
+                            ToolTip.delay: 500
+                            
+                            background: Rectangle {
+                                color: parent.down ? "#333333" : "#1a1a1a"
+                                radius: 8
+                                border.color: "#333333"
+                            }
+                            
+                            contentItem: Text {
+                                text: parent.text
+                                color: parent.enabled ? "#ffffff" : "#888888"
+                                font.pixelSize: 12
+                                font.weight: Font.Medium
+                                horizontalAlignment: Text.AlignHCenter
+                                verticalAlignment: Text.AlignVCenter
+                            }
+                            
+                            onClicked: {
+                                if (searchController && userDelegate.userData) {
+                                    saved = searchController.queue_follow(userDelegate.userData.pubkey, "search")
+                                }
+                            }
+                        }
+                        
                         // View button
                         Button {
                             text: "View"
@@ -579,7 +656,16 @@ Rectangle {
                 }
                 
                 model: root.localNoteCount
-                
+
+                // Page through older local matches near the bottom
+                onContentYChanged: {
+                    var distanceFromBottom = contentHeight - (contentY + height)
+                    if (contentHeight > height && distanceFromBottom < 500 &&
+                        searchController && searchController.has_more_notes && !searchController.is_searching) {
+                        searchController.load_more_notes()
+                    }
+                }
+
                 delegate: NoteCard {
                     id: noteDelegate
                     width: noteList.width
@@ -593,34 +679,35 @@ Rectangle {
                     
                     function loadData() {
                         if (searchController) {
-                            try {
-                                var json = searchController.get_note(displayIndex)
-                                if (json) {
-                                    var note = JSON.parse("" + json)
-                                    noteId = note.id || ""
-                                    authorPubkey = note.pubkey || ""
-                                    authorName = note.authorName || "Unknown"
-                                    authorPicture = note.authorPicture || ""
-                                    authorNip05 = note.authorNip05 || ""
-                                    content = note.content || ""
-                                    createdAt = note.createdAt || 0
-                                    likes = note.likes || 0
-                                    reposts = note.reposts || 0
-                                    replies = note.replies || 0
-                                    zapAmount = note.zapAmount || 0
-                                    zapCount = note.zapCount || 0
-                                    reactions = note.reactions || {}
-                                    images = note.images || []
-                                    videos = note.videos || []
-                                    isReply = note.isReply || false
-                                    replyTo = note.replyTo || ""
-                                    replyToAuthorName = note.replyToAuthorName || ""
-                                    isRepost = note.isRepost || false
-                                    repostAuthorName = note.repostAuthorName || ""
-                                    repostAuthorPicture = note.repostAuthorPicture || ""
-                                }
-                            } catch (e) {
-                                console.log("[SearchScreen] Error parsing note data:", e)
+                            var note = searchController.get_note(displayIndex)
+                            if (note && note.id) {
+                                noteId = note.id || ""
+                                authorPubkey = note.pubkey || ""
+                                authorName = note.authorName || "Unknown"
+                                authorPicture = note.authorPicture || ""
+                                authorNip05 = note.authorNip05 || ""
+                                content = note.content || ""
+                                createdAt = note.createdAt || 0
+                                likes = note.likes || 0
+                                reposts = note.reposts || 0
+                                replies = note.replies || 0
+                                zapAmount = note.zapAmount || 0
+                                zapAmountFiat = note.zapAmountFiat || ""
+                                zapCount = note.zapCount || 0
+                                reactions = note.reactions || {}
+                                images = note.images || []
+                                videos = note.videos || []
+                                isReply = note.isReply || false
+                                replyTo = note.replyTo || ""
+                                replyToAuthorName = note.replyToAuthorName || ""
+                                isRepost = note.isRepost || false
+                                repostAuthorName = note.repostAuthorName || ""
+                                repostAuthorPicture = note.repostAuthorPicture || ""
+                                outsideTrust = note.outsideTrust || false
+                                extras = note.extras || {}
+                                emojis = note.emojis || {}
+                                blurhashes = note.blurhashes || {}
+                                autoLoadMedia = note.autoLoadMedia !== false
                             }
                         }
                     }
@@ -1,6 +1,7 @@
 import QtQuick
 import QtQuick.Controls
 import QtQuick.Layouts
+import QtQuick.Dialogs
 
 Rectangle {
     id: root
@@ -8,6 +9,7 @@ Rectangle {
     
     property var appController: null
     property var feedController: null
+    property var signerController: null
     property string storedPassword: "" // Temporarily store password for NWC save
     property bool closeToTray: true  // Two-way binding with main window
     
@@ -55,6 +57,155 @@ Rectangle {
                     ColumnLayout {
                         Layout.fillWidth: true
                         spacing: 12
+
+                        // Key backup: decrypt the saved nsec with the login password
+                        ColumnLayout {
+                            id: keyBackupColumn
+                            Layout.fillWidth: true
+                            spacing: 8
+
+                            property string revealedNsec: ""
+                            property string error: ""
+
+                            Connections {
+                                target: root.appController
+                                function onNsec_revealed(nsec, error) {
+                                    keyBackupColumn.revealedNsec = nsec
+                                    keyBackupColumn.error = error
+                                }
+                            }
+
+                            Text {
+                                Layout.fillWidth: true
+                                text: root.appController && root.appController.backup_reminder_due
+                                      ? "Your private key has not been backed up yet. Without it you cannot recover this account."
+                                      : "Back up your private key (nsec) somewhere safe and never share it."
+                                color: root.appController && root.appController.backup_reminder_due ? "#f59e0b" : "#888888"
+                                font.pixelSize: 13
+                                wrapMode: Text.WordWrap
+                            }
+
+                            RowLayout {
+                                Layout.fillWidth: true
+                                spacing: 8
+                                visible: keyBackupColumn.revealedNsec === ""
+
+                                TextField {
+                                    id: backupPasswordInput
+                                    Layout.fillWidth: true
+                                    placeholderText: "Login password"
+                                    echoMode: TextInput.Password
+                                    color: "#ffffff"
+                                    font.pixelSize: 14
+
+                                    background: Rectangle {
+                                        color: "#1a1a1a"
+                                        radius: 8
+                                        border.color: backupPasswordInput.activeFocus ? "#9333ea" : "#333333"
+                                        border.width: 1
+                                    }
+
+                                    leftPadding: 16
+                                    rightPadding: 16
+                                    topPadding: 12
+                                    bottomPadding: 12
+                                }
+
+                                Button {
+                                    text: "Reveal Key"
+                                    Layout.preferredHeight: 44
+                                    enabled: backupPasswordInput.text !== ""
+
+                                    background: Rectangle {
+                                        color: parent.pressed ? "#7c22ce" : "#9333ea"
+                                        radius: 8
+                                        opacity: parent.enabled ? 1.0 : 0.5
+                                    }
+
+                                    contentItem: Text {
+                                        text: parent.text
+                                        color: "#ffffff"
+                                        font.pixelSize: 14
+                                        horizontalAlignment: Text.AlignHCenter
+                                        verticalAlignment: Text.AlignVCenter
+                                    }
+
+                                    onClicked: {
+                                        keyBackupColumn.error = ""
+                                        root.appController.reveal_nsec(backupPasswordInput.text)
+                                        backupPasswordInput.text = ""
+                                    }
+                                }
+                            }
+
+                            Text {
+                                Layout.fillWidth: true
+                                visible: keyBackupColumn.error !== ""
+                                text: keyBackupColumn.error
+                                color: "#ef4444"
+                                font.pixelSize: 12
+                                wrapMode: Text.WordWrap
+                            }
+
+                            TextEdit {
+                                id: revealedNsecText
+                                Layout.fillWidth: true
+                                visible: keyBackupColumn.revealedNsec !== ""
+                                text: keyBackupColumn.revealedNsec
+                                readOnly: true
+                                selectByMouse: true
+                                color: "#ffffff"
+                                font.pixelSize: 13
+                                font.family: "monospace"
+                                wrapMode: TextEdit.WrapAnywhere
+                            }
+
+                            RowLayout {
+                                Layout.fillWidth: true
+                                spacing: 8
+                                visible: keyBackupColumn.revealedNsec !== ""
+
+                                Button {
+                                    text: "Copy"
+                                    onClicked: {
+                                        revealedNsecText.selectAll()
+                                        revealedNsecText.copy()
+                                        revealedNsecText.deselect()
+                                    }
+                                }
+
+                                Button {
+                                    text: "I've backed it up"
+                                    onClicked: {
+                                        root.appController.confirm_key_backup()
+                                        keyBackupColumn.revealedNsec = ""
+                                    }
+                                }
+
+                                Button {
+                                    text: "Hide"
+                                    onClicked: keyBackupColumn.revealedNsec = ""
+                                }
+                            }
+                        }
+
+                        SettingsToggle {
+                            text: "Store credentials in the system keyring"
+                            property bool initialized: false
+                            checked: root.appController ? root.appController.get_use_system_keyring() : true
+                            Component.onCompleted: initialized = true
+                            onCheckedChanged: if (initialized && root.appController) root.appController.set_use_system_keyring(checked)
+                        }
+                        
+                        Text {
+                            text: root.appController && root.appController.credentials_in_keyring
+                                  ? "Your key is in the system keyring and unlocks when you log in to your desktop."
+                                  : "Uses the desktop keyring (GNOME Keyring, KWallet) when one is running; otherwise saved keys are encrypted with your password. Applies the next time you save your key."
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
                         
                         Button {
                             text: "Logout"
@@ -83,6 +234,236 @@ Rectangle {
                     }
                 }
                 
+                // Signer service section
+                SettingsSection {
+                    title: "Signer Service"
+                    
+                    ColumnLayout {
+                        Layout.fillWidth: true
+                        spacing: 12
+                        
+                        SettingsToggle {
+                            text: "Let other Nostr apps use Pleb Client as a signer"
+                            property bool initialized: false
+                            checked: root.signerController ? root.signerController.get_enabled() : false
+                            Component.onCompleted: initialized = true
+                            onCheckedChanged: if (initialized && root.signerController) root.signerController.set_enabled(checked)
+                        }
+                        
+                        Text {
+                            text: root.signerController && root.signerController.error_message !== ""
+                                  ? root.signerController.error_message
+                                  : "Apps that speak the Pleb Signer D-Bus interface can ask for signatures and encryption. Requests without a saved choice need your approval." +
+                                    (root.signerController && root.signerController.service_running ? " Running." : "")
+                            color: root.signerController && root.signerController.error_message !== "" ? "#ef4444" : "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                        
+                        ColumnLayout {
+                            id: signerPolicyColumn
+                            Layout.fillWidth: true
+                            spacing: 8
+                            
+                            property var policies: []
+                            property var sessions: []
+                            property var auditLog: []
+                            property bool showAudit: false
+                            
+                            property var outcomeColors: ({ "allowed": "#22c55e", "approved": "#22c55e", "rejected": "#ef4444", "blocked": "#ef4444" })
+                            
+                            function refresh() {
+                                if (!root.signerController) return
+                                policies = JSON.parse(root.signerController.get_policies())
+                                sessions = JSON.parse(root.signerController.get_connect_sessions())
+                                auditLog = showAudit ? JSON.parse(root.signerController.get_audit_log()) : []
+                            }
+                            
+                            Component.onCompleted: refresh()
+                            
+                            Connections {
+                                target: root.signerController
+                                function onPending_countChanged() { signerPolicyColumn.refresh() }
+                                function onSessions_changed() { signerPolicyColumn.refresh() }
+                            }
+                            
+                            Text {
+                                text: "Connected apps"
+                                color: "#ffffff"
+                                font.pixelSize: 14
+                                font.weight: Font.Medium
+                            }
+                            
+                            RowLayout {
+                                Layout.fillWidth: true
+                                spacing: 8
+                                
+                                TextField {
+                                    id: connectUriInput
+                                    Layout.fillWidth: true
+                                    placeholderText: "nostrconnect://..."
+                                    color: "#ffffff"
+                                    font.pixelSize: 14
+                                    
+                                    background: Rectangle {
+                                        color: "#1a1a1a"
+                                        radius: 8
+                                        border.color: connectUriInput.activeFocus ? "#9333ea" : "#333333"
+                                        border.width: 1
+                                    }
+                                }
+                                
+                                Button {
+                                    text: "Connect"
+                                    enabled: connectUriInput.text.trim() !== ""
+                                    
+                                    ToolTip.visible: hovered
+                                    ToolTip.text: "Paste the nostrconnect link a web or desktop app shows you"
+                                    ToolTip.delay: 500
+                                    
+                                    onClicked: {
+                                        root.signerController.handle_uri(connectUriInput.text.trim())
+                                        connectUriInput.text = ""
+                                    }
+                                }
+                            }
+                            
+                            Repeater {
+                                model: signerPolicyColumn.sessions
+                                
+                                RowLayout {
+                                    Layout.fillWidth: true
+                                    spacing: 8
+                                    
+                                    Text {
+                                        text: (modelData.name ? modelData.name + " · " : "") + modelData.appId + " · " + modelData.relays.join(", ")
+                                        color: "#ffffff"
+                                        font.pixelSize: 13
+                                        elide: Text.ElideRight
+                                        Layout.fillWidth: true
+                                    }
+                                    
+                                    Button {
+                                        text: "Disconnect"
+                                        onClicked: root.signerController.disconnect_session(modelData.id)
+                                    }
+                                }
+                            }
+                            
+                            Text {
+                                text: "Saved choices"
+                                color: "#ffffff"
+                                font.pixelSize: 14
+                                font.weight: Font.Medium
+                            }
+                            
+                            Text {
+                                visible: signerPolicyColumn.policies.length === 0
+                                text: "None yet. Tick \"Remember my choice\" when answering a request to save one."
+                                color: "#888888"
+                                font.pixelSize: 12
+                                wrapMode: Text.WordWrap
+                                Layout.fillWidth: true
+                            }
+                            
+                            Repeater {
+                                model: signerPolicyColumn.policies
+                                
+                                RowLayout {
+                                    Layout.fillWidth: true
+                                    spacing: 8
+                                    
+                                    Text {
+                                        text: modelData.appId + " · " + modelData.method
+                                              + (modelData.kind !== null && modelData.kind !== undefined ? " (kind " + modelData.kind + ")" : "")
+                                        color: "#ffffff"
+                                        font.pixelSize: 13
+                                        elide: Text.ElideRight
+                                        Layout.fillWidth: true
+                                    }
+                                    
+                                    ComboBox {
+                                        model: ["Always allow", "Ask", "Always reject"]
+                                        property var decisions: ["allow", "ask", "reject"]
+                                        currentIndex: decisions.indexOf(modelData.decision)
+                                        Layout.preferredWidth: 150
+                                        
+                                        onActivated: function(index) {
+                                            root.signerController.set_policy(
+                                                modelData.appId, modelData.method,
+                                                modelData.kind !== null && modelData.kind !== undefined ? modelData.kind : -1,
+                                                decisions[index])
+                                            signerPolicyColumn.refresh()
+                                        }
+                                    }
+                                }
+                            }
+                            
+                            RowLayout {
+                                Layout.fillWidth: true
+                                spacing: 8
+                                
+                                Button {
+                                    text: signerPolicyColumn.showAudit ? "Hide activity" : "Show activity"
+                                    onClicked: {
+                                        signerPolicyColumn.showAudit = !signerPolicyColumn.showAudit
+                                        signerPolicyColumn.refresh()
+                                    }
+                                }
+                                
+                                Button {
+                                    text: "Clear activity"
+                                    visible: signerPolicyColumn.showAudit && signerPolicyColumn.auditLog.length > 0
+                                    onClicked: {
+                                        root.signerController.clear_audit_log()
+                                        signerPolicyColumn.refresh()
+                                    }
+                                }
+                            }
+                            
+                            Text {
+                                visible: signerPolicyColumn.showAudit && signerPolicyColumn.auditLog.length === 0
+                                text: "No requests answered yet."
+                                color: "#888888"
+                                font.pixelSize: 12
+                            }
+                            
+                            Repeater {
+                                model: signerPolicyColumn.auditLog.slice(0, 100)
+                                
+                                RowLayout {
+                                    Layout.fillWidth: true
+                                    spacing: 8
+                                    
+                                    Text {
+                                        text: new Date(modelData.at * 1000).toLocaleString(Qt.locale(), Locale.ShortFormat)
+                                        color: "#888888"
+                                        font.pixelSize: 12
+                                        Layout.preferredWidth: 130
+                                    }
+                                    
+                                    Text {
+                                        text: modelData.appId + " · " + modelData.method
+                                              + (modelData.kind !== null && modelData.kind !== undefined ? " (kind " + modelData.kind + ")" : "")
+                                              + (modelData.eventId ? " · " + modelData.eventId.substring(0, 12) : "")
+                                        color: "#ffffff"
+                                        font.pixelSize: 12
+                                        elide: Text.ElideRight
+                                        Layout.fillWidth: true
+                                    }
+                                    
+                                    Text {
+                                        text: modelData.outcome
+                                        color: signerPolicyColumn.outcomeColors[modelData.outcome] || "#888888"
+                                        font.pixelSize: 12
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                
                 // Wallet section
                 SettingsSection {
                     title: "Wallet (NWC)"
@@ -276,8 +657,19 @@ Rectangle {
                         spacing: 12
                         
                         SettingsToggle {
-                            text: "Auto-load images"
-                            checked: true
+                            text: "Data saver"
+                            property bool initialized: false
+                            checked: root.appController ? root.appController.get_data_saver() : false
+                            Component.onCompleted: initialized = true
+                            onCheckedChanged: if (initialized && root.appController) root.appController.set_data_saver(checked)
+                        }
+                        
+                        Text {
+                            text: "For metered connections: images, videos, link previews and quoted notes wait until you tap to load them. Applies to notes loaded after the change."
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
                         }
                         
                         SettingsToggle {
@@ -293,14 +685,84 @@ Rectangle {
                                 }
                             }
                         }
+                        
+                        SettingsToggle {
+                            text: "Check link preview images before showing them"
+                            property bool initialized: false
+                            checked: root.appController ? root.appController.get_proxy_link_preview_images() : true
+                            Component.onCompleted: initialized = true
+                            onCheckedChanged: if (initialized && root.appController) root.appController.set_proxy_link_preview_images(checked)
+                        }
+                        
+                        Text {
+                            text: "Preview images are downloaded into the media cache with the preview and left out if they're too large or not images. Previews already loaded keep their image until they expire."
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                    }
+                }
+                
+                // Feed loading section
+                SettingsSection {
+                    title: "Feed"
+                    
+                    ColumnLayout {
+                        id: feedLoadingColumn
+                        Layout.fillWidth: true
+                        spacing: 12
+                        
+                        property var settings: root.feedController ? JSON.parse(root.feedController.get_feed_loading()) : ({})
+                        
+                        function save() {
+                            if (root.feedController) {
+                                root.feedController.set_feed_loading(
+                                    pageSizeSpin.value, windowSpin.value, prefetchToggle.checked)
+                            }
+                        }
+                        
+                        SettingsNumber {
+                            id: pageSizeSpin
+                            text: "Notes per page"
+                            from: 10
+                            to: 500
+                            value: feedLoadingColumn.settings.pageSize || 50
+                            onEdited: feedLoadingColumn.save()
+                        }
+                        
+                        SettingsNumber {
+                            id: windowSpin
+                            text: "First page reaches back (hours, 0 = no limit)"
+                            from: 0
+                            to: 720
+                            value: feedLoadingColumn.settings.windowHours || 0
+                            onEdited: feedLoadingColumn.save()
+                        }
+                        
+                        SettingsToggle {
+                            id: prefetchToggle
+                            text: "Load other feeds and messages in the background after login"
+                            property bool initialized: false
+                            checked: feedLoadingColumn.settings.prefetchOnStartup !== false
+                            Component.onCompleted: initialized = true
+                            onCheckedChanged: if (initialized) feedLoadingColumn.save()
+                        }
+                        
+                        Text {
+                            text: "Smaller pages and a shorter window load faster on slow connections. Scrolling down still loads older notes."
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
                     }
                 }
                 
-                // System Tray section - Currently disabled (requires QApplication)
-                // TODO: Enable this when migrating to QApplication for system tray support
-                /*
+                // System Tray section
                 SettingsSection {
                     title: "System Tray"
+                    visible: root.appController ? root.appController.tray_available : false
                     
                     ColumnLayout {
                         Layout.fillWidth: true
@@ -324,32 +786,299 @@ Rectangle {
                         }
                     }
                 }
-                */
                 
-                // Media Upload section
+                // Direct messages section
                 SettingsSection {
-                    title: "Media Upload"
+                    title: "Direct Messages"
                     
                     ColumnLayout {
                         Layout.fillWidth: true
                         spacing: 12
                         
+                        SettingsToggle {
+                            text: "Share typing indicators and read receipts"
+                            property bool initialized: false
+                            checked: root.appController ? root.appController.get_dm_presence() : false
+                            Component.onCompleted: initialized = true
+                            onCheckedChanged: if (initialized && root.appController) root.appController.set_dm_presence(checked)
+                        }
+                        
                         Text {
-                            text: "Blossom Server"
+                            text: "Lets the people you message see when you're typing and what you've read, and shows you theirs. Sent as short-lived encrypted events in NIP-04 conversations only, since they show who you talk to; needs a locally stored key."
                             color: "#888888"
                             font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
                         }
                         
-                        RowLayout {
+                        SettingsToggle {
+                            text: "Encrypt images before upload"
+                            property bool initialized: false
+                            checked: root.appController ? root.appController.get_dm_encrypt_attachments() : true
+                            Component.onCompleted: initialized = true
+                            onCheckedChanged: if (initialized && root.appController) root.appController.set_dm_encrypt_attachments(checked)
+                        }
+                        
+                        Text {
+                            text: "Images sent in messages are stored on your Blossom server. When encrypted, only the recipient can open them; other clients will just see a link."
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
                             Layout.fillWidth: true
-                            spacing: 8
-                            
-                            TextField {
-                                id: blossomServerInput
-                                Layout.fillWidth: true
-                                placeholderText: "https://blossom.band"
-                                text: root.feedController ? root.feedController.get_blossom_server() : "https://blossom.band"
-                                color: "#ffffff"
+                        }
+                    }
+                }
+                
+                // Desktop notifications section
+                SettingsSection {
+                    title: "Desktop Notifications"
+                    
+                    ColumnLayout {
+                        id: desktopNotifyColumn
+                        Layout.fillWidth: true
+                        spacing: 12
+                        
+                        property var settings: root.appController ? JSON.parse(root.appController.get_desktop_notify_settings()) : ({})
+                        
+                        Text {
+                            text: "Shown while Pleb Client is in the background. Notifications and messages are checked even when their screens aren't open."
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                        
+                        SettingsToggle {
+                            text: "Mentions and replies"
+                            property bool initialized: false
+                            checked: desktopNotifyColumn.settings.mention !== false
+                            Component.onCompleted: initialized = true
+                            onCheckedChanged: if (initialized && root.appController) root.appController.set_desktop_notify("mention", checked)
+                        }
+                        
+                        SettingsToggle {
+                            text: "Direct messages"
+                            property bool initialized: false
+                            checked: desktopNotifyColumn.settings.dm !== false
+                            Component.onCompleted: initialized = true
+                            onCheckedChanged: if (initialized && root.appController) root.appController.set_desktop_notify("dm", checked)
+                        }
+                        
+                        SettingsToggle {
+                            text: "Zaps"
+                            property bool initialized: false
+                            checked: desktopNotifyColumn.settings.zap !== false
+                            Component.onCompleted: initialized = true
+                            onCheckedChanged: if (initialized && root.appController) root.appController.set_desktop_notify("zap", checked)
+                        }
+                        
+                        SettingsNumber {
+                            text: "Check for notifications and messages every (seconds)"
+                            from: 15
+                            to: 3600
+                            value: root.appController ? root.appController.get_background_poll_interval() : 60
+                            onEdited: if (root.appController) root.appController.set_background_poll_interval(value)
+                        }
+                    }
+                }
+                
+                // Fiat display section
+                SettingsSection {
+                    title: "Currency"
+
+                    ColumnLayout {
+                        id: fiatColumn
+                        Layout.fillWidth: true
+                        spacing: 12
+
+                        property var settings: root.appController ? JSON.parse(root.appController.get_fiat_settings()) : ({ currency: "", currencies: [] })
+                        property var options: ["Sats only"].concat(settings.currencies || [])
+
+                        Text {
+                            text: "Show fiat equivalents next to zap amounts and your wallet balance"
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+
+                        ComboBox {
+                            id: currencyCombo
+                            implicitWidth: 160
+                            implicitHeight: 40
+                            model: fiatColumn.options
+                            currentIndex: Math.max(0, fiatColumn.options.indexOf(fiatColumn.settings.currency))
+
+                            onActivated: function(index) {
+                                if (root.appController) {
+                                    root.appController.set_fiat_currency(index === 0 ? "" : fiatColumn.options[index])
+                                }
+                            }
+
+                            background: Rectangle {
+                                color: parent.hovered ? "#252525" : "#1a1a1a"
+                                radius: 8
+                                border.color: "#333333"
+                                border.width: 1
+                            }
+
+                            contentItem: Text {
+                                leftPadding: 12
+                                rightPadding: currencyCombo.indicator.width + 8
+                                text: currencyCombo.displayText
+                                font.pixelSize: 13
+                                color: "#ffffff"
+                                verticalAlignment: Text.AlignVCenter
+                                elide: Text.ElideRight
+                            }
+
+                            indicator: Text {
+                                x: currencyCombo.width - width - 8
+                                y: (currencyCombo.height - height) / 2
+                                text: "▼"
+                                color: "#666666"
+                                font.pixelSize: 10
+                            }
+                        }
+                    }
+                }
+
+                // Web of trust section
+                SettingsSection {
+                    title: "Web of Trust"
+
+                    ColumnLayout {
+                        id: wotColumn
+                        Layout.fillWidth: true
+                        spacing: 12
+
+                        property var settings: root.appController ? JSON.parse(root.appController.get_wot_settings()) : ({ threshold: 0, action: "demote", scored: 0 })
+                        property var radiusOptions: ["Off", "People you follow", "Follows of follows"]
+                        property var actionOptions: ["Dim", "Hide"]
+
+                        function save() {
+                            if (root.appController) {
+                                root.appController.set_wot_settings(radiusCombo.currentIndex, actionCombo.currentIndex === 1 ? "hide" : "demote")
+                            }
+                        }
+
+                        Text {
+                            text: "Filter spam in the global feed and search by how far accounts are from the people you follow"
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+
+                        RowLayout {
+                            spacing: 12
+
+                            ComboBox {
+                                id: radiusCombo
+                                implicitWidth: 200
+                                implicitHeight: 40
+                                model: wotColumn.radiusOptions
+                                currentIndex: wotColumn.settings.threshold || 0
+                                onActivated: wotColumn.save()
+
+                                background: Rectangle {
+                                    color: parent.hovered ? "#252525" : "#1a1a1a"
+                                    radius: 8
+                                    border.color: "#333333"
+                                    border.width: 1
+                                }
+
+                                contentItem: Text {
+                                    leftPadding: 12
+                                    rightPadding: radiusCombo.indicator.width + 8
+                                    text: radiusCombo.displayText
+                                    font.pixelSize: 13
+                                    color: "#ffffff"
+                                    verticalAlignment: Text.AlignVCenter
+                                    elide: Text.ElideRight
+                                }
+
+                                indicator: Text {
+                                    x: radiusCombo.width - width - 8
+                                    y: (radiusCombo.height - height) / 2
+                                    text: "▼"
+                                    color: "#666666"
+                                    font.pixelSize: 10
+                                }
+                            }
+
+                            ComboBox {
+                                id: actionCombo
+                                implicitWidth: 120
+                                implicitHeight: 40
+                                enabled: radiusCombo.currentIndex > 0
+                                model: wotColumn.actionOptions
+                                currentIndex: wotColumn.settings.action === "hide" ? 1 : 0
+                                onActivated: wotColumn.save()
+
+                                background: Rectangle {
+                                    color: parent.hovered ? "#252525" : "#1a1a1a"
+                                    radius: 8
+                                    border.color: "#333333"
+                                    border.width: 1
+                                    opacity: actionCombo.enabled ? 1.0 : 0.5
+                                }
+
+                                contentItem: Text {
+                                    leftPadding: 12
+                                    rightPadding: actionCombo.indicator.width + 8
+                                    text: actionCombo.displayText
+                                    font.pixelSize: 13
+                                    color: actionCombo.enabled ? "#ffffff" : "#666666"
+                                    verticalAlignment: Text.AlignVCenter
+                                    elide: Text.ElideRight
+                                }
+
+                                indicator: Text {
+                                    x: actionCombo.width - width - 8
+                                    y: (actionCombo.height - height) / 2
+                                    text: "▼"
+                                    color: "#666666"
+                                    font.pixelSize: 10
+                                }
+                            }
+                        }
+
+                        Text {
+                            visible: radiusCombo.currentIndex > 0
+                            text: wotColumn.settings.scored > 0
+                                ? wotColumn.settings.scored + " accounts in your network"
+                                : "Your network is mapped the next time the global feed loads"
+                            color: "#666666"
+                            font.pixelSize: 11
+                        }
+                    }
+                }
+
+                // Media Upload section
+                SettingsSection {
+                    title: "Media Upload"
+                    
+                    ColumnLayout {
+                        Layout.fillWidth: true
+                        spacing: 12
+                        
+                        Text {
+                            text: "Blossom Server"
+                            color: "#888888"
+                            font.pixelSize: 12
+                        }
+                        
+                        RowLayout {
+                            Layout.fillWidth: true
+                            spacing: 8
+                            
+                            TextField {
+                                id: blossomServerInput
+                                Layout.fillWidth: true
+                                placeholderText: "https://blossom.band"
+                                text: root.feedController ? root.feedController.get_blossom_server() : "https://blossom.band"
+                                color: "#ffffff"
                                 font.pixelSize: 14
                                 
                                 background: Rectangle {
@@ -611,45 +1340,706 @@ Rectangle {
                     }
                 }
                 
-                // About section
+                // Network section
                 SettingsSection {
-                    title: "About"
+                    title: "Network"
                     
                     ColumnLayout {
+                        id: proxyColumn
                         Layout.fillWidth: true
-                        spacing: 8
+                        spacing: 12
+                        
+                        property var settings: root.appController ? JSON.parse(root.appController.get_proxy_settings()) : ({})
+                        property string error: ""
+                        property bool saved: false
                         
                         Text {
-                            text: "Pleb Client Qt"
+                            text: "Route relay and web traffic through a proxy. For Tor use socks5h://127.0.0.1:9050 (needed for .onion relays). Only SOCKS5 proxies work; if the proxy can't be used, nothing connects directly."
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                        
+                        TextField {
+                            id: proxyInput
+                            Layout.fillWidth: true
+                            placeholderText: "socks5h://127.0.0.1:9050 (empty = direct)"
+                            text: proxyColumn.settings.url || ""
                             color: "#ffffff"
-                            font.pixelSize: 16
-                            font.weight: Font.Medium
+                            font.pixelSize: 14
+                            
+                            background: Rectangle {
+                                color: "#1a1a1a"
+                                radius: 8
+                                border.color: proxyInput.activeFocus ? "#9333ea" : "#333333"
+                                border.width: 1
+                            }
+                            
+                            leftPadding: 16
+                            rightPadding: 16
+                            topPadding: 12
+                            bottomPadding: 12
+                            
+                            onTextEdited: proxyColumn.saved = false
+                        }
+                        
+                        SettingsToggle {
+                            id: onionOnlyToggle
+                            text: "Only use the proxy for .onion relays and sites"
+                            checked: proxyColumn.settings.onionOnly || false
                         }
                         
                         Text {
-                            text: "Version 0.1.0"
+                            visible: proxyColumn.error !== "" || proxyColumn.saved
+                            text: proxyColumn.error !== "" ? proxyColumn.error
+                                  : "Saved. Relays already connected switch over after a restart."
+                            color: proxyColumn.error !== "" ? "#ef4444" : "#22c55e"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                        
+                        Button {
+                            text: "Save proxy settings"
+                            Layout.fillWidth: true
+                            Layout.preferredHeight: 40
+                            
+                            onClicked: {
+                                if (root.appController) {
+                                    proxyColumn.error = root.appController.set_proxy_settings(
+                                        proxyInput.text.trim(), onionOnlyToggle.checked)
+                                    proxyColumn.saved = proxyColumn.error === ""
+                                }
+                            }
+                            
+                            background: Rectangle {
+                                color: parent.pressed ? "#333333" : "#2a2a2a"
+                                radius: 8
+                            }
+                            
+                            contentItem: Text {
+                                text: parent.text
+                                color: "#ffffff"
+                                font.pixelSize: 14
+                                horizontalAlignment: Text.AlignHCenter
+                                verticalAlignment: Text.AlignVCenter
+                            }
+                        }
+                    }
+                    
+                    ColumnLayout {
+                        id: rebroadcastColumn
+                        Layout.fillWidth: true
+                        spacing: 12
+                        
+                        property bool busy: false
+                        property string status: ""
+                        property bool failed: false
+                        
+                        Connections {
+                            target: root.appController
+                            function onRebroadcast_finished(resultJson) {
+                                var result = JSON.parse(resultJson)
+                                var names = { 0: "Profile", 10002: "Relay list", 10050: "DM relays" }
+                                rebroadcastColumn.busy = false
+                                rebroadcastColumn.failed = !!result.error
+                                rebroadcastColumn.status = result.error ? result.error
+                                    : result.events.map(function(e) {
+                                        return (names[e.kind] || ("Kind " + e.kind)) + ": " + e.accepted + " of " + e.total + " relays"
+                                    }).join(" · ")
+                            }
+                        }
+                        
+                        Text {
+                            text: "If people can't find your profile, send it and your relay lists to all your write relays again."
                             color: "#888888"
-                            font.pixelSize: 14
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                        
+                        Button {
+                            text: rebroadcastColumn.busy ? "Rebroadcasting..." : "Rebroadcast my profile and relay list"
+                            enabled: !rebroadcastColumn.busy
+                            Layout.fillWidth: true
+                            Layout.preferredHeight: 40
+                            
+                            onClicked: {
+                                if (root.appController) {
+                                    rebroadcastColumn.busy = true
+                                    rebroadcastColumn.status = ""
+                                    root.appController.rebroadcast_profile()
+                                }
+                            }
+                            
+                            background: Rectangle {
+                                color: parent.pressed ? "#333333" : "#2a2a2a"
+                                radius: 8
+                            }
+                            
+                            contentItem: Text {
+                                text: parent.text
+                                color: parent.enabled ? "#ffffff" : "#666666"
+                                font.pixelSize: 14
+                                horizontalAlignment: Text.AlignHCenter
+                                verticalAlignment: Text.AlignVCenter
+                            }
                         }
                         
                         Text {
-                            text: "A native Nostr client for Linux"
-                            color: "#666666"
-                            font.pixelSize: 14
+                            visible: rebroadcastColumn.status !== ""
+                            text: rebroadcastColumn.status
+                            color: rebroadcastColumn.failed ? "#ef4444" : "#22c55e"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
                         }
                     }
-                }
-                
-                Item { Layout.preferredHeight: 40 }
-            }
-        }
-    }
-    
-    component SettingsSection: ColumnLayout {
-        property string title: ""
-        default property alias content: contentColumn.data
-        
-        Layout.fillWidth: true
+                    
+                    ColumnLayout {
+                        Layout.fillWidth: true
+                        spacing: 12
+                        
+                        SettingsToggle {
+                            text: "Tag my posts with PlebClient"
+                            property bool initialized: false
+                            checked: root.appController ? root.appController.get_client_tag() : false
+                            Component.onCompleted: initialized = true
+                            onCheckedChanged: if (initialized && root.appController) root.appController.set_client_tag(checked)
+                        }
+                        
+                        Text {
+                            text: "Adds a client tag (NIP-89) to notes, replies and reactions so other apps can show where they were posted from."
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                    }
+                }
+                
+                // Backup & Export section
+                SettingsSection {
+                    title: "Backup & Export"
+                    
+                    ColumnLayout {
+                        id: archiveColumn
+                        Layout.fillWidth: true
+                        spacing: 12
+                        
+                        property bool busy: false
+                        property string status: ""
+                        property bool failed: false
+                        
+                        function localPath(url) {
+                            var path = url.toString()
+                            return path.startsWith("file://") ? path.substring(7) : path
+                        }
+                        
+                        Connections {
+                            target: root.appController
+                            function onArchive_finished(resultJson) {
+                                var result = JSON.parse(resultJson)
+                                archiveColumn.busy = false
+                                archiveColumn.failed = !!result.error
+                                if (result.error) {
+                                    archiveColumn.status = result.error
+                                } else if (result.action === "export") {
+                                    archiveColumn.status = "Exported " + result.events + " events"
+                                        + (result.dms > 0 ? " and " + result.dms + " decrypted messages" : "")
+                                } else {
+                                    archiveColumn.status = "Imported " + result.events + " events"
+                                        + (result.republished > 0 ? ", republished " + result.republished : "")
+                                        + (result.settingsRestored ? ". Settings restored, restart to apply them." : "")
+                                }
+                            }
+                        }
+                        
+                        FileDialog {
+                            id: exportDialog
+                            title: "Export Archive"
+                            fileMode: FileDialog.SaveFile
+                            defaultSuffix: "ndjson"
+                            nameFilters: ["Pleb archives (*.ndjson)"]
+                            
+                            onAccepted: {
+                                if (root.appController) {
+                                    archiveColumn.busy = true
+                                    archiveColumn.status = "Exporting..."
+                                    archiveColumn.failed = false
+                                    root.appController.export_archive(
+                                        archiveColumn.localPath(selectedFile), includeDmsToggle.checked)
+                                }
+                            }
+                        }
+                        
+                        FileDialog {
+                            id: importDialog
+                            title: "Import Archive"
+                            nameFilters: ["Pleb archives (*.ndjson)", "All files (*)"]
+                            
+                            onAccepted: {
+                                if (root.appController) {
+                                    archiveColumn.busy = true
+                                    archiveColumn.status = "Importing..."
+                                    archiveColumn.failed = false
+                                    root.appController.import_archive(
+                                        archiveColumn.localPath(selectedFile),
+                                        republishToggle.checked, restoreSettingsToggle.checked)
+                                }
+                            }
+                        }
+                        
+                        Text {
+                            text: "Save your notes, reactions, contact list, relay list and settings to a file, or load one back after moving to a new machine. The wallet connection is never exported."
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                        
+                        SettingsToggle {
+                            id: includeDmsToggle
+                            text: "Include decrypted direct messages (nsec login only)"
+                            checked: false
+                        }
+                        
+                        Text {
+                            visible: includeDmsToggle.checked
+                            text: "Decrypted messages are stored as plain text. Keep the file somewhere safe."
+                            color: "#f59e0b"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                        
+                        Button {
+                            text: "Export archive..."
+                            enabled: !archiveColumn.busy
+                            Layout.fillWidth: true
+                            Layout.preferredHeight: 40
+                            
+                            onClicked: exportDialog.open()
+                            
+                            background: Rectangle {
+                                color: parent.pressed ? "#333333" : "#2a2a2a"
+                                radius: 8
+                            }
+                            
+                            contentItem: Text {
+                                text: parent.text
+                                color: parent.enabled ? "#ffffff" : "#666666"
+                                font.pixelSize: 14
+                                horizontalAlignment: Text.AlignHCenter
+                                verticalAlignment: Text.AlignVCenter
+                            }
+                        }
+                        
+                        SettingsToggle {
+                            id: republishToggle
+                            text: "Republish imported events to my relays"
+                            checked: false
+                        }
+                        
+                        SettingsToggle {
+                            id: restoreSettingsToggle
+                            text: "Restore settings from the archive"
+                            checked: false
+                        }
+                        
+                        Button {
+                            text: "Import archive..."
+                            enabled: !archiveColumn.busy
+                            Layout.fillWidth: true
+                            Layout.preferredHeight: 40
+                            
+                            onClicked: importDialog.open()
+                            
+                            background: Rectangle {
+                                color: parent.pressed ? "#333333" : "#2a2a2a"
+                                radius: 8
+                            }
+                            
+                            contentItem: Text {
+                                text: parent.text
+                                color: parent.enabled ? "#ffffff" : "#666666"
+                                font.pixelSize: 14
+                                horizontalAlignment: Text.AlignHCenter
+                                verticalAlignment: Text.AlignVCenter
+                            }
+                        }
+                        
+                        Text {
+                            visible: archiveColumn.status !== ""
+                            text: archiveColumn.status
+                            color: archiveColumn.failed ? "#ef4444" : (archiveColumn.busy ? "#888888" : "#22c55e")
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                    }
+                }
+                
+                // Advanced section
+                SettingsSection {
+                    title: "Advanced"
+                    
+                    ColumnLayout {
+                        id: limitsColumn
+                        Layout.fillWidth: true
+                        spacing: 12
+                        
+                        property var limits: root.appController ? JSON.parse(root.appController.get_concurrency_limits()) : ({})
+                        
+                        function saveLimits() {
+                            if (root.appController) {
+                                root.appController.set_concurrency_limits(
+                                    relayFetchesSpin.value, threadsSpin.value, perHostSpin.value)
+                            }
+                        }
+                        
+                        Text {
+                            text: "Lower these on slow machines to keep the app responsive"
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                        
+                        SettingsNumber {
+                            id: relayFetchesSpin
+                            text: "Simultaneous relay fetches"
+                            value: limitsColumn.limits.relayFetches || 8
+                            onEdited: limitsColumn.saveLimits()
+                        }
+                        
+                        SettingsNumber {
+                            id: threadsSpin
+                            text: "Background workers"
+                            value: limitsColumn.limits.backgroundThreads || 16
+                            onEdited: limitsColumn.saveLimits()
+                        }
+                        
+                        SettingsNumber {
+                            id: perHostSpin
+                            text: "Requests per host (previews, media)"
+                            value: limitsColumn.limits.requestsPerHost || 4
+                            onEdited: limitsColumn.saveLimits()
+                        }
+                    }
+                    
+                    ColumnLayout {
+                        id: timeoutsColumn
+                        Layout.fillWidth: true
+                        spacing: 12
+                        
+                        property var timeouts: root.appController ? JSON.parse(root.appController.get_network_timeouts()) : ({})
+                        
+                        function saveTimeouts() {
+                            if (root.appController) {
+                                root.appController.set_network_timeouts(
+                                    fetchTimeoutSpin.value, connectTimeoutSpin.value, reconnectMaxSpin.value)
+                            }
+                        }
+                        
+                        Text {
+                            text: "Raise the timeouts on slow or proxied connections. Dropped relays are retried with a growing delay up to the maximum."
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                        
+                        SettingsNumber {
+                            id: fetchTimeoutSpin
+                            text: "Relay query timeout (seconds)"
+                            from: 2
+                            to: 120
+                            value: timeoutsColumn.timeouts.fetchSecs || 10
+                            onEdited: timeoutsColumn.saveTimeouts()
+                        }
+                        
+                        SettingsNumber {
+                            id: connectTimeoutSpin
+                            text: "Relay connect timeout (seconds)"
+                            from: 2
+                            to: 120
+                            value: timeoutsColumn.timeouts.connectSecs || 10
+                            onEdited: timeoutsColumn.saveTimeouts()
+                        }
+                        
+                        SettingsNumber {
+                            id: reconnectMaxSpin
+                            text: "Longest wait between reconnects (seconds)"
+                            from: 5
+                            to: 3600
+                            value: timeoutsColumn.timeouts.reconnectMaxSecs || 300
+                            onEdited: timeoutsColumn.saveTimeouts()
+                        }
+                    }
+                }
+                
+                // Diagnostics section
+                SettingsSection {
+                    title: "Diagnostics"
+                    
+                    ColumnLayout {
+                        id: diagnosticsColumn
+                        Layout.fillWidth: true
+                        spacing: 8
+                        
+                        property var stats: ({ caches: [] })
+                        property var cacheLabels: ({
+                            "feed": "Cached feeds",
+                            "embedded_events": "Embedded notes",
+                            "embedded_profiles": "Embedded profiles",
+                            "link_previews": "Link previews",
+                            "note_stats": "Note stats",
+                            "db_events": "Event cache",
+                            "db_profiles": "Profile cache",
+                            "gifs": "GIF search",
+                            "media": "Media on disk"
+                        })
+                        
+                        function refresh() {
+                            if (root.appController) {
+                                stats = JSON.parse(root.appController.get_cache_stats())
+                            }
+                        }
+                        
+                        function formatBytes(bytes) {
+                            if (!bytes) return "0 B"
+                            if (bytes < 1024) return bytes + " B"
+                            if (bytes < 1024 * 1024) return (bytes / 1024).toFixed(1) + " KB"
+                            if (bytes < 1024 * 1024 * 1024) return (bytes / (1024 * 1024)).toFixed(1) + " MB"
+                            return (bytes / (1024 * 1024 * 1024)).toFixed(2) + " GB"
+                        }
+                        
+                        function formatRatio(ratio) {
+                            return ratio === null || ratio === undefined ? "-" : Math.round(ratio * 100) + "%"
+                        }
+                        
+                        Component.onCompleted: refresh()
+                        
+                        Text {
+                            text: "Cache sizes and hit rates since start. Low hit rates or large caches point at what to tune."
+                            color: "#888888"
+                            font.pixelSize: 12
+                            wrapMode: Text.WordWrap
+                            Layout.fillWidth: true
+                        }
+                        
+                        Repeater {
+                            model: diagnosticsColumn.stats.caches || []
+                            
+                            RowLayout {
+                                Layout.fillWidth: true
+                                spacing: 8
+                                
+                                Text {
+                                    text: diagnosticsColumn.cacheLabels[modelData.name] || modelData.name
+                                    color: "#ffffff"
+                                    font.pixelSize: 13
+                                    Layout.fillWidth: true
+                                }
+                                
+                                Text {
+                                    text: modelData.entries + (modelData.capacity ? "/" + modelData.capacity : "") + " · " + diagnosticsColumn.formatBytes(modelData.approxBytes)
+                                    color: "#aaaaaa"
+                                    font.pixelSize: 12
+                                }
+                                
+                                Text {
+                                    text: diagnosticsColumn.formatRatio(modelData.hitRatio) + " hits"
+                                    color: "#888888"
+                                    font.pixelSize: 12
+                                    horizontalAlignment: Text.AlignRight
+                                    Layout.preferredWidth: 70
+                                }
+                            }
+                        }
+                        
+                        Text {
+                            visible: !!diagnosticsColumn.stats.database
+                            text: diagnosticsColumn.stats.database
+                                  ? "Database: " + diagnosticsColumn.formatBytes(diagnosticsColumn.stats.database.bytes)
+                                    + " in " + diagnosticsColumn.stats.database.files + " files"
+                                  : ""
+                            color: "#ffffff"
+                            font.pixelSize: 13
+                        }
+                        
+                        Text {
+                            text: diagnosticsColumn.stats.media
+                                  ? "Media cache: " + diagnosticsColumn.formatBytes(diagnosticsColumn.stats.media.bytes)
+                                    + " of " + diagnosticsColumn.formatBytes(diagnosticsColumn.stats.media.limitBytes)
+                                    + " in " + diagnosticsColumn.stats.media.files + " files"
+                                  : ""
+                            color: "#ffffff"
+                            font.pixelSize: 13
+                        }
+                        
+                        RowLayout {
+                            Layout.fillWidth: true
+                            spacing: 12
+                            
+                            Text {
+                                text: "Media cache limit (MB)"
+                                color: "#aaaaaa"
+                                font.pixelSize: 13
+                                Layout.fillWidth: true
+                            }
+                            
+                            SpinBox {
+                                id: mediaCacheLimit
+                                from: 16
+                                to: 16384
+                                stepSize: 64
+                                editable: true
+                                value: root.appController ? root.appController.get_media_cache_limit() : 512
+                                
+                                onValueModified: {
+                                    if (root.appController) {
+                                        root.appController.set_media_cache_limit(value)
+                                        diagnosticsColumn.refresh()
+                                    }
+                                }
+                            }
+                            
+                            Button {
+                                text: "Clear media cache"
+                                Layout.preferredHeight: 40
+                                
+                                ToolTip.visible: hovered
+                                ToolTip.text: "Deletes cached avatars and images; they download again when shown"
+                                ToolTip.delay: 500
+                                
+                                onClicked: {
+                                    if (root.appController) {
+                                        root.appController.clear_media_cache()
+                                        diagnosticsColumn.refresh()
+                                    }
+                                }
+                                
+                                background: Rectangle {
+                                    color: parent.pressed ? "#333333" : "#2a2a2a"
+                                    radius: 8
+                                }
+                                
+                                contentItem: Text {
+                                    text: parent.text
+                                    color: "#ffffff"
+                                    font.pixelSize: 14
+                                    horizontalAlignment: Text.AlignHCenter
+                                    verticalAlignment: Text.AlignVCenter
+                                }
+                            }
+                        }
+                        
+                        Text {
+                            visible: !!diagnosticsColumn.stats.residentBytes
+                            text: "Memory in use: " + diagnosticsColumn.formatBytes(diagnosticsColumn.stats.residentBytes)
+                            color: "#ffffff"
+                            font.pixelSize: 13
+                        }
+                        
+                        RowLayout {
+                            Layout.fillWidth: true
+                            spacing: 12
+                            
+                            Button {
+                                text: "Refresh"
+                                Layout.fillWidth: true
+                                Layout.preferredHeight: 40
+                                onClicked: diagnosticsColumn.refresh()
+                                
+                                background: Rectangle {
+                                    color: parent.pressed ? "#333333" : "#2a2a2a"
+                                    radius: 8
+                                }
+                                
+                                contentItem: Text {
+                                    text: parent.text
+                                    color: "#ffffff"
+                                    font.pixelSize: 14
+                                    horizontalAlignment: Text.AlignHCenter
+                                    verticalAlignment: Text.AlignVCenter
+                                }
+                            }
+                            
+                            Button {
+                                text: "Clear memory caches"
+                                Layout.fillWidth: true
+                                Layout.preferredHeight: 40
+                                
+                                ToolTip.visible: hovered
+                                ToolTip.text: "Frees memory; feeds and embeds reload on demand"
+                                ToolTip.delay: 500
+                                
+                                onClicked: {
+                                    if (root.appController) {
+                                        root.appController.clear_caches()
+                                        diagnosticsColumn.refresh()
+                                    }
+                                }
+                                
+                                background: Rectangle {
+                                    color: parent.pressed ? "#333333" : "#2a2a2a"
+                                    radius: 8
+                                }
+                                
+                                contentItem: Text {
+                                    text: parent.text
+                                    color: "#ffffff"
+                                    font.pixelSize: 14
+                                    horizontalAlignment: Text.AlignHCenter
+                                    verticalAlignment: Text.AlignVCenter
+                                }
+                            }
+                        }
+                    }
+                }
+                
+                // About section
+                SettingsSection {
+                    title: "About"
+                    
+                    ColumnLayout {
+                        Layout.fillWidth: true
+                        spacing: 8
+                        
+                        Text {
+                            text: "Pleb Client Qt"
+                            color: "#ffffff"
+                            font.pixelSize: 16
+                            font.weight: Font.Medium
+                        }
+                        
+                        Text {
+                            text: "Version 0.1.0"
+                            color: "#888888"
+                            font.pixelSize: 14
+                        }
+                        
+                        Text {
+                            text: "A native Nostr client for Linux"
+                            color: "#666666"
+                            font.pixelSize: 14
+                        }
+                    }
+                }
+                
+                Item { Layout.preferredHeight: 40 }
+            }
+        }
+    }
+    
+    component SettingsSection: ColumnLayout {
+        property string title: ""
+        default property alias content: contentColumn.data
+        
+        Layout.fillWidth: true
         Layout.leftMargin: 20
         Layout.rightMargin: 20
         spacing: 12
@@ -680,6 +2070,38 @@ Rectangle {
         }
     }
     
+    component SettingsNumber: Item {
+        property alias text: numberLabel.text
+        property alias value: numberSpin.value
+        property alias from: numberSpin.from
+        property alias to: numberSpin.to
+        signal edited()
+        
+        Layout.fillWidth: true
+        implicitHeight: 36
+        
+        Text {
+            id: numberLabel
+            anchors.left: parent.left
+            anchors.verticalCenter: parent.verticalCenter
+            anchors.right: numberSpin.left
+            anchors.rightMargin: 16
+            color: "#ffffff"
+            font.pixelSize: 14
+            elide: Text.ElideRight
+        }
+        
+        SpinBox {
+            id: numberSpin
+            anchors.right: parent.right
+            anchors.verticalCenter: parent.verticalCenter
+            from: 1
+            to: 256
+            editable: true
+            onValueModified: parent.edited()
+        }
+    }
+    
     component SettingsToggle: Item {
         property alias text: label.text
         property alias checked: toggle.checked
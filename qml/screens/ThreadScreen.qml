@@ -11,6 +11,13 @@ Rectangle {
     property var feedController: null
     property string noteId: ""
     
+    // Deeper replies stop indenting past this level
+    readonly property int maxIndentDepth: 6
+    readonly property int indentWidth: 16
+    
+    // Position of the latest live reply, -1 when there's nothing new to show
+    property int newReplyIndex: -1
+    
     signal back()
     signal openProfile(string pubkey)
     
@@ -49,6 +56,7 @@ Rectangle {
     
     // Load thread when noteId changes
     onNoteIdChanged: {
+        newReplyIndex = -1
         if (noteId && feedController) {
             // Reset model first to clear old delegates
             threadList.model = 0
@@ -64,6 +72,19 @@ Rectangle {
             console.log("[ThreadScreen] Thread loaded, count:", feedController ? feedController.thread_count : 0)
             threadList.model = feedController ? feedController.thread_count : 0
         }
+        function onThread_replies_loaded(added) {
+            // Rebuild delegates but stay where the user was reading
+            var y = threadList.contentY
+            threadList.model = feedController ? feedController.thread_count : 0
+            threadList.contentY = y
+        }
+        function onThread_reply_added(note_id, index) {
+            var y = threadList.contentY
+            threadList.model = feedController ? feedController.thread_count : 0
+            threadList.contentY = y
+            root.newReplyIndex = index
+            newReplyTimer.restart()
+        }
         function onError_occurred(error) {
             console.log("Thread error:", error)
         }
@@ -122,6 +143,34 @@ Rectangle {
                 
                 Item { Layout.fillWidth: true }
                 
+                // Reply ordering
+                ComboBox {
+                    id: sortCombo
+                    model: ["Oldest first", "Most replies"]
+                    currentIndex: feedController && feedController.thread_sort === "engagement" ? 1 : 0
+                    Layout.preferredWidth: 150
+                    
+                    onActivated: function(index) {
+                        if (feedController) {
+                            feedController.sort_thread(index === 1 ? "engagement" : "time")
+                        }
+                    }
+                    
+                    background: Rectangle {
+                        color: "#1a1a1a"
+                        radius: 8
+                        border.color: "#333333"
+                    }
+                    
+                    contentItem: Text {
+                        leftPadding: 10
+                        text: sortCombo.displayText
+                        color: "#ffffff"
+                        font.pixelSize: 13
+                        verticalAlignment: Text.AlignVCenter
+                    }
+                }
+                
                 // Reply button in header
                 Button {
                     text: "Reply"
@@ -190,18 +239,13 @@ Rectangle {
                 // Store the note data loaded in Component.onCompleted
                 property var noteData: ({})
                 property bool isTarget: false
+                property int depth: noteData.depth || 0
+                property int indent: Math.min(depth, root.maxIndentDepth) * root.indentWidth
                 
                 Component.onCompleted: {
                     if (feedController) {
-                        try {
-                            var noteJson = feedController.get_thread_note(index)
-                            console.log("[ThreadScreen] Loading note at index", index, ":", noteJson.substring(0, 100))
-                            noteData = JSON.parse(noteJson)
-                            isTarget = noteData.id === root.noteId
-                        } catch (e) {
-                            console.log("[ThreadScreen] Error parsing note:", e)
-                            noteData = {}
-                        }
+                        noteData = feedController.get_thread_note(index)
+                        isTarget = noteData.id === root.noteId
                     }
                 }
                 
@@ -213,13 +257,24 @@ Rectangle {
                     height: 8
                     color: "#333333"
                     anchors.horizontalCenter: parent.left
-                    anchors.horizontalCenterOffset: 40
+                    anchors.horizontalCenterOffset: 40 + delegateItem.indent
                     anchors.top: parent.top
                 }
                 
+                // Nesting guide for replies to replies
+                Rectangle {
+                    visible: delegateItem.depth > 1
+                    width: 2
+                    x: delegateItem.indent - root.indentWidth / 2
+                    anchors.top: threadConnector.bottom
+                    anchors.bottom: parent.bottom
+                    color: "#2a2a2a"
+                }
+                
                 NoteCard {
                     id: noteCard
-                    width: parent.width
+                    x: delegateItem.indent
+                    width: parent.width - delegateItem.indent
                     anchors.top: threadConnector.bottom
                     
                     // Highlight target note
@@ -238,6 +293,7 @@ Rectangle {
                     reposts: delegateItem.noteData.reposts || 0
                     replies: delegateItem.noteData.replies || 0
                     zapAmount: delegateItem.noteData.zapAmount || 0
+                    zapAmountFiat: delegateItem.noteData.zapAmountFiat || ""
                     images: delegateItem.noteData.images || []
                     videos: delegateItem.noteData.videos || []
                     isReply: delegateItem.noteData.isReply || false
@@ -246,6 +302,10 @@ Rectangle {
                     isRepost: delegateItem.noteData.isRepost || false
                     repostAuthorName: delegateItem.noteData.repostAuthorName || ""
                     repostAuthorPicture: delegateItem.noteData.repostAuthorPicture || ""
+                    extras: delegateItem.noteData.extras || {}
+                    emojis: delegateItem.noteData.emojis || {}
+                    blurhashes: delegateItem.noteData.blurhashes || {}
+                    autoLoadMedia: delegateItem.noteData.autoLoadMedia !== false
                     
                     onLikeClicked: feedController.like_note(noteId)
                     onRepostClicked: feedController.repost_note(noteId)
@@ -273,6 +333,34 @@ Rectangle {
                 }
             }
             
+            // Older replies
+            footer: Item {
+                width: threadList.width - 40
+                height: feedController && feedController.thread_has_more ? 56 : 0
+                visible: height > 0
+                
+                Button {
+                    anchors.centerIn: parent
+                    text: feedController && feedController.is_loading ? "Loading..." : "Load more replies"
+                    enabled: feedController && !feedController.is_loading
+                    onClicked: feedController.load_more_replies()
+                    
+                    background: Rectangle {
+                        color: parent.pressed ? "#333333" : "#1a1a1a"
+                        radius: 8
+                        border.color: "#333333"
+                    }
+                    
+                    contentItem: Text {
+                        text: parent.text
+                        color: "#9333ea"
+                        font.pixelSize: 14
+                        horizontalAlignment: Text.AlignHCenter
+                        verticalAlignment: Text.AlignVCenter
+                    }
+                }
+            }
+            
             // Loading indicator
             BusyIndicator {
                 anchors.centerIn: parent
@@ -296,6 +384,43 @@ Rectangle {
         }
     }
     
+    Timer {
+        id: newReplyTimer
+        interval: 8000
+        onTriggered: root.newReplyIndex = -1
+    }
+    
+    // Jump to a reply that just arrived
+    Rectangle {
+        visible: root.newReplyIndex >= 0
+        anchors.horizontalCenter: parent.horizontalCenter
+        anchors.top: parent.top
+        anchors.topMargin: 72
+        width: newReplyText.implicitWidth + 32
+        height: 34
+        radius: 17
+        color: "#9333ea"
+        z: 10
+        
+        Text {
+            id: newReplyText
+            anchors.centerIn: parent
+            text: "↓ New reply"
+            color: "#ffffff"
+            font.pixelSize: 13
+            font.weight: Font.Medium
+        }
+        
+        MouseArea {
+            anchors.fill: parent
+            cursorShape: Qt.PointingHandCursor
+            onClicked: {
+                threadList.positionViewAtIndex(root.newReplyIndex, ListView.Center)
+                root.newReplyIndex = -1
+            }
+        }
+    }
+    
     // Compose Dialog for replies
     ComposeDialog {
         id: composeDialog
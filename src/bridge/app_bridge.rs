@@ -25,9 +25,12 @@ pub mod qobject {
         #[qproperty(bool, tray_available)]
         #[qproperty(bool, signer_available)]
         #[qproperty(i64, wallet_balance_sats)]
+        #[qproperty(QString, wallet_balance_fiat)]
         #[qproperty(bool, show_global_feed)]
         #[qproperty(bool, has_saved_credentials)]
+        #[qproperty(bool, credentials_in_keyring)]
         #[qproperty(bool, nwc_connected)]
+        #[qproperty(bool, backup_reminder_due)]
         type AppController = super::AppControllerRust;
 
         /// Create a new Nostr account (generate keys)
@@ -50,7 +53,7 @@ pub mod qobject {
         #[qinvokable]
         fn logout(self: Pin<&mut AppController>);
         
-        /// Check for saved credentials (returns true if credentials exist)
+        /// Check for saved credentials (async); emits saved_credentials_checked
         #[qinvokable]
         fn check_saved_credentials(self: Pin<&mut AppController>);
         
@@ -58,6 +61,18 @@ pub mod qobject {
         #[qinvokable]
         fn login_with_password(self: Pin<&mut AppController>, password: &QString);
         
+        /// Login with credentials from the system keyring (no password needed)
+        #[qinvokable]
+        fn login_with_keyring(self: Pin<&mut AppController>);
+        
+        /// Get whether credentials are saved in the system keyring
+        #[qinvokable]
+        fn get_use_system_keyring(self: &AppController) -> bool;
+        
+        /// Save credentials in the system keyring when available; applies the next time they are saved
+        #[qinvokable]
+        fn set_use_system_keyring(self: Pin<&mut AppController>, enabled: bool);
+        
         /// Save nsec with password protection (remember me)
         #[qinvokable]
         fn save_credentials_with_password(self: Pin<&mut AppController>, nsec: &QString, password: &QString);
@@ -66,6 +81,19 @@ pub mod qobject {
         #[qinvokable]
         fn clear_saved_credentials(self: Pin<&mut AppController>);
         
+        /// Decrypt the saved nsec with the login password for backup (async)
+        /// Emits nsec_revealed, with an error if the password is wrong or nothing is saved
+        #[qinvokable]
+        fn reveal_nsec(self: Pin<&mut AppController>, password: &QString);
+        
+        /// Record that the user has backed up their key; stops backup reminders
+        #[qinvokable]
+        fn confirm_key_backup(self: Pin<&mut AppController>);
+        
+        /// Hide the backup reminder until the next reminder interval
+        #[qinvokable]
+        fn snooze_backup_reminder(self: Pin<&mut AppController>);
+        
         /// Navigate to a screen
         #[qinvokable]
         fn navigate_to(self: Pin<&mut AppController>, screen: &QString);
@@ -98,6 +126,32 @@ pub mod qobject {
         #[qinvokable]
         fn get_relays(self: Pin<&mut AppController>) -> QString;
         
+        /// Probe a relay's health before adding it (async)
+        /// Emits relay_probe_finished with a JSON report
+        #[qinvokable]
+        fn probe_relay(self: Pin<&mut AppController>, url: &QString);
+        
+        /// Look up a relay's NIP-11 document (async, cached for an hour)
+        /// Emits relay_info_ready with {url, info, warnings} or {url, error}
+        #[qinvokable]
+        fn get_relay_info(self: Pin<&mut AppController>, url: &QString);
+        
+        /// Look up a relay's payment requirement from NIP-11 (async)
+        /// Emits relay_payment_info_ready with {url, paymentsUrl, admissionSats, subscriptionSats, subscriptionPeriodSecs, paidUntil} or {} if free
+        #[qinvokable]
+        fn get_relay_payment_info(self: Pin<&mut AppController>, url: &QString);
+        
+        /// Pay a relay via NWC if it offers an invoice, then retest write access and
+        /// resend events it rejected for payment (async)
+        /// Emits relay_payment_finished
+        #[qinvokable]
+        fn pay_relay(self: Pin<&mut AppController>, url: &QString);
+        
+        /// Send the newest profile, relay list and DM relay list to all write relays again (async)
+        /// Emits rebroadcast_finished
+        #[qinvokable]
+        fn rebroadcast_profile(self: Pin<&mut AppController>);
+        
         /// Add a relay
         #[qinvokable]
         fn add_relay(self: Pin<&mut AppController>, url: &QString) -> bool;
@@ -110,9 +164,217 @@ pub mod qobject {
         #[qinvokable]
         fn reset_relays_to_default(self: Pin<&mut AppController>);
         
+        /// Get each configured relay's roles as a JSON object {url: {read, write, dm}}
+        #[qinvokable]
+        fn get_relay_roles(self: Pin<&mut AppController>) -> QString;
+        
+        /// Mark a relay for reading, writing and/or receiving DMs (NIP-17)
+        #[qinvokable]
+        fn set_relay_roles(self: Pin<&mut AppController>, url: &QString, read: bool, write: bool, dm: bool) -> bool;
+        
         /// Minimize to system tray
+        /// Emits tray_action_requested("hide") when a tray icon is available
         #[qinvokable]
         fn minimize_to_tray(self: Pin<&mut AppController>);
+        
+        /// Register the tray icon (async); sets tray_available on success
+        /// Tray clicks and menu picks are emitted as tray_action_requested
+        #[qinvokable]
+        fn enable_tray(self: Pin<&mut AppController>);
+        
+        /// Tell the tray whether the window is shown; hidden windows also pause periodic refreshes
+        #[qinvokable]
+        fn set_window_visible(self: Pin<&mut AppController>, visible: bool);
+        
+        /// Start the shared refresh scheduler; emits refresh_due for each due task
+        #[qinvokable]
+        fn start_refresh_scheduler(self: Pin<&mut AppController>);
+        
+        /// Enable or disable a periodic refresh ("feed", "stats")
+        #[qinvokable]
+        fn set_refresh_active(self: Pin<&mut AppController>, task: &QString, active: bool);
+        
+        /// Get the seconds between background notification/DM polls
+        #[qinvokable]
+        fn get_background_poll_interval(self: &AppController) -> i32;
+        
+        /// Set the seconds between background notification/DM polls; applied on the next tick
+        #[qinvokable]
+        fn set_background_poll_interval(self: Pin<&mut AppController>, secs: i32);
+        
+        /// Get whether closing the window hides it to the tray
+        #[qinvokable]
+        fn get_close_to_tray(self: &AppController) -> bool;
+        
+        /// Set whether closing the window hides it to the tray
+        #[qinvokable]
+        fn set_close_to_tray(self: Pin<&mut AppController>, enabled: bool);
+        
+        /// Check the release endpoint for a newer version (async)
+        /// Does nothing if update checks are disabled unless `force` is set
+        /// Emits update_available when a newer release exists
+        #[qinvokable]
+        fn check_for_updates(self: Pin<&mut AppController>, force: bool);
+        
+        /// Get whether automatic update checks are enabled
+        #[qinvokable]
+        fn get_check_for_updates(self: &AppController) -> bool;
+        
+        /// Enable or disable automatic update checks
+        #[qinvokable]
+        fn set_check_for_updates(self: Pin<&mut AppController>, enabled: bool);
+        
+        /// Get concurrency limits as JSON {relayFetches, backgroundThreads, requestsPerHost}
+        #[qinvokable]
+        fn get_concurrency_limits(self: &AppController) -> QString;
+        
+        /// Set concurrency limits (advanced settings); applied immediately
+        #[qinvokable]
+        fn set_concurrency_limits(self: Pin<&mut AppController>, relay_fetches: i32, background_threads: i32, requests_per_host: i32);
+        
+        /// Get relay timeouts as JSON {fetchSecs, connectSecs, reconnectMaxSecs}
+        #[qinvokable]
+        fn get_network_timeouts(self: &AppController) -> QString;
+        
+        /// Set relay query/connect timeouts and the reconnect backoff cap; applied immediately
+        #[qinvokable]
+        fn set_network_timeouts(self: Pin<&mut AppController>, fetch_secs: i32, connect_secs: i32, reconnect_max_secs: i32);
+        
+        /// Get proxy settings as JSON {url, onionOnly}
+        #[qinvokable]
+        fn get_proxy_settings(self: &AppController) -> QString;
+        
+        /// Set the SOCKS5/HTTP proxy (empty URL = direct); returns an error message or ""
+        /// New connections use it right away; relays already connected keep theirs until restart
+        #[qinvokable]
+        fn set_proxy_settings(self: Pin<&mut AppController>, url: &QString, onion_only: bool) -> QString;
+        
+        /// Route clicks on desktop notifications to notification_activated
+        #[qinvokable]
+        fn enable_desktop_notifications(self: Pin<&mut AppController>);
+        
+        /// Deliver queued and future deep links as deep_link_opened (call after login)
+        #[qinvokable]
+        fn enable_deep_links(self: Pin<&mut AppController>);
+        
+        /// Emit activation_requested when the app is launched again
+        #[qinvokable]
+        fn enable_single_instance(self: Pin<&mut AppController>);
+        
+        /// Tell the notifier whether the window has focus (notifications only show in the background)
+        #[qinvokable]
+        fn set_window_active(self: Pin<&mut AppController>, active: bool);
+        
+        /// Get desktop notification toggles as JSON {mention, dm, zap}
+        #[qinvokable]
+        fn get_desktop_notify_settings(self: &AppController) -> QString;
+        
+        /// Enable or disable desktop notifications for a kind ("mention", "dm", "zap")
+        #[qinvokable]
+        fn set_desktop_notify(self: Pin<&mut AppController>, kind: &QString, enabled: bool);
+        
+        /// Get whether DM typing indicators and read receipts are shared
+        #[qinvokable]
+        fn get_dm_presence(self: &AppController) -> bool;
+        
+        /// Share (and show) DM typing indicators and read receipts
+        #[qinvokable]
+        fn set_dm_presence(self: Pin<&mut AppController>, enabled: bool);
+        
+        /// Get whether images sent in DMs are encrypted before upload
+        #[qinvokable]
+        fn get_dm_encrypt_attachments(self: &AppController) -> bool;
+        
+        /// Encrypt (or not) images sent in DMs before upload
+        #[qinvokable]
+        fn set_dm_encrypt_attachments(self: Pin<&mut AppController>, enabled: bool);
+        
+        /// Start the hourly exchange rate refresh (no-op if already running)
+        #[qinvokable]
+        fn start_exchange_rate_updates(self: Pin<&mut AppController>);
+        
+        /// Get fiat display settings as JSON {currency, currencies}
+        /// currency is "" when fiat display is off
+        #[qinvokable]
+        fn get_fiat_settings(self: &AppController) -> QString;
+        
+        /// Set the fiat display currency ("" for sats only)
+        #[qinvokable]
+        fn set_fiat_currency(self: Pin<&mut AppController>, currency: &QString);
+        
+        /// Fiat equivalent of an amount of sats (e.g. "≈ $1.40"), or "" if unavailable
+        #[qinvokable]
+        fn format_fiat(self: &AppController, sats: i64) -> QString;
+        
+        /// Get web-of-trust settings as JSON {threshold, action, scored, computedAt}
+        /// threshold 0 means off; scored is the number of accounts with a hop distance
+        #[qinvokable]
+        fn get_wot_settings(self: &AppController) -> QString;
+        
+        /// Set the web-of-trust radius (0-2) and action ("hide" or "demote")
+        #[qinvokable]
+        fn set_wot_settings(self: Pin<&mut AppController>, threshold: i32, action: &QString);
+        
+        /// Cache diagnostics as JSON {caches: [{name, entries, approxBytes, hits, misses, hitRatio}],
+        /// database: {path, files, bytes, memoryCapacity}, media: {path, files, bytes, limitBytes}, residentBytes}
+        #[qinvokable]
+        fn get_cache_stats(self: &AppController) -> QString;
+        
+        /// Drop in-memory caches and reset hit/miss counters (the database is kept)
+        #[qinvokable]
+        fn clear_caches(self: Pin<&mut AppController>);
+        
+        /// Local file URL for an avatar or media URL if it is in the disk cache
+        /// Otherwise returns the URL unchanged and downloads it in the background;
+        /// media_cached fires when the local copy is ready
+        #[qinvokable]
+        fn cached_media_url(self: &AppController, url: &QString) -> QString;
+        
+        /// Size cap for the media cache in MB
+        #[qinvokable]
+        fn get_media_cache_limit(self: &AppController) -> i32;
+        
+        /// Set the media cache size cap in MB and trim the cache to it
+        #[qinvokable]
+        fn set_media_cache_limit(self: Pin<&mut AppController>, megabytes: i32);
+        
+        /// Get whether link preview images are checked and cached while the preview loads
+        #[qinvokable]
+        fn get_proxy_link_preview_images(self: &AppController) -> bool;
+        
+        /// Check and cache link preview images up front (or let them load when shown)
+        #[qinvokable]
+        fn set_proxy_link_preview_images(self: Pin<&mut AppController>, enabled: bool);
+        
+        /// Get whether data saver mode is on (media and previews load on tap)
+        #[qinvokable]
+        fn get_data_saver(self: &AppController) -> bool;
+        
+        /// Turn data saver mode on or off; applies to notes loaded afterwards
+        #[qinvokable]
+        fn set_data_saver(self: Pin<&mut AppController>, enabled: bool);
+        
+        /// Get whether published events are tagged with the client name
+        #[qinvokable]
+        fn get_client_tag(self: &AppController) -> bool;
+        
+        /// Tag published events with the client name (NIP-89), or stop doing so
+        #[qinvokable]
+        fn set_client_tag(self: Pin<&mut AppController>, enabled: bool);
+        
+        /// Delete all cached avatars and media from disk
+        #[qinvokable]
+        fn clear_media_cache(self: Pin<&mut AppController>) -> bool;
+        
+        /// Write the account's events, settings and (with include_dms and a local key)
+        /// decrypted DMs to an NDJSON archive (async); emits archive_finished
+        #[qinvokable]
+        fn export_archive(self: Pin<&mut AppController>, file_path: &QString, include_dms: bool);
+        
+        /// Load an archive of this account into the local database (async); emits archive_finished
+        /// republish sends its events to the write relays, restore_settings applies its settings
+        #[qinvokable]
+        fn import_archive(self: Pin<&mut AppController>, file_path: &QString, republish: bool, restore_settings: bool);
     }
 
     // Signals are declared in the extern block
@@ -145,9 +407,88 @@ pub mod qobject {
         #[qsignal]
         fn credentials_saved(self: Pin<&mut AppController>);
         
+        /// Emitted when check_saved_credentials has looked in the file and the keyring
+        #[qsignal]
+        fn saved_credentials_checked(self: Pin<&mut AppController>, has_credentials: bool, in_keyring: bool);
+        
+        /// Emitted with the nsec from reveal_nsec, or an error
+        #[qsignal]
+        fn nsec_revealed(self: Pin<&mut AppController>, nsec: &QString, error: &QString);
+        
         /// Emitted when loading status text changes
         #[qsignal]
         fn loading_status_changed(self: Pin<&mut AppController>, status: &QString);
+        
+        /// Emitted when a relay health probe completes
+        /// report_json contains: {url, healthy, connected, canRead, canWrite, connectMs, readMs, info, error}
+        #[qsignal]
+        fn relay_probe_finished(self: Pin<&mut AppController>, url: &QString, report_json: &QString);
+        
+        /// Emitted when a relay's NIP-11 document has been looked up
+        #[qsignal]
+        fn relay_info_ready(self: Pin<&mut AppController>, url: &QString, info_json: &QString);
+        
+        /// Emitted when a relay's payment requirement has been looked up
+        #[qsignal]
+        fn relay_payment_info_ready(self: Pin<&mut AppController>, url: &QString, info_json: &QString);
+        
+        /// Emitted when a relay payment attempt finishes
+        /// result_json contains: {paid, canWrite, paidUntil, paymentsUrl, writeError, resent, error}
+        /// paid=false with a paymentsUrl means the relay only takes payment on its website
+        #[qsignal]
+        fn relay_payment_finished(self: Pin<&mut AppController>, url: &QString, result_json: &QString);
+        
+        /// Emitted when rebroadcast_profile finishes
+        /// result_json contains: {events: [{kind, accepted, total}], error}
+        #[qsignal]
+        fn rebroadcast_finished(self: Pin<&mut AppController>, result_json: &QString);
+        
+        /// Emitted after logout cleared all session state
+        /// Controllers should call their reset() before the next login
+        #[qsignal]
+        fn session_reset(self: Pin<&mut AppController>);
+        
+        /// Emitted when a newer release is available
+        #[qsignal]
+        fn update_available(self: Pin<&mut AppController>, version: &QString, notes_url: &QString);
+        
+        /// Emitted when a desktop notification is clicked
+        /// kind is "mention", "dm" or "zap"; target is a note id or peer pubkey
+        #[qsignal]
+        fn notification_activated(self: Pin<&mut AppController>, kind: &QString, target: &QString);
+        
+        /// Emitted for tray icon actions
+        /// action is "show", "hide", "notifications", "messages" or "quit"
+        #[qsignal]
+        fn tray_action_requested(self: Pin<&mut AppController>, action: &QString);
+        
+        /// Emitted for nostr: links from the command line or another launch
+        /// kind is "profile" (hex pubkey), "thread" (hex id), "article" (nostr:naddr URI)
+        /// or "signer" (nostrconnect:// / nostrsigner: URI)
+        #[qsignal]
+        fn deep_link_opened(self: Pin<&mut AppController>, kind: &QString, target: &QString);
+        
+        /// Emitted when a second launch asks this instance to show its window
+        #[qsignal]
+        fn activation_requested(self: Pin<&mut AppController>);
+        
+        /// Emitted when exchange rates or the display currency change
+        #[qsignal]
+        fn exchange_rate_updated(self: Pin<&mut AppController>);
+        
+        /// Emitted when a periodic refresh is due
+        /// Tasks due close together are emitted in the same tick
+        #[qsignal]
+        fn refresh_due(self: Pin<&mut AppController>, task: &QString);
+        
+        /// Emitted when a URL requested through cached_media_url has been stored on disk
+        #[qsignal]
+        fn media_cached(self: Pin<&mut AppController>, url: &QString, local_url: &QString);
+        
+        /// Emitted when an archive export or import finishes
+        /// result_json contains: {action, events, dms, republished, settingsRestored, error}
+        #[qsignal]
+        fn archive_finished(self: Pin<&mut AppController>, result_json: &QString);
     }
 
     impl cxx_qt::Threading for AppController {}
@@ -159,10 +500,32 @@ use cxx_qt::Threading;
 use cxx_qt_lib::QString;
 use tokio::sync::Mutex;
 use crate::signer::SignerClient;
+use crate::core::cache_stats;
 use crate::core::credentials::CredentialManager;
+use crate::core::deep_link;
+use crate::core::desktop_notify::{self, NotifyKind};
+use crate::core::fiat;
+use crate::core::instance;
+use crate::core::key_backup;
+use crate::core::media_cache;
+use crate::core::refresh::{self, RefreshTask};
+use crate::core::tray::{self, TrayAction};
 use crate::nostr::GLOBAL_NWC_MANAGER;
-use crate::bridge::feed_bridge::set_feed_nsec;
-use crate::bridge::dm_bridge::set_dm_nsec;
+use crate::nostr::archive;
+use crate::nostr::database::NostrDbManager;
+use crate::nostr::handlers;
+use crate::nostr::profile::ProfileCache;
+use crate::nostr::profile_store;
+use crate::nostr::publish;
+use crate::nostr::relay::{self, GLOBAL_RELAY_MANAGER};
+use crate::nostr::relay_payment;
+use crate::nostr::wot;
+use crate::bridge::feed_bridge::{set_feed_nsec, get_feed_nsec, reset_feed_state, shared_relay_manager, sign_event_builder, view_cache_stats, clear_view_caches};
+use crate::bridge::dm_bridge::{set_dm_nsec, reset_dm_state, dm_presence_enabled, set_dm_presence_enabled};
+use crate::bridge::profile_bridge::reset_profile_state;
+use crate::bridge::signer_bridge::reset_signer_state;
+use crate::bridge::communities_bridge::reset_communities_state;
+use crate::bridge::wallet_bridge::reset_wallet_state;
 
 // Global signer client instance
 lazy_static::lazy_static! {
@@ -170,6 +533,30 @@ lazy_static::lazy_static! {
     static ref TOKIO_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
 }
 
+/// Clear all global session state: signers, keys, relay/DM/NWC connections
+/// and every per-user cache, so nothing leaks into the next login
+pub fn reset_session() {
+    tracing::info!("Resetting session state");
+    
+    TOKIO_RUNTIME.block_on(async {
+        *SIGNER_CLIENT.lock().await = None;
+        GLOBAL_NWC_MANAGER.lock().await.disconnect().await;
+    });
+    
+    reset_feed_state();
+    reset_dm_state();
+    reset_profile_state();
+    reset_signer_state();
+    reset_communities_state();
+    reset_wallet_state();
+    // Queued and paywalled events were signed by the previous account
+    publish::reset();
+    // Links opened while logged out wait for the next login
+    deep_link::clear_handler();
+    // Trust scores belong to the previous account
+    wot::set_owner(None);
+}
+
 /// Rust implementation of AppController
 pub struct AppControllerRust {
     current_screen: QString,
@@ -184,9 +571,12 @@ pub struct AppControllerRust {
     tray_available: bool,
     signer_available: bool,
     wallet_balance_sats: i64,
+    wallet_balance_fiat: QString,
     show_global_feed: bool,
     has_saved_credentials: bool,
+    credentials_in_keyring: bool,
     nwc_connected: bool,
+    backup_reminder_due: bool,
 }
 
 impl Default for AppControllerRust {
@@ -194,9 +584,10 @@ impl Default for AppControllerRust {
         // Load config to get saved settings
         let config = crate::core::config::Config::load();
         
-        // Check if encrypted credentials exist
+        // Check if encrypted credentials exist; the keyring is asked later,
+        // off the GUI thread, by check_saved_credentials
         let has_creds = CredentialManager::new()
-            .map(|cm| cm.has_credentials())
+            .map(|cm| cm.has_credentials_file())
             .unwrap_or(false);
         
         Self {
@@ -212,9 +603,12 @@ impl Default for AppControllerRust {
             tray_available: false,
             signer_available: false,
             wallet_balance_sats: 0,
+            wallet_balance_fiat: QString::from(""),
             show_global_feed: config.show_global_feed,
             has_saved_credentials: has_creds,
+            credentials_in_keyring: false,
             nwc_connected: false,
+            backup_reminder_due: false,
         }
     }
 }
@@ -230,6 +624,14 @@ impl qobject::AppController {
         match generate_keys() {
             Ok((nsec, npub)) => {
                 tracing::info!("Account created successfully: {}", npub);
+                
+                // The nsec is only shown once; remind the user until they back it up
+                let mut config = crate::core::config::Config::load();
+                key_backup::mark_new_key(&mut config);
+                if let Err(e) = config.save() {
+                    tracing::error!("Failed to save config: {}", e);
+                }
+                
                 self.as_mut().set_is_loading(false);
                 self.as_mut().account_created(&QString::from(&nsec), &QString::from(&npub));
             }
@@ -308,9 +710,10 @@ impl qobject::AppController {
                 self.as_mut().set_npub(QString::from(&npub));
                 self.as_mut().set_logged_in(true);
                 self.as_mut().set_current_screen(QString::from("feed"));
-                self.as_mut().set_display_name(QString::from("Anonymous")); // Will be fetched from profile
+                self.as_mut().set_display_name(QString::from("Anonymous"));
                 self.as_mut().set_is_loading(false);
                 self.as_mut().login_complete(true, &QString::from(""));
+                self.as_mut().load_own_profile();
                 tracing::info!("Login via signer successful: {}", npub);
             }
             Err(e) => {
@@ -344,7 +747,9 @@ impl qobject::AppController {
                 self.as_mut().set_current_screen(QString::from("feed"));
                 self.as_mut().set_display_name(QString::from("Anonymous"));
                 self.as_mut().set_is_loading(false);
+                self.as_mut().refresh_backup_reminder();
                 self.as_mut().login_complete(true, &QString::from(""));
+                self.as_mut().load_own_profile();
                 
                 tracing::info!("Login with nsec successful: {}", npub);
             }
@@ -358,20 +763,33 @@ impl qobject::AppController {
     }
     
     /// Check for saved credentials (just checks if they exist)
+    /// The keyring is asked in the background; emits saved_credentials_checked
     pub fn check_saved_credentials(mut self: Pin<&mut Self>) {
         tracing::info!("Checking for saved credentials...");
         
-        match CredentialManager::new() {
-            Ok(creds) => {
-                let has_creds = creds.has_credentials();
-                self.as_mut().set_has_saved_credentials(has_creds);
-                tracing::info!("Saved credentials exist: {}", has_creds);
-            }
+        let creds = match CredentialManager::new() {
+            Ok(creds) => creds,
             Err(e) => {
                 tracing::warn!("Credential manager unavailable: {}", e);
                 self.as_mut().set_has_saved_credentials(false);
+                self.as_mut().set_credentials_in_keyring(false);
+                self.as_mut().saved_credentials_checked(false, false);
+                return;
             }
-        }
+        };
+        self.as_mut().set_has_saved_credentials(creds.has_credentials_file());
+        
+        let qt_thread = self.qt_thread();
+        std::thread::spawn(move || {
+            let in_keyring = creds.in_keyring();
+            let has_creds = in_keyring || creds.has_credentials_file();
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().set_has_saved_credentials(has_creds);
+                qobject.as_mut().set_credentials_in_keyring(in_keyring);
+                tracing::info!("Saved credentials exist: {} (keyring: {})", has_creds, in_keyring);
+                qobject.as_mut().saved_credentials_checked(has_creds, in_keyring);
+            });
+        });
     }
     
     /// Login with password (for stored encrypted credentials)
@@ -384,164 +802,320 @@ impl qobject::AppController {
         self.as_mut().set_loading_status_text(QString::from("Decrypting credentials..."));
         self.as_mut().loading_status_changed(&QString::from("Decrypting credentials..."));
         
-        match CredentialManager::new() {
-            Ok(creds) => {
-                match creds.get_nsec(&password_str) {
-                    Ok(Some(nsec)) => {
-                        tracing::info!("Successfully decrypted credentials");
-                        self.as_mut().set_loading_status_text(QString::from("Credentials decrypted, logging in..."));
-                        self.as_mut().loading_status_changed(&QString::from("Credentials decrypted, logging in..."));
+        let qt_thread = self.qt_thread();
+        
+        // Key derivation and the keyring both block, the keyring possibly on its unlock dialog
+        std::thread::spawn(move || {
+            let result = CredentialManager::new().and_then(|creds| {
+                let Some(nsec) = creds.get_nsec(&password_str)? else {
+                    return Ok(None);
+                };
+                let nwc_uri = creds.get_nwc(&password_str).ok().flatten();
+                Ok(Some((nsec, nwc_uri)))
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().finish_saved_login(result);
+            });
+        });
+    }
+    
+    /// Log in with credentials read by login_with_password
+    fn finish_saved_login(mut self: Pin<&mut Self>, result: Result<Option<(String, Option<String>)>, String>) {
+        match result {
+            Ok(Some((nsec, nwc_uri))) => {
+                tracing::info!("Successfully decrypted credentials");
+                self.as_mut().set_loading_status_text(QString::from("Credentials decrypted, logging in..."));
+                self.as_mut().loading_status_changed(&QString::from("Credentials decrypted, logging in..."));
+                
+                // Use the nsec to complete login
+                match parse_nsec(&nsec) {
+                    Ok((_secret_key, pubkey, npub)) => {
+                        set_feed_nsec(Some(nsec.clone()));
+                        set_dm_nsec(Some(nsec));
                         
-                        // Use the nsec to complete login
-                        match parse_nsec(&nsec) {
-                            Ok((_secret_key, pubkey, npub)) => {
-                                set_feed_nsec(Some(nsec.clone()));
-                                set_dm_nsec(Some(nsec));
-                                
-                                self.as_mut().set_public_key(QString::from(&pubkey));
-                                self.as_mut().set_npub(QString::from(&npub));
-                                self.as_mut().set_logged_in(true);
-                                // Don't switch to feed screen yet - let feed loading complete first
-                                self.as_mut().set_display_name(QString::from("Anonymous"));
-                                self.as_mut().set_loading_status_text(QString::from("Loading your feed..."));
-                                self.as_mut().loading_status_changed(&QString::from("Loading your feed..."));
-                                // Keep is_loading true - will be set false after feed loads
-                                self.as_mut().login_complete(true, &QString::from(""));
-                                tracing::info!("Login with password successful: {}", npub);
+                        self.as_mut().set_public_key(QString::from(&pubkey));
+                        self.as_mut().set_npub(QString::from(&npub));
+                        self.as_mut().set_logged_in(true);
+                        // Don't switch to feed screen yet - let feed loading complete first
+                        self.as_mut().set_display_name(QString::from("Anonymous"));
+                        self.as_mut().set_loading_status_text(QString::from("Loading your feed..."));
+                        self.as_mut().loading_status_changed(&QString::from("Loading your feed..."));
+                        // Keep is_loading true - will be set false after feed loads
+                        self.as_mut().refresh_backup_reminder();
+                        self.as_mut().login_complete(true, &QString::from(""));
+                        self.as_mut().load_own_profile();
+                        tracing::info!("Login with password successful: {}", npub);
+                        
+                        // Try to reconnect NWC if it was saved
+                        if let Some(uri) = nwc_uri {
+                            tracing::info!("Found saved NWC, reconnecting...");
+                            let qt_thread = self.qt_thread();
+                            
+                            // Connect NWC in background without blocking
+                            std::thread::spawn(move || {
+                                let result = TOKIO_RUNTIME.block_on(async {
+                                    let mut nwc = GLOBAL_NWC_MANAGER.lock().await;
+                                    nwc.connect(&uri).await?;
+                                    let balance = nwc.balance_sats();
+                                    Ok::<_, String>(balance)
+                                });
                                 
-                                // Try to reconnect NWC if it was saved
-                                let password_for_nwc = password_str.clone();
-                                if let Ok(nwc_uri) = creds.get_nwc(&password_for_nwc) {
-                                    if let Some(uri) = nwc_uri {
-                                        tracing::info!("Found saved NWC, reconnecting...");
-                                        let qt_thread = self.qt_thread();
-                                        
-                                        // Connect NWC in background without blocking
-                                        std::thread::spawn(move || {
-                                            let result = TOKIO_RUNTIME.block_on(async {
-                                                let mut nwc = GLOBAL_NWC_MANAGER.lock().await;
-                                                nwc.connect(&uri).await?;
-                                                let balance = nwc.balance_sats();
-                                                Ok::<_, String>(balance)
-                                            });
-                                            
-                                            let _ = qt_thread.queue(move |mut qobject| {
-                                                match result {
-                                                    Ok(balance) => {
-                                                        tracing::info!("NWC reconnected, balance: {} sats", balance);
-                                                        qobject.as_mut().set_wallet_balance_sats(balance);
-                                                        qobject.as_mut().set_nwc_connected(true);
-                                                        qobject.as_mut().wallet_updated(balance);
-                                                    }
-                                                    Err(e) => {
-                                                        tracing::warn!("Failed to reconnect NWC: {}", e);
-                                                    }
-                                                }
-                                            });
-                                        });
+                                let _ = qt_thread.queue(move |mut qobject| {
+                                    match result {
+                                        Ok(balance) => {
+                                            tracing::info!("NWC reconnected, balance: {} sats", balance);
+                                            qobject.as_mut().set_wallet_balance(balance);
+                                            qobject.as_mut().set_nwc_connected(true);
+                                            qobject.as_mut().wallet_updated(balance);
+                                        }
+                                        Err(e) => {
+                                            tracing::warn!("Failed to reconnect NWC: {}", e);
+                                        }
                                     }
-                                }
-                            }
-                            Err(e) => {
-                                self.as_mut().set_error_message(QString::from(&e));
-                                self.as_mut().set_is_loading(false);
-                                self.as_mut().set_loading_status_text(QString::from(""));
-                                self.as_mut().login_complete(false, &QString::from(&e));
-                            }
+                                });
+                            });
                         }
                     }
-                    Ok(None) => {
-                        let err = "No saved credentials found";
-                        self.as_mut().set_error_message(QString::from(err));
-                        self.as_mut().set_is_loading(false);
-                        self.as_mut().set_loading_status_text(QString::from(""));
-                        self.as_mut().login_complete(false, &QString::from(err));
-                    }
                     Err(e) => {
-                        // Most likely wrong password
                         self.as_mut().set_error_message(QString::from(&e));
                         self.as_mut().set_is_loading(false);
                         self.as_mut().set_loading_status_text(QString::from(""));
                         self.as_mut().login_complete(false, &QString::from(&e));
-                        tracing::warn!("Failed to decrypt credentials: {}", e);
                     }
                 }
             }
+            Ok(None) => {
+                let err = "No saved credentials found";
+                self.as_mut().set_error_message(QString::from(err));
+                self.as_mut().set_is_loading(false);
+                self.as_mut().set_loading_status_text(QString::from(""));
+                self.as_mut().login_complete(false, &QString::from(err));
+            }
             Err(e) => {
+                // Most likely wrong password
                 self.as_mut().set_error_message(QString::from(&e));
                 self.as_mut().set_is_loading(false);
                 self.as_mut().set_loading_status_text(QString::from(""));
                 self.as_mut().login_complete(false, &QString::from(&e));
+                tracing::warn!("Failed to decrypt credentials: {}", e);
             }
         }
     }
     
+    /// Login with credentials from the system keyring
+    /// The keyring is unlocked with the login session, so no password is asked for
+    pub fn login_with_keyring(self: Pin<&mut Self>) {
+        tracing::info!("Attempting login with system keyring...");
+        self.login_with_password(&QString::from(""));
+    }
+    
+    /// Get whether credentials are saved in the system keyring
+    pub fn get_use_system_keyring(&self) -> bool {
+        crate::core::config::Config::load().use_system_keyring
+    }
+    
+    /// Enable or disable the system keyring for saved credentials
+    pub fn set_use_system_keyring(self: Pin<&mut Self>, enabled: bool) {
+        let mut config = crate::core::config::Config::load();
+        config.use_system_keyring = enabled;
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+    }
+    
     /// Save nsec with password protection
     pub fn save_credentials_with_password(mut self: Pin<&mut Self>, nsec: &QString, password: &QString) {
         let nsec_str = nsec.to_string();
         let password_str = password.to_string();
         tracing::info!("Saving credentials with password protection...");
         
-        match CredentialManager::new() {
-            Ok(creds) => {
-                match creds.save_nsec(&nsec_str, &password_str) {
-                    Ok(()) => {
-                        self.as_mut().set_has_saved_credentials(true);
-                        self.as_mut().credentials_saved();
+        let qt_thread = self.qt_thread();
+        std::thread::spawn(move || {
+            let result = CredentialManager::new().and_then(|creds| {
+                creds.save_nsec(&nsec_str, &password_str)?;
+                Ok(creds.in_keyring())
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok(in_keyring) => {
+                        qobject.as_mut().set_has_saved_credentials(true);
+                        qobject.as_mut().set_credentials_in_keyring(in_keyring);
+                        qobject.as_mut().credentials_saved();
                         tracing::info!("Credentials saved with password protection");
                     }
                     Err(e) => {
                         tracing::error!("Failed to save credentials: {}", e);
-                        self.as_mut().set_error_message(QString::from(&e));
+                        qobject.as_mut().set_error_message(QString::from(&e));
                     }
                 }
-            }
-            Err(e) => {
-                tracing::error!("Credential manager unavailable: {}", e);
-            }
-        }
+            });
+        });
     }
     
     /// Clear saved credentials
     pub fn clear_saved_credentials(mut self: Pin<&mut Self>) {
         tracing::info!("Clearing saved credentials...");
         
-        match CredentialManager::new() {
-            Ok(creds) => {
-                if let Err(e) = creds.clear() {
-                    tracing::warn!("Failed to clear credentials: {}", e);
-                } else {
-                    self.as_mut().set_has_saved_credentials(false);
-                    tracing::info!("Credentials cleared");
+        let qt_thread = self.qt_thread();
+        std::thread::spawn(move || {
+            let result = CredentialManager::new().and_then(|creds| creds.clear());
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok(()) => {
+                        qobject.as_mut().set_has_saved_credentials(false);
+                        qobject.as_mut().set_credentials_in_keyring(false);
+                        tracing::info!("Credentials cleared");
+                    }
+                    Err(e) => tracing::warn!("Failed to clear credentials: {}", e),
                 }
-            }
-            Err(e) => {
-                tracing::warn!("Credential manager unavailable: {}", e);
-            }
-        }
+            });
+        });
     }
     
-    /// Logout
-    pub fn logout(mut self: Pin<&mut Self>) {
-        tracing::info!("Logging out...");
-        
-        // Clear saved credentials
-        self.as_mut().clear_saved_credentials();
+    /// Decrypt the saved nsec for backup, checking the password and that it
+    /// belongs to the logged-in account (async); emits nsec_revealed
+    pub fn reveal_nsec(mut self: Pin<&mut Self>, password: &QString) {
+        self.as_mut().set_error_message(QString::from(""));
+        let current_pubkey = self.public_key().to_string();
+        let password = password.to_string();
+        let qt_thread = self.qt_thread();
         
-        // Clear signer client
-        TOKIO_RUNTIME.block_on(async {
-            let mut signer = SIGNER_CLIENT.lock().await;
-            *signer = None;
+        std::thread::spawn(move || {
+            let result = CredentialManager::new().and_then(|creds| {
+                let nsec = creds.reveal_nsec(&password)?
+                    .ok_or("No saved credentials - enable \"Remember me\" to back up your key here")?;
+                let (_secret_key, pubkey, _npub) = parse_nsec(&nsec)?;
+                if pubkey != current_pubkey {
+                    return Err("Saved credentials belong to a different account".to_string());
+                }
+                Ok(nsec)
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok(nsec) => {
+                        tracing::info!("Revealed nsec for backup");
+                        qobject.as_mut().nsec_revealed(&QString::from(&nsec), &QString::from(""));
+                    }
+                    Err(e) => {
+                        tracing::warn!("Failed to reveal nsec: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                        qobject.as_mut().nsec_revealed(&QString::from(""), &QString::from(&e));
+                    }
+                }
+            });
         });
-        
-        self.as_mut().set_logged_in(false);
-        self.as_mut().set_public_key(QString::from(""));
-        self.as_mut().set_npub(QString::from(""));
-        self.as_mut().set_display_name(QString::from(""));
-        self.as_mut().set_profile_picture(QString::from(""));
-        self.as_mut().set_signer_available(false);
+    }
+    
+    /// Record that the key has been backed up
+    pub fn confirm_key_backup(mut self: Pin<&mut Self>) {
+        let mut config = crate::core::config::Config::load();
+        key_backup::mark_backed_up(&mut config, chrono::Utc::now().timestamp());
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+        self.as_mut().set_backup_reminder_due(false);
+    }
+    
+    /// Dismiss the backup reminder for one interval
+    pub fn snooze_backup_reminder(mut self: Pin<&mut Self>) {
+        let mut config = crate::core::config::Config::load();
+        key_backup::snooze(&mut config, chrono::Utc::now().timestamp());
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+        self.as_mut().set_backup_reminder_due(false);
+    }
+    
+    /// Show the user's name and picture, from the last session's copy first
+    /// and then from the relays, and pick up their NIP-65 relay list
+    /// Both are kept in nostrdb for the next startup
+    fn load_own_profile(self: Pin<&mut Self>) {
+        let Ok(pk) = nostr_sdk::PublicKey::parse(&self.public_key().to_string()) else {
+            return;
+        };
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            let db = NostrDbManager::global().ok();
+            
+            // Cached copies show up before the relays answer
+            if let Some(event) = profile_store::cached(&pk) {
+                apply_own_profile(&qt_thread, pk, &event);
+            }
+            let cached_list = db.as_ref()
+                .and_then(|db| db.query_author_events(&[nostr_sdk::Kind::RelayList.as_u16()], &[pk], 4).ok())
+                .and_then(|events| events.into_iter().max_by_key(|e| e.created_at));
+            if let Some(event) = cached_list.as_ref() {
+                TOKIO_RUNTIME.block_on(adopt_relay_list(event));
+            }
+            
+            let result = TOKIO_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                let profile = profile_store::refresh(manager.client(), &[pk]).await.into_iter().next();
+                let relay_list = manager.fetch_relay_list(&pk).await.unwrap_or_else(|e| {
+                    tracing::warn!("{}", e);
+                    None
+                });
+                Ok::<_, String>((profile, relay_list))
+            });
+            
+            match result {
+                Ok((profile, relay_list)) => {
+                    if let Some(event) = profile {
+                        apply_own_profile(&qt_thread, pk, &event);
+                    }
+                    if let Some(event) = relay_list {
+                        if let Some(db) = db.as_ref() {
+                            let _ = db.ingest_event(&event);
+                        }
+                        TOKIO_RUNTIME.block_on(adopt_relay_list(&event));
+                    }
+                }
+                Err(e) => tracing::warn!("Failed to load own profile: {}", e),
+            }
+        });
+    }
+    
+    /// Re-evaluate whether the backup reminder is due
+    fn refresh_backup_reminder(self: Pin<&mut Self>) {
+        let config = crate::core::config::Config::load();
+        let due = key_backup::reminder_due(&config, chrono::Utc::now().timestamp());
+        self.set_backup_reminder_due(due);
+    }
+    
+    /// Logout
+    pub fn logout(mut self: Pin<&mut Self>) {
+        tracing::info!("Logging out...");
+        
+        // Clear saved credentials
+        self.as_mut().clear_saved_credentials();
+        
+        // Backup reminders belong to the account being logged out
+        let mut config = crate::core::config::Config::load();
+        key_backup::clear(&mut config);
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+        
+        // Drop every global holding the previous account's keys, connections and data
+        reset_session();
+        
+        self.as_mut().set_logged_in(false);
+        self.as_mut().set_public_key(QString::from(""));
+        self.as_mut().set_npub(QString::from(""));
+        self.as_mut().set_display_name(QString::from(""));
+        self.as_mut().set_profile_picture(QString::from(""));
+        self.as_mut().set_signer_available(false);
         self.as_mut().set_has_saved_credentials(false);
+        self.as_mut().set_wallet_balance(0);
+        self.as_mut().set_nwc_connected(false);
+        self.as_mut().set_backup_reminder_due(false);
         self.as_mut().set_current_screen(QString::from("login"));
+        self.as_mut().session_reset();
     }
     
     /// Navigate to a screen
@@ -578,7 +1152,7 @@ impl qobject::AppController {
                 match result {
                     Ok(balance) => {
                         tracing::info!("NWC connected, balance: {} sats", balance);
-                        qobject.as_mut().set_wallet_balance_sats(balance);
+                        qobject.as_mut().set_wallet_balance(balance);
                         qobject.as_mut().set_nwc_connected(true);
                         qobject.as_mut().set_is_loading(false);
                         qobject.as_mut().wallet_updated(balance);
@@ -613,22 +1187,24 @@ impl qobject::AppController {
                 Ok::<_, String>((balance, uri_str))
             });
             
+            // Save NWC URI to encrypted storage
+            if let Ok((_, uri)) = &result {
+                if let Ok(creds) = CredentialManager::new() {
+                    if let Err(e) = creds.save_nwc(uri, &password_str) {
+                        tracing::warn!("Failed to save NWC: {}", e);
+                        // Still connected, just not persisted
+                    } else {
+                        tracing::info!("NWC URI saved to encrypted storage");
+                    }
+                }
+            }
+            
             let _ = qt_thread.queue(move |mut qobject| {
                 match result {
-                    Ok((balance, uri)) => {
+                    Ok((balance, _uri)) => {
                         tracing::info!("NWC connected, balance: {} sats", balance);
                         
-                        // Save NWC URI to encrypted storage
-                        if let Ok(creds) = CredentialManager::new() {
-                            if let Err(e) = creds.save_nwc(&uri, &password_str) {
-                                tracing::warn!("Failed to save NWC: {}", e);
-                                // Still connected, just not persisted
-                            } else {
-                                tracing::info!("NWC URI saved to encrypted storage");
-                            }
-                        }
-                        
-                        qobject.as_mut().set_wallet_balance_sats(balance);
+                        qobject.as_mut().set_wallet_balance(balance);
                         qobject.as_mut().set_nwc_connected(true);
                         qobject.as_mut().set_is_loading(false);
                         qobject.as_mut().wallet_updated(balance);
@@ -657,13 +1233,13 @@ impl qobject::AppController {
                 nwc.disconnect().await;
             });
             
+            // Clear saved NWC URI
+            if let Ok(creds) = CredentialManager::new() {
+                let _ = creds.clear_nwc();
+            }
+            
             let _ = qt_thread.queue(move |mut qobject| {
-                // Clear saved NWC URI
-                if let Ok(creds) = CredentialManager::new() {
-                    let _ = creds.clear_nwc();
-                }
-                
-                qobject.as_mut().set_wallet_balance_sats(0);
+                qobject.as_mut().set_wallet_balance(0);
                 qobject.as_mut().set_nwc_connected(false);
                 qobject.as_mut().wallet_updated(0);
                 tracing::info!("NWC wallet disconnected");
@@ -698,7 +1274,95 @@ impl qobject::AppController {
     
     /// Minimize to system tray
     pub fn minimize_to_tray(self: Pin<&mut Self>) {
-        tracing::info!("Minimize to tray requested");
+        if !*self.tray_available() {
+            tracing::info!("Minimize to tray requested but no tray is available");
+            return;
+        }
+        self.tray_action_requested(&QString::from(TrayAction::Hide.as_str()));
+    }
+    
+    /// Register the tray icon in the background
+    pub fn enable_tray(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            let action_thread = qt_thread.clone();
+            let handler: tray::ActionHandler = Box::new(move |action| {
+                let _ = action_thread.queue(move |mut qobject| {
+                    qobject.as_mut().tray_action_requested(&QString::from(action.as_str()));
+                });
+            });
+            
+            let available = match tray::start(handler) {
+                Ok(()) => true,
+                Err(e) => {
+                    tracing::info!("Tray icon unavailable: {}", e);
+                    false
+                }
+            };
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().set_tray_available(available);
+            });
+        });
+    }
+    
+    /// Record window visibility for the tray menu and the refresh scheduler
+    pub fn set_window_visible(self: Pin<&mut Self>, visible: bool) {
+        tray::set_window_visible(visible);
+        // Nothing to refresh for while nobody is looking
+        refresh::set_paused(!visible);
+    }
+    
+    /// Start the shared refresh scheduler
+    pub fn start_refresh_scheduler(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        refresh::start(move |tasks| {
+            let _ = qt_thread.queue(move |mut qobject| {
+                for task in tasks {
+                    qobject.as_mut().refresh_due(&QString::from(task.as_str()));
+                }
+            });
+        });
+    }
+    
+    /// Enable or disable a periodic refresh
+    pub fn set_refresh_active(self: Pin<&mut Self>, task: &QString, active: bool) {
+        match RefreshTask::from_str(&task.to_string()) {
+            Some(task) => refresh::set_active(task, active),
+            None => tracing::warn!("Unknown refresh task: {}", task),
+        }
+    }
+    
+    /// Get the background poll interval in seconds
+    pub fn get_background_poll_interval(&self) -> i32 {
+        refresh::background_interval().as_secs() as i32
+    }
+    
+    /// Set and save the background poll interval
+    pub fn set_background_poll_interval(self: Pin<&mut Self>, secs: i32) {
+        let interval = refresh::set_background_interval(secs.max(0) as u64);
+        
+        let mut config = crate::core::config::Config::load();
+        config.background_poll_secs = interval.as_secs();
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+    }
+    
+    /// Get whether closing the window hides it to the tray
+    pub fn get_close_to_tray(&self) -> bool {
+        crate::core::config::Config::load().close_to_tray
+    }
+    
+    /// Set whether closing the window hides it to the tray
+    pub fn set_close_to_tray(self: Pin<&mut Self>, enabled: bool) {
+        let mut config = crate::core::config::Config::load();
+        config.close_to_tray = enabled;
+        
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
     }
     
     /// Get configured relays as JSON array
@@ -708,6 +1372,717 @@ impl qobject::AppController {
         QString::from(&json)
     }
     
+    /// Probe a relay's health in the background
+    pub fn probe_relay(self: Pin<&mut Self>, url: &QString) {
+        let url_str = url.to_string().trim().to_string();
+        tracing::info!("Probing relay: {}", url_str);
+        
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            let report = TOKIO_RUNTIME.block_on(crate::nostr::relay_health::probe_relay(&url_str));
+            let report_json = report.to_json();
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().relay_probe_finished(&QString::from(&url_str), &QString::from(&report_json));
+            });
+        });
+    }
+    
+    /// Look up a relay's NIP-11 document and what it can't do for us in the background
+    pub fn get_relay_info(self: Pin<&mut Self>, url: &QString) {
+        let url_str = url.to_string().trim().to_string();
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            let json = TOKIO_RUNTIME.block_on(async {
+                match crate::nostr::relay_info::cached_relay_info(&url_str).await {
+                    Ok(info) => serde_json::json!({
+                        "url": url_str,
+                        "info": info.to_json_value(),
+                        "warnings": info.capability_warnings(&url_str),
+                    }),
+                    Err(e) => {
+                        tracing::debug!("No NIP-11 document for {}: {}", url_str, e);
+                        serde_json::json!({ "url": url_str, "error": e })
+                    }
+                }
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().relay_info_ready(&QString::from(&url_str), &QString::from(&json.to_string()));
+            });
+        });
+    }
+    
+    /// Look up a relay's payment requirement in the background
+    pub fn get_relay_payment_info(self: Pin<&mut Self>, url: &QString) {
+        let url_str = url.to_string().trim().to_string();
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            let json = TOKIO_RUNTIME.block_on(async {
+                match crate::nostr::relay_info::cached_relay_info(&url_str).await {
+                    Ok(info) => relay_payment::payment_requirement(&url_str, &info)
+                        .map(|req| serde_json::to_value(req).unwrap_or_default())
+                        .unwrap_or_else(|| serde_json::json!({})),
+                    Err(e) => serde_json::json!({ "error": e }),
+                }
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().relay_payment_info_ready(&QString::from(&url_str), &QString::from(&json.to_string()));
+            });
+        });
+    }
+    
+    /// Pay a relay's admission/subscription via NWC and check we can write afterwards
+    pub fn pay_relay(self: Pin<&mut Self>, url: &QString) {
+        let url_str = url.to_string().trim().to_string();
+        let Ok(user_pk) = nostr_sdk::PublicKey::parse(&self.public_key().to_string()) else {
+            return;
+        };
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            let result = TOKIO_RUNTIME.block_on(async {
+                let info = crate::nostr::relay_info::fetch_relay_info(&url_str).await?;
+                let Some(requirement) = relay_payment::payment_requirement(&url_str, &info) else {
+                    return Err("Relay does not require payment".to_string());
+                };
+                let payments_url = requirement.payments_url.clone()
+                    .ok_or("Relay did not publish a payments URL")?;
+                
+                let amount = requirement.amount_sats();
+                let Some(invoice) = relay_payment::request_invoice(&payments_url, &user_pk, amount).await? else {
+                    // Web-only payment flow - the UI opens payments_url
+                    return Ok(serde_json::json!({ "paid": false, "paymentsUrl": payments_url }));
+                };
+                let paid_sats = relay_payment::check_invoice_amount(&invoice, amount)?;
+                
+                let preimage = {
+                    let mut nwc = GLOBAL_NWC_MANAGER.lock().await;
+                    if !nwc.is_connected() {
+                        return Err("NWC wallet not connected. Please connect your wallet in Settings.".to_string());
+                    }
+                    nwc.pay_invoice(&invoice).await?
+                };
+                
+                let period = requirement.admission_sats.is_none()
+                    .then_some(requirement.subscription_period_secs)
+                    .flatten();
+                let record = relay_payment::record_payment(&url_str, paid_sats, period, Some(preimage))?;
+                
+                // Relays may take a moment to whitelist the pubkey after settlement
+                tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+                let event = sign_event_builder(relay_payment::write_test_builder(), user_pk).await?;
+                let write = relay_payment::test_write(&url_str, &event).await;
+                
+                // Reconnect it in the main pool and resend what it turned down for payment
+                let mut resent = 0;
+                if write.is_ok() {
+                    if let Ok(manager) = shared_relay_manager().await {
+                        if let Err(e) = manager.client().connect_relay(url_str.as_str()).await {
+                            tracing::debug!("Paid relay {} not reconnected: {}", url_str, e);
+                        }
+                        resent = publish::release_paid(manager.client(), &url_str).await.len();
+                    }
+                }
+                
+                Ok(serde_json::json!({
+                    "paid": true,
+                    "resent": resent,
+                    "paidUntil": record.paid_until,
+                    "canWrite": write.is_ok(),
+                    "writeError": write.err(),
+                    "paymentsUrl": payments_url,
+                }))
+            });
+            
+            let json = result.unwrap_or_else(|e| serde_json::json!({ "paid": false, "error": e }));
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().relay_payment_finished(&QString::from(&url_str), &QString::from(&json.to_string()));
+            });
+        });
+    }
+    
+    /// Check for a newer release in the background
+    pub fn check_for_updates(self: Pin<&mut Self>, force: bool) {
+        let config = crate::core::config::Config::load();
+        if !config.check_for_updates && !force {
+            return;
+        }
+        
+        let endpoint = config.update_endpoint.clone();
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            let result = TOKIO_RUNTIME.block_on(crate::core::updates::check_for_update(&endpoint));
+            
+            match result {
+                Ok(Some(update)) => {
+                    tracing::info!("Update available: {} ({})", update.version, update.notes_url);
+                    let _ = qt_thread.queue(move |mut qobject| {
+                        qobject.as_mut().update_available(
+                            &QString::from(&update.version),
+                            &QString::from(&update.notes_url),
+                        );
+                    });
+                }
+                Ok(None) => tracing::info!("No update available"),
+                Err(e) => tracing::warn!("Update check failed: {}", e),
+            }
+        });
+    }
+    
+    /// Get whether automatic update checks are enabled
+    pub fn get_check_for_updates(&self) -> bool {
+        crate::core::config::Config::load().check_for_updates
+    }
+    
+    /// Enable or disable automatic update checks
+    pub fn set_check_for_updates(self: Pin<&mut Self>, enabled: bool) {
+        let mut config = crate::core::config::Config::load();
+        config.check_for_updates = enabled;
+        
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        } else {
+            tracing::info!("Update checks {}", if enabled { "enabled" } else { "disabled" });
+        }
+    }
+    
+    /// Get concurrency limits as JSON
+    pub fn get_concurrency_limits(&self) -> QString {
+        let config = crate::core::config::Config::load();
+        QString::from(&serde_json::json!({
+            "relayFetches": config.max_relay_fetches,
+            "backgroundThreads": config.max_background_threads,
+            "requestsPerHost": config.max_requests_per_host,
+        }).to_string())
+    }
+    
+    /// Set and apply concurrency limits
+    pub fn set_concurrency_limits(self: Pin<&mut Self>, relay_fetches: i32, background_threads: i32, requests_per_host: i32) {
+        let to_limit = |v: i32| (v.max(1) as usize).min(crate::core::limits::MAX_LIMIT);
+        let mut config = crate::core::config::Config::load();
+        config.max_relay_fetches = to_limit(relay_fetches);
+        config.max_background_threads = to_limit(background_threads);
+        config.max_requests_per_host = to_limit(requests_per_host);
+        
+        crate::core::limits::apply(
+            config.max_relay_fetches,
+            config.max_background_threads,
+            config.max_requests_per_host,
+        );
+        
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+    }
+    
+    /// Get relay timeouts as JSON
+    pub fn get_network_timeouts(&self) -> QString {
+        let timeouts = crate::core::network::timeouts();
+        QString::from(&serde_json::json!({
+            "fetchSecs": timeouts.fetch.as_secs(),
+            "connectSecs": timeouts.connect.as_secs(),
+            "reconnectMaxSecs": timeouts.reconnect_max.as_secs(),
+        }).to_string())
+    }
+    
+    /// Set and apply relay timeouts
+    pub fn set_network_timeouts(self: Pin<&mut Self>, fetch_secs: i32, connect_secs: i32, reconnect_max_secs: i32) {
+        let timeouts = crate::core::network::Timeouts::new(
+            fetch_secs.max(0) as u64,
+            connect_secs.max(0) as u64,
+            reconnect_max_secs.max(0) as u64,
+        );
+        crate::core::network::apply_timeouts(timeouts);
+        
+        let mut config = crate::core::config::Config::load();
+        config.fetch_timeout_secs = timeouts.fetch.as_secs();
+        config.connect_timeout_secs = timeouts.connect.as_secs();
+        config.reconnect_max_secs = timeouts.reconnect_max.as_secs();
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+    }
+    
+    /// Get proxy settings as JSON
+    pub fn get_proxy_settings(&self) -> QString {
+        let settings = crate::core::network::settings();
+        QString::from(&serde_json::json!({
+            "url": settings.url.unwrap_or_default(),
+            "onionOnly": settings.onion_only,
+        }).to_string())
+    }
+    
+    /// Validate, save and apply proxy settings
+    pub fn set_proxy_settings(self: Pin<&mut Self>, url: &QString, onion_only: bool) -> QString {
+        let url = url.to_string().trim().to_string();
+        if let Err(e) = crate::core::network::validate_proxy_url(&url) {
+            return QString::from(&e);
+        }
+        
+        let mut config = crate::core::config::Config::load();
+        config.proxy_url = if url.is_empty() { None } else { Some(url) };
+        config.proxy_onion_only = onion_only;
+        
+        crate::core::network::apply(crate::core::network::ProxySettings {
+            url: config.proxy_url.clone(),
+            onion_only,
+        });
+        
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+            return QString::from(&format!("Failed to save settings: {}", e));
+        }
+        QString::from("")
+    }
+    
+    /// Route clicks on desktop notifications to the notification_activated signal
+    pub fn enable_desktop_notifications(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        desktop_notify::set_activation_handler(Box::new(move |kind, target| {
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().notification_activated(
+                    &QString::from(kind.as_str()),
+                    &QString::from(&target),
+                );
+            });
+        }));
+    }
+    
+    /// Route deep links to the deep_link_opened signal
+    pub fn enable_deep_links(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        deep_link::set_handler(Box::new(move |link| {
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().deep_link_opened(
+                    &QString::from(link.kind()),
+                    &QString::from(link.target()),
+                );
+            });
+        }));
+    }
+    
+    /// Route activation from later launches to the activation_requested signal
+    pub fn enable_single_instance(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        instance::set_activation_handler(Box::new(move || {
+            let _ = qt_thread.queue(|mut qobject| {
+                qobject.as_mut().activation_requested();
+            });
+        }));
+    }
+    
+    /// Record window focus for desktop notifications
+    pub fn set_window_active(self: Pin<&mut Self>, active: bool) {
+        desktop_notify::set_window_active(active);
+    }
+    
+    /// Get desktop notification toggles as JSON
+    pub fn get_desktop_notify_settings(&self) -> QString {
+        let config = crate::core::config::Config::load();
+        QString::from(&serde_json::json!({
+            "mention": config.desktop_notify_mentions,
+            "dm": config.desktop_notify_dms,
+            "zap": config.desktop_notify_zaps,
+        }).to_string())
+    }
+    
+    /// Enable or disable desktop notifications for a kind
+    pub fn set_desktop_notify(self: Pin<&mut Self>, kind: &QString, enabled: bool) {
+        let Some(kind) = NotifyKind::from_str(&kind.to_string()) else {
+            tracing::warn!("Unknown desktop notification kind: {}", kind);
+            return;
+        };
+        
+        let mut config = crate::core::config::Config::load();
+        match kind {
+            NotifyKind::Mention => config.desktop_notify_mentions = enabled,
+            NotifyKind::DirectMessage => config.desktop_notify_dms = enabled,
+            NotifyKind::Zap => config.desktop_notify_zaps = enabled,
+        }
+        
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+    }
+    
+    /// Get whether DM presence is shared
+    pub fn get_dm_presence(&self) -> bool {
+        dm_presence_enabled()
+    }
+    
+    /// Enable or disable DM typing indicators and read receipts
+    pub fn set_dm_presence(self: Pin<&mut Self>, enabled: bool) {
+        set_dm_presence_enabled(enabled);
+        
+        let mut config = crate::core::config::Config::load();
+        config.dm_presence = enabled;
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+    }
+    
+    /// Get whether DM attachments are encrypted
+    pub fn get_dm_encrypt_attachments(&self) -> bool {
+        crate::core::config::Config::load().dm_encrypt_attachments
+    }
+    
+    /// Set whether DM attachments are encrypted
+    pub fn set_dm_encrypt_attachments(self: Pin<&mut Self>, enabled: bool) {
+        let mut config = crate::core::config::Config::load();
+        config.dm_encrypt_attachments = enabled;
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+    }
+    
+    /// Start the hourly exchange rate refresh
+    pub fn start_exchange_rate_updates(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        fiat::start_refresh_loop(move || {
+            let _ = qt_thread.queue(|mut qobject| {
+                qobject.as_mut().refresh_wallet_fiat();
+                qobject.as_mut().exchange_rate_updated();
+            });
+        });
+    }
+    
+    /// Get fiat display settings as JSON
+    pub fn get_fiat_settings(&self) -> QString {
+        QString::from(&serde_json::json!({
+            "currency": fiat::display_currency().unwrap_or_default(),
+            "currencies": fiat::available_currencies(),
+        }).to_string())
+    }
+    
+    /// Set the fiat display currency and refresh rates for it
+    pub fn set_fiat_currency(mut self: Pin<&mut Self>, currency: &QString) {
+        let currency = currency.to_string().trim().to_uppercase();
+        let currency = if currency.is_empty() { None } else { Some(currency) };
+        
+        let mut config = crate::core::config::Config::load();
+        config.fiat_currency = currency.clone();
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+        
+        fiat::set_display_currency(currency.clone());
+        self.as_mut().refresh_wallet_fiat();
+        self.as_mut().exchange_rate_updated();
+        
+        if currency.is_some() && fiat::needs_refresh() {
+            let qt_thread = self.qt_thread();
+            fiat::refresh_now(move || {
+                let _ = qt_thread.queue(|mut qobject| {
+                    qobject.as_mut().refresh_wallet_fiat();
+                    qobject.as_mut().exchange_rate_updated();
+                });
+            });
+        }
+    }
+    
+    /// Fiat equivalent of an amount of sats
+    pub fn format_fiat(&self, sats: i64) -> QString {
+        QString::from(&fiat::fiat_label(sats.max(0) as u64).unwrap_or_default())
+    }
+    
+    /// Get web-of-trust settings as JSON
+    pub fn get_wot_settings(&self) -> QString {
+        let policy = wot::policy();
+        let (scored, computed_at) = crate::nostr::database::NostrDbManager::global().ok()
+            .and_then(|db| db.trust_scores_info())
+            .map(|(_, computed_at, count)| (count, computed_at))
+            .unwrap_or((0, 0));
+        QString::from(&serde_json::json!({
+            "threshold": policy.threshold,
+            "action": policy.action.as_str(),
+            "scored": scored,
+            "computedAt": computed_at,
+        }).to_string())
+    }
+    
+    /// Set the web-of-trust policy and save it
+    /// Scores are computed on the next global feed fetch if missing
+    pub fn set_wot_settings(self: Pin<&mut Self>, threshold: i32, action: &QString) {
+        let threshold = (threshold.max(0) as u8).min(wot::MAX_HOPS);
+        let action = wot::TrustAction::from_str(&action.to_string());
+        
+        let mut config = crate::core::config::Config::load();
+        config.wot_threshold = threshold;
+        config.wot_action = action.as_str().to_string();
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+        
+        wot::set_policy(threshold, action);
+    }
+    
+    /// Cache sizes, hit ratios and disk usage for the diagnostics panel
+    pub fn get_cache_stats(&self) -> QString {
+        let mut caches = view_cache_stats();
+        let mut database = serde_json::Value::Null;
+        if let Ok(db) = NostrDbManager::global() {
+            caches.extend(db.cache_stats());
+            let (path, files, bytes) = db.disk_usage();
+            database = serde_json::json!({
+                "path": path.display().to_string(),
+                "files": files,
+                "bytes": bytes,
+                "memoryCapacity": db.memory_cache_capacity(),
+            });
+        }
+        
+        let media_dir = cache_stats::media_cache_dir();
+        let (media_files, media_bytes) = cache_stats::dir_usage(&media_dir);
+        caches.push(cache_stats::CacheStats::new(cache_stats::MEDIA, media_files, media_bytes));
+        
+        QString::from(&serde_json::json!({
+            "caches": caches,
+            "database": database,
+            "media": {
+                "path": media_dir.display().to_string(),
+                "files": media_files,
+                "bytes": media_bytes,
+                "limitBytes": media_cache::max_bytes(),
+            },
+            "residentBytes": cache_stats::resident_memory(),
+        }).to_string())
+    }
+    
+    /// Clear the in-memory caches; everything refills from nostrdb and relays
+    pub fn clear_caches(self: Pin<&mut Self>) {
+        clear_view_caches();
+        if let Ok(db) = NostrDbManager::global() {
+            db.clear_memory_cache();
+        }
+        cache_stats::reset();
+        tracing::info!("Cleared in-memory caches");
+    }
+    
+    /// Local file URL for a cached URL, or the URL itself while it downloads
+    pub fn cached_media_url(&self, url: &QString) -> QString {
+        let url_str = url.to_string();
+        if let Some(path) = media_cache::cached(&url_str) {
+            return QString::from(&media_cache::file_url(&path));
+        }
+        if url_str.starts_with("http://") || url_str.starts_with("https://") {
+            let qt_thread = self.qt_thread();
+            let target = url_str.clone();
+            crate::core::limits::spawn_limited(move || {
+                if let Ok(path) = TOKIO_RUNTIME.block_on(media_cache::fetch(&target)) {
+                    let local = media_cache::file_url(&path);
+                    let _ = qt_thread.queue(move |mut qobject| {
+                        qobject.as_mut().media_cached(&QString::from(&target), &QString::from(&local));
+                    });
+                }
+            });
+        }
+        url.clone()
+    }
+    
+    /// Size cap for the media cache in MB
+    pub fn get_media_cache_limit(&self) -> i32 {
+        crate::core::config::Config::load().media_cache_mb.min(i32::MAX as u64) as i32
+    }
+    
+    /// Set the media cache size cap and trim the cache to it
+    pub fn set_media_cache_limit(self: Pin<&mut Self>, megabytes: i32) {
+        let mut config = crate::core::config::Config::load();
+        config.media_cache_mb = megabytes.max(16) as u64;
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save media cache limit: {}", e);
+        }
+        let max_bytes = media_cache::max_bytes();
+        crate::core::limits::spawn_limited(move || {
+            media_cache::evict(max_bytes);
+        });
+    }
+    
+    /// Get whether link preview images go through the media cache up front
+    pub fn get_proxy_link_preview_images(&self) -> bool {
+        crate::core::config::Config::load().proxy_link_preview_images
+    }
+    
+    /// Set whether link preview images go through the media cache up front
+    pub fn set_proxy_link_preview_images(self: Pin<&mut Self>, enabled: bool) {
+        let mut config = crate::core::config::Config::load();
+        config.proxy_link_preview_images = enabled;
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+    }
+    
+    /// Get whether data saver mode is on
+    pub fn get_data_saver(&self) -> bool {
+        crate::core::network::data_saver()
+    }
+    
+    /// Save data saver mode and apply it to notes loaded from now on
+    pub fn set_data_saver(self: Pin<&mut Self>, enabled: bool) {
+        let mut config = crate::core::config::Config::load();
+        config.data_saver = enabled;
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+        crate::core::network::set_data_saver(enabled);
+    }
+    
+    /// Get whether published events carry a client tag
+    pub fn get_client_tag(&self) -> bool {
+        handlers::client_tag_enabled()
+    }
+    
+    /// Save the client tag setting and apply it to events published from now on
+    pub fn set_client_tag(self: Pin<&mut Self>, enabled: bool) {
+        let mut config = crate::core::config::Config::load();
+        config.client_tag = enabled;
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+        handlers::set_client_tag_enabled(enabled);
+    }
+    
+    /// Delete all cached avatars and media
+    pub fn clear_media_cache(self: Pin<&mut Self>) -> bool {
+        match media_cache::clear() {
+            Ok(()) => {
+                tracing::info!("Cleared media cache");
+                true
+            }
+            Err(e) => {
+                tracing::error!("{}", e);
+                false
+            }
+        }
+    }
+    
+    /// Export the account archive in the background
+    pub fn export_archive(self: Pin<&mut Self>, file_path: &QString, include_dms: bool) {
+        let path = file_path.to_string().trim_start_matches("file://").to_string();
+        let Ok(user_pk) = nostr_sdk::PublicKey::parse(&self.public_key().to_string()) else {
+            return;
+        };
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            // Decrypting needs the local key; a signer would prompt for every message
+            let keys = include_dms
+                .then(get_feed_nsec)
+                .flatten()
+                .and_then(|nsec| nostr_sdk::Keys::parse(&nsec).ok());
+            let result = TOKIO_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                archive::export(manager.client(), &user_pk, keys.as_ref(), std::path::Path::new(&path)).await
+            });
+            
+            let json = match result {
+                Ok(summary) => serde_json::json!({
+                    "action": "export",
+                    "events": summary.events,
+                    "dms": summary.dms,
+                }),
+                Err(e) => {
+                    tracing::error!("Archive export failed: {}", e);
+                    serde_json::json!({ "action": "export", "error": e })
+                }
+            };
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().archive_finished(&QString::from(&json.to_string()));
+            });
+        });
+    }
+    
+    /// Import an account archive in the background
+    pub fn import_archive(self: Pin<&mut Self>, file_path: &QString, republish: bool, restore_settings: bool) {
+        let path = file_path.to_string().trim_start_matches("file://").to_string();
+        let Ok(user_pk) = nostr_sdk::PublicKey::parse(&self.public_key().to_string()) else {
+            return;
+        };
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            let result = TOKIO_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                archive::import(manager.client(), &user_pk, std::path::Path::new(&path), republish, restore_settings).await
+            });
+            
+            let json = match result {
+                Ok(summary) => serde_json::json!({
+                    "action": "import",
+                    "events": summary.events,
+                    "republished": summary.republished,
+                    "settingsRestored": summary.settings_restored,
+                }),
+                Err(e) => {
+                    tracing::error!("Archive import failed: {}", e);
+                    serde_json::json!({ "action": "import", "error": e })
+                }
+            };
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().archive_finished(&QString::from(&json.to_string()));
+            });
+        });
+    }
+    
+    /// Update the wallet balance and its fiat equivalent together
+    fn set_wallet_balance(mut self: Pin<&mut Self>, sats: i64) {
+        self.as_mut().set_wallet_balance_sats(sats);
+        self.refresh_wallet_fiat();
+    }
+    
+    fn refresh_wallet_fiat(self: Pin<&mut Self>) {
+        let sats = *self.wallet_balance_sats();
+        let label = fiat::fiat_label(sats.max(0) as u64).unwrap_or_default();
+        self.set_wallet_balance_fiat(QString::from(&label));
+    }
+    
+    /// Republish the account's profile and relay lists in the background
+    pub fn rebroadcast_profile(self: Pin<&mut Self>) {
+        let Ok(user_pk) = nostr_sdk::PublicKey::parse(&self.public_key().to_string()) else {
+            return;
+        };
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            let kinds = [nostr_sdk::Kind::Metadata, nostr_sdk::Kind::RelayList, nostr_sdk::Kind::InboxRelays];
+            let result = TOKIO_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                let events = manager.fetch_latest_replaceable(&user_pk, &kinds).await?;
+                if events.is_empty() {
+                    return Err("No profile or relay list found to rebroadcast".to_string());
+                }
+                let mut results = Vec::new();
+                for event in &events {
+                    match publish::send(manager.client(), event).await {
+                        Ok(report) => results.push(serde_json::json!({
+                            "kind": event.kind.as_u16(),
+                            "accepted": report.accepted(),
+                            "total": report.relays.len(),
+                        })),
+                        Err(e) => tracing::warn!("Failed to rebroadcast kind {}: {}", event.kind.as_u16(), e),
+                    }
+                }
+                Ok(results)
+            });
+            
+            let json = match result {
+                Ok(results) => serde_json::json!({ "events": results }),
+                Err(e) => {
+                    tracing::error!("Rebroadcast failed: {}", e);
+                    serde_json::json!({ "events": [], "error": e })
+                }
+            };
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().rebroadcast_finished(&QString::from(&json.to_string()));
+            });
+        });
+    }
+    
     /// Add a relay URL
     pub fn add_relay(self: Pin<&mut Self>, url: &QString) -> bool {
         let url_str = url.to_string().trim().to_string();
@@ -734,6 +2109,7 @@ impl qobject::AppController {
         }
         
         tracing::info!("Added relay: {}", url_str);
+        self.apply_relay_changes(vec![url_str], Vec::new());
         true
     }
     
@@ -744,6 +2120,7 @@ impl qobject::AppController {
         
         let initial_len = config.relays.len();
         config.relays.retain(|r| r != &url_str);
+        config.relay_roles.remove(&url_str);
         
         if config.relays.len() == initial_len {
             tracing::warn!("Relay not found: {}", url_str);
@@ -756,27 +2133,188 @@ impl qobject::AppController {
         }
         
         tracing::info!("Removed relay: {}", url_str);
+        self.apply_relay_changes(Vec::new(), vec![url_str]);
         true
     }
     
     /// Reset relays to default
     pub fn reset_relays_to_default(self: Pin<&mut Self>) {
         let mut config = crate::core::config::Config::load();
-        config.relays = vec![
-            "wss://relay.pleb.one".to_string(),
-            "wss://relay.primal.net".to_string(),
-            "wss://relay.damus.io".to_string(),
-            "wss://nos.lol".to_string(),
-        ];
+        let defaults: Vec<String> = relay::DEFAULT_RELAYS.iter().map(|s| s.to_string()).collect();
+        let added: Vec<String> = defaults.iter().filter(|r| !config.relays.contains(r)).cloned().collect();
+        let removed: Vec<String> = config.relays.iter().filter(|r| !defaults.contains(r)).cloned().collect();
+        config.relays = defaults;
+        config.relay_roles.clear();
         
         if let Err(e) = config.save() {
             tracing::error!("Failed to save config: {}", e);
         } else {
             tracing::info!("Reset relays to default");
+            self.apply_relay_changes(added, removed);
+        }
+    }
+    
+    /// Get configured relays' roles
+    pub fn get_relay_roles(self: Pin<&mut Self>) -> QString {
+        let roles: serde_json::Map<String, serde_json::Value> = relay::configured_relay_roles().into_iter()
+            .map(|(url, roles)| (url, serde_json::to_value(roles).unwrap_or_default()))
+            .collect();
+        QString::from(&serde_json::Value::Object(roles).to_string())
+    }
+    
+    /// Change a relay's roles and apply them to the live pool
+    pub fn set_relay_roles(self: Pin<&mut Self>, url: &QString, read: bool, write: bool, dm: bool) -> bool {
+        let url_str = url.to_string();
+        let mut config = crate::core::config::Config::load();
+        if !config.relays.contains(&url_str) {
+            tracing::warn!("Relay not found: {}", url_str);
+            return false;
+        }
+        if !read && !write && !dm {
+            tracing::warn!("Relay {} needs at least one role", url_str);
+            return false;
+        }
+        
+        let roles = relay::RelayRoles { read, write, dm };
+        if roles == relay::RelayRoles::default() {
+            config.relay_roles.remove(&url_str);
+        } else {
+            config.relay_roles.insert(url_str.clone(), roles);
+        }
+        
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+            return false;
+        }
+        
+        tracing::info!("Relay {} roles: read={} write={} dm={}", url_str, read, write, dm);
+        // Pool flags are set when a relay is added, so re-add it
+        self.apply_relay_changes(vec![url_str.clone()], vec![url_str]);
+        true
+    }
+    
+    /// Bring the connected relay pool in line with an edited relay list and
+    /// republish the user's NIP-65 and DM relay lists (in the background).
+    /// Relays in `removed` are dropped before those in `added` are connected.
+    /// The published lists are fetched first so relays added from other
+    /// clients are kept.
+    fn apply_relay_changes(self: Pin<&mut Self>, added: Vec<String>, removed: Vec<String>) {
+        let user_pk = nostr_sdk::PublicKey::parse(&self.public_key().to_string()).ok();
+        let relays = relay::configured_relay_roles();
+        let dm_relays = relay::dm_relays();
+        
+        std::thread::spawn(move || {
+            TOKIO_RUNTIME.block_on(async {
+                update_pool(&added, &removed).await;
+                
+                let Some(user_pk) = user_pk else { return };
+                let result = async {
+                    let manager = shared_relay_manager().await?;
+                    let kinds = [nostr_sdk::Kind::RelayList, nostr_sdk::Kind::InboxRelays];
+                    let published = manager.fetch_latest_replaceable(&user_pk, &kinds).await?;
+                    Ok::<_, String>((manager, published))
+                }.await;
+                let (manager, published) = match result {
+                    Ok(fetched) => fetched,
+                    Err(e) => {
+                        // Publishing blind could drop relays added elsewhere
+                        tracing::warn!("Not publishing relay lists, couldn't fetch the current ones: {}", e);
+                        return;
+                    }
+                };
+                
+                let configured: Vec<String> = relays.iter().map(|(url, _)| url.clone()).collect();
+                let remote_relays = published.iter()
+                    .find(|e| e.kind == nostr_sdk::Kind::RelayList)
+                    .map(relay::parse_relay_list)
+                    .unwrap_or_default();
+                let merged = relay::merge_relay_lists(&relays, &remote_relays, |(url, _)| url.as_str(), &configured, &removed);
+                let mut lists = vec![("relay list", relay::relay_list_builder(&merged))];
+                // Only replace a DM relay list if the user picked DM relays here
+                if !dm_relays.is_empty() {
+                    let remote_dm = published.iter()
+                        .find(|e| e.kind == nostr_sdk::Kind::InboxRelays)
+                        .map(relay::parse_dm_relay_list)
+                        .unwrap_or_default();
+                    let merged_dm = relay::merge_relay_lists(&dm_relays, &remote_dm, |url| url.as_str(), &configured, &removed);
+                    lists.push(("DM relay list", relay::dm_relay_list_builder(&merged_dm)));
+                }
+                for (name, builder) in lists {
+                    let result = async {
+                        let event = sign_event_builder(builder, user_pk).await?;
+                        publish::send(manager.client(), &event).await
+                    }.await;
+                    match result {
+                        Ok(_) => tracing::info!("Published {}", name),
+                        Err(e) => tracing::warn!("Failed to publish {}: {}", name, e),
+                    }
+                }
+            });
+        });
+    }
+}
+
+/// Set display_name and profile_picture from the user's kind 0, unless the
+/// account changed in the meantime
+fn apply_own_profile(qt_thread: &cxx_qt::CxxQtThread<qobject::AppController>, pk: nostr_sdk::PublicKey, event: &nostr_sdk::Event) {
+    let Ok(profile) = ProfileCache::from_event(event) else { return };
+    let name = profile.get_display_name("Anonymous");
+    let picture = profile.picture.unwrap_or_default();
+    let _ = qt_thread.queue(move |mut qobject| {
+        if qobject.public_key().to_string() != pk.to_hex() {
+            return;
+        }
+        qobject.as_mut().set_display_name(QString::from(&name));
+        qobject.as_mut().set_profile_picture(QString::from(&picture));
+    });
+}
+
+/// Drop `removed` from and connect `added` to the running relay pool
+/// Without a running pool there is nothing to update; the next connect reads
+/// the saved list
+async fn update_pool(added: &[String], removed: &[String]) {
+    let pooled = GLOBAL_RELAY_MANAGER.read().unwrap()
+        .as_ref()
+        .filter(|m| m.is_connected())
+        .cloned();
+    let Some(manager) = pooled else { return };
+    for url in removed {
+        if let Err(e) = manager.remove_live_relay(url).await {
+            tracing::warn!("Failed to drop relay {}: {}", url, e);
+        }
+    }
+    for url in added {
+        if let Err(e) = manager.add_live_relay(url, relay::relay_roles(url)).await {
+            tracing::warn!("Failed to connect relay {}: {}", url, e);
         }
     }
 }
 
+/// Use the user's published NIP-65 relays instead of the built-in defaults
+/// Relays the user picked in settings are never replaced
+async fn adopt_relay_list(event: &nostr_sdk::Event) {
+    let relays = relay::parse_relay_list(event);
+    if relays.iter().all(|(_, roles)| !roles.in_pool()) || !relay::uses_default_relays() {
+        return;
+    }
+    
+    let mut config = crate::core::config::Config::load();
+    let previous = std::mem::take(&mut config.relays);
+    config.relays = relays.iter().map(|(url, _)| url.clone()).collect();
+    config.relay_roles = relays.iter()
+        .filter(|(_, roles)| *roles != relay::RelayRoles::default())
+        .map(|(url, roles)| (url.clone(), *roles))
+        .collect();
+    if let Err(e) = config.save() {
+        tracing::warn!("Failed to save relay list: {}", e);
+        return;
+    }
+    tracing::info!("Using {} relays from the published relay list", config.relays.len());
+    
+    let removed: Vec<String> = previous.into_iter().filter(|url| !config.relays.contains(url)).collect();
+    update_pool(&config.relays, &removed).await;
+}
+
 /// Parse an nsec string and extract keys
 fn parse_nsec(nsec: &str) -> Result<(String, String, String), String> {
     use nostr_sdk::prelude::*;
@@ -0,0 +1,473 @@
+//! Channels bridge - exposes NIP-28 public chat channels to QML
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, channel_count)]
+        #[qproperty(i32, message_count)]
+        #[qproperty(bool, is_loading)]
+        #[qproperty(bool, has_more)]
+        #[qproperty(QString, selected_channel)]
+        #[qproperty(QString, error_message)]
+        type ChannelsController = super::ChannelsControllerRust;
+
+        /// Set the logged-in user's pubkey (used to sign and to find joined channels)
+        #[qinvokable]
+        fn set_logged_in_user(self: Pin<&mut ChannelsController>, pubkey: &QString);
+
+        /// Load the channels in the user's public chats list
+        #[qinvokable]
+        fn load_channels(self: Pin<&mut ChannelsController>);
+
+        /// Get channel at index (returns JSON)
+        #[qinvokable]
+        fn get_channel(self: &ChannelsController, index: i32) -> QString;
+
+        /// Add a channel (hex id, note or nevent) to the public chats list
+        #[qinvokable]
+        fn join_channel(self: Pin<&mut ChannelsController>, channel_id: &QString);
+
+        /// Remove a channel from the public chats list
+        #[qinvokable]
+        fn leave_channel(self: Pin<&mut ChannelsController>, channel_id: &QString);
+
+        /// Open a channel and load its newest messages
+        #[qinvokable]
+        fn select_channel(self: Pin<&mut ChannelsController>, channel_id: &QString);
+
+        /// Load the next page of older messages in the open channel
+        #[qinvokable]
+        fn load_more_messages(self: Pin<&mut ChannelsController>);
+
+        /// Get message at index, newest first (returns JSON)
+        #[qinvokable]
+        fn get_message(self: &ChannelsController, index: i32) -> QString;
+
+        /// Post to the open channel, replying to a message if reply_to is set
+        #[qinvokable]
+        fn post_message(self: Pin<&mut ChannelsController>, content: &QString, reply_to: &QString);
+
+        /// Hide a message from your own view of channels
+        #[qinvokable]
+        fn hide_message(self: Pin<&mut ChannelsController>, message_id: &QString, reason: &QString);
+
+        /// Mute a user in your own view of channels
+        #[qinvokable]
+        fn mute_user(self: Pin<&mut ChannelsController>, pubkey: &QString, reason: &QString);
+    }
+
+    unsafe extern "RustQt" {
+        /// Emitted when the channel list is loaded
+        #[qsignal]
+        fn channels_loaded(self: Pin<&mut ChannelsController>, count: i32);
+
+        /// Emitted when the open channel's messages change
+        #[qsignal]
+        fn messages_loaded(self: Pin<&mut ChannelsController>, count: i32);
+
+        /// Emitted when a posted message is accepted
+        #[qsignal]
+        fn message_posted(self: Pin<&mut ChannelsController>, message_id: &QString);
+
+        /// Emitted when publishing a join/leave/post/hide/mute fails
+        #[qsignal]
+        fn action_failed(self: Pin<&mut ChannelsController>, action: &QString, error: &QString);
+    }
+
+    // Enable threading support for background work with UI updates
+    impl cxx_qt::Threading for ChannelsController {}
+}
+
+use std::collections::{HashMap, HashSet};
+use std::pin::Pin;
+use cxx_qt_lib::QString;
+use cxx_qt::{CxxQtType, Threading};
+use nostr_sdk::prelude::*;
+use crate::core::limits;
+use crate::nostr::channels::{self, Channel, ChannelMessage, Moderation};
+use crate::nostr::profile::ProfileCache;
+use crate::nostr::publish;
+use crate::nostr::relay::fetch_timeout;
+use crate::bridge::feed_bridge::{shared_relay_manager, sign_event_builder};
+
+// Global tokio runtime for channel operations
+lazy_static::lazy_static! {
+    static ref CHANNELS_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+}
+
+/// Rust implementation of ChannelsController
+#[derive(Default)]
+pub struct ChannelsControllerRust {
+    channel_count: i32,
+    message_count: i32,
+    is_loading: bool,
+    has_more: bool,
+    selected_channel: QString,
+    error_message: QString,
+
+    // Internal state
+    channels: Vec<Channel>,
+    /// Visible messages in the open channel, newest first
+    messages: Vec<ChannelMessage>,
+    /// Oldest message fetched (hidden or not), for paging
+    oldest_loaded: Option<i64>,
+    /// Message authors' profiles
+    profiles: HashMap<String, ProfileCache>,
+    moderation: Moderation,
+    /// Latest public chats list, so joins and leaves keep its other entries
+    public_chats: Option<Event>,
+    user_pubkey: Option<PublicKey>,
+}
+
+impl qobject::ChannelsController {
+    /// Set the logged-in user's pubkey
+    pub fn set_logged_in_user(mut self: Pin<&mut Self>, pubkey: &QString) {
+        self.as_mut().rust_mut().user_pubkey = PublicKey::parse(&pubkey.to_string()).ok();
+    }
+
+    /// Load the joined channels plus the user's hide/mute events
+    pub fn load_channels(mut self: Pin<&mut Self>) {
+        let Some(user) = self.user_pubkey else {
+            tracing::warn!("Cannot load channels: user pubkey not set");
+            return;
+        };
+
+        self.as_mut().set_is_loading(true);
+        self.as_mut().set_error_message(QString::from(""));
+
+        let qt_thread = self.qt_thread();
+
+        limits::spawn_limited(move || {
+            let result = CHANNELS_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                let client = manager.client();
+
+                let list = client.fetch_events(channels::public_chats_filter(&user), fetch_timeout())
+                    .await
+                    .map_err(|e| format!("Failed to fetch public chats list: {}", e))?
+                    .into_iter()
+                    .max_by_key(|e| e.created_at);
+
+                let moderation_events = client.fetch_events(channels::moderation_filter(&user), fetch_timeout())
+                    .await
+                    .unwrap_or_default();
+                let moderation = Moderation::from_events(moderation_events.iter());
+
+                let ids = list.as_ref().map(channels::joined_channel_ids).unwrap_or_default();
+                if ids.is_empty() {
+                    return Ok((list, Vec::new(), moderation));
+                }
+
+                let created = client.fetch_events(channels::channels_filter(&ids), fetch_timeout())
+                    .await
+                    .map_err(|e| format!("Failed to fetch channels: {}", e))?;
+                let updates = client.fetch_events(channels::channel_metadata_filter(&ids), fetch_timeout())
+                    .await
+                    .unwrap_or_default();
+
+                // Keep the list's order; each channel takes its creator's newest update
+                let mut joined: Vec<Channel> = ids.iter()
+                    .filter_map(|id| created.iter().find(|e| e.id == *id))
+                    .filter_map(Channel::from_creation)
+                    .collect();
+                for event in updates.iter() {
+                    for channel in joined.iter_mut() {
+                        channel.apply_metadata(event);
+                    }
+                }
+
+                Ok::<_, String>((list, joined, moderation))
+            });
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().set_is_loading(false);
+                match result {
+                    Ok((list, joined, moderation)) => {
+                        let count = joined.len() as i32;
+                        tracing::info!("Loaded {} joined channels", count);
+                        {
+                            let mut rust = qobject.as_mut().rust_mut();
+                            rust.public_chats = list;
+                            rust.channels = joined;
+                            rust.moderation = moderation;
+                        }
+                        qobject.as_mut().set_channel_count(count);
+                        qobject.as_mut().channels_loaded(count);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to load channels: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+
+    /// Get channel at index
+    pub fn get_channel(&self, index: i32) -> QString {
+        if let Some(channel) = self.channels.get(index as usize) {
+            QString::from(&channel.to_json())
+        } else {
+            QString::from("{}")
+        }
+    }
+
+    /// Join a channel
+    pub fn join_channel(self: Pin<&mut Self>, channel_id: &QString) {
+        self.update_public_chats("join", channel_id, true);
+    }
+
+    /// Leave a channel
+    pub fn leave_channel(self: Pin<&mut Self>, channel_id: &QString) {
+        self.update_public_chats("leave", channel_id, false);
+    }
+
+    /// Republish the public chats list with one channel added or removed, then reload
+    fn update_public_chats(mut self: Pin<&mut Self>, action: &'static str, channel_id: &QString, joined: bool) {
+        let Ok(id) = EventId::parse(channel_id.to_string().trim()) else {
+            self.as_mut().action_failed(&QString::from(action), &QString::from("Invalid channel id"));
+            return;
+        };
+        let builder = channels::public_chats_builder(self.public_chats.as_ref(), &id, joined);
+        self.publish(action, builder, |mut qobject, event| {
+            qobject.as_mut().rust_mut().public_chats = Some(event);
+            qobject.load_channels();
+        });
+    }
+
+    /// Open a channel
+    pub fn select_channel(mut self: Pin<&mut Self>, channel_id: &QString) {
+        self.as_mut().set_selected_channel(channel_id.clone());
+        {
+            let mut rust = self.as_mut().rust_mut();
+            rust.messages.clear();
+            rust.oldest_loaded = None;
+        }
+        self.as_mut().set_message_count(0);
+        self.as_mut().set_has_more(true);
+        self.load_page(None);
+    }
+
+    /// Load older messages
+    pub fn load_more_messages(self: Pin<&mut Self>) {
+        if !self.has_more || self.is_loading {
+            return;
+        }
+        let Some(oldest) = self.oldest_loaded else { return };
+        self.load_page(Some(Timestamp::from((oldest - 1).max(0) as u64)));
+    }
+
+    /// Fetch one page of history for the open channel, with its authors' profiles
+    fn load_page(mut self: Pin<&mut Self>, until: Option<Timestamp>) {
+        let channel_hex = self.selected_channel.to_string();
+        let Ok(channel_id) = EventId::from_hex(&channel_hex) else {
+            self.as_mut().set_error_message(QString::from("Invalid channel id"));
+            return;
+        };
+        let known: HashSet<String> = self.profiles.keys().cloned().collect();
+
+        self.as_mut().set_is_loading(true);
+        self.as_mut().set_error_message(QString::from(""));
+
+        let qt_thread = self.qt_thread();
+
+        limits::spawn_limited(move || {
+            let result = CHANNELS_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                let events = manager.client()
+                    .fetch_events(channels::messages_filter(&channel_id, until), fetch_timeout())
+                    .await
+                    .map_err(|e| format!("Failed to fetch channel messages: {}", e))?;
+
+                let page: Vec<ChannelMessage> = events.iter()
+                    .filter_map(ChannelMessage::from_event)
+                    .filter(|m| m.channel_id == channel_hex)
+                    .collect();
+
+                let authors: Vec<PublicKey> = page.iter()
+                    .filter(|m| !known.contains(&m.author))
+                    .filter_map(|m| PublicKey::from_hex(&m.author).ok())
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect();
+                let mut profiles = HashMap::new();
+                if !authors.is_empty() {
+                    for event in manager.fetch_profiles(&authors).await.unwrap_or_default().iter() {
+                        if let Ok(profile) = ProfileCache::from_event(event) {
+                            profiles.insert(event.pubkey.to_hex(), profile);
+                        }
+                    }
+                }
+
+                Ok::<_, String>((page, events.len(), profiles))
+            });
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                // Another channel was opened while this page loaded
+                if qobject.selected_channel.to_string() != channel_hex {
+                    return;
+                }
+                qobject.as_mut().set_is_loading(false);
+                match result {
+                    Ok((page, fetched, profiles)) => {
+                        let count = {
+                            let mut rust = qobject.as_mut().rust_mut();
+                            if let Some(oldest) = page.iter().map(|m| m.created_at).min() {
+                                rust.oldest_loaded = Some(rust.oldest_loaded.map_or(oldest, |o| o.min(oldest)));
+                            }
+                            rust.profiles.extend(profiles);
+                            let seen: HashSet<String> = rust.messages.iter().map(|m| m.id.clone()).collect();
+                            let visible: Vec<ChannelMessage> = page.into_iter()
+                                .filter(|m| !seen.contains(&m.id) && !rust.moderation.hides(m))
+                                .collect();
+                            rust.messages.extend(visible);
+                            rust.messages.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                            rust.messages.len() as i32
+                        };
+                        qobject.as_mut().set_has_more(fetched >= channels::PAGE_SIZE);
+                        qobject.as_mut().set_message_count(count);
+                        qobject.as_mut().messages_loaded(count);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to load channel messages: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+
+    /// Get message at index
+    pub fn get_message(&self, index: i32) -> QString {
+        let Some(message) = self.messages.get(index as usize) else {
+            return QString::from("{}");
+        };
+        let profile = self.profiles.get(&message.author);
+        let mut json = serde_json::to_value(message).unwrap_or_default();
+        json["authorName"] = serde_json::json!(profile
+            .map(|p| p.get_display_name(&message.author[..8]))
+            .unwrap_or_else(|| message.author[..8].to_string()));
+        json["authorPicture"] = serde_json::json!(profile.and_then(|p| p.picture.clone()));
+        json["isOwn"] = serde_json::json!(self.user_pubkey.map(|pk| pk.to_hex()) == Some(message.author.clone()));
+        QString::from(&json.to_string())
+    }
+
+    /// Post a message to the open channel
+    pub fn post_message(mut self: Pin<&mut Self>, content: &QString, reply_to: &QString) {
+        let content = content.to_string().trim().to_string();
+        if content.is_empty() {
+            return;
+        }
+        let selected = self.selected_channel.to_string();
+        let Some(channel) = self.channels.iter().find(|c| c.id == selected).cloned() else {
+            self.as_mut().action_failed(&QString::from("post"), &QString::from("No channel open"));
+            return;
+        };
+
+        // Replies tag the parent message and its author
+        let reply_to = reply_to.to_string();
+        let parent = self.messages.iter()
+            .find(|m| m.id == reply_to)
+            .and_then(|m| Some((EventId::from_hex(&m.id).ok()?, PublicKey::from_hex(&m.author).ok()?)));
+
+        let builder = match channels::message_builder(&channel, &content, parent) {
+            Ok(builder) => builder,
+            Err(e) => {
+                self.as_mut().action_failed(&QString::from("post"), &QString::from(&e));
+                return;
+            }
+        };
+        self.publish("post", builder, move |mut qobject, event| {
+            let Some(message) = ChannelMessage::from_event(&event) else { return };
+            if qobject.selected_channel.to_string() == message.channel_id {
+                let count = {
+                    let mut rust = qobject.as_mut().rust_mut();
+                    rust.messages.insert(0, message);
+                    rust.messages.len() as i32
+                };
+                qobject.as_mut().set_message_count(count);
+                qobject.as_mut().messages_loaded(count);
+            }
+            qobject.as_mut().message_posted(&QString::from(&event.id.to_hex()));
+        });
+    }
+
+    /// Hide a message
+    pub fn hide_message(mut self: Pin<&mut Self>, message_id: &QString, reason: &QString) {
+        let Ok(id) = EventId::from_hex(&message_id.to_string()) else {
+            self.as_mut().action_failed(&QString::from("hide"), &QString::from("Invalid message id"));
+            return;
+        };
+        let builder = channels::hide_message(id, &reason.to_string());
+        self.publish("hide", builder, |qobject, event| {
+            Self::apply_moderation(qobject, &event);
+        });
+    }
+
+    /// Mute a user
+    pub fn mute_user(mut self: Pin<&mut Self>, pubkey: &QString, reason: &QString) {
+        let Ok(pk) = PublicKey::parse(&pubkey.to_string()) else {
+            self.as_mut().action_failed(&QString::from("mute"), &QString::from("Invalid public key"));
+            return;
+        };
+        let builder = channels::mute_user(pk, &reason.to_string());
+        self.publish("mute", builder, |qobject, event| {
+            Self::apply_moderation(qobject, &event);
+        });
+    }
+
+    /// Record a published hide/mute and drop the affected messages from view
+    fn apply_moderation(mut qobject: Pin<&mut Self>, event: &Event) {
+        let count = {
+            let mut rust = qobject.as_mut().rust_mut();
+            rust.moderation.apply(event);
+            let moderation = rust.moderation.clone();
+            rust.messages.retain(|m| !moderation.hides(m));
+            rust.messages.len() as i32
+        };
+        qobject.as_mut().set_message_count(count);
+        qobject.as_mut().messages_loaded(count);
+    }
+
+    /// Sign and publish an event, then run `on_success` on the Qt thread with it
+    fn publish<F>(mut self: Pin<&mut Self>, action: &'static str, builder: EventBuilder, on_success: F)
+    where
+        F: FnOnce(Pin<&mut Self>, Event) + Send + 'static,
+    {
+        let Some(user) = self.user_pubkey else {
+            self.as_mut().action_failed(&QString::from(action), &QString::from("Not logged in"));
+            return;
+        };
+
+        let qt_thread = self.qt_thread();
+
+        limits::spawn_limited(move || {
+            let result = CHANNELS_RUNTIME.block_on(async {
+                let event = sign_event_builder(builder, user).await?;
+                let manager = shared_relay_manager().await?;
+                publish::send(manager.client(), &event).await?;
+                Ok::<Event, String>(event)
+            });
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok(event) => {
+                        tracing::info!("Channel action {} published: {}", action, event.id);
+                        on_success(qobject, event);
+                    }
+                    Err(e) => {
+                        tracing::error!("Channel action {} failed: {}", action, e);
+                        qobject.as_mut().action_failed(&QString::from(action), &QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+}
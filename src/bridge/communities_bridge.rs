@@ -0,0 +1,385 @@
+//! Communities bridge - exposes NIP-29 relay-based group administration to QML
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, group_count)]
+        #[qproperty(bool, is_loading)]
+        #[qproperty(QString, relay_url)]
+        #[qproperty(QString, error_message)]
+        type CommunitiesController = super::CommunitiesControllerRust;
+
+        /// Set the logged-in user's pubkey (used to sign admin actions)
+        #[qinvokable]
+        fn set_logged_in_user(self: Pin<&mut CommunitiesController>, pubkey: &QString);
+
+        /// Forget the previous session's groups and user
+        #[qinvokable]
+        fn reset(self: Pin<&mut CommunitiesController>);
+
+        /// Load the groups hosted on a NIP-29 relay
+        #[qinvokable]
+        fn load_groups(self: Pin<&mut CommunitiesController>, relay_url: &QString);
+
+        /// Get group at index (returns JSON)
+        #[qinvokable]
+        fn get_group(self: &CommunitiesController, index: i32) -> QString;
+
+        /// Check if the logged-in user is an admin of a group
+        #[qinvokable]
+        fn is_group_admin(self: &CommunitiesController, group_id: &QString) -> bool;
+
+        /// Create a group on the current relay and set its metadata
+        #[qinvokable]
+        fn create_group(
+            self: Pin<&mut CommunitiesController>,
+            group_id: &QString,
+            name: &QString,
+            about: &QString,
+            picture: &QString,
+            is_private: bool,
+            is_closed: bool,
+        );
+
+        /// Update a group's metadata
+        #[qinvokable]
+        fn set_group_metadata(
+            self: Pin<&mut CommunitiesController>,
+            group_id: &QString,
+            name: &QString,
+            about: &QString,
+            picture: &QString,
+            is_private: bool,
+            is_closed: bool,
+        );
+
+        /// Add a member to a group (role may be empty)
+        #[qinvokable]
+        fn add_member(self: Pin<&mut CommunitiesController>, group_id: &QString, pubkey: &QString, role: &QString);
+
+        /// Remove a member from a group
+        #[qinvokable]
+        fn remove_member(self: Pin<&mut CommunitiesController>, group_id: &QString, pubkey: &QString);
+
+        /// Delete a post from a group
+        #[qinvokable]
+        fn delete_group_event(self: Pin<&mut CommunitiesController>, group_id: &QString, event_id: &QString, reason: &QString);
+    }
+
+    unsafe extern "RustQt" {
+        /// Emitted when groups are loaded
+        #[qsignal]
+        fn groups_loaded(self: Pin<&mut CommunitiesController>, count: i32);
+
+        /// Emitted when the relay accepts an admin action
+        #[qsignal]
+        fn admin_action_succeeded(self: Pin<&mut CommunitiesController>, action: &QString, group_id: &QString);
+
+        /// Emitted when an admin action fails or is rejected by the relay
+        #[qsignal]
+        fn admin_action_failed(self: Pin<&mut CommunitiesController>, action: &QString, group_id: &QString, error: &QString);
+    }
+
+    // Enable threading support for background work with UI updates
+    impl cxx_qt::Threading for CommunitiesController {}
+}
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use cxx_qt_lib::QString;
+use cxx_qt::{CxxQtType, Threading};
+use nostr_sdk::prelude::*;
+use crate::core::limits;
+use crate::nostr::groups::{self, GroupMetadata, GroupSettings};
+use crate::nostr::relay::fetch_timeout;
+use crate::nostr::relay_info;
+use crate::bridge::feed_bridge::{create_authenticated_relay_manager, sign_event_builder};
+
+// Global tokio runtime for community operations
+lazy_static::lazy_static! {
+    static ref COMMUNITIES_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+}
+
+/// Bumped by reset_communities_state, so results for the previous session are dropped
+static COMMUNITIES_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Drop group loads and admin actions still running for the previous session
+pub fn reset_communities_state() {
+    COMMUNITIES_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Rust implementation of CommunitiesController
+#[derive(Default)]
+pub struct CommunitiesControllerRust {
+    group_count: i32,
+    is_loading: bool,
+    relay_url: QString,
+    error_message: QString,
+
+    // Internal state
+    groups: Vec<GroupMetadata>,
+    user_pubkey: Option<PublicKey>,
+}
+
+impl qobject::CommunitiesController {
+    /// Set the logged-in user's pubkey
+    pub fn set_logged_in_user(mut self: Pin<&mut Self>, pubkey: &QString) {
+        self.as_mut().rust_mut().user_pubkey = PublicKey::parse(&pubkey.to_string()).ok();
+    }
+
+    /// Clear state from the previous session
+    pub fn reset(mut self: Pin<&mut Self>) {
+        {
+            let mut rust = self.as_mut().rust_mut();
+            rust.groups.clear();
+            rust.user_pubkey = None;
+        }
+        self.as_mut().set_group_count(0);
+        self.as_mut().set_is_loading(false);
+        self.as_mut().set_relay_url(QString::from(""));
+        self.as_mut().set_error_message(QString::from(""));
+    }
+
+    /// Load groups from a NIP-29 relay
+    pub fn load_groups(mut self: Pin<&mut Self>, relay_url: &QString) {
+        let url = relay_url.to_string().trim().to_string();
+        if RelayUrl::parse(&url).is_err() {
+            let error = format!("Invalid relay URL: {}", url);
+            self.as_mut().set_error_message(QString::from(&error));
+            return;
+        }
+
+        self.as_mut().set_relay_url(QString::from(&url));
+        self.as_mut().set_is_loading(true);
+        self.as_mut().set_error_message(QString::from(""));
+
+        let qt_thread = self.qt_thread();
+        let generation = COMMUNITIES_GENERATION.load(Ordering::SeqCst);
+
+        limits::spawn_limited(move || {
+            let result = COMMUNITIES_RUNTIME.block_on(async {
+                // Group state only counts when signed by the relay's own key
+                let info = relay_info::cached_relay_info(&url).await?;
+                let relay_pubkey = info.pubkey.as_deref()
+                    .and_then(|pk| PublicKey::from_hex(pk).ok())
+                    .ok_or("Relay doesn't publish a pubkey (NIP-11), so its groups can't be verified")?;
+
+                let mut manager = create_authenticated_relay_manager();
+                manager.connect_to(&[url.clone()]).await?;
+
+                let events = manager.client()
+                    .fetch_events(groups::group_state_filter(&relay_pubkey), fetch_timeout())
+                    .await
+                    .map_err(|e| format!("Failed to fetch groups: {}", e))?;
+
+                manager.disconnect().await;
+
+                let events: Vec<Event> = events.into_iter()
+                    .filter(|e| groups::is_relay_signed(e, &relay_pubkey))
+                    .collect();
+
+                // Metadata first, then attach admin/member lists by d tag
+                let mut by_id: HashMap<String, GroupMetadata> = events.iter()
+                    .filter_map(|e| GroupMetadata::from_event(e, &url))
+                    .map(|g| (g.id.clone(), g))
+                    .collect();
+
+                for event in events.iter() {
+                    let Some(d) = event.tags.identifier() else { continue };
+                    if let Some(group) = by_id.get_mut(d) {
+                        group.apply_member_list(event);
+                    }
+                }
+
+                let mut groups: Vec<GroupMetadata> = by_id.into_values().collect();
+                groups.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.cmp(&b.id)));
+                Ok::<Vec<GroupMetadata>, String>(groups)
+            });
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                if COMMUNITIES_GENERATION.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+                qobject.as_mut().set_is_loading(false);
+                match result {
+                    Ok(groups) => {
+                        let count = groups.len() as i32;
+                        tracing::info!("Loaded {} NIP-29 groups", count);
+                        qobject.as_mut().rust_mut().groups = groups;
+                        qobject.as_mut().set_group_count(count);
+                        qobject.as_mut().groups_loaded(count);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to load groups: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+
+    /// Get group at index
+    pub fn get_group(&self, index: i32) -> QString {
+        if let Some(group) = self.groups.get(index as usize) {
+            QString::from(&group.to_json())
+        } else {
+            QString::from("{}")
+        }
+    }
+
+    /// Check if the logged-in user administers a group
+    pub fn is_group_admin(&self, group_id: &QString) -> bool {
+        let Some(user) = self.user_pubkey else {
+            return false;
+        };
+        let group_id = group_id.to_string();
+        self.groups.iter()
+            .find(|g| g.id == group_id)
+            .map(|g| g.admins.contains(&user.to_hex()))
+            .unwrap_or(false)
+    }
+
+    /// Create a group and set its initial metadata
+    pub fn create_group(
+        self: Pin<&mut Self>,
+        group_id: &QString,
+        name: &QString,
+        about: &QString,
+        picture: &QString,
+        is_private: bool,
+        is_closed: bool,
+    ) {
+        let id = group_id.to_string();
+        let settings = GroupSettings {
+            name: name.to_string(),
+            about: about.to_string(),
+            picture: picture.to_string(),
+            is_private,
+            is_closed,
+        };
+        let builders = vec![groups::create_group(&id), groups::edit_metadata(&id, &settings)];
+        self.publish_admin_action("create_group", id, builders);
+    }
+
+    /// Update group metadata
+    pub fn set_group_metadata(
+        self: Pin<&mut Self>,
+        group_id: &QString,
+        name: &QString,
+        about: &QString,
+        picture: &QString,
+        is_private: bool,
+        is_closed: bool,
+    ) {
+        let id = group_id.to_string();
+        let settings = GroupSettings {
+            name: name.to_string(),
+            about: about.to_string(),
+            picture: picture.to_string(),
+            is_private,
+            is_closed,
+        };
+        let builders = vec![groups::edit_metadata(&id, &settings)];
+        self.publish_admin_action("set_metadata", id, builders);
+    }
+
+    /// Add a member to a group
+    pub fn add_member(mut self: Pin<&mut Self>, group_id: &QString, pubkey: &QString, role: &QString) {
+        let id = group_id.to_string();
+        let Ok(member) = PublicKey::parse(&pubkey.to_string()) else {
+            self.as_mut().admin_action_failed(&QString::from("add_member"), group_id, &QString::from("Invalid public key"));
+            return;
+        };
+        let role = role.to_string();
+        let builders = vec![groups::put_user(&id, &member, Some(&role))];
+        self.publish_admin_action("add_member", id, builders);
+    }
+
+    /// Remove a member from a group
+    pub fn remove_member(mut self: Pin<&mut Self>, group_id: &QString, pubkey: &QString) {
+        let id = group_id.to_string();
+        let Ok(member) = PublicKey::parse(&pubkey.to_string()) else {
+            self.as_mut().admin_action_failed(&QString::from("remove_member"), group_id, &QString::from("Invalid public key"));
+            return;
+        };
+        let builders = vec![groups::remove_user(&id, &member)];
+        self.publish_admin_action("remove_member", id, builders);
+    }
+
+    /// Delete a post from a group
+    pub fn delete_group_event(mut self: Pin<&mut Self>, group_id: &QString, event_id: &QString, reason: &QString) {
+        let id = group_id.to_string();
+        let Ok(target) = EventId::parse(&event_id.to_string()) else {
+            self.as_mut().admin_action_failed(&QString::from("delete_event"), group_id, &QString::from("Invalid event ID"));
+            return;
+        };
+        let builders = vec![groups::delete_event(&id, &target, &reason.to_string())];
+        self.publish_admin_action("delete_event", id, builders);
+    }
+
+    /// Sign and send moderation events to the group relay, in order
+    fn publish_admin_action(mut self: Pin<&mut Self>, action: &'static str, group_id: String, builders: Vec<EventBuilder>) {
+        let relay_url = self.relay_url.to_string();
+        let Some(user_pk) = self.user_pubkey else {
+            self.as_mut().admin_action_failed(&QString::from(action), &QString::from(&group_id), &QString::from("Not logged in"));
+            return;
+        };
+        if relay_url.is_empty() {
+            self.as_mut().admin_action_failed(&QString::from(action), &QString::from(&group_id), &QString::from("No group relay selected"));
+            return;
+        }
+
+        let qt_thread = self.qt_thread();
+        let generation = COMMUNITIES_GENERATION.load(Ordering::SeqCst);
+
+        limits::spawn_limited(move || {
+            let result = COMMUNITIES_RUNTIME.block_on(async {
+                let mut manager = create_authenticated_relay_manager();
+                manager.connect_to(&[relay_url.clone()]).await?;
+
+                for builder in builders {
+                    if COMMUNITIES_GENERATION.load(Ordering::SeqCst) != generation {
+                        return Err("Logged out".to_string());
+                    }
+                    let event = sign_event_builder(builder, user_pk).await?;
+                    let output = manager.client().send_event(&event).await
+                        .map_err(|e| format!("Failed to send: {}", e))?;
+
+                    // The group relay is the only authority - it must accept the event
+                    if output.success.is_empty() {
+                        let reason = output.failed.values().next().cloned()
+                            .unwrap_or_else(|| "Rejected by relay".to_string());
+                        return Err(reason);
+                    }
+                }
+
+                manager.disconnect().await;
+                Ok::<(), String>(())
+            });
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                if COMMUNITIES_GENERATION.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+                match result {
+                    Ok(()) => {
+                        tracing::info!("Group action {} succeeded for {}", action, group_id);
+                        qobject.as_mut().admin_action_succeeded(&QString::from(action), &QString::from(&group_id));
+                    }
+                    Err(e) => {
+                        tracing::error!("Group action {} failed for {}: {}", action, group_id, e);
+                        qobject.as_mut().admin_action_failed(&QString::from(action), &QString::from(&group_id), &QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+}
@@ -0,0 +1,214 @@
+//! Discovery bridge - exposes trending notes and hashtags to QML for the Explore screen
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, note_count)]
+        #[qproperty(i32, window_hours)]
+        #[qproperty(bool, is_loading)]
+        #[qproperty(QString, error_message)]
+        type DiscoveryController = super::DiscoveryControllerRust;
+
+        /// Sample the last window_hours (1, 6 or 24) of activity and rank it (async)
+        /// Emits discovery_updated when done
+        #[qinvokable]
+        fn load(self: Pin<&mut DiscoveryController>, window_hours: i32);
+
+        /// Load again if the last sample is older than a few minutes
+        #[qinvokable]
+        fn refresh_if_stale(self: Pin<&mut DiscoveryController>);
+
+        /// Forget the previous session's results
+        #[qinvokable]
+        fn reset(self: Pin<&mut DiscoveryController>);
+
+        /// Trending note at index, as feed notes plus {reactors, zappers, zapSats}
+        #[qinvokable]
+        fn get_note(self: &DiscoveryController, index: i32) -> QVariant;
+
+        /// Trending hashtags as a JSON array [{tag, notes, authors}]
+        #[qinvokable]
+        fn get_hashtags(self: &DiscoveryController) -> QString;
+    }
+
+    unsafe extern "RustQt" {
+        /// Emitted whenever a sample finishes
+        #[qsignal]
+        fn discovery_updated(self: Pin<&mut DiscoveryController>);
+    }
+
+    // Enable threading support for background work with UI updates
+    impl cxx_qt::Threading for DiscoveryController {}
+}
+
+use std::collections::HashMap;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use cxx_qt_lib::{QString, QVariant};
+use cxx_qt::{CxxQtType, Threading};
+use nostr_sdk::prelude::*;
+use crate::core::limits;
+use crate::nostr::discovery::{self, TrendingHashtag, TrendingNote};
+use crate::nostr::feed::DisplayNote;
+use crate::nostr::profile::ProfileCache;
+use crate::bridge::feed_bridge::shared_relay_manager;
+use crate::bridge::variant;
+
+// Global tokio runtime for discovery sampling
+lazy_static::lazy_static! {
+    static ref DISCOVERY_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+}
+
+/// Bumped so a sample finishing after reset or a newer load is dropped
+static DISCOVERY_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// refresh_if_stale samples again after this long
+const STALE_AFTER: Duration = Duration::from_secs(10 * 60);
+
+/// Rust implementation of DiscoveryController
+pub struct DiscoveryControllerRust {
+    note_count: i32,
+    window_hours: i32,
+    is_loading: bool,
+    error_message: QString,
+
+    // Internal state
+    notes: Vec<(DisplayNote, TrendingNote)>,
+    hashtags: Vec<TrendingHashtag>,
+    loaded_at: Option<Instant>,
+}
+
+impl Default for DiscoveryControllerRust {
+    fn default() -> Self {
+        Self {
+            note_count: 0,
+            window_hours: discovery::DEFAULT_WINDOW_HOURS as i32,
+            is_loading: false,
+            error_message: QString::default(),
+            notes: Vec::new(),
+            hashtags: Vec::new(),
+            loaded_at: None,
+        }
+    }
+}
+
+impl qobject::DiscoveryController {
+    /// Sample and rank in the background
+    pub fn load(mut self: Pin<&mut Self>, window_hours: i32) {
+        let hours = discovery::WINDOW_HOURS.iter()
+            .copied()
+            .find(|h| *h as i32 == window_hours)
+            .unwrap_or(discovery::DEFAULT_WINDOW_HOURS);
+        let generation = DISCOVERY_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+
+        self.as_mut().set_window_hours(hours as i32);
+        self.as_mut().set_is_loading(true);
+        self.as_mut().set_error_message(QString::from(""));
+
+        let qt_thread = self.qt_thread();
+        limits::spawn_limited(move || {
+            let result = DISCOVERY_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                let (notes, hashtags) = discovery::sample(manager.client(), hours).await;
+
+                let pubkeys: Vec<PublicKey> = notes.iter()
+                    .map(|n| n.event.pubkey)
+                    .collect::<std::collections::HashSet<_>>()
+                    .into_iter()
+                    .collect();
+                let profiles: HashMap<String, ProfileCache> = manager.fetch_profiles(&pubkeys).await
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|e| ProfileCache::from_event(&e).ok().map(|p| (e.pubkey.to_hex(), p)))
+                    .collect();
+
+                let notes: Vec<(DisplayNote, TrendingNote)> = notes.into_iter()
+                    .map(|trending| {
+                        let profile = profiles.get(&trending.event.pubkey.to_hex());
+                        (DisplayNote::from_event_with_profiles(&trending.event, profile, &profiles), trending)
+                    })
+                    .collect();
+                Ok::<_, String>((notes, hashtags))
+            });
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                if DISCOVERY_GENERATION.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+                qobject.as_mut().set_is_loading(false);
+                match result {
+                    Ok((notes, hashtags)) => {
+                        tracing::info!("Discovery: {} trending notes, {} hashtags in the last {}h", notes.len(), hashtags.len(), hours);
+                        let count = notes.len() as i32;
+                        {
+                            let mut rust = qobject.as_mut().rust_mut();
+                            rust.notes = notes;
+                            rust.hashtags = hashtags;
+                            rust.loaded_at = Some(Instant::now());
+                        }
+                        qobject.as_mut().set_note_count(count);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to load trending notes: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                    }
+                }
+                qobject.as_mut().discovery_updated();
+            });
+        });
+    }
+
+    /// Sample again when the results are old or missing
+    pub fn refresh_if_stale(self: Pin<&mut Self>) {
+        if self.is_loading || self.loaded_at.is_some_and(|at| at.elapsed() < STALE_AFTER) {
+            return;
+        }
+        let hours = self.window_hours;
+        self.load(hours);
+    }
+
+    /// Clear state from the previous session
+    pub fn reset(mut self: Pin<&mut Self>) {
+        DISCOVERY_GENERATION.fetch_add(1, Ordering::SeqCst);
+        {
+            let mut rust = self.as_mut().rust_mut();
+            rust.notes.clear();
+            rust.hashtags.clear();
+            rust.loaded_at = None;
+        }
+        self.as_mut().set_note_count(0);
+        self.as_mut().set_is_loading(false);
+        self.as_mut().set_error_message(QString::from(""));
+    }
+
+    /// Trending note at index
+    pub fn get_note(&self, index: i32) -> QVariant {
+        let Some((note, trending)) = self.notes.get(index as usize) else {
+            return variant::empty_map();
+        };
+        let mut value = note.to_value();
+        value["reactors"] = serde_json::json!(trending.reactors);
+        value["zappers"] = serde_json::json!(trending.zappers);
+        value["zapSats"] = serde_json::json!(trending.zap_sats);
+        variant::from_json(&value)
+    }
+
+    /// Trending hashtags
+    pub fn get_hashtags(&self) -> QString {
+        let tags: Vec<serde_json::Value> = self.hashtags.iter()
+            .map(|t| serde_json::json!({ "tag": t.tag, "notes": t.notes, "authors": t.authors }))
+            .collect();
+        QString::from(&serde_json::Value::Array(tags).to_string())
+    }
+}
@@ -25,10 +25,18 @@ pub mod qobject {
         #[qinvokable]
         fn load_conversations(self: Pin<&mut DmController>);
         
+        /// Clear all per-user state so initialize can run again (logout / account switch)
+        #[qinvokable]
+        fn reset(self: Pin<&mut DmController>);
+        
         /// Get conversation at index (returns JSON)
         #[qinvokable]
         fn get_conversation(self: &DmController, index: i32) -> QString;
         
+        /// Get a conversation by peer pubkey (returns JSON, empty if unknown)
+        #[qinvokable]
+        fn get_conversation_by_pubkey(self: &DmController, peer_pubkey: &QString) -> QString;
+        
         /// Select a conversation
         #[qinvokable]
         fn select_conversation(self: Pin<&mut DmController>, peer_pubkey: &QString);
@@ -45,6 +53,23 @@ pub mod qobject {
         #[qinvokable]
         fn send_message(self: Pin<&mut DmController>, content: &QString);
         
+        /// Upload an image (encrypted if enabled in settings) and send it with an optional caption
+        #[qinvokable]
+        fn send_attachment(self: Pin<&mut DmController>, file_path: &QString, caption: &QString);
+        
+        /// Download and decrypt an attachment link from get_messages (async)
+        /// Emits attachment_ready or attachment_failed
+        #[qinvokable]
+        fn fetch_attachment(self: Pin<&mut DmController>, link: &QString);
+        
+        /// Send a failed message again by its operation id (from operation_failed)
+        #[qinvokable]
+        fn retry_operation(self: Pin<&mut DmController>, op_id: &QString) -> bool;
+        
+        /// Messages that failed to send as JSON [{id, kind, target, error, failedAt}]
+        #[qinvokable]
+        fn get_failed_operations(self: &DmController) -> QString;
+        
         /// Start new conversation
         #[qinvokable]
         fn start_conversation(self: Pin<&mut DmController>, pubkey: &QString);
@@ -76,15 +101,40 @@ pub mod qobject {
         /// Get category counts (returns JSON: {all, favorites, unfiltered, regular, archive})
         #[qinvokable]
         fn get_category_counts(self: &DmController) -> QString;
+        
+        /// Call as the user types; tells the selected peer we're typing (throttled,
+        /// only when DM presence sharing is on)
+        #[qinvokable]
+        fn notify_typing(self: Pin<&mut DmController>);
     }
 
     unsafe extern "RustQt" {
+        /// The conversation list changed order or membership; reload it
         #[qsignal]
         fn conversations_updated(self: Pin<&mut DmController>);
         
+        /// One conversation got new messages or its unread count changed, in place
+        #[qsignal]
+        fn conversation_updated(self: Pin<&mut DmController>, peer_pubkey: &QString);
+        
         #[qsignal]
         fn messages_updated(self: Pin<&mut DmController>);
         
+        /// A peer is typing to us; clear the indicator after a few seconds without another
+        #[qsignal]
+        fn peer_typing(self: Pin<&mut DmController>, peer_pubkey: &QString);
+        
+        /// A peer read our messages up to a time
+        #[qsignal]
+        fn peer_read(self: Pin<&mut DmController>, peer_pubkey: &QString, up_to: i64);
+        
+        /// An attachment is ready to show from a local file:// URL
+        #[qsignal]
+        fn attachment_ready(self: Pin<&mut DmController>, link: &QString, local_url: &QString);
+        
+        #[qsignal]
+        fn attachment_failed(self: Pin<&mut DmController>, link: &QString, error: &QString);
+        
         #[qsignal]
         fn message_sent(self: Pin<&mut DmController>, message_id: &QString);
         
@@ -93,25 +143,56 @@ pub mod qobject {
         
         #[qsignal]
         fn error_occurred(self: Pin<&mut DmController>, error: &QString);
+        
+        /// Operation signals, see FeedController; op_id is "send_message:<peer>:<content hash>"
+        #[qsignal]
+        fn operation_started(self: Pin<&mut DmController>, op_id: &QString, kind: &QString);
+        
+        #[qsignal]
+        fn operation_succeeded(self: Pin<&mut DmController>, op_id: &QString, kind: &QString, result: &QString);
+        
+        #[qsignal]
+        fn operation_failed(self: Pin<&mut DmController>, op_id: &QString, kind: &QString, error: &QString);
     }
+    
+    impl cxx_qt::Threading for DmController {}
 }
 
 use std::pin::Pin;
 use std::sync::Arc;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use cxx_qt_lib::QString;
-use cxx_qt::CxxQtType;
+use cxx_qt::{CxxQtType, Threading};
 use nostr_sdk::prelude::*;
 use tokio::sync::Mutex;
 
+use crate::core::desktop_notify::{self, NotifyKind};
+use crate::core::limits;
+use crate::core::media_cache;
+use crate::core::network;
+use crate::core::operations::{self, Operation, OperationKind};
+use crate::core::refresh;
+use crate::core::tray;
 use crate::signer::SignerClient;
-use crate::nostr::dm::{DmManager, DmMessage, DmProtocol, ConversationCategory, fetch_nip04_dms, get_nip04_peer, format_pubkey_short};
-use crate::nostr::relay::DEFAULT_TIMEOUT;
+use crate::nostr::dm::{
+    DmManager, DmMessage, DmProtocol, ConversationCategory, PresenceState,
+    fetch_nip04_dms, get_nip04_peer, format_pubkey_short,
+    create_presence_event, parse_presence_event, presence_filter,
+};
+use crate::nostr::database::{DmReadState, NostrDbManager};
+use crate::nostr::dm_media;
 use crate::nostr::profile::ProfileCache;
+use crate::nostr::profile_store;
 
 // Cache duration for DMs (5 minutes)
 const DM_CACHE_DURATION_SECS: u64 = 5 * 60;
 
+/// Least time between typing indicators sent to the same peer
+const TYPING_SEND_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// How often the presence listener checks whether it should stop
+const PRESENCE_POLL: std::time::Duration = std::time::Duration::from_secs(1);
+
 // Global state
 lazy_static::lazy_static! {
     static ref DM_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
@@ -124,6 +205,15 @@ lazy_static::lazy_static! {
     static ref DM_NSEC: Arc<std::sync::RwLock<Option<String>>> = Arc::new(std::sync::RwLock::new(None));
     // Last fetch timestamp for caching
     static ref DM_LAST_FETCH: Arc<std::sync::RwLock<Option<std::time::Instant>>> = Arc::new(std::sync::RwLock::new(None));
+    // Bumped to start or stop the background DM poll; older loops exit
+    static ref DM_POLL_GENERATION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    // Whether typing indicators and read receipts are shared (privacy setting, off by default)
+    static ref DM_PRESENCE: std::sync::atomic::AtomicBool =
+        std::sync::atomic::AtomicBool::new(crate::core::config::Config::load().dm_presence);
+    // Bumped to start or stop the presence listener
+    static ref DM_PRESENCE_GENERATION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    // Peer pubkey -> newest message time they told us they read
+    static ref PEER_READ_UP_TO: std::sync::RwLock<HashMap<String, i64>> = std::sync::RwLock::new(HashMap::new());
 }
 
 /// Rust implementation of DmController
@@ -140,6 +230,10 @@ pub struct DmControllerRust {
     current_protocol: DmProtocol,
     initialized: bool,
     category_filter: Option<ConversationCategory>,
+    /// Peer and time of the last typing indicator sent
+    last_typing_sent: Option<(String, std::time::Instant)>,
+    /// Peer -> newest message time covered by a read receipt we sent
+    receipts_sent: HashMap<String, i64>,
 }
 
 impl Default for DmControllerRust {
@@ -155,11 +249,33 @@ impl Default for DmControllerRust {
             current_protocol: DmProtocol::Nip04,
             initialized: false,
             category_filter: None,
+            last_typing_sent: None,
+            receipts_sent: HashMap::new(),
         }
     }
 }
 
 impl qobject::DmController {
+    /// Clear all per-user state
+    pub fn reset(mut self: Pin<&mut Self>) {
+        refresh::stop_background_poll(&DM_POLL_GENERATION);
+        DM_PRESENCE_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        {
+            let mut rust = self.as_mut().rust_mut();
+            rust.user_pubkey = None;
+            rust.user_nsec = None;
+            rust.initialized = false;
+            rust.category_filter = None;
+            rust.last_typing_sent = None;
+            rust.receipts_sent.clear();
+        }
+        self.as_mut().set_conversation_count(0);
+        self.as_mut().set_unread(0);
+        self.as_mut().set_selected_conversation(QString::from(""));
+        self.as_mut().set_error_message(QString::from(""));
+        self.as_mut().set_is_loading(false);
+    }
+    
     /// Initialize DM controller with user's pubkey
     pub fn initialize(mut self: Pin<&mut Self>, user_pubkey: &QString) {
         let pubkey_str = user_pubkey.to_string();
@@ -192,20 +308,20 @@ impl qobject::DmController {
                     if let Ok(secret_key) = SecretKey::parse(nsec) {
                         let keys = Keys::new(secret_key);
                         tracing::info!("Creating DM client with signing keys for NIP-42 auth");
-                        Client::new(keys)
+                        network::nostr_client(Some(keys))
                     } else {
                         tracing::warn!("Invalid nsec, creating DM client without keys");
-                        Client::default()
+                        network::nostr_client(None)
                     }
                 } else {
                     tracing::warn!("No nsec available for DM client, relay auth may fail");
-                    Client::default()
+                    network::nostr_client(None)
                 }
             };
             
-            // Add default relays
-            for relay in crate::nostr::relay::DEFAULT_RELAYS {
-                let _ = client.add_relay(*relay).await;
+            // Add the configured relays
+            for relay in crate::nostr::relay::configured_relays() {
+                let _ = client.add_relay(relay).await;
             }
             
             client.connect().await;
@@ -223,13 +339,65 @@ impl qobject::DmController {
             }
         }
         
+        self.as_mut().start_background_poll();
+        self.as_mut().start_presence_listener();
+        
         tracing::info!("DmController initialized");
     }
+    
+    /// Listen for typing indicators and read receipts sent to the user
+    /// Subscribes only while presence sharing is on; toggling it takes effect within a second
+    fn start_presence_listener(self: Pin<&mut Self>) {
+        use std::sync::atomic::Ordering;
+        
+        let Some(user_pk) = self.user_pubkey.as_deref().and_then(|pk| PublicKey::parse(pk).ok()) else { return };
+        let generation = DM_PRESENCE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            while DM_PRESENCE_GENERATION.load(Ordering::SeqCst) == generation {
+                let client = DM_CLIENT.read().unwrap().clone();
+                if let (true, Some(keys), Some(client)) = (dm_presence_enabled(), dm_keys(), client) {
+                    let result = DM_RUNTIME.block_on(listen_for_presence(&client, &keys, &user_pk, generation, &qt_thread));
+                    if let Err(e) = result {
+                        tracing::warn!("DM presence unavailable: {}", e);
+                    }
+                }
+                std::thread::sleep(PRESENCE_POLL);
+            }
+        });
+    }
+    
+    /// Poll for new DMs in the background so the unread count and tray badge
+    /// stay current while the messages screen is closed
+    fn start_background_poll(self: Pin<&mut Self>) {
+        let Some(user_pubkey) = self.user_pubkey.clone() else { return };
+        let qt_thread = self.qt_thread();
+        let generation = DM_POLL_GENERATION.load(std::sync::atomic::Ordering::SeqCst) + 1;
+        
+        refresh::start_background_poll(&DM_POLL_GENERATION, move || {
+            tracing::debug!("Background DM poll");
+            let user_nsec = DM_NSEC.read().unwrap().clone();
+            let result = fetch_conversations(&user_pubkey, user_nsec);
+            let _ = qt_thread.queue(move |qobject| {
+                // Dropped if the user logged out while the fetch was running
+                if DM_POLL_GENERATION.load(std::sync::atomic::Ordering::SeqCst) == generation {
+                    qobject.apply_conversations(result);
+                }
+            });
+        });
+    }
 
     pub fn load_conversations(self: Pin<&mut Self>) {
         self.load_conversations_with_cache(false);
     }
     
+    /// Update the unread count and the tray badge together
+    fn set_unread(self: Pin<&mut Self>, count: i32) {
+        tray::set_unread_messages(count.max(0) as u32);
+        self.set_unread_count(count);
+    }
+    
     /// Load conversations, optionally forcing a network refresh
     fn load_conversations_with_cache(mut self: Pin<&mut Self>, force_refresh: bool) {
         // Check if we have cached data and it's still fresh
@@ -259,7 +427,7 @@ impl qobject::DmController {
             drop(dm_mgr);
             
             self.as_mut().set_conversation_count(count);
-            self.as_mut().set_unread_count(unread);
+            self.as_mut().set_unread(unread);
             self.as_mut().set_is_loading(false);
             self.as_mut().conversations_updated();
             return;
@@ -281,62 +449,27 @@ impl qobject::DmController {
         
         let user_nsec = self.user_nsec.clone();
         
-        let result = DM_RUNTIME.block_on(async {
-            let pk = PublicKey::parse(&user_pubkey)
-                .map_err(|e| format!("Invalid pubkey: {}", e))?;
-            
-            // Get relay client
-            let client = {
-                let c = DM_CLIENT.read().unwrap();
-                c.clone().ok_or("Not connected to relays")?
-            };
-            
-            // Fetch NIP-04 DMs
-            let events = fetch_nip04_dms(&client, &pk, 100).await?;
-            
-            // Process events into conversations
-            let mut conversations: HashMap<String, Vec<(Event, bool)>> = HashMap::new();
-            
-            for event in events.iter() {
-                if let Some(peer_pk) = get_nip04_peer(event, &pk) {
-                    let peer_hex = peer_pk.to_hex();
-                    let is_outgoing = event.pubkey == pk;
-                    conversations.entry(peer_hex).or_default().push((event.clone(), is_outgoing));
-                }
-            }
-            
-            // Get unique peer pubkeys for profile fetching
-            let peer_pubkeys: Vec<PublicKey> = conversations.keys()
-                .filter_map(|hex| PublicKey::parse(hex).ok())
-                .collect();
-            
-            // Fetch profiles
-            let profiles = if !peer_pubkeys.is_empty() {
-                let profile_filter = Filter::new()
-                    .kind(Kind::Metadata)
-                    .authors(peer_pubkeys.clone());
-                
-                client.fetch_events(profile_filter, DEFAULT_TIMEOUT).await.ok()
-            } else {
-                None
-            };
-            
-            // Parse profiles
-            let mut profile_map: HashMap<String, ProfileCache> = HashMap::new();
-            if let Some(profile_events) = profiles {
-                for event in profile_events.iter() {
-                    if let Ok(metadata) = Metadata::from_json(&event.content) {
-                        profile_map.insert(event.pubkey.to_hex(), ProfileCache::from_metadata(&metadata));
-                    }
-                }
-            }
-            
-            Ok::<_, String>((conversations, profile_map, pk, user_nsec))
-        });
-        
+        let result = fetch_conversations(&user_pubkey, user_nsec);
+        self.apply_conversations(result);
+    }
+    
+    /// Merge fetched conversations into the DM manager and update counts
+    /// Reports incoming messages that are new since the previous load
+    fn apply_conversations(mut self: Pin<&mut Self>, result: Result<FetchedConversations, String>) {
         match result {
             Ok((conversations, profiles, user_pk, nsec_opt)) => {
                 let mut dm_mgr = DM_MANAGER.write().unwrap();
+                let order_before = dm_mgr.conversation_order(self.category_filter);
+                let mut changed = HashSet::new();
+                
+                // Incoming messages newer than this are new since the last load
+                // (nothing is reported on the first load)
+                let previous_newest_incoming = dm_mgr.get_conversations().iter()
+                    .flat_map(|c| c.messages.iter())
+                    .filter(|m| !m.is_outgoing)
+                    .map(|m| m.created_at)
+                    .max();
+                let mut new_incoming: HashMap<String, (String, i64)> = HashMap::new();
                 
                 // Check if we have a signer or nsec for decryption
                 let has_signer = DM_RUNTIME.block_on(async {
@@ -406,16 +539,34 @@ impl qobject::DmController {
                             protocol: DmProtocol::Nip04,
                         };
                         
-                        dm_mgr.add_message(msg);
+                        if let Some(previous) = previous_newest_incoming {
+                            if !msg.is_outgoing && msg.created_at > previous {
+                                let newest = new_incoming.get(&peer_hex).map_or(0, |(_, ts)| *ts);
+                                if msg.created_at > newest {
+                                    new_incoming.insert(peer_hex.clone(), (msg.content.clone(), msg.created_at));
+                                }
+                            }
+                        }
+                        
+                        if dm_mgr.add_message(msg) {
+                            changed.insert(peer_hex.clone());
+                        }
                     }
                 }
                 
                 // Apply saved categories
                 dm_mgr.apply_saved_categories();
                 
-                // Get count based on current filter
-                let count = dm_mgr.get_conversations_by_category(self.category_filter).len() as i32;
-                let unread = dm_mgr.total_unread() as i32;
+                // New messages in the open conversation are read as they arrive
+                let user_hex = user_pk.to_hex();
+                let selected = self.selected_conversation.to_string();
+                if changed.contains(&selected) {
+                    if let Some(up_to) = dm_mgr.mark_read(&selected) {
+                        mark_dm_read(&user_hex, &selected, up_to);
+                        self.as_mut().send_read_receipt(&selected, up_to);
+                    }
+                }
+                changed.extend(dm_mgr.recount_unread(&dm_read_state(&user_hex)));
                 
                 drop(dm_mgr);
                 
@@ -425,10 +576,20 @@ impl qobject::DmController {
                     *last_fetch = Some(std::time::Instant::now());
                 }
                 
-                self.as_mut().set_conversation_count(count);
-                self.as_mut().set_unread_count(unread);
                 self.as_mut().set_is_loading(false);
-                self.as_mut().conversations_updated();
+                let count = self.as_mut().publish_conversation_changes(&order_before, &changed);
+                if changed.contains(&selected) {
+                    self.as_mut().messages_updated();
+                }
+                
+                for (peer_hex, (content, _)) in new_incoming {
+                    let name = profiles.get(&peer_hex)
+                        .and_then(|p| p.display_name.clone().or(p.name.clone()))
+                        .unwrap_or_else(|| format_pubkey_short(&peer_hex));
+                    let preview: String = content.chars().take(100).collect();
+                    desktop_notify::notify(NotifyKind::DirectMessage, &name, &preview, &peer_hex);
+                    self.as_mut().new_message_received(&QString::from(&peer_hex), &QString::from(&preview));
+                }
                 
                 tracing::info!("Loaded {} conversations (filtered)", count);
             }
@@ -452,6 +613,14 @@ impl qobject::DmController {
         }
     }
     
+    pub fn get_conversation_by_pubkey(&self, peer_pubkey: &QString) -> QString {
+        let dm_mgr = DM_MANAGER.read().unwrap();
+        match dm_mgr.get_conversation(&peer_pubkey.to_string()) {
+            Some(convo) => QString::from(&convo.to_json()),
+            None => QString::from(""),
+        }
+    }
+    
     pub fn set_category_filter(mut self: Pin<&mut Self>, category: &QString) {
         let cat_str = category.to_string();
         let filter = match cat_str.as_str() {
@@ -539,7 +708,12 @@ impl qobject::DmController {
         // Mark as read
         {
             let mut dm_mgr = DM_MANAGER.write().unwrap();
-            dm_mgr.mark_read(&pubkey_str);
+            if let Some(up_to) = dm_mgr.mark_read(&pubkey_str) {
+                if let Some(user) = self.user_pubkey.as_deref() {
+                    mark_dm_read(user, &pubkey_str, up_to);
+                }
+                self.as_mut().send_read_receipt(&pubkey_str, up_to);
+            }
             
             // Update protocol based on conversation
             if let Some(convo) = dm_mgr.get_conversation(&pubkey_str) {
@@ -553,10 +727,56 @@ impl qobject::DmController {
             let dm_mgr = DM_MANAGER.read().unwrap();
             dm_mgr.total_unread() as i32
         };
-        self.as_mut().set_unread_count(unread);
+        self.as_mut().set_unread(unread);
+        self.as_mut().conversation_updated(peer_pubkey);
         self.as_mut().messages_updated();
     }
     
+    pub fn notify_typing(mut self: Pin<&mut Self>) {
+        let selected = self.selected_conversation.to_string();
+        if selected.is_empty() || !dm_presence_enabled() {
+            return;
+        }
+        if let Some((peer, sent_at)) = &self.last_typing_sent {
+            if *peer == selected && sent_at.elapsed() < TYPING_SEND_INTERVAL {
+                return;
+            }
+        }
+        
+        self.as_mut().rust_mut().last_typing_sent = Some((selected.clone(), std::time::Instant::now()));
+        send_presence(&selected, PresenceState::Typing);
+    }
+    
+    /// Tell a peer we read their messages up to a time, unless a receipt already covers it
+    fn send_read_receipt(mut self: Pin<&mut Self>, peer: &str, up_to: i64) {
+        if !dm_presence_enabled() || self.receipts_sent.get(peer).is_some_and(|sent| *sent >= up_to) {
+            return;
+        }
+        self.as_mut().rust_mut().receipts_sent.insert(peer.to_string(), up_to);
+        send_presence(peer, PresenceState::Read(up_to));
+    }
+    
+    /// Update counts and tell QML what changed: the whole list if its order
+    /// changed, otherwise just the changed rows. Returns the conversation count
+    fn publish_conversation_changes(mut self: Pin<&mut Self>, order_before: &[String], changed: &HashSet<String>) -> i32 {
+        let (order_after, unread) = {
+            let dm_mgr = DM_MANAGER.read().unwrap();
+            (dm_mgr.conversation_order(self.category_filter), dm_mgr.total_unread() as i32)
+        };
+        let count = order_after.len() as i32;
+        
+        self.as_mut().set_conversation_count(count);
+        self.as_mut().set_unread(unread);
+        if order_after != order_before {
+            self.as_mut().conversations_updated();
+        } else {
+            for peer in changed {
+                self.as_mut().conversation_updated(&QString::from(peer));
+            }
+        }
+        count
+    }
+    
     pub fn get_messages(&self) -> QString {
         let selected = self.selected_conversation.to_string();
         if selected.is_empty() {
@@ -565,8 +785,30 @@ impl qobject::DmController {
         
         let dm_mgr = DM_MANAGER.read().unwrap();
         if let Some(convo) = dm_mgr.get_conversation(&selected) {
+            // "seen" marks the newest outgoing message the peer has read
+            let read_up_to = PEER_READ_UP_TO.read().unwrap().get(&selected).copied().unwrap_or(0);
+            let seen_id = convo.messages.iter().rev()
+                .find(|m| m.is_outgoing && m.created_at <= read_up_to)
+                .map(|m| m.id.as_str());
             let messages_json: Vec<serde_json::Value> = convo.messages.iter()
-                .map(|m| m.to_json())
+                .map(|m| {
+                    let mut json = m.to_json();
+                    json["seen"] = serde_json::json!(Some(m.id.as_str()) == seen_id);
+                    
+                    // Image links are shown inline rather than as text
+                    let attachments = dm_media::find_attachments(&m.content);
+                    json["text"] = serde_json::json!(dm_media::strip_attachments(&m.content, &attachments));
+                    json["attachments"] = attachments.iter()
+                        .map(|(link, attachment)| serde_json::json!({
+                            "link": link,
+                            "encrypted": attachment.is_encrypted(),
+                            "localUrl": dm_media::cached(attachment)
+                                .map(|path| media_cache::file_url(&path))
+                                .unwrap_or_default(),
+                        }))
+                        .collect();
+                    json
+                })
                 .collect();
             QString::from(&serde_json::to_string(&messages_json).unwrap_or_else(|_| "[]".to_string()))
         } else {
@@ -588,7 +830,7 @@ impl qobject::DmController {
         }
     }
     
-    pub fn send_message(mut self: Pin<&mut Self>, content: &QString) {
+    pub fn send_message(self: Pin<&mut Self>, content: &QString) {
         let content_str = content.to_string();
         let selected = self.selected_conversation.to_string();
         
@@ -597,8 +839,85 @@ impl qobject::DmController {
             return;
         }
         
+        self.send_message_to(selected, content_str);
+    }
+    
+    pub fn send_attachment(mut self: Pin<&mut Self>, file_path: &QString, caption: &QString) {
+        let selected = self.selected_conversation.to_string();
+        if selected.is_empty() {
+            tracing::warn!("No conversation selected");
+            return;
+        }
+        
+        // Blossom uploads are authorized with the local key
+        let Some(keys) = dm_keys() else {
+            let error = QString::from("Sending images needs a locally stored key");
+            self.as_mut().set_error_message(error.clone());
+            self.as_mut().error_occurred(&error);
+            return;
+        };
+        
+        let path = file_path.to_string();
+        let path = path.strip_prefix("file://").unwrap_or(&path).to_string();
+        let caption = caption.to_string().trim().to_string();
+        let config = crate::core::config::Config::load();
+        let (server_url, encrypt) = (config.blossom_server, config.dm_encrypt_attachments);
+        
+        tracing::info!("Uploading DM attachment for {} (encrypted: {})", selected, encrypt);
+        self.as_mut().set_is_loading(true);
+        let qt_thread = self.qt_thread();
+        
+        limits::spawn_limited(move || {
+            let result = DM_RUNTIME.block_on(dm_media::upload(&server_url, &path, &keys, encrypt));
+            let _ = qt_thread.queue(move |mut qobject| match result {
+                Ok(attachment) => {
+                    let link = attachment.to_link();
+                    let content = if caption.is_empty() { link } else { format!("{}\n{}", caption, link) };
+                    qobject.send_message_to(selected, content);
+                }
+                Err(e) => {
+                    tracing::error!("DM attachment upload failed: {}", e);
+                    qobject.as_mut().set_is_loading(false);
+                    qobject.as_mut().set_error_message(QString::from(&e));
+                    qobject.as_mut().error_occurred(&QString::from(&e));
+                }
+            });
+        });
+    }
+    
+    pub fn fetch_attachment(self: Pin<&mut Self>, link: &QString) {
+        let link = link.to_string();
+        let Some(attachment) = dm_media::Attachment::parse(&link) else {
+            tracing::warn!("Not an attachment link: {}", link);
+            return;
+        };
+        let qt_thread = self.qt_thread();
+        
+        limits::spawn_limited(move || {
+            let result = DM_RUNTIME.block_on(dm_media::fetch(&attachment));
+            let _ = qt_thread.queue(move |mut qobject| match result {
+                Ok(path) => {
+                    let local_url = media_cache::file_url(&path);
+                    qobject.as_mut().attachment_ready(&QString::from(&link), &QString::from(&local_url));
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to load DM attachment: {}", e);
+                    qobject.as_mut().attachment_failed(&QString::from(&link), &QString::from(&e));
+                }
+            });
+        });
+    }
+    
+    /// Send a DM to a peer, whether or not their conversation is selected
+    fn send_message_to(mut self: Pin<&mut Self>, selected: String, content_str: String) {
         tracing::info!("Sending DM to {}", selected);
         
+        let op = Operation::new(
+            OperationKind::SendMessage,
+            &format!("{}:{}", selected, operations::content_key(&content_str)),
+        ).with_args(&[&selected, &content_str]);
+        self.as_mut().begin_operation(&op);
+        
         self.as_mut().set_is_loading(true);
         
         let protocol = self.current_protocol;
@@ -670,13 +989,15 @@ impl qobject::DmController {
             }
         });
         
+        self.as_mut().end_operation(&op, &result);
+        
         match result {
             Ok(event_id) => {
                 tracing::info!("DM sent: {}", event_id);
                 
                 let msg = DmMessage {
                     id: event_id.clone(),
-                    sender_pubkey: user_pubkey.unwrap_or_default(),
+                    sender_pubkey: user_pubkey.clone().unwrap_or_default(),
                     recipient_pubkey: selected,
                     content: content_str,
                     created_at: chrono::Utc::now().timestamp(),
@@ -684,12 +1005,20 @@ impl qobject::DmController {
                     protocol,
                 };
                 
-                {
+                let peer = msg.recipient_pubkey.clone();
+                let order_before = {
                     let mut dm_mgr = DM_MANAGER.write().unwrap();
+                    let order_before = dm_mgr.conversation_order(self.category_filter);
                     dm_mgr.add_message(msg);
-                }
+                    // Replying reads the conversation
+                    if let Some(user) = user_pubkey.as_deref() {
+                        dm_mgr.recount_unread(&dm_read_state(user));
+                    }
+                    order_before
+                };
                 
                 self.as_mut().set_is_loading(false);
+                self.as_mut().publish_conversation_changes(&order_before, &HashSet::from([peer]));
                 self.as_mut().message_sent(&QString::from(&event_id));
                 self.as_mut().messages_updated();
             }
@@ -702,6 +1031,47 @@ impl qobject::DmController {
         }
     }
     
+    /// Send a failed message again
+    pub fn retry_operation(self: Pin<&mut Self>, op_id: &QString) -> bool {
+        let Some(op) = operations::take_failed(&op_id.to_string()) else {
+            return false;
+        };
+        if op.kind != OperationKind::SendMessage {
+            return false;
+        }
+        let (peer, content) = (op.arg(0).to_string(), op.arg(1).to_string());
+        self.send_message_to(peer, content);
+        true
+    }
+    
+    /// Messages that failed to send as JSON [{id, kind, target, error, failedAt}], newest first
+    pub fn get_failed_operations(&self) -> QString {
+        let failed = operations::failed_list(&[OperationKind::SendMessage]);
+        QString::from(&serde_json::to_string(&failed).unwrap_or_else(|_| "[]".to_string()))
+    }
+    
+    /// Announce an operation on operation_started
+    fn begin_operation(mut self: Pin<&mut Self>, op: &Operation) {
+        operations::started(op);
+        self.as_mut().operation_started(&QString::from(&op.id()), &QString::from(op.kind.as_str()));
+    }
+    
+    /// Report an operation's outcome on operation_succeeded / operation_failed
+    fn end_operation(mut self: Pin<&mut Self>, op: &Operation, result: &Result<String, String>) {
+        let id = QString::from(&op.id());
+        let kind = QString::from(op.kind.as_str());
+        match result {
+            Ok(value) => {
+                operations::succeeded(op);
+                self.as_mut().operation_succeeded(&id, &kind, &QString::from(value));
+            }
+            Err(e) => {
+                operations::failed(op, e);
+                self.as_mut().operation_failed(&id, &kind, &QString::from(e));
+            }
+        }
+    }
+    
     pub fn start_conversation(mut self: Pin<&mut Self>, pubkey: &QString) {
         let pubkey_str = pubkey.to_string();
         tracing::info!("Starting conversation with: {}", pubkey_str);
@@ -770,6 +1140,172 @@ impl qobject::DmController {
     }
 }
 
+/// Conversations fetched from relays, not yet decrypted: (events by peer, peer profiles, user, nsec)
+type FetchedConversations = (HashMap<String, Vec<(Event, bool)>>, HashMap<String, ProfileCache>, PublicKey, Option<String>);
+
+/// Fetch the user's NIP-04 DMs and the peers' profiles
+/// Network only, so it can run off the Qt thread
+fn fetch_conversations(user_pubkey: &str, user_nsec: Option<String>) -> Result<FetchedConversations, String> {
+    DM_RUNTIME.block_on(async {
+        let pk = PublicKey::parse(user_pubkey)
+            .map_err(|e| format!("Invalid pubkey: {}", e))?;
+        
+        // Get relay client
+        let client = {
+            let c = DM_CLIENT.read().unwrap();
+            c.clone().ok_or("Not connected to relays")?
+        };
+        
+        // Fetch NIP-04 DMs
+        let events = fetch_nip04_dms(&client, &pk, 100).await?;
+        
+        // Process events into conversations
+        let mut conversations: HashMap<String, Vec<(Event, bool)>> = HashMap::new();
+        
+        for event in events.iter() {
+            if let Some(peer_pk) = get_nip04_peer(event, &pk) {
+                let peer_hex = peer_pk.to_hex();
+                let is_outgoing = event.pubkey == pk;
+                conversations.entry(peer_hex).or_default().push((event.clone(), is_outgoing));
+            }
+        }
+        
+        // Get unique peer pubkeys for profile fetching
+        let peer_pubkeys: Vec<PublicKey> = conversations.keys()
+            .filter_map(|hex| PublicKey::parse(hex).ok())
+            .collect();
+        
+        // Fetch profiles
+        let profiles = profile_store::get(&client, &peer_pubkeys).await;
+        
+        // Parse profiles
+        let mut profile_map: HashMap<String, ProfileCache> = HashMap::new();
+        for event in profiles.iter() {
+            if let Ok(metadata) = Metadata::from_json(&event.content) {
+                profile_map.insert(event.pubkey.to_hex(), ProfileCache::from_metadata(&metadata));
+            }
+        }
+        
+        Ok((conversations, profile_map, pk, user_nsec))
+    })
+}
+
+/// The persisted DM read state for a user (hex or npub)
+fn dm_read_state(user_pubkey: &str) -> DmReadState {
+    let Ok(pk) = PublicKey::parse(user_pubkey) else { return DmReadState::default() };
+    NostrDbManager::global()
+        .map(|db| db.get_dm_read_state(&pk.to_hex()))
+        .unwrap_or_default()
+}
+
+/// Persist that a conversation was read up to a message time
+fn mark_dm_read(user_pubkey: &str, peer: &str, up_to: i64) {
+    let (Ok(pk), Ok(db)) = (PublicKey::parse(user_pubkey), NostrDbManager::global()) else { return };
+    db.mark_dm_read(&pk.to_hex(), peer, up_to);
+}
+
+/// Whether DM typing indicators and read receipts are shared
+pub fn dm_presence_enabled() -> bool {
+    DM_PRESENCE.load(std::sync::atomic::Ordering::SeqCst)
+}
+
+/// Turn DM presence sharing on or off (settings)
+pub fn set_dm_presence_enabled(enabled: bool) {
+    DM_PRESENCE.store(enabled, std::sync::atomic::Ordering::SeqCst);
+    if !enabled {
+        PEER_READ_UP_TO.write().unwrap().clear();
+    }
+}
+
+/// Local keys from the stored nsec
+/// Presence needs them: a remote signer would be asked to sign every keystroke
+fn dm_keys() -> Option<Keys> {
+    let nsec = DM_NSEC.read().unwrap().clone()?;
+    SecretKey::parse(&nsec).ok().map(Keys::new)
+}
+
+/// Publish a presence event to a peer in the background
+/// NIP-17 conversations never get one: it would reveal who the user talks to
+fn send_presence(peer: &str, state: PresenceState) {
+    let protocol = DM_MANAGER.read().unwrap().get_conversation(peer).map(|c| c.protocol);
+    if protocol != Some(DmProtocol::Nip04) {
+        return;
+    }
+    let (Some(keys), Ok(peer_pk)) = (dm_keys(), PublicKey::parse(peer)) else {
+        tracing::debug!("DM presence needs local keys, not sent");
+        return;
+    };
+    let Some(client) = DM_CLIENT.read().unwrap().clone() else { return };
+    
+    std::thread::spawn(move || {
+        let result = create_presence_event(&keys, &peer_pk, state).and_then(|event| {
+            DM_RUNTIME.block_on(client.send_event(&event))
+                .map(|_| ())
+                .map_err(|e| format!("Failed to send: {}", e))
+        });
+        if let Err(e) = result {
+            tracing::debug!("DM presence not sent: {}", e);
+        }
+    });
+}
+
+/// Forward presence events addressed to the user until the listener is
+/// replaced or sharing is turned off
+async fn listen_for_presence(
+    client: &Client,
+    keys: &Keys,
+    user_pk: &PublicKey,
+    generation: u64,
+    qt_thread: &cxx_qt::CxxQtThread<qobject::DmController>,
+) -> Result<(), String> {
+    use std::sync::atomic::Ordering;
+    
+    let output = client.subscribe(presence_filter(user_pk), None)
+        .await
+        .map_err(|e| format!("Failed to subscribe: {}", e))?;
+    let sub_id = output.val;
+    let mut notifications = client.notifications();
+    
+    while DM_PRESENCE_GENERATION.load(Ordering::SeqCst) == generation && dm_presence_enabled() {
+        let notification = match tokio::time::timeout(PRESENCE_POLL, notifications.recv()).await {
+            Ok(Ok(notification)) => notification,
+            Ok(Err(tokio::sync::broadcast::error::RecvError::Lagged(_))) => continue,
+            Ok(Err(_)) => break,
+            Err(_) => continue,
+        };
+        let RelayPoolNotification::Event { subscription_id, event, .. } = notification else { continue };
+        if subscription_id != sub_id {
+            continue;
+        }
+        let Some((peer, state)) = parse_presence_event(&event, keys) else { continue };
+        
+        let peer_hex = peer.to_hex();
+        let _ = qt_thread.queue(move |mut qobject| {
+            if DM_PRESENCE_GENERATION.load(Ordering::SeqCst) != generation {
+                return;
+            }
+            match state {
+                PresenceState::Typing => qobject.as_mut().peer_typing(&QString::from(&peer_hex)),
+                PresenceState::Read(up_to) => {
+                    let newer = {
+                        let mut read = PEER_READ_UP_TO.write().unwrap();
+                        let entry = read.entry(peer_hex.clone()).or_insert(0);
+                        let newer = up_to > *entry;
+                        *entry = (*entry).max(up_to);
+                        newer
+                    };
+                    if newer {
+                        qobject.as_mut().peer_read(&QString::from(&peer_hex), up_to);
+                    }
+                }
+            }
+        });
+    }
+    
+    client.unsubscribe(&sub_id).await;
+    Ok(())
+}
+
 /// Set the signer client for DM encryption/decryption
 #[allow(dead_code)]
 pub fn set_dm_signer(signer: Option<SignerClient>) {
@@ -786,6 +1322,22 @@ pub fn set_dm_nsec(nsec: Option<String>) {
     tracing::info!("DM nsec set for encryption/signing operations");
 }
 
+/// Clear all DM globals left over from the previous account
+pub fn reset_dm_state() {
+    set_dm_signer(None);
+    set_dm_nsec(None);
+    
+    let client = DM_CLIENT.write().unwrap().take();
+    if let Some(client) = client {
+        DM_RUNTIME.block_on(client.disconnect());
+    }
+    
+    *DM_MANAGER.write().unwrap() = DmManager::new();
+    *DM_LAST_FETCH.write().unwrap() = None;
+    PEER_READ_UP_TO.write().unwrap().clear();
+    dm_media::clear_session();
+}
+
 /// Prefetch DMs in the background during app initialization
 /// This runs in a separate thread so it doesn't block the UI
 pub fn prefetch_dms(user_pubkey: String) {
@@ -809,18 +1361,18 @@ pub fn prefetch_dms(user_pubkey: String) {
                         if let Some(nsec) = nsec_opt.as_ref() {
                             if let Ok(secret_key) = SecretKey::parse(nsec) {
                                 let keys = Keys::new(secret_key);
-                                Client::new(keys)
+                                network::nostr_client(Some(keys))
                             } else {
-                                Client::default()
+                                network::nostr_client(None)
                             }
                         } else {
-                            Client::default()
+                            network::nostr_client(None)
                         }
                     };
                     
-                    // Add default relays
-                    for relay in crate::nostr::relay::DEFAULT_RELAYS {
-                        let _ = client.add_relay(*relay).await;
+                    // Add the configured relays
+                    for relay in crate::nostr::relay::configured_relays() {
+                        let _ = client.add_relay(relay).await;
                     }
                     client.connect().await;
                     
@@ -850,23 +1402,13 @@ pub fn prefetch_dms(user_pubkey: String) {
                 .collect();
             
             // Fetch profiles
-            let profiles = if !peer_pubkeys.is_empty() {
-                let profile_filter = Filter::new()
-                    .kind(Kind::Metadata)
-                    .authors(peer_pubkeys.clone());
-                
-                client.fetch_events(profile_filter, DEFAULT_TIMEOUT).await.ok()
-            } else {
-                None
-            };
+            let profiles = profile_store::get(&client, &peer_pubkeys).await;
             
             // Parse profiles
             let mut profile_map: std::collections::HashMap<String, ProfileCache> = std::collections::HashMap::new();
-            if let Some(profile_events) = profiles {
-                for event in profile_events.iter() {
-                    if let Ok(metadata) = Metadata::from_json(&event.content) {
-                        profile_map.insert(event.pubkey.to_hex(), ProfileCache::from_metadata(&metadata));
-                    }
+            for event in profiles.iter() {
+                if let Ok(metadata) = Metadata::from_json(&event.content) {
+                    profile_map.insert(event.pubkey.to_hex(), ProfileCache::from_metadata(&metadata));
                 }
             }
             
@@ -935,6 +1477,7 @@ pub fn prefetch_dms(user_pubkey: String) {
                     }
                 }
                 
+                dm_mgr.recount_unread(&dm_read_state(&user_pk.to_hex()));
                 let count = dm_mgr.get_conversations().len();
                 drop(dm_mgr);
                 
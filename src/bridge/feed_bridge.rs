@@ -8,6 +8,9 @@ pub mod qobject {
         
         include!("cxx-qt-lib/qstringlist.h");
         type QStringList = cxx_qt_lib::QStringList;
+        
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
     }
 
     unsafe extern "RustQt" {
@@ -19,7 +22,11 @@ pub mod qobject {
         #[qproperty(QString, error_message)]
         #[qproperty(i32, thread_count)]
         #[qproperty(QString, thread_note_id)]
+        #[qproperty(bool, thread_has_more)]
+        #[qproperty(QString, thread_sort)]
         #[qproperty(QString, loading_status)]
+        #[qproperty(bool, post_protected)]
+        #[qproperty(i32, outbox_count)]
         type FeedController = super::FeedControllerRust;
 
         /// Initialize the feed controller (called after login)
@@ -42,17 +49,29 @@ pub mod qobject {
         #[qinvokable]
         fn refresh(self: Pin<&mut FeedController>);
         
-        /// Get note at index (returns JSON for simplicity)
+        /// Get note at index as an object ({} past the end)
+        #[qinvokable]
+        fn get_note(self: &FeedController, index: i32) -> QVariant;
+        
+        /// Get note at index as a grid tile: {id, pubkey, authorName, media: [{url, type, blurhash}], thumbnail, isVideo, mediaCount, ...}
         #[qinvokable]
-        fn get_note(self: &FeedController, index: i32) -> QString;
+        fn get_media_note(self: &FeedController, index: i32) -> QVariant;
         
         /// Load thread for a specific note
         #[qinvokable]
         fn load_thread(self: Pin<&mut FeedController>, note_id: &QString);
         
-        /// Get thread note at index (returns JSON)
+        /// Get thread note at index as an object with depth, parentId, childCount, descendantCount
+        #[qinvokable]
+        fn get_thread_note(self: &FeedController, index: i32) -> QVariant;
+        
+        /// Load the next page of older replies in the open thread
+        #[qinvokable]
+        fn load_more_replies(self: Pin<&mut FeedController>);
+        
+        /// Order sibling replies by "time" (oldest first) or "engagement" (most replied first)
         #[qinvokable]
-        fn get_thread_note(self: &FeedController, index: i32) -> QString;
+        fn sort_thread(self: Pin<&mut FeedController>, sort: &QString);
         
         /// Clear thread view
         #[qinvokable]
@@ -66,9 +85,80 @@ pub mod qobject {
         #[qinvokable]
         fn react_to_note(self: Pin<&mut FeedController>, note_id: &QString, emoji: &QString);
         
+        /// The user's reactions, most recently used first
+        /// JSON array of {emoji, url, count, lastUsed}; url is set for custom emoji
+        #[qinvokable]
+        fn recent_reactions(self: &FeedController, limit: i32) -> QString;
+        
+        /// The user's reactions, most used first (same JSON as recent_reactions)
+        #[qinvokable]
+        fn frequent_reactions(self: &FeedController, limit: i32) -> QString;
+        
+        /// Custom emoji from the user's NIP-30 emoji list as JSON [{shortcode, url}]
+        /// Returns the stored list and refreshes it; custom_emoji_updated fires if it changed
+        #[qinvokable]
+        fn get_custom_emoji(self: Pin<&mut FeedController>) -> QString;
+        
+        /// Run a failed operation again by its id (from operation_failed)
+        /// Returns false if there's no failed operation with that id
+        #[qinvokable]
+        fn retry_operation(self: Pin<&mut FeedController>, op_id: &QString) -> bool;
+        
+        /// Failed operations that can be retried as JSON [{id, kind, target, error, failedAt}], newest first
+        #[qinvokable]
+        fn get_failed_operations(self: &FeedController) -> QString;
+        
+        /// The user's NIP-51 follow sets as JSON [{identifier, title, description, image, pubkeys, npubs, createdAt, feed}]
+        /// Returns the stored sets and refreshes them; follow_sets_updated fires if they changed
+        /// Load a set's timeline with load_feed(feed), i.e. "list:<d-tag>"
+        #[qinvokable]
+        fn get_follow_sets(self: Pin<&mut FeedController>) -> QString;
+        
+        /// Create or replace a follow set (members_json is an array of npubs or hex pubkeys)
+        /// An empty identifier creates a new set; returns the set's identifier
+        #[qinvokable]
+        fn save_follow_set(self: Pin<&mut FeedController>, identifier: &QString, title: &QString, description: &QString, members_json: &QString) -> QString;
+        
+        /// Add or remove one user from a follow set
+        #[qinvokable]
+        fn set_follow_set_member(self: Pin<&mut FeedController>, identifier: &QString, pubkey: &QString, member: bool);
+        
+        /// Delete a follow set (NIP-09 deletion request)
+        #[qinvokable]
+        fn delete_follow_set(self: Pin<&mut FeedController>, identifier: &QString);
+        
+        /// Followed hashtags (NIP-51 kind 10015 interest list) as a JSON array of lowercase tags
+        /// Returns the stored list and refreshes it; followed_hashtags_updated fires if it changed
+        /// Load their timeline with load_feed("hashtags")
+        #[qinvokable]
+        fn get_followed_hashtags(self: Pin<&mut FeedController>) -> QString;
+        
+        /// Follow or unfollow a hashtag (with or without '#') and republish the interest list
+        #[qinvokable]
+        fn follow_hashtag(self: Pin<&mut FeedController>, hashtag: &QString, follow: bool);
+        
+        /// Report a note (NIP-56 kind 1984) and hide it locally
+        /// report_type: nudity, malware, profanity, illegal, spam, impersonation or other
+        #[qinvokable]
+        fn report_note(self: Pin<&mut FeedController>, note_id: &QString, reason: &QString, report_type: &QString);
+        
+        /// Send a note (own or anyone's) as published to all write relays again (async)
+        /// Emits broadcast_finished; useful when some relays are missing it
+        #[qinvokable]
+        fn broadcast_note(self: Pin<&mut FeedController>, note_id: &QString);
+        
+        /// Publish a highlight (NIP-84 kind 9802) of text selected from a note
+        #[qinvokable]
+        fn highlight_note(self: Pin<&mut FeedController>, note_id: &QString, selected_text: &QString);
+        
+        /// Report a user (NIP-56 kind 1984) and hide their notes locally
+        #[qinvokable]
+        fn report_user(self: Pin<&mut FeedController>, pubkey: &QString, reason: &QString, report_type: &QString);
+        
         /// Fetch reactions and zap stats for a specific note (async - non-blocking)
-        /// Returns cached stats immediately if available, otherwise returns loading state and fetches in background
-        /// Use get_cached_note_stats() with a timer to poll for results
+        /// Returns cached stats immediately if available, otherwise returns loading state,
+        /// fetches in the background together with other notes requested at the same time,
+        /// and emits note_stats_ready when the stats arrive
         #[qinvokable]
         fn fetch_note_stats(self: Pin<&mut FeedController>, note_id: &QString) -> QString;
         
@@ -77,6 +167,11 @@ pub mod qobject {
         #[qinvokable]
         fn refresh_visible_stats(self: Pin<&mut FeedController>, note_ids_json: &QString);
         
+        /// Prefetch stats for the loaded notes first_index..=last_index in one round trip
+        /// Notes already cached or being fetched are skipped; results arrive via note_stats_ready
+        #[qinvokable]
+        fn fetch_stats_for_range(self: Pin<&mut FeedController>, first_index: i32, last_index: i32);
+        
         /// Get cached note stats (non-blocking, read-only)
         /// Returns cached stats or loading state if fetch is in progress
         #[qinvokable]
@@ -87,21 +182,51 @@ pub mod qobject {
         fn repost_note(self: Pin<&mut FeedController>, note_id: &QString);
         
         /// Reply to a note
+        /// content_warning is an optional NIP-36 reason (empty for none)
         #[qinvokable]
-        fn reply_to_note(self: Pin<&mut FeedController>, note_id: &QString, content: &QString);
+        fn reply_to_note(self: Pin<&mut FeedController>, note_id: &QString, content: &QString, content_warning: &QString);
         
         /// Zap a note
         #[qinvokable]
         fn zap_note(self: Pin<&mut FeedController>, note_id: &QString, amount_sats: i64, comment: &QString);
         
         /// Post a new note
+        /// content_warning is an optional NIP-36 reason (empty for none)
         #[qinvokable]
-        fn post_note(self: Pin<&mut FeedController>, content: &QString);
+        fn post_note(self: Pin<&mut FeedController>, content: &QString, content_warning: &QString);
         
         /// Post a new note with media attachments
         /// media_urls is a JSON array of media URLs to attach
         #[qinvokable]
-        fn post_note_with_media(self: Pin<&mut FeedController>, content: &QString, media_urls: &QString);
+        fn post_note_with_media(self: Pin<&mut FeedController>, content: &QString, media_urls: &QString, content_warning: &QString);
+        
+        /// Post a picture event (kind 20) instead of a kind 1 with attachments
+        /// image_urls is a JSON array of uploaded image URLs; content is the description
+        #[qinvokable]
+        fn post_picture(self: Pin<&mut FeedController>, title: &QString, content: &QString, image_urls: &QString, content_warning: &QString);
+        
+        /// Post a poll (kind 1068); options_json is a JSON array of option labels
+        /// duration_secs <= 0 leaves the poll open indefinitely
+        #[qinvokable]
+        fn create_poll(self: Pin<&mut FeedController>, question: &QString, options_json: &QString, duration_secs: i64, multiple_choice: bool);
+        
+        /// Fetch and tally a poll's votes (async); results arrive via poll_results_ready
+        #[qinvokable]
+        fn fetch_poll_results(self: Pin<&mut FeedController>, poll_id: &QString);
+        
+        /// Vote on a poll; option_ids_json is a JSON array of option ids
+        #[qinvokable]
+        fn vote_poll(self: Pin<&mut FeedController>, poll_id: &QString, option_ids_json: &QString);
+        
+        /// Sum the zaps towards a zap goal (async); results arrive via zap_goal_progress_ready
+        /// Contributing is a zap_note on the goal's id
+        #[qinvokable]
+        fn fetch_zap_goal_progress(self: Pin<&mut FeedController>, goal_id: &QString);
+        
+        /// Look up apps that can open a note of a kind PlebClient doesn't render (NIP-89, async)
+        /// bech32 is the note's extras.handler.bech32; results arrive via kind_handlers_ready
+        #[qinvokable]
+        fn fetch_kind_handlers(self: Pin<&mut FeedController>, note_id: &QString, kind: i32, bech32: &QString);
         
         /// Upload media to Blossom server
         /// Returns JSON with url on success, or error message
@@ -118,8 +243,11 @@ pub mod qobject {
 
         /// Fetch an embedded nostr event by nevent/naddr/note bech32 string
         /// Returns JSON with the note data or empty if not found
+        /// Nested quotes are under "embeds"; past the depth limit or on a cycle back to
+        /// `parent_id` (the note containing the embed, may be empty) they are placeholders
+        /// {uri, placeholder: "deep"|"cycle"|"missing", id} - expand by fetching uri again
         #[qinvokable]
-        fn fetch_embedded_event(self: Pin<&mut FeedController>, nostr_uri: &QString) -> QString;
+        fn fetch_embedded_event(self: Pin<&mut FeedController>, nostr_uri: &QString, parent_id: &QString) -> QString;
         
         /// Fetch an embedded nostr profile by nprofile/npub bech32 string
         /// Returns JSON with profile data or empty if not found
@@ -127,7 +255,8 @@ pub mod qobject {
         fn fetch_embedded_profile(self: Pin<&mut FeedController>, nostr_uri: &QString) -> QString;
         
         /// Fetch link preview metadata for a URL
-        /// Returns JSON with title, description, image, siteName
+        /// Returns JSON with title, description, image, siteName and, for
+        /// players and posts, embed {provider, type, id, embedUrl, aspectRatio, author, authorUrl, streamUrl}
         #[qinvokable]
         fn fetch_link_preview(self: Pin<&mut FeedController>, url: &QString) -> QString;
         
@@ -150,15 +279,39 @@ pub mod qobject {
         #[qinvokable]
         fn delete_article_draft(self: Pin<&mut FeedController>, draft_id: &QString) -> bool;
         
-        /// Search Tenor for GIFs
-        /// Returns JSON array of GIF results with urls and dimensions
+        /// Save a composer draft to disk
+        /// draft_json contains: {id, content, replyTo, replyToAuthor, replyToContent, mediaPaths, contentWarning}
+        /// Returns the stored draft JSON (with id and timestamps) or {"error": ...}
+        #[qinvokable]
+        fn save_draft(self: Pin<&mut FeedController>, draft_json: &QString) -> QString;
+        
+        /// List composer drafts, most recent first (JSON array)
+        #[qinvokable]
+        fn list_drafts(self: &FeedController) -> QString;
+        
+        /// Delete a composer draft
+        #[qinvokable]
+        fn delete_draft(self: Pin<&mut FeedController>, draft_id: &QString) -> bool;
+        
+        /// Canonicalize pasted npub/nprofile mentions in composer text to nostr: URIs
+        /// Returns the rewritten text; display names arrive via mention_resolved
+        #[qinvokable]
+        fn preprocess_composer(self: Pin<&mut FeedController>, text: &QString) -> QString;
+        
+        /// Search Tenor for GIFs; pos is the previous page's "next" (empty for the first page)
+        /// Returns JSON {results: [{url, preview_url, width, height, id}], next} or {"error": ...}
+        #[qinvokable]
+        fn search_gifs(self: Pin<&mut FeedController>, query: &QString, pos: &QString) -> QString;
+        
+        /// Get trending GIFs from Tenor; pos as for search_gifs
+        /// Returns JSON {results, next} or {"error": ...}
         #[qinvokable]
-        fn search_gifs(self: Pin<&mut FeedController>, query: &QString) -> QString;
+        fn trending_gifs(self: Pin<&mut FeedController>, pos: &QString) -> QString;
         
-        /// Get trending GIFs from Tenor
-        /// Returns JSON array of GIF results
+        /// Get Tenor's featured search categories
+        /// Returns JSON array of {searchterm, name, image} or {"error": ...}
         #[qinvokable]
-        fn get_trending_gifs(self: Pin<&mut FeedController>) -> QString;
+        fn gif_categories(self: Pin<&mut FeedController>) -> QString;
         
         /// Bridge a GIF from Tenor to Nostr via NIP-96 re-upload
         /// Downloads from Tenor and re-uploads to NIP-96 server for privacy
@@ -180,6 +333,115 @@ pub mod qobject {
         /// Set the NIP-96 server URL
         #[qinvokable]
         fn set_nip96_server(self: Pin<&mut FeedController>, url: &QString);
+        
+        /// Build a "catch up" summary of activity since the last session
+        /// Emits catch_up_ready with the summary JSON
+        #[qinvokable]
+        fn load_catch_up(self: Pin<&mut FeedController>);
+        
+        /// Check which connected relays advertise NIP-70 (protected events) support
+        /// Emits protected_support_checked with a JSON array of {url, supported, error}
+        #[qinvokable]
+        fn check_protected_support(self: Pin<&mut FeedController>);
+        
+        /// Diagnostic: dump the current feed state (ids, timestamps, source relays, filters)
+        /// to a JSON file for bug reports. Returns JSON with path or error.
+        #[qinvokable]
+        fn export_feed_snapshot(self: Pin<&mut FeedController>, file_path: &QString, redact_pubkeys: bool) -> QString;
+        
+        /// Open a note in the configured web viewer (njump, primal or custom)
+        /// Returns the URL that was opened, or empty on failure
+        #[qinvokable]
+        fn open_note_in_browser(self: Pin<&mut FeedController>, note_id: &QString) -> QString;
+        
+        /// Get a feed's saved visibility preferences as JSON
+        /// {showReposts, showReplies, onlyMedia, languages, availableLanguages}
+        #[qinvokable]
+        fn get_feed_preferences(self: &FeedController, feed: &QString) -> QString;
+        
+        /// Save a feed's visibility preferences (same JSON as get_feed_preferences)
+        /// Notes already loaded were filtered with the old preferences; refresh to re-apply
+        #[qinvokable]
+        fn set_feed_preferences(self: Pin<&mut FeedController>, feed: &QString, prefs_json: &QString);
+        
+        /// Get the configured web viewer ("njump", "primal" or a URL template with {nevent})
+        #[qinvokable]
+        fn get_web_viewer(self: &FeedController) -> QString;
+        
+        /// Set the web viewer used by open_note_in_browser
+        #[qinvokable]
+        fn set_web_viewer(self: Pin<&mut FeedController>, viewer: &QString);
+        
+        /// Get the event kinds shown in the home feed (JSON array of numbers)
+        #[qinvokable]
+        fn get_home_feed_kinds(self: &FeedController) -> QString;
+        
+        /// Set the event kinds shown in the home feed (JSON array of numbers)
+        #[qinvokable]
+        fn set_home_feed_kinds(self: Pin<&mut FeedController>, kinds_json: &QString);
+        
+        /// Get feed loading settings as JSON {pageSize, windowHours, prefetchOnStartup}
+        #[qinvokable]
+        fn get_feed_loading(self: &FeedController) -> QString;
+        
+        /// Set notes per page, hours the first page reaches back (0 = no limit)
+        /// and whether other feeds load in the background after login
+        /// Cached feeds are dropped so the next load uses the new settings
+        #[qinvokable]
+        fn set_feed_loading(self: Pin<&mut FeedController>, page_size: i32, window_hours: i32, prefetch_on_startup: bool);
+        
+        /// Whether notes with a content warning are shown without blurring
+        #[qinvokable]
+        fn get_always_reveal_sensitive(self: &FeedController) -> bool;
+        
+        /// Set whether notes with a content warning are shown without blurring
+        #[qinvokable]
+        fn set_always_reveal_sensitive(self: Pin<&mut FeedController>, reveal: bool);
+        
+        /// Clear all per-user state so initialize can run again (logout / account switch)
+        #[qinvokable]
+        fn reset(self: Pin<&mut FeedController>);
+        
+        /// Schedule a note to be published at publish_at (unix seconds)
+        /// Returns the scheduled post JSON or {"error": ...}
+        #[qinvokable]
+        fn schedule_note(self: Pin<&mut FeedController>, content: &QString, publish_at: i64, content_warning: &QString) -> QString;
+        
+        /// List the user's scheduled posts, soonest first (JSON array)
+        #[qinvokable]
+        fn list_scheduled_posts(self: &FeedController) -> QString;
+        
+        /// Cancel a scheduled post
+        #[qinvokable]
+        fn cancel_scheduled_post(self: Pin<&mut FeedController>, post_id: &QString) -> bool;
+        
+        /// Change a scheduled post's content and time (empty content / 0 keeps the current value)
+        /// A new time must be in the future; returns false if the edit was refused
+        #[qinvokable]
+        fn edit_scheduled_post(self: Pin<&mut FeedController>, post_id: &QString, content: &QString, publish_at: i64) -> bool;
+        
+        /// Whether at least one relay is connected right now
+        #[qinvokable]
+        fn is_online(self: &FeedController) -> bool;
+        
+        /// Queue a note whose attachments may still be local files
+        /// media_json is a JSON array of uploaded URLs and/or local file paths; the
+        /// files are uploaded and the note is signed and published once relays are
+        /// reachable. Returns the outbox entry JSON or {"error": ...}
+        #[qinvokable]
+        fn queue_note_with_media(self: Pin<&mut FeedController>, content: &QString, media_json: &QString, content_warning: &QString) -> QString;
+        
+        /// List the user's outbox, oldest first (JSON array)
+        #[qinvokable]
+        fn get_outbox(self: &FeedController) -> QString;
+        
+        /// Retry the outbox now, including entries that gave up
+        #[qinvokable]
+        fn retry_outbox(self: Pin<&mut FeedController>);
+        
+        /// Drop an outbox entry without publishing it
+        #[qinvokable]
+        fn discard_outbox_entry(self: Pin<&mut FeedController>, entry_id: &QString) -> bool;
     }
 
     unsafe extern "RustQt" {
@@ -199,6 +461,14 @@ pub mod qobject {
         #[qsignal]
         fn thread_loaded(self: Pin<&mut FeedController>);
         
+        /// Emitted when load_more_replies finishes; added is the number of new replies
+        #[qsignal]
+        fn thread_replies_loaded(self: Pin<&mut FeedController>, added: i32);
+        
+        /// Emitted when a reply to the open thread arrives live; index is its position in the thread
+        #[qsignal]
+        fn thread_reply_added(self: Pin<&mut FeedController>, note_id: &QString, index: i32);
+        
         /// Emitted when a note is posted
         #[qsignal]
         fn note_posted(self: Pin<&mut FeedController>, note_id: &QString);
@@ -228,10 +498,25 @@ pub mod qobject {
         fn zap_failed(self: Pin<&mut FeedController>, note_id: &QString, error: &QString);
         
         /// Emitted when note stats are fetched (async)
-        /// stats_json contains: {reactions: {emoji: count}, zapAmount: sats, zapCount: number}
+        /// stats_json contains: {reactions: {emoji: count}, zapAmount: sats, zapAmountFiat: string, zapCount: number}
         #[qsignal]
         fn note_stats_ready(self: Pin<&mut FeedController>, note_id: &QString, stats_json: &QString);
         
+        /// Emitted when a poll's votes are tallied
+        /// results_json contains: {pollId, counts: {optionId: votes}, voters, myVotes: [optionId], closed}
+        #[qsignal]
+        fn poll_results_ready(self: Pin<&mut FeedController>, poll_id: &QString, results_json: &QString);
+        
+        /// Emitted when a zap goal's receipts are summed
+        /// progress_json contains: {goalId, raisedSats, targetSats, contributors, zaps, fraction, closed}
+        #[qsignal]
+        fn zap_goal_progress_ready(self: Pin<&mut FeedController>, goal_id: &QString, progress_json: &QString);
+        
+        /// Emitted when handler apps for a note's kind are looked up
+        /// handlers_json is an array of {name, picture, url, recommendations}, best first
+        #[qsignal]
+        fn kind_handlers_ready(self: Pin<&mut FeedController>, note_id: &QString, handlers_json: &QString);
+        
         /// Emitted when user's own profile is loaded
         /// Contains display_name and picture URL
         #[qsignal]
@@ -256,6 +541,97 @@ pub mod qobject {
         /// Emitted when GIF bridge fails
         #[qsignal]
         fn gif_bridge_failed(self: Pin<&mut FeedController>, error: &QString);
+        
+        /// Emitted when a catch up summary is ready (JSON)
+        #[qsignal]
+        fn catch_up_ready(self: Pin<&mut FeedController>, summary_json: &QString);
+        
+        /// Emitted when NIP-70 support has been checked for the connected relays
+        #[qsignal]
+        fn protected_support_checked(self: Pin<&mut FeedController>, relays_json: &QString);
+        
+        /// Emitted when a composer mention's profile is known (uri is the nostr: URI in the text)
+        #[qsignal]
+        fn mention_resolved(self: Pin<&mut FeedController>, uri: &QString, pubkey: &QString, display_name: &QString);
+        
+        /// Emitted when an async operation starts
+        /// op_id is "<kind>:<target>" (e.g. "like:<note id>") and stays the same across retries
+        #[qsignal]
+        fn operation_started(self: Pin<&mut FeedController>, op_id: &QString, kind: &QString);
+        
+        /// Emitted when an async operation completes; result is the new event id where there is one
+        #[qsignal]
+        fn operation_succeeded(self: Pin<&mut FeedController>, op_id: &QString, kind: &QString, result: &QString);
+        
+        /// Emitted when an async operation fails; retry_operation(op_id) runs it again
+        #[qsignal]
+        fn operation_failed(self: Pin<&mut FeedController>, op_id: &QString, kind: &QString, error: &QString);
+        
+        /// Emitted when the user's follow sets change (same JSON as get_follow_sets)
+        #[qsignal]
+        fn follow_sets_updated(self: Pin<&mut FeedController>, sets_json: &QString);
+        
+        /// Emitted when a follow set save or deletion has been published
+        #[qsignal]
+        fn follow_set_saved(self: Pin<&mut FeedController>, identifier: &QString, success: bool, error: &QString);
+        
+        /// Emitted when the followed hashtags change (same JSON as get_followed_hashtags)
+        #[qsignal]
+        fn followed_hashtags_updated(self: Pin<&mut FeedController>, hashtags_json: &QString);
+        
+        /// Emitted when a report has been published (target is a note id or pubkey)
+        /// The content is hidden locally as soon as it's reported, before publishing
+        #[qsignal]
+        fn report_finished(self: Pin<&mut FeedController>, target: &QString, success: bool, error: &QString);
+        
+        /// Emitted when a broadcast_note finishes
+        /// results_json is the publish report (as in publish_status) or {error}
+        #[qsignal]
+        fn broadcast_finished(self: Pin<&mut FeedController>, note_id: &QString, results_json: &QString);
+        
+        /// Emitted when the user's custom emoji list has been refreshed from relays
+        #[qsignal]
+        fn custom_emoji_updated(self: Pin<&mut FeedController>, emoji_json: &QString);
+        
+        /// Emitted when an author's NIP-05 identifier has been verified
+        #[qsignal]
+        fn nip05_verified(self: Pin<&mut FeedController>, pubkey: &QString, verified: bool);
+        
+        /// Emitted when a scheduled post has been published
+        #[qsignal]
+        fn scheduled_post_published(self: Pin<&mut FeedController>, post_id: &QString, event_id: &QString);
+        
+        /// Emitted when publishing a scheduled post fails (it is retried later)
+        #[qsignal]
+        fn scheduled_post_failed(self: Pin<&mut FeedController>, post_id: &QString, error: &QString);
+        
+        /// Emitted as an outbox entry moves along: "waiting", "uploading",
+        /// "publishing", "published" (detail is the event id) or "failed"
+        /// (detail is the error; "waiting" after an error means it will be retried)
+        #[qsignal]
+        fn outbox_entry_status(self: Pin<&mut FeedController>, entry_id: &QString, status: &QString, detail: &QString);
+        
+        /// Emitted when entries are added to or leave the outbox
+        #[qsignal]
+        fn outbox_changed(self: Pin<&mut FeedController>, count: i32);
+        
+        /// Emitted when a relay that dropped is connected again
+        #[qsignal]
+        fn relay_reconnected(self: Pin<&mut FeedController>, url: &QString, attempts: i32);
+        
+        /// Emitted when reconnecting a dropped relay fails; the next attempt is in retry_in_secs
+        #[qsignal]
+        fn relay_failed(self: Pin<&mut FeedController>, url: &QString, attempts: i32, retry_in_secs: i32, error: &QString);
+        
+        /// Emitted with per-relay results whenever an event is published or retried
+        /// results_json: {eventId, kind, retry, relays: [{url, status: ok|queued|rejected, message}], accepted, queued, rejected}
+        #[qsignal]
+        fn publish_status(self: Pin<&mut FeedController>, event_id: &QString, results_json: &QString);
+        
+        /// An author of loaded notes changed their profile; cards by this pubkey
+        /// should show the new name and picture
+        #[qsignal]
+        fn note_author_updated(self: Pin<&mut FeedController>, pubkey: &QString, author_name: &QString, author_picture: &QString);
     }
     
     // Enable threading support for background work with UI updates
@@ -264,22 +640,53 @@ pub mod qobject {
 
 use std::pin::Pin;
 use std::sync::Arc;
-use cxx_qt_lib::QString;
+use cxx_qt_lib::{QString, QVariant};
 use cxx_qt::{CxxQtType, Threading};
 use nostr_sdk::prelude::*;
 use tokio::sync::Mutex;
 use crate::nostr::{
     database::NostrDbManager,
-    relay::RelayManager,
-    feed::DisplayNote,
+    relay::{media_fetch_limit, MyInteractions, NoteStats, RelayManager, KIND_PICTURE, MAX_FEED_PAGE_SIZE, MEDIA_FEED_KINDS, MIN_FEED_PAGE_SIZE},
+    relay_service,
+    feed::{apply_feed_preferences, DisplayNote, FeedPreferences},
     profile::ProfileCache,
+    profile_store,
     blossom,
+    catchup,
+    compose,
+    embeds,
+    emoji,
+    handlers,
+    highlights,
+    links,
+    interests,
+    lists::{self, FollowSet},
+    nip05,
+    outbox::{self, OutboxEntry, OutboxItem, OutboxStatus, PendingEvent, PendingMedia, PendingNote, PendingZap},
+    polls::{self, Poll},
+    publish,
+    relay_info,
+    relay_watch::{RelayEvent, RelayWatch},
+    report::{self, ReportType},
+    snapshot::{FeedSnapshot, SnapshotFilters},
     tenor,
+    thread::{ThreadEntry, ThreadSort, ThreadView, REPLY_PAGE_SIZE},
     zap::{self, GLOBAL_NWC_MANAGER},
+    zap_goals::{self, ZapGoal},
 };
+use crate::core::cache_stats;
 use crate::core::config::Config;
+use crate::core::fiat;
+use crate::core::limits;
+use crate::core::link_preview;
+use crate::core::lru::LruCache;
+use crate::core::operations::{self, Operation, OperationKind};
+use crate::core::refresh::{self, RefreshTask};
+use crate::core::drafts::{DraftManager, NoteDraft};
+use crate::core::scheduler::{ScheduledPost, GLOBAL_SCHEDULER};
 use crate::signer::SignerClient;
 use crate::bridge::dm_bridge::prefetch_dms;
+use crate::bridge::variant;
 
 /// Feed types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -289,21 +696,117 @@ pub enum FeedType {
     Global,
     ReadsFollowing,
     ReadsGlobal,
+    Media,     // Picture (kind 20) and short video (kind 22) events
+    List,      // NIP-51 follow set, named "list:<d-tag>"
+    Hashtags,  // Notes tagged with the user's followed hashtags (NIP-51 interest list)
 }
 
 impl FeedType {
     pub fn from_str(s: &str) -> Self {
+        if lists::list_identifier(s).is_some() {
+            return FeedType::List;
+        }
         match s.to_lowercase().as_str() {
             "following" => FeedType::Following,
             "replies" => FeedType::Replies,
             "global" => FeedType::Global,
             "reads_following" => FeedType::ReadsFollowing,
             "reads_global" => FeedType::ReadsGlobal,
+            "media" => FeedType::Media,
+            "hashtags" => FeedType::Hashtags,
             _ => FeedType::Following,
         }
     }
+    
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FeedType::Following => "following",
+            FeedType::Replies => "replies",
+            FeedType::Global => "global",
+            FeedType::ReadsFollowing => "reads_following",
+            FeedType::ReadsGlobal => "reads_global",
+            FeedType::Media => "media",
+            FeedType::List => "list",
+            FeedType::Hashtags => "hashtags",
+        }
+    }
+}
+
+/// Follow sets as JSON, each with the feed name that loads it
+fn follow_sets_json(sets: &[FollowSet]) -> String {
+    let values: Vec<serde_json::Value> = sets.iter()
+        .map(|set| {
+            let mut value = serde_json::to_value(set).unwrap_or_default();
+            value["feed"] = serde_json::Value::from(set.feed_name());
+            value["title"] = serde_json::Value::from(set.display_title());
+            value["npubs"] = set.members().iter()
+                .filter_map(|pk| pk.to_bech32().ok())
+                .collect::<Vec<_>>()
+                .into();
+            value
+        })
+        .collect();
+    serde_json::to_string(&values).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// Config key for a feed's preferences; each list feed keeps its own
+fn feed_preferences_key(feed: &str) -> String {
+    match FeedType::from_str(feed) {
+        FeedType::List => feed.to_string(),
+        other => other.as_str().to_string(),
+    }
+}
+
+/// Member pubkeys of a "list:<d-tag>" feed; empty for other feeds
+fn list_feed_authors(user_pubkey: Option<&String>, feed: &str) -> Vec<PublicKey> {
+    user_pubkey
+        .and_then(|pk| PublicKey::parse(pk).ok())
+        .map(|user| lists::feed_authors(&user, feed))
+        .unwrap_or_default()
 }
 
+/// Followed hashtags for the hashtags feed
+fn followed_hashtags(user_pubkey: Option<&String>) -> Vec<String> {
+    user_pubkey
+        .and_then(|pk| PublicKey::parse(pk).ok())
+        .map(|user| interests::user_hashtags(&user))
+        .unwrap_or_default()
+}
+
+/// How often the scheduler checks for due posts
+const SCHEDULER_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// How often the outbox is retried while it has entries
+const OUTBOX_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15);
+
+/// How often the relay watch looks for dropped relays
+const RELAY_WATCH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// How often the thread watcher checks whether its thread is still open
+const THREAD_WATCH_POLL: std::time::Duration = std::time::Duration::from_secs(1);
+
+/// Feeds kept for instant switching, and how long before they are refetched
+const FEED_CACHE_FEEDS: usize = 16;
+const FEED_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(30 * 60);
+
+/// Resolved nostr: embeds (events and profiles, each)
+const EMBED_CACHE_ENTRIES: usize = 1000;
+const EMBED_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+/// Link previews change rarely, so they are kept longest
+const LINK_PREVIEW_CACHE_ENTRIES: usize = 500;
+const LINK_PREVIEW_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(6 * 60 * 60);
+
+/// Stats go stale as reactions come in; expired ones are fetched again
+const NOTE_STATS_CACHE_ENTRIES: usize = 2000;
+const NOTE_STATS_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(10 * 60);
+
+/// How long stats requests are collected before one batched relay query goes out
+const STATS_BATCH_DELAY: std::time::Duration = std::time::Duration::from_millis(150);
+
+/// Most notes per stats query
+const STATS_BATCH_SIZE: usize = 50;
+
 // Use the global relay manager from relay module
 use crate::nostr::relay::GLOBAL_RELAY_MANAGER as RELAY_MANAGER;
 
@@ -311,8 +814,7 @@ use crate::nostr::relay::GLOBAL_RELAY_MANAGER as RELAY_MANAGER;
 lazy_static::lazy_static! {
     static ref FEED_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
     // Prefetched feed cache - keyed by feed type string
-    static ref FEED_CACHE: std::sync::RwLock<std::collections::HashMap<String, Vec<DisplayNote>>> = 
-        std::sync::RwLock::new(std::collections::HashMap::new());
+    static ref FEED_CACHE: LruCache<String, Vec<DisplayNote>> = LruCache::new(FEED_CACHE_FEEDS, Some(FEED_CACHE_TTL));
     // Signer client for signing events
     static ref FEED_SIGNER: Arc<Mutex<Option<SignerClient>>> = Arc::new(Mutex::new(None));
     // User's nsec for local signing (fallback)
@@ -320,36 +822,86 @@ lazy_static::lazy_static! {
     
     // Caches for embedded content to avoid blocking UI during scroll
     // Embedded event cache - keyed by nostr URI (nevent/note/naddr)
-    static ref EMBEDDED_EVENT_CACHE: std::sync::RwLock<std::collections::HashMap<String, String>> = 
-        std::sync::RwLock::new(std::collections::HashMap::new());
+    static ref EMBEDDED_EVENT_CACHE: LruCache<String, String> = LruCache::new(EMBED_CACHE_ENTRIES, Some(EMBED_CACHE_TTL));
     // Embedded profile cache - keyed by nostr URI (nprofile/npub)
-    static ref EMBEDDED_PROFILE_CACHE: std::sync::RwLock<std::collections::HashMap<String, String>> = 
-        std::sync::RwLock::new(std::collections::HashMap::new());
+    static ref EMBEDDED_PROFILE_CACHE: LruCache<String, String> = LruCache::new(EMBED_CACHE_ENTRIES, Some(EMBED_CACHE_TTL));
     // Link preview cache - keyed by URL
-    static ref LINK_PREVIEW_CACHE: std::sync::RwLock<std::collections::HashMap<String, String>> = 
-        std::sync::RwLock::new(std::collections::HashMap::new());
+    static ref LINK_PREVIEW_CACHE: LruCache<String, String> = LruCache::new(LINK_PREVIEW_CACHE_ENTRIES, Some(LINK_PREVIEW_CACHE_TTL));
     // Track pending fetches to avoid duplicate requests
     static ref PENDING_EMBEDS: std::sync::RwLock<std::collections::HashSet<String>> = 
         std::sync::RwLock::new(std::collections::HashSet::new());
     // Note stats cache - keyed by note ID
-    static ref NOTE_STATS_CACHE: std::sync::RwLock<std::collections::HashMap<String, String>> = 
-        std::sync::RwLock::new(std::collections::HashMap::new());
+    static ref NOTE_STATS_CACHE: LruCache<String, String> = LruCache::new(NOTE_STATS_CACHE_ENTRIES, Some(NOTE_STATS_CACHE_TTL));
+    // Bumped to start a new scheduled-post loop; older loops exit
+    static ref SCHEDULER_GENERATION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    // Bumped to start a new outbox loop; older loops exit
+    static ref OUTBOX_GENERATION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    // Held while an outbox pass runs so entries are never published twice
+    static ref OUTBOX_FLUSHING: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+    // Bumped to start a new relay reconnect loop; older loops exit
+    static ref RELAY_WATCH_GENERATION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    // Bumped when the open thread changes or closes; the live reply watcher for older threads exits
+    static ref THREAD_WATCH_GENERATION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    // Bumped to start a new profile update listener; older listeners exit
+    static ref PROFILE_UPDATE_GENERATION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+    // NIP-05 checks in flight, keyed by pubkey
+    static ref PENDING_NIP05: std::sync::RwLock<std::collections::HashSet<String>> = 
+        std::sync::RwLock::new(std::collections::HashSet::new());
     // Track pending stats fetches to avoid duplicate requests
     static ref PENDING_STATS: std::sync::RwLock<std::collections::HashSet<String>> = 
         std::sync::RwLock::new(std::collections::HashSet::new());
+    // Notes waiting for the next stats batch
+    static ref STATS_QUEUE: std::sync::Mutex<Vec<String>> = std::sync::Mutex::new(Vec::new());
+    // Composer mention profile fetches in flight, keyed by pubkey
+    static ref PENDING_MENTIONS: std::sync::RwLock<std::collections::HashSet<String>> = 
+        std::sync::RwLock::new(std::collections::HashSet::new());
+    // Set while the user's emoji list is being fetched
+    static ref EMOJI_REFRESHING: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+    static ref FOLLOW_SETS_REFRESHING: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+    static ref HASHTAGS_REFRESHING: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+}
+
+/// Count a sent reaction for the picker's recent/frequent lists
+/// "+" is stored as the heart it's displayed as
+fn record_reaction_usage(user_pubkey: Option<&str>, content: &str) {
+    let (Some(user), Ok(db)) = (user_pubkey, NostrDbManager::global()) else { return };
+    let content = if content == "+" || content.is_empty() { "❤️" } else { content };
+    let url = PublicKey::parse(user).ok().and_then(|pk| emoji::lookup(&pk, content));
+    db.record_reaction(user, content, url);
+}
+
+/// Notes per feed page from the settings
+fn feed_page_size() -> u64 {
+    Config::load().feed_page_size.clamp(MIN_FEED_PAGE_SIZE, MAX_FEED_PAGE_SIZE) as u64
+}
+
+/// Hours between the newest and oldest loaded note
+fn coverage_hours(notes: &[DisplayNote]) -> i64 {
+    match (notes.first(), notes.last()) {
+        (Some(newest), Some(oldest)) if oldest.created_at > 0 => (newest.created_at - oldest.created_at) / 3600,
+        _ => 0,
+    }
 }
 
 /// Prefetch a feed in the background and cache it
 fn prefetch_feed(feed_type: FeedType) {
+    // List and hashtag feeds need the user's lists and load on demand
+    if matches!(feed_type, FeedType::List | FeedType::Hashtags) {
+        return;
+    }
+    
     let feed_name = match feed_type {
         FeedType::Following => "following",
         FeedType::Replies => "replies",
         FeedType::Global => "global",
         FeedType::ReadsFollowing => "reads_following",
         FeedType::ReadsGlobal => "reads_global",
+        FeedType::Media => "media",
+        FeedType::List => "list",
+        FeedType::Hashtags => "hashtags",
     };
     
-    std::thread::spawn(move || {
+    limits::spawn_limited(move || {
         tracing::info!("Background prefetching {} feed...", feed_name);
         
         let result = FEED_RUNTIME.block_on(async {
@@ -358,13 +910,15 @@ fn prefetch_feed(feed_type: FeedType) {
                 return Err("Relay manager not initialized".to_string());
             };
             
-            let limit = 50u64;
+            let limit = feed_page_size();
             let events = match feed_type {
                 FeedType::Following => manager.fetch_following_feed(limit, None).await?,
                 FeedType::Replies => manager.fetch_home_feed(limit, None).await?,
                 FeedType::Global => manager.fetch_global_feed(limit, None).await?,
                 FeedType::ReadsFollowing => manager.fetch_long_form_following(limit, None).await?,
                 FeedType::ReadsGlobal => manager.fetch_long_form_global(limit, None).await?,
+                FeedType::Media => manager.fetch_media_feed(limit, None).await?,
+                FeedType::List | FeedType::Hashtags => Events::default(),
             };
             
             // Collect author pubkeys
@@ -421,6 +975,7 @@ fn prefetch_feed(feed_type: FeedType) {
                 })
                 .collect();
             
+            let mut notes = apply_feed_preferences(feed_type.as_str(), notes);
             notes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
             Ok(notes)
         });
@@ -428,10 +983,8 @@ fn prefetch_feed(feed_type: FeedType) {
         match result {
             Ok(notes) => {
                 let count = notes.len();
-                if let Ok(mut cache) = FEED_CACHE.write() {
-                    cache.insert(feed_name.to_string(), notes);
-                    tracing::info!("Prefetched {} notes for {} feed", count, feed_name);
-                }
+                FEED_CACHE.insert(feed_name.to_string(), notes);
+                tracing::info!("Prefetched {} notes for {} feed", count, feed_name);
             }
             Err(e) => {
                 tracing::warn!("Failed to prefetch {} feed: {}", feed_name, e);
@@ -449,11 +1002,17 @@ pub struct FeedControllerRust {
     error_message: QString,
     thread_count: i32,
     thread_note_id: QString,
+    thread_has_more: bool,
+    thread_sort: QString,
     loading_status: QString,
+    post_protected: bool,
+    outbox_count: i32,
     
     // Internal state
     notes: Vec<DisplayNote>,
-    thread_notes: Vec<DisplayNote>,  // Thread view: parents + target + replies
+    thread_notes: Vec<ThreadEntry>,  // Thread view: parents + target + reply tree
+    thread: Option<ThreadView>,
+    thread_loading_more: bool,
     user_pubkey: Option<String>,
     initialized: bool,
 }
@@ -469,6 +1028,7 @@ impl qobject::FeedController {
         
         let pubkey_str = user_pubkey.to_string();
         tracing::info!("Initializing FeedController for user: {}", pubkey_str);
+        report::load_hidden(&pubkey_str);
         
         // Mark as loading and show initial status
         self.as_mut().set_is_loading(true);
@@ -480,11 +1040,29 @@ impl qobject::FeedController {
             rust.user_pubkey = Some(pubkey_str.clone());
         }
         
+        // Remember where the previous session left off for catch up
+        catchup::begin_session();
+        
+        // Publish any scheduled posts that came due while the app was closed
+        self.as_mut().start_scheduler();
+        
+        // Publish notes and actions made while offline once relays are reachable
+        if let Ok(pk) = PublicKey::parse(&pubkey_str) {
+            self.as_mut().set_outbox_count(outbox::len(&pk) as i32);
+        }
+        self.as_mut().start_outbox();
+        
+        // Reconnect dropped relays with backoff and report degraded connectivity
+        self.as_mut().start_relay_watch();
+        
+        // Show profile edits on notes that are already loaded
+        self.as_mut().start_profile_updates();
+        
         // Get qt_thread handle for updating UI from background thread
         let qt_thread = self.qt_thread();
         
         // Spawn background thread for initialization
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             tracing::info!("Background init thread started");
             
             // Update status: Initializing database
@@ -517,37 +1095,26 @@ impl qobject::FeedController {
             // Initialize relay manager
             let pubkey_for_relay = pubkey_str.clone();
             let relay_result = FEED_RUNTIME.block_on(async {
-                // Initialize relay manager - use keys if nsec is available for NIP-42 auth
-                let mut manager = {
-                    let nsec_opt = FEED_NSEC.read().unwrap();
-                    if let Some(nsec) = nsec_opt.as_ref() {
-                        if let Ok(secret_key) = SecretKey::parse(nsec) {
-                            let keys = Keys::new(secret_key);
-                            tracing::info!("Creating relay manager with signing keys for NIP-42 auth");
-                            RelayManager::with_keys(keys)
-                        } else {
-                            tracing::warn!("Invalid nsec, creating relay manager without keys");
-                            RelayManager::new()
-                        }
-                    } else {
-                        tracing::warn!("No nsec available, relay authentication may fail");
-                        RelayManager::new()
-                    }
-                };
+                // Join the shared relay pool (another bridge may already have opened it)
+                // Keys are used if an nsec is available for NIP-42 auth
+                if FEED_NSEC.read().unwrap().is_none() {
+                    tracing::warn!("No nsec available, relay authentication may fail");
+                }
+                let mut manager = relay_service::acquire(create_authenticated_relay_manager).await
+                    .map_err(|e| format!("Failed to connect to relays: {}", e))?;
+                
+                let config = Config::load();
+                manager.set_home_feed_kinds(
+                    config.home_feed_kinds.into_iter().map(Kind::from).collect()
+                );
+                manager.set_feed_window_hours(config.feed_window_hours);
                 
-                // Set user pubkey and connect
                 if let Ok(pk) = PublicKey::parse(&pubkey_for_relay) {
                     manager.set_user_pubkey(pk);
-                    
-                    // Connect to relays
-                    if let Err(e) = manager.connect().await {
-                        return Err(format!("Failed to connect to relays: {}", e));
-                    }
                 }
                 
-                // Store relay manager
-                let mut rm = RELAY_MANAGER.write().unwrap();
-                *rm = Some(manager);
+                // Store the configured clone as the pooled manager
+                relay_service::install(manager);
                 
                 Ok(())
             });
@@ -583,6 +1150,17 @@ impl qobject::FeedController {
                 }
             });
             
+            // Keep followed users' names and avatars current for the session
+            let watched = RELAY_MANAGER.read().unwrap().clone();
+            if let Some(manager) = watched {
+                std::thread::spawn(move || {
+                    let follows = manager.following().to_vec();
+                    if let Err(e) = FEED_RUNTIME.block_on(profile_store::watch_follows(&manager, follows)) {
+                        tracing::warn!("Live profile updates unavailable: {}", e);
+                    }
+                });
+            }
+            
             // Fetch user's own profile
             let qt_thread_clone = qt_thread.clone();
             let _ = qt_thread_clone.queue(|mut qobject| {
@@ -631,7 +1209,7 @@ impl qobject::FeedController {
                     return Err("Relay manager not initialized".to_string());
                 };
                 
-                let limit = 50u64;
+                let limit = feed_page_size();
                 let events = manager.fetch_following_feed(limit, None).await?;
                 
                 // Collect author pubkeys
@@ -676,6 +1254,7 @@ impl qobject::FeedController {
                     })
                     .collect();
                 
+                let mut notes = apply_feed_preferences(FeedType::Following.as_str(), notes);
                 notes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
                 Ok(notes)
             });
@@ -684,9 +1263,7 @@ impl qobject::FeedController {
             match feed_result {
                 Ok(notes) => {
                     // Cache the results
-                    if let Ok(mut cache) = FEED_CACHE.write() {
-                        cache.insert("following".to_string(), notes.clone());
-                    }
+                    FEED_CACHE.insert("following".to_string(), notes.clone());
                     
                     let count = notes.len() as i32;
                     let _ = qt_thread.queue(move |mut qobject| {
@@ -703,17 +1280,20 @@ impl qobject::FeedController {
                         qobject.as_mut().set_error_message(QString::from(""));
                         qobject.as_mut().loading_changed(false);
                         qobject.as_mut().feed_updated();
+                        qobject.as_mut().schedule_nip05_checks();
                         
                         tracing::info!("FeedController initialized with {} notes", count);
                     });
                     
-                    // Prefetch other feeds in background
-                    prefetch_feed(FeedType::Replies);
-                    prefetch_feed(FeedType::Global);
-                    
-                    // Prefetch DMs in background so they're ready when user navigates to messages
-                    let pubkey_for_dm_prefetch = pubkey_str.clone();
-                    prefetch_dms(pubkey_for_dm_prefetch);
+                    if Config::load().prefetch_on_startup {
+                        // Prefetch other feeds in background
+                        prefetch_feed(FeedType::Replies);
+                        prefetch_feed(FeedType::Global);
+                        
+                        // Prefetch DMs in background so they're ready when user navigates to messages
+                        let pubkey_for_dm_prefetch = pubkey_str.clone();
+                        prefetch_dms(pubkey_for_dm_prefetch);
+                    }
                 }
                 Err(e) => {
                     let error_msg = e.clone();
@@ -741,26 +1321,24 @@ impl qobject::FeedController {
         self.as_mut().set_current_feed(feed_type.clone());
         
         // Check if we have this feed cached already
-        if let Ok(cache) = FEED_CACHE.read() {
-            if let Some(cached_notes) = cache.get(&feed_type_str) {
-                if !cached_notes.is_empty() {
-                    tracing::info!("Using cached {} feed ({} notes)", feed_type_str, cached_notes.len());
-                    let notes = cached_notes.clone();
-                    let count = notes.len() as i32;
-                    {
-                        let mut rust = self.as_mut().rust_mut();
-                        rust.notes = notes;
-                        rust.note_count = count;
-                    }
-                    self.as_mut().set_note_count(count);
-                    self.as_mut().set_is_loading(false);
-                    self.as_mut().set_error_message(QString::from(""));
-                    self.as_mut().set_loading_status(QString::from(""));
-                    self.as_mut().loading_changed(false);
-                    self.as_mut().feed_updated();
-                    return;
-                }
+        let cached = FEED_CACHE.get(&feed_type_str).filter(|notes| !notes.is_empty());
+        cache_stats::record(cache_stats::FEED, cached.is_some());
+        if let Some(notes) = cached {
+            tracing::info!("Using cached {} feed ({} notes)", feed_type_str, notes.len());
+            let count = notes.len() as i32;
+            {
+                let mut rust = self.as_mut().rust_mut();
+                rust.notes = notes;
+                rust.note_count = count;
             }
+            self.as_mut().set_note_count(count);
+            self.as_mut().set_is_loading(false);
+            self.as_mut().set_error_message(QString::from(""));
+            self.as_mut().set_loading_status(QString::from(""));
+            self.as_mut().loading_changed(false);
+            self.as_mut().feed_updated();
+            self.as_mut().schedule_nip05_checks();
+            return;
         }
         
         // No cache - load from network in background thread
@@ -770,11 +1348,13 @@ impl qobject::FeedController {
         self.as_mut().loading_changed(true);
         
         let feed = FeedType::from_str(&feed_type_str);
+        let list_authors = list_feed_authors(self.user_pubkey.as_ref(), &feed_type_str);
+        let hashtags = followed_hashtags(self.user_pubkey.as_ref());
         let qt_thread = self.qt_thread();
         let feed_type_for_thread = feed_type_str.clone();
         
         // Spawn background thread for feed loading
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = FEED_RUNTIME.block_on(async {
                 let rm = RELAY_MANAGER.read().unwrap();
                 let Some(manager) = rm.as_ref() else {
@@ -782,13 +1362,16 @@ impl qobject::FeedController {
                 };
                 
                 // Fetch feed based on type
-                let limit = 50u64;
+                let limit = feed_page_size();
                 let events = match feed {
                     FeedType::Following => manager.fetch_following_feed(limit, None).await?,
                     FeedType::Replies => manager.fetch_home_feed(limit, None).await?,
                     FeedType::Global => manager.fetch_global_feed(limit, None).await?,
                     FeedType::ReadsFollowing => manager.fetch_long_form_following(limit, None).await?,
                     FeedType::ReadsGlobal => manager.fetch_long_form_global(limit, None).await?,
+                    FeedType::Media => manager.fetch_media_feed(limit, None).await?,
+                    FeedType::List => manager.fetch_authors_feed(&list_authors, limit, None).await?,
+                    FeedType::Hashtags => manager.fetch_hashtag_feed(&hashtags, limit, None).await?,
                 };
                 
                 // Collect unique pubkeys for profile fetching
@@ -872,7 +1455,7 @@ impl qobject::FeedController {
                     tracing::info!("Reads feed: {} notes passed filter", notes.len());
                 }
                 
-                Ok(notes)
+                Ok(apply_feed_preferences(&feed_preferences_key(&feed_type_for_thread), notes))
             });
             
             match result {
@@ -881,9 +1464,7 @@ impl qobject::FeedController {
                     notes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
                     
                     // Cache the results
-                    if let Ok(mut cache) = FEED_CACHE.write() {
-                        cache.insert(feed_type_for_thread.clone(), notes.clone());
-                    }
+                    FEED_CACHE.insert(feed_type_for_thread.clone(), notes.clone());
                     
                     let count = notes.len() as i32;
                     let feed_name = feed_type_for_thread.clone();
@@ -899,6 +1480,7 @@ impl qobject::FeedController {
                         qobject.as_mut().set_error_message(QString::from(""));
                         qobject.as_mut().loading_changed(false);
                         qobject.as_mut().feed_updated();
+                        qobject.as_mut().schedule_nip05_checks();
                         
                         tracing::info!("Loaded {} notes for {} feed", count, feed_name);
                     });
@@ -941,25 +1523,14 @@ impl qobject::FeedController {
             return;
         }
         
-        // Check if we already have 24 hours of data
-        let newest_timestamp = {
-            let rust = self.as_ref();
-            rust.notes.first().map(|n| n.created_at).unwrap_or(0)
-        };
-        
-        let hours_covered = if newest_timestamp > 0 && oldest_timestamp > 0 {
-            (newest_timestamp - oldest_timestamp) / 3600 // seconds to hours
-        } else {
-            0
-        };
-        
-        tracing::info!("Current feed coverage: {} hours ({} to {})", 
-            hours_covered, oldest_timestamp, newest_timestamp);
+        tracing::info!("Current feed coverage: {} hours", coverage_hours(&self.notes));
         
         self.as_mut().set_is_loading(true);
         self.as_mut().loading_changed(true);
         
         let feed = FeedType::from_str(&current_feed_type);
+        let list_authors = list_feed_authors(self.user_pubkey.as_ref(), &current_feed_type);
+        let hashtags = followed_hashtags(self.user_pubkey.as_ref());
         let current_feed_clone = current_feed_type.clone();
         
         tracing::info!("Loading more for {} feed, before timestamp {}", current_feed_type, oldest_timestamp);
@@ -967,7 +1538,7 @@ impl qobject::FeedController {
         let qt_thread = self.qt_thread();
         
         // Spawn thread to avoid Qt/tokio conflicts (same pattern as check_for_new)
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = FEED_RUNTIME.block_on(async {
                 let rm = RELAY_MANAGER.read().unwrap();
                 let Some(manager) = rm.as_ref() else {
@@ -976,7 +1547,7 @@ impl qobject::FeedController {
                 
                 // Use timestamp - 1 to avoid duplicates
                 let until = Some(Timestamp::from((oldest_timestamp - 1) as u64));
-                let limit = 50u64;
+                let limit = feed_page_size();
                 
                 let events = match feed {
                     FeedType::Following => manager.fetch_following_feed(limit, until).await?,
@@ -984,16 +1555,12 @@ impl qobject::FeedController {
                     FeedType::Global => manager.fetch_global_feed(limit, until).await?,
                     FeedType::ReadsFollowing => manager.fetch_long_form_following(limit, until).await?,
                     FeedType::ReadsGlobal => manager.fetch_long_form_global(limit, until).await?,
+                    FeedType::Media => manager.fetch_media_feed(limit, until).await?,
+                    FeedType::List => manager.fetch_authors_feed(&list_authors, limit, until).await?,
+                    FeedType::Hashtags => manager.fetch_hashtag_feed(&hashtags, limit, until).await?,
                 };
                 
-                tracing::info!("Fetched {} older events for {} feed", events.len(), 
-                    match feed { 
-                        FeedType::Following => "following", 
-                        FeedType::Replies => "replies", 
-                        FeedType::Global => "global",
-                        FeedType::ReadsFollowing => "reads_following",
-                        FeedType::ReadsGlobal => "reads_global",
-                    });
+                tracing::info!("Fetched {} older events for {} feed", events.len(), current_feed_clone);
                 
                 // Fetch profiles for new authors
                 let mut pubkeys: Vec<PublicKey> = events
@@ -1049,7 +1616,7 @@ impl qobject::FeedController {
                     })
                     .collect();
                 
-                Ok(notes)
+                Ok(apply_feed_preferences(&feed_preferences_key(&current_feed_clone), notes))
             });
             
             let _ = qt_thread.queue(move |mut qobject| {
@@ -1084,25 +1651,16 @@ impl qobject::FeedController {
                         };
                         
                         // Update cache
-                        if let Ok(mut cache) = FEED_CACHE.write() {
-                            let rust = qobject.as_ref();
-                            cache.insert(current_feed_clone.clone(), rust.notes.clone());
-                        }
+                        FEED_CACHE.insert(current_feed_clone.clone(), qobject.as_ref().notes.clone());
                         
-                        // Get newest for logging
-                        let new_oldest = {
-                            let rust = qobject.as_ref();
-                            rust.notes.last().map(|n| n.created_at).unwrap_or(0)
-                        };
-                        let hours = if newest_timestamp > 0 && new_oldest > 0 {
-                            (newest_timestamp - new_oldest) / 3600
-                        } else { 0 };
+                        let hours = coverage_hours(&qobject.as_ref().notes);
                         
                         qobject.as_mut().set_note_count(total);
                         qobject.as_mut().set_is_loading(false);
                         qobject.as_mut().loading_changed(false);
                         qobject.as_mut().more_loaded(count);
                         qobject.as_mut().feed_updated();
+                        qobject.as_mut().schedule_nip05_checks();
                         
                         tracing::info!("Loaded {} more notes, total: {}, coverage: {} hours", count, total, hours);
                     }
@@ -1161,6 +1719,7 @@ impl qobject::FeedController {
                     FeedType::Following => manager.fetch_following_feed(limit, until).await?,
                     FeedType::Replies => manager.fetch_replies_feed(limit, until).await?,
                     FeedType::Global => manager.fetch_global_feed(limit, until).await?,
+                    FeedType::Media => manager.fetch_media_feed(limit, until).await?,
                 };
                 
                 // Fetch profiles for new authors
@@ -1210,6 +1769,7 @@ impl qobject::FeedController {
                 self.as_mut().set_is_loading(false);
                 self.as_mut().more_loaded(count);
                 self.as_mut().feed_updated();
+                self.as_mut().schedule_nip05_checks();
                 
                 tracing::info!("Loaded {} more notes, total: {}", count, total);
             }
@@ -1234,6 +1794,7 @@ impl qobject::FeedController {
             tracing::warn!("check_for_new called while already loading, ignoring");
             return;
         }
+        refresh::mark_ran(RefreshTask::Feed);
         
         let (newest_timestamp, should_do_normal_load) = {
             let rust = self.as_ref();
@@ -1255,6 +1816,8 @@ impl qobject::FeedController {
         
         let current = self.current_feed().to_string();
         let feed = FeedType::from_str(&current);
+        let list_authors = list_feed_authors(self.user_pubkey.as_ref(), &current);
+        let hashtags = followed_hashtags(self.user_pubkey.as_ref());
         let current_clone = current.clone();
         
         tracing::info!("Checking for new {} notes since timestamp {}", current, newest_timestamp);
@@ -1264,7 +1827,7 @@ impl qobject::FeedController {
         let qt_thread = self.qt_thread();
         
         // Use a separate thread to avoid Qt/tokio conflicts
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = FEED_RUNTIME.block_on(async {
                 let rm = RELAY_MANAGER.read().unwrap();
                 let Some(manager) = rm.as_ref() else {
@@ -1272,13 +1835,16 @@ impl qobject::FeedController {
                 };
                 
                 // Fetch recent notes - we'll filter by timestamp on our end
-                let limit = 50u64; // Fetch more to increase chance of finding new ones
+                let limit = feed_page_size();
                 let events = match feed {
                     FeedType::Following => manager.fetch_following_feed(limit, None).await?,
                     FeedType::Replies => manager.fetch_home_feed(limit, None).await?,
                     FeedType::Global => manager.fetch_global_feed(limit, None).await?,
                     FeedType::ReadsFollowing => manager.fetch_long_form_following(limit, None).await?,
                     FeedType::ReadsGlobal => manager.fetch_long_form_global(limit, None).await?,
+                    FeedType::Media => manager.fetch_media_feed(limit, None).await?,
+                    FeedType::List => manager.fetch_authors_feed(&list_authors, limit, None).await?,
+                    FeedType::Hashtags => manager.fetch_hashtag_feed(&hashtags, limit, None).await?,
                 };
                 
                 tracing::debug!("check_for_new: fetched {} events from relays", events.len());
@@ -1357,7 +1923,7 @@ impl qobject::FeedController {
                     })
                     .collect();
             
-                Ok(notes)
+                Ok(apply_feed_preferences(&feed_preferences_key(&current_clone), notes))
             });
             
             let _ = qt_thread.queue(move |mut qobject| {
@@ -1384,14 +1950,12 @@ impl qobject::FeedController {
                         };
                         
                         // Update the cache too
-                        if let Ok(mut cache) = FEED_CACHE.write() {
-                            let rust = qobject.as_ref();
-                            cache.insert(current_clone.clone(), rust.notes.clone());
-                        }
+                        FEED_CACHE.insert(current_clone.clone(), qobject.as_ref().notes.clone());
                         
                         qobject.as_mut().set_note_count(total);
                         qobject.as_mut().new_notes_found(new_count);
                         qobject.as_mut().feed_updated();
+                        qobject.as_mut().schedule_nip05_checks();
                         
                         tracing::info!("Found {} new notes for {} feed, total: {}", new_count, current_clone, total);
                     }
@@ -1409,35 +1973,43 @@ impl qobject::FeedController {
         let current = self.current_feed().to_string();
         
         // Clear the cache for this feed so we fetch fresh data
-        if let Ok(mut cache) = FEED_CACHE.write() {
-            cache.remove(&current);
-            tracing::info!("Cleared cache for {} feed, fetching fresh", current);
-        }
+        FEED_CACHE.remove(&current);
+        tracing::info!("Cleared cache for {} feed, fetching fresh", current);
         
         self.load_feed(&QString::from(&current));
     }
     
     /// Get note at index
-    pub fn get_note(&self, index: i32) -> QString {
-        if let Some(note) = self.notes.get(index as usize) {
-            QString::from(&note.to_json())
-        } else {
-            QString::from("{}")
+    pub fn get_note(&self, index: i32) -> QVariant {
+        match self.notes.get(index as usize) {
+            Some(note) => variant::from_json(&note.to_value()),
+            None => variant::empty_map(),
+        }
+    }
+    
+    /// Get note at index with its extracted media
+    pub fn get_media_note(&self, index: i32) -> QVariant {
+        match self.notes.get(index as usize) {
+            Some(note) => variant::from_json(&note.to_media_value()),
+            None => variant::empty_map(),
         }
     }
     
-    /// Load thread for a specific note (parents + target + replies)
+    /// Load thread for a specific note (parents + target + reply tree)
     pub fn load_thread(mut self: Pin<&mut Self>, note_id: &QString) {
         let note_id_str = note_id.to_string();
         tracing::info!("Loading thread for note: {}", note_id_str);
         
         self.as_mut().set_is_loading(true);
         self.as_mut().set_thread_note_id(note_id.clone());
+        self.as_mut().set_thread_has_more(false);
+        THREAD_WATCH_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
         
+        let sort = ThreadSort::from_str(&self.thread_sort().to_string());
         let qt_thread = self.qt_thread();
         
         // Use a separate thread to avoid Qt/tokio conflicts
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = FEED_RUNTIME.block_on(async {
                 let rm = RELAY_MANAGER.read().unwrap();
                 let Some(manager) = rm.as_ref() else {
@@ -1449,83 +2021,38 @@ impl qobject::FeedController {
                 
                 let (parents, target, replies) = manager.fetch_thread(&event_id).await?;
                 
-                // Collect all events for profile fetching
-                let mut all_events = parents.clone();
-                if let Some(ref t) = target {
-                    all_events.push(t.clone());
-                }
-                all_events.extend(replies.clone());
+                // Parents first, then the target
+                let mut head = parents;
+                head.extend(target);
                 
-                // Fetch profiles (including reply-to authors)
-                let mut pubkeys: Vec<PublicKey> = all_events
-                    .iter()
-                    .map(|e| e.pubkey)
-                    .collect::<std::collections::HashSet<_>>()
-                    .into_iter()
-                    .collect();
-                
-                // Also collect reply-to author pubkeys
-                let reply_to_pubkeys = crate::nostr::feed::extract_reply_to_pubkeys(&all_events);
-                for pk_hex in reply_to_pubkeys {
-                    if let Ok(pk) = PublicKey::parse(&pk_hex) {
-                        pubkeys.push(pk);
-                    }
-                }
-                
-                // Deduplicate
-                let pubkeys: Vec<PublicKey> = pubkeys
-                    .into_iter()
-                    .collect::<std::collections::HashSet<_>>()
-                    .into_iter()
-                    .collect();
-                
-                let profiles = manager.fetch_profiles(&pubkeys).await.unwrap_or_default();
-                
-                let mut profile_map = std::collections::HashMap::new();
-                for profile_event in profiles.iter() {
-                    if let Ok(metadata) = Metadata::from_json(&profile_event.content) {
-                        let pubkey_hex = profile_event.pubkey.to_hex();
-                        profile_map.insert(pubkey_hex, ProfileCache::from_metadata(&metadata));
-                    }
-                }
-                
-                // Build thread notes list: parents first, then target, then replies
-                let mut thread_notes: Vec<DisplayNote> = Vec::new();
-                
-                for event in &parents {
-                    let pubkey_hex = event.pubkey.to_hex();
-                    let profile = profile_map.get(&pubkey_hex);
-                    thread_notes.push(DisplayNote::from_event_with_profiles(event, profile, &profile_map));
-                }
-                
-                if let Some(ref t) = target {
-                    let pubkey_hex = t.pubkey.to_hex();
-                    let profile = profile_map.get(&pubkey_hex);
-                    thread_notes.push(DisplayNote::from_event_with_profiles(t, profile, &profile_map));
-                }
-                
-                for event in &replies {
-                    let pubkey_hex = event.pubkey.to_hex();
-                    let profile = profile_map.get(&pubkey_hex);
-                    thread_notes.push(DisplayNote::from_event_with_profiles(event, profile, &profile_map));
-                }
-                
-                Ok(thread_notes)
+                let profile_map = fetch_thread_profiles(manager, head.iter().chain(replies.events.iter())).await;
+                Ok(ThreadView::new(&note_id_str, head, replies, profile_map, sort))
             });
             
             let _ = qt_thread.queue(move |mut qobject| {
                 match result {
-                    Ok(thread_notes) => {
-                        let count = thread_notes.len() as i32;
+                    Ok(view) => {
+                        // Another thread was opened while this one loaded
+                        if qobject.thread_note_id().to_string() != view.target_id {
+                            return;
+                        }
+                        let has_more = view.has_more;
+                        let entries = view.entries();
+                        let count = entries.len() as i32;
+                        let watch_ids = view.watch_ids();
+                        let target_id = view.target_id.clone();
                         {
                             let mut rust = qobject.as_mut().rust_mut();
-                            rust.thread_notes = thread_notes;
+                            rust.thread_notes = entries;
+                            rust.thread = Some(view);
                             rust.thread_count = count;
                         }
                         qobject.as_mut().set_thread_count(count);
+                        qobject.as_mut().set_thread_has_more(has_more);
                         qobject.as_mut().set_is_loading(false);
                         qobject.as_mut().thread_loaded();
                         tracing::info!("Loaded thread with {} notes", count);
+                        qobject.as_mut().watch_thread(target_id, watch_ids);
                     }
                     Err(e) => {
                         tracing::error!("Failed to load thread: {}", e);
@@ -1538,177 +2065,272 @@ impl qobject::FeedController {
     }
     
     /// Get thread note at index
-    pub fn get_thread_note(&self, index: i32) -> QString {
-        if let Some(note) = self.thread_notes.get(index as usize) {
-            QString::from(&note.to_json())
-        } else {
-            QString::from("{}")
+    pub fn get_thread_note(&self, index: i32) -> QVariant {
+        match self.thread_notes.get(index as usize) {
+            Some(entry) => variant::from_json(&entry.to_value()),
+            None => variant::empty_map(),
         }
     }
     
-    /// Clear thread view
-    pub fn clear_thread(mut self: Pin<&mut Self>) {
-        {
-            let mut rust = self.as_mut().rust_mut();
-            rust.thread_notes.clear();
-            rust.thread_count = 0;
+    /// Load older replies to the open thread and merge them into the tree
+    pub fn load_more_replies(mut self: Pin<&mut Self>) {
+        if self.thread_loading_more {
+            return;
         }
-        self.as_mut().set_thread_count(0);
-        self.as_mut().set_thread_note_id(QString::from(""));
-    }
-    
-    /// Like a note (kind 7)
-    pub fn like_note(mut self: Pin<&mut Self>, note_id: &QString) {
-        let note_id_str = note_id.to_string();
-        tracing::info!("Like note: {}", note_id_str);
+        let Some((target_id, until)) = self.thread.as_ref()
+            .filter(|view| view.has_more)
+            .map(|view| (view.target_id.clone(), view.until))
+        else {
+            return;
+        };
+        let Ok(event_id) = EventId::parse(&target_id) else { return };
         
-        let user_pubkey = self.user_pubkey.clone();
+        self.as_mut().rust_mut().thread_loading_more = true;
+        self.as_mut().set_is_loading(true);
         
-        let result = FEED_RUNTIME.block_on(async {
-            let event_id = EventId::from_hex(&note_id_str)
-                .map_err(|e| format!("Invalid event ID: {}", e))?;
-            
-            let user_pk = user_pubkey.as_ref()
-                .and_then(|pk| PublicKey::parse(pk).ok())
-                .ok_or("User not initialized")?;
-            
-            // Get relay manager
-            let rm = RELAY_MANAGER.read().unwrap();
-            let manager = rm.as_ref().ok_or("Not connected to relays")?;
-            let client = manager.client();
-            
-            // Build reaction event (kind 7)
-            let tags = vec![
-                Tag::event(event_id),
-                Tag::public_key(user_pk), // Tag the author (we'd need to fetch the event to get author)
-            ];
+        let qt_thread = self.qt_thread();
+        
+        limits::spawn_limited(move || {
+            let result = FEED_RUNTIME.block_on(async {
+                let rm = RELAY_MANAGER.read().unwrap();
+                let Some(manager) = rm.as_ref() else {
+                    return Err("Relay manager not initialized".to_string());
+                };
+                let page = manager.fetch_reply_page(&event_id, REPLY_PAGE_SIZE, until).await?;
+                let profile_map = fetch_thread_profiles(manager, page.events.iter()).await;
+                Ok((page, profile_map))
+            });
             
-            // Try signer first
-            let signer = FEED_SIGNER.lock().await;
-            if let Some(s) = signer.as_ref() {
-                let unsigned = EventBuilder::new(Kind::Reaction, "+")
-                    .tags(tags)
-                    .build(user_pk);
-                
-                let unsigned_json = serde_json::to_string(&unsigned)
-                    .map_err(|e| format!("Serialization failed: {}", e))?;
-                
-                let signed_result = s.sign_event(&unsigned_json).await
-                    .map_err(|e| format!("Signing failed: {}", e))?;
-                
-                let signed_event: Event = serde_json::from_str(&signed_result.event_json)
-                    .map_err(|e| format!("Failed to parse signed event: {}", e))?;
-                
-                client.send_event(&signed_event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().rust_mut().thread_loading_more = false;
+                qobject.as_mut().set_is_loading(false);
                 
-                Ok::<String, String>(signed_event.id.to_hex())
-            } else if let Some(nsec) = FEED_NSEC.read().unwrap().as_ref() {
-                // Use local keys
-                let secret_key = SecretKey::parse(nsec)
-                    .map_err(|e| format!("Invalid nsec: {}", e))?;
-                let keys = Keys::new(secret_key);
+                let (page, profile_map) = match result {
+                    Ok(loaded) => loaded,
+                    Err(e) => {
+                        tracing::error!("Failed to load more replies: {}", e);
+                        qobject.as_mut().error_occurred(&QString::from(&e));
+                        return;
+                    }
+                };
                 
-                let event = EventBuilder::new(Kind::Reaction, "+")
-                    .tags(tags)
-                    .sign_with_keys(&keys)
-                    .map_err(|e| format!("Failed to sign: {}", e))?;
+                // The thread may have been closed or replaced meanwhile
+                let merged = {
+                    let mut rust = qobject.as_mut().rust_mut();
+                    rust.thread.as_mut()
+                        .filter(|view| view.target_id == target_id)
+                        .map(|view| {
+                            let added = view.add_page(page, profile_map);
+                            (added, view.has_more, view.entries())
+                        })
+                };
+                let Some((added, has_more, entries)) = merged else { return };
                 
-                client.send_event(&event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
+                let count = entries.len() as i32;
+                {
+                    let mut rust = qobject.as_mut().rust_mut();
+                    rust.thread_notes = entries;
+                    rust.thread_count = count;
+                }
+                qobject.as_mut().set_thread_count(count);
+                qobject.as_mut().set_thread_has_more(has_more);
+                qobject.as_mut().thread_replies_loaded(added as i32);
+                tracing::info!("Loaded {} more replies", added);
+            });
+        });
+    }
+    
+    /// Subscribe to new replies for the open thread until it closes or another opens
+    /// Replies that fit the loaded tree are merged in and announced on thread_reply_added
+    fn watch_thread(self: Pin<&mut Self>, target_id: String, watch_ids: Vec<EventId>) {
+        use std::sync::atomic::Ordering;
+        
+        if watch_ids.is_empty() {
+            return;
+        }
+        let generation = THREAD_WATCH_GENERATION.load(Ordering::SeqCst);
+        let qt_thread = self.qt_thread();
+        
+        // Long-lived, so it gets its own thread rather than a limited worker
+        std::thread::spawn(move || {
+            let result: Result<(), String> = FEED_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                let filter = Filter::new()
+                    .kind(Kind::TextNote)
+                    .events(watch_ids)
+                    .since(Timestamp::now());
+                let handle = relay_service::subscribe(&manager, filter).await?;
+                let mut notifications = manager.client().notifications();
+                tracing::info!("Watching thread {} for new replies", target_id);
+                
+                while THREAD_WATCH_GENERATION.load(Ordering::SeqCst) == generation {
+                    let notification = match tokio::time::timeout(THREAD_WATCH_POLL, notifications.recv()).await {
+                        Ok(Ok(notification)) => notification,
+                        Ok(Err(tokio::sync::broadcast::error::RecvError::Lagged(_))) => continue,
+                        Ok(Err(_)) => break,
+                        Err(_) => continue,  // Timed out; check whether the thread is still open
+                    };
+                    let RelayPoolNotification::Event { subscription_id, event, .. } = notification else { continue };
+                    if subscription_id != *handle.id() {
+                        continue;
+                    }
+                    
+                    let event = *event;
+                    let profiles = fetch_thread_profiles(&manager, std::iter::once(&event)).await;
+                    let target_id = target_id.clone();
+                    let _ = qt_thread.queue(move |mut qobject| {
+                        if THREAD_WATCH_GENERATION.load(Ordering::SeqCst) != generation {
+                            return;
+                        }
+                        let note_id = event.id.to_hex();
+                        let entries = {
+                            let mut rust = qobject.as_mut().rust_mut();
+                            match rust.thread.as_mut() {
+                                Some(view) if view.target_id == target_id => {
+                                    view.add_live(event, profiles).then(|| view.entries())
+                                }
+                                _ => None,
+                            }
+                        };
+                        let Some(entries) = entries else { return };
+                        
+                        let index = entries.iter().position(|e| e.note.id == note_id).map_or(-1, |i| i as i32);
+                        let count = entries.len() as i32;
+                        {
+                            let mut rust = qobject.as_mut().rust_mut();
+                            rust.thread_notes = entries;
+                            rust.thread_count = count;
+                        }
+                        qobject.as_mut().set_thread_count(count);
+                        qobject.as_mut().thread_reply_added(&QString::from(&note_id), index);
+                    });
+                }
                 
-                Ok(event.id.to_hex())
-            } else {
-                Err("No signing capability available".to_string())
+                drop(handle);
+                tracing::info!("Stopped watching thread {}", target_id);
+                Ok(())
+            });
+            if let Err(e) = result {
+                tracing::warn!("Live thread updates unavailable: {}", e);
             }
         });
+    }
+    
+    /// Re-sort the open thread's replies without refetching
+    pub fn sort_thread(mut self: Pin<&mut Self>, sort: &QString) {
+        let sort = ThreadSort::from_str(&sort.to_string());
+        self.as_mut().set_thread_sort(QString::from(sort.as_str()));
         
-        match result {
-            Ok(event_id) => {
-                tracing::info!("Liked note, reaction event: {}", event_id);
-            }
-            Err(e) => {
-                tracing::error!("Failed to like note: {}", e);
-                self.as_mut().error_occurred(&QString::from(&e));
+        let entries = {
+            let mut rust = self.as_mut().rust_mut();
+            match rust.thread.as_mut() {
+                Some(view) if view.sort != sort => {
+                    view.sort = sort;
+                    Some(view.entries())
+                }
+                _ => None,
             }
+        };
+        let Some(entries) = entries else { return };
+        self.as_mut().rust_mut().thread_notes = entries;
+        self.as_mut().thread_loaded();
+    }
+    
+    /// Clear thread view
+    pub fn clear_thread(mut self: Pin<&mut Self>) {
+        THREAD_WATCH_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        {
+            let mut rust = self.as_mut().rust_mut();
+            rust.thread_notes.clear();
+            rust.thread = None;
+            rust.thread_count = 0;
         }
+        self.as_mut().set_thread_count(0);
+        self.as_mut().set_thread_has_more(false);
+        self.as_mut().set_thread_note_id(QString::from(""));
+    }
+    
+    /// Like a note (kind 7)
+    pub fn like_note(self: Pin<&mut Self>, note_id: &QString) {
+        tracing::info!("Like note: {}", note_id);
+        self.send_reaction(OperationKind::Like, &note_id.to_string(), "");
     }
     
     /// React to a note with a custom emoji (kind 7)
-    pub fn react_to_note(mut self: Pin<&mut Self>, note_id: &QString, emoji: &QString) {
-        let note_id_str = note_id.to_string();
-        let emoji_str = emoji.to_string();
-        let reaction_content = if emoji_str.is_empty() { "+".to_string() } else { emoji_str.clone() };
-        tracing::info!("Reacting to note {} with: {}", note_id_str, reaction_content);
-        
+    pub fn react_to_note(self: Pin<&mut Self>, note_id: &QString, emoji: &QString) {
+        tracing::info!("Reacting to note {} with: {}", note_id, emoji);
+        self.send_reaction(OperationKind::React, &note_id.to_string(), &emoji.to_string());
+    }
+    
+    /// Sign and send a kind 7 reaction; kept in the outbox while offline
+    fn send_reaction(mut self: Pin<&mut Self>, kind: OperationKind, note_id_str: &str, emoji_str: &str) {
+        let reaction_content = if emoji_str.is_empty() { "+".to_string() } else { emoji_str.to_string() };
         let user_pubkey = self.user_pubkey.clone();
+        let author = self.loaded_note_author(note_id_str);
+        
+        let op = match kind {
+            OperationKind::Like => Operation::new(kind, note_id_str).with_args(&[note_id_str]),
+            _ => Operation::new(kind, note_id_str).with_args(&[note_id_str, emoji_str]),
+        };
+        self.as_mut().begin_operation(&op);
         
         let result = FEED_RUNTIME.block_on(async {
-            let event_id = EventId::from_hex(&note_id_str)
+            let event_id = EventId::from_hex(note_id_str)
                 .map_err(|e| format!("Invalid event ID: {}", e))?;
             
             let user_pk = user_pubkey.as_ref()
                 .and_then(|pk| PublicKey::parse(pk).ok())
                 .ok_or("User not initialized")?;
             
-            // Get relay manager
-            let rm = RELAY_MANAGER.read().unwrap();
-            let manager = rm.as_ref().ok_or("Not connected to relays")?;
-            let client = manager.client();
-            
-            // Fetch the original event to get the author's pubkey
-            let original_event = manager.fetch_event(&event_id).await?
-                .ok_or("Original event not found")?;
+            // The author is needed for the p tag; ask relays only if the note isn't loaded
+            let author = match author {
+                Some(author) => author,
+                None => {
+                    let manager = shared_relay_manager().await?;
+                    manager.fetch_event(&event_id).await?
+                        .ok_or("Original event not found")?
+                        .pubkey
+                }
+            };
             
             // Build reaction event (kind 7)
-            let tags = vec![
+            let mut tags = vec![
                 Tag::event(event_id),
-                Tag::public_key(original_event.pubkey),
+                Tag::public_key(author),
             ];
             
-            // Try signer first
-            let signer = FEED_SIGNER.lock().await;
-            if let Some(s) = signer.as_ref() {
-                let unsigned = EventBuilder::new(Kind::Reaction, &reaction_content)
-                    .tags(tags)
-                    .build(user_pk);
-                
-                let unsigned_json = serde_json::to_string(&unsigned)
-                    .map_err(|e| format!("Serialization failed: {}", e))?;
-                
-                let signed_result = s.sign_event(&unsigned_json).await
-                    .map_err(|e| format!("Signing failed: {}", e))?;
-                
-                let signed_event: Event = serde_json::from_str(&signed_result.event_json)
-                    .map_err(|e| format!("Failed to parse signed event: {}", e))?;
-                
-                client.send_event(&signed_event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
-                
-                Ok::<String, String>(signed_event.id.to_hex())
-            } else if let Some(nsec) = FEED_NSEC.read().unwrap().as_ref() {
-                // Use local keys
-                let secret_key = SecretKey::parse(nsec)
-                    .map_err(|e| format!("Invalid nsec: {}", e))?;
-                let keys = Keys::new(secret_key);
-                
-                let event = EventBuilder::new(Kind::Reaction, &reaction_content)
-                    .tags(tags)
-                    .sign_with_keys(&keys)
-                    .map_err(|e| format!("Failed to sign: {}", e))?;
-                
-                client.send_event(&event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
-                
-                Ok(event.id.to_hex())
-            } else {
-                Err("No signing capability available".to_string())
+            // Custom emoji reactions carry their image (NIP-30); one seen on
+            // the note works too when it isn't in the user's own list
+            let emoji_url = emoji::lookup(&user_pk, &reaction_content)
+                .or_else(|| emoji::known_url(&reaction_content));
+            if let (Some(code), Some(url)) = (emoji::shortcode(&reaction_content), emoji_url) {
+                if let Ok(tag) = Tag::parse(["emoji", code, url.as_str()]) {
+                    tags.push(tag);
+                }
             }
+            
+            let builder = EventBuilder::new(Kind::Reaction, &reaction_content).tags(tags);
+            let label = if kind == OperationKind::Like { "Like" } else { "Reaction" };
+            let (event, queued) = send_or_queue(builder, user_pk, label).await?;
+            Ok::<(String, bool, usize), String>((event.id.to_hex(), queued, outbox::len(&user_pk)))
         });
         
+        self.as_mut().end_operation(&op, &result.as_ref().map(|(id, _, _)| id.clone()).map_err(|e| e.clone()));
+        
         match result {
-            Ok(event_id) => {
+            Ok((event_id, queued, count)) => {
                 tracing::info!("Reacted to note with {}, event: {}", reaction_content, event_id);
+                record_reaction_usage(user_pubkey.as_deref(), &reaction_content);
+                if let Some(stats) = mark_own_interaction(note_id_str, |stats| {
+                    stats["hasLiked"] = serde_json::Value::from(true);
+                    stats["myReaction"] = serde_json::Value::from(reaction_content.as_str());
+                }) {
+                    self.as_mut().note_stats_ready(&QString::from(note_id_str), &QString::from(&stats));
+                }
+                if queued {
+                    self.as_mut().set_outbox_count(count as i32);
+                    self.as_mut().outbox_changed(count as i32);
+                }
             }
             Err(e) => {
                 tracing::error!("Failed to react to note: {}", e);
@@ -1717,104 +2339,628 @@ impl qobject::FeedController {
         }
     }
     
-    /// Fetch reactions and zap stats for a specific note (async - non-blocking)
-    /// Returns cached data immediately if available, otherwise returns empty and fetches in background
-    /// Call get_cached_note_stats() to retrieve results after fetching
-    pub fn fetch_note_stats(self: Pin<&mut Self>, note_id: &QString) -> QString {
+    /// Author of a note in the feed or open thread, without asking relays
+    fn loaded_note_author(&self, note_id: &str) -> Option<PublicKey> {
+        self.notes.iter()
+            .chain(self.thread_notes.iter().map(|t| &t.note))
+            .find(|n| n.id == note_id)
+            .map(|n| n.pubkey.clone())
+            .or_else(|| NostrDbManager::global().ok()?.get_event(note_id).map(|e| e.pubkey))
+            .and_then(|pk| PublicKey::parse(&pk).ok())
+    }
+    
+    /// Report a note and hide it locally
+    pub fn report_note(mut self: Pin<&mut Self>, note_id: &QString, reason: &QString, report_type: &QString) {
         let note_id_str = note_id.to_string();
+        let Some(report_type) = ReportType::from_str(&report_type.to_string()) else {
+            self.as_mut().error_occurred(&QString::from("Unknown report type"));
+            return;
+        };
+        let Some(user) = self.user_pubkey.clone() else { return };
         
-        // Check cache first
-        {
-            let cache = NOTE_STATS_CACHE.read().unwrap();
-            if let Some(cached) = cache.get(&note_id_str) {
-                return QString::from(cached);
-            }
-        }
-        
-        // Check if already pending
-        {
-            let pending = PENDING_STATS.read().unwrap();
-            if pending.contains(&note_id_str) {
-                // Already fetching, return empty (loading state)
-                return QString::from(r#"{"reactions":{},"zapAmount":0,"zapCount":0,"loading":true}"#);
-            }
-        }
+        // Author is needed for the p tag; look in loaded notes before asking relays
+        let author = self.notes.iter()
+            .chain(self.thread_notes.iter().map(|t| &t.note))
+            .find(|n| n.id == note_id_str)
+            .map(|n| n.pubkey.clone());
         
-        // Mark as pending
-        {
-            let mut pending = PENDING_STATS.write().unwrap();
-            pending.insert(note_id_str.clone());
-        }
+        report::hide(&user, Some(&note_id_str), None);
+        self.as_mut().feed_updated();
         
-        // Spawn background fetch - don't block UI
-        let note_id_clone = note_id_str.clone();
-        std::thread::spawn(move || {
-            let result: Result<String, String> = FEED_RUNTIME.block_on(async {
-                let event_id = EventId::from_hex(&note_id_clone)
+        let reason = reason.to_string();
+        let op = Operation::new(OperationKind::Report, &note_id_str)
+            .with_args(&["note", &note_id_str, &reason, report_type.as_str()]);
+        self.as_mut().begin_operation(&op);
+        let qt_thread = self.qt_thread();
+        limits::spawn_limited(move || {
+            let result = FEED_RUNTIME.block_on(async {
+                let user_pk = PublicKey::parse(&user).map_err(|e| format!("Invalid pubkey: {}", e))?;
+                let event_id = EventId::from_hex(&note_id_str)
                     .map_err(|e| format!("Invalid event ID: {}", e))?;
+                let manager = shared_relay_manager().await?;
                 
-                // Get relay manager
-                let rm = RELAY_MANAGER.read().unwrap();
-                let manager = rm.as_ref().ok_or_else(|| "Not connected to relays".to_string())?;
-                
-                // Fetch stats for this note
-                let stats = manager.fetch_note_stats(&[event_id]).await?;
-                
-                // Get the stats for this specific note
-                if let Some((reactions, zap_amount, zap_count, reply_count, repost_count)) = stats.get(&note_id_clone) {
-                    Ok(serde_json::json!({
-                        "reactions": reactions,
-                        "zapAmount": zap_amount,
-                        "zapCount": zap_count,
-                        "replyCount": reply_count,
-                        "repostCount": repost_count
-                    }).to_string())
-                } else {
-                    Ok(serde_json::json!({
-                        "reactions": {},
-                        "zapAmount": 0,
-                        "zapCount": 0,
-                        "replyCount": 0,
-                        "repostCount": 0
-                    }).to_string())
-                }
+                let author = match author.and_then(|pk| PublicKey::parse(&pk).ok()) {
+                    Some(pk) => pk,
+                    None => manager.fetch_event(&event_id).await?
+                        .ok_or("Reported note not found")?
+                        .pubkey,
+                };
+                
+                let builder = report::note_report_builder(&event_id, &author, report_type, &reason)?;
+                let event = sign_event_builder(builder, user_pk).await?;
+                publish::send(manager.client(), &event).await?;
+                Ok::<(), String>(())
             });
             
-            // Cache the result
-            match result {
-                Ok(json) => {
-                    if let Ok(mut cache) = NOTE_STATS_CACHE.write() {
-                        cache.insert(note_id_clone.clone(), json);
-                    }
+            let _ = qt_thread.queue(move |mut qobject| {
+                let result = result.map(|_| String::new());
+                qobject.as_mut().end_operation(&op, &result);
+                let error = result.err().unwrap_or_default();
+                if !error.is_empty() {
+                    tracing::error!("Failed to report note: {}", error);
                 }
-                Err(e) => {
-                    tracing::warn!("Failed to fetch note stats for {}: {}", note_id_clone, e);
-                    // Cache empty result to prevent repeated failed fetches
-                    if let Ok(mut cache) = NOTE_STATS_CACHE.write() {
-                        cache.insert(note_id_clone.clone(), r#"{"reactions":{},"zapAmount":0,"zapCount":0,"replyCount":0,"repostCount":0}"#.to_string());
-                    }
+                qobject.as_mut().report_finished(&QString::from(&note_id_str), error.is_empty(), &QString::from(&error));
+            });
+        });
+    }
+    
+    /// Republish a note exactly as signed to the write relays (non-blocking)
+    pub fn broadcast_note(self: Pin<&mut Self>, note_id: &QString) {
+        let note_id_str = note_id.to_string();
+        let qt_thread = self.qt_thread();
+        limits::spawn_limited(move || {
+            let result = FEED_RUNTIME.block_on(async {
+                let event_id = EventId::from_hex(&note_id_str)
+                    .map_err(|e| format!("Invalid event ID: {}", e))?;
+                let manager = shared_relay_manager().await?;
+                let event = manager.fetch_event(&event_id).await?
+                    .ok_or("Note not found on any relay")?;
+                publish::send(manager.client(), &event).await
+            });
+            
+            let json = match result {
+                Ok(report) => {
+                    tracing::info!("Broadcast {} to {} of {} relays", note_id_str, report.accepted(), report.relays.len());
+                    report.to_json()
                 }
-            }
+                Err(e) => {
+                    tracing::error!("Failed to broadcast note: {}", e);
+                    serde_json::json!({ "error": e }).to_string()
+                }
+            };
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().broadcast_finished(&QString::from(&note_id_str), &QString::from(&json));
+            });
+        });
+    }
+    
+    /// Publish a highlight of part of a note (non-blocking)
+    pub fn highlight_note(mut self: Pin<&mut Self>, note_id: &QString, selected_text: &QString) {
+        let note_id_str = note_id.to_string();
+        let text = selected_text.to_string();
+        let Some(user) = self.user_pubkey.clone() else { return };
+        
+        let op = Operation::new(OperationKind::Post, &note_id_str)
+            .with_args(&["highlight", &note_id_str, &text]);
+        self.as_mut().begin_operation(&op);
+        let qt_thread = self.qt_thread();
+        limits::spawn_limited(move || {
+            let result = FEED_RUNTIME.block_on(async {
+                let user_pk = PublicKey::parse(&user).map_err(|e| format!("Invalid pubkey: {}", e))?;
+                let event_id = EventId::from_hex(&note_id_str)
+                    .map_err(|e| format!("Invalid event ID: {}", e))?;
+                let manager = shared_relay_manager().await?;
+                
+                // The source is needed for its author, address and surrounding context
+                let source = manager.fetch_event(&event_id).await?
+                    .ok_or("Highlighted note not found")?;
+                let builder = highlights::highlight_builder(&source, &text)?;
+                let event = sign_event_builder(builder, user_pk).await?;
+                publish::send(manager.client(), &event).await?;
+                Ok::<String, String>(event.id.to_hex())
+            });
             
-            // Remove from pending
-            if let Ok(mut pending) = PENDING_STATS.write() {
-                pending.remove(&note_id_clone);
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().end_operation(&op, &result);
+                match result {
+                    Ok(event_id) => {
+                        tracing::info!("Highlighted note {}, event: {}", note_id_str, event_id);
+                        qobject.as_mut().note_posted(&QString::from(&event_id));
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to highlight note: {}", e);
+                        qobject.as_mut().error_occurred(&QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+    
+    /// Report a user and hide their notes locally
+    pub fn report_user(mut self: Pin<&mut Self>, pubkey: &QString, reason: &QString, report_type: &QString) {
+        let pubkey_str = pubkey.to_string();
+        let Some(report_type) = ReportType::from_str(&report_type.to_string()) else {
+            self.as_mut().error_occurred(&QString::from("Unknown report type"));
+            return;
+        };
+        let Some(user) = self.user_pubkey.clone() else { return };
+        let Ok(reported_pk) = PublicKey::parse(&pubkey_str) else {
+            self.as_mut().error_occurred(&QString::from("Invalid pubkey"));
+            return;
+        };
+        let reported_hex = reported_pk.to_hex();
+        
+        report::hide(&user, None, Some(&reported_hex));
+        self.as_mut().feed_updated();
+        
+        let reason = reason.to_string();
+        let op = Operation::new(OperationKind::Report, &reported_hex)
+            .with_args(&["user", &reported_hex, &reason, report_type.as_str()]);
+        self.as_mut().begin_operation(&op);
+        let qt_thread = self.qt_thread();
+        limits::spawn_limited(move || {
+            let result = FEED_RUNTIME.block_on(async {
+                let user_pk = PublicKey::parse(&user).map_err(|e| format!("Invalid pubkey: {}", e))?;
+                let manager = shared_relay_manager().await?;
+                
+                let builder = report::user_report_builder(&reported_pk, report_type, &reason)?;
+                let event = sign_event_builder(builder, user_pk).await?;
+                publish::send(manager.client(), &event).await?;
+                Ok::<(), String>(())
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                let result = result.map(|_| String::new());
+                qobject.as_mut().end_operation(&op, &result);
+                let error = result.err().unwrap_or_default();
+                if !error.is_empty() {
+                    tracing::error!("Failed to report user: {}", error);
+                }
+                qobject.as_mut().report_finished(&QString::from(&reported_hex), error.is_empty(), &QString::from(&error));
+            });
+        });
+    }
+    
+    /// The user's reactions, most recently used first
+    pub fn recent_reactions(&self, limit: i32) -> QString {
+        let usage = match (self.user_pubkey.as_ref(), NostrDbManager::global()) {
+            (Some(user), Ok(db)) => db.recent_reactions(user, limit.max(0) as usize),
+            _ => Vec::new(),
+        };
+        QString::from(&serde_json::to_string(&usage).unwrap_or_else(|_| "[]".to_string()))
+    }
+    
+    /// The user's reactions, most used first
+    pub fn frequent_reactions(&self, limit: i32) -> QString {
+        let usage = match (self.user_pubkey.as_ref(), NostrDbManager::global()) {
+            (Some(user), Ok(db)) => db.frequent_reactions(user, limit.max(0) as usize),
+            _ => Vec::new(),
+        };
+        QString::from(&serde_json::to_string(&usage).unwrap_or_else(|_| "[]".to_string()))
+    }
+    
+    /// Custom emoji from the user's emoji list, refreshed in the background
+    pub fn get_custom_emoji(self: Pin<&mut Self>) -> QString {
+        let Some(user) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) else {
+            return QString::from("[]");
+        };
+        let stored = emoji::user_emoji(&user);
+        let stored_json = serde_json::to_string(&stored).unwrap_or_else(|_| "[]".to_string());
+        
+        if !EMOJI_REFRESHING.swap(true, std::sync::atomic::Ordering::SeqCst) {
+            let qt_thread = self.qt_thread();
+            limits::spawn_limited(move || {
+                let result = FEED_RUNTIME.block_on(async {
+                    let manager = shared_relay_manager().await?;
+                    emoji::refresh_user_emoji(&manager, &user).await
+                });
+                EMOJI_REFRESHING.store(false, std::sync::atomic::Ordering::SeqCst);
+                
+                if let Err(e) = result {
+                    tracing::warn!("Failed to refresh custom emoji: {}", e);
+                    return;
+                }
+                let fresh = emoji::user_emoji(&user);
+                if fresh != stored {
+                    let json = serde_json::to_string(&fresh).unwrap_or_else(|_| "[]".to_string());
+                    let _ = qt_thread.queue(move |mut qobject| {
+                        qobject.as_mut().custom_emoji_updated(&QString::from(&json));
+                    });
+                }
+            });
+        }
+        
+        QString::from(&stored_json)
+    }
+    
+    /// Run a failed operation again
+    pub fn retry_operation(mut self: Pin<&mut Self>, op_id: &QString) -> bool {
+        let Some(op) = operations::take_failed(&op_id.to_string()) else {
+            return false;
+        };
+        tracing::info!("Retrying {}", op.id());
+        let arg = |i: usize| QString::from(op.arg(i));
+        
+        match op.kind {
+            OperationKind::Like => self.as_mut().like_note(&arg(0)),
+            OperationKind::React => self.as_mut().react_to_note(&arg(0), &arg(1)),
+            OperationKind::Repost => self.as_mut().repost_note(&arg(0)),
+            OperationKind::Reply => self.as_mut().reply_to_note(&arg(0), &arg(1), &arg(2)),
+            OperationKind::Zap => {
+                let amount = op.arg(1).parse().unwrap_or(0);
+                self.as_mut().zap_note(&arg(0), amount, &arg(2));
+            }
+            OperationKind::Post => match op.arg(0) {
+                "picture" => self.as_mut().post_picture(&arg(3), &arg(1), &arg(4), &arg(2)),
+                "highlight" => self.as_mut().highlight_note(&arg(1), &arg(2)),
+                "poll" => {
+                    let duration = op.arg(3).parse().unwrap_or(0);
+                    self.as_mut().create_poll(&arg(1), &arg(2), duration, op.arg(4) == "1");
+                }
+                "media" => self.as_mut().post_note_with_media(&arg(1), &arg(3), &arg(2)),
+                _ => self.as_mut().post_note(&arg(1), &arg(2)),
+            },
+            OperationKind::Report => match op.arg(0) {
+                "user" => self.as_mut().report_user(&arg(1), &arg(2), &arg(3)),
+                _ => self.as_mut().report_note(&arg(1), &arg(2), &arg(3)),
+            },
+            OperationKind::FollowSet => match op.arg(0) {
+                "member" => self.as_mut().set_follow_set_member(&arg(1), &arg(2), op.arg(3) == "1"),
+                "delete" => self.as_mut().delete_follow_set(&arg(1)),
+                _ => {
+                    self.as_mut().save_follow_set(&arg(1), &arg(2), &arg(3), &arg(4));
+                }
+            },
+            OperationKind::Interests => self.as_mut().follow_hashtag(&arg(0), op.arg(1) == "1"),
+            _ => return false,
+        }
+        true
+    }
+    
+    /// Failed operations that can be retried from this controller
+    pub fn get_failed_operations(&self) -> QString {
+        let failed = operations::failed_list(&[
+            OperationKind::Like,
+            OperationKind::React,
+            OperationKind::Repost,
+            OperationKind::Reply,
+            OperationKind::Post,
+            OperationKind::Zap,
+            OperationKind::Report,
+            OperationKind::FollowSet,
+            OperationKind::Interests,
+        ]);
+        QString::from(&serde_json::to_string(&failed).unwrap_or_else(|_| "[]".to_string()))
+    }
+    
+    /// Announce an operation on operation_started
+    fn begin_operation(mut self: Pin<&mut Self>, op: &Operation) {
+        operations::started(op);
+        self.as_mut().operation_started(&QString::from(&op.id()), &QString::from(op.kind.as_str()));
+    }
+    
+    /// Report an operation's outcome on operation_succeeded / operation_failed
+    fn end_operation(mut self: Pin<&mut Self>, op: &Operation, result: &Result<String, String>) {
+        let id = QString::from(&op.id());
+        let kind = QString::from(op.kind.as_str());
+        match result {
+            Ok(value) => {
+                operations::succeeded(op);
+                self.as_mut().operation_succeeded(&id, &kind, &QString::from(value));
+            }
+            Err(e) => {
+                operations::failed(op, e);
+                self.as_mut().operation_failed(&id, &kind, &QString::from(e));
+            }
+        }
+    }
+    
+    /// The user's follow sets, refreshed in the background
+    pub fn get_follow_sets(self: Pin<&mut Self>) -> QString {
+        let Some(user) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) else {
+            return QString::from("[]");
+        };
+        let stored = lists::user_sets(&user);
+        
+        if !FOLLOW_SETS_REFRESHING.swap(true, std::sync::atomic::Ordering::SeqCst) {
+            let qt_thread = self.qt_thread();
+            let stored = stored.clone();
+            limits::spawn_limited(move || {
+                let result = FEED_RUNTIME.block_on(async {
+                    let manager = shared_relay_manager().await?;
+                    lists::refresh(&manager, &user).await
+                });
+                FOLLOW_SETS_REFRESHING.store(false, std::sync::atomic::Ordering::SeqCst);
+                
+                if let Err(e) = result {
+                    tracing::warn!("Failed to refresh follow sets: {}", e);
+                    return;
+                }
+                let fresh = lists::user_sets(&user);
+                if fresh != stored {
+                    let json = follow_sets_json(&fresh);
+                    let _ = qt_thread.queue(move |mut qobject| {
+                        qobject.as_mut().follow_sets_updated(&QString::from(&json));
+                    });
+                }
+            });
+        }
+        
+        QString::from(&follow_sets_json(&stored))
+    }
+    
+    /// Create or replace a follow set and publish it
+    pub fn save_follow_set(mut self: Pin<&mut Self>, identifier: &QString, title: &QString, description: &QString, members_json: &QString) -> QString {
+        let Some(user) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) else {
+            return QString::from("");
+        };
+        let title = title.to_string().trim().to_string();
+        let members: Vec<String> = match serde_json::from_str(&members_json.to_string()) {
+            Ok(m) => m,
+            Err(e) => {
+                self.as_mut().error_occurred(&QString::from(&format!("Invalid list members: {}", e)));
+                return QString::from("");
+            }
+        };
+        let mut pubkeys = Vec::new();
+        for member in members.iter().map(|m| m.trim()).filter(|m| !m.is_empty()) {
+            match PublicKey::parse(member) {
+                Ok(pk) if !pubkeys.contains(&pk.to_hex()) => pubkeys.push(pk.to_hex()),
+                Ok(_) => {}
+                Err(_) => {
+                    self.as_mut().error_occurred(&QString::from(&format!("Invalid pubkey in list: {}", member)));
+                    return QString::from("");
+                }
             }
+        }
+        
+        let identifier = match identifier.to_string().trim() {
+            "" => lists::new_identifier(&title),
+            id => id.to_string(),
+        };
+        let image = lists::get(&user, &identifier).and_then(|s| s.image);
+        let set = FollowSet {
+            identifier: identifier.clone(),
+            title,
+            description: description.to_string().trim().to_string(),
+            image,
+            pubkeys,
+            created_at: Timestamp::now().as_secs() as i64,
+        };
+        
+        let op = Operation::new(OperationKind::FollowSet, &identifier)
+            .with_args(&["save", &identifier, &set.title, &set.description, &members_json.to_string()]);
+        self.publish_follow_set_event(user, op, set.to_event_builder());
+        QString::from(&identifier)
+    }
+    
+    /// Add or remove one user from a follow set and republish it
+    pub fn set_follow_set_member(mut self: Pin<&mut Self>, identifier: &QString, pubkey: &QString, member: bool) {
+        let Some(user) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) else { return };
+        let identifier = identifier.to_string();
+        let Ok(pk) = PublicKey::parse(&pubkey.to_string()) else {
+            self.as_mut().error_occurred(&QString::from("Invalid pubkey"));
+            return;
+        };
+        let Some(mut set) = lists::get(&user, &identifier) else {
+            self.as_mut().error_occurred(&QString::from("List not found"));
+            return;
+        };
+        
+        let hex = pk.to_hex();
+        let present = set.pubkeys.contains(&hex);
+        if present == member {
+            return;
+        }
+        if member {
+            set.pubkeys.push(hex);
+        } else {
+            set.pubkeys.retain(|p| *p != hex);
+        }
+        
+        let op = Operation::new(OperationKind::FollowSet, &identifier)
+            .with_args(&["member", &identifier, &pk.to_hex(), if member { "1" } else { "0" }]);
+        self.publish_follow_set_event(user, op, set.to_event_builder());
+    }
+    
+    /// Delete a follow set
+    pub fn delete_follow_set(self: Pin<&mut Self>, identifier: &QString) {
+        let Some(user) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) else { return };
+        let identifier = identifier.to_string();
+        let builder = lists::deletion_builder(&user, &identifier);
+        let op = Operation::new(OperationKind::FollowSet, &identifier).with_args(&["delete", &identifier]);
+        self.publish_follow_set_event(user, op, builder);
+    }
+    
+    /// Sign and publish a follow set or its deletion, storing it once signed
+    fn publish_follow_set_event(mut self: Pin<&mut Self>, user: PublicKey, op: Operation, builder: EventBuilder) {
+        let identifier = op.target.clone();
+        self.as_mut().begin_operation(&op);
+        
+        // Members changed, so the list's cached timeline is stale
+        FEED_CACHE.remove(&format!("{}{}", lists::LIST_FEED_PREFIX, identifier));
+        
+        let qt_thread = self.qt_thread();
+        limits::spawn_limited(move || {
+            let result = FEED_RUNTIME.block_on(async {
+                let event = sign_event_builder(builder, user).await?;
+                lists::store(&event)?;
+                let manager = shared_relay_manager().await?;
+                publish::send(manager.client(), &event).await?;
+                Ok::<(), String>(())
+            });
+            
+            let sets_json = follow_sets_json(&lists::user_sets(&user));
+            let _ = qt_thread.queue(move |mut qobject| {
+                let result = result.map(|_| String::new());
+                qobject.as_mut().end_operation(&op, &result);
+                let error = result.err().unwrap_or_default();
+                if !error.is_empty() {
+                    tracing::error!("Failed to publish follow set {}: {}", identifier, error);
+                }
+                qobject.as_mut().follow_sets_updated(&QString::from(&sets_json));
+                qobject.as_mut().follow_set_saved(&QString::from(&identifier), error.is_empty(), &QString::from(&error));
+            });
+        });
+    }
+    
+    /// Followed hashtags, refreshed in the background
+    pub fn get_followed_hashtags(self: Pin<&mut Self>) -> QString {
+        let Some(user) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) else {
+            return QString::from("[]");
+        };
+        let stored = interests::user_hashtags(&user);
+        
+        if !HASHTAGS_REFRESHING.swap(true, std::sync::atomic::Ordering::SeqCst) {
+            let qt_thread = self.qt_thread();
+            let stored = stored.clone();
+            limits::spawn_limited(move || {
+                let result = FEED_RUNTIME.block_on(async {
+                    let manager = shared_relay_manager().await?;
+                    interests::refresh(&manager, &user).await
+                });
+                HASHTAGS_REFRESHING.store(false, std::sync::atomic::Ordering::SeqCst);
+                
+                match result {
+                    Ok(fresh) if fresh != stored => {
+                        FEED_CACHE.remove(interests::HASHTAG_FEED);
+                        let json = serde_json::to_string(&fresh).unwrap_or_else(|_| "[]".to_string());
+                        let _ = qt_thread.queue(move |mut qobject| {
+                            qobject.as_mut().followed_hashtags_updated(&QString::from(&json));
+                        });
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("Failed to refresh followed hashtags: {}", e),
+                }
+            });
+        }
+        
+        QString::from(&serde_json::to_string(&stored).unwrap_or_else(|_| "[]".to_string()))
+    }
+    
+    /// Add or remove a hashtag from the interest list and publish it
+    pub fn follow_hashtag(mut self: Pin<&mut Self>, hashtag: &QString, follow: bool) {
+        let Some(user) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) else { return };
+        let Some(tag) = interests::normalize(&hashtag.to_string()) else {
+            self.as_mut().error_occurred(&QString::from("Invalid hashtag"));
+            return;
+        };
+        
+        let previous = interests::latest(&user);
+        let mut tags = previous.as_ref().map(interests::hashtags).unwrap_or_default();
+        if tags.contains(&tag) == follow {
+            return;
+        }
+        if follow {
+            tags.push(tag.clone());
+        } else {
+            tags.retain(|t| *t != tag);
+        }
+        let builder = interests::list_builder(previous.as_ref(), &tags);
+        
+        let op = Operation::new(OperationKind::Interests, &tag)
+            .with_args(&[&tag, if follow { "1" } else { "0" }]);
+        self.as_mut().begin_operation(&op);
+        
+        // The topic timeline is stale either way
+        FEED_CACHE.remove(interests::HASHTAG_FEED);
+        
+        let qt_thread = self.qt_thread();
+        limits::spawn_limited(move || {
+            let result = FEED_RUNTIME.block_on(async {
+                let event = sign_event_builder(builder, user).await?;
+                interests::store(&event)?;
+                let manager = shared_relay_manager().await?;
+                publish::send(manager.client(), &event).await?;
+                Ok::<(), String>(())
+            });
+            
+            let json = serde_json::to_string(&interests::user_hashtags(&user)).unwrap_or_else(|_| "[]".to_string());
+            let _ = qt_thread.queue(move |mut qobject| {
+                let result = result.map(|_| String::new());
+                qobject.as_mut().end_operation(&op, &result);
+                if let Err(e) = &result {
+                    tracing::error!("Failed to update followed hashtags: {}", e);
+                }
+                qobject.as_mut().followed_hashtags_updated(&QString::from(&json));
+            });
         });
+    }
+    
+    /// Fetch reactions and zap stats for a specific note (async - non-blocking)
+    /// Returns cached data immediately if available, otherwise returns a loading state
+    /// and queues the note; notes queued within STATS_BATCH_DELAY share one relay query
+    /// and each result is emitted through note_stats_ready
+    pub fn fetch_note_stats(self: Pin<&mut Self>, note_id: &QString) -> QString {
+        let note_id_str = note_id.to_string();
+        
+        // Check cache first
+        let cached = NOTE_STATS_CACHE.get(&note_id_str);
+        cache_stats::record(cache_stats::NOTE_STATS, cached.is_some());
+        if let Some(cached) = cached {
+            return QString::from(&render_note_stats(&cached));
+        }
+        
+        // Mark as pending; if it already was, its result is on the way
+        if !PENDING_STATS.write().unwrap().insert(note_id_str.clone()) {
+            return QString::from(r#"{"reactions":{},"zapAmount":0,"zapCount":0,"loading":true}"#);
+        }
+        
+        // The first note of a batch starts the flush; later ones just join it
+        let start_batch = {
+            let mut queue = STATS_QUEUE.lock().unwrap();
+            queue.push(note_id_str);
+            queue.len() == 1
+        };
+        if start_batch {
+            let qt_thread = self.qt_thread();
+            let me = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok());
+            limits::spawn_limited(move || {
+                std::thread::sleep(STATS_BATCH_DELAY);
+                let note_ids = std::mem::take(&mut *STATS_QUEUE.lock().unwrap());
+                fetch_stats_batch(&qt_thread, note_ids, me);
+            });
+        }
         
         // Return loading state while fetching
         QString::from(r#"{"reactions":{},"zapAmount":0,"zapCount":0,"loading":true}"#)
     }
     
+    /// Prefetch stats for the loaded notes first_index..=last_index in one round trip
+    /// Notes already cached or being fetched are skipped; results arrive via note_stats_ready
+    pub fn fetch_stats_for_range(self: Pin<&mut Self>, first_index: i32, last_index: i32) {
+        let count = self.notes.len() as i32;
+        let first = first_index.max(0);
+        let last = last_index.min(count - 1);
+        if first > last {
+            return;
+        }
+        
+        let note_ids: Vec<String> = {
+            let mut pending = PENDING_STATS.write().unwrap();
+            self.notes[first as usize..=last as usize].iter()
+                .map(|note| note.id.clone())
+                .filter(|id| !NOTE_STATS_CACHE.contains(id) && pending.insert(id.clone()))
+                .collect()
+        };
+        if note_ids.is_empty() {
+            return;
+        }
+        tracing::debug!("Prefetching stats for {} notes ({}..={})", note_ids.len(), first, last);
+        
+        let qt_thread = self.qt_thread();
+        let me = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok());
+        limits::spawn_limited(move || fetch_stats_batch(&qt_thread, note_ids, me));
+    }
+    
     /// Get cached note stats (non-blocking)
     /// Returns cached stats or empty if not yet fetched
     pub fn get_cached_note_stats(&self, note_id: &QString) -> QString {
         let note_id_str = note_id.to_string();
         
         // Check cache
-        let cache = NOTE_STATS_CACHE.read().unwrap();
-        if let Some(cached) = cache.get(&note_id_str) {
-            return QString::from(cached);
+        if let Some(cached) = NOTE_STATS_CACHE.get(&note_id_str) {
+            return QString::from(&render_note_stats(&cached));
         }
         
         // Check if pending
@@ -1830,6 +2976,7 @@ impl qobject::FeedController {
     /// Refresh stats for multiple notes at once (for visible notes)
     /// Takes a JSON array of note IDs, fetches fresh stats, and emits note_stats_ready for each
     pub fn refresh_visible_stats(self: Pin<&mut Self>, note_ids_json: &QString) {
+        refresh::mark_ran(RefreshTask::Stats);
         let note_ids_str = note_ids_json.to_string();
         
         // Parse the JSON array
@@ -1850,75 +2997,22 @@ impl qobject::FeedController {
         
         // Get qt_thread for emitting signals
         let qt_thread = self.qt_thread();
+        let me = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok());
         
         // Spawn background fetch
-        std::thread::spawn(move || {
-            let result: Result<Vec<(String, String)>, String> = FEED_RUNTIME.block_on(async {
-                // Parse event IDs
-                let event_ids: Vec<EventId> = note_ids.iter()
-                    .filter_map(|id| EventId::from_hex(id).ok())
-                    .collect();
-                
-                if event_ids.is_empty() {
-                    return Ok(vec![]);
-                }
-                
-                // Get relay manager
-                let rm = RELAY_MANAGER.read().unwrap();
-                let manager = rm.as_ref().ok_or_else(|| "Not connected to relays".to_string())?;
-                
-                // Fetch stats for all notes at once
-                let stats = manager.fetch_note_stats(&event_ids).await?;
-                
-                // Build results
-                let mut results = Vec::new();
-                for (note_id, (reactions, zap_amount, zap_count, reply_count, repost_count)) in stats {
-                    let json = serde_json::json!({
-                        "reactions": reactions,
-                        "zapAmount": zap_amount,
-                        "zapCount": zap_count,
-                        "replyCount": reply_count,
-                        "repostCount": repost_count
-                    }).to_string();
-                    
-                    // Update cache
-                    if let Ok(mut cache) = NOTE_STATS_CACHE.write() {
-                        cache.insert(note_id.clone(), json.clone());
-                    }
-                    
-                    results.push((note_id, json));
-                }
-                
-                Ok(results)
-            });
-            
-            // Emit signals for each updated note
-            match result {
-                Ok(stats_list) => {
-                    for (note_id, stats_json) in stats_list {
-                        let note_id_clone = note_id.clone();
-                        let stats_json_clone = stats_json.clone();
-                        let _ = qt_thread.queue(move |mut qobject| {
-                            qobject.as_mut().note_stats_ready(
-                                &QString::from(&note_id_clone),
-                                &QString::from(&stats_json_clone)
-                            );
-                        });
-                    }
-                }
-                Err(e) => {
-                    tracing::warn!("Failed to refresh visible stats: {}", e);
-                }
-            }
-        });
+        limits::spawn_limited(move || fetch_stats_batch(&qt_thread, note_ids, me));
     }
     
-    /// Repost a note (kind 6)
+    /// Repost a note (kind 6); kept in the outbox while offline
     pub fn repost_note(mut self: Pin<&mut Self>, note_id: &QString) {
         let note_id_str = note_id.to_string();
         tracing::info!("Repost note: {}", note_id_str);
         
         let user_pubkey = self.user_pubkey.clone();
+        let author = self.loaded_note_author(&note_id_str);
+        
+        let op = Operation::new(OperationKind::Repost, &note_id_str).with_args(&[&note_id_str]);
+        self.as_mut().begin_operation(&op);
         
         let result = FEED_RUNTIME.block_on(async {
             let event_id = EventId::from_hex(&note_id_str)
@@ -1928,66 +3022,45 @@ impl qobject::FeedController {
                 .and_then(|pk| PublicKey::parse(pk).ok())
                 .ok_or("User not initialized")?;
             
-            // Get relay manager
-            let rm = RELAY_MANAGER.read().unwrap();
-            let manager = rm.as_ref().ok_or("Not connected to relays")?;
-            let client = manager.client();
-            
-            // Fetch the original event to include in repost
-            let original_event = manager.fetch_event(&event_id).await?
+            // Include the original event JSON as content when relays have it;
+            // offline, NIP-18 allows an empty content with just the tags
+            let original_event = if relays_online().await {
+                let manager = shared_relay_manager().await?;
+                manager.fetch_event(&event_id).await?
+            } else {
+                None
+            };
+            let author = original_event.as_ref().map(|e| e.pubkey).or(author)
                 .ok_or("Original event not found")?;
+            let original_json = original_event.as_ref()
+                .map(|e| e.as_json())
+                .unwrap_or_default();
             
             // Build repost event (kind 6)
             let tags = vec![
                 Tag::event(event_id),
-                Tag::public_key(original_event.pubkey),
+                Tag::public_key(author),
             ];
             
-            // Include original event JSON as content
-            let original_json = serde_json::to_string(&original_event)
-                .unwrap_or_default();
-            
-            let signer = FEED_SIGNER.lock().await;
-            if let Some(s) = signer.as_ref() {
-                let unsigned = EventBuilder::new(Kind::Repost, &original_json)
-                    .tags(tags)
-                    .build(user_pk);
-                
-                let unsigned_json = serde_json::to_string(&unsigned)
-                    .map_err(|e| format!("Serialization failed: {}", e))?;
-                
-                let signed_result = s.sign_event(&unsigned_json).await
-                    .map_err(|e| format!("Signing failed: {}", e))?;
-                
-                let signed_event: Event = serde_json::from_str(&signed_result.event_json)
-                    .map_err(|e| format!("Failed to parse signed event: {}", e))?;
-                
-                client.send_event(&signed_event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
-                
-                Ok::<String, String>(signed_event.id.to_hex())
-            } else if let Some(nsec) = FEED_NSEC.read().unwrap().as_ref() {
-                let secret_key = SecretKey::parse(nsec)
-                    .map_err(|e| format!("Invalid nsec: {}", e))?;
-                let keys = Keys::new(secret_key);
-                
-                let event = EventBuilder::new(Kind::Repost, &original_json)
-                    .tags(tags)
-                    .sign_with_keys(&keys)
-                    .map_err(|e| format!("Failed to sign: {}", e))?;
-                
-                client.send_event(&event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
-                
-                Ok(event.id.to_hex())
-            } else {
-                Err("No signing capability available".to_string())
-            }
+            let builder = EventBuilder::new(Kind::Repost, &original_json).tags(tags);
+            let (event, queued) = send_or_queue(builder, user_pk, "Repost").await?;
+            Ok::<(String, bool, usize), String>((event.id.to_hex(), queued, outbox::len(&user_pk)))
         });
         
+        self.as_mut().end_operation(&op, &result.as_ref().map(|(id, _, _)| id.clone()).map_err(|e| e.clone()));
+        
         match result {
-            Ok(event_id) => {
+            Ok((event_id, queued, count)) => {
                 tracing::info!("Reposted note, event: {}", event_id);
+                if let Some(stats) = mark_own_interaction(&note_id_str, |stats| {
+                    stats["hasReposted"] = serde_json::Value::from(true);
+                }) {
+                    self.as_mut().note_stats_ready(&QString::from(&note_id_str), &QString::from(&stats));
+                }
+                if queued {
+                    self.as_mut().set_outbox_count(count as i32);
+                    self.as_mut().outbox_changed(count as i32);
+                }
             }
             Err(e) => {
                 tracing::error!("Failed to repost note: {}", e);
@@ -1997,12 +3070,18 @@ impl qobject::FeedController {
     }
     
     /// Reply to a note
-    pub fn reply_to_note(mut self: Pin<&mut Self>, note_id: &QString, content: &QString) {
+    pub fn reply_to_note(mut self: Pin<&mut Self>, note_id: &QString, content: &QString, content_warning: &QString) {
         let note_id_str = note_id.to_string();
         let content_str = content.to_string();
+        let content_warning = content_warning.to_string();
         tracing::info!("Reply to {}: {}", note_id_str, &content_str[..content_str.len().min(50)]);
         
         let user_pubkey = self.user_pubkey.clone();
+        let protected = self.post_protected;
+        
+        let op = Operation::new(OperationKind::Reply, &note_id_str)
+            .with_args(&[&note_id_str, &content_str, &content_warning]);
+        self.as_mut().begin_operation(&op);
         
         let result = FEED_RUNTIME.block_on(async {
             let event_id = EventId::from_hex(&note_id_str)
@@ -2044,9 +3123,27 @@ impl qobject::FeedController {
                 }
             }
             
+            // Hashtags and mentions; the replied-to author is already tagged
+            let prepared = compose::prepare_note(&content_str);
+            for tag in prepared.tags {
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+            
+            if protected {
+                tags.push(Tag::protected());
+            }
+            
+            if let Some(tag) = content_warning_tag(&content_warning) {
+                tags.push(tag);
+            }
+            
+            tags.extend(handlers::client_tag());
+            
             let signer = FEED_SIGNER.lock().await;
             if let Some(s) = signer.as_ref() {
-                let unsigned = EventBuilder::text_note(&content_str)
+                let unsigned = EventBuilder::text_note(&prepared.content)
                     .tags(tags)
                     .build(user_pk);
                 
@@ -2059,8 +3156,7 @@ impl qobject::FeedController {
                 let signed_event: Event = serde_json::from_str(&signed_result.event_json)
                     .map_err(|e| format!("Failed to parse signed event: {}", e))?;
                 
-                client.send_event(&signed_event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
+                publish::send(client, &signed_event).await?;
                 
                 Ok::<String, String>(signed_event.id.to_hex())
             } else if let Some(nsec) = FEED_NSEC.read().unwrap().as_ref() {
@@ -2073,8 +3169,7 @@ impl qobject::FeedController {
                     .sign_with_keys(&keys)
                     .map_err(|e| format!("Failed to sign: {}", e))?;
                 
-                client.send_event(&event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
+                publish::send(client, &event).await?;
                 
                 Ok(event.id.to_hex())
             } else {
@@ -2082,6 +3177,8 @@ impl qobject::FeedController {
             }
         });
         
+        self.as_mut().end_operation(&op, &result);
+        
         match result {
             Ok(event_id) => {
                 tracing::info!("Posted reply, event: {}", event_id);
@@ -2094,103 +3191,42 @@ impl qobject::FeedController {
         }
     }
     
-    /// Zap a note
+    /// Zap a note; kept in the outbox while offline and paid once relays are reachable
     pub fn zap_note(mut self: Pin<&mut Self>, note_id: &QString, amount_sats: i64, comment: &QString) {
         let note_id_str = note_id.to_string();
         let comment_str = comment.to_string();
         tracing::info!("Zapping note {} with {} sats", note_id_str, amount_sats);
         
-        // Get user's signing keys
-        let nsec_opt = FEED_NSEC.read().unwrap().clone();
-        
-        let result = FEED_RUNTIME.block_on(async {
-            // Check if NWC is connected
-            let mut nwc = GLOBAL_NWC_MANAGER.lock().await;
-            if !nwc.is_connected() {
-                return Err("NWC wallet not connected. Please connect your wallet in Settings.".to_string());
-            }
-            
-            // Get signing keys
-            let keys = match nsec_opt.as_ref() {
-                Some(nsec) => {
-                    let secret_key = SecretKey::parse(nsec)
-                        .map_err(|e| format!("Invalid nsec: {}", e))?;
-                    Keys::new(secret_key)
+        let user_pk = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok());
+        if let Some(user_pk) = user_pk.filter(|_| !FEED_RUNTIME.block_on(relays_online())) {
+            let zap = PendingZap { note_id: note_id_str.clone(), amount_sats: amount_sats as u64, comment: comment_str };
+            match outbox::add(&user_pk, OutboxItem::Zap(zap)) {
+                Ok(entry) => {
+                    tracing::info!("Offline: zap queued as outbox entry {}", entry.id);
+                    let count = outbox::len(&user_pk) as i32;
+                    self.as_mut().set_outbox_count(count);
+                    self.as_mut().outbox_changed(count);
                 }
-                None => {
-                    return Err("No signing keys available".to_string());
-                }
-            };
-            
-            // Get relay manager for fetching note author
-            let rm = RELAY_MANAGER.read().unwrap();
-            let manager = rm.as_ref().ok_or("Not connected to relays")?;
-            let client = manager.client();
-            
-            // Parse note ID
-            let event_id = EventId::parse(&note_id_str)
-                .or_else(|_| EventId::from_bech32(&note_id_str))
-                .map_err(|e| format!("Invalid note ID: {}", e))?;
-            
-            // Fetch the note to get author's pubkey and find their lud16
-            let note_filter = Filter::new()
-                .id(event_id.clone())
-                .limit(1);
-            
-            let note_events = client.fetch_events(note_filter, std::time::Duration::from_secs(10)).await
-                .map_err(|e| format!("Failed to fetch note: {}", e))?;
-            
-            let note_event = note_events.into_iter().next()
-                .ok_or("Note not found")?;
-            
-            let author_pubkey = note_event.pubkey.clone();
-            
-            // Fetch author's profile to get their lightning address
-            let profile_filter = Filter::new()
-                .kind(Kind::Metadata)
-                .author(author_pubkey.clone())
-                .limit(1);
-            
-            let profile_events = client.fetch_events(profile_filter, std::time::Duration::from_secs(10)).await
-                .map_err(|e| format!("Failed to fetch author profile: {}", e))?;
-            
-            let profile_event = profile_events.into_iter().next()
-                .ok_or("Author profile not found")?;
-            
-            // Parse metadata to get lud16
-            let metadata: Metadata = serde_json::from_str(&profile_event.content)
-                .map_err(|e| format!("Failed to parse profile metadata: {}", e))?;
-            
-            let lud16 = metadata.lud16
-                .ok_or("Author doesn't have a lightning address (lud16)")?;
-            
-            if lud16.is_empty() {
-                return Err("Author's lightning address is empty".to_string());
+                Err(e) => self.as_mut().zap_failed(&QString::from(&note_id_str), &QString::from(&e)),
             }
-            
-            // Get relay URLs for zap request (use default relays)
-            let relays: Vec<String> = crate::nostr::relay::DEFAULT_RELAYS.iter()
-                .take(3) // Include up to 3 relays
-                .map(|s| s.to_string())
-                .collect();
-            
-            // Perform the zap
-            zap::zap(
-                &mut *nwc,
-                &keys,
-                &author_pubkey,
-                &lud16,
-                Some(&event_id),
-                amount_sats as u64,
-                &comment_str,
-                &relays,
-            ).await
-        });
+            return;
+        }
+        
+        let op = Operation::new(OperationKind::Zap, &note_id_str)
+            .with_args(&[&note_id_str, &amount_sats.to_string(), &comment_str]);
+        self.as_mut().begin_operation(&op);
+        
+        let result = FEED_RUNTIME.block_on(pay_zap(&note_id_str, amount_sats as u64, &comment_str));
+        
+        self.as_mut().end_operation(&op, &result.as_ref().map(|_| String::new()).map_err(|e| e.clone()));
         
         match result {
             Ok(preimage) => {
                 tracing::info!("Zap successful! Preimage: {}", &preimage[..16.min(preimage.len())]);
                 self.as_mut().zap_success(&QString::from(&note_id_str), amount_sats);
+                if let Some(stats) = mark_zapped(&note_id_str, amount_sats as u64) {
+                    self.as_mut().note_stats_ready(&QString::from(&note_id_str), &QString::from(&stats));
+                }
             }
             Err(e) => {
                 tracing::error!("Zap failed: {}", e);
@@ -2200,11 +3236,17 @@ impl qobject::FeedController {
     }
     
     /// Post a new note
-    pub fn post_note(mut self: Pin<&mut Self>, content: &QString) {
+    pub fn post_note(mut self: Pin<&mut Self>, content: &QString, content_warning: &QString) {
         let content_str = content.to_string();
+        let content_warning = content_warning.to_string();
         tracing::info!("Post note: {}", &content_str[..content_str.len().min(50)]);
         
         let user_pubkey = self.user_pubkey.clone();
+        let protected = self.post_protected;
+        
+        let op = Operation::new(OperationKind::Post, &operations::content_key(&content_str))
+            .with_args(&["note", &content_str, &content_warning]);
+        self.as_mut().begin_operation(&op);
         
         let result = FEED_RUNTIME.block_on(async {
             let user_pk = user_pubkey.as_ref()
@@ -2216,10 +3258,23 @@ impl qobject::FeedController {
             let manager = rm.as_ref().ok_or("Not connected to relays")?;
             let client = manager.client();
             
+            // NIP-27: @name -> nostr: URIs, plus t/p tags for hashtags and mentions
+            let prepared = compose::prepare_note(&content_str);
+            let mut builder = EventBuilder::text_note(&prepared.content)
+                .tags(prepared.tags);
+            if protected {
+                // NIP-70: only the author may publish this event to a relay
+                builder = builder.tag(Tag::protected());
+            }
+            if let Some(tag) = content_warning_tag(&content_warning) {
+                builder = builder.tag(tag);
+            }
+            
+            builder = handlers::tag_client(builder);
+            
             let signer = FEED_SIGNER.lock().await;
             if let Some(s) = signer.as_ref() {
-                let unsigned = EventBuilder::text_note(&content_str)
-                    .build(user_pk);
+                let unsigned = builder.build(user_pk);
                 
                 let unsigned_json = serde_json::to_string(&unsigned)
                     .map_err(|e| format!("Serialization failed: {}", e))?;
@@ -2230,8 +3285,7 @@ impl qobject::FeedController {
                 let signed_event: Event = serde_json::from_str(&signed_result.event_json)
                     .map_err(|e| format!("Failed to parse signed event: {}", e))?;
                 
-                client.send_event(&signed_event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
+                publish::send(client, &signed_event).await?;
                 
                 Ok::<String, String>(signed_event.id.to_hex())
             } else if let Some(nsec) = FEED_NSEC.read().unwrap().as_ref() {
@@ -2239,12 +3293,11 @@ impl qobject::FeedController {
                     .map_err(|e| format!("Invalid nsec: {}", e))?;
                 let keys = Keys::new(secret_key);
                 
-                let event = EventBuilder::text_note(&content_str)
+                let event = builder
                     .sign_with_keys(&keys)
                     .map_err(|e| format!("Failed to sign: {}", e))?;
                 
-                client.send_event(&event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
+                publish::send(client, &event).await?;
                 
                 Ok(event.id.to_hex())
             } else {
@@ -2252,6 +3305,8 @@ impl qobject::FeedController {
             }
         });
         
+        self.as_mut().end_operation(&op, &result);
+        
         match result {
             Ok(event_id) => {
                 tracing::info!("Posted note, event: {}", event_id);
@@ -2264,24 +3319,307 @@ impl qobject::FeedController {
         }
     }
     
+    /// Post a picture event (kind 20)
+    pub fn post_picture(mut self: Pin<&mut Self>, title: &QString, content: &QString, image_urls: &QString, content_warning: &QString) {
+        let title = title.to_string().trim().to_string();
+        let content_str = content.to_string();
+        let content_warning = content_warning.to_string();
+        let image_urls: Vec<String> = serde_json::from_str(&image_urls.to_string()).unwrap_or_default();
+        
+        if image_urls.is_empty() {
+            self.as_mut().error_occurred(&QString::from("A picture post needs at least one image"));
+            return;
+        }
+        
+        let user_pubkey = self.user_pubkey.clone();
+        let protected = self.post_protected;
+        
+        let op = Operation::new(OperationKind::Post, &operations::content_key(&content_str))
+            .with_args(&["picture", &content_str, &content_warning, &title, &serde_json::to_string(&image_urls).unwrap_or_default()]);
+        self.as_mut().begin_operation(&op);
+        
+        let result = FEED_RUNTIME.block_on(async {
+            let user_pk = user_pubkey.as_ref()
+                .and_then(|pk| PublicKey::parse(pk).ok())
+                .ok_or("User not initialized")?;
+            
+            // NIP-68: media lives only in imeta tags, content is the description
+            let mut tags = Vec::new();
+            if !title.is_empty() {
+                tags.push(Tag::custom(TagKind::Title, [title.as_str()]));
+            }
+            for url in &image_urls {
+                let mime = mime_guess::from_path(url.split('?').next().unwrap_or(url))
+                    .first()
+                    .map(|m| m.to_string())
+                    .unwrap_or_else(|| "image/jpeg".to_string());
+                if !mime.starts_with("image/") {
+                    return Err(format!("Not an image: {}", url));
+                }
+                tags.push(Tag::custom(
+                    TagKind::Custom("imeta".into()),
+                    vec![format!("url {}", url), format!("m {}", mime)],
+                ));
+            }
+            
+            let prepared = compose::prepare_note(&content_str);
+            tags.extend(prepared.tags);
+            
+            if protected {
+                tags.push(Tag::protected());
+            }
+            if let Some(tag) = content_warning_tag(&content_warning) {
+                tags.push(tag);
+            }
+            
+            let builder = EventBuilder::new(Kind::from(KIND_PICTURE), &prepared.content).tags(tags);
+            let event = sign_event_builder(builder, user_pk).await?;
+            
+            let rm = RELAY_MANAGER.read().unwrap();
+            let manager = rm.as_ref().ok_or("Not connected to relays")?;
+            publish::send(manager.client(), &event).await?;
+            
+            Ok::<String, String>(event.id.to_hex())
+        });
+        
+        self.as_mut().end_operation(&op, &result);
+        
+        match result {
+            Ok(event_id) => {
+                tracing::info!("Posted picture, event: {}", event_id);
+                self.as_mut().note_posted(&QString::from(&event_id));
+            }
+            Err(e) => {
+                tracing::error!("Failed to post picture: {}", e);
+                self.as_mut().error_occurred(&QString::from(&e));
+            }
+        }
+    }
+    
+    /// Post a poll (kind 1068)
+    pub fn create_poll(mut self: Pin<&mut Self>, question: &QString, options_json: &QString, duration_secs: i64, multiple_choice: bool) {
+        let question = question.to_string();
+        let options: Vec<String> = serde_json::from_str(&options_json.to_string()).unwrap_or_default();
+        let ends_at = (duration_secs > 0).then(|| Timestamp::now() + duration_secs as u64);
+        
+        let builder = match polls::poll_builder(&question, &options, ends_at, multiple_choice) {
+            Ok(builder) => builder,
+            Err(e) => {
+                self.as_mut().error_occurred(&QString::from(&e));
+                return;
+            }
+        };
+        
+        let user_pubkey = self.user_pubkey.clone();
+        let op = Operation::new(OperationKind::Post, &operations::content_key(&question))
+            .with_args(&["poll", &question, &options_json.to_string(), &duration_secs.to_string(), if multiple_choice { "1" } else { "0" }]);
+        self.as_mut().begin_operation(&op);
+        
+        let result = FEED_RUNTIME.block_on(async {
+            let user_pk = user_pubkey.as_ref()
+                .and_then(|pk| PublicKey::parse(pk).ok())
+                .ok_or("User not initialized")?;
+            let event = sign_event_builder(builder, user_pk).await?;
+            
+            let rm = RELAY_MANAGER.read().unwrap();
+            let manager = rm.as_ref().ok_or("Not connected to relays")?;
+            publish::send(manager.client(), &event).await?;
+            
+            Ok::<String, String>(event.id.to_hex())
+        });
+        
+        self.as_mut().end_operation(&op, &result);
+        
+        match result {
+            Ok(event_id) => {
+                tracing::info!("Posted poll, event: {}", event_id);
+                self.as_mut().note_posted(&QString::from(&event_id));
+            }
+            Err(e) => {
+                tracing::error!("Failed to post poll: {}", e);
+                self.as_mut().error_occurred(&QString::from(&e));
+            }
+        }
+    }
+    
+    /// Fetch a poll's responses and tally them in the background
+    pub fn fetch_poll_results(self: Pin<&mut Self>, poll_id: &QString) {
+        self.tally_poll(poll_id.to_string(), None);
+    }
+    
+    /// Vote on a poll, then refresh its results
+    pub fn vote_poll(mut self: Pin<&mut Self>, poll_id: &QString, option_ids_json: &QString) {
+        match serde_json::from_str::<Vec<String>>(&option_ids_json.to_string()) {
+            Ok(option_ids) => self.tally_poll(poll_id.to_string(), Some(option_ids)),
+            Err(e) => self.as_mut().error_occurred(&QString::from(&format!("Invalid poll choices: {}", e))),
+        }
+    }
+    
+    /// Fetch the poll (voting first if `vote` is given) and emit its tallied results
+    fn tally_poll(self: Pin<&mut Self>, poll_id: String, vote: Option<Vec<String>>) {
+        let Ok(event_id) = EventId::parse(&poll_id) else {
+            tracing::warn!("Invalid poll id: {}", poll_id);
+            return;
+        };
+        let user = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok());
+        let qt_thread = self.qt_thread();
+        
+        limits::spawn_limited(move || {
+            let result = FEED_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                let poll = manager.fetch_event(&event_id).await?
+                    .as_ref()
+                    .and_then(Poll::from_event)
+                    .ok_or("Poll not found")?;
+                
+                let mut sent = None;
+                if let Some(option_ids) = vote {
+                    let now = Timestamp::now().as_secs() as i64;
+                    if poll.is_closed(now) {
+                        return Err("This poll has closed".to_string());
+                    }
+                    let user = user.ok_or("User not initialized")?;
+                    let builder = polls::response_builder(&poll, &option_ids)?;
+                    let event = sign_event_builder(builder, user).await?;
+                    publish::send(manager.client(), &event).await?;
+                    sent = Some(event);
+                }
+                
+                let filter = polls::responses_filter(&poll).ok_or("Invalid poll id")?;
+                let mut responses: Vec<Event> = manager.client()
+                    .fetch_events(filter, crate::nostr::relay::fetch_timeout())
+                    .await
+                    .map_err(|e| format!("Failed to fetch poll responses: {}", e))?
+                    .into_iter()
+                    .collect();
+                // Relays may not return a vote we just sent yet
+                responses.extend(sent);
+                
+                let now = Timestamp::now().as_secs() as i64;
+                Ok::<_, String>(polls::tally(&poll, &responses, user.as_ref(), now))
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| match result {
+                Ok(results) => {
+                    let json = serde_json::to_string(&results).unwrap_or_default();
+                    qobject.as_mut().poll_results_ready(&QString::from(&poll_id), &QString::from(&json));
+                }
+                Err(e) => {
+                    tracing::warn!("Poll {} failed: {}", poll_id, e);
+                    qobject.as_mut().error_occurred(&QString::from(&e));
+                }
+            });
+        });
+    }
+    
+    /// Fetch a zap goal and its zap receipts in the background and emit the sum
+    pub fn fetch_zap_goal_progress(self: Pin<&mut Self>, goal_id: &QString) {
+        let goal_id = goal_id.to_string();
+        let Ok(event_id) = EventId::parse(&goal_id) else {
+            tracing::warn!("Invalid zap goal id: {}", goal_id);
+            return;
+        };
+        let qt_thread = self.qt_thread();
+        
+        limits::spawn_limited(move || {
+            let result = FEED_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                let goal = manager.fetch_event(&event_id).await?
+                    .as_ref()
+                    .and_then(ZapGoal::from_event)
+                    .ok_or("Zap goal not found")?;
+                
+                // Receipts go to the relays the goal names, which may not be ours
+                let filter = zap_goals::receipts_filter(&goal).ok_or("Invalid zap goal id")?;
+                let timeout = crate::nostr::relay::fetch_timeout();
+                let (ours, theirs) = tokio::join!(
+                    manager.client().fetch_events(filter.clone(), timeout),
+                    manager.fetch_from_hints(&goal.relay_urls(), filter, timeout),
+                );
+                let mut receipts: Vec<Event> = ours
+                    .map_err(|e| format!("Failed to fetch zap receipts: {}", e))?
+                    .into_iter()
+                    .collect();
+                match theirs {
+                    Ok(events) => receipts.extend(events),
+                    Err(e) => tracing::debug!("Zap goal relays unavailable: {}", e),
+                }
+                
+                let now = Timestamp::now().as_secs() as i64;
+                Ok::<_, String>(zap_goals::tally(&goal, &receipts, now))
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| match result {
+                Ok(progress) => {
+                    let json = serde_json::to_string(&progress).unwrap_or_default();
+                    qobject.as_mut().zap_goal_progress_ready(&QString::from(&goal_id), &QString::from(&json));
+                }
+                Err(e) => tracing::warn!("Zap goal {} progress failed: {}", goal_id, e),
+            });
+        });
+    }
+    
+    /// Find handler apps for a kind in the background and emit links opening the note in them
+    pub fn fetch_kind_handlers(self: Pin<&mut Self>, note_id: &QString, kind: i32, bech32: &QString) {
+        let note_id = note_id.to_string();
+        let bech32 = bech32.to_string();
+        let Ok(kind) = u16::try_from(kind) else { return };
+        let qt_thread = self.qt_thread();
+        
+        limits::spawn_limited(move || {
+            let result = FEED_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                handlers::handlers_for_kind(&manager, kind).await
+            });
+            
+            let links: Vec<serde_json::Value> = match result {
+                Ok(found) => found.iter()
+                    .filter_map(|handler| Some(serde_json::json!({
+                        "name": handler.name,
+                        "picture": handler.picture,
+                        "url": handler.url_for(&bech32)?,
+                        "recommendations": handler.recommendations,
+                    })))
+                    .collect(),
+                Err(e) => {
+                    tracing::warn!("Handler lookup for kind {} failed: {}", kind, e);
+                    Vec::new()
+                }
+            };
+            let json = serde_json::to_string(&links).unwrap_or_else(|_| "[]".to_string());
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().kind_handlers_ready(&QString::from(&note_id), &QString::from(&json));
+            });
+        });
+    }
+    
     /// Post a new note with media attachments
-    pub fn post_note_with_media(mut self: Pin<&mut Self>, content: &QString, media_urls: &QString) {
+    pub fn post_note_with_media(mut self: Pin<&mut Self>, content: &QString, media_urls: &QString, content_warning: &QString) {
         let content_str = content.to_string();
+        let content_warning = content_warning.to_string();
         let media_urls_str = media_urls.to_string();
         
         // Parse media URLs from JSON array
         let media_urls: Vec<String> = serde_json::from_str(&media_urls_str).unwrap_or_default();
         
+        // NIP-27: @name -> nostr: URIs, plus t/p tags for hashtags and mentions
+        let prepared = compose::prepare_note(&content_str);
+        
         // Append media URLs to content
         let full_content = if media_urls.is_empty() {
-            content_str.clone()
+            prepared.content.clone()
         } else {
-            format!("{}\n\n{}", content_str, media_urls.join("\n"))
+            format!("{}\n\n{}", prepared.content, media_urls.join("\n"))
         };
         
         tracing::info!("Post note with {} media: {}", media_urls.len(), &full_content[..full_content.len().min(100)]);
         
         let user_pubkey = self.user_pubkey.clone();
+        let protected = self.post_protected;
+        
+        let op = Operation::new(OperationKind::Post, &operations::content_key(&content_str))
+            .with_args(&["media", &content_str, &content_warning, &media_urls_str]);
+        self.as_mut().begin_operation(&op);
         
         let result = FEED_RUNTIME.block_on(async {
             let user_pk = user_pubkey.as_ref()
@@ -2294,7 +3632,8 @@ impl qobject::FeedController {
             let client = manager.client();
             
             // Build event with imeta tags for each media URL
-            let mut builder = EventBuilder::text_note(&full_content);
+            let mut builder = EventBuilder::text_note(&full_content)
+                .tags(prepared.tags);
             
             // Add imeta tags for media URLs (NIP-92 style)
             for url in &media_urls {
@@ -2316,6 +3655,16 @@ impl qobject::FeedController {
                 ));
             }
             
+            if protected {
+                builder = builder.tag(Tag::protected());
+            }
+            
+            if let Some(tag) = content_warning_tag(&content_warning) {
+                builder = builder.tag(tag);
+            }
+            
+            builder = handlers::tag_client(builder);
+            
             let signer = FEED_SIGNER.lock().await;
             if let Some(s) = signer.as_ref() {
                 let unsigned = builder.build(user_pk);
@@ -2329,8 +3678,7 @@ impl qobject::FeedController {
                 let signed_event: Event = serde_json::from_str(&signed_result.event_json)
                     .map_err(|e| format!("Failed to parse signed event: {}", e))?;
                 
-                client.send_event(&signed_event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
+                publish::send(client, &signed_event).await?;
                 
                 Ok::<String, String>(signed_event.id.to_hex())
             } else if let Some(nsec) = FEED_NSEC.read().unwrap().as_ref() {
@@ -2342,8 +3690,7 @@ impl qobject::FeedController {
                     .sign_with_keys(&keys)
                     .map_err(|e| format!("Failed to sign: {}", e))?;
                 
-                client.send_event(&event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
+                publish::send(client, &event).await?;
                 
                 Ok(event.id.to_hex())
             } else {
@@ -2351,6 +3698,8 @@ impl qobject::FeedController {
             }
         });
         
+        self.as_mut().end_operation(&op, &result);
+        
         match result {
             Ok(event_id) => {
                 tracing::info!("Posted note with media, event: {}", event_id);
@@ -2442,15 +3791,17 @@ impl qobject::FeedController {
     
     /// Fetch an embedded nostr event by nevent/naddr/note bech32 string
     /// Uses caching to avoid blocking the UI thread during scroll
-    pub fn fetch_embedded_event(self: Pin<&mut Self>, nostr_uri: &QString) -> QString {
+    pub fn fetch_embedded_event(self: Pin<&mut Self>, nostr_uri: &QString, parent_id: &QString) -> QString {
         let uri = nostr_uri.to_string();
-        let cache_key = uri.clone();
+        let parent_id = parent_id.to_string();
+        // Cycle placeholders depend on the containing note
+        let cache_key = format!("{}|{}", uri, parent_id);
         
         // Check cache first - return immediately if cached
-        if let Ok(cache) = EMBEDDED_EVENT_CACHE.read() {
-            if let Some(cached) = cache.get(&cache_key) {
-                return QString::from(cached);
-            }
+        let cached = EMBEDDED_EVENT_CACHE.get(&cache_key);
+        cache_stats::record(cache_stats::EMBEDDED_EVENTS, cached.is_some());
+        if let Some(cached) = cached {
+            return QString::from(&cached);
         }
         
         // Check if already pending
@@ -2468,86 +3819,21 @@ impl qobject::FeedController {
             pending.insert(cache_key.clone());
         }
         
-        // Strip nostr: prefix if present
-        let bech32_str = uri.strip_prefix("nostr:").unwrap_or(&uri).to_string();
-        
         // Spawn background fetch - don't block UI
         let cache_key_clone = cache_key.clone();
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = FEED_RUNTIME.block_on(async {
-                // Try to parse as different nostr types
-                let event_id = if bech32_str.starts_with("nevent") {
-                    match Nip19Event::from_bech32(&bech32_str) {
-                        Ok(nip19) => Some(nip19.event_id),
-                        Err(_) => None,
-                    }
-                } else if bech32_str.starts_with("note") {
-                    match EventId::from_bech32(&bech32_str) {
-                        Ok(id) => Some(id),
-                        Err(_) => None,
-                    }
-                } else if bech32_str.starts_with("naddr") {
-                    match Nip19Coordinate::from_bech32(&bech32_str) {
-                        Ok(coord) => {
-                            let rm = RELAY_MANAGER.read().unwrap();
-                            let Some(manager) = rm.as_ref() else {
-                                return Err("Relay manager not initialized".to_string());
-                            };
-                            
-                            let filter = Filter::new()
-                                .kind(coord.coordinate.kind)
-                                .author(coord.coordinate.public_key)
-                                .identifier(&coord.coordinate.identifier)
-                                .limit(1);
-                            
-                            let events = manager.client().fetch_events(filter, std::time::Duration::from_secs(3))
-                                .await
-                                .map_err(|e| format!("Failed to fetch naddr: {}", e))?;
-                            
-                            if let Some(event) = events.into_iter().next() {
-                                let profiles = manager.fetch_profiles(&[event.pubkey]).await.unwrap_or_default();
-                                let profile = profiles.iter().next().and_then(|p| {
-                                    Metadata::from_json(&p.content).ok().map(|m| ProfileCache::from_metadata(&m))
-                                });
-                                let note = DisplayNote::from_event(&event, profile.as_ref());
-                                return Ok(note.to_json());
-                            }
-                            return Err("naddr event not found".to_string());
-                        }
-                        Err(_) => None,
-                    }
-                } else {
-                    None
-                };
-                
-                if let Some(event_id) = event_id {
-                    let rm = RELAY_MANAGER.read().unwrap();
-                    let Some(manager) = rm.as_ref() else {
-                        return Err("Relay manager not initialized".to_string());
-                    };
-                    
-                    let filter = Filter::new().id(event_id).limit(1);
-                    let events = manager.client().fetch_events(filter, std::time::Duration::from_secs(3))
-                        .await
-                        .map_err(|e| format!("Failed to fetch event: {}", e))?;
-                    
-                    if let Some(event) = events.into_iter().next() {
-                        let profiles = manager.fetch_profiles(&[event.pubkey]).await.unwrap_or_default();
-                        let profile = profiles.iter().next().and_then(|p| {
-                            Metadata::from_json(&p.content).ok().map(|m| ProfileCache::from_metadata(&m))
-                        });
-                        let note = DisplayNote::from_event(&event, profile.as_ref());
-                        return Ok(note.to_json());
-                    }
-                }
-                Err("Event not found".to_string())
+                let manager = shared_relay_manager().await?;
+                let ancestors: Vec<String> = if parent_id.is_empty() { Vec::new() } else { vec![parent_id] };
+                embeds::resolve(&manager, &uri, &ancestors).await
             });
             
             // Cache the result
-            if let Ok(json) = result {
-                if let Ok(mut cache) = EMBEDDED_EVENT_CACHE.write() {
-                    cache.insert(cache_key_clone.clone(), json);
+            match result {
+                Ok(value) => {
+                    EMBEDDED_EVENT_CACHE.insert(cache_key_clone.clone(), value.to_string());
                 }
+                Err(e) => tracing::debug!("Embedded event not resolved: {}", e),
             }
             
             // Remove from pending
@@ -2567,10 +3853,10 @@ impl qobject::FeedController {
         let cache_key = uri.clone();
         
         // Check cache first - return immediately if cached
-        if let Ok(cache) = EMBEDDED_PROFILE_CACHE.read() {
-            if let Some(cached) = cache.get(&cache_key) {
-                return QString::from(cached);
-            }
+        let cached = EMBEDDED_PROFILE_CACHE.get(&cache_key);
+        cache_stats::record(cache_stats::EMBEDDED_PROFILES, cached.is_some());
+        if let Some(cached) = cached {
+            return QString::from(&cached);
         }
         
         // Check if already pending
@@ -2592,7 +3878,7 @@ impl qobject::FeedController {
         
         // Spawn background fetch
         let cache_key_clone = cache_key.clone();
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = FEED_RUNTIME.block_on(async {
                 let pubkey = if bech32_str.starts_with("nprofile") {
                     match Nip19Profile::from_bech32(&bech32_str) {
@@ -2614,9 +3900,22 @@ impl qobject::FeedController {
                         return Err("Relay manager not initialized".to_string());
                     };
                     
-                    let profiles = manager.fetch_profiles(&[pk]).await.unwrap_or_default();
+                    let mut profile_event = manager.fetch_profiles(&[pk]).await.unwrap_or_default().into_iter().next();
+                    
+                    // Not on our relays - ask the ones the nprofile names
+                    let hints = embeds::relay_hints(&bech32_str);
+                    if profile_event.is_none() && !hints.is_empty() {
+                        let filter = Filter::new().kind(Kind::Metadata).author(pk).limit(1);
+                        profile_event = manager.fetch_from_hints(&hints, filter, crate::nostr::relay::fetch_timeout())
+                            .await
+                            .map(|events| events.into_iter().max_by_key(|e| e.created_at))
+                            .unwrap_or_default();
+                        if let Some(event) = &profile_event {
+                            profile_store::ingest(std::slice::from_ref(event));
+                        }
+                    }
                     
-                    if let Some(profile_event) = profiles.into_iter().next() {
+                    if let Some(profile_event) = profile_event {
                         if let Ok(metadata) = Metadata::from_json(&profile_event.content) {
                             let profile = ProfileCache::from_metadata(&metadata);
                             let npub = pk.to_bech32().unwrap_or_default();
@@ -2649,9 +3948,7 @@ impl qobject::FeedController {
             
             // Cache the result
             if let Ok(json) = result {
-                if let Ok(mut cache) = EMBEDDED_PROFILE_CACHE.write() {
-                    cache.insert(cache_key_clone.clone(), json);
-                }
+                EMBEDDED_PROFILE_CACHE.insert(cache_key_clone.clone(), json);
             }
             
             // Remove from pending
@@ -2680,17 +3977,23 @@ impl qobject::FeedController {
         let cache_key = url_str.clone();
         
         // Check cache first
-        if let Ok(cache) = LINK_PREVIEW_CACHE.read() {
-            if let Some(cached) = cache.get(&cache_key) {
-                return QString::from(cached);
-            }
+        let cached = LINK_PREVIEW_CACHE.get(&cache_key);
+        cache_stats::record(cache_stats::LINK_PREVIEWS, cached.is_some());
+        if let Some(cached) = cached {
+            return QString::from(&cached);
         }
         
+        // Players that need nothing but the URL show while the full preview loads
+        let instant = match link_preview::instant(&url_str) {
+            Some(preview) => QString::from(&preview.to_string()),
+            None => QString::from("{}"),
+        };
+        
         // Check if already pending
         {
             let pending = PENDING_EMBEDS.read().unwrap();
             if pending.contains(&cache_key) {
-                return QString::from("{}");
+                return instant;
             }
         }
         
@@ -2703,19 +4006,20 @@ impl qobject::FeedController {
         // Spawn background fetch
         let cache_key_clone = cache_key.clone();
         let url_clone = url_str.clone();
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = FEED_RUNTIME.block_on(async {
-                fetch_og_metadata(&url_clone).await
+                link_preview::fetch(&url_clone).await
             });
             
             // Cache the result (even errors to avoid refetching)
-            if let Ok(mut cache) = LINK_PREVIEW_CACHE.write() {
-                let cached_val = match result {
-                    Ok(metadata) => metadata,
-                    Err(_) => "{}".to_string(),
-                };
-                cache.insert(cache_key_clone.clone(), cached_val);
-            }
+            let cached_val = match result {
+                Ok(metadata) => metadata.to_string(),
+                Err(e) => {
+                    tracing::debug!("No link preview for {}: {}", url_clone, e);
+                    "{}".to_string()
+                }
+            };
+            LINK_PREVIEW_CACHE.insert(cache_key_clone.clone(), cached_val);
             
             // Remove from pending
             if let Ok(mut pending) = PENDING_EMBEDS.write() {
@@ -2723,7 +4027,7 @@ impl qobject::FeedController {
             }
         });
         
-        QString::from("{}")
+        instant
     }
     
     /// Publish a long-form article (NIP-23 kind 30023)
@@ -2808,6 +4112,8 @@ impl qobject::FeedController {
                 vec![published_at.to_string()]
             ));
             
+            builder = handlers::tag_client(builder);
+            
             let signer = FEED_SIGNER.lock().await;
             if let Some(s) = signer.as_ref() {
                 let unsigned = builder.build(user_pk);
@@ -2823,8 +4129,7 @@ impl qobject::FeedController {
                 
                 let event_id = signed_event.id.to_hex();
                 
-                client.send_event(&signed_event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
+                publish::send(client, &signed_event).await?;
                 
                 // Create naddr for the article
                 let naddr = format!("naddr1{}", ""); // Simplified - full naddr encoding would be more complex
@@ -2840,8 +4145,7 @@ impl qobject::FeedController {
                 
                 let event_id = event.id.to_hex();
                 
-                client.send_event(&event).await
-                    .map_err(|e| format!("Failed to send: {}", e))?;
+                publish::send(client, &event).await?;
                 
                 // Create naddr coordinate
                 let coordinate = Coordinate::new(Kind::LongFormTextNote, user_pk)
@@ -3007,62 +4311,156 @@ impl qobject::FeedController {
         }
     }
     
+    /// Canonicalize composer mentions and resolve their display names
+    /// Cached names are emitted immediately; others are fetched from relays
+    pub fn preprocess_composer(mut self: Pin<&mut Self>, text: &QString) -> QString {
+        let canonical = compose::canonicalize_mentions(&text.to_string());
+        
+        let mut to_fetch: Vec<(String, PublicKey)> = Vec::new();
+        for (uri, pubkey) in compose::mention_uris(&canonical) {
+            let hex = pubkey.to_hex();
+            let cached = NostrDbManager::global().ok()
+                .and_then(|db| db.get_profile(&hex))
+                .and_then(|p| p.get_display_name().map(|n| n.to_string()));
+            match cached {
+                Some(name) => self.as_mut().mention_resolved(&QString::from(&uri), &QString::from(&hex), &QString::from(&name)),
+                None => {
+                    if PENDING_MENTIONS.write().unwrap().insert(hex) {
+                        to_fetch.push((uri, pubkey));
+                    }
+                }
+            }
+        }
+        
+        if !to_fetch.is_empty() {
+            let qt_thread = self.qt_thread();
+            limits::spawn_limited(move || {
+                let pubkeys: Vec<PublicKey> = to_fetch.iter().map(|(_, pk)| *pk).collect();
+                let profiles = FEED_RUNTIME.block_on(async {
+                    let manager = shared_relay_manager().await?;
+                    manager.fetch_profiles(&pubkeys).await
+                }).unwrap_or_default();
+                
+                let mut names: std::collections::HashMap<PublicKey, String> = std::collections::HashMap::new();
+                for event in profiles {
+                    if let Ok(metadata) = Metadata::from_json(&event.content) {
+                        let name = ProfileCache::from_metadata(&metadata).get_display_name("");
+                        if !name.is_empty() {
+                            names.insert(event.pubkey, name);
+                        }
+                    }
+                }
+                
+                {
+                    let mut pending = PENDING_MENTIONS.write().unwrap();
+                    for pk in &pubkeys {
+                        pending.remove(&pk.to_hex());
+                    }
+                }
+                
+                let _ = qt_thread.queue(move |mut qobject| {
+                    for (uri, pubkey) in to_fetch {
+                        let Some(name) = names.get(&pubkey) else { continue };
+                        qobject.as_mut().mention_resolved(
+                            &QString::from(&uri),
+                            &QString::from(&pubkey.to_hex()),
+                            &QString::from(name),
+                        );
+                    }
+                });
+            });
+        }
+        
+        QString::from(&canonical)
+    }
+    
+    /// Save a composer draft to disk
+    pub fn save_draft(mut self: Pin<&mut Self>, draft_json: &QString) -> QString {
+        let result = serde_json::from_str::<NoteDraft>(&draft_json.to_string())
+            .map_err(|e| format!("Invalid draft JSON: {}", e))
+            .and_then(|draft| DraftManager::new()?.save(draft));
+        
+        match result {
+            Ok(draft) => {
+                tracing::debug!("Saved note draft: {}", draft.id);
+                self.as_mut().draft_saved(&QString::from(&draft.id));
+                QString::from(&serde_json::to_string(&draft).unwrap_or_default())
+            }
+            Err(e) => {
+                tracing::error!("Failed to save note draft: {}", e);
+                QString::from(&serde_json::json!({ "error": e }).to_string())
+            }
+        }
+    }
+    
+    /// List composer drafts
+    pub fn list_drafts(&self) -> QString {
+        let drafts = DraftManager::new()
+            .map(|dm| dm.list())
+            .unwrap_or_else(|e| {
+                tracing::error!("Failed to open drafts: {}", e);
+                Vec::new()
+            });
+        QString::from(&serde_json::to_string(&drafts).unwrap_or_else(|_| "[]".to_string()))
+    }
+    
+    /// Delete a composer draft
+    pub fn delete_draft(self: Pin<&mut Self>, draft_id: &QString) -> bool {
+        let id = draft_id.to_string();
+        match DraftManager::new().and_then(|dm| dm.delete(&id)) {
+            Ok(deleted) => {
+                if deleted {
+                    tracing::info!("Deleted note draft: {}", id);
+                }
+                deleted
+            }
+            Err(e) => {
+                tracing::error!("Failed to delete note draft: {}", e);
+                false
+            }
+        }
+    }
+    
     /// Search Tenor for GIFs
-    pub fn search_gifs(self: Pin<&mut Self>, query: &QString) -> QString {
+    pub fn search_gifs(self: Pin<&mut Self>, query: &QString, pos: &QString) -> QString {
         let query_str = query.to_string();
         
         if query_str.trim().is_empty() {
-            return QString::from("[]");
+            return QString::from(r#"{"results": [], "next": null}"#);
         }
         
-        let config = Config::load();
-        let api_key = match config.tenor_api_key {
-            Some(key) if !key.is_empty() => key,
-            _ => {
-                tracing::warn!("Tenor API key not configured");
-                return QString::from(r#"{"error": "Tenor API key not configured"}"#);
-            }
+        let Some(api_key) = tenor_api_key() else {
+            return QString::from(r#"{"error": "Tenor API key not configured"}"#);
         };
+        let pos = pos.to_string();
         
         let result = FEED_RUNTIME.block_on(async {
-            tenor::search_gifs(&api_key, &query_str, 20).await
+            tenor::search_gifs(&api_key, query_str.trim(), tenor::PAGE_SIZE, Some(&pos)).await
         });
-        
-        match result {
-            Ok(gifs) => {
-                QString::from(&serde_json::to_string(&gifs).unwrap_or_else(|_| "[]".to_string()))
-            }
-            Err(e) => {
-                tracing::error!("Tenor search failed: {}", e);
-                QString::from(&format!(r#"{{"error": "{}"}}"#, e.replace('"', "'")))
-            }
-        }
+        gif_result_json("search", result.map(|page| serde_json::to_string(&page).unwrap_or_default()))
     }
     
     /// Get trending GIFs from Tenor
-    pub fn get_trending_gifs(self: Pin<&mut Self>) -> QString {
-        let config = Config::load();
-        let api_key = match config.tenor_api_key {
-            Some(key) if !key.is_empty() => key,
-            _ => {
-                tracing::warn!("Tenor API key not configured");
-                return QString::from(r#"{"error": "Tenor API key not configured"}"#);
-            }
+    pub fn trending_gifs(self: Pin<&mut Self>, pos: &QString) -> QString {
+        let Some(api_key) = tenor_api_key() else {
+            return QString::from(r#"{"error": "Tenor API key not configured"}"#);
         };
+        let pos = pos.to_string();
         
         let result = FEED_RUNTIME.block_on(async {
-            tenor::get_trending_gifs(&api_key, 20).await
+            tenor::get_trending_gifs(&api_key, tenor::PAGE_SIZE, Some(&pos)).await
         });
+        gif_result_json("trending", result.map(|page| serde_json::to_string(&page).unwrap_or_default()))
+    }
+    
+    /// Get Tenor's featured search categories
+    pub fn gif_categories(self: Pin<&mut Self>) -> QString {
+        let Some(api_key) = tenor_api_key() else {
+            return QString::from(r#"{"error": "Tenor API key not configured"}"#);
+        };
         
-        match result {
-            Ok(gifs) => {
-                QString::from(&serde_json::to_string(&gifs).unwrap_or_else(|_| "[]".to_string()))
-            }
-            Err(e) => {
-                tracing::error!("Tenor trending failed: {}", e);
-                QString::from(&format!(r#"{{"error": "{}"}}"#, e.replace('"', "'")))
-            }
-        }
+        let result = FEED_RUNTIME.block_on(tenor::get_categories(&api_key));
+        gif_result_json("categories", result.map(|c| serde_json::to_string(&c).unwrap_or_else(|_| "[]".to_string())))
     }
     
     /// Bridge a GIF from Tenor to Nostr via NIP-96 re-upload
@@ -3074,7 +4472,7 @@ impl qobject::FeedController {
         // Clone self for the thread
         let qt_thread = self.qt_thread();
         
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = FEED_RUNTIME.block_on(async {
                 // Get keys for signing
                 let nsec_opt = FEED_NSEC.read().unwrap();
@@ -3138,146 +4536,1402 @@ impl qobject::FeedController {
             tracing::error!("Failed to save NIP-96 server: {}", e);
         }
     }
-}
 
-/// Get the drafts directory, creating it if necessary
-fn get_drafts_directory() -> Result<std::path::PathBuf, String> {
-    let data_dir = dirs::data_local_dir()
-        .ok_or("Could not determine data directory")?
-        .join("pleb-client")
-        .join("drafts");
-    
-    if !data_dir.exists() {
-        std::fs::create_dir_all(&data_dir)
-            .map_err(|e| format!("Failed to create drafts directory: {}", e))?;
+    /// Verify NIP-05 identifiers of authors in the current feed that aren't cached yet
+    fn schedule_nip05_checks(self: Pin<&mut Self>) {
+        let mut to_check: Vec<(String, String)> = Vec::new();
+        {
+            let mut pending = PENDING_NIP05.write().unwrap();
+            for note in self.notes.iter() {
+                let Some(nip05_id) = note.author_nip05.as_ref() else { continue };
+                if note.author_nip05_verified || pending.contains(&note.pubkey) {
+                    continue;
+                }
+                if nip05::cached_status(nip05_id, &note.pubkey).is_some() {
+                    continue;
+                }
+                pending.insert(note.pubkey.clone());
+                to_check.push((note.pubkey.clone(), nip05_id.clone()));
+            }
+        }
+        
+        if to_check.is_empty() {
+            return;
+        }
+        
+        let qt_thread = self.qt_thread();
+        
+        limits::spawn_limited(move || {
+            let results = FEED_RUNTIME.block_on(nip05::check_all(to_check));
+            
+            {
+                let mut pending = PENDING_NIP05.write().unwrap();
+                for (pubkey, _) in results.iter() {
+                    pending.remove(pubkey);
+                }
+            }
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                for (pubkey, verified) in results {
+                    if verified {
+                        let mut rust = qobject.as_mut().rust_mut();
+                        for note in rust.notes.iter_mut().filter(|n| n.pubkey == pubkey) {
+                            note.author_nip05_verified = true;
+                        }
+                    }
+                    qobject.as_mut().nip05_verified(&QString::from(&pubkey), verified);
+                }
+            });
+        });
     }
     
-    Ok(data_dir)
-}
-
-/// Fetch OpenGraph metadata from a URL
-async fn fetch_og_metadata(url: &str) -> Result<String, String> {
-    use std::time::Duration;
-    
-    let client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(5))
-        .user_agent("Mozilla/5.0 (compatible; PlebClient/1.0)")
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+    /// Clear all per-user state
+    pub fn reset(mut self: Pin<&mut Self>) {
+        {
+            let mut rust = self.as_mut().rust_mut();
+            rust.notes.clear();
+            rust.thread_notes.clear();
+            rust.thread = None;
+            rust.user_pubkey = None;
+            rust.initialized = false;
+        }
+        self.as_mut().set_note_count(0);
+        self.as_mut().set_thread_count(0);
+        self.as_mut().set_thread_note_id(QString::from(""));
+        self.as_mut().set_current_feed(QString::from(""));
+        self.as_mut().set_error_message(QString::from(""));
+        self.as_mut().set_loading_status(QString::from(""));
+        self.as_mut().set_is_loading(false);
+        self.as_mut().feed_updated();
+    }
     
-    let response = client.get(url)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to fetch URL: {}", e))?;
+    /// Whether content warnings are revealed automatically
+    pub fn get_always_reveal_sensitive(&self) -> bool {
+        Config::load().always_reveal_sensitive
+    }
     
-    if !response.status().is_success() {
-        return Err(format!("HTTP error: {}", response.status()));
+    /// Set whether content warnings are revealed automatically
+    pub fn set_always_reveal_sensitive(self: Pin<&mut Self>, reveal: bool) {
+        let mut config = Config::load();
+        config.always_reveal_sensitive = reveal;
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save content warning setting: {}", e);
+        }
     }
     
-    let html = response.text().await
-        .map_err(|e| format!("Failed to read response: {}", e))?;
+    /// Schedule a note for later publishing
+    pub fn schedule_note(self: Pin<&mut Self>, content: &QString, publish_at: i64, content_warning: &QString) -> QString {
+        let result = (|| {
+            let pubkey = self.user_pubkey.clone().ok_or("User not initialized")?;
+            if publish_at <= chrono::Utc::now().timestamp() {
+                return Err("Scheduled time must be in the future".to_string());
+            }
+            
+            // Tags are resolved now so the stored post is exactly what gets signed
+            let prepared = compose::prepare_note(&content.to_string());
+            let mut tags = prepared.tags;
+            if self.post_protected {
+                tags.push(Tag::protected());
+            }
+            if let Some(tag) = content_warning_tag(&content_warning.to_string()) {
+                tags.push(tag);
+            }
+            
+            let post = ScheduledPost {
+                pubkey,
+                publish_at,
+                kind: Kind::TextNote.as_u16(),
+                content: prepared.content,
+                tags: tags.into_iter().map(|t| t.to_vec()).collect(),
+                ..Default::default()
+            };
+            GLOBAL_SCHEDULER.lock().unwrap().add(post)
+        })();
+        
+        match result {
+            Ok(post) => {
+                tracing::info!("Scheduled post {} for {}", post.id, post.publish_at);
+                QString::from(&serde_json::to_string(&post).unwrap_or_default())
+            }
+            Err(e) => {
+                tracing::error!("Failed to schedule post: {}", e);
+                QString::from(&serde_json::json!({ "error": e }).to_string())
+            }
+        }
+    }
     
-    // Parse OpenGraph meta tags
-    let mut title = None;
-    let mut description = None;
-    let mut image = None;
-    let mut site_name = None;
-    let mut audio = None;
-    let mut video = None;
+    /// List the user's scheduled posts
+    pub fn list_scheduled_posts(&self) -> QString {
+        let Some(pubkey) = self.user_pubkey.as_ref() else {
+            return QString::from("[]");
+        };
+        let posts = GLOBAL_SCHEDULER.lock().unwrap().list(pubkey);
+        QString::from(&serde_json::to_string(&posts).unwrap_or_else(|_| "[]".to_string()))
+    }
     
-    // Simple regex-based OG parsing (faster than full HTML parsing)
-    let og_regex = regex::Regex::new(r#"<meta[^>]*(?:property|name)=["']og:([^"']+)["'][^>]*content=["']([^"']*?)["'][^>]*/?>|<meta[^>]*content=["']([^"']*?)["'][^>]*(?:property|name)=["']og:([^"']+)["'][^>]*/?>"#).unwrap();
+    /// Cancel a scheduled post
+    pub fn cancel_scheduled_post(self: Pin<&mut Self>, post_id: &QString) -> bool {
+        match GLOBAL_SCHEDULER.lock().unwrap().cancel(&post_id.to_string()) {
+            Ok(cancelled) => cancelled,
+            Err(e) => {
+                tracing::error!("Failed to cancel scheduled post: {}", e);
+                false
+            }
+        }
+    }
     
-    for cap in og_regex.captures_iter(&html) {
-        let (prop, content) = if let (Some(p), Some(c)) = (cap.get(1), cap.get(2)) {
-            (p.as_str(), c.as_str())
-        } else if let (Some(c), Some(p)) = (cap.get(3), cap.get(4)) {
-            (p.as_str(), c.as_str())
+    /// Edit one of the user's scheduled posts
+    pub fn edit_scheduled_post(self: Pin<&mut Self>, post_id: &QString, content: &QString, publish_at: i64) -> bool {
+        let Some(pubkey) = self.user_pubkey.clone() else {
+            return false;
+        };
+        let content = content.to_string();
+        let content = if content.trim().is_empty() {
+            None
         } else {
-            continue;
+            // Mentions and hashtags are extracted again, as when scheduling
+            let prepared = compose::prepare_note(&content);
+            Some((prepared.content, prepared.tags.into_iter().map(|t| t.to_vec()).collect()))
         };
+        let publish_at = if publish_at > 0 { Some(publish_at) } else { None };
+        let now = chrono::Utc::now().timestamp();
         
-        match prop {
-            "title" => title = Some(content.to_string()),
-            "description" => description = Some(content.to_string()),
-            "image" => image = Some(content.to_string()),
-            "site_name" => site_name = Some(content.to_string()),
-            "audio" => audio = Some(content.to_string()),
-            "video" => video = Some(content.to_string()),
-            _ => {}
+        match GLOBAL_SCHEDULER.lock().unwrap().edit(&post_id.to_string(), &pubkey, content, publish_at, now) {
+            Ok(edited) => edited,
+            Err(e) => {
+                tracing::error!("Failed to edit scheduled post: {}", e);
+                false
+            }
         }
     }
     
-    // Fallback to regular title tag if no og:title
-    if title.is_none() {
-        let title_regex = regex::Regex::new(r#"<title[^>]*>([^<]+)</title>"#).unwrap();
-        if let Some(cap) = title_regex.captures(&html) {
-            title = Some(cap[1].to_string());
+    /// Start the background loop that publishes due scheduled posts
+    /// A newer loop (e.g. after switching accounts) supersedes older ones
+    fn start_scheduler(self: Pin<&mut Self>) {
+        let Some(user_pk) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) else {
+            return;
+        };
+        let generation = SCHEDULER_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            while SCHEDULER_GENERATION.load(std::sync::atomic::Ordering::SeqCst) == generation {
+                let due = GLOBAL_SCHEDULER.lock().unwrap()
+                    .due(&user_pk.to_hex(), chrono::Utc::now().timestamp());
+                
+                for post in due {
+                    let result = FEED_RUNTIME.block_on(publish_scheduled_post(&post, user_pk));
+                    
+                    let mut scheduler = GLOBAL_SCHEDULER.lock().unwrap();
+                    let post_id = post.id.clone();
+                    match result {
+                        Ok(event_id) => {
+                            tracing::info!("Published scheduled post {} as {}", post_id, event_id);
+                            scheduler.mark_published(&post_id);
+                            let _ = qt_thread.queue(move |mut qobject| {
+                                qobject.as_mut().scheduled_post_published(&QString::from(&post_id), &QString::from(&event_id));
+                            });
+                        }
+                        Err(e) => {
+                            tracing::warn!("Failed to publish scheduled post {}: {}", post_id, e);
+                            scheduler.mark_failed(&post_id, &e);
+                            let _ = qt_thread.queue(move |mut qobject| {
+                                qobject.as_mut().scheduled_post_failed(&QString::from(&post_id), &QString::from(&e));
+                            });
+                        }
+                    }
+                }
+                
+                std::thread::sleep(SCHEDULER_INTERVAL);
+            }
+        });
+    }
+    
+    /// Whether at least one relay is connected right now
+    pub fn is_online(&self) -> bool {
+        FEED_RUNTIME.block_on(relays_online())
+    }
+    
+    /// Queue a note with local and/or uploaded attachments for publishing
+    pub fn queue_note_with_media(mut self: Pin<&mut Self>, content: &QString, media_json: &QString, content_warning: &QString) -> QString {
+        let result = (|| {
+            let user_pk = self.user_pubkey.as_ref()
+                .and_then(|pk| PublicKey::parse(pk).ok())
+                .ok_or("User not initialized")?;
+            let content = content.to_string();
+            if content.trim().is_empty() {
+                return Err("Note is empty".to_string());
+            }
+            let sources: Vec<String> = serde_json::from_str(&media_json.to_string())
+                .map_err(|e| format!("Invalid media list: {}", e))?;
+            
+            let note = PendingNote {
+                content,
+                content_warning: content_warning.to_string(),
+                protected: self.post_protected,
+                media: sources.iter().map(|s| PendingMedia::from_source(s)).collect(),
+            };
+            let entry = outbox::add(&user_pk, OutboxItem::Note(note))?;
+            Ok::<(OutboxEntry, usize), String>((entry, outbox::len(&user_pk)))
+        })();
+        
+        match result {
+            Ok((entry, count)) => {
+                tracing::info!("Queued outbox entry {}", entry.id);
+                self.as_mut().set_outbox_count(count as i32);
+                self.as_mut().outbox_changed(count as i32);
+                // Goes out right away when online, otherwise on the next pass after reconnecting
+                self.as_mut().flush_outbox();
+                QString::from(&entry.to_json().to_string())
+            }
+            Err(e) => {
+                tracing::error!("Failed to queue note: {}", e);
+                QString::from(&serde_json::json!({ "error": e }).to_string())
+            }
         }
     }
     
-    // Fallback to meta description if no og:description
-    if description.is_none() {
-        let desc_regex = regex::Regex::new(r#"<meta[^>]*name=["']description["'][^>]*content=["']([^"']*?)["'][^>]*/?>"#).unwrap();
-        if let Some(cap) = desc_regex.captures(&html) {
-            description = Some(cap[1].to_string());
+    /// List the user's outbox
+    pub fn get_outbox(&self) -> QString {
+        let Some(user_pk) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) else {
+            return QString::from("[]");
+        };
+        let entries: Vec<serde_json::Value> = outbox::list(&user_pk).iter().map(|e| e.to_json()).collect();
+        QString::from(&serde_json::Value::from(entries).to_string())
+    }
+    
+    /// Retry the outbox now, including entries that gave up
+    pub fn retry_outbox(mut self: Pin<&mut Self>) {
+        if let Some(user_pk) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) {
+            outbox::reset_failed(&user_pk);
         }
+        self.as_mut().flush_outbox();
     }
     
-    // Get site name from URL if not in OG
-    if site_name.is_none() {
-        if let Ok(parsed) = url::Url::parse(url) {
-            site_name = parsed.host_str().map(|h| h.to_string());
+    /// Drop an outbox entry without publishing it
+    pub fn discard_outbox_entry(mut self: Pin<&mut Self>, entry_id: &QString) -> bool {
+        let Some(user_pk) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) else {
+            return false;
+        };
+        let removed = outbox::remove(&user_pk, &entry_id.to_string());
+        if removed {
+            let count = outbox::len(&user_pk) as i32;
+            self.as_mut().set_outbox_count(count);
+            self.as_mut().outbox_changed(count);
         }
+        removed
     }
     
-    // Only return if we have at least a title
-    if title.is_none() && description.is_none() && image.is_none() {
-        return Err("No metadata found".to_string());
+    /// Start the background loop that retries the outbox while it has entries
+    /// A newer loop (e.g. after switching accounts) supersedes older ones
+    fn start_outbox(self: Pin<&mut Self>) {
+        let Some(user_pk) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) else {
+            return;
+        };
+        let generation = OUTBOX_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            while OUTBOX_GENERATION.load(std::sync::atomic::Ordering::SeqCst) == generation {
+                std::thread::sleep(OUTBOX_INTERVAL);
+                if OUTBOX_GENERATION.load(std::sync::atomic::Ordering::SeqCst) == generation {
+                    process_outbox(&qt_thread, user_pk);
+                }
+            }
+        });
     }
     
-    // Decode HTML entities
-    let decode_html = |s: &str| {
-        s.replace("&amp;", "&")
-         .replace("&lt;", "<")
-         .replace("&gt;", ">")
-         .replace("&quot;", "\"")
-         .replace("&#39;", "'")
-         .replace("&nbsp;", " ")
-    };
+    /// Start listening for profile updates from the profile store
+    /// Loaded feed and thread notes by an updated author take the new name and
+    /// picture, and QML hears about it on note_author_updated
+    fn start_profile_updates(self: Pin<&mut Self>) {
+        let generation = PROFILE_UPDATE_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            let mut updates = profile_store::subscribe();
+            while PROFILE_UPDATE_GENERATION.load(std::sync::atomic::Ordering::SeqCst) == generation {
+                let received = FEED_RUNTIME.block_on(async {
+                    tokio::time::timeout(RELAY_WATCH_INTERVAL, updates.recv()).await
+                });
+                let pubkey = match received {
+                    Ok(Ok(pubkey)) => pubkey,
+                    Ok(Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped))) => {
+                        tracing::debug!("Skipped {} profile updates", skipped);
+                        continue;
+                    }
+                    Ok(Err(tokio::sync::broadcast::error::RecvError::Closed)) => break,
+                    Err(_) => continue,
+                };
+                let Some(profile) = profile_store::cached(&pubkey).and_then(|e| ProfileCache::from_event(&e).ok()) else {
+                    continue;
+                };
+                
+                let pubkey_hex = pubkey.to_hex();
+                let _ = qt_thread.queue(move |mut qobject| {
+                    let mut shown = None;
+                    {
+                        let mut rust = qobject.as_mut().rust_mut();
+                        for note in rust.notes.iter_mut().filter(|n| n.pubkey == pubkey_hex) {
+                            if note.apply_author_profile(&profile) {
+                                shown = Some((note.author_name.clone(), note.author_picture.clone()));
+                            }
+                        }
+                        for entry in rust.thread_notes.iter_mut().filter(|e| e.note.pubkey == pubkey_hex) {
+                            if entry.note.apply_author_profile(&profile) {
+                                shown = Some((entry.note.author_name.clone(), entry.note.author_picture.clone()));
+                            }
+                        }
+                    }
+                    if let Some((name, picture)) = shown {
+                        qobject.as_mut().note_author_updated(
+                            &QString::from(&pubkey_hex),
+                            &QString::from(&name),
+                            &QString::from(&picture.unwrap_or_default()),
+                        );
+                    }
+                });
+            }
+        });
+    }
     
-    let json = serde_json::json!({
-        "url": url,
-        "title": title.map(|t| decode_html(&t)),
-        "description": description.map(|d| decode_html(&d)),
-        "image": image,
-        "siteName": site_name,
-        "audio": audio,
-        "video": video,
-    });
+    /// Start the loop that reconnects dropped relays of the shared pool
+    /// A relay coming back gets the events it missed resent and triggers an outbox pass
+    fn start_relay_watch(self: Pin<&mut Self>) {
+        let user_pk = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok());
+        let generation = RELAY_WATCH_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            let mut watch = RelayWatch::new();
+            while RELAY_WATCH_GENERATION.load(std::sync::atomic::Ordering::SeqCst) == generation {
+                std::thread::sleep(RELAY_WATCH_INTERVAL);
+                
+                // Only watch a pool that exists; don't open one just for this
+                let manager = RELAY_MANAGER.read().unwrap().clone();
+                let Some(manager) = manager.filter(|m| m.is_connected()) else {
+                    continue;
+                };
+                let events = FEED_RUNTIME.block_on(watch.check(manager.client()));
+                if RELAY_WATCH_GENERATION.load(std::sync::atomic::Ordering::SeqCst) != generation {
+                    break;
+                }
+                
+                let mut recovered = Vec::new();
+                for event in events {
+                    match event {
+                        RelayEvent::Reconnected { url, attempts } => {
+                            recovered.push(url.clone());
+                            let _ = qt_thread.queue(move |mut qobject| {
+                                qobject.as_mut().relay_reconnected(&QString::from(&url), attempts as i32);
+                            });
+                        }
+                        RelayEvent::Failed { url, attempts, retry_in, error } => {
+                            let _ = qt_thread.queue(move |mut qobject| {
+                                qobject.as_mut().relay_failed(
+                                    &QString::from(&url),
+                                    attempts as i32,
+                                    retry_in.as_secs() as i32,
+                                    &QString::from(&error),
+                                );
+                            });
+                        }
+                    }
+                }
+                
+                // Resend events that a recovered relay missed, then anything else due
+                FEED_RUNTIME.block_on(async {
+                    for url in &recovered {
+                        publish::retry(manager.client(), Some(url.as_str())).await;
+                    }
+                    if publish::queued_count() > 0 {
+                        publish::retry(manager.client(), None).await;
+                    }
+                });
+                
+                if !recovered.is_empty() {
+                    if let Some(user_pk) = user_pk {
+                        process_outbox(&qt_thread, user_pk);
+                    }
+                }
+            }
+        });
+        
+        // Forward per-relay publish results to QML
+        let qt_thread = self.qt_thread();
+        std::thread::spawn(move || {
+            let mut reports = publish::subscribe();
+            while RELAY_WATCH_GENERATION.load(std::sync::atomic::Ordering::SeqCst) == generation {
+                let received = FEED_RUNTIME.block_on(async {
+                    tokio::time::timeout(RELAY_WATCH_INTERVAL, reports.recv()).await
+                });
+                match received {
+                    Ok(Ok(report)) => {
+                        let _ = qt_thread.queue(move |mut qobject| {
+                            qobject.as_mut().publish_status(
+                                &QString::from(&report.event_id),
+                                &QString::from(&report.to_json()),
+                            );
+                        });
+                    }
+                    Ok(Err(tokio::sync::broadcast::error::RecvError::Lagged(skipped))) => {
+                        tracing::debug!("Skipped {} publish reports", skipped);
+                    }
+                    Ok(Err(tokio::sync::broadcast::error::RecvError::Closed)) => break,
+                    Err(_) => {}
+                }
+            }
+        });
+    }
     
-    Ok(json.to_string())
-}
-
-/// Set the signer client for feed operations
-#[allow(dead_code)]
-pub fn set_feed_signer(signer: Option<SignerClient>) {
-    FEED_RUNTIME.block_on(async {
-        let mut feed_signer = FEED_SIGNER.lock().await;
-        *feed_signer = signer;
-    });
-}
-
-/// Set the user's nsec for local signing
-pub fn set_feed_nsec(nsec: Option<String>) {
-    let mut feed_nsec = FEED_NSEC.write().unwrap();
-    *feed_nsec = nsec;
-}
-
-/// Get the nsec for creating relay managers in other bridges
-#[allow(dead_code)]
-pub fn get_feed_nsec() -> Option<String> {
-    FEED_NSEC.read().unwrap().clone()
+    /// Run one outbox pass in the background
+    fn flush_outbox(self: Pin<&mut Self>) {
+        let Some(user_pk) = self.user_pubkey.as_ref().and_then(|pk| PublicKey::parse(pk).ok()) else {
+            return;
+        };
+        let qt_thread = self.qt_thread();
+        std::thread::spawn(move || process_outbox(&qt_thread, user_pk));
+    }
+    
+    /// Get the home feed kind allowlist
+    pub fn get_home_feed_kinds(&self) -> QString {
+        let config = Config::load();
+        QString::from(&serde_json::to_string(&config.home_feed_kinds).unwrap_or_else(|_| "[]".to_string()))
+    }
+    
+    /// Set the home feed kind allowlist and reload the following feed
+    pub fn set_home_feed_kinds(mut self: Pin<&mut Self>, kinds_json: &QString) {
+        let mut kinds: Vec<u16> = match serde_json::from_str(&kinds_json.to_string()) {
+            Ok(kinds) => kinds,
+            Err(e) => {
+                self.as_mut().error_occurred(&QString::from(&format!("Invalid kinds: {}", e)));
+                return;
+            }
+        };
+        kinds.sort();
+        kinds.dedup();
+        
+        let mut config = Config::load();
+        config.home_feed_kinds = kinds.clone();
+        config.home_feed_kinds_customized = true;
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save home feed kinds: {}", e);
+        }
+        
+        if let Some(manager) = RELAY_MANAGER.write().unwrap().as_mut() {
+            manager.set_home_feed_kinds(kinds.into_iter().map(Kind::from).collect());
+        }
+        
+        // The cached following feed was built with the old kinds
+        FEED_CACHE.remove("following");
+        
+        if self.current_feed.to_string() == "following" {
+            self.refresh();
+        }
+    }
+    
+    /// Get feed loading settings as JSON
+    pub fn get_feed_loading(&self) -> QString {
+        let config = Config::load();
+        QString::from(&serde_json::json!({
+            "pageSize": config.feed_page_size,
+            "windowHours": config.feed_window_hours,
+            "prefetchOnStartup": config.prefetch_on_startup,
+        }).to_string())
+    }
+    
+    /// Save feed loading settings and apply them to the next feed load
+    pub fn set_feed_loading(self: Pin<&mut Self>, page_size: i32, window_hours: i32, prefetch_on_startup: bool) {
+        let mut config = Config::load();
+        config.feed_page_size = (page_size.max(0) as u32).clamp(MIN_FEED_PAGE_SIZE, MAX_FEED_PAGE_SIZE);
+        config.feed_window_hours = window_hours.max(0) as u32;
+        config.prefetch_on_startup = prefetch_on_startup;
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save feed loading settings: {}", e);
+        }
+        
+        if let Some(manager) = RELAY_MANAGER.write().unwrap().as_mut() {
+            manager.set_feed_window_hours(config.feed_window_hours);
+        }
+        FEED_CACHE.clear();
+    }
+    
+    /// Open a note in the configured web viewer, with relay hints
+    pub fn open_note_in_browser(mut self: Pin<&mut Self>, note_id: &QString) -> QString {
+        let id_str = note_id.to_string();
+        
+        // Author and kind from the displayed notes, then the local cache
+        let known = self.notes.iter()
+            .chain(self.thread_notes.iter().map(|t| &t.note))
+            .find(|n| n.id == id_str)
+            .map(|n| (n.pubkey.clone(), n.kind))
+            .or_else(|| {
+                NostrDbManager::global().ok()?
+                    .get_event(&id_str)
+                    .map(|e| (e.pubkey, e.kind))
+            });
+        
+        let result = FEED_RUNTIME.block_on(async {
+            let event_id = EventId::parse(&id_str)
+                .map_err(|e| format!("Invalid event ID: {}", e))?;
+            
+            let relays: Vec<String> = {
+                let rm = RELAY_MANAGER.read().unwrap();
+                match rm.as_ref() {
+                    Some(manager) => manager.client().database()
+                        .event_seen_on_relays(&event_id)
+                        .await
+                        .ok()
+                        .flatten()
+                        .map(|set| set.iter().map(|r| r.to_string()).collect())
+                        .unwrap_or_default(),
+                    None => Vec::new(),
+                }
+            };
+            
+            let author = known.as_ref().and_then(|(pk, _)| PublicKey::parse(pk).ok());
+            let kind = known.as_ref().map(|(_, kind)| Kind::from(*kind));
+            
+            let url = links::note_web_url(&Config::load().web_viewer, event_id, author, kind, &relays)?;
+            links::open_in_browser(&url)?;
+            Ok::<String, String>(url)
+        });
+        
+        match result {
+            Ok(url) => {
+                tracing::info!("Opened note in browser: {}", url);
+                QString::from(&url)
+            }
+            Err(e) => {
+                tracing::error!("Failed to open note in browser: {}", e);
+                self.as_mut().error_occurred(&QString::from(&e));
+                QString::from("")
+            }
+        }
+    }
+    
+    /// Get a feed's saved visibility preferences
+    pub fn get_feed_preferences(&self, feed: &QString) -> QString {
+        let prefs = FeedPreferences::for_feed(&feed_preferences_key(&feed.to_string()));
+        QString::from(&serde_json::json!({
+            "showReposts": prefs.show_reposts,
+            "showReplies": prefs.show_replies,
+            "onlyMedia": prefs.only_media,
+            "languages": prefs.languages,
+            "availableLanguages": crate::nostr::feed::filter_languages(),
+        }).to_string())
+    }
+    
+    /// Save a feed's visibility preferences and drop its cached notes
+    pub fn set_feed_preferences(mut self: Pin<&mut Self>, feed: &QString, prefs_json: &QString) {
+        let feed_name = feed_preferences_key(&feed.to_string());
+        let value: serde_json::Value = match serde_json::from_str(&prefs_json.to_string()) {
+            Ok(v) => v,
+            Err(e) => {
+                self.as_mut().error_occurred(&QString::from(&format!("Invalid feed preferences: {}", e)));
+                return;
+            }
+        };
+        
+        let mut config = Config::load();
+        let prefs = config.feed_preferences.entry(feed_name.clone()).or_default();
+        if let Some(v) = value.get("showReposts").and_then(|v| v.as_bool()) {
+            prefs.show_reposts = v;
+        }
+        if let Some(v) = value.get("showReplies").and_then(|v| v.as_bool()) {
+            prefs.show_replies = v;
+        }
+        if let Some(v) = value.get("onlyMedia").and_then(|v| v.as_bool()) {
+            prefs.only_media = v;
+        }
+        if let Some(langs) = value.get("languages").and_then(|v| v.as_array()) {
+            prefs.languages = langs.iter()
+                .filter_map(|l| l.as_str())
+                .map(|l| l.trim().to_lowercase())
+                .filter(|l| !l.is_empty())
+                .collect();
+        }
+        
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+        FEED_CACHE.remove(&feed_name);
+    }
+    
+    /// Get the configured web viewer
+    pub fn get_web_viewer(&self) -> QString {
+        QString::from(&Config::load().web_viewer)
+    }
+    
+    /// Set the web viewer
+    pub fn set_web_viewer(self: Pin<&mut Self>, viewer: &QString) {
+        let viewer = viewer.to_string().trim().to_string();
+        let mut config = Config::load();
+        config.web_viewer = if viewer.is_empty() { links::DEFAULT_WEB_VIEWER.to_string() } else { viewer };
+        
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        } else {
+            tracing::info!("Web viewer set to: {}", config.web_viewer);
+        }
+    }
+    
+    /// Write a deterministic snapshot of the current feed to a file
+    pub fn export_feed_snapshot(self: Pin<&mut Self>, file_path: &QString, redact_pubkeys: bool) -> QString {
+        let path = file_path.to_string();
+        let path = path.strip_prefix("file://").unwrap_or(&path).to_string();
+        let feed_name = self.current_feed.to_string();
+        let notes = self.notes.clone();
+        let list_authors_count = list_feed_authors(self.user_pubkey.as_ref(), &feed_name).len();
+        
+        let result = FEED_RUNTIME.block_on(async {
+            let rm = RELAY_MANAGER.read().unwrap();
+            let manager = rm.as_ref().ok_or("Not connected to relays")?;
+            
+            // Look up which relays delivered each event
+            let database = manager.client().database();
+            let mut seen_on = std::collections::HashMap::new();
+            for note in notes.iter() {
+                let Ok(event_id) = EventId::from_hex(&note.id) else { continue };
+                if let Ok(Some(relays)) = database.event_seen_on_relays(&event_id).await {
+                    seen_on.insert(note.id.clone(), relays.iter().map(|r| r.to_string()).collect());
+                }
+            }
+            
+            // Same kinds and page size the feed's fetch requests
+            let feed_type = FeedType::from_str(&feed_name);
+            let home_kinds: Vec<u16> = manager.home_feed_kinds().iter().map(|k| k.as_u16()).collect();
+            let (kinds, authors_count) = match feed_type {
+                FeedType::Following => (home_kinds, manager.following().len()),
+                FeedType::Replies => (vec![1], manager.following().len()),
+                FeedType::Global => (vec![1], 0),
+                FeedType::ReadsFollowing => (vec![30023], manager.following().len()),
+                FeedType::ReadsGlobal => (vec![30023], 0),
+                FeedType::Media => (MEDIA_FEED_KINDS.to_vec(), manager.following().len()),
+                FeedType::List => (home_kinds, list_authors_count),
+                FeedType::Hashtags => (vec![1], 0),
+            };
+            let limit = match feed_type {
+                FeedType::Media => media_fetch_limit(feed_page_size()),
+                _ => feed_page_size(),
+            };
+            let filters = SnapshotFilters {
+                feed_type: feed_name.clone(),
+                kinds,
+                authors_count,
+                limit,
+            };
+            
+            let snapshot = FeedSnapshot::build(&notes, &seen_on, filters, redact_pubkeys);
+            std::fs::write(&path, snapshot.to_json_pretty())
+                .map_err(|e| format!("Failed to write snapshot: {}", e))?;
+            
+            Ok::<usize, String>(snapshot.note_count)
+        });
+        
+        match result {
+            Ok(count) => {
+                tracing::info!("Wrote feed snapshot with {} notes to {}", count, path);
+                QString::from(&serde_json::json!({ "path": path, "noteCount": count }).to_string())
+            }
+            Err(e) => {
+                tracing::error!("Failed to export feed snapshot: {}", e);
+                QString::from(&serde_json::json!({ "error": e }).to_string())
+            }
+        }
+    }
+    
+    /// Check NIP-70 support for the connected relays in the background
+    pub fn check_protected_support(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        
+        limits::spawn_limited(move || {
+            let results = FEED_RUNTIME.block_on(async {
+                let urls: Vec<String> = {
+                    let rm = RELAY_MANAGER.read().unwrap();
+                    match rm.as_ref() {
+                        Some(manager) => manager.client().relays().await
+                            .keys()
+                            .map(|url| url.to_string())
+                            .collect(),
+                        None => Vec::new(),
+                    }
+                };
+                relay_info::fetch_relay_infos(&urls).await
+            });
+            
+            let relays: Vec<serde_json::Value> = results.into_iter()
+                .map(|(url, info)| match info {
+                    Ok(info) => serde_json::json!({
+                        "url": url,
+                        "supported": info.supports_nip(70),
+                        "error": null,
+                    }),
+                    Err(e) => serde_json::json!({
+                        "url": url,
+                        "supported": false,
+                        "error": e,
+                    }),
+                })
+                .collect();
+            
+            let json = serde_json::Value::Array(relays).to_string();
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().protected_support_checked(&QString::from(&json));
+            });
+        });
+    }
+
+    /// Build a catch up summary in the background
+    pub fn load_catch_up(self: Pin<&mut Self>) {
+        let Some(user_pubkey) = self.user_pubkey.clone() else {
+            tracing::warn!("Cannot build catch up summary: no user pubkey");
+            return;
+        };
+
+        let qt_thread = self.qt_thread();
+        let since = catchup::session_since();
+
+        limits::spawn_limited(move || {
+            let result = FEED_RUNTIME.block_on(async {
+                let pk = PublicKey::parse(&user_pubkey)
+                    .map_err(|e| format!("Invalid pubkey: {}", e))?;
+
+                // Sync the window into the local database first
+                let following: std::collections::HashSet<String> = {
+                    let rm = RELAY_MANAGER.read().unwrap();
+                    let Some(manager) = rm.as_ref() else {
+                        return Err("Relay manager not initialized".to_string());
+                    };
+
+                    let events = manager
+                        .fetch_catch_up_events(&pk, Timestamp::from(since as u64))
+                        .await?;
+                    let events: Vec<Event> = events.into_iter().collect();
+
+                    if let Ok(db) = NostrDbManager::global() {
+                        if let Err(e) = db.ingest_events(&events) {
+                            tracing::warn!("Failed to store catch up events: {}", e);
+                        }
+                    }
+
+                    manager.following().iter().map(|p| p.to_hex()).collect()
+                };
+
+                // Summarize from what is stored locally
+                let db = NostrDbManager::global()?;
+                let events = db.query_events(&[1, 3, 9735], since, 5000)?;
+
+                // Followers already on record aren't new, even if they edited their list
+                db.record_followers(&pk.to_hex(), &catchup::follower_lists(&events, &user_pubkey));
+                let known_followers = db.get_notification_state(&pk.to_hex()).followers;
+
+                Ok(catchup::summarize(&events, &user_pubkey, &following, &known_followers, since))
+            });
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok(summary) => {
+                        tracing::info!(
+                            "Catch up ready: {} zapped posts, {} new followers, {} threads",
+                            summary.top_zapped.len(),
+                            summary.new_followers.len(),
+                            summary.active_threads.len()
+                        );
+                        qobject.as_mut().catch_up_ready(&QString::from(&summary.to_json()));
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to build catch up summary: {}", e);
+                        qobject.as_mut().error_occurred(&QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+}
+
+/// The configured Tenor API key, if any
+fn tenor_api_key() -> Option<String> {
+    let key = Config::load().tenor_api_key.filter(|key| !key.is_empty());
+    if key.is_none() {
+        tracing::warn!("Tenor API key not configured");
+    }
+    key
+}
+
+/// JSON for QML from a Tenor call: the payload, or {"error": ...}
+fn gif_result_json(what: &str, result: Result<String, String>) -> QString {
+    match result {
+        Ok(json) => QString::from(&json),
+        Err(e) => {
+            tracing::error!("Tenor {} failed: {}", what, e);
+            QString::from(&serde_json::json!({ "error": e }).to_string())
+        }
+    }
+}
+
+/// Build a NIP-36 content-warning tag (None when no reason was given)
+fn content_warning_tag(reason: &str) -> Option<Tag> {
+    let reason = reason.trim();
+    if reason.is_empty() {
+        return None;
+    }
+    Tag::parse(["content-warning", reason]).ok()
+}
+
+/// Get the drafts directory, creating it if necessary
+fn get_drafts_directory() -> Result<std::path::PathBuf, String> {
+    let data_dir = dirs::data_local_dir()
+        .ok_or("Could not determine data directory")?
+        .join("pleb-client")
+        .join("drafts");
+    
+    if !data_dir.exists() {
+        std::fs::create_dir_all(&data_dir)
+            .map_err(|e| format!("Failed to create drafts directory: {}", e))?;
+    }
+    
+    Ok(data_dir)
+}
+
+/// Profiles for thread notes' authors and the people they reply to
+async fn fetch_thread_profiles<'a>(
+    manager: &RelayManager,
+    events: impl Iterator<Item = &'a Event> + Clone,
+) -> std::collections::HashMap<String, ProfileCache> {
+    let mut pubkeys: std::collections::HashSet<PublicKey> = events.clone().map(|e| e.pubkey).collect();
+    for pk_hex in crate::nostr::feed::extract_reply_to_pubkeys(events) {
+        if let Ok(pk) = PublicKey::parse(&pk_hex) {
+            pubkeys.insert(pk);
+        }
+    }
+    let pubkeys: Vec<PublicKey> = pubkeys.into_iter().collect();
+    
+    let profiles = manager.fetch_profiles(&pubkeys).await.unwrap_or_default();
+    let mut profile_map = std::collections::HashMap::new();
+    for profile_event in profiles.iter() {
+        if let Ok(metadata) = Metadata::from_json(&profile_event.content) {
+            profile_map.insert(profile_event.pubkey.to_hex(), ProfileCache::from_metadata(&metadata));
+        }
+    }
+    profile_map
+}
+
+/// Set the signer client for feed operations
+#[allow(dead_code)]
+pub fn set_feed_signer(signer: Option<SignerClient>) {
+    FEED_RUNTIME.block_on(async {
+        let mut feed_signer = FEED_SIGNER.lock().await;
+        *feed_signer = signer;
+    });
+}
+
+/// Set the user's nsec for local signing
+pub fn set_feed_nsec(nsec: Option<String>) {
+    let mut feed_nsec = FEED_NSEC.write().unwrap();
+    *feed_nsec = nsec;
+}
+
+/// Entry counts, rough sizes and hit ratios of the feed view caches
+pub fn view_cache_stats() -> Vec<cache_stats::CacheStats> {
+    fn string_map(name: &'static str, cache: &LruCache<String, String>) -> cache_stats::CacheStats {
+        cache.sweep();
+        let (entries, bytes) = cache.usage(|k, v| k.len() + v.len());
+        cache_stats::CacheStats::new(name, entries, bytes).with_capacity(cache.capacity())
+    }
+    
+    // Feed entries are counted in feeds against the capacity, sized by note content
+    FEED_CACHE.sweep();
+    let (feeds, bytes) = FEED_CACHE.usage(|_, notes| notes.iter().map(|note| note.content.len()).sum());
+    
+    vec![
+        cache_stats::CacheStats::new(cache_stats::FEED, feeds, bytes).with_capacity(FEED_CACHE.capacity()),
+        string_map(cache_stats::EMBEDDED_EVENTS, &EMBEDDED_EVENT_CACHE),
+        string_map(cache_stats::EMBEDDED_PROFILES, &EMBEDDED_PROFILE_CACHE),
+        string_map(cache_stats::LINK_PREVIEWS, &LINK_PREVIEW_CACHE),
+        string_map(cache_stats::NOTE_STATS, &NOTE_STATS_CACHE),
+        profile_store::cache_stats(),
+        {
+            let (pages, bytes) = tenor::cache_usage();
+            cache_stats::CacheStats::new(cache_stats::GIFS, pages, bytes)
+        },
+    ]
+}
+
+/// Drop the feed view caches (feeds, embeds, previews, stats, profiles); they refill on demand
+pub fn clear_view_caches() {
+    FEED_CACHE.clear();
+    EMBEDDED_EVENT_CACHE.clear();
+    EMBEDDED_PROFILE_CACHE.clear();
+    LINK_PREVIEW_CACHE.clear();
+    NOTE_STATS_CACHE.clear();
+    profile_store::clear();
+    tenor::clear_cache();
+}
+
+/// Clear all feed globals left over from the previous account
+/// Disconnects and drops the shared relay manager and stops the scheduler loop
+pub fn reset_feed_state() {
+    set_feed_signer(None);
+    set_feed_nsec(None);
+    
+    // Any running scheduler, outbox and relay watch loops and thread watcher belong to the old account
+    SCHEDULER_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    OUTBOX_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    RELAY_WATCH_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    THREAD_WATCH_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    PROFILE_UPDATE_GENERATION.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    profile_store::stop_watching();
+    
+    let manager = RELAY_MANAGER.write().unwrap().take();
+    if let Some(mut manager) = manager {
+        FEED_RUNTIME.block_on(manager.disconnect());
+    }
+    relay_service::reset();
+    
+    clear_view_caches();
+    if let Ok(mut pending) = PENDING_EMBEDS.write() { pending.clear(); }
+    if let Ok(mut pending) = PENDING_NIP05.write() { pending.clear(); }
+    if let Ok(mut pending) = PENDING_STATS.write() { pending.clear(); }
+    
+    if let Ok(mut contacts) = crate::nostr::contacts::GLOBAL_CONTACT_MANAGER.lock() {
+        contacts.reset(None);
+    }
+}
+
+/// Get the nsec for creating relay managers in other bridges
+#[allow(dead_code)]
+pub fn get_feed_nsec() -> Option<String> {
+    FEED_NSEC.read().unwrap().clone()
+}
+
+/// Sign an event for the logged-in user, using the signer if connected and the nsec otherwise
+pub async fn sign_event_builder(builder: EventBuilder, user_pk: PublicKey) -> Result<Event, String> {
+    let builder = handlers::tag_client(builder);
+    let signer = FEED_SIGNER.lock().await;
+    if let Some(s) = signer.as_ref() {
+        let unsigned = builder.build(user_pk);
+        
+        let unsigned_json = serde_json::to_string(&unsigned)
+            .map_err(|e| format!("Serialization failed: {}", e))?;
+        
+        let signed_result = s.sign_event(&unsigned_json).await
+            .map_err(|e| format!("Signing failed: {}", e))?;
+        
+        serde_json::from_str(&signed_result.event_json)
+            .map_err(|e| format!("Failed to parse signed event: {}", e))
+    } else if let Some(nsec) = FEED_NSEC.read().unwrap().as_ref() {
+        let secret_key = SecretKey::parse(nsec)
+            .map_err(|e| format!("Invalid nsec: {}", e))?;
+        let keys = Keys::new(secret_key);
+        
+        builder.sign_with_keys(&keys)
+            .map_err(|e| format!("Failed to sign: {}", e))
+    } else {
+        Err("No signing capability available".to_string())
+    }
+}
+
+/// Sign (if needed) and send a scheduled post through the relay manager
+async fn publish_scheduled_post(post: &ScheduledPost, user_pk: PublicKey) -> Result<String, String> {
+    let event: Event = match &post.signed_event_json {
+        Some(json) => serde_json::from_str(json)
+            .map_err(|e| format!("Invalid signed event: {}", e))?,
+        None => {
+            let tags: Vec<Tag> = post.tags.iter()
+                .filter_map(|t| Tag::parse(t.clone()).ok())
+                .collect();
+            let builder = EventBuilder::new(Kind::from(post.kind), &post.content).tags(tags);
+            sign_event_builder(builder, user_pk).await?
+        }
+    };
+    
+    let rm = RELAY_MANAGER.read().unwrap();
+    let manager = rm.as_ref().ok_or("Not connected to relays")?;
+    publish::send(manager.client(), &event).await?;
+    
+    Ok(event.id.to_hex())
+}
+
+/// Stats JSON for the cache: totals plus the user's own interactions
+/// (hasLiked, myReaction, hasReposted, zappedSats); see render_note_stats
+fn note_stats_json(
+    stats: &NoteStats,
+    mine: Option<&MyInteractions>,
+) -> String {
+    let mine = mine.cloned().unwrap_or_default();
+    serde_json::json!({
+        "reactions": stats.reactions,
+        "reactionEmoji": emoji::reaction_urls(stats.reactions.keys()),
+        "zapAmount": stats.zap_amount,
+        "zapCount": stats.zap_count,
+        "replyCount": stats.reply_count,
+        "repostCount": stats.repost_count,
+        "quoteCount": stats.quote_count,
+        "hasLiked": mine.reaction.is_some(),
+        "myReaction": mine.reaction.unwrap_or_default(),
+        "hasReposted": mine.reposted,
+        "zappedSats": mine.zapped_sats
+    }).to_string()
+}
+
+/// Stats for notes without any activity (or whose stats could not be fetched)
+/// Cached stats JSON as handed to QML, with the zap total in the current
+/// display currency; the cache holds sats only so a rate or currency change
+/// shows up on the next render
+fn render_note_stats(json: &str) -> String {
+    let Ok(mut stats) = serde_json::from_str::<serde_json::Value>(json) else {
+        return json.to_string();
+    };
+    let sats = stats["zapAmount"].as_u64().unwrap_or(0);
+    stats["zapAmountFiat"] = serde_json::Value::from(fiat::fiat_label(sats).unwrap_or_default());
+    stats.to_string()
+}
+
+const EMPTY_NOTE_STATS: &str = r#"{"reactions":{},"zapAmount":0,"zapCount":0,"replyCount":0,"repostCount":0,"quoteCount":0}"#;
+
+/// Fetch stats for a batch of notes with one set of relay queries per
+/// STATS_BATCH_SIZE notes, cache them and emit note_stats_ready for each
+fn fetch_stats_batch(qt_thread: &cxx_qt::CxxQtThread<qobject::FeedController>, note_ids: Vec<String>, me: Option<PublicKey>) {
+    for chunk in note_ids.chunks(STATS_BATCH_SIZE) {
+        let event_ids: Vec<EventId> = chunk.iter()
+            .filter_map(|id| EventId::from_hex(id).ok())
+            .collect();
+        
+        let result: Result<Vec<(String, String)>, String> = FEED_RUNTIME.block_on(async {
+            if event_ids.is_empty() {
+                return Ok(vec![]);
+            }
+            
+            let rm = RELAY_MANAGER.read().unwrap();
+            let manager = rm.as_ref().ok_or_else(|| "Not connected to relays".to_string())?;
+            
+            // One query per kind for the whole batch, with the user's own interactions
+            let (stats, mine) = tokio::join!(
+                manager.fetch_note_stats(&event_ids),
+                fetch_my_interactions(manager, &event_ids, me.as_ref())
+            );
+            let stats = stats?;
+            
+            Ok(stats.iter()
+                .map(|(note_id, note_stats)| (note_id.clone(), note_stats_json(note_stats, mine.get(note_id))))
+                .collect())
+        });
+        
+        let mut fetched: std::collections::HashMap<String, String> = match result {
+            Ok(stats) => stats.into_iter().collect(),
+            Err(e) => {
+                // Cache empty results to prevent repeated failed fetches
+                tracing::warn!("Failed to fetch stats for {} notes: {}", chunk.len(), e);
+                std::collections::HashMap::new()
+            }
+        };
+        
+        let mut ready = Vec::with_capacity(chunk.len());
+        for note_id in chunk {
+            let json = fetched.remove(note_id).unwrap_or_else(|| EMPTY_NOTE_STATS.to_string());
+            NOTE_STATS_CACHE.insert(note_id.clone(), json.clone());
+            ready.push((note_id.clone(), render_note_stats(&json)));
+        }
+        if let Ok(mut pending) = PENDING_STATS.write() {
+            for note_id in chunk {
+                pending.remove(note_id);
+            }
+        }
+        
+        let _ = qt_thread.queue(move |mut qobject| {
+            for (note_id, json) in ready {
+                qobject.as_mut().note_stats_ready(&QString::from(&note_id), &QString::from(&json));
+            }
+        });
+    }
+}
+
+/// The user's own interactions with notes; empty if not logged in or the query fails
+async fn fetch_my_interactions(manager: &RelayManager, note_ids: &[EventId], me: Option<&PublicKey>) -> std::collections::HashMap<String, MyInteractions> {
+    let Some(me) = me else {
+        return std::collections::HashMap::new();
+    };
+    manager.fetch_my_interactions(note_ids, me).await.unwrap_or_else(|e| {
+        tracing::debug!("Failed to fetch own interactions: {}", e);
+        std::collections::HashMap::new()
+    })
+}
+
+/// Record one of the user's interactions in the cached stats right away, so
+/// buttons turn active without waiting for a refetch. Returns the updated JSON
+/// Notes without cached stats get stub stats marked partial, which aren't
+/// cached so the real ones are still fetched
+fn mark_own_interaction(note_id: &str, update: impl Fn(&mut serde_json::Value)) -> Option<String> {
+    let apply = |json: &str| {
+        let mut stats: serde_json::Value = serde_json::from_str(json)
+            .unwrap_or_else(|_| serde_json::json!({ "reactions": {}, "zapAmount": 0, "zapCount": 0, "partial": true }));
+        update(&mut stats);
+        stats.to_string()
+    };
+    NOTE_STATS_CACHE.update_existing(note_id, |json| *json = apply(json))
+        .or_else(|| Some(apply("")))
+        .map(|json| render_note_stats(&json))
+}
+
+/// Add a zap the user just paid to the cached stats
+fn mark_zapped(note_id: &str, amount_sats: u64) -> Option<String> {
+    mark_own_interaction(note_id, |stats| {
+        let zapped = stats["zappedSats"].as_u64().unwrap_or(0) + amount_sats;
+        stats["zappedSats"] = serde_json::Value::from(zapped);
+    })
+}
+
+/// Whether the shared relay pool has a live connection
+async fn relays_online() -> bool {
+    match shared_relay_manager().await {
+        Ok(manager) => manager.connected_relay_count().await > 0,
+        Err(_) => false,
+    }
+}
+
+/// Upload a queued attachment to the configured Blossom server
+async fn upload_pending_media(media: &mut PendingMedia) -> Result<(), String> {
+    let nsec = FEED_NSEC.read().unwrap().clone()
+        .ok_or("No signing keys available for uploads")?;
+    let secret_key = SecretKey::parse(&nsec)
+        .map_err(|e| format!("Invalid nsec: {}", e))?;
+    let keys = Keys::new(secret_key);
+    
+    let config = Config::load();
+    let response = blossom::upload_media(&config.blossom_server, &media.path, &keys).await?;
+    
+    media.url = Some(response.url);
+    media.sha256 = Some(response.sha256);
+    media.size = Some(response.size);
+    if response.mime_type.is_some() {
+        media.mime_type = response.mime_type;
+    }
+    Ok(())
+}
+
+/// Sign a queued note now and send it
+async fn publish_pending_note(note: &PendingNote, user_pk: PublicKey) -> Result<String, String> {
+    let event = sign_event_builder(note.builder()?, user_pk).await?;
+    let manager = shared_relay_manager().await?;
+    let result = publish::send(manager.client(), &event).await;
+    // The outbox re-signs and resends the whole note if nothing got through
+    let accepted = result.as_ref().map(|report| report.accepted()).unwrap_or(0);
+    if accepted == 0 {
+        publish::forget(&event.id);
+    }
+    result?;
+    if accepted == 0 {
+        return Err("No relay accepted the note".to_string());
+    }
+    Ok(event.id.to_hex())
+}
+
+/// Pay a zap for a note through the connected NWC wallet; returns the preimage
+async fn pay_zap(note_id_str: &str, amount_sats: u64, comment_str: &str) -> Result<String, String> {
+    // Check if NWC is connected
+    let mut nwc = GLOBAL_NWC_MANAGER.lock().await;
+    if !nwc.is_connected() {
+        return Err("NWC wallet not connected. Please connect your wallet in Settings.".to_string());
+    }
+    
+    // Get signing keys
+    let nsec_opt = FEED_NSEC.read().unwrap().clone();
+    let keys = match nsec_opt.as_ref() {
+        Some(nsec) => {
+            let secret_key = SecretKey::parse(nsec)
+                .map_err(|e| format!("Invalid nsec: {}", e))?;
+            Keys::new(secret_key)
+        }
+        None => {
+            return Err("No signing keys available".to_string());
+        }
+    };
+    
+    // Get relay manager for fetching note author
+    let manager = shared_relay_manager().await?;
+    let client = manager.client();
+    
+    // Parse note ID
+    let event_id = EventId::parse(note_id_str)
+        .or_else(|_| EventId::from_bech32(note_id_str))
+        .map_err(|e| format!("Invalid note ID: {}", e))?;
+    
+    // Fetch the note to get author's pubkey and find their lud16
+    let note_filter = Filter::new()
+        .id(event_id.clone())
+        .limit(1);
+    
+    let note_events = client.fetch_events(note_filter, std::time::Duration::from_secs(10)).await
+        .map_err(|e| format!("Failed to fetch note: {}", e))?;
+    
+    let note_event = note_events.into_iter().next()
+        .ok_or("Note not found")?;
+    
+    let author_pubkey = note_event.pubkey.clone();
+    
+    // Fetch author's profile to get their lightning address
+    let profile_events = profile_store::get(client, &[author_pubkey]).await;
+    
+    let profile_event = profile_events.into_iter().next()
+        .ok_or("Author profile not found")?;
+    
+    // Parse metadata to get lud16
+    let metadata: Metadata = serde_json::from_str(&profile_event.content)
+        .map_err(|e| format!("Failed to parse profile metadata: {}", e))?;
+    
+    let lud16 = metadata.lud16
+        .ok_or("Author doesn't have a lightning address (lud16)")?;
+    
+    if lud16.is_empty() {
+        return Err("Author's lightning address is empty".to_string());
+    }
+    
+    // Get relay URLs for zap request: a zap goal's own relays (NIP-75), else the user's
+    let relays: Vec<String> = ZapGoal::from_event(&note_event)
+        .map(|goal| goal.relays)
+        .filter(|relays| !relays.is_empty())
+        .unwrap_or_else(|| crate::nostr::relay::configured_relays()
+            .into_iter()
+            .take(3) // Include up to 3 relays
+            .collect());
+    
+    // Perform the zap
+    zap::zap(
+        &mut *nwc,
+        &keys,
+        &author_pubkey,
+        &lud16,
+        Some(&event_id),
+        amount_sats,
+        comment_str,
+        &relays,
+    ).await
+}
+
+/// Send an event that was signed while offline
+async fn publish_pending_event(pending: &PendingEvent) -> Result<String, String> {
+    let event = pending.event()?;
+    let manager = shared_relay_manager().await?;
+    let report = publish::send(manager.client(), &event).await?;
+    if report.accepted() == 0 {
+        // Stays in the outbox; a new attempt resends the same event
+        publish::forget(&event.id);
+        return Err(format!("No relay accepted the {}", pending.label.to_lowercase()));
+    }
+    Ok(event.id.to_hex())
+}
+
+/// Sign a builder and send it, or keep the signed event in the outbox while
+/// no relay is reachable. Returns the event and whether it was queued
+async fn send_or_queue(builder: EventBuilder, user_pk: PublicKey, label: &str) -> Result<(Event, bool), String> {
+    let event = sign_event_builder(builder, user_pk).await?;
+    if relays_online().await {
+        let manager = shared_relay_manager().await?;
+        if let Ok(report) = publish::send(manager.client(), &event).await {
+            if report.accepted() > 0 {
+                return Ok((event, false));
+            }
+        }
+        publish::forget(&event.id);
+    }
+    outbox::add(&user_pk, OutboxItem::Event(PendingEvent::new(label, &event)))?;
+    tracing::info!("{} {} queued in the outbox", label, event.id);
+    Ok((event, true))
+}
+
+/// Upload and publish every waiting outbox entry, reporting progress to QML
+/// Does nothing while offline or while another pass is running
+fn process_outbox(qt_thread: &cxx_qt::CxxQtThread<qobject::FeedController>, user_pk: PublicKey) {
+    use std::sync::atomic::Ordering;
+    
+    let entries: Vec<OutboxEntry> = outbox::list(&user_pk).into_iter()
+        .filter(|e| e.status != OutboxStatus::Failed)
+        .collect();
+    if entries.is_empty() || OUTBOX_FLUSHING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+    
+    if FEED_RUNTIME.block_on(relays_online()) {
+        for entry in entries {
+            flush_outbox_entry(qt_thread, user_pk, entry);
+        }
+    }
+    
+    OUTBOX_FLUSHING.store(false, Ordering::SeqCst);
+}
+
+/// Take one outbox entry through upload and publish
+fn flush_outbox_entry(qt_thread: &cxx_qt::CxxQtThread<qobject::FeedController>, user_pk: PublicKey, mut entry: OutboxEntry) {
+    let report = |entry_id: &str, status: &str, detail: &str| {
+        let (entry_id, status, detail) = (entry_id.to_string(), status.to_string(), detail.to_string());
+        let _ = qt_thread.queue(move |mut qobject| {
+            qobject.as_mut().outbox_entry_status(&QString::from(&entry_id), &QString::from(&status), &QString::from(&detail));
+        });
+    };
+    
+    let result = (|| {
+        if let OutboxItem::Note(note) = &entry.item {
+            let pending: Vec<usize> = (0..note.media.len())
+                .filter(|&i| !note.media[i].is_uploaded())
+                .collect();
+            if !pending.is_empty() {
+                entry.status = OutboxStatus::Uploading;
+                report(&entry.id, entry.status.as_str(), "");
+                for index in pending {
+                    if let OutboxItem::Note(note) = &mut entry.item {
+                        FEED_RUNTIME.block_on(upload_pending_media(&mut note.media[index]))?;
+                    }
+                    // Keep the URL even if a later step fails, so the file isn't uploaded again
+                    outbox::update(&user_pk, &entry);
+                }
+            }
+        }
+        
+        entry.status = OutboxStatus::Publishing;
+        report(&entry.id, entry.status.as_str(), "");
+        match &entry.item {
+            OutboxItem::Note(note) => FEED_RUNTIME.block_on(publish_pending_note(note, user_pk)),
+            OutboxItem::Event(pending) => FEED_RUNTIME.block_on(publish_pending_event(pending)),
+            OutboxItem::Zap(zap) => FEED_RUNTIME.block_on(pay_zap(&zap.note_id, zap.amount_sats, &zap.comment)),
+        }
+    })();
+    
+    match result {
+        Ok(result) => {
+            tracing::info!("Published outbox entry {}", entry.id);
+            outbox::remove(&user_pk, &entry.id);
+            let count = outbox::len(&user_pk) as i32;
+            report(&entry.id, "published", &result);
+            let item = entry.item.clone();
+            let _ = qt_thread.queue(move |mut qobject| {
+                match item {
+                    OutboxItem::Note(_) => qobject.as_mut().note_posted(&QString::from(&result)),
+                    OutboxItem::Zap(zap) => {
+                        qobject.as_mut().zap_success(&QString::from(&zap.note_id), zap.amount_sats as i64);
+                        if let Some(stats) = mark_zapped(&zap.note_id, zap.amount_sats) {
+                            qobject.as_mut().note_stats_ready(&QString::from(&zap.note_id), &QString::from(&stats));
+                        }
+                    }
+                    OutboxItem::Event(_) => {}
+                }
+                qobject.as_mut().set_outbox_count(count);
+                qobject.as_mut().outbox_changed(count);
+            });
+        }
+        Err(e) => {
+            tracing::warn!("Outbox entry {} failed: {}", entry.id, e);
+            entry.fail(&e);
+            outbox::update(&user_pk, &entry);
+            report(&entry.id, entry.status.as_str(), &e);
+        }
+    }
+}
+
+/// Get the shared, already-connected relay manager used by all bridges
+/// Opens the pool (with signing keys if available) on first use
+pub async fn shared_relay_manager() -> Result<RelayManager, String> {
+    relay_service::acquire(create_authenticated_relay_manager).await
 }
 
 /// Create a RelayManager with signing keys if available
@@ -0,0 +1,129 @@
+//! Image provider - serves `image://media/...` sources from the disk cache
+//!
+//! QML loads remote avatars and media through this provider instead of
+//! fetching them itself, so every image lands in the size-capped media cache
+//! and is read from disk on later loads. Blossom servers that require
+//! authorization for downloads get a signed kind 24242 "get" header, and
+//! `image://media/blurhash/<hash>` decodes a blurhash into a placeholder.
+//!
+//! The provider itself is a small C++ QQuickImageProvider (cpp/) that calls
+//! `media_image_load` on Qt's image loader threads.
+
+#[cxx_qt::bridge]
+pub mod ffi {
+    /// Image handed back to the C++ provider
+    /// `data` is encoded file bytes, or raw RGBA8 pixels when `rgba` is set
+    struct MediaImage {
+        data: Vec<u8>,
+        width: i32,
+        height: i32,
+        rgba: bool,
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qqmlapplicationengine.h");
+        type QQmlApplicationEngine = cxx_qt_lib::QQmlApplicationEngine;
+
+        include!("media_image_provider.h");
+        /// Register the "media" image provider on the engine
+        fn register_media_image_provider(engine: Pin<&mut QQmlApplicationEngine>);
+    }
+
+    extern "Rust" {
+        fn media_image_load(id: &str, width: i32, height: i32) -> MediaImage;
+    }
+}
+
+use nostr_sdk::prelude::*;
+
+use crate::bridge::feed_bridge::get_feed_nsec;
+use crate::core::media_cache;
+use crate::nostr::blossom;
+
+/// Largest side of a decoded blurhash; Qt scales it up smoothly
+const BLURHASH_SIZE: i32 = 32;
+
+lazy_static::lazy_static! {
+    static ref PROVIDER_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+}
+
+pub use ffi::register_media_image_provider;
+
+/// Load the image for a provider id (an encoded URL, or `blurhash/<encoded hash>`)
+fn media_image_load(id: &str, width: i32, height: i32) -> ffi::MediaImage {
+    if let Some(hash) = id.strip_prefix("blurhash/") {
+        return blurhash_image(&decode_id(hash), width, height);
+    }
+
+    let url = decode_id(id);
+    match PROVIDER_RUNTIME.block_on(load(&url)) {
+        Ok(data) => ffi::MediaImage { data, width: 0, height: 0, rgba: false },
+        Err(e) => {
+            tracing::debug!("Image provider: {} failed: {}", url, e);
+            empty_image()
+        }
+    }
+}
+
+/// Qt may hand the id over partly decoded; decoding once more is harmless for URLs
+fn decode_id(id: &str) -> String {
+    urlencoding::decode(id)
+        .map(|s| s.into_owned())
+        .unwrap_or_else(|_| id.to_string())
+}
+
+fn empty_image() -> ffi::MediaImage {
+    ffi::MediaImage { data: Vec::new(), width: 0, height: 0, rgba: false }
+}
+
+/// File bytes for a URL, downloading (with Blossom auth if asked for) into the cache
+async fn load(url: &str) -> Result<Vec<u8>, String> {
+    let path = match media_cache::fetch(url).await {
+        Ok(path) => path,
+        Err(e) if e == media_cache::UNAUTHORIZED => {
+            let auth = blossom_get_auth(url)?;
+            media_cache::fetch_authorized(url, Some(auth)).await?
+        }
+        Err(e) => return Err(e),
+    };
+    std::fs::read(&path).map_err(|e| format!("Failed to read cached media: {}", e))
+}
+
+/// Signed "get" authorization for a Blossom blob URL (`.../<sha256>[.ext]`)
+/// Only signs with a local key: an external signer would prompt for every image
+fn blossom_get_auth(url: &str) -> Result<reqwest::header::HeaderValue, String> {
+    let hash = blob_hash(url).ok_or("Authorization required for a non-Blossom URL")?;
+    let nsec = get_feed_nsec().ok_or("Authorization required but no local key is available")?;
+    let secret_key = SecretKey::parse(&nsec).map_err(|e| format!("Invalid nsec: {}", e))?;
+    let keys = Keys::new(secret_key);
+    let event = blossom::auth_event_builder("get", "Get blob", Some(&hash))
+        .sign_with_keys(&keys)
+        .map_err(|e| format!("Failed to sign: {}", e))?;
+    blossom::auth_header(&event)
+}
+
+/// The sha256 a Blossom URL names in its last path segment
+fn blob_hash(url: &str) -> Option<String> {
+    let parsed = url::Url::parse(url).ok()?;
+    let segment = parsed.path_segments()?.next_back()?;
+    let hash = segment.split('.').next()?;
+    (hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit())).then(|| hash.to_lowercase())
+}
+
+/// Decode a blurhash into a small RGBA image with the requested aspect ratio
+fn blurhash_image(hash: &str, width: i32, height: i32) -> ffi::MediaImage {
+    let (w, h) = if width > 0 && height > 0 {
+        let scale = BLURHASH_SIZE as f32 / width.max(height) as f32;
+        (((width as f32 * scale).round() as i32).max(1), ((height as f32 * scale).round() as i32).max(1))
+    } else {
+        (BLURHASH_SIZE, BLURHASH_SIZE)
+    };
+
+    match blurhash::decode(hash, w as u32, h as u32, 1.0) {
+        Ok(data) => ffi::MediaImage { data, width: w, height: h, rgba: true },
+        Err(e) => {
+            tracing::debug!("Image provider: invalid blurhash {}: {:?}", hash, e);
+            empty_image()
+        }
+    }
+}
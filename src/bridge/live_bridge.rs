@@ -0,0 +1,264 @@
+//! Live bridge - exposes NIP-53 live streams to QML
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, stream_count)]
+        #[qproperty(i32, live_count)]
+        #[qproperty(bool, is_loading)]
+        #[qproperty(QString, error_message)]
+        type LiveController = super::LiveControllerRust;
+
+        /// Set the user, load streams and keep watching for new ones
+        #[qinvokable]
+        fn initialize(self: Pin<&mut LiveController>, user_pubkey: &QString);
+
+        /// Forget the current user's streams and stop watching
+        #[qinvokable]
+        fn reset(self: Pin<&mut LiveController>);
+
+        /// Fetch streams from followed authors and popular relays
+        #[qinvokable]
+        fn load_streams(self: Pin<&mut LiveController>);
+
+        /// Get stream at index (returns JSON); live streams come first
+        #[qinvokable]
+        fn get_stream(self: &LiveController, index: i32) -> QString;
+
+        /// Streams live right now (returns JSON array)
+        #[qinvokable]
+        fn get_live_now(self: &LiveController) -> QString;
+    }
+
+    unsafe extern "RustQt" {
+        /// Emitted whenever the stream list is refreshed
+        #[qsignal]
+        fn streams_updated(self: Pin<&mut LiveController>, count: i32);
+
+        /// Emitted for each stream that went live since the previous refresh
+        #[qsignal]
+        fn stream_went_live(self: Pin<&mut LiveController>, stream_json: &QString);
+    }
+
+    // Enable threading support for background work with UI updates
+    impl cxx_qt::Threading for LiveController {}
+}
+
+use std::collections::HashSet;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use cxx_qt_lib::QString;
+use cxx_qt::{CxxQtType, Threading};
+use nostr_sdk::prelude::*;
+use crate::core::limits;
+use crate::core::refresh;
+use crate::nostr::contacts::GLOBAL_CONTACT_MANAGER;
+use crate::nostr::live::{self, LiveStream};
+use crate::nostr::relay::{fetch_timeout, RelayManager};
+use crate::bridge::feed_bridge::shared_relay_manager;
+
+// Global tokio runtime for live stream lookups
+lazy_static::lazy_static! {
+    static ref LIVE_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+}
+
+/// Bumped to stop the running background poll
+static LIVE_POLL_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Follows per author/participant query, to keep filters a relay will accept
+const AUTHOR_CHUNK: usize = 250;
+
+/// Rust implementation of LiveController
+#[derive(Default)]
+pub struct LiveControllerRust {
+    stream_count: i32,
+    live_count: i32,
+    is_loading: bool,
+    error_message: QString,
+
+    // Internal state
+    streams: Vec<LiveStream>,
+    /// Hex pubkeys the user follows, to flag their streams
+    followed: HashSet<String>,
+    /// Addresses live at the last refresh
+    live_addresses: HashSet<String>,
+    /// Whether a refresh has completed (the first one announces nothing)
+    loaded: bool,
+    user_pubkey: Option<PublicKey>,
+}
+
+impl qobject::LiveController {
+    /// Set the user and start watching
+    pub fn initialize(mut self: Pin<&mut Self>, user_pubkey: &QString) {
+        let Ok(pubkey) = PublicKey::parse(&user_pubkey.to_string()) else {
+            tracing::warn!("LiveController: invalid user pubkey");
+            return;
+        };
+        self.as_mut().rust_mut().user_pubkey = Some(pubkey);
+        self.as_mut().load_streams();
+        self.start_background_poll();
+    }
+
+    /// Clear state from the previous session
+    pub fn reset(mut self: Pin<&mut Self>) {
+        refresh::stop_background_poll(&LIVE_POLL_GENERATION);
+        {
+            let mut rust = self.as_mut().rust_mut();
+            rust.streams.clear();
+            rust.followed.clear();
+            rust.live_addresses.clear();
+            rust.loaded = false;
+            rust.user_pubkey = None;
+        }
+        self.as_mut().set_stream_count(0);
+        self.as_mut().set_live_count(0);
+        self.as_mut().set_is_loading(false);
+    }
+
+    /// Refresh streams in the background so go-live announcements arrive on any screen
+    fn start_background_poll(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        let generation = LIVE_POLL_GENERATION.load(Ordering::SeqCst) + 1;
+
+        refresh::start_background_poll(&LIVE_POLL_GENERATION, move || {
+            let _ = qt_thread.queue(move |mut qobject| {
+                if LIVE_POLL_GENERATION.load(Ordering::SeqCst) != generation || qobject.is_loading {
+                    return;
+                }
+                qobject.as_mut().load_streams();
+            });
+        });
+    }
+
+    /// Fetch streams (non-blocking)
+    pub fn load_streams(mut self: Pin<&mut Self>) {
+        if self.user_pubkey.is_none() {
+            tracing::warn!("Cannot load live streams: user pubkey not set");
+            return;
+        }
+
+        let following = GLOBAL_CONTACT_MANAGER.lock().unwrap().following();
+        self.as_mut().set_is_loading(true);
+        self.as_mut().set_error_message(QString::from(""));
+
+        let qt_thread = self.qt_thread();
+
+        limits::spawn_limited(move || {
+            let result = LIVE_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                let mut events: Vec<Event> = Vec::new();
+
+                // Followed hosts, whether they publish the event or a service does
+                for chunk in following.chunks(AUTHOR_CHUNK) {
+                    for filter in [live::authored_filter(chunk), live::participant_filter(chunk)] {
+                        match manager.client().fetch_events(filter, fetch_timeout()).await {
+                            Ok(found) => events.extend(found),
+                            Err(e) => tracing::warn!("Failed to fetch followed streams: {}", e),
+                        }
+                    }
+                }
+
+                // Whatever is popular on the big relays
+                let urls: Vec<String> = live::POPULAR_LIVE_RELAYS.iter().map(|s| s.to_string()).collect();
+                let mut popular = RelayManager::new();
+                match popular.connect_to(&urls).await {
+                    Ok(()) => {
+                        match popular.client().fetch_events(live::recent_filter(), fetch_timeout()).await {
+                            Ok(found) => events.extend(found),
+                            Err(e) => tracing::warn!("Failed to fetch popular streams: {}", e),
+                        }
+                        popular.disconnect().await;
+                    }
+                    Err(e) => tracing::warn!("Failed to connect to live relays: {}", e),
+                }
+
+                Ok::<_, String>(events)
+            });
+
+            let followed: HashSet<String> = following.iter().map(|pk| pk.to_hex()).collect();
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().set_is_loading(false);
+                match result {
+                    Ok(events) => qobject.as_mut().apply_streams(&events, followed),
+                    Err(e) => {
+                        tracing::error!("Failed to load live streams: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+
+    /// Replace the stream list and announce streams that just went live
+    fn apply_streams(mut self: Pin<&mut Self>, events: &[Event], followed: HashSet<String>) {
+        let now = Timestamp::now().as_secs() as i64;
+        let mut streams = live::collect_streams(events.iter(), now);
+
+        // Followed hosts' live streams lead; the sort is stable so the rest keep their order
+        streams.sort_by_key(|s| !(s.is_live(now) && s.involves_any(&followed)));
+
+        self.as_mut().rust_mut().followed = followed;
+        let live_now: HashSet<String> = streams.iter()
+            .filter(|s| s.is_live(now))
+            .map(|s| s.address.clone())
+            .collect();
+        let went_live: Vec<String> = if self.loaded {
+            streams.iter()
+                .filter(|s| live_now.contains(&s.address) && !self.live_addresses.contains(&s.address))
+                .map(|s| self.stream_json(s, now).to_string())
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let count = streams.len() as i32;
+        let live_count = live_now.len() as i32;
+        {
+            let mut rust = self.as_mut().rust_mut();
+            rust.streams = streams;
+            rust.live_addresses = live_now;
+            rust.loaded = true;
+        }
+        tracing::info!("Loaded {} live streams ({} live)", count, live_count);
+
+        self.as_mut().set_stream_count(count);
+        self.as_mut().set_live_count(live_count);
+        self.as_mut().streams_updated(count);
+        for json in went_live {
+            self.as_mut().stream_went_live(&QString::from(&json));
+        }
+    }
+
+    /// JSON for one stream, flagged if the user follows its host or a participant
+    fn stream_json(&self, stream: &LiveStream, now: i64) -> serde_json::Value {
+        let mut json = stream.to_json(now);
+        json["followed"] = serde_json::json!(stream.involves_any(&self.followed));
+        json
+    }
+
+    /// Get stream at index
+    pub fn get_stream(&self, index: i32) -> QString {
+        let now = Timestamp::now().as_secs() as i64;
+        match self.streams.get(index as usize) {
+            Some(stream) => QString::from(&self.stream_json(stream, now).to_string()),
+            None => QString::from("{}"),
+        }
+    }
+
+    /// Streams live right now
+    pub fn get_live_now(&self) -> QString {
+        let now = Timestamp::now().as_secs() as i64;
+        let live: Vec<serde_json::Value> = self.streams.iter()
+            .filter(|s| s.is_live(now))
+            .map(|s| self.stream_json(s, now))
+            .collect();
+        QString::from(&serde_json::Value::Array(live).to_string())
+    }
+}
@@ -0,0 +1,249 @@
+//! Media bridge - exposes the user's Blossom upload history to QML
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, blob_count)]
+        #[qproperty(i64, total_bytes)]
+        #[qproperty(bool, is_loading)]
+        #[qproperty(QString, error_message)]
+        type MediaManagerController = super::MediaManagerControllerRust;
+
+        /// Set the logged-in user's pubkey
+        #[qinvokable]
+        fn set_logged_in_user(self: Pin<&mut MediaManagerController>, pubkey: &QString);
+
+        /// Load blobs from the configured server and the user's server list
+        #[qinvokable]
+        fn load_blobs(self: Pin<&mut MediaManagerController>);
+
+        /// Get blob at index (returns JSON)
+        #[qinvokable]
+        fn get_blob(self: &MediaManagerController, index: i32) -> QString;
+
+        /// Delete a blob from every server that hosts it
+        #[qinvokable]
+        fn delete_blob(self: Pin<&mut MediaManagerController>, sha256: &QString);
+    }
+
+    unsafe extern "RustQt" {
+        /// Emitted when blobs are loaded
+        #[qsignal]
+        fn blobs_loaded(self: Pin<&mut MediaManagerController>, count: i32);
+
+        /// Emitted when a blob was deleted from all its servers
+        #[qsignal]
+        fn blob_deleted(self: Pin<&mut MediaManagerController>, sha256: &QString);
+
+        /// Emitted when deleting a blob fails on any server
+        #[qsignal]
+        fn blob_delete_failed(self: Pin<&mut MediaManagerController>, sha256: &QString, error: &QString);
+    }
+
+    // Enable threading support for background work with UI updates
+    impl cxx_qt::Threading for MediaManagerController {}
+}
+
+use std::pin::Pin;
+use cxx_qt_lib::QString;
+use cxx_qt::{CxxQtType, Threading};
+use nostr_sdk::prelude::*;
+use crate::core::config::Config;
+use crate::core::limits;
+use crate::nostr::blossom::{self, MediaBlob};
+use crate::bridge::feed_bridge::{shared_relay_manager, sign_event_builder};
+
+/// How many of the user's recent notes are scanned for blob usage
+const USAGE_SCAN_LIMIT: u64 = 500;
+
+// Global tokio runtime for media operations
+lazy_static::lazy_static! {
+    static ref MEDIA_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+}
+
+/// Rust implementation of MediaManagerController
+#[derive(Default)]
+pub struct MediaManagerControllerRust {
+    blob_count: i32,
+    total_bytes: i64,
+    is_loading: bool,
+    error_message: QString,
+
+    // Internal state
+    blobs: Vec<MediaBlob>,
+    user_pubkey: Option<PublicKey>,
+}
+
+impl qobject::MediaManagerController {
+    /// Set the logged-in user's pubkey
+    pub fn set_logged_in_user(mut self: Pin<&mut Self>, pubkey: &QString) {
+        self.as_mut().rust_mut().user_pubkey = PublicKey::parse(&pubkey.to_string()).ok();
+        self.as_mut().rust_mut().blobs.clear();
+        self.as_mut().set_blob_count(0);
+        self.as_mut().set_total_bytes(0);
+    }
+
+    /// Load the user's blobs from all of their Blossom servers
+    pub fn load_blobs(mut self: Pin<&mut Self>) {
+        let Some(user_pk) = self.user_pubkey else {
+            self.as_mut().set_error_message(QString::from("Not logged in"));
+            return;
+        };
+
+        self.as_mut().set_is_loading(true);
+        self.as_mut().set_error_message(QString::from(""));
+
+        let qt_thread = self.qt_thread();
+
+        limits::spawn_limited(move || {
+            let result = MEDIA_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+
+                // Configured upload server first, then the user's BUD-03 list
+                let mut servers = vec![Config::load().blossom_server.trim_end_matches('/').to_string()];
+                match manager.fetch_blossom_servers(&user_pk).await {
+                    Ok(list) => {
+                        for server in list {
+                            let server = server.trim_end_matches('/').to_string();
+                            if !servers.contains(&server) {
+                                servers.push(server);
+                            }
+                        }
+                    }
+                    Err(e) => tracing::warn!("Failed to fetch Blossom server list: {}", e),
+                }
+
+                let notes = manager.fetch_user_notes(&user_pk, USAGE_SCAN_LIMIT, None).await
+                    .map(|events| events.into_iter().collect::<Vec<Event>>())
+                    .unwrap_or_default();
+
+                // One failing server shouldn't hide the blobs on the others
+                let mut listings = Vec::new();
+                let mut last_error = None;
+                for server in servers {
+                    let auth = sign_event_builder(
+                        blossom::auth_event_builder("list", "List Blobs", None),
+                        user_pk,
+                    ).await.ok();
+                    match blossom::list_blobs(&server, &user_pk, auth.as_ref()).await {
+                        Ok(descriptors) => listings.push((server, descriptors)),
+                        Err(e) => {
+                            tracing::warn!("Failed to list blobs on {}: {}", server, e);
+                            last_error = Some(e);
+                        }
+                    }
+                }
+
+                if listings.is_empty() {
+                    return Err(last_error.unwrap_or_else(|| "No Blossom servers configured".to_string()));
+                }
+
+                let mut blobs = MediaBlob::merge(listings);
+                for blob in blobs.iter_mut() {
+                    blob.count_usage(&notes);
+                }
+                Ok::<Vec<MediaBlob>, String>(blobs)
+            });
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().set_is_loading(false);
+                match result {
+                    Ok(blobs) => {
+                        tracing::info!("Loaded {} Blossom blobs", blobs.len());
+                        qobject.as_mut().rust_mut().blobs = blobs;
+                        qobject.as_mut().update_totals();
+                        let count = qobject.blob_count;
+                        qobject.as_mut().blobs_loaded(count);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to load blobs: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+
+    /// Get blob at index
+    pub fn get_blob(&self, index: i32) -> QString {
+        if let Some(blob) = self.blobs.get(index as usize) {
+            QString::from(&blob.to_json())
+        } else {
+            QString::from("{}")
+        }
+    }
+
+    /// Delete a blob from every server hosting it
+    pub fn delete_blob(mut self: Pin<&mut Self>, sha256: &QString) {
+        let hash = sha256.to_string();
+        let Some(user_pk) = self.user_pubkey else {
+            self.as_mut().blob_delete_failed(sha256, &QString::from("Not logged in"));
+            return;
+        };
+        let Some(blob) = self.blobs.iter().find(|b| b.sha256 == hash) else {
+            self.as_mut().blob_delete_failed(sha256, &QString::from("Unknown blob"));
+            return;
+        };
+        let servers = blob.servers.clone();
+
+        let qt_thread = self.qt_thread();
+
+        limits::spawn_limited(move || {
+            let result = MEDIA_RUNTIME.block_on(async {
+                let auth = sign_event_builder(
+                    blossom::auth_event_builder("delete", &format!("Delete {}", hash), Some(&hash)),
+                    user_pk,
+                ).await?;
+
+                let mut remaining = Vec::new();
+                let mut last_error = None;
+                for server in servers {
+                    if let Err(e) = blossom::delete_blob(&server, &hash, &auth).await {
+                        tracing::warn!("Failed to delete blob {} on {}: {}", hash, server, e);
+                        remaining.push(server);
+                        last_error = Some(e);
+                    }
+                }
+                Ok::<(Vec<String>, Option<String>), String>((remaining, last_error))
+            });
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok((remaining, error)) => {
+                        if remaining.is_empty() {
+                            qobject.as_mut().rust_mut().blobs.retain(|b| b.sha256 != hash);
+                            qobject.as_mut().update_totals();
+                            qobject.as_mut().blob_deleted(&QString::from(&hash));
+                        } else {
+                            // Keep the blob listed under the servers that still have it
+                            if let Some(blob) = qobject.as_mut().rust_mut().blobs.iter_mut().find(|b| b.sha256 == hash) {
+                                blob.servers = remaining;
+                            }
+                            let error = error.unwrap_or_else(|| "Delete failed".to_string());
+                            qobject.as_mut().blob_delete_failed(&QString::from(&hash), &QString::from(&error));
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to delete blob {}: {}", hash, e);
+                        qobject.as_mut().blob_delete_failed(&QString::from(&hash), &QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+
+    /// Recompute blob count and total size
+    fn update_totals(mut self: Pin<&mut Self>) {
+        let count = self.blobs.len() as i32;
+        let total: u64 = self.blobs.iter().map(|b| b.size).sum();
+        self.as_mut().set_blob_count(count);
+        self.as_mut().set_total_bytes(total as i64);
+    }
+}
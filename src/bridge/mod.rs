@@ -6,3 +6,13 @@ pub mod dm_bridge;
 pub mod notification_bridge;
 pub mod profile_bridge;
 pub mod search_bridge;
+pub mod wallet_bridge;
+pub mod communities_bridge;
+pub mod channels_bridge;
+pub mod live_bridge;
+pub mod discovery_bridge;
+pub mod onboarding_bridge;
+pub mod media_bridge;
+pub mod signer_bridge;
+pub mod image_provider;
+pub mod variant;
@@ -5,12 +5,16 @@ pub mod qobject {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qstring.h");
         type QString = cxx_qt_lib::QString;
+        
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
     }
 
     unsafe extern "RustQt" {
         #[qobject]
         #[qml_element]
         #[qproperty(i32, notification_count)]
+        #[qproperty(i32, group_count)]
         #[qproperty(i32, unread_count)]
         #[qproperty(bool, is_loading)]
         #[qproperty(QString, error_message)]
@@ -19,6 +23,10 @@ pub mod qobject {
         /// Initialize with user's pubkey
         #[qinvokable]
         fn initialize(self: Pin<&mut NotificationController>, user_pubkey: &QString);
+        
+        /// Clear all per-user state (logout / account switch)
+        #[qinvokable]
+        fn reset(self: Pin<&mut NotificationController>);
 
         /// Load notifications
         #[qinvokable]
@@ -32,11 +40,16 @@ pub mod qobject {
         #[qinvokable]
         fn load_more(self: Pin<&mut NotificationController>);
         
-        /// Get notification at index (returns JSON)
+        /// Get notification at index as an object
+        #[qinvokable]
+        fn get_notification(self: &NotificationController, index: i32) -> QVariant;
+        
+        /// Get grouped notification at index as an object
+        /// Reactions, zaps and reposts of the same note are merged into one entry
         #[qinvokable]
-        fn get_notification(self: &NotificationController, index: i32) -> QString;
+        fn get_group(self: &NotificationController, index: i32) -> QVariant;
         
-        /// Mark notification as read
+        /// Mark notification (or a whole group) as read
         #[qinvokable]
         fn mark_as_read(self: Pin<&mut NotificationController>, notification_id: &QString);
         
@@ -73,16 +86,25 @@ pub mod qobject {
 }
 
 use std::pin::Pin;
-use cxx_qt_lib::QString;
+use cxx_qt_lib::{QString, QVariant};
 use cxx_qt::{CxxQtType, Threading};
 use nostr_sdk::prelude::*;
+use crate::core::desktop_notify::{self, NotifyKind};
+use crate::core::fiat;
+use crate::core::limits;
+use crate::core::refresh;
+use crate::core::tray;
 use crate::nostr::profile::ProfileCache;
-use crate::bridge::feed_bridge::create_authenticated_relay_manager;
+use crate::nostr::database::NostrDbManager;
+use crate::bridge::feed_bridge::shared_relay_manager;
+use crate::bridge::variant;
 use std::collections::HashMap;
 
 // Global tokio runtime for notification operations
 lazy_static::lazy_static! {
     static ref NOTIFICATION_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+    // Bumped to start or stop the background notification poll; older loops exit
+    static ref POLL_GENERATION: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
 }
 
 /// Notification types
@@ -93,6 +115,7 @@ pub enum NotificationType {
     Reaction,
     Zap,
     Repost,
+    Follow,
 }
 
 impl NotificationType {
@@ -103,6 +126,7 @@ impl NotificationType {
             NotificationType::Reaction => "reaction",
             NotificationType::Zap => "zap",
             NotificationType::Repost => "repost",
+            NotificationType::Follow => "follow",
         }
     }
     
@@ -113,6 +137,7 @@ impl NotificationType {
             NotificationType::Reaction => "❤️",
             NotificationType::Zap => "⚡",
             NotificationType::Repost => "🔁",
+            NotificationType::Follow => "👤",
         }
     }
 }
@@ -184,6 +209,7 @@ impl DisplayNotification {
                 format!("zapped {} sats", amount.unwrap_or(0))
             }
             NotificationType::Repost => "reposted your note".to_string(),
+            NotificationType::Follow => "followed you".to_string(),
             NotificationType::Reply => {
                 let preview = truncate_content(&event.content, 100);
                 format!("replied: {}", preview)
@@ -230,8 +256,33 @@ impl DisplayNotification {
         }
     }
     
-    /// Serialize to JSON for QML consumption
-    pub fn to_json(&self) -> String {
+    /// "Followed you" from a follower first seen at `followed_at`
+    /// The id is per follower, so it stays the same when they update their contact list
+    pub fn follow(follower: &str, followed_at: i64, profile: Option<&ProfileCache>) -> Self {
+        let (author_name, author_picture) = profile
+            .map(|p| (
+                p.name.clone().unwrap_or_else(|| format_npub(follower)),
+                p.picture.clone(),
+            ))
+            .unwrap_or_else(|| (format_npub(follower), None));
+        
+        Self {
+            id: format!("{}{}", FOLLOW_ID_PREFIX, follower),
+            notification_type: NotificationType::Follow,
+            author_pubkey: follower.to_string(),
+            author_name,
+            author_picture,
+            content_preview: "followed you".to_string(),
+            referenced_event_id: None,
+            created_at: followed_at,
+            is_read: false,
+            reaction_content: None,
+            zap_amount: None,
+        }
+    }
+    
+    /// Fields as QML reads them
+    fn to_value(&self) -> serde_json::Value {
         serde_json::json!({
             "id": self.id,
             "type": self.notification_type.as_str(),
@@ -245,10 +296,232 @@ impl DisplayNotification {
             "isRead": self.is_read,
             "reactionContent": self.reaction_content,
             "zapAmount": self.zap_amount,
-        }).to_string()
+            "zapAmountFiat": self.zap_amount.and_then(fiat::fiat_label).unwrap_or_default(),
+        })
+    }
+}
+
+/// Prefix for group ids, so mark_as_read can tell groups from single notifications
+const GROUP_ID_PREFIX: &str = "group:";
+
+/// Prefix for follow notification ids, which name the follower rather than an event
+const FOLLOW_ID_PREFIX: &str = "follow:";
+
+/// Maximum actors listed in a group's title
+const GROUP_TITLE_ACTORS: usize = 2;
+
+/// Notifications shown as one list entry
+/// Indices point into the controller's notification list (newest first)
+#[derive(Debug, Clone)]
+pub struct NotificationGroup {
+    pub referenced_event_id: Option<String>,
+    pub members: Vec<usize>,
+}
+
+impl NotificationGroup {
+    fn id(&self, notifications: &[DisplayNotification]) -> String {
+        match (&self.referenced_event_id, self.members.len()) {
+            (Some(event_id), n) if n > 1 => format!("{}{}", GROUP_ID_PREFIX, event_id),
+            _ => notifications[self.members[0]].id.clone(),
+        }
+    }
+    
+    /// Fields as QML reads them
+    /// Single-member groups serialize as the plain notification (plus grouping fields)
+    pub fn to_value(&self, notifications: &[DisplayNotification]) -> serde_json::Value {
+        let members: Vec<&DisplayNotification> = self.members.iter()
+            .filter_map(|&i| notifications.get(i))
+            .collect();
+        
+        let Some(first) = members.first() else {
+            return serde_json::json!({});
+        };
+        
+        if members.len() == 1 {
+            let mut value = first.to_value();
+            value["grouped"] = serde_json::json!(false);
+            value["count"] = serde_json::json!(1);
+            return value;
+        }
+        
+        // Unique actors, newest first
+        let mut actors: Vec<&DisplayNotification> = Vec::new();
+        for n in &members {
+            if !actors.iter().any(|a| a.author_pubkey == n.author_pubkey) {
+                actors.push(n);
+            }
+        }
+        
+        let count_of = |t: NotificationType| members.iter().filter(|n| n.notification_type == t).count();
+        let reaction_count = count_of(NotificationType::Reaction);
+        let zap_count = count_of(NotificationType::Zap);
+        let repost_count = count_of(NotificationType::Repost);
+        let total_sats: u64 = members.iter().filter_map(|n| n.zap_amount).sum();
+        
+        // Single kind of interaction keeps its own icon and verb
+        let kinds = [reaction_count, zap_count, repost_count].iter().filter(|&&c| c > 0).count();
+        let (type_icon, verb) = if kinds > 1 {
+            ("🔔", "interacted with")
+        } else if zap_count > 0 {
+            (NotificationType::Zap.icon(), "zapped")
+        } else if repost_count > 0 {
+            (NotificationType::Repost.icon(), "reposted")
+        } else {
+            (NotificationType::Reaction.icon(), "reacted to")
+        };
+        
+        let mut summary = if actors.len() == 1 {
+            format!("{} {} your note", first.author_name, verb)
+        } else {
+            format!("{} people {} your note", actors.len(), verb)
+        };
+        if total_sats > 0 {
+            summary.push_str(&format!(", {} sats total", format_sats(total_sats)));
+        }
+        
+        let names: Vec<&str> = actors.iter()
+            .take(GROUP_TITLE_ACTORS)
+            .map(|a| a.author_name.as_str())
+            .collect();
+        let title = match actors.len() {
+            1 => names[0].to_string(),
+            2 => format!("{} and {}", names[0], names[1]),
+            n => format!("{} and {} others", names.join(", "), n - GROUP_TITLE_ACTORS),
+        };
+        
+        serde_json::json!({
+            "id": self.id(notifications),
+            "type": "group",
+            "typeIcon": type_icon,
+            "grouped": true,
+            "count": members.len(),
+            "actorCount": actors.len(),
+            "reactionCount": reaction_count,
+            "zapCount": zap_count,
+            "repostCount": repost_count,
+            "totalSats": total_sats,
+            "totalFiat": fiat::fiat_label(total_sats).unwrap_or_default(),
+            "authorPubkey": first.author_pubkey,
+            "authorName": title,
+            "authorPicture": first.author_picture,
+            "contentPreview": summary,
+            "referencedEventId": self.referenced_event_id,
+            "createdAt": first.created_at,
+            "isRead": members.iter().all(|n| n.is_read),
+            "members": members.iter().map(|n| n.to_value()).collect::<Vec<_>>(),
+        })
     }
 }
 
+/// Group reactions, zaps and reposts that target the same note
+/// Input must be sorted newest first; groups are ordered by their newest member.
+/// Duplicate events (same id) are dropped.
+pub fn group_notifications(notifications: &[DisplayNotification]) -> Vec<NotificationGroup> {
+    let mut groups: Vec<NotificationGroup> = Vec::new();
+    let mut group_by_event: HashMap<String, usize> = HashMap::new();
+    let mut seen_ids = std::collections::HashSet::new();
+    
+    for (index, n) in notifications.iter().enumerate() {
+        if !seen_ids.insert(n.id.as_str()) {
+            continue;
+        }
+        
+        let groupable = matches!(
+            n.notification_type,
+            NotificationType::Reaction | NotificationType::Zap | NotificationType::Repost
+        );
+        
+        match (&n.referenced_event_id, groupable) {
+            (Some(event_id), true) => {
+                if let Some(&g) = group_by_event.get(event_id) {
+                    groups[g].members.push(index);
+                } else {
+                    group_by_event.insert(event_id.clone(), groups.len());
+                    groups.push(NotificationGroup {
+                        referenced_event_id: Some(event_id.clone()),
+                        members: vec![index],
+                    });
+                }
+            }
+            _ => groups.push(NotificationGroup {
+                referenced_event_id: n.referenced_event_id.clone(),
+                members: vec![index],
+            }),
+        }
+    }
+    
+    groups
+}
+
+/// Turn fetched events into notifications, newest first
+/// Contact lists naming the user become "followed you" for followers seen
+/// after the first check (see `NotificationReadState::record_followers`)
+fn build_notifications<'a>(
+    events: impl IntoIterator<Item = &'a Event>,
+    profiles: &HashMap<String, ProfileCache>,
+    user_pubkey: &PublicKey,
+) -> Vec<DisplayNotification> {
+    let mut notifications = Vec::new();
+    let mut lists = Vec::new();
+    for event in events {
+        if event.kind == Kind::ContactList {
+            lists.push((event.pubkey.to_hex(), event.created_at.as_secs() as i64));
+        } else {
+            notifications.push(DisplayNotification::from_event(event, profiles.get(&event.pubkey.to_hex()), user_pubkey));
+        }
+    }
+    
+    if !lists.is_empty() {
+        if let Ok(db) = NostrDbManager::global() {
+            for (follower, followed_at) in db.record_followers(&user_pubkey.to_hex(), &lists) {
+                notifications.push(DisplayNotification::follow(&follower, followed_at, profiles.get(&follower)));
+            }
+        }
+    }
+    
+    notifications.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    notifications
+}
+
+/// Restore persisted read flags for a batch of notifications
+fn apply_read_state(notifications: &mut [DisplayNotification], user_pubkey: &PublicKey) {
+    let Ok(db) = NostrDbManager::global() else {
+        return;
+    };
+    let state = db.get_notification_state(&user_pubkey.to_hex());
+    for n in notifications.iter_mut() {
+        n.is_read = state.is_read(&n.id, n.created_at);
+    }
+}
+
+/// Post a desktop notification for a new mention, reply or zap
+fn notify_desktop(n: &DisplayNotification) {
+    let kind = match n.notification_type {
+        NotificationType::Mention | NotificationType::Reply => NotifyKind::Mention,
+        NotificationType::Zap => NotifyKind::Zap,
+        _ => return,
+    };
+    // Zaps open the zapped note; mentions and replies open the event itself
+    let target = match kind {
+        NotifyKind::Zap => n.referenced_event_id.clone().unwrap_or_default(),
+        _ => n.id.clone(),
+    };
+    desktop_notify::notify(kind, &n.author_name, &n.content_preview, &target);
+}
+
+/// Format a sat amount with thousands separators (2100 -> "2,100")
+fn format_sats(sats: u64) -> String {
+    let digits = sats.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
 /// Helper to check if event has an 'e' tag (is a reply)
 fn has_event_tag(event: &Event) -> bool {
     event.tags.iter().any(|tag| {
@@ -393,12 +666,14 @@ fn format_npub(pubkey: &str) -> String {
 /// Rust implementation of NotificationController
 pub struct NotificationControllerRust {
     notification_count: i32,
+    group_count: i32,
     unread_count: i32,
     is_loading: bool,
     error_message: QString,
     
     // Internal state
     notifications: Vec<DisplayNotification>,
+    groups: Vec<NotificationGroup>,
     user_pubkey: Option<PublicKey>,
     profiles: HashMap<String, ProfileCache>,
     oldest_timestamp: Option<Timestamp>,
@@ -410,10 +685,12 @@ impl Default for NotificationControllerRust {
     fn default() -> Self {
         Self {
             notification_count: 0,
+            group_count: 0,
             unread_count: 0,
             is_loading: false,
             error_message: QString::from(""),
             notifications: Vec::new(),
+            groups: Vec::new(),
             user_pubkey: None,
             profiles: HashMap::new(),
             oldest_timestamp: None,
@@ -424,6 +701,43 @@ impl Default for NotificationControllerRust {
 }
 
 impl qobject::NotificationController {
+    /// Clear all per-user state
+    pub fn reset(mut self: Pin<&mut Self>) {
+        refresh::stop_background_poll(&POLL_GENERATION);
+        {
+            let mut rust = self.as_mut().rust_mut();
+            rust.notifications.clear();
+            rust.groups.clear();
+            rust.user_pubkey = None;
+            rust.profiles.clear();
+            rust.oldest_timestamp = None;
+            rust.newest_timestamp = None;
+            rust.is_checking = false;
+        }
+        self.as_mut().set_notification_count(0);
+        self.as_mut().set_group_count(0);
+        self.as_mut().set_unread(0);
+        self.as_mut().set_error_message(QString::from(""));
+        self.as_mut().set_is_loading(false);
+    }
+    
+    /// Update the unread count and the tray badge together
+    fn set_unread(self: Pin<&mut Self>, count: i32) {
+        tray::set_unread_notifications(count.max(0) as u32);
+        self.set_unread_count(count);
+    }
+    
+    /// Rebuild the grouped view after the notification list changed
+    fn regroup(mut self: Pin<&mut Self>) {
+        let count = {
+            let mut rust = self.as_mut().rust_mut();
+            let groups = group_notifications(&rust.notifications);
+            rust.groups = groups;
+            rust.groups.len() as i32
+        };
+        self.as_mut().set_group_count(count);
+    }
+    
     /// Initialize with user's pubkey
     pub fn initialize(mut self: Pin<&mut Self>, user_pubkey: &QString) {
         let pubkey_str = user_pubkey.to_string();
@@ -442,7 +756,29 @@ impl qobject::NotificationController {
         }
         
         // Auto-load notifications after init
-        self.load_notifications();
+        self.as_mut().load_notifications();
+        self.as_mut().start_background_poll();
+    }
+    
+    /// Poll for new notifications in the background (whatever screen is open,
+    /// window hidden or not) to keep unread_count and the tray badge current
+    fn start_background_poll(self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        let generation = POLL_GENERATION.load(std::sync::atomic::Ordering::SeqCst) + 1;
+        
+        refresh::start_background_poll(&POLL_GENERATION, move || {
+            let _ = qt_thread.queue(move |mut qobject| {
+                if POLL_GENERATION.load(std::sync::atomic::Ordering::SeqCst) != generation || qobject.is_loading {
+                    return;
+                }
+                // Nothing loaded yet (first load failed): try a full load again
+                if qobject.newest_timestamp.is_none() {
+                    qobject.as_mut().load_notifications();
+                } else {
+                    qobject.as_mut().check_for_new();
+                }
+            });
+        });
     }
     
     /// Load notifications (non-blocking with proper Qt threading)
@@ -463,11 +799,10 @@ impl qobject::NotificationController {
         let qt_thread = self.qt_thread();
         
         // Spawn background thread - does NOT block the main thread
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = NOTIFICATION_RUNTIME.block_on(async {
                 // Create a temporary relay manager for fetching
-                let mut manager = create_authenticated_relay_manager();
-                manager.connect().await?;
+                let manager = shared_relay_manager().await?;
                 
                 let events = manager.fetch_notifications(&pubkey, 100, None).await?;
                 
@@ -489,17 +824,9 @@ impl qobject::NotificationController {
                     }
                 }
                 
-                // Convert events to display notifications
-                let mut notifications: Vec<DisplayNotification> = events
-                    .iter()
-                    .map(|e| {
-                        let profile = profiles.get(&e.pubkey.to_hex());
-                        DisplayNotification::from_event(e, profile, &pubkey)
-                    })
-                    .collect();
-                
-                // Sort by timestamp (newest first)
-                notifications.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                // Convert events to display notifications (newest first)
+                let mut notifications = build_notifications(events.iter(), &profiles, &pubkey);
+                apply_read_state(&mut notifications, &pubkey);
                 
                 // Get oldest timestamp for pagination and newest for check_for_new
                 let oldest = notifications.last().map(|n| Timestamp::from(n.created_at as u64));
@@ -524,7 +851,8 @@ impl qobject::NotificationController {
                             rust.unread_count = unread;
                         }
                         qobject.as_mut().set_notification_count(count);
-                        qobject.as_mut().set_unread_count(unread);
+                        qobject.as_mut().regroup();
+                        qobject.as_mut().set_unread(unread);
                         qobject.as_mut().set_is_loading(false);
                         qobject.as_mut().set_error_message(QString::from(""));
                         qobject.as_mut().notifications_updated();
@@ -568,10 +896,9 @@ impl qobject::NotificationController {
         
         let qt_thread = self.qt_thread();
         
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = NOTIFICATION_RUNTIME.block_on(async {
-                let mut manager = create_authenticated_relay_manager();
-                manager.connect().await?;
+                let manager = shared_relay_manager().await?;
                 
                 let events = manager.fetch_notifications(&pubkey, 50, Some(until_ts)).await?;
                 
@@ -594,15 +921,8 @@ impl qobject::NotificationController {
                     }
                 }
                 
-                let mut notifications: Vec<DisplayNotification> = events
-                    .iter()
-                    .map(|e| {
-                        let profile = profiles.get(&e.pubkey.to_hex());
-                        DisplayNotification::from_event(e, profile, &pubkey)
-                    })
-                    .collect();
-                
-                notifications.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                let mut notifications = build_notifications(events.iter(), &profiles, &pubkey);
+                apply_read_state(&mut notifications, &pubkey);
                 let oldest = notifications.last().map(|n| Timestamp::from(n.created_at as u64));
                 
                 Ok::<_, String>((notifications, profiles, oldest))
@@ -625,7 +945,8 @@ impl qobject::NotificationController {
                             (rust.notification_count, rust.unread_count)
                         };
                         qobject.as_mut().set_notification_count(total);
-                        qobject.as_mut().set_unread_count(unread);
+                        qobject.as_mut().regroup();
+                        qobject.as_mut().set_unread(unread);
                         qobject.as_mut().set_is_loading(false);
                         qobject.as_mut().more_loaded(new_count);
                     }
@@ -640,28 +961,52 @@ impl qobject::NotificationController {
     }
     
     /// Get notification at index
-    pub fn get_notification(&self, index: i32) -> QString {
-        if let Some(notification) = self.notifications.get(index as usize) {
-            QString::from(&notification.to_json())
-        } else {
-            QString::from("{}")
+    pub fn get_notification(&self, index: i32) -> QVariant {
+        match self.notifications.get(index as usize) {
+            Some(notification) => variant::from_json(&notification.to_value()),
+            None => variant::empty_map(),
+        }
+    }
+    
+    /// Get grouped notification at index
+    pub fn get_group(&self, index: i32) -> QVariant {
+        match self.groups.get(index as usize) {
+            Some(group) => variant::from_json(&group.to_value(&self.notifications)),
+            None => variant::empty_map(),
         }
     }
     
     /// Mark notification as read
+    /// A group id marks every notification in that group
     pub fn mark_as_read(mut self: Pin<&mut Self>, notification_id: &QString) {
         let id = notification_id.to_string();
         let unread = {
             let mut rust = self.as_mut().rust_mut();
-            if let Some(n) = rust.notifications.iter_mut().find(|n| n.id == id) {
-                if !n.is_read {
+            let group_event = id.strip_prefix(GROUP_ID_PREFIX).map(|s| s.to_string());
+            let mut marked_ids = Vec::new();
+            for n in rust.notifications.iter_mut() {
+                let matches = match &group_event {
+                    Some(event_id) => n.referenced_event_id.as_deref() == Some(event_id.as_str())
+                        && matches!(
+                            n.notification_type,
+                            NotificationType::Reaction | NotificationType::Zap | NotificationType::Repost
+                        ),
+                    None => n.id == id,
+                };
+                if matches && !n.is_read {
                     n.is_read = true;
-                    rust.unread_count = rust.unread_count.saturating_sub(1);
+                    marked_ids.push((n.id.clone(), n.created_at));
                 }
             }
+            if let (Some(pubkey), Ok(db)) = (rust.user_pubkey, NostrDbManager::global()) {
+                if !marked_ids.is_empty() {
+                    db.mark_notifications_read(&pubkey.to_hex(), &marked_ids);
+                }
+            }
+            rust.unread_count = rust.unread_count.saturating_sub(marked_ids.len() as i32);
             rust.unread_count
         };
-        self.as_mut().set_unread_count(unread);
+        self.as_mut().set_unread(unread);
     }
     
     /// Mark all as read
@@ -672,8 +1017,14 @@ impl qobject::NotificationController {
                 n.is_read = true;
             }
             rust.unread_count = 0;
+            // Persist as a cursor so anything older stays read after restart
+            if let (Some(pubkey), Some(newest), Ok(db)) =
+                (rust.user_pubkey, rust.newest_timestamp, NostrDbManager::global())
+            {
+                db.mark_all_notifications_read(&pubkey.to_hex(), newest);
+            }
         }
-        self.as_mut().set_unread_count(0);
+        self.as_mut().set_unread(0);
         // Signal UI to refresh so isRead changes are reflected
         self.as_mut().notifications_updated();
     }
@@ -718,10 +1069,9 @@ impl qobject::NotificationController {
         
         let qt_thread = self.qt_thread();
         
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = NOTIFICATION_RUNTIME.block_on(async {
-                let mut manager = create_authenticated_relay_manager();
-                manager.connect().await?;
+                let manager = shared_relay_manager().await?;
                 
                 // Fetch recent notifications - use 'since' to only get newer ones
                 let since_ts = Timestamp::from((newest_ts + 1) as u64);
@@ -751,18 +1101,25 @@ impl qobject::NotificationController {
                     .since(since_ts)
                     .limit(50);
                 
+                let follow_filter = Filter::new()
+                    .kind(Kind::ContactList)
+                    .pubkey(pubkey)
+                    .since(since_ts)
+                    .limit(50);
+                
                 // Fetch all in parallel
                 let timeout = std::time::Duration::from_secs(10);
-                let (mentions, reactions, zaps, reposts) = tokio::join!(
+                let (mentions, reactions, zaps, reposts, follows) = tokio::join!(
                     manager.client().fetch_events(mention_filter, timeout),
                     manager.client().fetch_events(reaction_filter, timeout),
                     manager.client().fetch_events(zap_filter, timeout),
-                    manager.client().fetch_events(repost_filter, timeout)
+                    manager.client().fetch_events(repost_filter, timeout),
+                    manager.client().fetch_events(follow_filter, timeout)
                 );
                 
                 let mut combined = Events::default();
                 
-                for events_result in [mentions, reactions, zaps, reposts] {
+                for events_result in [mentions, reactions, zaps, reposts, follows] {
                     if let Ok(events) = events_result {
                         for event in events.into_iter() {
                             // Skip events from the user themselves
@@ -798,17 +1155,11 @@ impl qobject::NotificationController {
                     }
                 }
                 
-                // Convert to display notifications
-                let mut notifications: Vec<DisplayNotification> = combined
-                    .iter()
-                    .map(|e| {
-                        let profile = profiles.get(&e.pubkey.to_hex());
-                        DisplayNotification::from_event(e, profile, &pubkey)
-                    })
-                    .collect();
-                
-                // Sort by timestamp (newest first)
-                notifications.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                // Convert to display notifications (newest first)
+                // Followers seen earlier come back when they update their list; keep only new ones
+                let mut notifications = build_notifications(combined.iter(), &profiles, &pubkey);
+                notifications.retain(|n| n.created_at > newest_ts);
+                apply_read_state(&mut notifications, &pubkey);
                 
                 Ok((notifications, profiles))
             });
@@ -851,6 +1202,10 @@ impl qobject::NotificationController {
                             let truly_new_count = truly_new.len();
                             let truly_new_unread = truly_new.iter().filter(|n| !n.is_read).count();
                             
+                            for n in truly_new.iter().filter(|n| !n.is_read) {
+                                notify_desktop(n);
+                            }
+                            
                             // Prepend new notifications
                             let mut combined = truly_new;
                             combined.append(&mut rust.notifications);
@@ -875,7 +1230,8 @@ impl qobject::NotificationController {
                         };
                         
                         qobject.as_mut().set_notification_count(total);
-                        qobject.as_mut().set_unread_count(unread);
+                        qobject.as_mut().regroup();
+                        qobject.as_mut().set_unread(unread);
                         qobject.as_mut().new_notifications_found(new_count);
                         qobject.as_mut().notifications_updated();
                     }
@@ -0,0 +1,223 @@
+//! Onboarding bridge - first-run profile, relay list and starter follows for new accounts
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, is_busy)]
+        #[qproperty(QString, avatar_url)]
+        #[qproperty(QString, status_text)]
+        #[qproperty(QString, error_message)]
+        type OnboardingController = super::OnboardingControllerRust;
+
+        /// Set the new account's pubkey
+        #[qinvokable]
+        fn initialize(self: Pin<&mut OnboardingController>, user_pubkey: &QString);
+
+        /// Curated follow packs (returns JSON array of {id, title, description, pubkeys})
+        #[qinvokable]
+        fn get_starter_packs(self: &OnboardingController) -> QString;
+
+        /// Upload an avatar image to the Blossom server (async)
+        /// Sets avatar_url and emits avatar_uploaded on success
+        #[qinvokable]
+        fn upload_avatar(self: Pin<&mut OnboardingController>, file_path: &QString);
+
+        /// Publish the profile, relay list and follows from the chosen packs (async)
+        /// pack_ids_json is a JSON array of starter pack ids
+        #[qinvokable]
+        fn finish(self: Pin<&mut OnboardingController>, name: &QString, about: &QString, pack_ids_json: &QString);
+    }
+
+    unsafe extern "RustQt" {
+        /// Emitted when the avatar upload completes
+        #[qsignal]
+        fn avatar_uploaded(self: Pin<&mut OnboardingController>, url: &QString);
+
+        /// Emitted when everything is published
+        #[qsignal]
+        fn onboarding_finished(self: Pin<&mut OnboardingController>, follow_count: i32);
+
+        /// Emitted when an upload or publish fails
+        #[qsignal]
+        fn onboarding_failed(self: Pin<&mut OnboardingController>, error: &QString);
+    }
+
+    // Enable threading support for background work with UI updates
+    impl cxx_qt::Threading for OnboardingController {}
+}
+
+use std::pin::Pin;
+use cxx_qt_lib::QString;
+use cxx_qt::{CxxQtType, Threading};
+use nostr_sdk::prelude::*;
+use crate::core::config::Config;
+use crate::core::limits;
+use crate::nostr::blossom;
+use crate::nostr::contacts::GLOBAL_CONTACT_MANAGER;
+use crate::nostr::onboarding;
+use crate::nostr::publish;
+use crate::nostr::relay::{self, GLOBAL_RELAY_MANAGER};
+use crate::bridge::feed_bridge::{get_feed_nsec, shared_relay_manager, sign_event_builder};
+
+// Global tokio runtime for onboarding uploads and publishing
+lazy_static::lazy_static! {
+    static ref ONBOARDING_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+}
+
+/// Rust implementation of OnboardingController
+#[derive(Default)]
+pub struct OnboardingControllerRust {
+    is_busy: bool,
+    avatar_url: QString,
+    status_text: QString,
+    error_message: QString,
+
+    // Internal state
+    user_pubkey: Option<PublicKey>,
+}
+
+impl qobject::OnboardingController {
+    /// Set the account being onboarded
+    pub fn initialize(mut self: Pin<&mut Self>, user_pubkey: &QString) {
+        let Ok(pubkey) = PublicKey::parse(&user_pubkey.to_string()) else {
+            tracing::warn!("OnboardingController: invalid user pubkey");
+            return;
+        };
+        self.as_mut().rust_mut().user_pubkey = Some(pubkey);
+        self.as_mut().set_avatar_url(QString::from(""));
+        self.as_mut().set_status_text(QString::from(""));
+        self.as_mut().set_error_message(QString::from(""));
+    }
+
+    /// Curated follow packs
+    pub fn get_starter_packs(&self) -> QString {
+        QString::from(&serde_json::to_string(onboarding::STARTER_PACKS).unwrap_or_else(|_| "[]".to_string()))
+    }
+
+    /// Upload an avatar (non-blocking)
+    pub fn upload_avatar(mut self: Pin<&mut Self>, file_path: &QString) {
+        // Blossom uploads are authorized with the local key, which new accounts always have
+        let Some(keys) = get_feed_nsec().and_then(|nsec| SecretKey::parse(&nsec).ok()).map(Keys::new) else {
+            self.as_mut().fail("Uploading an avatar needs a locally stored key".to_string());
+            return;
+        };
+
+        let path = file_path.to_string();
+        let path = path.strip_prefix("file://").unwrap_or(&path).to_string();
+        let server_url = Config::load().blossom_server;
+
+        self.as_mut().set_is_busy(true);
+        self.as_mut().set_status_text(QString::from("Uploading avatar..."));
+        self.as_mut().set_error_message(QString::from(""));
+        let qt_thread = self.qt_thread();
+
+        limits::spawn_limited(move || {
+            let result = ONBOARDING_RUNTIME.block_on(blossom::upload_media(&server_url, &path, &keys));
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().set_is_busy(false);
+                qobject.as_mut().set_status_text(QString::from(""));
+                match result {
+                    Ok(response) => {
+                        tracing::info!("Uploaded avatar: {}", response.url);
+                        let url = QString::from(&response.url);
+                        qobject.as_mut().set_avatar_url(url.clone());
+                        qobject.as_mut().avatar_uploaded(&url);
+                    }
+                    Err(e) => qobject.as_mut().fail(format!("Avatar upload failed: {}", e)),
+                }
+            });
+        });
+    }
+
+    /// Publish profile, relay list and contact list (non-blocking)
+    pub fn finish(mut self: Pin<&mut Self>, name: &QString, about: &QString, pack_ids_json: &QString) {
+        let Some(user_pk) = self.user_pubkey else {
+            self.as_mut().fail("Not logged in".to_string());
+            return;
+        };
+        let profile = match onboarding::profile_builder(&name.to_string(), &about.to_string(), &self.avatar_url().to_string()) {
+            Ok(builder) => builder,
+            Err(e) => {
+                self.as_mut().fail(e);
+                return;
+            }
+        };
+        let pack_ids: Vec<String> = serde_json::from_str(&pack_ids_json.to_string()).unwrap_or_default();
+        let follows = onboarding::pack_pubkeys(&pack_ids);
+        let relays = relay::configured_relay_roles();
+
+        self.as_mut().set_is_busy(true);
+        self.as_mut().set_error_message(QString::from(""));
+        let qt_thread = self.qt_thread();
+
+        limits::spawn_limited(move || {
+            let status = |text: &'static str| {
+                let _ = qt_thread.queue(move |mut qobject| {
+                    qobject.as_mut().set_status_text(QString::from(text));
+                });
+            };
+
+            let result = ONBOARDING_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+
+                status("Publishing your profile...");
+                let event = sign_event_builder(profile, user_pk).await?;
+                publish::send(manager.client(), &event).await?;
+
+                status("Publishing your relay list...");
+                let event = sign_event_builder(relay::relay_list_builder(&relays), user_pk).await?;
+                publish::send(manager.client(), &event).await?;
+
+                if follows.is_empty() {
+                    return Ok::<Vec<PublicKey>, String>(Vec::new());
+                }
+                status("Following your picks...");
+                let (builder, generation) = {
+                    let mut contacts = GLOBAL_CONTACT_MANAGER.lock().unwrap();
+                    contacts.follow_all(&follows);
+                    (contacts.to_event_builder(), contacts.generation())
+                };
+                let event = sign_event_builder(builder, user_pk).await?;
+                publish::send(manager.client(), &event).await?;
+
+                let mut contacts = GLOBAL_CONTACT_MANAGER.lock().unwrap();
+                contacts.mark_published(event, generation);
+                Ok(contacts.following())
+            });
+
+            // The following feed reads the pooled manager's list
+            if let Ok(following) = &result {
+                if let Some(manager) = GLOBAL_RELAY_MANAGER.write().unwrap().as_mut() {
+                    manager.set_following(following.clone());
+                }
+            }
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().set_is_busy(false);
+                qobject.as_mut().set_status_text(QString::from(""));
+                match result {
+                    Ok(following) => {
+                        tracing::info!("Onboarding finished with {} follows", following.len());
+                        qobject.as_mut().onboarding_finished(following.len() as i32);
+                    }
+                    Err(e) => qobject.as_mut().fail(e),
+                }
+            });
+        });
+    }
+
+    fn fail(mut self: Pin<&mut Self>, error: String) {
+        tracing::error!("Onboarding: {}", error);
+        let error = QString::from(&error);
+        self.as_mut().set_error_message(error.clone());
+        self.as_mut().onboarding_failed(&error);
+    }
+}
@@ -5,6 +5,9 @@ pub mod qobject {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qstring.h");
         type QString = cxx_qt_lib::QString;
+        
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
     }
 
     unsafe extern "RustQt" {
@@ -18,6 +21,7 @@ pub mod qobject {
         #[qproperty(QString, banner)]
         #[qproperty(QString, website)]
         #[qproperty(QString, nip05)]
+        #[qproperty(bool, nip05_verified)]
         #[qproperty(QString, lud16)]
         #[qproperty(i32, following_count)]
         #[qproperty(i32, followers_count)]
@@ -26,12 +30,21 @@ pub mod qobject {
         #[qproperty(bool, is_own_profile)]
         #[qproperty(bool, is_following)]
         #[qproperty(QString, error_message)]
+        #[qproperty(i32, user_note_count)]
+        #[qproperty(bool, is_loading_notes)]
+        #[qproperty(QString, notes_filter)]
+        #[qproperty(i32, user_media_count)]
+        #[qproperty(bool, is_loading_media)]
         type ProfileController = super::ProfileControllerRust;
 
         /// Load profile for a given pubkey
         #[qinvokable]
         fn load_profile(self: Pin<&mut ProfileController>, pubkey: &QString);
         
+        /// Check the loaded profile's NIP-05 identifier, using the cache when possible
+        #[qinvokable]
+        fn verify_nip05(self: Pin<&mut ProfileController>);
+        
         /// Reload current profile
         #[qinvokable]
         fn reload(self: Pin<&mut ProfileController>);
@@ -77,9 +90,80 @@ pub mod qobject {
         #[qinvokable]
         fn get_follower_at(self: &ProfileController, index: i32) -> QString;
         
+        /// Forget the previous session's profile, lists and timelines
+        #[qinvokable]
+        fn reset(self: Pin<&mut ProfileController>);
+        
         /// Set the logged-in user's pubkey (to determine is_own_profile)
         #[qinvokable]
         fn set_logged_in_user(self: Pin<&mut ProfileController>, pubkey: &QString);
+        
+        /// Pay a lightning address directly via NWC (tip without a note)
+        /// If lud16 is empty, the loaded profile's lightning address is used
+        #[qinvokable]
+        fn pay_lightning_address(self: Pin<&mut ProfileController>, lud16: &QString, amount_sats: i64, comment: &QString);
+        
+        /// Export the logged-in user's contact list (with petnames and relay hints) to a file
+        /// Emits following_exported when written
+        #[qinvokable]
+        fn export_following(self: Pin<&mut ProfileController>, file_path: &QString);
+        
+        /// Load a contact list backup (JSON) and compare it against the published list
+        /// Emits following_import_ready so the user can confirm before anything is published
+        #[qinvokable]
+        fn import_following(self: Pin<&mut ProfileController>, json: &QString);
+        
+        /// Publish the pending imported contact list
+        #[qinvokable]
+        fn confirm_import_following(self: Pin<&mut ProfileController>);
+        
+        /// Discard the pending imported contact list
+        #[qinvokable]
+        fn cancel_import_following(self: Pin<&mut ProfileController>);
+        
+        /// Load a user's notes for the profile timeline
+        /// until is a unix timestamp for pagination (0 loads the newest page)
+        #[qinvokable]
+        fn load_user_notes(self: Pin<&mut ProfileController>, pubkey: &QString, until: i64);
+        
+        /// Get user note at index (returns JSON)
+        #[qinvokable]
+        fn get_user_note(self: &ProfileController, index: i32) -> QString;
+        
+        /// Filter the profile timeline ("all", "notes", "replies", "media")
+        #[qinvokable]
+        fn set_user_notes_filter(self: Pin<&mut ProfileController>, filter: &QString);
+        
+        /// Load a page of the user's notes that have images or videos, for the media grid
+        /// until is a unix timestamp for pagination (0 loads the newest page)
+        #[qinvokable]
+        fn load_user_media(self: Pin<&mut ProfileController>, pubkey: &QString, until: i64);
+        
+        /// Get media grid note at index (see FeedController::get_media_note)
+        #[qinvokable]
+        fn get_media_note(self: &ProfileController, index: i32) -> QVariant;
+        
+        /// Publish the contact list again after operation_failed ("contacts:<user pubkey>")
+        #[qinvokable]
+        fn retry_operation(self: Pin<&mut ProfileController>, op_id: &QString) -> bool;
+        
+        /// Park a suggested follow (hex or npub) in the review queue instead of following now
+        /// source says where it came from ("search", "profile", ...); false if already followed or queued
+        #[qinvokable]
+        fn queue_follow(self: Pin<&mut ProfileController>, pubkey: &QString, source: &QString) -> bool;
+        
+        /// Follows waiting for review as JSON [{pubkey, npub, source, addedAt, name, picture, nip05}]
+        /// Fetches missing profiles in the background; follow_queue_changed fires when they arrive
+        #[qinvokable]
+        fn get_follow_queue(self: Pin<&mut ProfileController>) -> QString;
+        
+        /// Follow the given queued pubkeys (JSON array) with a single contact list update
+        #[qinvokable]
+        fn approve_follows(self: Pin<&mut ProfileController>, pubkeys_json: &QString);
+        
+        /// Remove the given queued pubkeys (JSON array) without following
+        #[qinvokable]
+        fn dismiss_follows(self: Pin<&mut ProfileController>, pubkeys_json: &QString);
     }
 
     unsafe extern "RustQt" {
@@ -106,6 +190,49 @@ pub mod qobject {
         /// Emitted when an error occurs
         #[qsignal]
         fn error_occurred(self: Pin<&mut ProfileController>, error: &QString);
+        
+        /// Emitted when a lightning address payment succeeds
+        #[qsignal]
+        fn payment_sent(self: Pin<&mut ProfileController>, lud16: &QString, amount_sats: i64);
+        
+        /// Emitted when a lightning address payment fails
+        #[qsignal]
+        fn payment_failed(self: Pin<&mut ProfileController>, lud16: &QString, error: &QString);
+        
+        /// Emitted when the contact list has been exported to a file
+        #[qsignal]
+        fn following_exported(self: Pin<&mut ProfileController>, file_path: &QString, count: i32);
+        
+        /// Emitted when an imported contact list is ready for confirmation
+        #[qsignal]
+        fn following_import_ready(self: Pin<&mut ProfileController>, total: i32, added: i32, removed: i32);
+        
+        /// Emitted when an imported contact list has been published
+        #[qsignal]
+        fn following_imported(self: Pin<&mut ProfileController>, count: i32);
+        
+        /// Emitted when a page of the user's notes is loaded
+        #[qsignal]
+        fn user_notes_loaded(self: Pin<&mut ProfileController>, count: i32);
+        
+        /// Emitted when a page of the media grid is loaded; count is the page size before dedup
+        /// next_until continues paging with load_user_media, 0 once the relays have nothing older
+        #[qsignal]
+        fn user_media_loaded(self: Pin<&mut ProfileController>, count: i32, next_until: i64);
+        
+        /// Emitted when the follow review queue changes; count is the number waiting
+        #[qsignal]
+        fn follow_queue_changed(self: Pin<&mut ProfileController>, count: i32);
+        
+        /// Operation signals, see FeedController; follow/unfollow publish as "contacts:<user pubkey>"
+        #[qsignal]
+        fn operation_started(self: Pin<&mut ProfileController>, op_id: &QString, kind: &QString);
+        
+        #[qsignal]
+        fn operation_succeeded(self: Pin<&mut ProfileController>, op_id: &QString, kind: &QString, result: &QString);
+        
+        #[qsignal]
+        fn operation_failed(self: Pin<&mut ProfileController>, op_id: &QString, kind: &QString, error: &QString);
     }
     
     // Enable threading support for background work with UI updates
@@ -114,11 +241,26 @@ pub mod qobject {
 
 use std::pin::Pin;
 use std::sync::RwLock;
-use cxx_qt_lib::QString;
+use std::sync::atomic::{AtomicU64, Ordering};
+use cxx_qt_lib::{QString, QVariant};
 use cxx_qt::{CxxQtType, Threading};
 use nostr_sdk::prelude::*;
+use crate::core::limits;
+use crate::core::operations::{self, Operation, OperationKind};
+use crate::nostr::contacts::{self, ContactListBackup, GLOBAL_CONTACT_MANAGER};
+use crate::nostr::database::NostrDbManager;
+use crate::nostr::feed::{self, DisplayNote};
+use crate::nostr::follow_queue;
+use crate::nostr::nip05;
 use crate::nostr::profile::ProfileCache;
-use crate::bridge::feed_bridge::create_authenticated_relay_manager;
+use crate::nostr::profile_store;
+use crate::nostr::publish;
+use crate::nostr::zap::{self, GLOBAL_NWC_MANAGER};
+use crate::bridge::feed_bridge::{shared_relay_manager, sign_event_builder};
+use crate::bridge::variant;
+
+/// Number of notes requested per profile timeline page
+const USER_NOTES_PAGE_SIZE: u64 = 30;
 
 // Global tokio runtime for profile operations
 lazy_static::lazy_static! {
@@ -141,6 +283,17 @@ lazy_static::lazy_static! {
     static ref OWN_PROFILE_CACHE: RwLock<Option<CachedOwnProfile>> = RwLock::new(None);
 }
 
+/// Bumped by reset_profile_state, so loads started for the previous account are dropped
+static PROFILE_SESSION: AtomicU64 = AtomicU64::new(0);
+
+/// Forget the previous account's cached profile
+pub fn reset_profile_state() {
+    PROFILE_SESSION.fetch_add(1, Ordering::SeqCst);
+    if let Ok(mut cache) = OWN_PROFILE_CACHE.write() {
+        *cache = None;
+    }
+}
+
 /// Simple profile info for following/followers display
 #[derive(Debug, Clone)]
 struct ProfileListItem {
@@ -173,6 +326,7 @@ pub struct ProfileControllerRust {
     banner: QString,
     website: QString,
     nip05: QString,
+    nip05_verified: bool,
     lud16: QString,
     following_count: i32,
     followers_count: i32,
@@ -181,6 +335,11 @@ pub struct ProfileControllerRust {
     is_own_profile: bool,
     is_following: bool,
     error_message: QString,
+    user_note_count: i32,
+    is_loading_notes: bool,
+    notes_filter: QString,
+    user_media_count: i32,
+    is_loading_media: bool,
     
     // Internal state
     target_pubkey: Option<PublicKey>,
@@ -188,6 +347,14 @@ pub struct ProfileControllerRust {
     following_list: Vec<ProfileListItem>,
     followers_list: Vec<ProfileListItem>,
     user_following: Vec<PublicKey>, // Who the logged-in user is following
+    pending_import: Option<ContactListBackup>, // Imported contact list awaiting confirmation
+    user_notes_pubkey: Option<PublicKey>, // Whose timeline is loaded
+    user_notes_generation: u64, // Bumped when a new timeline starts; older pages are dropped
+    all_user_notes: Vec<DisplayNote>, // Every loaded timeline note
+    user_notes: Vec<DisplayNote>, // Timeline notes matching notes_filter
+    user_media_pubkey: Option<PublicKey>, // Whose media grid is loaded
+    user_media: Vec<DisplayNote>, // Media grid notes, newest first
+    user_media_generation: u64, // Bumped when a new media grid starts; older pages are dropped
 }
 
 impl Default for ProfileControllerRust {
@@ -201,6 +368,7 @@ impl Default for ProfileControllerRust {
             banner: QString::from(""),
             website: QString::from(""),
             nip05: QString::from(""),
+            nip05_verified: false,
             lud16: QString::from(""),
             following_count: 0,
             followers_count: 0,
@@ -209,16 +377,70 @@ impl Default for ProfileControllerRust {
             is_own_profile: false,
             is_following: false,
             error_message: QString::from(""),
+            user_note_count: 0,
+            is_loading_notes: false,
+            notes_filter: QString::from("all"),
+            user_media_count: 0,
+            is_loading_media: false,
             target_pubkey: None,
             logged_in_pubkey: None,
             following_list: Vec::new(),
             followers_list: Vec::new(),
             user_following: Vec::new(),
+            pending_import: None,
+            user_notes_pubkey: None,
+            user_notes_generation: 0,
+            all_user_notes: Vec::new(),
+            user_notes: Vec::new(),
+            user_media_pubkey: None,
+            user_media: Vec::new(),
+            user_media_generation: 0,
         }
     }
 }
 
 impl qobject::ProfileController {
+    /// Clear state from the previous session
+    pub fn reset(mut self: Pin<&mut Self>) {
+        {
+            let mut rust = self.as_mut().rust_mut();
+            rust.target_pubkey = None;
+            rust.logged_in_pubkey = None;
+            rust.following_list.clear();
+            rust.followers_list.clear();
+            rust.user_following.clear();
+            rust.pending_import = None;
+            rust.user_notes_pubkey = None;
+            rust.user_notes_generation += 1;
+            rust.all_user_notes.clear();
+            rust.user_notes.clear();
+            rust.user_media_pubkey = None;
+            rust.user_media_generation += 1;
+            rust.user_media.clear();
+        }
+        self.as_mut().set_public_key(QString::from(""));
+        self.as_mut().set_name(QString::from(""));
+        self.as_mut().set_display_name(QString::from(""));
+        self.as_mut().set_about(QString::from(""));
+        self.as_mut().set_picture(QString::from(""));
+        self.as_mut().set_banner(QString::from(""));
+        self.as_mut().set_website(QString::from(""));
+        self.as_mut().set_nip05(QString::from(""));
+        self.as_mut().set_lud16(QString::from(""));
+        self.as_mut().set_error_message(QString::from(""));
+        self.as_mut().set_nip05_verified(false);
+        self.as_mut().set_following_count(0);
+        self.as_mut().set_followers_count(0);
+        self.as_mut().set_notes_count(0);
+        self.as_mut().set_is_loading(false);
+        self.as_mut().set_is_own_profile(false);
+        self.as_mut().set_is_following(false);
+        self.as_mut().set_user_note_count(0);
+        self.as_mut().set_is_loading_notes(false);
+        self.as_mut().set_user_media_count(0);
+        self.as_mut().set_is_loading_media(false);
+    }
+    
     /// Set the logged-in user's pubkey
     pub fn set_logged_in_user(mut self: Pin<&mut Self>, pubkey: &QString) {
         let pubkey_str = pubkey.to_string();
@@ -233,6 +455,13 @@ impl qobject::ProfileController {
             rust.logged_in_pubkey = parsed;
         }
         
+        // Start a fresh contact list for this user unless it's already theirs
+        if let Ok(mut contacts) = GLOBAL_CONTACT_MANAGER.lock() {
+            if contacts.owner() != parsed {
+                contacts.reset(parsed);
+            }
+        }
+        
         // Fetch who this user is following
         self.fetch_user_following();
     }
@@ -248,18 +477,40 @@ impl qobject::ProfileController {
         };
         
         let qt_thread = self.qt_thread();
+        let session = PROFILE_SESSION.load(Ordering::SeqCst);
         
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = PROFILE_RUNTIME.block_on(async {
-                let mut manager = create_authenticated_relay_manager();
-                manager.connect().await?;
-                manager.fetch_contact_list(&pubkey).await
+                let manager = shared_relay_manager().await?;
+                manager.fetch_contact_list_event(&pubkey).await
             });
+            // Logged out meanwhile; the contact manager belongs to the next account
+            if PROFILE_SESSION.load(Ordering::SeqCst) != session {
+                return;
+            }
             
-            if let Ok(following) = result {
+            // Reconcile with the contact manager, keeping any optimistic changes
+            let result = result.map(|event| {
+                let mut contacts = GLOBAL_CONTACT_MANAGER.lock().unwrap();
+                match event {
+                    Some(event) => contacts.merge_remote(event),
+                    // Never published one; follows start from an empty list
+                    None => contacts.confirm_none(),
+                }
+                // Changes held back while no list was known can go out now
+                let publish = contacts.has_base() && contacts.has_pending();
+                (contacts.following(), publish)
+            });
+            
+            if let Ok((following, publish)) = result {
                 let _ = qt_thread.queue(move |mut qobject| {
-                    let mut rust = qobject.as_mut().rust_mut();
-                    rust.user_following = following;
+                    if PROFILE_SESSION.load(Ordering::SeqCst) != session {
+                        return;
+                    }
+                    qobject.as_mut().rust_mut().user_following = following;
+                    if publish {
+                        qobject.as_mut().schedule_contact_publish();
+                    }
                 });
             }
         });
@@ -336,6 +587,7 @@ impl qobject::ProfileController {
                         self.as_mut().set_is_loading(false);
                         self.as_mut().set_error_message(QString::from(""));
                         self.as_mut().profile_loaded();
+                        self.as_mut().verify_nip05();
                         
                         // Return early - no need to fetch from network
                         return;
@@ -350,15 +602,15 @@ impl qobject::ProfileController {
         let qt_thread = self.qt_thread();
         let pk = target_pubkey.clone();
         let is_own_for_cache = is_own;
+        let session = PROFILE_SESSION.load(Ordering::SeqCst);
         
         // Spawn background thread - non-blocking
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = PROFILE_RUNTIME.block_on(async {
-                let mut manager = create_authenticated_relay_manager();
-                manager.connect().await?;
+                let mut manager = shared_relay_manager().await?;
                 
-                // Fetch profile metadata
-                let profile_events = manager.fetch_profiles(&[pk.clone()]).await?;
+                // Fetch profile metadata, skipping the cache since the profile was opened
+                let profile_events = profile_store::refresh(manager.client(), &[pk]).await;
                 let profile = profile_events.first()
                     .and_then(|e| ProfileCache::from_event(e).ok());
                 
@@ -397,8 +649,8 @@ impl qobject::ProfileController {
                         })
                         .collect();
                     
-                    // Cache the profile if it's own profile
-                    if is_own_for_cache {
+                    // Cache the profile if it's own profile (and still logged in)
+                    if is_own_for_cache && PROFILE_SESSION.load(Ordering::SeqCst) == session {
                         if let Some(ref p) = profile {
                             if let Ok(mut cache) = OWN_PROFILE_CACHE.write() {
                                 *cache = Some(CachedOwnProfile {
@@ -415,6 +667,9 @@ impl qobject::ProfileController {
                     }
                     
                     let _ = qt_thread.queue(move |mut qobject| {
+                        if PROFILE_SESSION.load(Ordering::SeqCst) != session {
+                            return;
+                        }
                         {
                             let mut rust = qobject.as_mut().rust_mut();
                             rust.following_list = following_items;
@@ -443,6 +698,7 @@ impl qobject::ProfileController {
                         qobject.as_mut().set_is_loading(false);
                         qobject.as_mut().set_error_message(QString::from(""));
                         qobject.as_mut().profile_loaded();
+                        qobject.as_mut().verify_nip05();
                         
                         tracing::info!("Profile loaded: following={}, followers={}", following_count, followers_count);
                     });
@@ -450,6 +706,9 @@ impl qobject::ProfileController {
                 Err(e) => {
                     let error_msg = e.clone();
                     let _ = qt_thread.queue(move |mut qobject| {
+                        if PROFILE_SESSION.load(Ordering::SeqCst) != session {
+                            return;
+                        }
                         tracing::error!("Failed to load profile: {}", error_msg);
                         qobject.as_mut().set_is_loading(false);
                         qobject.as_mut().set_error_message(QString::from(&error_msg));
@@ -460,6 +719,36 @@ impl qobject::ProfileController {
         });
     }
     
+    /// Check the loaded profile's NIP-05 identifier, using the cache when possible
+    pub fn verify_nip05(mut self: Pin<&mut Self>) {
+        let nip05_id = self.nip05.to_string();
+        let pubkey = self.public_key.to_string();
+        
+        if nip05_id.is_empty() {
+            self.as_mut().set_nip05_verified(false);
+            return;
+        }
+        
+        if let Some(verified) = nip05::cached_status(&nip05_id, &pubkey) {
+            self.as_mut().set_nip05_verified(verified);
+            return;
+        }
+        
+        self.as_mut().set_nip05_verified(false);
+        let qt_thread = self.qt_thread();
+        
+        limits::spawn_limited(move || {
+            let verified = PROFILE_RUNTIME.block_on(nip05::check(&nip05_id, &pubkey));
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                // Ignore results for a profile that is no longer shown
+                if qobject.as_ref().public_key.to_string() == pubkey {
+                    qobject.as_mut().set_nip05_verified(verified);
+                }
+            });
+        });
+    }
+    
     /// Reload current profile
     pub fn reload(self: Pin<&mut Self>) {
         let pubkey = {
@@ -567,23 +856,23 @@ impl qobject::ProfileController {
             self.as_ref().target_pubkey.clone()
         };
         
-        let Some(_target_pk) = target else {
+        let Some(target_pk) = target else {
             return;
         };
         
-        // TODO: Update contact list and publish
-        // For now, just update local state
+        // Optimistic update - the kind 3 is published once toggling settles
+        let following = {
+            let mut contacts = GLOBAL_CONTACT_MANAGER.lock().unwrap();
+            contacts.follow(&target_pk);
+            contacts.following()
+        };
+        
         self.as_mut().set_is_following(true);
-        {
-            let mut rust = self.as_mut().rust_mut();
-            if let Some(pk) = rust.target_pubkey.clone() {
-                if !rust.user_following.contains(&pk) {
-                    rust.user_following.push(pk);
-                }
-            }
-        }
+        self.as_mut().rust_mut().user_following = following;
         self.as_mut().follow_status_changed();
         tracing::info!("Follow status changed to: following");
+        
+        self.schedule_contact_publish();
     }
     
     /// Unfollow user
@@ -592,21 +881,224 @@ impl qobject::ProfileController {
             self.as_ref().target_pubkey.clone()
         };
         
-        let Some(_target_pk) = target else {
+        let Some(target_pk) = target else {
             return;
         };
         
-        // TODO: Update contact list and publish
-        // For now, just update local state
+        // Optimistic update - the kind 3 is published once toggling settles
+        let following = {
+            let mut contacts = GLOBAL_CONTACT_MANAGER.lock().unwrap();
+            contacts.unfollow(&target_pk);
+            contacts.following()
+        };
+        
         self.as_mut().set_is_following(false);
-        {
-            let mut rust = self.as_mut().rust_mut();
-            if let Some(pk) = rust.target_pubkey.clone() {
-                rust.user_following.retain(|p| *p != pk);
-            }
-        }
+        self.as_mut().rust_mut().user_following = following;
         self.as_mut().follow_status_changed();
         tracing::info!("Follow status changed to: not following");
+        
+        self.schedule_contact_publish();
+    }
+    
+    /// Queue a suggested follow for review
+    pub fn queue_follow(mut self: Pin<&mut Self>, pubkey: &QString, source: &QString) -> bool {
+        match follow_queue::queue(&pubkey.to_string(), &source.to_string()) {
+            Ok(queued) => {
+                if queued {
+                    self.as_mut().follow_queue_changed(follow_queue::len() as i32);
+                }
+                queued
+            }
+            Err(e) => {
+                self.as_mut().error_occurred(&QString::from(&e));
+                false
+            }
+        }
+    }
+    
+    /// Queued follows with whatever profile data is cached
+    pub fn get_follow_queue(self: Pin<&mut Self>) -> QString {
+        let queue = follow_queue::list();
+        let db = NostrDbManager::global().ok();
+        
+        let mut missing: Vec<PublicKey> = Vec::new();
+        let items: Vec<serde_json::Value> = queue.iter()
+            .map(|q| {
+                let profile = db.as_ref().and_then(|db| db.get_profile(&q.pubkey));
+                let pk = PublicKey::parse(&q.pubkey).ok();
+                if profile.is_none() {
+                    missing.extend(pk);
+                }
+                serde_json::json!({
+                    "pubkey": q.pubkey,
+                    "npub": pk.and_then(|pk| pk.to_bech32().ok()).unwrap_or_default(),
+                    "source": q.source,
+                    "addedAt": q.added_at,
+                    "name": profile.as_ref().and_then(|p| p.display_name.clone().filter(|n| !n.is_empty()).or(p.name.clone())),
+                    "picture": profile.as_ref().and_then(|p| p.picture.clone()),
+                    "nip05": profile.as_ref().and_then(|p| p.nip05.clone()),
+                })
+            })
+            .collect();
+        
+        if !missing.is_empty() {
+            let qt_thread = self.qt_thread();
+            limits::spawn_limited(move || {
+                let fetched = PROFILE_RUNTIME.block_on(async {
+                    let manager = shared_relay_manager().await?;
+                    let events = manager.fetch_profiles(&missing).await?;
+                    Ok::<usize, String>(events.len())
+                });
+                match fetched {
+                    Ok(0) => {}
+                    Ok(_) => {
+                        let _ = qt_thread.queue(|mut qobject| {
+                            qobject.as_mut().follow_queue_changed(follow_queue::len() as i32);
+                        });
+                    }
+                    Err(e) => tracing::warn!("Failed to fetch queued follow profiles: {}", e),
+                }
+            });
+        }
+        
+        QString::from(&serde_json::Value::from(items).to_string())
+    }
+    
+    /// Follow a batch of queued users, publishing one contact list
+    pub fn approve_follows(mut self: Pin<&mut Self>, pubkeys_json: &QString) {
+        let pubkeys: Vec<String> = serde_json::from_str(&pubkeys_json.to_string()).unwrap_or_default();
+        let approved = follow_queue::take(&pubkeys);
+        if approved.is_empty() {
+            return;
+        }
+        
+        let following = {
+            let mut contacts = GLOBAL_CONTACT_MANAGER.lock().unwrap();
+            contacts.follow_all(&approved);
+            contacts.following()
+        };
+        tracing::info!("Approved {} queued follows", approved.len());
+        
+        let is_following = self.as_ref().target_pubkey
+            .map(|pk| following.contains(&pk))
+            .unwrap_or(false);
+        self.as_mut().rust_mut().user_following = following;
+        if is_following != self.as_ref().is_following {
+            self.as_mut().set_is_following(is_following);
+            self.as_mut().follow_status_changed();
+        }
+        self.as_mut().follow_queue_changed(follow_queue::len() as i32);
+        
+        self.schedule_contact_publish();
+    }
+    
+    /// Drop queued users without following them
+    pub fn dismiss_follows(mut self: Pin<&mut Self>, pubkeys_json: &QString) {
+        let pubkeys: Vec<String> = serde_json::from_str(&pubkeys_json.to_string()).unwrap_or_default();
+        if !follow_queue::take(&pubkeys).is_empty() {
+            self.as_mut().follow_queue_changed(follow_queue::len() as i32);
+        }
+    }
+    
+    /// Publish the contact list after a quiet period, batching quick follow toggles
+    fn schedule_contact_publish(mut self: Pin<&mut Self>) {
+        let Some(user_pk) = self.as_ref().logged_in_pubkey.clone() else {
+            return;
+        };
+        
+        let op = Operation::new(OperationKind::Contacts, &user_pk.to_hex());
+        self.as_mut().begin_operation(&op);
+        
+        let generation = GLOBAL_CONTACT_MANAGER.lock().unwrap().generation();
+        let qt_thread = self.qt_thread();
+        
+        std::thread::spawn(move || {
+            std::thread::sleep(contacts::CONTACT_PUBLISH_DEBOUNCE);
+            
+            // A newer toggle will publish instead
+            if GLOBAL_CONTACT_MANAGER.lock().unwrap().generation() != generation {
+                return;
+            }
+            
+            let result = PROFILE_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                
+                // Reconcile against relays first so follows made elsewhere survive.
+                // If the fetch fails, don't publish - an incomplete list would clobber follows.
+                let remote = manager.fetch_contact_list_event(&user_pk).await?;
+                
+                let builder = {
+                    let mut contacts = GLOBAL_CONTACT_MANAGER.lock().unwrap();
+                    match remote {
+                        Some(remote) => contacts.merge_remote(remote),
+                        None => contacts.confirm_none(),
+                    }
+                    contacts.update_builder()?
+                };
+                
+                let event = sign_event_builder(builder, user_pk).await?;
+                publish::send(manager.client(), &event).await?;
+                
+                let mut contacts = GLOBAL_CONTACT_MANAGER.lock().unwrap();
+                contacts.mark_published(event, generation);
+                Ok::<Vec<PublicKey>, String>(contacts.following())
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().end_operation(&op, &result.as_ref().map(|_| String::new()).map_err(|e| e.clone()));
+                match result {
+                    Ok(following) => {
+                        tracing::info!("Published contact list with {} follows", following.len());
+                        let is_following = qobject.as_ref().target_pubkey
+                            .map(|pk| following.contains(&pk))
+                            .unwrap_or(false);
+                        qobject.as_mut().rust_mut().user_following = following;
+                        if is_following != qobject.as_ref().is_following {
+                            qobject.as_mut().set_is_following(is_following);
+                            qobject.as_mut().follow_status_changed();
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to publish contact list: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                        qobject.as_mut().error_occurred(&QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+    
+    /// Publish the contact list again after a failure
+    pub fn retry_operation(self: Pin<&mut Self>, op_id: &QString) -> bool {
+        match operations::take_failed(&op_id.to_string()) {
+            Some(op) if op.kind == OperationKind::Contacts => {
+                self.schedule_contact_publish();
+                true
+            }
+            _ => false,
+        }
+    }
+    
+    /// Announce an operation on operation_started
+    fn begin_operation(mut self: Pin<&mut Self>, op: &Operation) {
+        operations::started(op);
+        self.as_mut().operation_started(&QString::from(&op.id()), &QString::from(op.kind.as_str()));
+    }
+    
+    /// Report an operation's outcome on operation_succeeded / operation_failed
+    fn end_operation(mut self: Pin<&mut Self>, op: &Operation, result: &Result<String, String>) {
+        let id = QString::from(&op.id());
+        let kind = QString::from(op.kind.as_str());
+        match result {
+            Ok(value) => {
+                operations::succeeded(op);
+                self.as_mut().operation_succeeded(&id, &kind, &QString::from(value));
+            }
+            Err(e) => {
+                operations::failed(op, e);
+                self.as_mut().operation_failed(&id, &kind, &QString::from(e));
+            }
+        }
     }
     
     /// Get following list as JSON
@@ -635,10 +1127,9 @@ impl qobject::ProfileController {
         
         let qt_thread = self.qt_thread();
         
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = PROFILE_RUNTIME.block_on(async {
-                let mut manager = create_authenticated_relay_manager();
-                manager.connect().await?;
+                let manager = shared_relay_manager().await?;
                 
                 // Fetch recent notes by this author
                 let filter = Filter::new()
@@ -677,4 +1168,406 @@ impl qobject::ProfileController {
             QString::from("{}")
         }
     }
+    
+    /// Pay a lightning address directly via NWC
+    pub fn pay_lightning_address(self: Pin<&mut Self>, lud16: &QString, amount_sats: i64, comment: &QString) {
+        let lud16_str = {
+            let given = lud16.to_string().trim().to_string();
+            if given.is_empty() { self.lud16.to_string() } else { given }
+        };
+        let comment_str = comment.to_string();
+        
+        let qt_thread = self.qt_thread();
+        
+        if lud16_str.is_empty() || amount_sats <= 0 {
+            let error = if lud16_str.is_empty() {
+                "This profile doesn't have a lightning address"
+            } else {
+                "Amount must be greater than zero"
+            };
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().payment_failed(&QString::from(&lud16_str), &QString::from(error));
+            });
+            return;
+        }
+        
+        tracing::info!("Tipping {} sats to {}", amount_sats, lud16_str);
+        
+        limits::spawn_limited(move || {
+            let result = PROFILE_RUNTIME.block_on(async {
+                let mut nwc = GLOBAL_NWC_MANAGER.lock().await;
+                if !nwc.is_connected() {
+                    return Err("NWC wallet not connected. Please connect your wallet in Settings.".to_string());
+                }
+                zap::pay_lightning_address(&mut *nwc, &lud16_str, amount_sats as u64, &comment_str).await
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok(_preimage) => {
+                        qobject.as_mut().payment_sent(&QString::from(&lud16_str), amount_sats);
+                    }
+                    Err(e) => {
+                        tracing::error!("Lightning address payment failed: {}", e);
+                        qobject.as_mut().payment_failed(&QString::from(&lud16_str), &QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+    
+    /// Export the logged-in user's contact list to a file
+    pub fn export_following(mut self: Pin<&mut Self>, file_path: &QString) {
+        let Some(pubkey) = self.as_ref().logged_in_pubkey.clone() else {
+            self.as_mut().error_occurred(&QString::from("Not logged in"));
+            return;
+        };
+        
+        let path = file_path.to_string();
+        let path = path.strip_prefix("file://").unwrap_or(&path).to_string();
+        let qt_thread = self.qt_thread();
+        
+        limits::spawn_limited(move || {
+            let result = PROFILE_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                let event = manager.fetch_contact_list_event(&pubkey).await?
+                    .ok_or("No contact list found on relays")?;
+                
+                let backup = ContactListBackup::from_event(&event);
+                std::fs::write(&path, backup.to_json_pretty())
+                    .map_err(|e| format!("Failed to write contact list backup: {}", e))?;
+                
+                Ok::<usize, String>(backup.contacts.len())
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok(count) => {
+                        tracing::info!("Exported {} contacts to {}", count, path);
+                        qobject.as_mut().following_exported(&QString::from(&path), count as i32);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to export contact list: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                        qobject.as_mut().error_occurred(&QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+    
+    /// Parse a contact list backup and diff it against the published list
+    pub fn import_following(mut self: Pin<&mut Self>, json: &QString) {
+        let Some(pubkey) = self.as_ref().logged_in_pubkey.clone() else {
+            self.as_mut().error_occurred(&QString::from("Not logged in"));
+            return;
+        };
+        
+        let backup = match ContactListBackup::from_json(&json.to_string()) {
+            Ok(backup) => backup,
+            Err(e) => {
+                self.as_mut().set_error_message(QString::from(&e));
+                self.as_mut().error_occurred(&QString::from(&e));
+                return;
+            }
+        };
+        
+        let qt_thread = self.qt_thread();
+        
+        limits::spawn_limited(move || {
+            let current = PROFILE_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                manager.fetch_contact_list_event(&pubkey).await
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                match current {
+                    Ok(event) => {
+                        let current = event.as_ref().map(contacts::contacts_from_event).unwrap_or_default();
+                        let (added, removed) = backup.diff(&current);
+                        let total = backup.contacts.len() as i32;
+                        
+                        qobject.as_mut().rust_mut().pending_import = Some(backup);
+                        qobject.as_mut().following_import_ready(total, added as i32, removed as i32);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to fetch current contact list: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                        qobject.as_mut().error_occurred(&QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+    
+    /// Sign and publish the pending imported contact list
+    pub fn confirm_import_following(mut self: Pin<&mut Self>) {
+        let Some(pubkey) = self.as_ref().logged_in_pubkey.clone() else {
+            self.as_mut().error_occurred(&QString::from("Not logged in"));
+            return;
+        };
+        
+        let Some(backup) = self.as_mut().rust_mut().pending_import.take() else {
+            self.as_mut().error_occurred(&QString::from("No contact list import pending"));
+            return;
+        };
+        
+        let qt_thread = self.qt_thread();
+        
+        limits::spawn_limited(move || {
+            let result = PROFILE_RUNTIME.block_on(async {
+                let event = sign_event_builder(backup.to_event_builder(), pubkey).await?;
+                
+                let manager = shared_relay_manager().await?;
+                publish::send(manager.client(), &event).await?;
+                
+                let mut contacts = GLOBAL_CONTACT_MANAGER.lock().unwrap();
+                contacts.merge_remote(event);
+                Ok::<Vec<PublicKey>, String>(contacts.following())
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok(following) => {
+                        let count = following.len() as i32;
+                        tracing::info!("Imported contact list with {} follows", count);
+                        qobject.as_mut().rust_mut().user_following = following;
+                        qobject.as_mut().following_imported(count);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to import contact list: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                        qobject.as_mut().error_occurred(&QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+    
+    /// Discard the pending imported contact list
+    pub fn cancel_import_following(mut self: Pin<&mut Self>) {
+        self.as_mut().rust_mut().pending_import = None;
+    }
+    
+    /// Load a page of a user's notes
+    pub fn load_user_notes(mut self: Pin<&mut Self>, pubkey: &QString, until: i64) {
+        let pubkey_str = pubkey.to_string();
+        let Ok(pk) = PublicKey::parse(&pubkey_str) else {
+            self.as_mut().set_error_message(QString::from("Invalid public key"));
+            self.as_mut().error_occurred(&QString::from("Invalid public key"));
+            return;
+        };
+        
+        // A different user or a fresh load starts a new timeline, replacing any
+        // load in progress; only paging waits for the previous page
+        let append = until > 0 && self.user_notes_pubkey == Some(pk);
+        if append && self.is_loading_notes {
+            return;
+        }
+        if !append {
+            {
+                let mut rust = self.as_mut().rust_mut();
+                rust.user_notes_pubkey = Some(pk);
+                rust.user_notes_generation += 1;
+                rust.all_user_notes.clear();
+                rust.user_notes.clear();
+            }
+            self.as_mut().set_user_note_count(0);
+        }
+        self.as_mut().set_is_loading_notes(true);
+        let generation = self.user_notes_generation;
+        
+        let qt_thread = self.qt_thread();
+        let until_ts = if until > 0 { Some(Timestamp::from(until as u64)) } else { None };
+        
+        limits::spawn_limited(move || {
+            let result = PROFILE_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                
+                let events = manager.fetch_user_notes(&pk, USER_NOTES_PAGE_SIZE, until_ts).await?;
+                
+                // Author plus reply-to targets for display names
+                let mut pubkeys: Vec<PublicKey> = vec![pk];
+                for pk_hex in feed::extract_reply_to_pubkeys(events.iter()) {
+                    if let Ok(p) = PublicKey::parse(&pk_hex) {
+                        pubkeys.push(p);
+                    }
+                }
+                pubkeys.sort_by_key(|p| p.to_hex());
+                pubkeys.dedup();
+                
+                let profiles = manager.fetch_profiles(&pubkeys).await.unwrap_or_default();
+                let mut profile_map = std::collections::HashMap::new();
+                for profile_event in profiles.iter() {
+                    if let Ok(metadata) = Metadata::from_json(&profile_event.content) {
+                        profile_map.insert(profile_event.pubkey.to_hex(), ProfileCache::from_metadata(&metadata));
+                    }
+                }
+                
+                let author = profile_map.get(&pk.to_hex()).cloned();
+                let mut notes: Vec<DisplayNote> = events.iter()
+                    .map(|e| DisplayNote::from_event_with_profiles(e, author.as_ref(), &profile_map))
+                    .collect();
+                notes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                
+                Ok::<Vec<DisplayNote>, String>(notes)
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                // A timeline for another profile may have been started meanwhile
+                if qobject.as_ref().user_notes_generation != generation {
+                    return;
+                }
+                qobject.as_mut().set_is_loading_notes(false);
+                match result {
+                    Ok(page) => {
+                        let page_len = page.len() as i32;
+                        {
+                            let mut rust = qobject.as_mut().rust_mut();
+                            // Pages overlap at the until boundary
+                            for note in page {
+                                if !rust.all_user_notes.iter().any(|n| n.id == note.id) {
+                                    rust.all_user_notes.push(note);
+                                }
+                            }
+                        }
+                        qobject.as_mut().apply_user_notes_filter();
+                        qobject.as_mut().user_notes_loaded(page_len);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to load user notes: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                        qobject.as_mut().error_occurred(&QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+    
+    /// Get user note at index
+    pub fn get_user_note(&self, index: i32) -> QString {
+        if let Some(note) = self.user_notes.get(index as usize) {
+            QString::from(&note.to_json())
+        } else {
+            QString::from("{}")
+        }
+    }
+    
+    /// Load a page of the user's media notes
+    pub fn load_user_media(mut self: Pin<&mut Self>, pubkey: &QString, until: i64) {
+        let Ok(pk) = PublicKey::parse(&pubkey.to_string()) else {
+            self.as_mut().set_error_message(QString::from("Invalid public key"));
+            self.as_mut().error_occurred(&QString::from("Invalid public key"));
+            return;
+        };
+        
+        // Only paging waits for the previous page; opening another profile starts over
+        let append = until > 0 && self.user_media_pubkey == Some(pk);
+        if append && self.is_loading_media {
+            return;
+        }
+        if !append {
+            {
+                let mut rust = self.as_mut().rust_mut();
+                rust.user_media_pubkey = Some(pk);
+                rust.user_media_generation += 1;
+                rust.user_media.clear();
+            }
+            self.as_mut().set_user_media_count(0);
+        }
+        self.as_mut().set_is_loading_media(true);
+        let generation = self.user_media_generation;
+        
+        let qt_thread = self.qt_thread();
+        let until_ts = if until > 0 { Some(Timestamp::from(until as u64)) } else { None };
+        
+        limits::spawn_limited(move || {
+            let result = PROFILE_RUNTIME.block_on(async {
+                let manager = shared_relay_manager().await?;
+                let (events, oldest) = manager.fetch_media_page(&[pk], USER_NOTES_PAGE_SIZE, until_ts).await?;
+                
+                let author = manager.fetch_profiles(&[pk]).await
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find_map(|e| ProfileCache::from_event(&e).ok());
+                let mut notes: Vec<DisplayNote> = events.iter()
+                    .map(|e| DisplayNote::from_event(e, author.as_ref()))
+                    .collect();
+                notes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                
+                // Continue below the relays' page, not the last note with media
+                let next_until = oldest.map(|ts| ts.as_u64().saturating_sub(1) as i64).unwrap_or(0);
+                Ok::<(Vec<DisplayNote>, i64), String>((notes, next_until))
+            });
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                // A grid for another profile, or a fresh one, may have been started meanwhile
+                if qobject.as_ref().user_media_generation != generation {
+                    return;
+                }
+                qobject.as_mut().set_is_loading_media(false);
+                match result {
+                    Ok((page, next_until)) => {
+                        let page_len = page.len() as i32;
+                        let count = {
+                            let mut rust = qobject.as_mut().rust_mut();
+                            for note in page {
+                                if !rust.user_media.iter().any(|n| n.id == note.id) {
+                                    rust.user_media.push(note);
+                                }
+                            }
+                            rust.user_media.len() as i32
+                        };
+                        qobject.as_mut().set_user_media_count(count);
+                        qobject.as_mut().user_media_loaded(page_len, next_until);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to load user media: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                        qobject.as_mut().error_occurred(&QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+    
+    /// Get media grid note at index
+    pub fn get_media_note(&self, index: i32) -> QVariant {
+        match self.user_media.get(index as usize) {
+            Some(note) => variant::from_json(&note.to_media_value()),
+            None => variant::empty_map(),
+        }
+    }
+    
+    /// Change the profile timeline filter
+    pub fn set_user_notes_filter(mut self: Pin<&mut Self>, filter: &QString) {
+        let filter = match filter.to_string().as_str() {
+            "notes" => "notes",
+            "replies" => "replies",
+            "media" => "media",
+            _ => "all",
+        };
+        self.as_mut().set_notes_filter(QString::from(filter));
+        self.apply_user_notes_filter();
+    }
+    
+    /// Rebuild the visible timeline from all loaded notes
+    fn apply_user_notes_filter(mut self: Pin<&mut Self>) {
+        let filter = self.notes_filter.to_string();
+        let count = {
+            let mut rust = self.as_mut().rust_mut();
+            let filtered: Vec<DisplayNote> = rust.all_user_notes.iter()
+                .filter(|n| match filter.as_str() {
+                    "notes" => !n.is_reply,
+                    "replies" => n.is_reply,
+                    "media" => !n.images.is_empty() || !n.videos.is_empty(),
+                    _ => true,
+                })
+                .cloned()
+                .collect();
+            rust.user_notes = filtered;
+            rust.user_notes.len() as i32
+        };
+        self.as_mut().set_user_note_count(count);
+    }
 }
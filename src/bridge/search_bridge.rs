@@ -1,17 +1,22 @@
 //! Search bridge - QML bridge for search functionality
 //! Supports searching for notes, users, and hashtags with time range filtering
+//! Note search uses NIP-50 relays when reachable, merged with the local cache
+//! Local results come from the full-text index and are shown before relays answer
 
 use cxx_qt::CxxQtType;
-use cxx_qt_lib::QString;
+use cxx_qt_lib::{QString, QVariant};
 use std::pin::Pin;
 
-use nostr_sdk::{Filter, Kind, Timestamp};
+use nostr_sdk::{Event, EventId, Filter, Kind, PublicKey, Timestamp};
 
 #[cxx_qt::bridge]
 mod ffi {
     unsafe extern "C++" {
         include!("cxx-qt-lib/qstring.h");
         type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
     }
 
     unsafe extern "RustQt" {
@@ -23,6 +28,8 @@ mod ffi {
         #[qproperty(i32, note_count)]
         #[qproperty(QString, search_type)]
         #[qproperty(i32, time_range_days)]
+        #[qproperty(bool, has_more_notes)]
+        #[qproperty(bool, rank_by_proximity)]
         type SearchController = super::SearchControllerRust;
 
         #[qinvokable]
@@ -34,6 +41,9 @@ mod ffi {
         #[qinvokable]
         fn search_notes_with_time(self: Pin<&mut SearchController>, query: &QString, days: i32);
 
+        #[qinvokable]
+        fn load_more_notes(self: Pin<&mut SearchController>);
+
         #[qinvokable]
         fn search_hashtag(self: Pin<&mut SearchController>, hashtag: &QString);
 
@@ -47,10 +57,20 @@ mod ffi {
         fn get_user(self: &SearchController, index: i32) -> QString;
 
         #[qinvokable]
-        fn get_note(self: &SearchController, index: i32) -> QString;
+        fn get_note(self: &SearchController, index: i32) -> QVariant;
 
         #[qinvokable]
         fn clear_results(self: Pin<&mut SearchController>);
+
+        /// Users followed by people you follow, ranked (JSON array)
+        /// Also refreshes follows' contact lists; suggested_follows_updated fires when new data arrives
+        #[qinvokable]
+        fn get_suggested_follows(self: Pin<&mut SearchController>, limit: i32) -> QString;
+
+        /// Save a user to the follow review queue (see ProfileController::approve_follows)
+        /// Returns false if already followed or queued
+        #[qinvokable]
+        fn queue_follow(self: Pin<&mut SearchController>, pubkey: &QString, source: &QString) -> bool;
     }
 
     unsafe extern "RustQt" {
@@ -59,6 +79,9 @@ mod ffi {
 
         #[qsignal]
         fn error_occurred(self: Pin<&mut SearchController>, error: QString);
+
+        #[qsignal]
+        fn suggested_follows_updated(self: Pin<&mut SearchController>);
     }
 
     impl cxx_qt::Threading for SearchController {}
@@ -69,11 +92,35 @@ use cxx_qt::Threading;
 // Use global relay manager and local runtime
 lazy_static::lazy_static! {
     static ref SEARCH_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+    // Set while follows' contact lists are being refreshed for suggestions
+    static ref SUGGESTIONS_REFRESHING: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
 }
 
 // Alias for cleaner code
-use crate::nostr::relay::GLOBAL_RELAY_MANAGER as SEARCH_RELAY_MANAGER;
-use crate::nostr::database::NostrDbManager;
+use crate::core::limits;
+use crate::bridge::feed_bridge::shared_relay_manager;
+use crate::bridge::variant;
+use crate::core::config::Config;
+use crate::nostr::database::{CachedProfile, NostrDbManager};
+use crate::nostr::contacts::GLOBAL_CONTACT_MANAGER;
+use crate::nostr::follow_queue;
+use crate::nostr::profile_store;
+use crate::nostr::search;
+use crate::nostr::social_graph::{self, SocialGraph};
+use crate::nostr::report;
+use crate::nostr::wot;
+
+/// Most notes shown for a note search
+const MAX_NOTE_RESULTS: usize = 100;
+
+/// Cached notes scanned for local matches when the search index is unavailable
+const LOCAL_SCAN_LIMIT: usize = 5000;
+
+/// Notes per page of local index results
+const NOTE_PAGE_SIZE: usize = 50;
+
+/// Most profiles taken from the search index for a user search
+const LOCAL_PROFILE_LIMIT: usize = 100;
 
 /// Search result types
 #[derive(Clone, Debug, Default)]
@@ -84,6 +131,9 @@ pub struct UserResult {
     pub picture: String,
     pub nip05: String,
     pub about: String,
+    /// How many of the user's follows follow this user
+    pub followed_by: usize,
+    pub is_following: bool,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -94,6 +144,8 @@ pub struct NoteResult {
     pub author_picture: String,
     pub content: String,
     pub created_at: i64,
+    /// Author is outside the web-of-trust radius (demoted)
+    pub outside_trust: bool,
 }
 
 /// Rust struct for SearchController
@@ -104,9 +156,14 @@ pub struct SearchControllerRust {
     note_count: i32,
     search_type: QString,
     time_range_days: i32,
+    has_more_notes: bool,
+    rank_by_proximity: bool,
     
     user_results: Vec<UserResult>,
     note_results: Vec<NoteResult>,
+    /// Local index results consumed so far, for load_more_notes
+    local_offset: usize,
+    loading_more: bool,
 }
 
 impl Default for SearchControllerRust {
@@ -118,8 +175,12 @@ impl Default for SearchControllerRust {
             note_count: 0,
             search_type: QString::from("notes"), // Default to notes search
             time_range_days: 7, // Default to 7 days
+            has_more_notes: false,
+            rank_by_proximity: false,
             user_results: Vec::new(),
             note_results: Vec::new(),
+            local_offset: 0,
+            loading_more: false,
         }
     }
 }
@@ -131,6 +192,73 @@ fn fuzzy_match(text: &str, search_words: &[String]) -> bool {
     search_words.iter().all(|word| text_lower.contains(word))
 }
 
+/// Local note matches: the full-text index when available, otherwise a scan of recent cached notes
+fn local_note_matches(db: &NostrDbManager, query: &str, search_words: &[String], since: i64, offset: usize) -> Vec<Event> {
+    if db.has_search_index() {
+        match db.search_notes_indexed(query, Some(since), NOTE_PAGE_SIZE, offset) {
+            Ok(events) => return events,
+            Err(e) => tracing::warn!("Search index query failed, scanning cache: {}", e),
+        }
+    }
+    if offset > 0 {
+        return Vec::new();
+    }
+    db.query_events(&[Kind::TextNote.as_u16()], since, LOCAL_SCAN_LIMIT)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|event| fuzzy_match(&event.content, search_words))
+        .collect()
+}
+
+/// Build note results, resolving author names from the profile cache
+fn build_note_results(events: Vec<Event>) -> Vec<NoteResult> {
+    let db = NostrDbManager::global().ok();
+    let results = events.into_iter()
+        .filter(|event| !report::is_hidden(&event.id.to_hex(), &event.pubkey.to_hex()))
+        .map(|event| {
+            let profile = db.as_ref().and_then(|db| db.get_profile(&event.pubkey.to_hex()));
+            NoteResult {
+                id: event.id.to_hex(),
+                pubkey: event.pubkey.to_hex(),
+                author_name: profile.as_ref()
+                    .and_then(|p| p.display_name.clone().or(p.name.clone()))
+                    .unwrap_or_default(),
+                author_picture: profile.and_then(|p| p.picture).unwrap_or_default(),
+                content: event.content,
+                created_at: event.created_at.as_secs() as i64,
+                outside_trust: false,
+            }
+        })
+        .collect();
+    apply_trust(results)
+}
+
+/// Drop note results from authors outside the web-of-trust radius, or move
+/// them after trusted ones (keeping order otherwise) when demoting
+fn apply_trust(mut results: Vec<NoteResult>) -> Vec<NoteResult> {
+    if !wot::policy().enabled() {
+        return results;
+    }
+    for note in results.iter_mut() {
+        note.outside_trust = wot::is_outside(&note.pubkey);
+    }
+    if wot::policy().action == wot::TrustAction::Hide {
+        results.retain(|note| !note.outside_trust);
+    } else {
+        results.sort_by_key(|note| note.outside_trust);
+    }
+    results
+}
+
+/// The logged-in user's social graph from locally stored contact lists
+fn load_social_graph() -> Option<SocialGraph> {
+    let contacts = GLOBAL_CONTACT_MANAGER.lock().ok()?;
+    let user = contacts.owner()?;
+    let following = contacts.following();
+    drop(contacts);
+    Some(SocialGraph::load(&user, &following))
+}
+
 /// Calculate timestamp for N days ago
 fn days_ago(days: i32) -> Timestamp {
     let now = std::time::SystemTime::now()
@@ -144,9 +272,9 @@ fn days_ago(days: i32) -> Timestamp {
 impl ffi::SearchController {
     pub fn search_users(mut self: Pin<&mut Self>, query: &QString) {
         let query_str = query.to_string();
-        println!("[Search] search_users called with query: '{}'", query_str);
+        tracing::debug!("User search for '{}'", query_str);
         if query_str.trim().is_empty() {
-            println!("[Search] Empty query, returning");
+            tracing::debug!("Empty query, returning");
             return;
         }
         
@@ -163,22 +291,40 @@ impl ffi::SearchController {
         self.as_mut().set_search_type(QString::from("users"));
         
         let query_lower = query_str.to_lowercase();
+        let rank_by_proximity = *self.rank_by_proximity();
         let qt_thread = self.qt_thread();
         
-        std::thread::spawn(move || {
-            println!("[Search] Background thread started");
+        limits::spawn_limited(move || {
+            tracing::debug!("Background thread started");
             let result: Result<Vec<UserResult>, String> = SEARCH_RUNTIME.block_on(async {
                 let mut results = Vec::new();
                 let mut seen_pubkeys = std::collections::HashSet::new();
                 
                 // First, search local database cache
-                println!("[Search] Searching local cache...");
+                tracing::debug!("Searching local cache...");
                 if let Ok(db) = NostrDbManager::global() {
                     let cached_count = db.profile_count();
-                    println!("[Search] Local cache has {} profiles", cached_count);
+                    tracing::debug!("Local cache has {} profiles", cached_count);
+                    
+                    // Full-text index covers every profile ever cached, not just the hot set
+                    for event in db.search_profiles_indexed(&query_str, LOCAL_PROFILE_LIMIT).unwrap_or_default() {
+                        let Ok(metadata) = serde_json::from_str::<serde_json::Value>(&event.content) else { continue };
+                        let field = |name: &str| metadata.get(name).and_then(|v| v.as_str()).unwrap_or("").to_string();
+                        if seen_pubkeys.insert(event.pubkey.to_hex()) {
+                            results.push(UserResult {
+                                pubkey: event.pubkey.to_hex(),
+                                name: field("name"),
+                                display_name: field("display_name"),
+                                picture: field("picture"),
+                                nip05: field("nip05"),
+                                about: field("about"),
+                                ..Default::default()
+                            });
+                        }
+                    }
                     
                     let local_results = db.search_profiles(&query_lower);
-                    println!("[Search] Found {} matches in local cache", local_results.len());
+                    tracing::debug!("Found {} matches in local cache", local_results.len());
                     
                     for profile in local_results {
                         if seen_pubkeys.insert(profile.pubkey.clone()) {
@@ -189,15 +335,15 @@ impl ffi::SearchController {
                                 picture: profile.picture.unwrap_or_default(),
                                 nip05: profile.nip05.unwrap_or_default(),
                                 about: profile.about.unwrap_or_default(),
+                                ..Default::default()
                             });
                         }
                     }
                 }
                 
                 // Then fetch from relays to find more
-                let rm = SEARCH_RELAY_MANAGER.read().unwrap();
-                if let Some(manager) = rm.as_ref() {
-                    println!("[Search] Fetching from relays (limit 500)...");
+                if let Ok(manager) = shared_relay_manager().await {
+                    tracing::debug!("Fetching from relays (limit 500)...");
                     
                     // Fetch more metadata events with a larger limit
                     let filter = Filter::new()
@@ -206,14 +352,12 @@ impl ffi::SearchController {
                     
                     match manager.client().fetch_events(filter, std::time::Duration::from_secs(15)).await {
                         Ok(events) => {
-                            println!("[Search] Fetched {} metadata events from relays", events.len());
+                            tracing::debug!("Fetched {} metadata events from relays", events.len());
                             let mut relay_matches = 0;
                             
                             for event in events {
-                                // Store in local cache for future searches
-                                if let Ok(db) = NostrDbManager::global() {
-                                    let _ = db.ingest_profile(&event);
-                                }
+                                // Store for future searches and profile lookups
+                                profile_store::ingest(std::slice::from_ref(&event));
                                 
                                 if let Ok(metadata) = serde_json::from_str::<serde_json::Value>(&event.content) {
                                     let name = metadata.get("name").and_then(|n| n.as_str()).unwrap_or("");
@@ -239,31 +383,48 @@ impl ffi::SearchController {
                                             picture: metadata.get("picture").and_then(|p| p.as_str()).unwrap_or("").to_string(),
                                             nip05: nip05.to_string(),
                                             about: metadata.get("about").and_then(|a| a.as_str()).unwrap_or("").to_string(),
+                                            ..Default::default()
                                         });
                                     }
                                 }
                             }
-                            println!("[Search] Found {} new matches from relays", relay_matches);
+                            tracing::debug!("Found {} new matches from relays", relay_matches);
                         }
                         Err(e) => {
-                            println!("[Search] ERROR fetching events: {:?}", e);
+                            tracing::warn!("User search relay fetch failed: {:?}", e);
                         }
                     }
                 } else {
-                    println!("[Search] WARNING: Relay manager not available");
+                    tracing::warn!("User search: relay manager not available");
                 }
                 
-                println!("[Search] Total results: {}", results.len());
+                // Social proximity: how many of your follows follow each result
+                if let Some(graph) = load_social_graph() {
+                    for user in results.iter_mut() {
+                        if let Ok(pk) = PublicKey::parse(&user.pubkey) {
+                            user.followed_by = graph.proximity(&pk);
+                            user.is_following = graph.is_following(&pk);
+                        }
+                    }
+                    if rank_by_proximity {
+                        // Stable sort keeps local-first order among equals
+                        results.sort_by(|a, b| {
+                            b.is_following.cmp(&a.is_following).then(b.followed_by.cmp(&a.followed_by))
+                        });
+                    }
+                }
+                
+                tracing::debug!("Total results: {}", results.len());
                 Ok(results)
             });
             
-            println!("[Search] Search result: {:?}", result.as_ref().map(|r| r.len()));
+            tracing::debug!("Search result: {:?}", result.as_ref().map(|r| r.len()));
             let _ = qt_thread.queue(move |mut qobject| {
-                println!("[Search] Qt thread callback EXECUTING");
+                tracing::debug!("User search callback started");
                 match result {
                     Ok(results) => {
                         let count = results.len() as i32;
-                        println!("[Search] Setting user_count to {}", count);
+                        tracing::debug!("Setting user_count to {}", count);
                         {
                             let mut rust = qobject.as_mut().rust_mut();
                             rust.user_results = results;
@@ -271,12 +432,12 @@ impl ffi::SearchController {
                         // Set properties through the setter methods to trigger QML notifications
                         qobject.as_mut().set_user_count(count);
                         qobject.as_mut().set_is_searching(false);
-                        println!("[Search] Emitting search_completed signal");
+                        tracing::debug!("Emitting search_completed signal");
                         qobject.as_mut().search_completed();
-                        println!("[Search] Qt thread callback DONE, user_count should be {}", count);
+                        tracing::debug!("User search callback done, {} users", count);
                     }
                     Err(e) => {
-                        println!("[Search] Error in callback: {}", e);
+                        tracing::warn!("User search failed: {}", e);
                         qobject.as_mut().set_is_searching(false);
                         qobject.as_mut().error_occurred(QString::from(e.as_str()));
                     }
@@ -308,11 +469,13 @@ impl ffi::SearchController {
             rust.note_results.clear();
             rust.note_count = 0;
             rust.time_range_days = days;
+            rust.local_offset = 0;
         }
         self.as_mut().set_is_searching(true);
         self.as_mut().set_note_count(0);
         self.as_mut().set_search_type(QString::from("notes"));
         self.as_mut().set_time_range_days(days);
+        self.as_mut().set_has_more_notes(false);
         
         // Split query into words for fuzzy matching
         let search_words: Vec<String> = query_str
@@ -324,107 +487,115 @@ impl ffi::SearchController {
         let qt_thread = self.qt_thread();
         let since_timestamp = days_ago(days);
         
-        println!("[Search] Searching notes with {} words, last {} days", search_words.len(), days);
+        tracing::debug!("Searching notes with {} words, last {} days", search_words.len(), days);
         
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
+            // Local cache first - instant and works offline
+            let mut matching_events: Vec<Event> = match NostrDbManager::global() {
+                Ok(db) => local_note_matches(&db, &query_str, &search_words, since_timestamp.as_secs() as i64, 0),
+                Err(_) => Vec::new(),
+            };
+            let local_count = matching_events.len();
+            tracing::debug!("{} matching notes in local cache", local_count);
+            
+            // Show local matches right away; relay results replace them when they arrive
+            let local_results = build_note_results(matching_events.clone());
+            let _ = qt_thread.queue(move |mut qobject| {
+                let count = local_results.len() as i32;
+                {
+                    let mut rust = qobject.as_mut().rust_mut();
+                    rust.note_results = local_results;
+                    rust.note_count = count;
+                    rust.local_offset = local_count;
+                }
+                qobject.as_mut().set_note_count(count);
+                qobject.as_mut().set_has_more_notes(local_count == NOTE_PAGE_SIZE);
+            });
+            
             let result = SEARCH_RUNTIME.block_on(async {
-                let rm = SEARCH_RELAY_MANAGER.read().unwrap();
-                let Some(manager) = rm.as_ref() else {
-                    return Err("Relay manager not initialized".to_string());
-                };
+                let manager = shared_relay_manager().await?;
                 
-                let mut results = Vec::new();
-                
-                // Fetch notes within time range
-                let filter = Filter::new()
-                    .kind(Kind::TextNote)
-                    .since(since_timestamp)
-                    .limit(1000);
-                
-                println!("[Search] Fetching notes since timestamp: {}", since_timestamp.as_secs());
-                
-                if let Ok(events) = manager.client().fetch_events(filter, std::time::Duration::from_secs(20)).await {
-                    println!("[Search] Fetched {} notes, filtering with fuzzy match", events.len());
-                    
-                    // First pass: collect matching notes and their author pubkeys
-                    let mut matching_events = Vec::new();
-                    let mut author_pubkeys = std::collections::HashSet::new();
-                    
-                    for event in events {
-                        // Fuzzy match: all search words must appear in the content
-                        if fuzzy_match(&event.content, &search_words) {
-                            author_pubkeys.insert(event.pubkey);
-                            matching_events.push(event);
-                            
-                            if matching_events.len() >= 100 {
-                                break;
-                            }
-                        }
+                // Relay-side search on NIP-50 relays (search relays plus any configured relay that supports it)
+                let config = Config::load();
+                let mut candidates = config.search_relays.clone();
+                for relay in &config.relays {
+                    if !candidates.contains(relay) {
+                        candidates.push(relay.clone());
                     }
-                    
-                    println!("[Search] Found {} matching notes from {} authors", matching_events.len(), author_pubkeys.len());
-                    
-                    // Fetch author profiles from relays
-                    if !author_pubkeys.is_empty() {
-                        let pubkeys: Vec<_> = author_pubkeys.into_iter().collect();
-                        let profile_filter = Filter::new()
-                            .kind(Kind::Metadata)
-                            .authors(pubkeys)
-                            .limit(200);
+                }
+                
+                let remote = match search::search_notes(&candidates, &query_str, since_timestamp, MAX_NOTE_RESULTS).await {
+                    Ok(Some(events)) => events,
+                    Ok(None) => {
+                        // No search relay reachable - scan recent notes instead
+                        tracing::warn!("No NIP-50 relay reachable, scanning recent notes");
+                        let filter = Filter::new()
+                            .kind(Kind::TextNote)
+                            .since(since_timestamp)
+                            .limit(1000);
                         
-                        println!("[Search] Fetching profiles for {} authors...", profile_filter.authors.as_ref().map(|a| a.len()).unwrap_or(0));
+                        tracing::debug!("Fetching notes since timestamp: {}", since_timestamp.as_secs());
                         
-                        if let Ok(profile_events) = manager.client().fetch_events(profile_filter, std::time::Duration::from_secs(10)).await {
-                            println!("[Search] Fetched {} profile events", profile_events.len());
-                            // Store profiles in local cache
-                            for event in profile_events {
-                                if let Ok(db) = NostrDbManager::global() {
-                                    let _ = db.ingest_profile(&event);
-                                }
+                        match manager.client().fetch_events(filter, std::time::Duration::from_secs(20)).await {
+                            Ok(events) => {
+                                tracing::debug!("Fetched {} notes, filtering with fuzzy match", events.len());
+                                events.into_iter()
+                                    .filter(|event| fuzzy_match(&event.content, &search_words))
+                                    .collect()
                             }
+                            Err(_) => Vec::new(),
                         }
                     }
-                    
-                    // Second pass: build results with resolved author info
-                    for event in matching_events {
-                        let mut author_name = String::new();
-                        let mut author_picture = String::new();
-                        
-                        // Try to resolve author profile from cache (now populated)
-                        if let Ok(db) = NostrDbManager::global() {
-                            if let Some(profile) = db.get_profile(&event.pubkey.to_hex()) {
-                                author_name = profile.display_name.or(profile.name).unwrap_or_default();
-                                author_picture = profile.picture.unwrap_or_default();
-                            }
-                        }
-
-                        results.push(NoteResult {
-                            id: event.id.to_hex(),
-                            pubkey: event.pubkey.to_hex(),
-                            author_name,
-                            author_picture,
-                            content: event.content.clone(),
-                            created_at: event.created_at.as_secs() as i64,
-                        });
+                    Err(e) => {
+                        tracing::warn!("NIP-50 search failed: {}", e);
+                        Vec::new()
                     }
+                };
+                
+                if let Ok(db) = NostrDbManager::global() {
+                    let _ = db.ingest_events(&remote);
+                }
+                
+                // Merge, dropping duplicates found both locally and remotely
+                let mut seen: std::collections::HashSet<EventId> = matching_events.iter().map(|e| e.id).collect();
+                for event in remote {
+                    if seen.insert(event.id) {
+                        matching_events.push(event);
+                    }
+                }
+                matching_events.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                matching_events.truncate(MAX_NOTE_RESULTS);
+                
+                let author_pubkeys: std::collections::HashSet<PublicKey> = matching_events.iter()
+                    .map(|e| e.pubkey)
+                    .collect();
+                
+                tracing::debug!("Found {} matching notes from {} authors", matching_events.len(), author_pubkeys.len());
+                
+                // Fetch author profiles from relays
+                if !author_pubkeys.is_empty() {
+                    let pubkeys: Vec<_> = author_pubkeys.into_iter().collect();
+                    tracing::debug!("Fetching profiles for {} authors...", pubkeys.len());
                     
-                    println!("[Search] Built {} results with author info", results.len());
+                    // The profile store keeps them in the local cache
+                    let profile_events = profile_store::get(manager.client(), &pubkeys).await;
+                    tracing::debug!("Fetched {} profile events", profile_events.len());
                 }
                 
-                // Sort by created_at descending (newest first)
-                results.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                // Build results with resolved author info (cache now populated)
+                let results = build_note_results(matching_events);
                 
-                println!("[Search] Sorted {} results, returning from async block", results.len());
+                tracing::debug!("Built {} results with author info", results.len());
                 Ok(results)
             });
             
-            println!("[Search] Async block finished, queuing Qt callback");
+            tracing::debug!("Async block finished, queuing Qt callback");
             let _ = qt_thread.queue(move |mut qobject| {
-                println!("[Search] Qt callback started for notes");
+                tracing::debug!("Qt callback started for notes");
                 match result {
                     Ok(results) => {
                         let count = results.len() as i32;
-                        println!("[Search] Updating note count to {}", count);
+                        tracing::debug!("Updating note count to {}", count);
                         {
                             let mut rust = qobject.as_mut().rust_mut();
                             rust.note_results = results;
@@ -434,10 +605,10 @@ impl ffi::SearchController {
                         qobject.as_mut().set_note_count(count);
                         qobject.as_mut().set_is_searching(false);
                         qobject.as_mut().search_completed();
-                        println!("[Search] Notes search completed signal emitted");
+                        tracing::debug!("Notes search completed signal emitted");
                     }
                     Err(e) => {
-                        println!("[Search] Error in notes search: {}", e);
+                        tracing::warn!("Error in notes search: {}", e);
                         qobject.as_mut().rust_mut().is_searching = false;
                         qobject.as_mut().set_is_searching(false);
                         qobject.as_mut().error_occurred(QString::from(&e));
@@ -447,6 +618,49 @@ impl ffi::SearchController {
         });
     }
     
+    /// Append the next page of local index matches for the current note search
+    pub fn load_more_notes(mut self: Pin<&mut Self>) {
+        let (query_str, days, offset) = {
+            let rust = self.as_ref();
+            if rust.is_searching || rust.loading_more || !rust.has_more_notes || rust.search_type.to_string() != "notes" {
+                return;
+            }
+            (rust.query.to_string(), rust.time_range_days, rust.local_offset)
+        };
+        self.as_mut().rust_mut().loading_more = true;
+        
+        let qt_thread = self.qt_thread();
+        let since = days_ago(days).as_secs() as i64;
+        
+        limits::spawn_limited(move || {
+            let page = match NostrDbManager::global() {
+                Ok(db) => db.search_notes_indexed(&query_str, Some(since), NOTE_PAGE_SIZE, offset).unwrap_or_default(),
+                Err(_) => Vec::new(),
+            };
+            let page_len = page.len();
+            let page_results = build_note_results(page);
+            
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().rust_mut().loading_more = false;
+                // A new search started meanwhile - drop this page
+                if qobject.as_ref().query.to_string() != query_str {
+                    return;
+                }
+                let count = {
+                    let mut rust = qobject.as_mut().rust_mut();
+                    let seen: std::collections::HashSet<String> = rust.note_results.iter().map(|n| n.id.clone()).collect();
+                    rust.note_results.extend(page_results.into_iter().filter(|n| !seen.contains(&n.id)));
+                    rust.local_offset += page_len;
+                    rust.note_count = rust.note_results.len() as i32;
+                    rust.note_count
+                };
+                qobject.as_mut().set_note_count(count);
+                qobject.as_mut().set_has_more_notes(page_len == NOTE_PAGE_SIZE);
+                qobject.as_mut().search_completed();
+            });
+        });
+    }
+    
     pub fn search_hashtag(mut self: Pin<&mut Self>, hashtag: &QString) {
         // Use the stored time range, defaulting to 7 days
         let days = {
@@ -481,14 +695,11 @@ impl ffi::SearchController {
         let qt_thread = self.qt_thread();
         let since_timestamp = days_ago(days);
         
-        println!("[Search] Searching hashtag #{} in last {} days", hashtag_clean, days);
+        tracing::debug!("Searching hashtag #{} in last {} days", hashtag_clean, days);
         
-        std::thread::spawn(move || {
+        limits::spawn_limited(move || {
             let result = SEARCH_RUNTIME.block_on(async {
-                let rm = SEARCH_RELAY_MANAGER.read().unwrap();
-                let Some(manager) = rm.as_ref() else {
-                    return Err("Relay manager not initialized".to_string());
-                };
+                let manager = shared_relay_manager().await?;
                 
                 let mut results = Vec::new();
                 
@@ -500,7 +711,7 @@ impl ffi::SearchController {
                     .limit(200);
                 
                 if let Ok(events) = manager.client().fetch_events(filter, std::time::Duration::from_secs(20)).await {
-                    println!("[Search] Found {} notes with #{}", events.len(), hashtag_clean);
+                    tracing::debug!("Found {} notes with #{}", events.len(), hashtag_clean);
                     
                     // Collect author pubkeys for profile fetching
                     let author_pubkeys: std::collections::HashSet<_> = events.iter()
@@ -510,25 +721,17 @@ impl ffi::SearchController {
                     // Fetch author profiles from relays
                     if !author_pubkeys.is_empty() {
                         let pubkeys: Vec<_> = author_pubkeys.into_iter().collect();
-                        let profile_filter = Filter::new()
-                            .kind(Kind::Metadata)
-                            .authors(pubkeys)
-                            .limit(200);
-                        
-                        println!("[Search] Fetching profiles for hashtag search authors...");
+                        tracing::debug!("Fetching profiles for hashtag search authors...");
                         
-                        if let Ok(profile_events) = manager.client().fetch_events(profile_filter, std::time::Duration::from_secs(10)).await {
-                            println!("[Search] Fetched {} profile events", profile_events.len());
-                            for event in profile_events {
-                                if let Ok(db) = NostrDbManager::global() {
-                                    let _ = db.ingest_profile(&event);
-                                }
-                            }
-                        }
+                        let profile_events = profile_store::get(manager.client(), &pubkeys).await;
+                        tracing::debug!("Fetched {} profile events", profile_events.len());
                     }
                     
                     // Build results with resolved author info
                     for event in events {
+                        if report::is_hidden(&event.id.to_hex(), &event.pubkey.to_hex()) {
+                            continue;
+                        }
                         let mut author_name = String::new();
                         let mut author_picture = String::new();
                         
@@ -547,6 +750,7 @@ impl ffi::SearchController {
                             author_picture,
                             content: event.content.clone(),
                             created_at: event.created_at.as_secs() as i64,
+                            outside_trust: false,
                         });
                     }
                 }
@@ -554,7 +758,7 @@ impl ffi::SearchController {
                 // Sort by created_at descending (newest first)
                 results.sort_by(|a, b| b.created_at.cmp(&a.created_at));
                 
-                Ok(results)
+                Ok(apply_trust(results))
             });
             
             let _ = qt_thread.queue(move |mut qobject| {
@@ -586,9 +790,9 @@ impl ffi::SearchController {
     }
     
     pub fn get_user(&self, index: i32) -> QString {
-        println!("[Search] get_user called for index {}", index);
+        tracing::debug!("get_user({})", index);
         if index < 0 || index as usize >= self.user_results.len() {
-            println!("[Search] get_user index out of bounds or results empty");
+            tracing::debug!("get_user index out of bounds");
             return QString::from("{}");
         }
         
@@ -600,14 +804,16 @@ impl ffi::SearchController {
             "picture": user.picture,
             "nip05": user.nip05,
             "about": user.about,
+            "followedByCount": user.followed_by,
+            "isFollowing": user.is_following,
         });
         
         QString::from(&json.to_string())
     }
     
-    pub fn get_note(&self, index: i32) -> QString {
+    pub fn get_note(&self, index: i32) -> QVariant {
         if index < 0 || index as usize >= self.note_results.len() {
-            return QString::from("{}");
+            return variant::empty_map();
         }
         
         let note = &self.note_results[index as usize];
@@ -618,9 +824,82 @@ impl ffi::SearchController {
             "authorPicture": note.author_picture,
             "content": note.content,
             "createdAt": note.created_at,
+            "outsideTrust": note.outside_trust,
         });
         
-        QString::from(&json.to_string())
+        variant::from_json(&json)
+    }
+    
+    pub fn queue_follow(self: Pin<&mut Self>, pubkey: &QString, source: &QString) -> bool {
+        match follow_queue::queue(&pubkey.to_string(), &source.to_string()) {
+            Ok(queued) => queued,
+            Err(e) => {
+                self.error_occurred(QString::from(&e));
+                false
+            }
+        }
+    }
+
+    pub fn get_suggested_follows(self: Pin<&mut Self>, limit: i32) -> QString {
+        let Some(graph) = load_social_graph() else {
+            return QString::from("[]");
+        };
+        let suggestions = graph.suggestions(limit.max(1) as usize);
+        let db = NostrDbManager::global().ok();
+        
+        let name_of = |pk: &PublicKey| db.as_ref()
+            .and_then(|db| db.get_profile(&pk.to_hex()))
+            .and_then(|p| p.get_display_name().map(|n| n.to_string()));
+        
+        let mut missing_profiles: Vec<PublicKey> = Vec::new();
+        let json: Vec<serde_json::Value> = suggestions.iter()
+            .map(|(pk, count)| {
+                let profile = db.as_ref().and_then(|db| db.get_profile(&pk.to_hex()));
+                if profile.is_none() {
+                    missing_profiles.push(*pk);
+                }
+                let field = |get: fn(&CachedProfile) -> &Option<String>| {
+                    profile.as_ref().and_then(|p| get(p).clone()).unwrap_or_default()
+                };
+                serde_json::json!({
+                    "pubkey": pk.to_hex(),
+                    "name": field(|p| &p.name),
+                    "displayName": field(|p| &p.display_name),
+                    "picture": field(|p| &p.picture),
+                    "nip05": field(|p| &p.nip05),
+                    "about": field(|p| &p.about),
+                    "followedByCount": count,
+                    "followedBy": graph.followed_by(pk, 3).iter().filter_map(|f| name_of(f)).collect::<Vec<_>>(),
+                })
+            })
+            .collect();
+        
+        // Refresh follows' contact lists and missing profiles, then tell QML to ask again
+        if !SUGGESTIONS_REFRESHING.swap(true, std::sync::atomic::Ordering::SeqCst) {
+            let following = GLOBAL_CONTACT_MANAGER.lock().map(|c| c.following()).unwrap_or_default();
+            let qt_thread = self.qt_thread();
+            limits::spawn_limited(move || {
+                let changed = SEARCH_RUNTIME.block_on(async {
+                    let manager = shared_relay_manager().await?;
+                    let lists = social_graph::refresh(&manager, &following).await?;
+                    let profiles = manager.fetch_profiles(&missing_profiles).await.unwrap_or_default();
+                    Ok::<bool, String>(lists > 0 || !profiles.is_empty())
+                });
+                SUGGESTIONS_REFRESHING.store(false, std::sync::atomic::Ordering::SeqCst);
+                
+                match changed {
+                    Ok(true) => {
+                        let _ = qt_thread.queue(|mut qobject| {
+                            qobject.as_mut().suggested_follows_updated();
+                        });
+                    }
+                    Ok(false) => {}
+                    Err(e) => tracing::warn!("Failed to refresh suggested follows: {}", e),
+                }
+            });
+        }
+        
+        QString::from(&serde_json::Value::Array(json).to_string())
     }
     
     pub fn clear_results(mut self: Pin<&mut Self>) {
@@ -630,10 +909,12 @@ impl ffi::SearchController {
             rust.note_results.clear();
             rust.user_count = 0;
             rust.note_count = 0;
+            rust.local_offset = 0;
             rust.query = QString::default();
         }
         self.as_mut().set_user_count(0);
         self.as_mut().set_note_count(0);
+        self.as_mut().set_has_more_notes(false);
         self.as_mut().set_query(QString::default());
     }
 }
@@ -0,0 +1,374 @@
+//! Signer bridge - runs the D-Bus signer service and surfaces its approval prompts to QML
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(bool, service_running)]
+        #[qproperty(i32, pending_count)]
+        #[qproperty(QString, error_message)]
+        type SignerRequestsController = super::SignerRequestsControllerRust;
+
+        /// Route signer requests to this controller and start the service if enabled
+        #[qinvokable]
+        fn initialize(self: Pin<&mut SignerRequestsController>);
+
+        /// Stop the service and drop pending requests (logout)
+        #[qinvokable]
+        fn reset(self: Pin<&mut SignerRequestsController>);
+
+        /// Get whether other apps may use Pleb-Client as a signer
+        #[qinvokable]
+        fn get_enabled(self: &SignerRequestsController) -> bool;
+
+        /// Enable or disable the signer service; starts or stops it right away
+        #[qinvokable]
+        fn set_enabled(self: Pin<&mut SignerRequestsController>, enabled: bool);
+
+        /// Requests waiting for a decision (returns JSON array of {id, appId, method, kind, preview, createdAt})
+        #[qinvokable]
+        fn get_pending_requests(self: &SignerRequestsController) -> QString;
+
+        /// Approve a request; `remember` always allows this app, method and event kind
+        #[qinvokable]
+        fn approve(self: Pin<&mut SignerRequestsController>, request_id: &QString, remember: bool);
+
+        /// Reject a request; `remember` always rejects this app, method and event kind
+        #[qinvokable]
+        fn reject(self: Pin<&mut SignerRequestsController>, request_id: &QString, remember: bool);
+
+        /// Signing policies of the active account (returns JSON array of {pubkey, appId, method, kind, decision})
+        /// kind is null for policies covering every kind
+        #[qinvokable]
+        fn get_policies(self: &SignerRequestsController) -> QString;
+
+        /// Set the active account's policy for an app and method ("allow", "ask" or "reject");
+        /// kind -1 covers every kind. "ask" removes the policy
+        #[qinvokable]
+        fn set_policy(self: Pin<&mut SignerRequestsController>, app_id: &QString, method: &QString, kind: i32, decision: &QString) -> bool;
+
+        /// Answered signer requests, newest first
+        /// (returns JSON array of {at, appId, method, kind, eventId, outcome})
+        #[qinvokable]
+        fn get_audit_log(self: &SignerRequestsController) -> QString;
+
+        /// Delete the audit log
+        #[qinvokable]
+        fn clear_audit_log(self: Pin<&mut SignerRequestsController>) -> bool;
+
+        /// Handle a nostrconnect:// or nostrsigner: URI (non-blocking); answers with uri_handled
+        #[qinvokable]
+        fn handle_uri(self: Pin<&mut SignerRequestsController>, uri: &QString);
+
+        /// Running nostrconnect sessions (returns JSON array of {id, appId, name, relays, startedAt})
+        #[qinvokable]
+        fn get_connect_sessions(self: &SignerRequestsController) -> QString;
+
+        /// End a nostrconnect session
+        #[qinvokable]
+        fn disconnect_session(self: Pin<&mut SignerRequestsController>, session_id: &QString);
+    }
+
+    unsafe extern "RustQt" {
+        /// Emitted when another app asks for a signature, key or encryption
+        /// method is "get_public_key", "sign_event", "nip04_encrypt", ...; kind is -1 unless signing
+        #[qsignal]
+        fn request_received(
+            self: Pin<&mut SignerRequestsController>,
+            request_id: &QString,
+            app_id: &QString,
+            method: &QString,
+            kind: i32,
+            preview: &QString,
+        );
+
+        /// Emitted when a signer URI was handled
+        /// result is the nostrsigner answer to hand back when there is no callback_url
+        #[qsignal]
+        fn uri_handled(
+            self: Pin<&mut SignerRequestsController>,
+            success: bool,
+            message: &QString,
+            result: &QString,
+            callback_url: &QString,
+        );
+
+        /// Emitted when nostrconnect sessions start or end
+        #[qsignal]
+        fn sessions_changed(self: Pin<&mut SignerRequestsController>);
+    }
+
+    // Enable threading support for background work with UI updates
+    impl cxx_qt::Threading for SignerRequestsController {}
+}
+
+use std::pin::Pin;
+use std::sync::Arc;
+use cxx_qt_lib::QString;
+use cxx_qt::Threading;
+use nostr_sdk::prelude::*;
+use tokio::sync::{Mutex, RwLock};
+use zbus::Connection;
+use crate::core::config::Config;
+use crate::signer::policy::{self, PolicyDecision, SignerPolicy};
+use crate::signer::service::{self, SignerMethod, SignerState};
+use crate::signer::uri::{self, UriOutcome};
+use crate::bridge::feed_bridge::get_feed_nsec;
+
+// Global tokio runtime and service connection for the signer service
+lazy_static::lazy_static! {
+    static ref SIGNER_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+    static ref SIGNER_STATE: Arc<RwLock<SignerState>> = Arc::new(RwLock::new(SignerState::default()));
+    static ref SERVICE_CONNECTION: Mutex<Option<Connection>> = Mutex::new(None);
+}
+
+/// Stop the signer service and nostrconnect sessions and forget keys and approvals
+pub fn reset_signer_state() {
+    SIGNER_RUNTIME.block_on(async {
+        SIGNER_STATE.write().await.lock();
+        // Dropping the connection releases the bus name
+        *SERVICE_CONNECTION.lock().await = None;
+        uri::disconnect_all().await;
+    });
+    service::reset_approvals();
+}
+
+/// The local key; signing for other apps needs the key itself, not an external signer
+fn local_keys() -> Option<Keys> {
+    get_feed_nsec().and_then(|nsec| SecretKey::parse(&nsec).ok()).map(Keys::new)
+}
+
+/// Rust implementation of SignerRequestsController
+#[derive(Default)]
+pub struct SignerRequestsControllerRust {
+    service_running: bool,
+    pending_count: i32,
+    error_message: QString,
+}
+
+impl qobject::SignerRequestsController {
+    /// Register as the approval prompt and start the service if enabled
+    pub fn initialize(mut self: Pin<&mut Self>) {
+        let qt_thread = self.qt_thread();
+        service::set_request_handler(Box::new(move |request| {
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().refresh_pending_count();
+                qobject.as_mut().request_received(
+                    &QString::from(&request.id),
+                    &QString::from(&request.app_id),
+                    &QString::from(request.method.as_str()),
+                    request.kind.map(i32::from).unwrap_or(-1),
+                    &QString::from(&request.preview),
+                );
+            });
+        }));
+
+        if Config::load().signer_service_enabled {
+            self.as_mut().start_service();
+        }
+    }
+
+    /// Stop the service (logout)
+    pub fn reset(mut self: Pin<&mut Self>) {
+        reset_signer_state();
+        self.as_mut().set_service_running(false);
+        self.as_mut().set_pending_count(0);
+        self.as_mut().set_error_message(QString::from(""));
+        self.as_mut().sessions_changed();
+    }
+
+    /// Get whether the signer service is enabled
+    pub fn get_enabled(&self) -> bool {
+        Config::load().signer_service_enabled
+    }
+
+    /// Enable or disable the signer service
+    pub fn set_enabled(mut self: Pin<&mut Self>, enabled: bool) {
+        let mut config = Config::load();
+        config.signer_service_enabled = enabled;
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save config: {}", e);
+        }
+
+        if enabled {
+            self.as_mut().start_service();
+        } else {
+            self.as_mut().reset();
+        }
+    }
+
+    /// Pending requests as JSON
+    pub fn get_pending_requests(&self) -> QString {
+        QString::from(&serde_json::to_string(&service::pending_requests()).unwrap_or_else(|_| "[]".to_string()))
+    }
+
+    /// Approve a pending request
+    pub fn approve(mut self: Pin<&mut Self>, request_id: &QString, remember: bool) {
+        if !service::respond(&request_id.to_string(), true, remember) {
+            tracing::warn!("Signer request {} already answered or timed out", request_id);
+        }
+        self.as_mut().refresh_pending_count();
+    }
+
+    /// Reject a pending request
+    pub fn reject(mut self: Pin<&mut Self>, request_id: &QString, remember: bool) {
+        service::respond(&request_id.to_string(), false, remember);
+        self.as_mut().refresh_pending_count();
+    }
+
+    /// Signing policies of the active account as JSON
+    pub fn get_policies(&self) -> QString {
+        let pubkey = local_keys().map(|keys| keys.public_key().to_hex()).unwrap_or_default();
+        let policies = policy::policies_for(&Config::load(), &pubkey);
+        QString::from(&serde_json::to_string(&policies).unwrap_or_else(|_| "[]".to_string()))
+    }
+
+    /// Add, replace or (with "ask") remove a signing policy
+    pub fn set_policy(self: Pin<&mut Self>, app_id: &QString, method: &QString, kind: i32, decision: &QString) -> bool {
+        let (Some(method), Some(decision)) = (
+            SignerMethod::from_str(&method.to_string()),
+            PolicyDecision::from_str(&decision.to_string()),
+        ) else {
+            tracing::warn!("Invalid signer policy: {} {}", method, decision);
+            return false;
+        };
+
+        let Some(keys) = local_keys() else {
+            tracing::warn!("No local key to set a signer policy for");
+            return false;
+        };
+        let mut config = Config::load();
+        let stored = policy::set_policy(&mut config, SignerPolicy {
+            pubkey: keys.public_key().to_hex(),
+            app_id: app_id.to_string(),
+            method,
+            kind: u16::try_from(kind).ok(),
+            decision,
+        });
+        if !stored {
+            tracing::warn!("Signer policies can't be stored for {}", app_id);
+            return false;
+        }
+        match config.save() {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::error!("Failed to save config: {}", e);
+                false
+            }
+        }
+    }
+
+    /// Audit log as JSON, newest first
+    pub fn get_audit_log(&self) -> QString {
+        let mut entries = policy::audit_log();
+        entries.reverse();
+        QString::from(&serde_json::to_string(&entries).unwrap_or_else(|_| "[]".to_string()))
+    }
+
+    /// Delete the audit log
+    pub fn clear_audit_log(self: Pin<&mut Self>) -> bool {
+        match policy::clear_audit_log() {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::error!("{}", e);
+                false
+            }
+        }
+    }
+
+    /// Handle a signer URI in the background; approval prompts show as usual
+    pub fn handle_uri(mut self: Pin<&mut Self>, uri: &QString) {
+        let uri = uri.to_string();
+        let Some(keys) = local_keys() else {
+            self.as_mut().uri_handled(false, &QString::from("Signing for other apps needs a locally stored key"), &QString::from(""), &QString::from(""));
+            return;
+        };
+        let qt_thread = self.qt_thread();
+
+        std::thread::spawn(move || {
+            let result = SIGNER_RUNTIME.block_on(uri::handle_uri(&uri, keys));
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok(UriOutcome::Connected(session)) => {
+                        qobject.as_mut().sessions_changed();
+                        qobject.as_mut().uri_handled(
+                            true,
+                            &QString::from(&format!("Connected to {}", session.name.as_deref().unwrap_or(&session.app_id))),
+                            &QString::from(""),
+                            &QString::from(""),
+                        );
+                    }
+                    Ok(UriOutcome::Answered(answer)) => {
+                        qobject.as_mut().uri_handled(
+                            true,
+                            &QString::from(&format!("Answered {}", answer.app_id)),
+                            &QString::from(&answer.result),
+                            &QString::from(answer.callback_url.as_deref().unwrap_or("")),
+                        );
+                    }
+                    Err(e) => {
+                        tracing::warn!("Signer URI failed: {}", e);
+                        qobject.as_mut().uri_handled(false, &QString::from(&e), &QString::from(""), &QString::from(""));
+                    }
+                }
+            });
+        });
+    }
+
+    /// nostrconnect sessions as JSON
+    pub fn get_connect_sessions(&self) -> QString {
+        QString::from(&serde_json::to_string(&uri::sessions()).unwrap_or_else(|_| "[]".to_string()))
+    }
+
+    /// End a nostrconnect session
+    pub fn disconnect_session(mut self: Pin<&mut Self>, session_id: &QString) {
+        let session_id = session_id.to_string();
+        SIGNER_RUNTIME.block_on(uri::disconnect(&session_id));
+        self.as_mut().sessions_changed();
+    }
+
+    /// Unlock the signer with the local key and claim the bus name (non-blocking)
+    fn start_service(mut self: Pin<&mut Self>) {
+        let Some(keys) = local_keys() else {
+            self.as_mut().set_error_message(QString::from("The signer service needs a locally stored key"));
+            return;
+        };
+        self.as_mut().set_error_message(QString::from(""));
+        let qt_thread = self.qt_thread();
+
+        std::thread::spawn(move || {
+            let result = SIGNER_RUNTIME.block_on(async {
+                SIGNER_STATE.write().await.unlock(keys);
+                let mut connection = SERVICE_CONNECTION.lock().await;
+                if connection.is_none() {
+                    *connection = Some(service::start_signer_service(SIGNER_STATE.clone()).await
+                        .map_err(|e| format!("Failed to start signer service: {}", e))?);
+                }
+                Ok::<_, String>(())
+            });
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok(()) => qobject.as_mut().set_service_running(true),
+                    Err(e) => {
+                        tracing::error!("{}", e);
+                        qobject.as_mut().set_service_running(false);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+
+    fn refresh_pending_count(self: Pin<&mut Self>) {
+        let count = service::pending_requests().len() as i32;
+        self.set_pending_count(count);
+    }
+}
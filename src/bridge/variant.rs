@@ -0,0 +1,72 @@
+//! Variant conversion - hands structured data to QML as QVariantMap/QVariantList
+//!
+//! Invokables that return a `QVariant` built here arrive in QML as plain
+//! JavaScript objects and arrays, so delegates read fields directly instead of
+//! receiving a JSON string and calling JSON.parse for every item.
+
+#[cxx_qt::bridge]
+pub mod ffi {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+
+        include!("cxx-qt-lib/qmap.h");
+        type QMap_QString_QVariant = cxx_qt_lib::QMap<cxx_qt_lib::QMapPair_QString_QVariant>;
+
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QVariant = cxx_qt_lib::QList<cxx_qt_lib::QVariant>;
+
+        include!("variant_helpers.h");
+        /// Wrap a map as a QVariant (a JS object in QML)
+        fn variant_from_map(map: &QMap_QString_QVariant) -> QVariant;
+        /// Wrap a list as a QVariant (a JS array in QML)
+        fn variant_from_list(list: &QList_QVariant) -> QVariant;
+    }
+}
+
+use cxx_qt_lib::{QList, QMap, QMapPair_QString_QVariant, QString, QVariant};
+use serde_json::Value;
+
+/// Convert a JSON value into a QVariant: objects become QVariantMap, arrays
+/// QVariantList and null an invalid variant (undefined in QML)
+pub fn from_json(value: &Value) -> QVariant {
+    match value {
+        Value::Null => QVariant::default(),
+        Value::Bool(b) => QVariant::from(b),
+        Value::Number(n) => {
+            if let Some(i) = n.as_i64() {
+                QVariant::from(&i)
+            } else if let Some(u) = n.as_u64() {
+                QVariant::from(&u)
+            } else {
+                QVariant::from(&n.as_f64().unwrap_or_default())
+            }
+        }
+        Value::String(s) => QVariant::from(&QString::from(s)),
+        Value::Array(items) => {
+            let mut list = QList::<QVariant>::default();
+            list.reserve(items.len() as isize);
+            for item in items {
+                list.append(from_json(item));
+            }
+            ffi::variant_from_list(&list)
+        }
+        Value::Object(_) => ffi::variant_from_map(&map_from_json(value)),
+    }
+}
+
+/// A JSON object as a QVariantMap; anything else gives an empty map
+pub fn map_from_json(value: &Value) -> QMap<QMapPair_QString_QVariant> {
+    let mut map = QMap::<QMapPair_QString_QVariant>::default();
+    if let Value::Object(fields) = value {
+        for (key, field) in fields {
+            map.insert(QString::from(key), from_json(field));
+        }
+    }
+    map
+}
+
+/// An empty QVariantMap as a QVariant ({} in QML)
+pub fn empty_map() -> QVariant {
+    ffi::variant_from_map(&QMap::default())
+}
@@ -0,0 +1,437 @@
+//! Wallet bridge - exposes NWC wallet history to QML
+
+#[cxx_qt::bridge]
+pub mod qobject {
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "RustQt" {
+        #[qobject]
+        #[qml_element]
+        #[qproperty(i32, transaction_count)]
+        #[qproperty(bool, is_loading)]
+        #[qproperty(bool, has_more)]
+        #[qproperty(QString, transaction_filter)]
+        #[qproperty(QString, error_message)]
+        #[qproperty(QString, pending_invoice)]
+        type WalletController = super::WalletControllerRust;
+
+        /// Load the first page of transactions ("all", "incoming" or "outgoing")
+        #[qinvokable]
+        fn load_transactions(self: Pin<&mut WalletController>, filter: &QString);
+
+        /// Load the next page of transactions
+        #[qinvokable]
+        fn load_more_transactions(self: Pin<&mut WalletController>);
+
+        /// Reload transactions with the current filter
+        #[qinvokable]
+        fn refresh(self: Pin<&mut WalletController>);
+
+        /// Get transaction at index (returns JSON)
+        #[qinvokable]
+        fn get_transaction(self: &WalletController, index: i32) -> QString;
+
+        /// Create an invoice to receive a payment
+        /// Returns JSON with invoice and qr (data URI), or error message
+        /// Emits invoice_settled once the wallet reports the invoice as paid
+        #[qinvokable]
+        fn receive_payment(self: Pin<&mut WalletController>, amount_sats: i64, description: &QString) -> QString;
+
+        /// Stop waiting for the pending invoice to settle
+        #[qinvokable]
+        fn cancel_receive(self: Pin<&mut WalletController>);
+
+        /// Render a bolt11 invoice as a QR code image (SVG data URI)
+        #[qinvokable]
+        fn get_invoice_qr(self: &WalletController, invoice: &QString) -> QString;
+
+        /// Export all incoming and outgoing zaps to a file ("csv" or "json")
+        /// Combines relay zap receipts with the NWC transaction list (async)
+        /// Emits zap_history_exported or error_occurred
+        #[qinvokable]
+        fn export_zap_history(self: Pin<&mut WalletController>, user_pubkey: &QString, format: &QString, file_path: &QString);
+    }
+
+    unsafe extern "RustQt" {
+        /// Emitted when a page of transactions is loaded
+        #[qsignal]
+        fn transactions_loaded(self: Pin<&mut WalletController>, count: i32);
+
+        /// Emitted when the pending invoice has been paid
+        #[qsignal]
+        fn invoice_settled(self: Pin<&mut WalletController>, invoice: &QString, amount_sats: i64);
+
+        /// Emitted when the pending invoice expires without being paid
+        #[qsignal]
+        fn invoice_expired(self: Pin<&mut WalletController>, invoice: &QString);
+
+        /// Emitted when a zap history export has been written
+        #[qsignal]
+        fn zap_history_exported(self: Pin<&mut WalletController>, path: &QString, count: i32);
+
+        /// Emitted when an error occurs
+        #[qsignal]
+        fn error_occurred(self: Pin<&mut WalletController>, error: &QString);
+    }
+
+    // Enable threading support for background work with UI updates
+    impl cxx_qt::Threading for WalletController {}
+}
+
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use base64::Engine;
+use cxx_qt_lib::QString;
+use cxx_qt::{CxxQtType, Threading};
+use nostr_sdk::PublicKey;
+use crate::bridge::feed_bridge::shared_relay_manager;
+use crate::core::limits;
+use crate::nostr::nwc::{NwcTransaction, TransactionFilter};
+use crate::nostr::zap_history::{self, ExportFormat};
+use crate::nostr::GLOBAL_NWC_MANAGER;
+
+/// Number of transactions requested per page
+const TRANSACTIONS_PAGE_SIZE: u32 = 20;
+
+/// Page size when reading the full transaction list for exports
+const EXPORT_PAGE_SIZE: u32 = 100;
+
+/// Upper bound on wallet transactions read for an export
+const EXPORT_MAX_TRANSACTIONS: usize = 10_000;
+
+/// Zap receipts requested per direction for an export
+const EXPORT_RECEIPT_LIMIT: usize = 5000;
+
+/// How often to poll lookup_invoice while waiting for a payment
+const INVOICE_POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// How long to wait for an invoice to be paid before giving up
+const INVOICE_POLL_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+// Global tokio runtime for wallet operations
+lazy_static::lazy_static! {
+    static ref WALLET_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+}
+
+/// Bumped whenever a new invoice is requested or receiving is cancelled,
+/// so stale settlement pollers know to stop
+static RECEIVE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Stop any settlement poller from the previous session
+pub fn reset_wallet_state() {
+    RECEIVE_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Rust implementation of WalletController
+pub struct WalletControllerRust {
+    transaction_count: i32,
+    is_loading: bool,
+    has_more: bool,
+    transaction_filter: QString,
+    error_message: QString,
+    pending_invoice: QString,
+
+    // Internal state
+    transactions: Vec<NwcTransaction>,
+}
+
+impl Default for WalletControllerRust {
+    fn default() -> Self {
+        Self {
+            transaction_count: 0,
+            is_loading: false,
+            has_more: false,
+            transaction_filter: QString::from("all"),
+            error_message: QString::from(""),
+            pending_invoice: QString::from(""),
+            transactions: Vec::new(),
+        }
+    }
+}
+
+impl qobject::WalletController {
+    /// Load the first page of transactions
+    pub fn load_transactions(mut self: Pin<&mut Self>, filter: &QString) {
+        let filter = TransactionFilter::from_str(&filter.to_string());
+
+        {
+            let mut rust = self.as_mut().rust_mut();
+            rust.transactions.clear();
+            rust.transaction_count = 0;
+        }
+        self.as_mut().set_transaction_filter(QString::from(filter.as_str()));
+        self.as_mut().set_transaction_count(0);
+        self.as_mut().set_has_more(false);
+
+        self.fetch_page(filter, 0);
+    }
+
+    /// Load the next page of transactions
+    pub fn load_more_transactions(self: Pin<&mut Self>) {
+        if self.is_loading || !self.has_more {
+            return;
+        }
+
+        let filter = TransactionFilter::from_str(&self.transaction_filter.to_string());
+        let offset = self.transactions.len() as u32;
+        self.fetch_page(filter, offset);
+    }
+
+    /// Reload transactions with the current filter
+    pub fn refresh(self: Pin<&mut Self>) {
+        let filter = self.transaction_filter.clone();
+        self.load_transactions(&filter);
+    }
+
+    /// Get transaction at index
+    pub fn get_transaction(&self, index: i32) -> QString {
+        if let Some(tx) = self.transactions.get(index as usize) {
+            QString::from(&tx.to_json())
+        } else {
+            QString::from("{}")
+        }
+    }
+
+    /// Create an invoice and start polling for its settlement
+    pub fn receive_payment(mut self: Pin<&mut Self>, amount_sats: i64, description: &QString) -> QString {
+        if amount_sats <= 0 {
+            return QString::from(&serde_json::json!({ "error": "Amount must be greater than zero" }).to_string());
+        }
+
+        let description_str = description.to_string();
+        tracing::info!("Creating invoice for {} sats", amount_sats);
+
+        let result = WALLET_RUNTIME.block_on(async {
+            let mut nwc = GLOBAL_NWC_MANAGER.lock().await;
+            if !nwc.is_connected() {
+                return Err("NWC wallet not connected. Please connect your wallet in Settings.".to_string());
+            }
+            nwc.make_invoice(amount_sats as u64, &description_str).await
+        });
+
+        let invoice = match result {
+            Ok(invoice) => invoice,
+            Err(e) => {
+                tracing::error!("Failed to create invoice: {}", e);
+                self.as_mut().set_error_message(QString::from(&e));
+                self.as_mut().error_occurred(&QString::from(&e));
+                return QString::from(&serde_json::json!({ "error": e }).to_string());
+            }
+        };
+
+        self.as_mut().set_pending_invoice(QString::from(&invoice));
+
+        // Start polling for settlement, superseding any previous poller
+        let generation = RECEIVE_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+        let qt_thread = self.qt_thread();
+        let invoice_for_poll = invoice.clone();
+
+        std::thread::spawn(move || {
+            let settled = WALLET_RUNTIME.block_on(async {
+                let started = std::time::Instant::now();
+                while started.elapsed() < INVOICE_POLL_TIMEOUT {
+                    tokio::time::sleep(INVOICE_POLL_INTERVAL).await;
+
+                    if RECEIVE_GENERATION.load(Ordering::SeqCst) != generation {
+                        return None;
+                    }
+
+                    // Don't hold the manager while waiting on the wallet; zaps
+                    // and balance refreshes need it in the meantime
+                    let session = GLOBAL_NWC_MANAGER.lock().await.session();
+                    let lookup = match session {
+                        Some(session) => session.lookup_invoice(&invoice_for_poll).await,
+                        None => Err("Not connected to NWC".to_string()),
+                    };
+
+                    match lookup {
+                        Ok(tx) if tx.is_settled() => return Some(Ok(tx.amount_sats())),
+                        Ok(_) => {}
+                        Err(e) => tracing::debug!("lookup_invoice failed, will retry: {}", e),
+                    }
+                }
+                Some(Err(()))
+            });
+
+            let Some(settled) = settled else {
+                tracing::debug!("Invoice polling cancelled");
+                return;
+            };
+
+            // Refresh balance after receiving
+            if settled.is_ok() {
+                WALLET_RUNTIME.block_on(async {
+                    let mut nwc = GLOBAL_NWC_MANAGER.lock().await;
+                    let _ = nwc.fetch_balance().await;
+                });
+            }
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                qobject.as_mut().set_pending_invoice(QString::from(""));
+                match settled {
+                    Ok(amount) => {
+                        tracing::info!("Invoice settled: {} sats", amount);
+                        qobject.as_mut().invoice_settled(&QString::from(&invoice_for_poll), amount);
+                    }
+                    Err(()) => {
+                        tracing::info!("Invoice polling timed out");
+                        qobject.as_mut().invoice_expired(&QString::from(&invoice_for_poll));
+                    }
+                }
+            });
+        });
+
+        QString::from(&serde_json::json!({
+            "invoice": invoice,
+            "qr": invoice_qr_data_uri(&invoice).unwrap_or_default(),
+        }).to_string())
+    }
+
+    /// Stop waiting for the pending invoice
+    pub fn cancel_receive(mut self: Pin<&mut Self>) {
+        RECEIVE_GENERATION.fetch_add(1, Ordering::SeqCst);
+        self.as_mut().set_pending_invoice(QString::from(""));
+    }
+
+    /// Render an invoice as a QR code data URI
+    pub fn get_invoice_qr(&self, invoice: &QString) -> QString {
+        QString::from(&invoice_qr_data_uri(&invoice.to_string()).unwrap_or_default())
+    }
+
+    /// Export zap history in the background
+    pub fn export_zap_history(self: Pin<&mut Self>, user_pubkey: &QString, format: &QString, file_path: &QString) {
+        let Some(format) = ExportFormat::from_str(&format.to_string()) else {
+            let e = format!("Unsupported export format: {}", format);
+            self.error_occurred(&QString::from(&e));
+            return;
+        };
+        let path = file_path.to_string();
+        let path = path.strip_prefix("file://").unwrap_or(&path).to_string();
+        let pubkey_str = user_pubkey.to_string();
+        let qt_thread = self.qt_thread();
+
+        limits::spawn_limited(move || {
+            let result = WALLET_RUNTIME.block_on(async {
+                let pubkey = PublicKey::parse(&pubkey_str)
+                    .map_err(|e| format!("Invalid pubkey: {}", e))?;
+
+                let manager = shared_relay_manager().await?;
+                let (incoming, outgoing) = manager.fetch_zap_receipts(&pubkey, None, EXPORT_RECEIPT_LIMIT).await?;
+
+                // The wallet is optional - receipts alone still make a useful export
+                let transactions = fetch_all_transactions().await.unwrap_or_else(|e| {
+                    tracing::warn!("Exporting zaps without wallet transactions: {}", e);
+                    Vec::new()
+                });
+
+                let records = zap_history::merge(&incoming, &outgoing, &transactions);
+                let contents = match format {
+                    ExportFormat::Csv => zap_history::to_csv(&records),
+                    ExportFormat::Json => zap_history::to_json(&records),
+                };
+                std::fs::write(&path, contents)
+                    .map_err(|e| format!("Failed to write zap history: {}", e))?;
+
+                Ok::<usize, String>(records.len())
+            });
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok(count) => {
+                        tracing::info!("Exported {} zaps to {}", count, path);
+                        qobject.as_mut().zap_history_exported(&QString::from(&path), count as i32);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to export zap history: {}", e);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                        qobject.as_mut().error_occurred(&QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+
+    /// Fetch a page of transactions in the background and append it
+    fn fetch_page(mut self: Pin<&mut Self>, filter: TransactionFilter, offset: u32) {
+        self.as_mut().set_is_loading(true);
+        self.as_mut().set_error_message(QString::from(""));
+
+        let qt_thread = self.qt_thread();
+
+        std::thread::spawn(move || {
+            let result = WALLET_RUNTIME.block_on(async {
+                let nwc = GLOBAL_NWC_MANAGER.lock().await;
+                if !nwc.is_connected() {
+                    return Err("NWC wallet not connected. Please connect your wallet in Settings.".to_string());
+                }
+                nwc.list_transactions(filter, TRANSACTIONS_PAGE_SIZE, offset).await
+            });
+
+            let _ = qt_thread.queue(move |mut qobject| {
+                match result {
+                    Ok(mut page) => {
+                        let page_len = page.len() as i32;
+                        let total = {
+                            let mut rust = qobject.as_mut().rust_mut();
+                            rust.transactions.append(&mut page);
+                            rust.transactions.len() as i32
+                        };
+                        qobject.as_mut().set_transaction_count(total);
+                        qobject.as_mut().set_has_more(page_len as u32 == TRANSACTIONS_PAGE_SIZE);
+                        qobject.as_mut().set_is_loading(false);
+                        qobject.as_mut().transactions_loaded(page_len);
+                        tracing::info!("Loaded {} wallet transactions ({} total)", page_len, total);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to load wallet transactions: {}", e);
+                        qobject.as_mut().set_is_loading(false);
+                        qobject.as_mut().set_error_message(QString::from(&e));
+                        qobject.as_mut().error_occurred(&QString::from(&e));
+                    }
+                }
+            });
+        });
+    }
+}
+
+/// Read every wallet transaction, page by page
+async fn fetch_all_transactions() -> Result<Vec<NwcTransaction>, String> {
+    let nwc = GLOBAL_NWC_MANAGER.lock().await;
+    if !nwc.is_connected() {
+        return Err("NWC wallet not connected".to_string());
+    }
+
+    let mut transactions = Vec::new();
+    loop {
+        let page = nwc.list_transactions(TransactionFilter::All, EXPORT_PAGE_SIZE, transactions.len() as u32).await?;
+        let done = (page.len() as u32) < EXPORT_PAGE_SIZE;
+        transactions.extend(page);
+        if done || transactions.len() >= EXPORT_MAX_TRANSACTIONS {
+            break;
+        }
+    }
+    Ok(transactions)
+}
+
+/// Render a bolt11 invoice as an SVG QR code wrapped in a data URI for QML Image
+fn invoice_qr_data_uri(invoice: &str) -> Result<String, String> {
+    // Uppercase bolt11 uses the alphanumeric QR mode, producing a denser code
+    let payload = format!("lightning:{}", invoice).to_uppercase();
+
+    let code = qrcode::QrCode::new(payload.as_bytes())
+        .map_err(|e| format!("Failed to encode QR code: {}", e))?;
+
+    let svg = code.render::<qrcode::render::svg::Color>()
+        .min_dimensions(256, 256)
+        .quiet_zone(true)
+        .build();
+
+    Ok(format!(
+        "data:image/svg+xml;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(svg)
+    ))
+}
@@ -0,0 +1,126 @@
+//! Cache diagnostics - hit/miss counters and on-disk sizes
+//!
+//! Cache lookups call `hit`/`miss` with the cache's name; the diagnostics
+//! endpoint combines these counters with entry counts from the caches
+//! themselves so regressions in cache behaviour show up in Settings.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use serde::Serialize;
+
+/// Names used by the instrumented caches
+pub const FEED: &str = "feed";
+pub const EMBEDDED_EVENTS: &str = "embedded_events";
+pub const EMBEDDED_PROFILES: &str = "embedded_profiles";
+pub const LINK_PREVIEWS: &str = "link_previews";
+pub const NOTE_STATS: &str = "note_stats";
+pub const DB_EVENTS: &str = "db_events";
+pub const DB_PROFILES: &str = "db_profiles";
+pub const PROFILES: &str = "profiles";
+pub const GIFS: &str = "gifs";
+pub const MEDIA: &str = "media";
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Counts {
+    hits: u64,
+    misses: u64,
+}
+
+lazy_static::lazy_static! {
+    static ref COUNTERS: Mutex<HashMap<&'static str, Counts>> = Mutex::new(HashMap::new());
+}
+
+/// Record a lookup that was served from the cache
+pub fn hit(cache: &'static str) {
+    COUNTERS.lock().unwrap().entry(cache).or_default().hits += 1;
+}
+
+/// Record a lookup that had to go elsewhere
+pub fn miss(cache: &'static str) {
+    COUNTERS.lock().unwrap().entry(cache).or_default().misses += 1;
+}
+
+/// Record a lookup result
+pub fn record(cache: &'static str, found: bool) {
+    if found { hit(cache) } else { miss(cache) }
+}
+
+/// Forget all counters (after clearing caches)
+pub fn reset() {
+    COUNTERS.lock().unwrap().clear();
+}
+
+/// One cache as shown in diagnostics
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheStats {
+    pub name: String,
+    pub entries: usize,
+    /// Most entries the cache keeps, for bounded caches
+    pub capacity: Option<usize>,
+    /// Rough payload size; 0 when not measured
+    pub approx_bytes: u64,
+    pub hits: u64,
+    pub misses: u64,
+    /// hits / (hits + misses), None before the first lookup
+    pub hit_ratio: Option<f64>,
+}
+
+impl CacheStats {
+    pub fn new(name: &'static str, entries: usize, approx_bytes: u64) -> Self {
+        let counts = COUNTERS.lock().unwrap().get(name).copied().unwrap_or_default();
+        let total = counts.hits + counts.misses;
+        Self {
+            name: name.to_string(),
+            entries,
+            capacity: None,
+            approx_bytes,
+            hits: counts.hits,
+            misses: counts.misses,
+            hit_ratio: if total > 0 { Some(counts.hits as f64 / total as f64) } else { None },
+        }
+    }
+
+    /// Report the entry cap of a bounded cache
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+}
+
+/// Directory for downloaded media
+pub fn media_cache_dir() -> PathBuf {
+    directories::ProjectDirs::from("com", "plebclient", "PlebClient")
+        .map(|dirs| dirs.cache_dir().join("media"))
+        .unwrap_or_else(|| PathBuf::from("media-cache"))
+}
+
+/// (file count, total bytes) under a directory
+pub fn dir_usage(path: &Path) -> (usize, u64) {
+    let Ok(entries) = std::fs::read_dir(path) else { return (0, 0) };
+    entries.flatten()
+        .filter_map(|entry| entry.metadata().ok().map(|meta| (entry.path(), meta)))
+        .fold((0, 0), |(files, bytes), (path, meta)| {
+            if meta.is_dir() {
+                let (f, b) = dir_usage(&path);
+                (files + f, bytes + b)
+            } else {
+                (files + 1, bytes + meta.len())
+            }
+        })
+}
+
+/// Resident memory of this process in bytes, where the platform exposes it
+pub fn resident_memory() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let statm = std::fs::read_to_string("/proc/self/statm").ok()?;
+        let pages: u64 = statm.split_whitespace().nth(1)?.parse().ok()?;
+        Some(pages * 4096)
+    }
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
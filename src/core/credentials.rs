@@ -1,7 +1,14 @@
 //! Secure credential storage with password-based encryption
 //!
-//! Uses Argon2 for key derivation and ChaCha20-Poly1305 for encryption.
-//! Credentials are stored in an encrypted file in the user's data directory.
+//! Credentials go to the system keyring (Secret Service, through the
+//! `keyring` crate) when one is running and enabled in the config, so they
+//! unlock with the login session. Otherwise they are stored in an encrypted
+//! file in the user's data directory, using Argon2 for key derivation and
+//! ChaCha20-Poly1305 for encryption. Keyring calls block, and can wait on the
+//! desktop's unlock dialog, so callers run them off the GUI thread.
+//!
+//! The password chosen when saving is also kept as an Argon2 hash, so showing
+//! the key for backup asks for it even when the key itself is in the keyring.
 
 #![allow(dead_code)]  // Planned infrastructure for future integration
 
@@ -17,10 +24,18 @@ use rand::RngCore;
 const CREDENTIALS_FILE: &str = "credentials.enc";
 const SALT_FILE: &str = "credentials.salt";
 const NWC_FILE: &str = "nwc.enc";
+/// Salt and Argon2 hash of the password, checked before revealing the key
+const VERIFIER_FILE: &str = "credentials.verifier";
+
+/// Keyring service name; entries are named after what they hold
+const KEYRING_SERVICE: &str = "pleb-client";
+const KEYRING_NSEC: &str = "nsec";
+const KEYRING_NWC: &str = "nwc";
 
 /// Credential manager for secure, password-protected storage of Nostr keys
 pub struct CredentialManager {
     data_dir: PathBuf,
+    use_keyring: bool,
 }
 
 impl CredentialManager {
@@ -35,7 +50,9 @@ impl CredentialManager {
         fs::create_dir_all(&data_dir)
             .map_err(|e| format!("Failed to create data directory: {}", e))?;
         
-        Ok(Self { data_dir })
+        let use_keyring = super::config::Config::load().use_system_keyring;
+        
+        Ok(Self { data_dir, use_keyring })
     }
     
     fn credentials_path(&self) -> PathBuf {
@@ -50,6 +67,10 @@ impl CredentialManager {
         self.data_dir.join(NWC_FILE)
     }
     
+    fn verifier_path(&self) -> PathBuf {
+        self.data_dir.join(VERIFIER_FILE)
+    }
+    
     /// Derive encryption key from password using Argon2
     fn derive_key(&self, password: &str, salt: &[u8]) -> Result<[u8; 32], String> {
         let mut key = [0u8; 32];
@@ -59,8 +80,63 @@ impl CredentialManager {
         Ok(key)
     }
     
-    /// Store the nsec securely with password protection
+    /// Store a secret in the keyring if enabled; false means use the file instead
+    fn save_to_keyring(&self, kind: &str, secret: &str) -> bool {
+        if !self.use_keyring {
+            return false;
+        }
+        match keyring_entry(kind).and_then(|entry| entry.set_password(secret)) {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::info!("System keyring unavailable, using encrypted file: {}", e);
+                false
+            }
+        }
+    }
+    
+    /// Look up a secret in the keyring if enabled
+    fn load_from_keyring(&self, kind: &str) -> Option<String> {
+        if !self.use_keyring {
+            return None;
+        }
+        match keyring_entry(kind).and_then(|entry| entry.get_password()) {
+            Ok(secret) => Some(secret),
+            Err(keyring::Error::NoEntry) => None,
+            Err(e) => {
+                tracing::warn!("Failed to read from system keyring: {}", e);
+                None
+            }
+        }
+    }
+    
+    /// Delete a keyring entry, even if the keyring has since been disabled
+    fn delete_from_keyring(kind: &str) {
+        match keyring_entry(kind).and_then(|entry| entry.delete_credential()) {
+            Ok(()) | Err(keyring::Error::NoEntry) => {}
+            Err(e) => tracing::debug!("No keyring entry removed for {}: {}", kind, e),
+        }
+    }
+    
+    /// Check if the nsec is stored in the system keyring (no password needed)
+    /// May wait for the keyring to be unlocked
+    pub fn in_keyring(&self) -> bool {
+        self.load_from_keyring(KEYRING_NSEC).is_some()
+    }
+    
+    /// Store the nsec securely: in the system keyring if available,
+    /// otherwise encrypted with the password
     pub fn save_nsec(&self, nsec: &str, password: &str) -> Result<(), String> {
+        if password.is_empty() {
+            return Err("A password is required to save credentials".to_string());
+        }
+        self.save_verifier(password)?;
+        
+        if self.save_to_keyring(KEYRING_NSEC, nsec) {
+            // Don't leave an older file copy behind
+            let _ = fs::remove_file(self.credentials_path());
+            return Ok(());
+        }
+        
         // Generate a random salt and save it
         let mut salt = [0u8; 16];
         rand::thread_rng().fill_bytes(&mut salt);
@@ -92,8 +168,50 @@ impl CredentialManager {
         Ok(())
     }
     
-    /// Retrieve the stored nsec using the password
+    /// Keep a hash of the password to check before revealing the key
+    fn save_verifier(&self, password: &str) -> Result<(), String> {
+        let mut salt = [0u8; 16];
+        rand::thread_rng().fill_bytes(&mut salt);
+        let hash = self.derive_key(password, &salt)?;
+        let mut data = salt.to_vec();
+        data.extend(hash);
+        fs::write(self.verifier_path(), &data)
+            .map_err(|e| format!("Failed to save credentials: {}", e))
+    }
+    
+    /// Check a password against the saved hash
+    fn verify_password(&self, password: &str) -> Result<(), String> {
+        let data = fs::read(self.verifier_path())
+            .map_err(|_| "No password is saved for this key - save your credentials again to back it up here".to_string())?;
+        if data.len() != 48 {
+            return Err("Invalid credential data".to_string());
+        }
+        let hash = self.derive_key(password, &data[..16])?;
+        // Compare without stopping at the first difference
+        let diff = hash.iter().zip(&data[16..]).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        if diff != 0 {
+            return Err("Invalid password".to_string());
+        }
+        Ok(())
+    }
+    
+    /// Retrieve the nsec for showing it to the user; the password is always
+    /// checked, also when the key comes from the keyring
+    pub fn reveal_nsec(&self, password: &str) -> Result<Option<String>, String> {
+        if let Some(nsec) = self.load_from_keyring(KEYRING_NSEC) {
+            self.verify_password(password)?;
+            return Ok(Some(nsec));
+        }
+        self.get_nsec(password)
+    }
+    
+    /// Retrieve the stored nsec from the keyring, or from the file using the password
+    /// The keyring needs no password: it is unlocked with the desktop session
     pub fn get_nsec(&self, password: &str) -> Result<Option<String>, String> {
+        if let Some(nsec) = self.load_from_keyring(KEYRING_NSEC) {
+            return Ok(Some(nsec));
+        }
+        
         // Read salt
         let salt = match fs::read(self.salt_path()) {
             Ok(s) => s,
@@ -128,22 +246,39 @@ impl CredentialManager {
             .map_err(|e| format!("Invalid credential data: {}", e))
     }
     
-    /// Check if encrypted credentials are stored
-    pub fn has_credentials(&self) -> bool {
+    /// Check if credentials are stored in the encrypted file (no keyring access)
+    pub fn has_credentials_file(&self) -> bool {
         self.credentials_path().exists() && self.salt_path().exists()
     }
     
+    /// Check if credentials are stored (in the keyring or the encrypted file)
+    /// May wait for the keyring to be unlocked
+    pub fn has_credentials(&self) -> bool {
+        self.has_credentials_file() || self.in_keyring()
+    }
+    
     /// Clear stored credentials (logout)
     pub fn clear(&self) -> Result<(), String> {
         // Remove both files, ignore if they don't exist
         let _ = fs::remove_file(self.credentials_path());
         let _ = fs::remove_file(self.salt_path());
         let _ = fs::remove_file(self.nwc_path());
+        let _ = fs::remove_file(self.verifier_path());
+        // Clear the keyring even if it has since been disabled
+        for kind in [KEYRING_NSEC, KEYRING_NWC] {
+            Self::delete_from_keyring(kind);
+        }
         Ok(())
     }
     
-    /// Store NWC URI securely with password protection (uses existing salt)
+    /// Store NWC URI securely: in the system keyring if available,
+    /// otherwise encrypted with the password (uses existing salt)
     pub fn save_nwc(&self, nwc_uri: &str, password: &str) -> Result<(), String> {
+        if self.save_to_keyring(KEYRING_NWC, nwc_uri) {
+            let _ = fs::remove_file(self.nwc_path());
+            return Ok(());
+        }
+        
         // Salt must exist from nsec storage
         let salt = fs::read(self.salt_path())
             .map_err(|_| "No credentials stored - set up password first".to_string())?;
@@ -173,8 +308,12 @@ impl CredentialManager {
         Ok(())
     }
     
-    /// Retrieve the stored NWC URI using the password
+    /// Retrieve the stored NWC URI from the keyring, or from the file using the password
     pub fn get_nwc(&self, password: &str) -> Result<Option<String>, String> {
+        if let Some(uri) = self.load_from_keyring(KEYRING_NWC) {
+            return Ok(Some(uri));
+        }
+        
         // Read salt
         let salt = match fs::read(self.salt_path()) {
             Ok(s) => s,
@@ -211,16 +350,21 @@ impl CredentialManager {
     
     /// Check if NWC is stored
     pub fn has_nwc(&self) -> bool {
-        self.nwc_path().exists()
+        self.nwc_path().exists() || self.load_from_keyring(KEYRING_NWC).is_some()
     }
     
     /// Clear just NWC (disconnect wallet without clearing nsec)
     pub fn clear_nwc(&self) -> Result<(), String> {
         let _ = fs::remove_file(self.nwc_path());
+        Self::delete_from_keyring(KEYRING_NWC);
         Ok(())
     }
 }
 
+fn keyring_entry(kind: &str) -> keyring::Result<keyring::Entry> {
+    keyring::Entry::new(KEYRING_SERVICE, kind)
+}
+
 impl Default for CredentialManager {
     fn default() -> Self {
         Self::new().expect("Failed to create credential manager")
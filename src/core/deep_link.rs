@@ -0,0 +1,113 @@
+//! Deep links - nostr: URIs (NIP-21) and signer URIs handed to the app on
+//! the command line or by another instance over D-Bus
+//!
+//! Links arriving before the UI is ready (or while logged out) are queued
+//! and delivered once a handler is registered after login.
+
+use std::sync::{Mutex, RwLock};
+use nostr_sdk::prelude::*;
+
+use crate::signer::uri::is_signer_uri;
+
+/// Where a link leads
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeepLink {
+    /// Profile by hex pubkey (npub, nprofile)
+    Profile(String),
+    /// Thread by hex event id (note, nevent)
+    Thread(String),
+    /// Addressable event by its nostr:naddr URI
+    Article(String),
+    /// nostrconnect:// or nostrsigner: request for the signer
+    Signer(String),
+}
+
+impl DeepLink {
+    /// Parse nostr:<bech32> (also web+nostr: and bare bech32) or a signer URI
+    pub fn parse(uri: &str) -> Option<Self> {
+        let uri = uri.trim();
+        if is_signer_uri(uri) {
+            return Some(DeepLink::Signer(uri.to_string()));
+        }
+
+        let bech32 = uri.strip_prefix("web+nostr:")
+            .or_else(|| uri.strip_prefix("nostr:"))
+            .unwrap_or(uri)
+            .trim_start_matches("//");
+        if bech32.starts_with("npub1") {
+            PublicKey::from_bech32(bech32).ok().map(|pk| DeepLink::Profile(pk.to_hex()))
+        } else if bech32.starts_with("nprofile1") {
+            Nip19Profile::from_bech32(bech32).ok().map(|p| DeepLink::Profile(p.public_key.to_hex()))
+        } else if bech32.starts_with("note1") {
+            EventId::from_bech32(bech32).ok().map(|id| DeepLink::Thread(id.to_hex()))
+        } else if bech32.starts_with("nevent1") {
+            Nip19Event::from_bech32(bech32).ok().map(|n| DeepLink::Thread(n.event_id.to_hex()))
+        } else if bech32.starts_with("naddr1") {
+            Nip19Coordinate::from_bech32(bech32).ok().map(|_| DeepLink::Article(format!("nostr:{}", bech32)))
+        } else {
+            None
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DeepLink::Profile(_) => "profile",
+            DeepLink::Thread(_) => "thread",
+            DeepLink::Article(_) => "article",
+            DeepLink::Signer(_) => "signer",
+        }
+    }
+
+    /// Pubkey, event id or URI the link opens
+    pub fn target(&self) -> &str {
+        match self {
+            DeepLink::Profile(target)
+            | DeepLink::Thread(target)
+            | DeepLink::Article(target)
+            | DeepLink::Signer(target) => target,
+        }
+    }
+}
+
+/// Called for each link once the UI is ready for it
+pub type LinkHandler = Box<dyn Fn(DeepLink) + Send + Sync>;
+
+lazy_static::lazy_static! {
+    static ref HANDLER: RwLock<Option<LinkHandler>> = RwLock::new(None);
+}
+
+/// Links waiting for a handler, oldest first
+static PENDING: Mutex<Vec<DeepLink>> = Mutex::new(Vec::new());
+
+/// Whether a command line argument is a link this app opens
+pub fn is_link(arg: &str) -> bool {
+    DeepLink::parse(arg).is_some()
+}
+
+/// Open a link now, or once the UI is ready; returns false if it isn't one
+pub fn open(uri: &str) -> bool {
+    let Some(link) = DeepLink::parse(uri) else {
+        tracing::info!("Ignoring unsupported link: {}", uri);
+        return false;
+    };
+    let handler = HANDLER.read().unwrap();
+    match handler.as_ref() {
+        Some(handler) => handler(link),
+        None => PENDING.lock().unwrap().push(link),
+    }
+    true
+}
+
+/// Register the handler and deliver any queued links to it
+pub fn set_handler(handler: LinkHandler) {
+    let mut guard = HANDLER.write().unwrap();
+    for link in std::mem::take(&mut *PENDING.lock().unwrap()) {
+        handler(link);
+    }
+    *guard = Some(handler);
+}
+
+/// Queue links again until the next login
+pub fn clear_handler() {
+    *HANDLER.write().unwrap() = None;
+}
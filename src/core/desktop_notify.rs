@@ -0,0 +1,180 @@
+//! Desktop notifications via the freedesktop D-Bus notification service
+//!
+//! Posts org.freedesktop.Notifications messages for new mentions, DMs and
+//! zaps while the window is in the background. Clicking a notification
+//! invokes its "default" action, which is forwarded to the registered
+//! activation handler so the UI can open the related note or conversation.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, RwLock};
+use futures::StreamExt;
+use zbus::{zvariant::Value, Connection, Proxy};
+
+use super::config::Config;
+
+/// D-Bus service details for the notification daemon
+const DBUS_SERVICE: &str = "org.freedesktop.Notifications";
+const DBUS_PATH: &str = "/org/freedesktop/Notifications";
+const DBUS_INTERFACE: &str = "org.freedesktop.Notifications";
+
+const APP_NAME: &str = "Pleb Client";
+const APP_ICON: &str = "pleb-client";
+
+/// How long a notification stays on screen (ms)
+const EXPIRE_TIMEOUT_MS: i32 = 8000;
+
+/// Clickable notifications remembered for activation
+const MAX_TRACKED: usize = 100;
+
+/// Kind of event a desktop notification is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotifyKind {
+    Mention,
+    DirectMessage,
+    Zap,
+}
+
+impl NotifyKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotifyKind::Mention => "mention",
+            NotifyKind::DirectMessage => "dm",
+            NotifyKind::Zap => "zap",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "mention" => Some(NotifyKind::Mention),
+            "dm" => Some(NotifyKind::DirectMessage),
+            "zap" => Some(NotifyKind::Zap),
+            _ => None,
+        }
+    }
+
+    /// Whether this kind is enabled in the config
+    pub fn is_enabled(&self, config: &Config) -> bool {
+        match self {
+            NotifyKind::Mention => config.desktop_notify_mentions,
+            NotifyKind::DirectMessage => config.desktop_notify_dms,
+            NotifyKind::Zap => config.desktop_notify_zaps,
+        }
+    }
+}
+
+/// Called with the kind and target (note id or peer pubkey) when a notification is clicked
+pub type ActivationHandler = Box<dyn Fn(NotifyKind, String) + Send + Sync>;
+
+/// Notifications start out suppressed until the window reports its state
+static WINDOW_ACTIVE: AtomicBool = AtomicBool::new(true);
+
+lazy_static::lazy_static! {
+    static ref NOTIFY_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+    static ref CONNECTION: tokio::sync::Mutex<Option<Connection>> = tokio::sync::Mutex::new(None);
+    /// Notification id -> what to open when it is clicked
+    static ref TARGETS: Mutex<HashMap<u32, (NotifyKind, String)>> = Mutex::new(HashMap::new());
+    static ref HANDLER: RwLock<Option<ActivationHandler>> = RwLock::new(None);
+}
+
+/// Record whether the main window has focus; notifications only show when it doesn't
+pub fn set_window_active(active: bool) {
+    WINDOW_ACTIVE.store(active, Ordering::SeqCst);
+}
+
+/// Register the handler for clicked notifications
+pub fn set_activation_handler(handler: ActivationHandler) {
+    *HANDLER.write().unwrap() = Some(handler);
+}
+
+/// Show a desktop notification if its kind is enabled and the window is in the background
+/// Runs in the background; failures (e.g. no notification daemon) are only logged
+pub fn notify(kind: NotifyKind, summary: &str, body: &str, target: &str) {
+    if WINDOW_ACTIVE.load(Ordering::SeqCst) || !kind.is_enabled(&Config::load()) {
+        return;
+    }
+
+    let summary = summary.to_string();
+    let body = body.to_string();
+    let target = target.to_string();
+
+    NOTIFY_RUNTIME.spawn(async move {
+        match send(&summary, &body).await {
+            Ok(id) => {
+                let mut targets = TARGETS.lock().unwrap();
+                if targets.len() >= MAX_TRACKED {
+                    // Ids increase, so the smallest is the oldest
+                    if let Some(&oldest) = targets.keys().min() {
+                        targets.remove(&oldest);
+                    }
+                }
+                targets.insert(id, (kind, target));
+            }
+            Err(e) => tracing::warn!("Desktop notification failed: {}", e),
+        }
+    });
+}
+
+/// Session bus connection, opened on first use along with the action listener
+async fn connection() -> Result<Connection, String> {
+    let mut conn = CONNECTION.lock().await;
+    if let Some(c) = conn.as_ref() {
+        return Ok(c.clone());
+    }
+
+    let c = Connection::session()
+        .await
+        .map_err(|e| format!("D-Bus connection error: {}", e))?;
+
+    let listener_conn = c.clone();
+    NOTIFY_RUNTIME.spawn(async move {
+        if let Err(e) = listen_for_actions(listener_conn).await {
+            tracing::warn!("Notification action listener stopped: {}", e);
+        }
+    });
+
+    *conn = Some(c.clone());
+    Ok(c)
+}
+
+async fn send(summary: &str, body: &str) -> Result<u32, String> {
+    let conn = connection().await?;
+    let proxy = Proxy::new(&conn, DBUS_SERVICE, DBUS_PATH, DBUS_INTERFACE)
+        .await
+        .map_err(|e| format!("D-Bus proxy error: {}", e))?;
+
+    let actions = vec!["default", "Open"];
+    let hints: HashMap<&str, Value> = HashMap::new();
+
+    proxy.call("Notify", &(APP_NAME, 0u32, APP_ICON, summary, body, actions, hints, EXPIRE_TIMEOUT_MS))
+        .await
+        .map_err(|e| format!("Notify call failed: {}", e))
+}
+
+/// Forward "default" actions on our notifications to the activation handler
+async fn listen_for_actions(conn: Connection) -> Result<(), String> {
+    let proxy = Proxy::new(&conn, DBUS_SERVICE, DBUS_PATH, DBUS_INTERFACE)
+        .await
+        .map_err(|e| format!("D-Bus proxy error: {}", e))?;
+    let mut stream = proxy.receive_signal("ActionInvoked")
+        .await
+        .map_err(|e| format!("Failed to subscribe to actions: {}", e))?;
+
+    while let Some(message) = stream.next().await {
+        let Ok((id, action)) = message.body().deserialize::<(u32, String)>() else {
+            continue;
+        };
+        if action != "default" {
+            continue;
+        }
+
+        let target = TARGETS.lock().unwrap().remove(&id);
+        if let Some((kind, target)) = target {
+            if let Some(handler) = HANDLER.read().unwrap().as_ref() {
+                handler(kind, target);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+//! Composer drafts persisted to disk
+//!
+//! Each draft is a JSON file in the user's data directory, written via a
+//! temporary file and rename so a crash mid-write never corrupts an existing
+//! draft.
+
+use std::fs;
+use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+
+const DRAFTS_DIR: &str = "note-drafts";
+
+/// A half-written note from the composer
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoteDraft {
+    #[serde(default)]
+    pub id: String,
+    pub content: String,
+    /// Event id being replied to, if this is a reply
+    #[serde(default)]
+    pub reply_to: Option<String>,
+    /// Display context for the reply (author name, content preview)
+    #[serde(default)]
+    pub reply_to_author: Option<String>,
+    #[serde(default)]
+    pub reply_to_content: Option<String>,
+    /// Attached media (local paths or already-uploaded URLs)
+    #[serde(default)]
+    pub media_paths: Vec<String>,
+    #[serde(default)]
+    pub content_warning: Option<String>,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+/// Draft manager for the note composer
+pub struct DraftManager {
+    drafts_dir: PathBuf,
+}
+
+impl DraftManager {
+    /// Create a new draft manager
+    pub fn new() -> Result<Self, String> {
+        let drafts_dir = directories::ProjectDirs::from("", "", "pleb-client")
+            .ok_or("Failed to determine data directory")?
+            .data_dir()
+            .join(DRAFTS_DIR);
+
+        fs::create_dir_all(&drafts_dir)
+            .map_err(|e| format!("Failed to create drafts directory: {}", e))?;
+
+        Ok(Self { drafts_dir })
+    }
+
+    fn draft_path(&self, id: &str) -> PathBuf {
+        self.drafts_dir.join(format!("{}.json", id))
+    }
+
+    /// Only ids we generated are accepted, so an id can't escape the drafts directory
+    fn is_valid_id(id: &str) -> bool {
+        !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    }
+
+    /// Save a draft, assigning an id and timestamps as needed
+    /// Returns the stored draft
+    pub fn save(&self, mut draft: NoteDraft) -> Result<NoteDraft, String> {
+        let now = chrono::Utc::now().timestamp();
+
+        if draft.id.is_empty() {
+            draft.id = format!("draft-{}", uuid::Uuid::new_v4());
+        } else if !Self::is_valid_id(&draft.id) {
+            return Err(format!("Invalid draft id: {}", draft.id));
+        }
+        if draft.created_at == 0 {
+            draft.created_at = now;
+        }
+        draft.updated_at = now;
+
+        let json = serde_json::to_string_pretty(&draft)
+            .map_err(|e| format!("Failed to serialize draft: {}", e))?;
+
+        let path = self.draft_path(&draft.id);
+        let tmp_path = path.with_extension("json.tmp");
+        fs::write(&tmp_path, json)
+            .map_err(|e| format!("Failed to write draft: {}", e))?;
+        fs::rename(&tmp_path, &path)
+            .map_err(|e| format!("Failed to save draft: {}", e))?;
+
+        Ok(draft)
+    }
+
+    /// All drafts, most recently updated first
+    /// Unreadable files are skipped
+    pub fn list(&self) -> Vec<NoteDraft> {
+        let mut drafts: Vec<NoteDraft> = fs::read_dir(&self.drafts_dir)
+            .map(|entries| {
+                entries.flatten()
+                    .map(|e| e.path())
+                    .filter(|p| p.extension().map_or(false, |e| e == "json"))
+                    .filter_map(|p| fs::read_to_string(p).ok())
+                    .filter_map(|s| serde_json::from_str(&s).ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        drafts.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        drafts
+    }
+
+    /// Load a single draft
+    pub fn get(&self, id: &str) -> Option<NoteDraft> {
+        if !Self::is_valid_id(id) {
+            return None;
+        }
+        let content = fs::read_to_string(self.draft_path(id)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Delete a draft; returns false if it didn't exist
+    pub fn delete(&self, id: &str) -> Result<bool, String> {
+        if !Self::is_valid_id(id) {
+            return Err(format!("Invalid draft id: {}", id));
+        }
+        let path = self.draft_path(id);
+        if !path.exists() {
+            return Ok(false);
+        }
+        fs::remove_file(&path)
+            .map_err(|e| format!("Failed to delete draft: {}", e))?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,222 @@
+//! Fiat display - sats to fiat conversion with a cached exchange rate
+//!
+//! The rate endpoint is expected to return a JSON object mapping currency
+//! codes to the price of one bitcoin (mempool.space `/api/v1/prices` style,
+//! e.g. `{"USD": 65000, "EUR": 60000}`). Rates are cached and refreshed at
+//! most hourly; conversion is skipped when no currency is configured.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use super::config::Config;
+
+/// Default exchange rate endpoint
+pub const DEFAULT_RATE_ENDPOINT: &str = "https://mempool.space/api/v1/prices";
+
+/// How often rates are refreshed
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// How often the refresh loop wakes up to check for stale rates
+const REFRESH_CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Timeout for the rate lookup
+const RATE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Offered in settings before any rates have been fetched
+const COMMON_CURRENCIES: &[&str] = &["AUD", "CAD", "CHF", "EUR", "GBP", "JPY", "USD"];
+
+const SATS_PER_BTC: f64 = 100_000_000.0;
+
+struct RateCache {
+    fetched_at: Instant,
+    /// Currency code (uppercase) -> BTC price
+    prices: HashMap<String, f64>,
+}
+
+/// Set once the hourly refresh loop is running
+static REFRESH_STARTED: AtomicBool = AtomicBool::new(false);
+
+lazy_static::lazy_static! {
+    static ref FIAT_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+    static ref RATES: RwLock<Option<RateCache>> = RwLock::new(None);
+    /// Display currency, cached so per-note formatting doesn't hit the config file
+    static ref CURRENCY: RwLock<Option<String>> = RwLock::new(normalize(Config::load().fiat_currency));
+}
+
+fn normalize(currency: Option<String>) -> Option<String> {
+    currency
+        .map(|c| c.trim().to_uppercase())
+        .filter(|c| !c.is_empty())
+}
+
+/// Configured display currency (uppercase), if fiat display is enabled
+pub fn display_currency() -> Option<String> {
+    CURRENCY.read().unwrap().clone()
+}
+
+/// Change the display currency (None turns fiat display off)
+/// The caller is responsible for saving it to the config
+pub fn set_display_currency(currency: Option<String>) {
+    *CURRENCY.write().unwrap() = normalize(currency);
+}
+
+/// True if rates are missing or older than REFRESH_INTERVAL
+pub fn needs_refresh() -> bool {
+    RATES.read().unwrap()
+        .as_ref()
+        .map_or(true, |cache| cache.fetched_at.elapsed() >= REFRESH_INTERVAL)
+}
+
+/// Fetch current rates from the endpoint and replace the cache
+pub async fn refresh_rates(endpoint: &str) -> Result<(), String> {
+    let client = super::network::http_client()
+        .timeout(RATE_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let _permit = super::limits::host_permit(endpoint).await;
+    let response = client.get(endpoint)
+        .send()
+        .await
+        .map_err(|e| format!("Exchange rate request failed: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Exchange rate request failed ({})", response.status()));
+    }
+
+    let body: HashMap<String, serde_json::Value> = response.json()
+        .await
+        .map_err(|e| format!("Invalid exchange rate response: {}", e))?;
+
+    // Non-price fields (e.g. "time") are skipped
+    let prices: HashMap<String, f64> = body.into_iter()
+        .filter(|(code, _)| code.len() == 3 && code.chars().all(|c| c.is_ascii_alphabetic()))
+        .filter_map(|(code, value)| value.as_f64().map(|price| (code.to_uppercase(), price)))
+        .filter(|(_, price)| *price > 0.0)
+        .collect();
+
+    if prices.is_empty() {
+        return Err("Exchange rate response contained no prices".to_string());
+    }
+
+    *RATES.write().unwrap() = Some(RateCache { fetched_at: Instant::now(), prices });
+    Ok(())
+}
+
+/// Start refreshing rates in the background (now, then hourly)
+/// `on_update` runs after each successful refresh; later calls are no-ops
+pub fn start_refresh_loop<F>(on_update: F)
+where
+    F: Fn() + Send + 'static,
+{
+    if REFRESH_STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    FIAT_RUNTIME.spawn(async move {
+        loop {
+            // Config is re-read each round so currency/endpoint changes apply
+            let config = Config::load();
+            if display_currency().is_some() && needs_refresh() {
+                match refresh_rates(&config.exchange_rate_endpoint).await {
+                    Ok(()) => on_update(),
+                    Err(e) => tracing::warn!("Exchange rate refresh failed: {}", e),
+                }
+            }
+            tokio::time::sleep(REFRESH_CHECK_INTERVAL).await;
+        }
+    });
+}
+
+/// Refresh now in the background (e.g. after the currency changed)
+pub fn refresh_now<F>(on_update: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    let endpoint = Config::load().exchange_rate_endpoint;
+    FIAT_RUNTIME.spawn(async move {
+        match refresh_rates(&endpoint).await {
+            Ok(()) => on_update(),
+            Err(e) => tracing::warn!("Exchange rate refresh failed: {}", e),
+        }
+    });
+}
+
+/// Currency codes with a cached rate, sorted; the common set before the first fetch
+pub fn available_currencies() -> Vec<String> {
+    let rates = RATES.read().unwrap();
+    let mut codes: Vec<String> = match rates.as_ref() {
+        Some(cache) => cache.prices.keys().cloned().collect(),
+        None => COMMON_CURRENCIES.iter().map(|c| c.to_string()).collect(),
+    };
+    codes.sort();
+    codes
+}
+
+/// Fiat value of an amount of sats, if a rate for the currency is cached
+pub fn sats_to_fiat(sats: u64, currency: &str) -> Option<f64> {
+    let rates = RATES.read().unwrap();
+    let price = rates.as_ref()?.prices.get(&currency.to_uppercase())?;
+    Some(sats as f64 / SATS_PER_BTC * price)
+}
+
+fn currency_symbol(currency: &str) -> Option<&'static str> {
+    match currency {
+        "USD" | "CAD" | "AUD" => Some("$"),
+        "EUR" => Some("€"),
+        "GBP" => Some("£"),
+        "JPY" => Some("¥"),
+        "CHF" => Some("CHF "),
+        _ => None,
+    }
+}
+
+/// Format a fiat amount, e.g. "$1.40", "¥210", "12.50 SEK"
+pub fn format_fiat(amount: f64, currency: &str) -> String {
+    let decimals = if currency == "JPY" { 0 } else { 2 };
+    let smallest = if decimals == 0 { 1.0 } else { 0.01 };
+
+    let number = if amount > 0.0 && amount < smallest {
+        format!("<{}", format_number(smallest, decimals))
+    } else {
+        format_number(amount, decimals)
+    };
+
+    match currency_symbol(currency) {
+        Some(symbol) if number.starts_with('<') => format!("<{}{}", symbol, &number[1..]),
+        Some(symbol) => format!("{}{}", symbol, number),
+        None => format!("{} {}", number, currency),
+    }
+}
+
+/// Fixed-point number with thousands separators
+fn format_number(amount: f64, decimals: usize) -> String {
+    let formatted = format!("{:.*}", decimals, amount);
+    let (int_part, frac_part) = match formatted.split_once('.') {
+        Some((i, f)) => (i, Some(f)),
+        None => (formatted.as_str(), None),
+    };
+
+    let mut grouped = String::new();
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+
+    match frac_part {
+        Some(f) => format!("{}.{}", grouped, f),
+        None => grouped,
+    }
+}
+
+/// Fiat equivalent of sats in the configured currency, e.g. "≈ $1.40"
+/// None when fiat display is off or no rate is cached yet
+pub fn fiat_label(sats: u64) -> Option<String> {
+    let currency = display_currency()?;
+    let amount = sats_to_fiat(sats, &currency)?;
+    Some(format!("≈ {}", format_fiat(amount, &currency)))
+}
@@ -0,0 +1,77 @@
+//! Single-instance guard over D-Bus
+//!
+//! The first instance owns com.plebclient.Client. A later launch hands its
+//! nostr: or signer URIs over with OpenUri, asks the running instance to
+//! raise its window with Activate, and exits instead of starting a second
+//! client with its own runtimes and relay connections.
+
+use std::sync::{OnceLock, RwLock};
+use zbus::blocking::{connection::Builder as ConnectionBuilder, Connection};
+use zbus::interface;
+
+use super::deep_link;
+
+const BUS_NAME: &str = "com.plebclient.Client";
+const OBJECT_PATH: &str = "/com/plebclient/Client";
+const INTERFACE: &str = "com.plebclient.Client1";
+
+/// Keeps the bus name for the life of the process
+static CONNECTION: OnceLock<Connection> = OnceLock::new();
+
+/// Called on the D-Bus thread when another launch wants the window shown
+pub type ActivationHandler = Box<dyn Fn() + Send + Sync>;
+
+static HANDLER: RwLock<Option<ActivationHandler>> = RwLock::new(None);
+
+/// Register the handler that raises the window
+pub fn set_activation_handler(handler: ActivationHandler) {
+    *HANDLER.write().unwrap() = Some(handler);
+}
+
+struct ClientInstance;
+
+#[interface(name = "com.plebclient.Client1")]
+impl ClientInstance {
+    /// Open a nostr: or signer URI; false if it isn't one
+    fn open_uri(&self, uri: &str) -> bool {
+        deep_link::open(uri)
+    }
+
+    /// Bring the window to the front
+    fn activate(&self) {
+        if let Some(handler) = HANDLER.read().unwrap().as_ref() {
+            handler();
+        }
+    }
+}
+
+/// Claim the bus name so later launches can find this instance
+pub fn serve() -> Result<(), String> {
+    let connection = ConnectionBuilder::session()
+        .and_then(|builder| builder.name(BUS_NAME))
+        .and_then(|builder| builder.serve_at(OBJECT_PATH, ClientInstance))
+        .and_then(|builder| builder.build())
+        .map_err(|e| format!("Failed to register {}: {}", BUS_NAME, e))?;
+    let _ = CONNECTION.set(connection);
+    Ok(())
+}
+
+/// Hand links to the running instance and raise its window; false if there
+/// is none and this launch should become the instance
+pub fn forward(uris: &[String]) -> bool {
+    let Ok(connection) = Connection::session() else {
+        return false;
+    };
+    // Raise first: it fails cheaply when nobody owns the name
+    if let Err(e) = connection.call_method(Some(BUS_NAME), OBJECT_PATH, Some(INTERFACE), "Activate", &()) {
+        tracing::debug!("No running instance: {}", e);
+        return false;
+    }
+    for uri in uris {
+        let reply = connection.call_method(Some(BUS_NAME), OBJECT_PATH, Some(INTERFACE), "OpenUri", &(uri.as_str(),));
+        if let Err(e) = reply {
+            tracing::warn!("Running instance did not take {}: {}", uri, e);
+        }
+    }
+    true
+}
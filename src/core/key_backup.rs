@@ -0,0 +1,47 @@
+//! Key backup reminders
+//!
+//! Accounts created in the app only show their nsec once. Until the user
+//! confirms they have a copy, a reminder comes due every
+//! `REMINDER_INTERVAL_SECS`; "remind me later" pushes it back by the same
+//! interval. Accounts imported with an nsec are assumed to be backed up.
+
+use super::config::Config;
+
+/// Seconds between "have you backed up your key" reminders (one week)
+pub const REMINDER_INTERVAL_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// True if the backup reminder should be shown at `now`
+pub fn reminder_due(config: &Config, now: i64) -> bool {
+    if !config.key_backup_pending || config.key_backed_up_at.is_some() {
+        return false;
+    }
+    match config.key_backup_reminded_at {
+        Some(reminded_at) => now - reminded_at >= REMINDER_INTERVAL_SECS,
+        None => true,
+    }
+}
+
+/// Start tracking backups for a freshly generated key
+pub fn mark_new_key(config: &mut Config) {
+    config.key_backup_pending = true;
+    config.key_backed_up_at = None;
+    config.key_backup_reminded_at = None;
+}
+
+/// Record that the user has a copy of their key
+pub fn mark_backed_up(config: &mut Config, now: i64) {
+    config.key_backup_pending = false;
+    config.key_backed_up_at = Some(now);
+}
+
+/// Push the next reminder back by one interval
+pub fn snooze(config: &mut Config, now: i64) {
+    config.key_backup_reminded_at = Some(now);
+}
+
+/// Forget backup state (logout)
+pub fn clear(config: &mut Config) {
+    config.key_backup_pending = false;
+    config.key_backed_up_at = None;
+    config.key_backup_reminded_at = None;
+}
@@ -0,0 +1,99 @@
+//! Concurrency limits - caps on simultaneous relay fetches, background
+//! worker threads and HTTP requests per host
+//!
+//! Limits are semaphores shared by every bridge. Changing a limit swaps in a
+//! new semaphore; work already holding a permit finishes under the old one.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use super::config::Config;
+
+/// Default cap on relay queries in flight at once
+pub const DEFAULT_MAX_RELAY_FETCHES: usize = 8;
+
+/// Default cap on bridge worker threads doing work at once
+pub const DEFAULT_MAX_BACKGROUND_THREADS: usize = 16;
+
+/// Default cap on HTTP requests to a single host at once
+pub const DEFAULT_MAX_REQUESTS_PER_HOST: usize = 4;
+
+/// Upper bound accepted from settings for any limit
+pub const MAX_LIMIT: usize = 256;
+
+struct Limits {
+    relay_fetches: Arc<Semaphore>,
+    threads: Arc<Semaphore>,
+    per_host: usize,
+    hosts: Mutex<HashMap<String, Arc<Semaphore>>>,
+}
+
+impl Limits {
+    fn new(relay_fetches: usize, threads: usize, per_host: usize) -> Self {
+        Self {
+            relay_fetches: Arc::new(Semaphore::new(clamp(relay_fetches))),
+            threads: Arc::new(Semaphore::new(clamp(threads))),
+            per_host: clamp(per_host),
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+fn clamp(limit: usize) -> usize {
+    limit.clamp(1, MAX_LIMIT)
+}
+
+lazy_static::lazy_static! {
+    static ref LIMITS: RwLock<Limits> = {
+        let config = Config::load();
+        RwLock::new(Limits::new(
+            config.max_relay_fetches,
+            config.max_background_threads,
+            config.max_requests_per_host,
+        ))
+    };
+}
+
+/// Apply new limits (from settings) to all future work
+pub fn apply(relay_fetches: usize, threads: usize, per_host: usize) {
+    *LIMITS.write().unwrap() = Limits::new(relay_fetches, threads, per_host);
+    tracing::info!(
+        "Concurrency limits: {} relay fetches, {} threads, {} requests per host",
+        clamp(relay_fetches), clamp(threads), clamp(per_host)
+    );
+}
+
+/// Wait for a relay fetch slot; the slot is released when the permit drops
+pub async fn relay_fetch_permit() -> Option<OwnedSemaphorePermit> {
+    let semaphore = LIMITS.read().unwrap().relay_fetches.clone();
+    semaphore.acquire_owned().await.ok()
+}
+
+/// Wait for a request slot for the URL's host
+/// URLs without a host aren't limited
+pub async fn host_permit(url: &str) -> Option<OwnedSemaphorePermit> {
+    let host = url::Url::parse(url).ok()?.host_str()?.to_lowercase();
+    let semaphore = {
+        let limits = LIMITS.read().unwrap();
+        let mut hosts = limits.hosts.lock().unwrap();
+        hosts.entry(host)
+            .or_insert_with(|| Arc::new(Semaphore::new(limits.per_host)))
+            .clone()
+    };
+    semaphore.acquire_owned().await.ok()
+}
+
+/// Spawn a bridge worker thread that waits for a thread slot before running
+/// Only for one-shot work; long-lived loops would hold their slot forever
+pub fn spawn_limited<F, T>(f: F) -> std::thread::JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let semaphore = LIMITS.read().unwrap().threads.clone();
+    std::thread::spawn(move || {
+        let _permit = futures::executor::block_on(semaphore.acquire_owned()).ok();
+        f()
+    })
+}
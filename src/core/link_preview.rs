@@ -0,0 +1,515 @@
+//! Link previews - OpenGraph metadata for URLs shown in notes
+//!
+//! Pages are read up to MAX_HTML_BYTES, decoded with the charset from the
+//! Content-Type header or a `<meta charset>` near the top, and parsed as
+//! HTML. OpenGraph tags win over Twitter card tags, which win over `<title>`
+//! and `<meta name="description">`. Image, audio and video URLs are resolved
+//! against `<base href>` or the final page URL. A `<meta http-equiv="refresh">`
+//! on a page without metadata is followed like a redirect.
+//!
+//! Only public http(s) hosts are fetched, redirects included, so a note can't
+//! make the client probe localhost or the LAN. Host names are checked after
+//! resolving: every address a name resolves to must be public, so DNS can't
+//! point a preview at a private address either. With `proxy_link_preview_images`
+//! set, the preview image is downloaded into the media cache up front, so QML
+//! reads it from disk through the image provider; anything that isn't a small
+//! image is dropped from the preview instead of being handed to QML. Data
+//! saver mode always proxies, with a smaller size cap.
+//!
+//! Links to video, music and social sites get an `embed` object (see
+//! `oembed`) so QML can show a player card instead of a plain link card.
+
+use std::net::{IpAddr, SocketAddr};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use scraper::{Html, Selector};
+
+use super::config::Config;
+use super::limits;
+use super::media_cache;
+use super::network;
+use super::oembed::{self, Embed};
+
+/// Bytes of HTML read per page; metadata lives in <head>
+const MAX_HTML_BYTES: usize = 1024 * 1024;
+
+/// Largest preview image accepted when proxying
+const MAX_IMAGE_BYTES: u64 = 5 * 1024 * 1024;
+
+/// Largest preview image accepted in data saver mode
+const DATA_SAVER_IMAGE_BYTES: u64 = 512 * 1024;
+
+/// HTTP redirects followed per request
+const MAX_REDIRECTS: usize = 5;
+
+/// Meta refresh hops followed per preview
+const MAX_META_REFRESHES: usize = 2;
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Bytes searched for a `<meta charset>` when the header has none
+const CHARSET_SNIFF_BYTES: usize = 2048;
+
+const MAX_TITLE_CHARS: usize = 300;
+const MAX_DESCRIPTION_CHARS: usize = 500;
+
+/// Metadata found on a page
+#[derive(Debug, Default)]
+struct PageMeta {
+    title: Option<String>,
+    description: Option<String>,
+    image: Option<String>,
+    site_name: Option<String>,
+    audio: Option<String>,
+    video: Option<String>,
+    /// og:video:type, og:video:width and og:video:height
+    video_type: Option<String>,
+    video_size: Option<(f64, f64)>,
+    /// Endpoint from <link rel="alternate" type="application/json+oembed">
+    oembed: Option<url::Url>,
+    refresh: Option<String>,
+}
+
+impl PageMeta {
+    fn is_empty(&self) -> bool {
+        self.title.is_none() && self.description.is_none() && self.image.is_none()
+    }
+}
+
+/// Whether a URL is http(s) on a host that isn't loopback, private or link-local
+pub fn is_public_url(url: &url::Url) -> bool {
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return false;
+    }
+    match url.host() {
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_lowercase();
+            domain != "localhost" && !domain.ends_with(".localhost") && !domain.ends_with(".local")
+                && !domain.ends_with(".internal")
+        }
+        Some(url::Host::Ipv4(ip)) => is_public_ip(IpAddr::V4(ip)),
+        Some(url::Host::Ipv6(ip)) => is_public_ip(IpAddr::V6(ip)),
+        None => false,
+    }
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => !(ip.is_loopback() || ip.is_private() || ip.is_link_local()
+            || ip.is_unspecified() || ip.is_broadcast() || ip.octets()[0] == 0
+            // 100.64.0.0/10 carrier-grade NAT
+            || (ip.octets()[0] == 100 && (ip.octets()[1] & 0xc0) == 64)),
+        IpAddr::V6(ip) => {
+            if let Some(v4) = ip.to_ipv4_mapped() {
+                return is_public_ip(IpAddr::V4(v4));
+            }
+            let first = ip.segments()[0];
+            !(ip.is_loopback() || ip.is_unspecified()
+                // fc00::/7 unique local, fe80::/10 link-local
+                || (first & 0xfe00) == 0xfc00 || (first & 0xffc0) == 0xfe80)
+        }
+    }
+}
+
+/// Resolver for preview requests that refuses names with any non-public address
+struct PublicResolver;
+
+impl reqwest::dns::Resolve for PublicResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+            if addrs.is_empty() {
+                return Err(format!("{} did not resolve", host).into());
+            }
+            if addrs.iter().any(|addr| !is_public_ip(addr.ip())) {
+                return Err(format!("{} resolves to a non-public address", host).into());
+            }
+            Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+/// Preview built from the URL alone, for sites whose player card needs no
+/// request (YouTube); shown while the full preview loads
+pub fn instant(url: &str) -> Option<serde_json::Value> {
+    let embed = oembed::match_url(&url::Url::parse(url).ok()?)?;
+    embed.is_complete().then(|| embed_preview(url, embed.thumbnail.clone(), &embed))
+}
+
+fn embed_preview(url: &str, image: Option<String>, embed: &Embed) -> serde_json::Value {
+    serde_json::json!({
+        "url": url,
+        "title": embed.title,
+        "description": embed.text,
+        "image": image,
+        "siteName": embed.provider,
+        "embed": embed.to_value(),
+    })
+}
+
+/// Fetch a page and build its preview JSON
+/// {url, title, description, image, siteName, audio, video, embed?}
+pub async fn fetch(url: &str) -> Result<serde_json::Value, String> {
+    let mut page_url = url::Url::parse(url).map_err(|e| format!("Invalid URL: {}", e))?;
+
+    let client = http_client()?;
+
+    // Known sites: oEmbed instead of scraping the (often heavy) page
+    if let Some(mut embed) = oembed::match_url(&page_url) {
+        let enriched = oembed::enrich(&client, &mut embed, url).await
+            .map_err(|e| tracing::debug!("oEmbed for {} failed: {}", url, e))
+            .is_ok();
+        if enriched || embed.is_complete() {
+            let image = preview_image(embed.thumbnail.clone()).await;
+            return Ok(embed_preview(url, image, &embed));
+        }
+    }
+
+    let mut refreshes = 0;
+    let (final_url, meta) = loop {
+        if !is_public_url(&page_url) {
+            return Err("Not a public web address".to_string());
+        }
+        let (final_url, html) = fetch_html(&client, &page_url).await?;
+        let meta = parse(&html, &final_url);
+        match meta.refresh.as_deref().and_then(|target| final_url.join(target).ok()) {
+            Some(target) if meta.is_empty() && refreshes < MAX_META_REFRESHES && target != final_url => {
+                refreshes += 1;
+                page_url = target;
+            }
+            _ => break (final_url, meta),
+        }
+    };
+
+    if meta.is_empty() {
+        return Err("No metadata found".to_string());
+    }
+
+    let site_name = meta.site_name.clone().or_else(|| final_url.host_str().map(|h| h.to_string()));
+
+    let mut embed = match &meta.oembed {
+        Some(endpoint) => oembed::discover(&client, endpoint).await
+            .map_err(|e| tracing::debug!("oEmbed for {} failed: {}", url, e))
+            .ok(),
+        None => None,
+    };
+    if embed.is_none() {
+        embed = native_video(&meta, site_name.as_deref());
+    }
+
+    let image = preview_image(meta.image.or_else(|| embed.as_ref().and_then(|e| e.thumbnail.clone()))).await;
+
+    let mut preview = serde_json::json!({
+        "url": url,
+        "title": meta.title.or_else(|| embed.as_ref().and_then(|e| e.title.clone())),
+        "description": meta.description.or_else(|| embed.as_ref().and_then(|e| e.text.clone())),
+        "image": image,
+        "siteName": site_name,
+        "audio": meta.audio,
+        "video": meta.video,
+    });
+    if let Some(embed) = embed {
+        preview["embed"] = embed.to_value();
+    }
+    Ok(preview)
+}
+
+/// A page whose og:video is a file Qt can play gets a native video embed
+fn native_video(meta: &PageMeta, site_name: Option<&str>) -> Option<Embed> {
+    let video = meta.video.as_deref()?;
+    let lower = video.to_lowercase();
+    let path = lower.split(['?', '#']).next().unwrap_or("");
+    let playable = meta.video_type.as_deref().map(|t| t.starts_with("video/mp4") || t.starts_with("video/webm"))
+        .unwrap_or(false)
+        || path.ends_with(".mp4") || path.ends_with(".webm") || path.ends_with(".mov");
+    if !playable {
+        return None;
+    }
+    Some(Embed {
+        provider: site_name.unwrap_or_default().to_string(),
+        kind: "video",
+        aspect_ratio: meta.video_size.map(|(w, h)| w / h).unwrap_or(16.0 / 9.0),
+        title: meta.title.clone(),
+        thumbnail: meta.image.clone(),
+        stream_url: Some(video.to_string()),
+        ..Default::default()
+    })
+}
+
+/// The preview image, checked and cached first when the config asks for it
+async fn preview_image(image: Option<String>) -> Option<String> {
+    match image {
+        // Data saver always goes through the cache, for the smaller size cap
+        Some(image) if Config::load().proxy_link_preview_images || network::data_saver() => proxy_image(image).await,
+        other => other,
+    }
+}
+
+/// HTTP client for previews: short timeout, public hosts only across redirects
+/// and DNS
+fn http_client() -> Result<reqwest::Client, String> {
+    network::http_client()
+        .dns_resolver(Arc::new(PublicResolver))
+        .timeout(FETCH_TIMEOUT)
+        .user_agent("Mozilla/5.0 (compatible; PlebClient/1.0)")
+        .redirect(reqwest::redirect::Policy::custom(|attempt| {
+            if attempt.previous().len() >= MAX_REDIRECTS {
+                attempt.error("Too many redirects")
+            } else if !is_public_url(attempt.url()) {
+                attempt.error("Redirect to a non-public address")
+            } else {
+                attempt.follow()
+            }
+        }))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))
+}
+
+/// Read up to MAX_HTML_BYTES of an HTML page and decode it
+/// Returns the URL after redirects with the text
+async fn fetch_html(client: &reqwest::Client, url: &url::Url) -> Result<(url::Url, String), String> {
+    let _permit = limits::host_permit(url.as_str()).await;
+    let response = client.get(url.clone())
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch URL: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("HTTP error: {}", response.status()));
+    }
+
+    let content_type = response.headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("")
+        .to_lowercase();
+    if !content_type.is_empty() && !content_type.contains("html") {
+        return Err(format!("Not an HTML page: {}", content_type));
+    }
+
+    let final_url = response.url().clone();
+    let bytes = read_capped(response, MAX_HTML_BYTES).await?;
+
+    let encoding = header_charset(&content_type)
+        .or_else(|| sniff_charset(&bytes))
+        .unwrap_or(encoding_rs::UTF_8);
+    let (text, _, _) = encoding.decode(&bytes);
+    Ok((final_url, text.into_owned()))
+}
+
+/// Read at most max_bytes of a response body; the rest is never downloaded
+pub async fn read_capped(mut response: reqwest::Response, max_bytes: usize) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response.chunk().await.map_err(|e| format!("Failed to read response: {}", e))? {
+        bytes.extend_from_slice(&chunk);
+        if bytes.len() >= max_bytes {
+            bytes.truncate(max_bytes);
+            break;
+        }
+    }
+    Ok(bytes)
+}
+
+/// charset=... from a Content-Type value
+fn header_charset(content_type: &str) -> Option<&'static encoding_rs::Encoding> {
+    content_type.split(';')
+        .filter_map(|part| part.trim().strip_prefix("charset="))
+        .find_map(|label| encoding_rs::Encoding::for_label(label.trim_matches('"').as_bytes()))
+}
+
+/// <meta charset="..."> or the http-equiv Content-Type form near the top of the page
+fn sniff_charset(bytes: &[u8]) -> Option<&'static encoding_rs::Encoding> {
+    let head = String::from_utf8_lossy(&bytes[..bytes.len().min(CHARSET_SNIFF_BYTES)]).to_lowercase();
+    let start = head.find("charset=")? + "charset=".len();
+    let label: String = head[start..]
+        .trim_start_matches(['"', '\''])
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+        .collect();
+    encoding_rs::Encoding::for_label(label.as_bytes())
+}
+
+/// Metadata from a parsed page; media URLs are resolved to absolute http(s) URLs
+fn parse(html: &str, page_url: &url::Url) -> PageMeta {
+    let document = Html::parse_document(html);
+    let selector = |s: &str| Selector::parse(s).expect("static selector");
+
+    let base = document.select(&selector("base[href]"))
+        .next()
+        .and_then(|el| el.value().attr("href"))
+        .and_then(|href| page_url.join(href).ok())
+        .unwrap_or_else(|| page_url.clone());
+    let resolve = |value: &str| {
+        base.join(value.trim()).ok()
+            .filter(|u| u.scheme() == "http" || u.scheme() == "https")
+            .map(|u| u.to_string())
+    };
+
+    // First non-empty value per property; og:* and twitter:* come from
+    // property= or name= depending on the site
+    let mut tags: std::collections::HashMap<String, String> = std::collections::HashMap::new();
+    let mut refresh = None;
+    for el in document.select(&selector("meta")) {
+        let attrs = el.value();
+        let content = attrs.attr("content").map(str::trim).unwrap_or("");
+        if content.is_empty() {
+            continue;
+        }
+        if attrs.attr("http-equiv").is_some_and(|v| v.eq_ignore_ascii_case("refresh")) {
+            refresh = refresh_target(content);
+            continue;
+        }
+        if let Some(key) = attrs.attr("property").or_else(|| attrs.attr("name")) {
+            tags.entry(key.trim().to_lowercase()).or_insert_with(|| content.to_string());
+        }
+    }
+    let tag = |keys: &[&str]| keys.iter().find_map(|k| tags.get(*k).cloned());
+
+    let oembed = document.select(&selector("link[rel=alternate][href]"))
+        .find(|el| el.value().attr("type").is_some_and(|t| t.eq_ignore_ascii_case("application/json+oembed")))
+        .and_then(|el| el.value().attr("href"))
+        .and_then(|href| base.join(href.trim()).ok());
+    let dimension = |key: &str| tag(&[key]).and_then(|v| v.parse::<f64>().ok()).filter(|v| *v > 0.0);
+
+    let title_element = document.select(&selector("title"))
+        .next()
+        .map(|el| el.text().collect::<String>());
+
+    PageMeta {
+        title: tag(&["og:title", "twitter:title"]).or(title_element)
+            .and_then(|t| clean_text(&t, MAX_TITLE_CHARS)),
+        description: tag(&["og:description", "twitter:description", "description"])
+            .and_then(|d| clean_text(&d, MAX_DESCRIPTION_CHARS)),
+        image: tag(&["og:image:secure_url", "og:image", "og:image:url", "twitter:image", "twitter:image:src"])
+            .and_then(|u| resolve(&u)),
+        site_name: tag(&["og:site_name"]).and_then(|s| clean_text(&s, MAX_TITLE_CHARS)),
+        audio: tag(&["og:audio:secure_url", "og:audio", "og:audio:url"]).and_then(|u| resolve(&u)),
+        video: tag(&["og:video:secure_url", "og:video", "og:video:url"]).and_then(|u| resolve(&u)),
+        video_type: tag(&["og:video:type"]).map(|t| t.to_lowercase()),
+        video_size: dimension("og:video:width").zip(dimension("og:video:height")),
+        oembed,
+        refresh,
+    }
+}
+
+/// URL from a refresh value like `0; url=/next`
+fn refresh_target(content: &str) -> Option<String> {
+    let (_, rest) = content.split_once(';')?;
+    let rest = rest.trim();
+    let url = rest.get(..4)
+        .filter(|prefix| prefix.eq_ignore_ascii_case("url="))
+        .map(|_| &rest[4..])
+        .unwrap_or(rest);
+    let url = url.trim().trim_matches(['"', '\'']);
+    (!url.is_empty()).then(|| url.to_string())
+}
+
+/// Collapse whitespace and cut to max_chars; None if nothing is left
+pub fn clean_text(text: &str, max_chars: usize) -> Option<String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return None;
+    }
+    if text.chars().count() <= max_chars {
+        return Some(text);
+    }
+    let cut: String = text.chars().take(max_chars).collect();
+    Some(format!("{}…", cut.trim_end()))
+}
+
+/// Download a preview image into the media cache
+/// Returns the URL back, or None if it can't be fetched, is too large or isn't an image
+async fn proxy_image(url: String) -> Option<String> {
+    let parsed = url::Url::parse(&url).ok()?;
+    if !is_public_url(&parsed) {
+        return None;
+    }
+    let max_bytes = if network::data_saver() { DATA_SAVER_IMAGE_BYTES } else { MAX_IMAGE_BYTES };
+    let client = http_client().ok()?;
+    let path = match media_cache::fetch_capped_with(&client, &url, max_bytes).await {
+        Ok(path) => path,
+        Err(e) => {
+            tracing::debug!("Link preview image {} not cached: {}", url, e);
+            return None;
+        }
+    };
+    if !looks_like_image(&path) {
+        tracing::debug!("Link preview image {} is not an image", url);
+        let _ = std::fs::remove_file(&path);
+        return None;
+    }
+    Some(url)
+}
+
+/// Check the file signature against the formats Qt can show
+fn looks_like_image(path: &Path) -> bool {
+    use std::io::Read;
+    let mut head = [0u8; 16];
+    let Ok(read) = std::fs::File::open(path).and_then(|mut f| f.read(&mut head)) else {
+        return false;
+    };
+    let head = &head[..read];
+    head.starts_with(&[0xff, 0xd8, 0xff])                       // JPEG
+        || head.starts_with(b"\x89PNG\r\n\x1a\n")
+        || head.starts_with(b"GIF87a") || head.starts_with(b"GIF89a")
+        || (head.starts_with(b"RIFF") && head.get(8..12) == Some(&b"WEBP"[..]))
+        || head.get(4..8) == Some(&b"ftyp"[..])                     // AVIF/HEIF
+        || head.starts_with(b"BM")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn public(url: &str) -> bool {
+        is_public_url(&url::Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn rejects_private_ipv4() {
+        assert!(!public("http://127.0.0.1/"));
+        assert!(!public("http://10.1.2.3/"));
+        assert!(!public("http://192.168.1.1:8080/"));
+        assert!(!public("http://169.254.169.254/latest/meta-data"));
+        assert!(!public("http://100.64.0.1/"));
+        assert!(!public("http://0.0.0.0/"));
+        assert!(public("https://93.184.216.34/"));
+    }
+
+    #[test]
+    fn rejects_private_ipv6() {
+        assert!(!public("http://[::1]/"));
+        assert!(!public("http://[::]/"));
+        assert!(!public("http://[fd00::1]/"));
+        assert!(!public("http://[fe80::1]/"));
+        assert!(public("https://[2606:4700::1111]/"));
+    }
+
+    #[test]
+    fn rejects_ipv4_mapped_private_addresses() {
+        assert!(!public("http://[::ffff:127.0.0.1]/"));
+        assert!(!public("http://[::ffff:10.0.0.1]/"));
+        assert!(!public("http://[::ffff:a9fe:a9fe]/"));
+        assert!(public("http://[::ffff:93.184.216.34]/"));
+    }
+
+    #[test]
+    fn rejects_local_names_and_other_schemes() {
+        assert!(!public("http://localhost/"));
+        assert!(!public("http://printer.local/"));
+        assert!(!public("http://api.localhost./"));
+        assert!(!public("file:///etc/passwd"));
+        assert!(!public("ftp://example.com/"));
+        assert!(public("https://example.com/"));
+    }
+
+    #[tokio::test]
+    async fn resolver_refuses_loopback_names() {
+        use reqwest::dns::Resolve;
+        let name: reqwest::dns::Name = "localhost".parse().unwrap();
+        assert!(PublicResolver.resolve(name).await.is_err());
+    }
+}
@@ -0,0 +1,297 @@
+//! Bounded in-memory cache - least recently used eviction with optional expiry
+//!
+//! The view caches used to be plain HashMaps that grew for the whole session.
+//! `LruCache` holds at most `capacity` entries: inserting into a full cache
+//! drops the entry that was read or written longest ago, and entries older
+//! than the TTL are treated as missing and dropped when looked up or swept.
+//!
+//! The cache locks internally, so it can sit in a `lazy_static` and be shared
+//! between the Qt thread and background fetches. Lookups clone the value.
+
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+struct Entry<V> {
+    value: V,
+    inserted: Instant,
+    /// Position in `Inner::order`
+    used: u64,
+}
+
+struct Inner<K, V> {
+    entries: HashMap<K, Entry<V>>,
+    /// Use counter -> key, oldest first
+    order: BTreeMap<u64, K>,
+    counter: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> Inner<K, V> {
+    fn next_use(&mut self) -> u64 {
+        self.counter += 1;
+        self.counter
+    }
+
+    fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let entry = self.entries.remove(key)?;
+        self.order.remove(&entry.used);
+        Some(entry.value)
+    }
+
+    /// Store a value, first evicting least recently used entries down to below `capacity`
+    fn insert(&mut self, key: K, value: V, capacity: usize) {
+        self.remove(&key);
+
+        while self.entries.len() >= capacity {
+            let Some((_, oldest)) = self.order.pop_first() else { break };
+            self.entries.remove(&oldest);
+        }
+
+        let used = self.next_use();
+        self.order.insert(used, key.clone());
+        self.entries.insert(key, Entry { value, inserted: Instant::now(), used });
+    }
+}
+
+/// Thread-safe LRU cache with a fixed capacity and an optional TTL
+pub struct LruCache<K, V> {
+    inner: Mutex<Inner<K, V>>,
+    capacity: usize,
+    ttl: Option<Duration>,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> LruCache<K, V> {
+    /// Cache holding at most `capacity` entries, each valid for `ttl` if given
+    pub fn new(capacity: usize, ttl: Option<Duration>) -> Self {
+        Self {
+            inner: Mutex::new(Inner {
+                entries: HashMap::new(),
+                order: BTreeMap::new(),
+                counter: 0,
+            }),
+            capacity: capacity.max(1),
+            ttl,
+        }
+    }
+
+    fn expired(&self, entry: &Entry<V>) -> bool {
+        self.ttl.is_some_and(|ttl| entry.inserted.elapsed() >= ttl)
+    }
+
+    /// The value for `key`, marking it as recently used
+    pub fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let mut guard = self.inner.lock().unwrap();
+        let inner = &mut *guard;
+        let expired = self.expired(inner.entries.get(key)?);
+        if expired {
+            inner.remove(key);
+            return None;
+        }
+
+        let used = inner.next_use();
+        let entry = inner.entries.get_mut(key)?;
+        let old = std::mem::replace(&mut entry.used, used);
+        let value = entry.value.clone();
+        if let Some(k) = inner.order.remove(&old) {
+            inner.order.insert(used, k);
+        }
+        Some(value)
+    }
+
+    /// Whether `key` has a live entry (does not count as a use)
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let inner = self.inner.lock().unwrap();
+        inner.entries.get(key).is_some_and(|entry| !self.expired(entry))
+    }
+
+    /// Store a value, evicting the least recently used entry if full
+    pub fn insert(&self, key: K, value: V) {
+        self.inner.lock().unwrap().insert(key, value, self.capacity);
+    }
+
+    /// Replace the value for `key` with `update(current)` and return it
+    /// The entry's age restarts, as with `insert`. The lock is held
+    /// throughout, so concurrent updates don't overwrite each other
+    pub fn update(&self, key: K, update: impl FnOnce(Option<V>) -> V) -> V {
+        let mut inner = self.inner.lock().unwrap();
+        let current = inner.entries.get(&key)
+            .filter(|entry| !self.expired(entry))
+            .map(|entry| entry.value.clone());
+        let value = update(current);
+        inner.insert(key, value.clone(), self.capacity);
+        value
+    }
+
+    /// Change the value for `key` in place and return it, if it has a live
+    /// entry; a missing key stays missing. The entry keeps its age
+    pub fn update_existing<Q>(&self, key: &Q, update: impl FnOnce(&mut V)) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let mut guard = self.inner.lock().unwrap();
+        let inner = &mut *guard;
+        let expired = self.expired(inner.entries.get(key)?);
+        if expired {
+            inner.remove(key);
+            return None;
+        }
+
+        let used = inner.next_use();
+        let entry = inner.entries.get_mut(key)?;
+        let old = std::mem::replace(&mut entry.used, used);
+        update(&mut entry.value);
+        let value = entry.value.clone();
+        if let Some(k) = inner.order.remove(&old) {
+            inner.order.insert(used, k);
+        }
+        Some(value)
+    }
+
+    /// Drop one entry
+    pub fn remove<Q>(&self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.inner.lock().unwrap().remove(key)
+    }
+
+    /// Drop every entry
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.entries.clear();
+        inner.order.clear();
+    }
+
+    /// Drop expired entries now rather than on their next lookup
+    pub fn sweep(&self) -> usize {
+        if self.ttl.is_none() {
+            return 0;
+        }
+        let mut inner = self.inner.lock().unwrap();
+        let expired: Vec<K> = inner.entries.iter()
+            .filter(|(_, entry)| self.expired(entry))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &expired {
+            inner.remove(key);
+        }
+        expired.len()
+    }
+
+    /// Entries currently held (including expired ones not yet swept)
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Most entries the cache holds
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// (entries, approximate bytes) with each entry sized by `weigh`
+    pub fn usage(&self, weigh: impl Fn(&K, &V) -> usize) -> (usize, u64) {
+        let inner = self.inner.lock().unwrap();
+        let bytes: usize = inner.entries.iter().map(|(key, entry)| weigh(key, &entry.value)).sum();
+        (inner.entries.len(), bytes as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = LruCache::new(2, None);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        // Reading "a" makes "b" the oldest
+        assert_eq!(cache.get("a"), Some(1));
+        cache.insert("c", 3);
+        assert_eq!(cache.get("b"), None);
+        assert_eq!(cache.get("a"), Some(1));
+        assert_eq!(cache.get("c"), Some(3));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn reinserting_refreshes_position() {
+        let cache = LruCache::new(2, None);
+        cache.insert("a", 1);
+        cache.insert("b", 2);
+        cache.insert("a", 10);
+        cache.insert("c", 3);
+        assert_eq!(cache.get("a"), Some(10));
+        assert_eq!(cache.get("b"), None);
+    }
+
+    #[test]
+    fn expired_entries_are_missing() {
+        let cache = LruCache::new(2, Some(Duration::ZERO));
+        cache.insert("a", 1);
+        assert!(!cache.contains("a"));
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.update("a", |current| current.unwrap_or(0) + 1), 1);
+    }
+
+    #[test]
+    fn update_reads_current_value_and_counts_as_use() {
+        let cache = LruCache::new(2, None);
+        assert_eq!(cache.update("a", |current| current.unwrap_or(0) + 1), 1);
+        assert_eq!(cache.update("a", |current| current.unwrap_or(0) + 1), 2);
+        cache.insert("b", 1);
+        cache.update("a", |current| current.unwrap_or(0));
+        cache.insert("c", 1);
+        assert_eq!(cache.get("a"), Some(2));
+        assert_eq!(cache.get("b"), None);
+    }
+
+    #[test]
+    fn update_existing_leaves_missing_keys_alone() {
+        let cache = LruCache::new(2, None);
+        assert_eq!(cache.update_existing("a", |v: &mut i32| *v += 1), None);
+        assert!(!cache.contains("a"));
+        cache.insert("a", 1);
+        assert_eq!(cache.update_existing("a", |v| *v += 1), Some(2));
+    }
+
+    #[test]
+    fn concurrent_updates_are_not_lost() {
+        let cache = Arc::new(LruCache::new(4, None));
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let cache = Arc::clone(&cache);
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        cache.update("count", |current| current.unwrap_or(0) + 1);
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert_eq!(cache.get("count"), Some(8000));
+    }
+}
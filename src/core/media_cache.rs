@@ -0,0 +1,262 @@
+//! Media cache - avatars, banners and note media kept on disk
+//!
+//! Files are named after the SHA-256 of their URL. Every read touches the
+//! file's modification time, so evicting the oldest files first drops the
+//! least recently used ones. After each download the cache is trimmed back
+//! under the size cap from the config.
+
+use std::collections::HashSet;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+use reqwest::header::HeaderValue;
+use sha2::{Digest, Sha256};
+
+use super::cache_stats;
+use super::config::Config;
+use super::limits;
+
+/// Default size cap for the cache
+pub const DEFAULT_MAX_MB: u64 = 512;
+
+/// Larger downloads are not cached (long videos would evict everything else)
+const MAX_FILE_BYTES: u64 = 50 * 1024 * 1024;
+
+lazy_static::lazy_static! {
+    // URLs being downloaded right now
+    static ref IN_FLIGHT: Mutex<HashSet<String>> = Mutex::new(HashSet::new());
+}
+
+/// Where a URL is (or would be) stored
+fn path_for(url: &str) -> PathBuf {
+    let hash = hex::encode(Sha256::digest(url.as_bytes()));
+    // Keep a short extension so Qt can tell the format from the name
+    let ext = url::Url::parse(url).ok()
+        .and_then(|u| Path::new(u.path()).extension().map(|e| e.to_string_lossy().to_lowercase()))
+        .filter(|e| !e.is_empty() && e.len() <= 5 && e.chars().all(|c| c.is_ascii_alphanumeric()));
+    let name = match ext {
+        Some(ext) => format!("{}.{}", hash, ext),
+        None => hash,
+    };
+    cache_stats::media_cache_dir().join(name)
+}
+
+/// Mark a file as just used
+fn touch(path: &Path) {
+    let _ = std::fs::File::options()
+        .write(true)
+        .open(path)
+        .and_then(|f| f.set_modified(SystemTime::now()));
+}
+
+/// The cached file for a URL, if present
+pub fn cached(url: &str) -> Option<PathBuf> {
+    let path = path_for(url);
+    let found = path.is_file();
+    cache_stats::record(cache_stats::MEDIA, found);
+    if found {
+        touch(&path);
+        Some(path)
+    } else {
+        None
+    }
+}
+
+/// file:// URL for a cached file
+pub fn file_url(path: &Path) -> String {
+    url::Url::from_file_path(path)
+        .map(|u| u.to_string())
+        .unwrap_or_else(|_| format!("file://{}", path.display()))
+}
+
+/// Error returned when the server wants an authorization header (Blossom BUD-01)
+pub const UNAUTHORIZED: &str = "Download failed: authorization required";
+
+/// Download a URL into the cache (or return the cached file)
+pub async fn fetch(url: &str) -> Result<PathBuf, String> {
+    fetch_authorized(url, None).await
+}
+
+/// Like `fetch`, refusing files over `max_bytes` instead of MAX_FILE_BYTES
+pub async fn fetch_capped(url: &str, max_bytes: u64) -> Result<PathBuf, String> {
+    fetch_with(url, None, max_bytes.min(MAX_FILE_BYTES)).await
+}
+
+/// Like `fetch_capped`, downloading with `client` (e.g. one that refuses
+/// private addresses and redirects to them)
+pub async fn fetch_capped_with(client: &reqwest::Client, url: &str, max_bytes: u64) -> Result<PathBuf, String> {
+    fetch_with_client(client, url, None, max_bytes.min(MAX_FILE_BYTES)).await
+}
+
+/// Like `fetch`, sending `auth` as the Authorization header
+pub async fn fetch_authorized(url: &str, auth: Option<HeaderValue>) -> Result<PathBuf, String> {
+    fetch_with(url, auth, MAX_FILE_BYTES).await
+}
+
+async fn fetch_with(url: &str, auth: Option<HeaderValue>, max_bytes: u64) -> Result<PathBuf, String> {
+    fetch_with_client(&super::network::http(), url, auth, max_bytes).await
+}
+
+/// A download of the same URL already in progress is waited for instead of repeated
+async fn fetch_with_client(client: &reqwest::Client, url: &str, auth: Option<HeaderValue>, max_bytes: u64) -> Result<PathBuf, String> {
+    if let Some(path) = cached(url) {
+        return Ok(path);
+    }
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(format!("Not a web URL: {}", url));
+    }
+    if !IN_FLIGHT.lock().unwrap().insert(url.to_string()) {
+        return wait_for(url).await;
+    }
+    let result = download(client, url, auth, max_bytes).await;
+    IN_FLIGHT.lock().unwrap().remove(url);
+
+    if result.is_ok() {
+        evict(max_bytes());
+    }
+    result
+}
+
+/// Wait for another task's download of `url` to finish
+async fn wait_for(url: &str) -> Result<PathBuf, String> {
+    for _ in 0..600 {
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        if !IN_FLIGHT.lock().unwrap().contains(url) {
+            break;
+        }
+    }
+    let path = path_for(url);
+    if path.is_file() {
+        Ok(path)
+    } else {
+        Err("Download failed".to_string())
+    }
+}
+
+async fn download(client: &reqwest::Client, url: &str, auth: Option<HeaderValue>, max_bytes: u64) -> Result<PathBuf, String> {
+    let _permit = limits::host_permit(url).await;
+    let mut request = client
+        .get(url)
+        .timeout(std::time::Duration::from_secs(30));
+    if let Some(auth) = auth {
+        request = request.header(reqwest::header::AUTHORIZATION, auth);
+    }
+    let mut response = request
+        .send()
+        .await
+        .map_err(|e| format!("Download failed: {}", e))?;
+
+    if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(UNAUTHORIZED.to_string());
+    }
+    if !response.status().is_success() {
+        return Err(format!("Download failed: HTTP {}", response.status()));
+    }
+    if response.content_length().is_some_and(|len| len > max_bytes) {
+        return Err("File too large to cache".to_string());
+    }
+
+    let path = path_for(url);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create media cache: {}", e))?;
+    }
+
+    // Content-Length can be missing or wrong, so the cap is enforced while reading
+    let tmp_path = path.with_extension("part");
+    let written = async {
+        let mut file = std::fs::File::create(&tmp_path)
+            .map_err(|e| format!("Failed to store media: {}", e))?;
+        let mut total: u64 = 0;
+        while let Some(chunk) = response.chunk().await.map_err(|e| format!("Download failed: {}", e))? {
+            total += chunk.len() as u64;
+            if total > max_bytes {
+                return Err("File too large to cache".to_string());
+            }
+            file.write_all(&chunk).map_err(|e| format!("Failed to store media: {}", e))?;
+        }
+        Ok(())
+    }.await;
+    if let Err(e) = written {
+        let _ = std::fs::remove_file(&tmp_path);
+        return Err(e);
+    }
+    std::fs::rename(&tmp_path, &path)
+        .map_err(|e| format!("Failed to store media: {}", e))?;
+    Ok(path)
+}
+
+/// Download URLs into the cache in the background (not in data saver mode)
+pub fn prefetch(urls: Vec<String>) {
+    if super::network::data_saver() {
+        return;
+    }
+    let urls: Vec<String> = urls.into_iter()
+        .filter(|u| !u.is_empty() && !path_for(u).is_file())
+        .collect();
+    if urls.is_empty() {
+        return;
+    }
+    limits::spawn_limited(move || {
+        let Ok(runtime) = tokio::runtime::Builder::new_current_thread().enable_all().build() else {
+            return;
+        };
+        runtime.block_on(async {
+            for url in urls {
+                if let Err(e) = fetch(&url).await {
+                    tracing::debug!("Media prefetch of {} failed: {}", url, e);
+                }
+            }
+        });
+    });
+}
+
+/// Size cap from the config, in bytes
+pub fn max_bytes() -> u64 {
+    Config::load().media_cache_mb.max(1) * 1024 * 1024
+}
+
+/// Delete least recently used files until the cache is under `max_bytes`
+/// Returns (files removed, bytes freed)
+pub fn evict(max_bytes: u64) -> (usize, u64) {
+    let Ok(entries) = std::fs::read_dir(cache_stats::media_cache_dir()) else {
+        return (0, 0);
+    };
+    let mut files: Vec<(PathBuf, u64, SystemTime)> = entries.flatten()
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            meta.is_file().then(|| (entry.path(), meta.len(), meta.modified().unwrap_or(SystemTime::UNIX_EPOCH)))
+        })
+        .collect();
+
+    let mut total: u64 = files.iter().map(|(_, len, _)| len).sum();
+    if total <= max_bytes {
+        return (0, 0);
+    }
+
+    files.sort_by_key(|(_, _, modified)| *modified);
+    let (mut removed, mut freed) = (0, 0);
+    for (path, len, _) in files {
+        if total <= max_bytes {
+            break;
+        }
+        if std::fs::remove_file(&path).is_ok() {
+            total -= len;
+            removed += 1;
+            freed += len;
+        }
+    }
+    tracing::info!("Media cache: evicted {} files ({} bytes)", removed, freed);
+    (removed, freed)
+}
+
+/// Delete every cached file
+pub fn clear() -> Result<(), String> {
+    let dir = cache_stats::media_cache_dir();
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir)
+            .map_err(|e| format!("Failed to clear media cache: {}", e))?;
+    }
+    Ok(())
+}
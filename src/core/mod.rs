@@ -1,6 +1,24 @@
 //! Core business logic modules
 //! These can be largely reused from the iced version
 
+pub mod cache_stats;
 pub mod config;
 pub mod credentials;
+pub mod deep_link;
+pub mod desktop_notify;
+pub mod drafts;
 pub mod error;
+pub mod fiat;
+pub mod instance;
+pub mod key_backup;
+pub mod limits;
+pub mod link_preview;
+pub mod lru;
+pub mod media_cache;
+pub mod network;
+pub mod oembed;
+pub mod operations;
+pub mod refresh;
+pub mod scheduler;
+pub mod tray;
+pub mod updates;
@@ -0,0 +1,244 @@
+//! Network settings - the proxy every HTTP client and relay connection uses,
+//! relay timeouts and the reconnect backoff policy
+//!
+//! The proxy must be SOCKS5 (e.g. Tor at `socks5h://127.0.0.1:9050`), as
+//! nostr-sdk only tunnels relay websockets through SOCKS5. With `onion_only`
+//! set, only `.onion` hosts and relays go through the proxy.
+//!
+//! A configured proxy that can't be used (invalid, unresolvable, or an HTTP
+//! proxy left in an older config) fails closed: connections are pointed at
+//! an address nothing listens on rather than made directly.
+//!
+//! Relays are added without nostr-sdk's own reconnect loop: a dropped relay
+//! is retried after `reconnect_delay`, which doubles with every failure up
+//! to the configured maximum.
+//!
+//! Settings are read from the config once and swapped by `apply` and
+//! `apply_timeouts`; clients built afterwards pick them up, existing relay
+//! connections keep their proxy.
+//!
+//! Data saver mode (for metered connections) is kept here too; notes carry it
+//! to QML as `autoLoadMedia` so their media and previews wait for a tap.
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+use std::time::Duration;
+use nostr_sdk::prelude::*;
+
+use super::config::Config;
+
+/// Proxy configuration
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProxySettings {
+    /// Proxy URL (socks5:// or socks5h://); None for direct connections
+    pub url: Option<String>,
+    /// Only .onion hosts and relays use the proxy
+    pub onion_only: bool,
+}
+
+impl ProxySettings {
+    fn from_config(config: &Config) -> Self {
+        Self {
+            url: config.proxy_url.clone().filter(|u| !u.trim().is_empty()),
+            onion_only: config.proxy_onion_only,
+        }
+    }
+
+    /// SOCKS5 address for relay connections, if the proxy is SOCKS5
+    fn socks_addr(&self) -> Option<SocketAddr> {
+        let url = url::Url::parse(self.url.as_deref()?).ok()?;
+        if !url.scheme().starts_with("socks5") {
+            return None;
+        }
+        let host = url.host_str()?;
+        let port = url.port().unwrap_or(1080);
+        (host, port).to_socket_addrs().ok()?.next()
+    }
+}
+
+/// Nothing listens on port 0, so connections through this fail
+const BLOCKED_PROXY_ADDR: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0);
+
+/// Default wait for relay queries
+pub const DEFAULT_FETCH_TIMEOUT_SECS: u64 = 10;
+
+/// Default wait for a relay connection
+pub const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Default cap on the wait between reconnect attempts
+pub const DEFAULT_RECONNECT_MAX_SECS: u64 = 300;
+
+/// Wait before the first reconnect attempt
+const RECONNECT_BASE: Duration = Duration::from_secs(5);
+
+/// Relay timeouts and reconnect backoff
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timeouts {
+    /// Relay queries (REQ until EOSE)
+    pub fetch: Duration,
+    /// Establishing a relay connection
+    pub connect: Duration,
+    /// Longest wait between reconnect attempts
+    pub reconnect_max: Duration,
+}
+
+impl Timeouts {
+    fn from_config(config: &Config) -> Self {
+        Self::new(config.fetch_timeout_secs, config.connect_timeout_secs, config.reconnect_max_secs)
+    }
+
+    /// Timeouts from seconds, clamped to sane ranges
+    pub fn new(fetch_secs: u64, connect_secs: u64, reconnect_max_secs: u64) -> Self {
+        Self {
+            fetch: Duration::from_secs(fetch_secs.clamp(2, 120)),
+            connect: Duration::from_secs(connect_secs.clamp(2, 120)),
+            reconnect_max: Duration::from_secs(reconnect_max_secs.clamp(RECONNECT_BASE.as_secs(), 3600)),
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref SETTINGS: RwLock<ProxySettings> = RwLock::new(ProxySettings::from_config(&Config::load()));
+    static ref TIMEOUTS: RwLock<Timeouts> = RwLock::new(Timeouts::from_config(&Config::load()));
+    static ref DATA_SAVER: AtomicBool = AtomicBool::new(Config::load().data_saver);
+}
+
+/// Whether media, previews and embeds wait for a tap
+pub fn data_saver() -> bool {
+    DATA_SAVER.load(Ordering::Relaxed)
+}
+
+/// Turn data saver mode on or off for notes loaded from now on
+pub fn set_data_saver(enabled: bool) {
+    tracing::info!("Data saver: {}", if enabled { "on" } else { "off" });
+    DATA_SAVER.store(enabled, Ordering::Relaxed);
+}
+
+/// Current timeouts
+pub fn timeouts() -> Timeouts {
+    *TIMEOUTS.read().unwrap()
+}
+
+/// Use new timeouts (from settings) for all future work
+pub fn apply_timeouts(timeouts: Timeouts) {
+    tracing::info!(
+        "Relay timeouts: fetch {}s, connect {}s, reconnect backoff up to {}s",
+        timeouts.fetch.as_secs(), timeouts.connect.as_secs(), timeouts.reconnect_max.as_secs()
+    );
+    *TIMEOUTS.write().unwrap() = timeouts;
+}
+
+/// How long to wait before reconnect attempt number `failures` + 1
+pub fn reconnect_delay(failures: u32) -> Duration {
+    let factor = 2u32.saturating_pow(failures.saturating_sub(1).min(16));
+    (RECONNECT_BASE * factor).min(timeouts().reconnect_max)
+}
+
+/// Check a proxy URL from settings; an empty string means no proxy
+pub fn validate_proxy_url(url: &str) -> Result<(), String> {
+    let url = url.trim();
+    if url.is_empty() {
+        return Ok(());
+    }
+    let parsed = url::Url::parse(url).map_err(|e| format!("Invalid proxy URL: {}", e))?;
+    match parsed.scheme() {
+        "socks5" | "socks5h" => {}
+        "http" | "https" => return Err("Relays can't use an HTTP proxy; use a SOCKS5 proxy (socks5h://)".to_string()),
+        scheme => return Err(format!("Unsupported proxy type: {} (use socks5h://)", scheme)),
+    }
+    if parsed.host_str().is_none() {
+        return Err("Proxy URL has no host".to_string());
+    }
+    reqwest::Proxy::all(url).map_err(|e| format!("Invalid proxy URL: {}", e))?;
+    Ok(())
+}
+
+/// Current proxy settings
+pub fn settings() -> ProxySettings {
+    SETTINGS.read().unwrap().clone()
+}
+
+/// Use new proxy settings (from settings) for all future connections
+pub fn apply(settings: ProxySettings) {
+    match &settings.url {
+        Some(url) => tracing::info!(
+            "Proxy: {} for {}",
+            url,
+            if settings.onion_only { ".onion hosts" } else { "all traffic" }
+        ),
+        None => tracing::info!("Proxy: off"),
+    }
+    *SETTINGS.write().unwrap() = settings;
+}
+
+/// reqwest client builder with the proxy applied
+pub fn http_client() -> reqwest::ClientBuilder {
+    let settings = settings();
+    let builder = reqwest::Client::builder();
+    let Some(url) = settings.url else {
+        return builder;
+    };
+
+    let proxy = match reqwest::Proxy::all(&url) {
+        Ok(_) if settings.onion_only => {
+            let proxy_url = url.clone();
+            Ok(reqwest::Proxy::custom(move |target| {
+                target.host_str()
+                    .filter(|host| host.ends_with(".onion"))
+                    .and_then(|_| url::Url::parse(&proxy_url).ok())
+            }))
+        }
+        result => result,
+    };
+
+    match proxy {
+        Ok(proxy) => builder.proxy(proxy),
+        Err(e) => {
+            tracing::error!("Invalid proxy {}, blocking web requests: {}", url, e);
+            let blocked = format!("socks5h://{}", BLOCKED_PROXY_ADDR);
+            builder.proxy(reqwest::Proxy::all(blocked).expect("valid proxy URL"))
+        }
+    }
+}
+
+/// reqwest client with the proxy applied and no other settings
+pub fn http() -> reqwest::Client {
+    http_client().build().unwrap_or_default()
+}
+
+/// nostr-sdk options routing relay connections through a SOCKS5 proxy
+pub fn client_options() -> ClientOptions {
+    let settings = settings();
+    let opts = ClientOptions::new();
+    if settings.url.is_none() {
+        return opts;
+    }
+    let addr = settings.socks_addr().unwrap_or_else(|| {
+        tracing::error!("Proxy {:?} can't carry relay connections; blocking them", settings.url);
+        BLOCKED_PROXY_ADDR
+    });
+    let target = if settings.onion_only { ConnectionTarget::Onion } else { ConnectionTarget::All };
+    opts.connection(Connection::new().proxy(addr).target(target))
+}
+
+/// Options for one relay: the proxy as in `client_options`, and no built-in
+/// reconnect (see `reconnect_delay`)
+pub fn relay_options(url: &RelayUrl) -> RelayOptions {
+    let opts = RelayOptions::new().reconnect(false);
+    let settings = settings();
+    if settings.url.is_none() || (settings.onion_only && !url.is_onion()) {
+        return opts;
+    }
+    let addr = settings.socks_addr().unwrap_or(BLOCKED_PROXY_ADDR);
+    opts.connection_mode(ConnectionMode::proxy(addr))
+}
+
+/// nostr-sdk client using the proxy settings, signing with `keys` if given
+pub fn nostr_client(keys: Option<Keys>) -> Client {
+    let builder = Client::builder().opts(client_options());
+    match keys {
+        Some(keys) => builder.signer(keys).build(),
+        None => builder.build(),
+    }
+}
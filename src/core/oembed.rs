@@ -0,0 +1,288 @@
+//! Rich embeds for link previews - per-site handlers and oEmbed
+//!
+//! Known sites (YouTube, Rumble, X, Spotify, Vimeo, SoundCloud, TikTok) are
+//! recognised from the URL alone, which gives the provider, the media id and
+//! a default aspect ratio without a request. Their oEmbed endpoint then adds
+//! the title, author and thumbnail. Other pages can advertise an endpoint with
+//! `<link rel="alternate" type="application/json+oembed">`, which the link
+//! preview follows.
+//!
+//! QML renders an embed as a player card (thumbnail, play button, author)
+//! instead of a generic link card; `streamUrl` is set only for media files Qt
+//! can play directly.
+
+use serde::Deserialize;
+
+use super::link_preview;
+
+/// Bytes of an oEmbed response read
+const MAX_OEMBED_BYTES: usize = 256 * 1024;
+
+const MAX_TEXT_CHARS: usize = 500;
+
+/// What a handler recognised in a URL, filled in further by oEmbed
+#[derive(Debug, Clone, Default)]
+pub struct Embed {
+    /// Display name of the site ("YouTube", "X", ...)
+    pub provider: String,
+    /// "video", "audio" or "rich" (posts)
+    pub kind: &'static str,
+    /// Media or post id on the provider
+    pub id: Option<String>,
+    /// Provider's embeddable player page
+    pub embed_url: Option<String>,
+    /// Width / height of the player; 0 when the embed has no fixed shape
+    pub aspect_ratio: f64,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub author_url: Option<String>,
+    pub thumbnail: Option<String>,
+    /// Direct media file Qt can play inline
+    pub stream_url: Option<String>,
+    /// Post text for rich embeds
+    pub text: Option<String>,
+    /// oEmbed endpoint to ask for the rest
+    endpoint: Option<&'static str>,
+}
+
+impl Embed {
+    /// Fields as QML reads them (previewData.embed)
+    pub fn to_value(&self) -> serde_json::Value {
+        serde_json::json!({
+            "provider": self.provider,
+            "type": self.kind,
+            "id": self.id,
+            "embedUrl": self.embed_url,
+            "aspectRatio": self.aspect_ratio,
+            "author": self.author,
+            "authorUrl": self.author_url,
+            "streamUrl": self.stream_url,
+        })
+    }
+
+    /// Whether the URL alone gives enough to show a player card
+    pub fn is_complete(&self) -> bool {
+        self.id.is_some() && self.thumbnail.is_some()
+    }
+}
+
+/// oEmbed response fields used
+#[derive(Debug, Default, Deserialize)]
+struct OEmbedResponse {
+    #[serde(rename = "type")]
+    kind: Option<String>,
+    title: Option<String>,
+    author_name: Option<String>,
+    author_url: Option<String>,
+    provider_name: Option<String>,
+    thumbnail_url: Option<String>,
+    width: Option<serde_json::Value>,
+    height: Option<serde_json::Value>,
+    html: Option<String>,
+}
+
+fn embed(provider: &str, kind: &'static str, id: String, endpoint: &'static str) -> Embed {
+    Embed {
+        provider: provider.to_string(),
+        kind,
+        id: Some(id),
+        endpoint: Some(endpoint),
+        ..Default::default()
+    }
+}
+
+fn host(url: &url::Url) -> String {
+    let host = url.host_str().unwrap_or("").to_lowercase();
+    ["www.", "m.", "mobile."].iter()
+        .find_map(|prefix| host.strip_prefix(prefix).map(str::to_string))
+        .unwrap_or(host)
+}
+
+fn segments(url: &url::Url) -> Vec<String> {
+    url.path_segments()
+        .map(|s| s.filter(|s| !s.is_empty()).map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+fn valid_id(id: &str, allowed: impl Fn(char) -> bool) -> bool {
+    !id.is_empty() && id.chars().all(allowed)
+}
+
+/// The per-site handler for a URL, if there is one
+pub fn match_url(url: &url::Url) -> Option<Embed> {
+    let host = host(url);
+    let path = segments(url);
+    let seg = |i: usize| path.get(i).map(String::as_str);
+
+    match host.as_str() {
+        "youtube.com" | "music.youtube.com" | "youtu.be" | "youtube-nocookie.com" => {
+            let (id, short) = if host == "youtu.be" {
+                (seg(0).map(str::to_string), false)
+            } else {
+                match seg(0) {
+                    Some("watch") => (url.query_pairs().find(|(k, _)| k == "v").map(|(_, v)| v.into_owned()), false),
+                    Some("shorts") => (seg(1).map(str::to_string), true),
+                    Some("embed") | Some("live") | Some("v") => (seg(1).map(str::to_string), false),
+                    _ => (None, false),
+                }
+            };
+            let id = id.filter(|id| id.len() == 11 && valid_id(id, |c| c.is_ascii_alphanumeric() || c == '-' || c == '_'))?;
+            let mut e = embed("YouTube", "video", id.clone(), "https://www.youtube.com/oembed");
+            e.aspect_ratio = if short { 9.0 / 16.0 } else { 16.0 / 9.0 };
+            e.embed_url = Some(format!("https://www.youtube-nocookie.com/embed/{}", id));
+            e.thumbnail = Some(format!("https://i.ytimg.com/vi/{}/hqdefault.jpg", id));
+            Some(e)
+        }
+        "rumble.com" => {
+            // rumble.com/v4abcde-some-title.html or rumble.com/embed/v4abcde/
+            let id = match seg(0) {
+                Some("embed") => seg(1).map(str::to_string),
+                Some(first) if first.starts_with('v') => first.split('-').next().map(str::to_string),
+                _ => None,
+            }.filter(|id| id.len() > 1 && valid_id(id, |c| c.is_ascii_alphanumeric()))?;
+            let mut e = embed("Rumble", "video", id.clone(), "https://rumble.com/api/Media/oembed.json");
+            e.aspect_ratio = 16.0 / 9.0;
+            e.embed_url = Some(format!("https://rumble.com/embed/{}/", id));
+            Some(e)
+        }
+        "twitter.com" | "x.com" => {
+            // /<user>/status/<id>
+            let id = (seg(1) == Some("status")).then(|| seg(2)).flatten()
+                .filter(|id| valid_id(id, |c| c.is_ascii_digit()))?;
+            Some(embed("X", "rich", id.to_string(), "https://publish.twitter.com/oembed"))
+        }
+        "open.spotify.com" => {
+            // Optional locale prefix: /intl-de/track/<id>
+            let offset = usize::from(seg(0).is_some_and(|s| s.starts_with("intl-")));
+            let kind = seg(offset).filter(|k| ["track", "album", "playlist", "episode", "show", "artist"].contains(k))?;
+            let id = seg(offset + 1).filter(|id| valid_id(id, |c| c.is_ascii_alphanumeric()))?;
+            let mut e = embed("Spotify", "audio", format!("{}:{}", kind, id), "https://open.spotify.com/oembed");
+            e.embed_url = Some(format!("https://open.spotify.com/embed/{}/{}", kind, id));
+            Some(e)
+        }
+        "vimeo.com" | "player.vimeo.com" => {
+            let id = path.iter().find(|s| valid_id(s, |c| c.is_ascii_digit()))?.clone();
+            let mut e = embed("Vimeo", "video", id.clone(), "https://vimeo.com/api/oembed.json");
+            e.aspect_ratio = 16.0 / 9.0;
+            e.embed_url = Some(format!("https://player.vimeo.com/video/{}", id));
+            Some(e)
+        }
+        "soundcloud.com" => {
+            // /<user>/<track> (sets too); profile pages alone aren't embeds
+            let id = (path.len() >= 2).then(|| path.join("/"))?;
+            Some(embed("SoundCloud", "audio", id, "https://soundcloud.com/oembed"))
+        }
+        "tiktok.com" => {
+            // /@user/video/<id>
+            let id = (seg(1) == Some("video")).then(|| seg(2)).flatten()
+                .filter(|id| valid_id(id, |c| c.is_ascii_digit()))?;
+            let mut e = embed("TikTok", "video", id.to_string(), "https://www.tiktok.com/oembed");
+            e.aspect_ratio = 9.0 / 16.0;
+            e.embed_url = Some(format!("https://www.tiktok.com/embed/v2/{}", id));
+            Some(e)
+        }
+        _ => None,
+    }
+}
+
+/// Ask the handler's oEmbed endpoint for title, author and thumbnail
+pub async fn enrich(client: &reqwest::Client, embed: &mut Embed, url: &str) -> Result<(), String> {
+    let Some(endpoint) = embed.endpoint else {
+        return Ok(());
+    };
+    let mut request_url = url::Url::parse(endpoint).map_err(|e| e.to_string())?;
+    request_url.query_pairs_mut()
+        .append_pair("url", url)
+        .append_pair("format", "json");
+    if embed.provider == "X" {
+        request_url.query_pairs_mut()
+            .append_pair("omit_script", "true")
+            .append_pair("dnt", "true");
+    }
+    let response = request(client, &request_url).await?;
+    apply(embed, response);
+    Ok(())
+}
+
+/// Embed from an endpoint a page advertised with <link rel="alternate">
+pub async fn discover(client: &reqwest::Client, endpoint: &url::Url) -> Result<Embed, String> {
+    if !link_preview::is_public_url(endpoint) {
+        return Err("oEmbed endpoint is not a public address".to_string());
+    }
+    let response = request(client, endpoint).await?;
+    let kind = match response.kind.as_deref() {
+        Some("video") => "video",
+        Some("rich") => "rich",
+        // "photo" and "link" add nothing over the OpenGraph card
+        _ => return Err("Not a video or rich embed".to_string()),
+    };
+    let mut embed = Embed {
+        provider: response.provider_name.clone().unwrap_or_default(),
+        kind,
+        ..Default::default()
+    };
+    apply(&mut embed, response);
+    Ok(embed)
+}
+
+async fn request(client: &reqwest::Client, url: &url::Url) -> Result<OEmbedResponse, String> {
+    let _permit = super::limits::host_permit(url.as_str()).await;
+    let response = client.get(url.clone())
+        .send()
+        .await
+        .map_err(|e| format!("oEmbed request failed: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("oEmbed HTTP error: {}", response.status()));
+    }
+    let bytes = link_preview::read_capped(response, MAX_OEMBED_BYTES).await?;
+    serde_json::from_slice(&bytes).map_err(|e| format!("Invalid oEmbed response: {}", e))
+}
+
+/// Copy an oEmbed response onto the embed, keeping what the URL already gave
+fn apply(embed: &mut Embed, response: OEmbedResponse) {
+    let number = |v: &Option<serde_json::Value>| match v {
+        Some(serde_json::Value::Number(n)) => n.as_f64(),
+        Some(serde_json::Value::String(s)) => s.parse::<f64>().ok(),
+        _ => None,
+    };
+    if embed.kind == "video" {
+        if let (Some(w), Some(h)) = (number(&response.width), number(&response.height)) {
+            if w > 0.0 && h > 0.0 {
+                embed.aspect_ratio = w / h;
+            }
+        }
+    }
+
+    let html = response.html.as_deref().map(scraper::Html::parse_fragment);
+    if let Some(html) = &html {
+        if embed.embed_url.is_none() {
+            let iframe = scraper::Selector::parse("iframe[src]").expect("static selector");
+            embed.embed_url = html.select(&iframe)
+                .next()
+                .and_then(|el| el.value().attr("src"))
+                .filter(|src| src.starts_with("https://"))
+                .map(str::to_string);
+        }
+        // Tweets and similar posts carry their text in the first paragraph
+        if embed.kind == "rich" {
+            let paragraph = scraper::Selector::parse("p").expect("static selector");
+            embed.text = html.select(&paragraph)
+                .next()
+                .map(|el| el.text().collect::<Vec<_>>().join(" "))
+                .and_then(|text| link_preview::clean_text(&text, MAX_TEXT_CHARS));
+        }
+    }
+
+    let non_empty = |s: Option<String>| s.map(|s| s.trim().to_string()).filter(|s| !s.is_empty());
+    embed.title = non_empty(response.title).or(embed.title.take());
+    embed.author = non_empty(response.author_name).or(embed.author.take());
+    embed.author_url = non_empty(response.author_url)
+        .filter(|u| u.starts_with("https://") || u.starts_with("http://"))
+        .or(embed.author_url.take());
+    embed.thumbnail = non_empty(response.thumbnail_url)
+        .filter(|u| u.starts_with("https://") || u.starts_with("http://"))
+        .or(embed.thumbnail.take());
+    if embed.provider.is_empty() {
+        embed.provider = non_empty(response.provider_name).unwrap_or_default();
+    }
+}
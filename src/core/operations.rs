@@ -0,0 +1,150 @@
+//! Async operation reporting - one started/succeeded/failed contract
+//!
+//! Invokables that publish to relays or wait on a signer report through the
+//! same three signals on their controller, keyed by an operation id of the
+//! form "<kind>:<target>" (e.g. "like:<note id>"). Running the same action on
+//! the same target again reuses the id, so QML can tie a retry button to the
+//! control that failed. Failed operations keep their arguments here so the
+//! controller can re-run them from `retry_operation`.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use serde::Serialize;
+
+/// Failed operations kept for retry; older ones are dropped first
+const MAX_FAILED: usize = 50;
+
+/// What an operation does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationKind {
+    Like,
+    React,
+    Repost,
+    Reply,
+    Post,
+    Zap,
+    Report,
+    FollowSet,
+    Interests,
+    Contacts,
+    SendMessage,
+}
+
+impl OperationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OperationKind::Like => "like",
+            OperationKind::React => "react",
+            OperationKind::Repost => "repost",
+            OperationKind::Reply => "reply",
+            OperationKind::Post => "post",
+            OperationKind::Zap => "zap",
+            OperationKind::Report => "report",
+            OperationKind::FollowSet => "follow_set",
+            OperationKind::Interests => "interests",
+            OperationKind::Contacts => "contacts",
+            OperationKind::SendMessage => "send_message",
+        }
+    }
+}
+
+/// One run of an async action
+#[derive(Debug, Clone)]
+pub struct Operation {
+    pub kind: OperationKind,
+    /// Note id, pubkey, list identifier... whatever the action is about
+    pub target: String,
+    /// Invokable arguments needed to run it again
+    pub args: Vec<String>,
+}
+
+impl Operation {
+    pub fn new(kind: OperationKind, target: &str) -> Self {
+        Self { kind, target: target.to_string(), args: Vec::new() }
+    }
+
+    pub fn with_args(mut self, args: &[&str]) -> Self {
+        self.args = args.iter().map(|a| a.to_string()).collect();
+        self
+    }
+
+    /// Stable id: the same action on the same target always gets the same id
+    pub fn id(&self) -> String {
+        format!("{}:{}", self.kind.as_str(), self.target)
+    }
+
+    /// Argument by position, empty if missing
+    pub fn arg(&self, index: usize) -> &str {
+        self.args.get(index).map(|a| a.as_str()).unwrap_or("")
+    }
+}
+
+/// Target for operations without one (new posts): a hash of the content,
+/// so retrying the same text keeps its id
+pub fn content_key(content: &str) -> String {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    content.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// A failed operation as shown to QML
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailedOperation {
+    pub id: String,
+    pub kind: String,
+    pub target: String,
+    pub error: String,
+    pub failed_at: i64,
+    #[serde(skip)]
+    operation: Operation,
+}
+
+lazy_static::lazy_static! {
+    static ref FAILED: Mutex<HashMap<String, FailedOperation>> = Mutex::new(HashMap::new());
+}
+
+/// Mark an operation as started, clearing any earlier failure with its id
+pub fn started(op: &Operation) {
+    FAILED.lock().unwrap().remove(&op.id());
+}
+
+/// Mark an operation as succeeded
+pub fn succeeded(op: &Operation) {
+    FAILED.lock().unwrap().remove(&op.id());
+}
+
+/// Remember a failed operation so it can be retried
+pub fn failed(op: &Operation, error: &str) {
+    let mut failed = FAILED.lock().unwrap();
+    if failed.len() >= MAX_FAILED {
+        if let Some(oldest) = failed.values().min_by_key(|f| f.failed_at).map(|f| f.id.clone()) {
+            failed.remove(&oldest);
+        }
+    }
+    failed.insert(op.id(), FailedOperation {
+        id: op.id(),
+        kind: op.kind.as_str().to_string(),
+        target: op.target.clone(),
+        error: error.to_string(),
+        failed_at: chrono::Utc::now().timestamp(),
+        operation: op.clone(),
+    });
+}
+
+/// Take a failed operation to run it again
+pub fn take_failed(id: &str) -> Option<Operation> {
+    FAILED.lock().unwrap().remove(id).map(|f| f.operation)
+}
+
+/// Failed operations of the given kinds, newest first
+pub fn failed_list(kinds: &[OperationKind]) -> Vec<FailedOperation> {
+    let mut list: Vec<FailedOperation> = FAILED.lock().unwrap()
+        .values()
+        .filter(|f| kinds.contains(&f.operation.kind))
+        .cloned()
+        .collect();
+    list.sort_by(|a, b| b.failed_at.cmp(&a.failed_at));
+    list
+}
@@ -0,0 +1,229 @@
+//! Refresh scheduler - one clock for all periodic relay refreshes
+//!
+//! Feed and stats refreshes used to run on their own timers and hit relays at
+//! the same moments. Here each task gets a jittered due time; when one task
+//! comes due, any other task due soon is fired in the same tick so their REQs
+//! go out together. Everything pauses while the window is hidden, and manual
+//! refreshes push a task's next run back.
+//!
+//! Notifications and DMs are polled separately by background loops started
+//! after login. They keep running while the window is hidden or another screen
+//! is open so the tray badge and unread counts stay current.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use rand::Rng;
+
+/// How often the scheduler checks for due tasks
+const TICK: Duration = Duration::from_secs(1);
+
+/// Tasks due within this window of a due task run with it
+const COALESCE_WINDOW: Duration = Duration::from_secs(10);
+
+/// Fraction of an interval by which a run may be moved earlier or later
+const JITTER: f64 = 0.15;
+
+/// Longest random delay before overdue tasks fire after resuming
+const RESUME_SPREAD: Duration = Duration::from_secs(3);
+
+/// A periodic refresh
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RefreshTask {
+    Feed,
+    Stats,
+}
+
+impl RefreshTask {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RefreshTask::Feed => "feed",
+            RefreshTask::Stats => "stats",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "feed" => Some(RefreshTask::Feed),
+            "stats" => Some(RefreshTask::Stats),
+            _ => None,
+        }
+    }
+
+    /// Base interval between runs
+    pub fn interval(&self) -> Duration {
+        match self {
+            RefreshTask::Feed => Duration::from_secs(60),
+            RefreshTask::Stats => Duration::from_secs(30),
+        }
+    }
+
+    const ALL: [RefreshTask; 2] = [
+        RefreshTask::Feed,
+        RefreshTask::Stats,
+    ];
+}
+
+struct TaskState {
+    /// Only active tasks are fired (e.g. stats while the feed is on screen)
+    active: bool,
+    next_due: Instant,
+}
+
+struct SchedulerState {
+    tasks: HashMap<RefreshTask, TaskState>,
+    paused: bool,
+}
+
+/// Default seconds between background notification and DM polls
+pub const DEFAULT_BACKGROUND_POLL_SECS: u64 = 60;
+
+/// Allowed range for the background poll interval, in seconds
+const BACKGROUND_POLL_RANGE: (u64, u64) = (15, 3600);
+
+lazy_static::lazy_static! {
+    static ref BACKGROUND_POLL_SECS: AtomicU64 = AtomicU64::new(
+        clamp_background_secs(crate::core::config::Config::load().background_poll_secs)
+    );
+    static ref STATE: Mutex<SchedulerState> = Mutex::new(SchedulerState {
+        tasks: RefreshTask::ALL.iter()
+            .map(|t| (*t, TaskState { active: false, next_due: Instant::now() + jittered(t.interval()) }))
+            .collect(),
+        paused: false,
+    });
+}
+
+/// Set once the tick thread is running
+static STARTED: AtomicBool = AtomicBool::new(false);
+
+/// Interval moved by a random amount up to JITTER in either direction
+fn jittered(interval: Duration) -> Duration {
+    let factor = rand::thread_rng().gen_range((1.0 - JITTER)..=(1.0 + JITTER));
+    interval.mul_f64(factor)
+}
+
+/// Start the scheduler; `on_due` receives the tasks to run in one batch
+/// Later calls are no-ops
+pub fn start<F>(on_due: F)
+where
+    F: Fn(Vec<RefreshTask>) + Send + 'static,
+{
+    if STARTED.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    std::thread::spawn(move || loop {
+        std::thread::sleep(TICK);
+        let due = take_due(Instant::now());
+        if !due.is_empty() {
+            tracing::debug!("Refresh tick: {:?}", due);
+            on_due(due);
+        }
+    });
+}
+
+/// Collect active tasks that are due (plus those due within the coalesce window)
+/// and schedule their next run
+fn take_due(now: Instant) -> Vec<RefreshTask> {
+    let mut state = STATE.lock().unwrap();
+    if state.paused {
+        return Vec::new();
+    }
+
+    let any_due = state.tasks.values().any(|t| t.active && t.next_due <= now);
+    if !any_due {
+        return Vec::new();
+    }
+
+    let mut due = Vec::new();
+    for (task, task_state) in state.tasks.iter_mut() {
+        if task_state.active && task_state.next_due <= now + COALESCE_WINDOW {
+            task_state.next_due = now + jittered(task.interval());
+            due.push(*task);
+        }
+    }
+    due
+}
+
+/// Pause (window hidden) or resume all refreshes
+/// On resume, overdue tasks fire within a short random delay rather than all at once
+pub fn set_paused(paused: bool) {
+    let mut state = STATE.lock().unwrap();
+    if state.paused == paused {
+        return;
+    }
+    state.paused = paused;
+
+    if !paused {
+        let now = Instant::now();
+        for task_state in state.tasks.values_mut() {
+            if task_state.next_due < now {
+                task_state.next_due = now + RESUME_SPREAD.mul_f64(rand::thread_rng().gen_range(0.0..=1.0));
+            }
+        }
+    }
+}
+
+/// Turn a task on or off (typically tied to the visibility of its screen)
+pub fn set_active(task: RefreshTask, active: bool) {
+    let mut state = STATE.lock().unwrap();
+    if let Some(task_state) = state.tasks.get_mut(&task) {
+        task_state.active = active;
+    }
+}
+
+/// Record that a task just ran outside the scheduler (manual refresh)
+/// so the next periodic run is a full interval away
+pub fn mark_ran(task: RefreshTask) {
+    let mut state = STATE.lock().unwrap();
+    if let Some(task_state) = state.tasks.get_mut(&task) {
+        task_state.next_due = Instant::now() + jittered(task.interval());
+    }
+}
+
+fn clamp_background_secs(secs: u64) -> u64 {
+    secs.clamp(BACKGROUND_POLL_RANGE.0, BACKGROUND_POLL_RANGE.1)
+}
+
+/// Current interval between background polls
+pub fn background_interval() -> Duration {
+    Duration::from_secs(BACKGROUND_POLL_SECS.load(Ordering::SeqCst))
+}
+
+/// Change the background poll interval (clamped); running loops pick it up
+/// on their next tick. Returns the interval in effect
+pub fn set_background_interval(secs: u64) -> Duration {
+    let secs = clamp_background_secs(secs);
+    tracing::info!("Background poll interval: {}s", secs);
+    BACKGROUND_POLL_SECS.store(secs, Ordering::SeqCst);
+    Duration::from_secs(secs)
+}
+
+/// Start a background poll calling `on_due` every `background_interval()`
+/// Bumps `generation`; the loop exits once it is bumped again (see `stop_background_poll`)
+pub fn start_background_poll<F>(generation: &'static AtomicU64, on_due: F)
+where
+    F: Fn() + Send + 'static,
+{
+    let current = generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+    std::thread::spawn(move || {
+        let mut last_run = Instant::now();
+        loop {
+            std::thread::sleep(TICK);
+            if generation.load(Ordering::SeqCst) != current {
+                break;
+            }
+            if last_run.elapsed() >= background_interval() {
+                last_run = Instant::now();
+                on_due();
+            }
+        }
+    });
+}
+
+/// Stop the background poll started with `generation`
+pub fn stop_background_poll(generation: &AtomicU64) {
+    generation.fetch_add(1, Ordering::SeqCst);
+}
@@ -0,0 +1,189 @@
+//! Scheduled posts - notes queued for publishing at a later time
+//!
+//! Posts are kept in a JSON file in the user's data directory so they survive
+//! restarts. Most are stored unsigned and signed when due (so created_at is the
+//! publish time); a pre-signed event can be stored instead and is sent as-is.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
+
+const SCHEDULED_FILE: &str = "scheduled_posts.json";
+
+/// Attempts before a failing post stops being retried
+pub const MAX_PUBLISH_ATTEMPTS: u32 = 5;
+
+/// Tags extracted from the content (hashtags and mentions), replaced when it is edited
+const CONTENT_TAGS: [&str; 2] = ["t", "p"];
+
+/// A note waiting to be published
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledPost {
+    #[serde(default)]
+    pub id: String,
+    /// Author pubkey (hex); only published while this user is logged in
+    pub pubkey: String,
+    /// Unix timestamp to publish at
+    pub publish_at: i64,
+    pub kind: u16,
+    pub content: String,
+    #[serde(default)]
+    pub tags: Vec<Vec<String>>,
+    /// Pre-signed event JSON, sent unchanged when due
+    #[serde(default)]
+    pub signed_event_json: Option<String>,
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub attempts: u32,
+    #[serde(default)]
+    pub last_error: Option<String>,
+}
+
+impl ScheduledPost {
+    /// True once the post has failed too often to retry automatically
+    pub fn is_stalled(&self) -> bool {
+        self.attempts >= MAX_PUBLISH_ATTEMPTS
+    }
+}
+
+/// Persistent queue of scheduled posts
+pub struct Scheduler {
+    path: PathBuf,
+    posts: Vec<ScheduledPost>,
+}
+
+impl Scheduler {
+    /// Load the queue from disk (empty if missing or unreadable)
+    pub fn load() -> Self {
+        let path = directories::ProjectDirs::from("", "", "pleb-client")
+            .map(|dirs| dirs.data_dir().join(SCHEDULED_FILE))
+            .unwrap_or_else(|| PathBuf::from(SCHEDULED_FILE));
+
+        let posts = fs::read_to_string(&path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+
+        Self { path, posts }
+    }
+
+    fn save(&self) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create data directory: {}", e))?;
+        }
+        let json = serde_json::to_string_pretty(&self.posts)
+            .map_err(|e| format!("Failed to serialize scheduled posts: {}", e))?;
+        let tmp_path = self.path.with_extension("json.tmp");
+        fs::write(&tmp_path, json)
+            .map_err(|e| format!("Failed to write scheduled posts: {}", e))?;
+        fs::rename(&tmp_path, &self.path)
+            .map_err(|e| format!("Failed to save scheduled posts: {}", e))
+    }
+
+    /// Queue a post; assigns an id and creation time
+    pub fn add(&mut self, mut post: ScheduledPost) -> Result<ScheduledPost, String> {
+        if post.publish_at <= 0 {
+            return Err("Invalid publish time".to_string());
+        }
+        post.id = format!("scheduled-{}", uuid::Uuid::new_v4());
+        post.created_at = chrono::Utc::now().timestamp();
+        post.attempts = 0;
+        post.last_error = None;
+
+        self.posts.push(post.clone());
+        self.save()?;
+        Ok(post)
+    }
+
+    /// Posts for a user, soonest first
+    pub fn list(&self, pubkey: &str) -> Vec<ScheduledPost> {
+        let mut posts: Vec<ScheduledPost> = self.posts.iter()
+            .filter(|p| p.pubkey == pubkey)
+            .cloned()
+            .collect();
+        posts.sort_by_key(|p| p.publish_at);
+        posts
+    }
+
+    /// Remove a post; returns false if it didn't exist
+    pub fn cancel(&mut self, id: &str) -> Result<bool, String> {
+        let before = self.posts.len();
+        self.posts.retain(|p| p.id != id);
+        if self.posts.len() == before {
+            return Ok(false);
+        }
+        self.save()?;
+        Ok(true)
+    }
+
+    /// Change one of `pubkey`'s posts: its content with the tags extracted
+    /// from it, and/or its time, which must be after `now`. Returns false if
+    /// the user has no such post. Nothing changes unless the whole edit is valid
+    /// Any pre-signed event is dropped since its signature no longer matches
+    pub fn edit(
+        &mut self,
+        id: &str,
+        pubkey: &str,
+        content: Option<(String, Vec<Vec<String>>)>,
+        publish_at: Option<i64>,
+        now: i64,
+    ) -> Result<bool, String> {
+        if publish_at.is_some_and(|at| at <= now) {
+            return Err("Scheduled time must be in the future".to_string());
+        }
+        let Some(post) = self.posts.iter_mut().find(|p| p.id == id && p.pubkey == pubkey) else {
+            return Ok(false);
+        };
+        if let Some((content, content_tags)) = content {
+            post.content = content;
+            post.tags.retain(|tag| !tag.first().is_some_and(|name| CONTENT_TAGS.contains(&name.as_str())));
+            post.tags.extend(content_tags);
+            post.signed_event_json = None;
+        }
+        if let Some(publish_at) = publish_at {
+            post.publish_at = publish_at;
+            post.signed_event_json = None;
+        }
+        // An edit is a fresh start for stalled posts
+        post.attempts = 0;
+        post.last_error = None;
+        self.save()?;
+        Ok(true)
+    }
+
+    /// Posts for a user that are due and still being retried
+    pub fn due(&self, pubkey: &str, now: i64) -> Vec<ScheduledPost> {
+        self.posts.iter()
+            .filter(|p| p.pubkey == pubkey && p.publish_at <= now && !p.is_stalled())
+            .cloned()
+            .collect()
+    }
+
+    /// Remove a post after it was published
+    pub fn mark_published(&mut self, id: &str) {
+        self.posts.retain(|p| p.id != id);
+        if let Err(e) = self.save() {
+            tracing::error!("{}", e);
+        }
+    }
+
+    /// Record a failed publish attempt
+    pub fn mark_failed(&mut self, id: &str, error: &str) {
+        if let Some(post) = self.posts.iter_mut().find(|p| p.id == id) {
+            post.attempts += 1;
+            post.last_error = Some(error.to_string());
+        }
+        if let Err(e) = self.save() {
+            tracing::error!("{}", e);
+        }
+    }
+}
+
+// Global scheduler instance
+lazy_static::lazy_static! {
+    pub static ref GLOBAL_SCHEDULER: Mutex<Scheduler> = Mutex::new(Scheduler::load());
+}
@@ -0,0 +1,430 @@
+//! System tray icon via StatusNotifierItem (SNI) over D-Bus
+//!
+//! Registers an org.kde.StatusNotifierItem with the session's
+//! StatusNotifierWatcher, plus a small com.canonical.dbusmenu menu. This works
+//! with QGuiApplication since the tray host draws the icon, not Qt.
+//! Unread notification and DM counts show in the title, tooltip and menu, and
+//! put the item into the NeedsAttention state with a badge overlay.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::RwLock;
+use zbus::{
+    connection::Builder as ConnectionBuilder,
+    interface,
+    object_server::SignalEmitter,
+    zvariant::{OwnedObjectPath, OwnedValue, Value},
+    Connection, Proxy,
+};
+
+const WATCHER_SERVICE: &str = "org.kde.StatusNotifierWatcher";
+const WATCHER_PATH: &str = "/StatusNotifierWatcher";
+const WATCHER_INTERFACE: &str = "org.kde.StatusNotifierWatcher";
+
+const ITEM_PATH: &str = "/StatusNotifierItem";
+const MENU_PATH: &str = "/MenuBar";
+
+const APP_ID: &str = "pleb-client";
+const APP_TITLE: &str = "Pleb Client";
+const APP_ICON: &str = "pleb-client";
+
+/// Size of the badge overlay pixmap (px)
+const BADGE_SIZE: i32 = 16;
+
+/// Menu item ids
+const MENU_ROOT: i32 = 0;
+const MENU_TOGGLE: i32 = 1;
+const MENU_NOTIFICATIONS: i32 = 2;
+const MENU_MESSAGES: i32 = 3;
+const MENU_SEPARATOR: i32 = 4;
+const MENU_QUIT: i32 = 5;
+
+/// Something the user asked for from the tray
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrayAction {
+    Show,
+    Hide,
+    OpenNotifications,
+    OpenMessages,
+    Quit,
+}
+
+impl TrayAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrayAction::Show => "show",
+            TrayAction::Hide => "hide",
+            TrayAction::OpenNotifications => "notifications",
+            TrayAction::OpenMessages => "messages",
+            TrayAction::Quit => "quit",
+        }
+    }
+}
+
+/// Called on the tray's D-Bus thread when the user picks an action
+pub type ActionHandler = Box<dyn Fn(TrayAction) + Send + Sync>;
+
+#[derive(Debug, Clone, Copy)]
+struct TrayState {
+    unread_notifications: u32,
+    unread_messages: u32,
+    window_visible: bool,
+}
+
+impl TrayState {
+    fn total_unread(&self) -> u32 {
+        self.unread_notifications + self.unread_messages
+    }
+
+    fn title(&self) -> String {
+        match self.total_unread() {
+            0 => APP_TITLE.to_string(),
+            n => format!("{} ({})", APP_TITLE, n),
+        }
+    }
+
+    fn description(&self) -> String {
+        if self.total_unread() == 0 {
+            return "No unread notifications or messages".to_string();
+        }
+        format!(
+            "{} unread notification{}, {} unread message{}",
+            self.unread_notifications, plural(self.unread_notifications),
+            self.unread_messages, plural(self.unread_messages),
+        )
+    }
+
+    fn status(&self) -> &'static str {
+        if self.total_unread() > 0 { "NeedsAttention" } else { "Active" }
+    }
+}
+
+fn plural(n: u32) -> &'static str {
+    if n == 1 { "" } else { "s" }
+}
+
+/// Menu layout revision, bumped whenever labels change
+static MENU_REVISION: AtomicU32 = AtomicU32::new(1);
+
+lazy_static::lazy_static! {
+    static ref TRAY_RUNTIME: tokio::runtime::Runtime = tokio::runtime::Runtime::new().unwrap();
+    static ref CONNECTION: tokio::sync::Mutex<Option<Connection>> = tokio::sync::Mutex::new(None);
+    static ref STATE: RwLock<TrayState> = RwLock::new(TrayState {
+        unread_notifications: 0,
+        unread_messages: 0,
+        window_visible: true,
+    });
+    static ref HANDLER: RwLock<Option<ActionHandler>> = RwLock::new(None);
+}
+
+fn state() -> TrayState {
+    *STATE.read().unwrap()
+}
+
+fn dispatch(action: TrayAction) {
+    if let Some(handler) = HANDLER.read().unwrap().as_ref() {
+        handler(action);
+    }
+}
+
+/// StatusNotifierItem object
+struct StatusNotifierItem;
+
+#[interface(name = "org.kde.StatusNotifierItem")]
+impl StatusNotifierItem {
+    /// Left click toggles the window
+    fn activate(&self, _x: i32, _y: i32) {
+        dispatch(if state().window_visible { TrayAction::Hide } else { TrayAction::Show });
+    }
+
+    fn secondary_activate(&self, _x: i32, _y: i32) {}
+
+    /// The menu is exported separately, so hosts don't need this
+    fn context_menu(&self, _x: i32, _y: i32) {}
+
+    fn scroll(&self, _delta: i32, _orientation: String) {}
+
+    #[zbus(property)]
+    fn category(&self) -> String {
+        "Communications".to_string()
+    }
+
+    #[zbus(property)]
+    fn id(&self) -> String {
+        APP_ID.to_string()
+    }
+
+    #[zbus(property)]
+    fn title(&self) -> String {
+        state().title()
+    }
+
+    #[zbus(property)]
+    fn status(&self) -> String {
+        state().status().to_string()
+    }
+
+    #[zbus(property)]
+    fn icon_name(&self) -> String {
+        APP_ICON.to_string()
+    }
+
+    #[zbus(property)]
+    fn attention_icon_name(&self) -> String {
+        APP_ICON.to_string()
+    }
+
+    /// Unread badge, drawn by the host over the icon
+    #[zbus(property)]
+    fn overlay_icon_pixmap(&self) -> Vec<(i32, i32, Vec<u8>)> {
+        if state().total_unread() > 0 {
+            vec![badge_pixmap(BADGE_SIZE)]
+        } else {
+            Vec::new()
+        }
+    }
+
+    #[zbus(property)]
+    fn tool_tip(&self) -> (String, Vec<(i32, i32, Vec<u8>)>, String, String) {
+        let state = state();
+        (APP_ICON.to_string(), Vec::new(), state.title(), state.description())
+    }
+
+    #[zbus(property)]
+    fn item_is_menu(&self) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn menu(&self) -> OwnedObjectPath {
+        OwnedObjectPath::try_from(MENU_PATH).unwrap()
+    }
+
+    #[zbus(signal)]
+    async fn new_title(emitter: &SignalEmitter<'_>) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    async fn new_overlay_icon(emitter: &SignalEmitter<'_>) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    async fn new_tool_tip(emitter: &SignalEmitter<'_>) -> zbus::Result<()>;
+
+    #[zbus(signal)]
+    async fn new_status(emitter: &SignalEmitter<'_>, status: &str) -> zbus::Result<()>;
+}
+
+/// com.canonical.dbusmenu object with a flat, fixed menu
+struct TrayMenu;
+
+type MenuLayout = (i32, HashMap<String, OwnedValue>, Vec<OwnedValue>);
+
+#[interface(name = "com.canonical.dbusmenu")]
+impl TrayMenu {
+    fn get_layout(&self, _parent_id: i32, _recursion_depth: i32, _property_names: Vec<String>) -> (u32, MenuLayout) {
+        let children = [MENU_TOGGLE, MENU_NOTIFICATIONS, MENU_MESSAGES, MENU_SEPARATOR, MENU_QUIT]
+            .iter()
+            .filter_map(|&id| to_owned((id, menu_item_properties(id), Vec::<OwnedValue>::new())))
+            .collect();
+
+        let root = (MENU_ROOT, menu_item_properties(MENU_ROOT), children);
+        (MENU_REVISION.load(Ordering::SeqCst), root)
+    }
+
+    fn get_group_properties(&self, ids: Vec<i32>, _property_names: Vec<String>) -> Vec<(i32, HashMap<String, OwnedValue>)> {
+        ids.into_iter().map(|id| (id, menu_item_properties(id))).collect()
+    }
+
+    fn get_property(&self, id: i32, name: String) -> OwnedValue {
+        menu_item_properties(id)
+            .remove(&name)
+            .unwrap_or_else(|| owned(""))
+    }
+
+    fn event(&self, id: i32, event_id: String, _data: OwnedValue, _timestamp: u32) {
+        if event_id != "clicked" {
+            return;
+        }
+        let action = match id {
+            MENU_TOGGLE if state().window_visible => TrayAction::Hide,
+            MENU_TOGGLE => TrayAction::Show,
+            MENU_NOTIFICATIONS => TrayAction::OpenNotifications,
+            MENU_MESSAGES => TrayAction::OpenMessages,
+            MENU_QUIT => TrayAction::Quit,
+            _ => return,
+        };
+        dispatch(action);
+    }
+
+    fn about_to_show(&self, _id: i32) -> bool {
+        false
+    }
+
+    #[zbus(property)]
+    fn version(&self) -> u32 {
+        3
+    }
+
+    #[zbus(property)]
+    fn text_direction(&self) -> String {
+        "ltr".to_string()
+    }
+
+    #[zbus(property)]
+    fn status(&self) -> String {
+        "normal".to_string()
+    }
+
+    #[zbus(signal)]
+    async fn layout_updated(emitter: &SignalEmitter<'_>, revision: u32, parent: i32) -> zbus::Result<()>;
+}
+
+fn owned<'a>(value: impl Into<Value<'a>>) -> OwnedValue {
+    OwnedValue::try_from(value.into()).unwrap()
+}
+
+fn to_owned<'a>(value: impl Into<Value<'a>>) -> Option<OwnedValue> {
+    OwnedValue::try_from(value.into()).ok()
+}
+
+fn menu_item_properties(id: i32) -> HashMap<String, OwnedValue> {
+    let state = state();
+    let mut props = HashMap::new();
+    match id {
+        MENU_ROOT => {
+            props.insert("children-display".to_string(), owned("submenu"));
+        }
+        MENU_TOGGLE => {
+            let label = if state.window_visible { "Hide Pleb Client" } else { "Show Pleb Client" };
+            props.insert("label".to_string(), owned(label));
+        }
+        MENU_NOTIFICATIONS => {
+            let label = match state.unread_notifications {
+                0 => "Notifications".to_string(),
+                n => format!("Notifications ({})", n),
+            };
+            props.insert("label".to_string(), owned(label));
+        }
+        MENU_MESSAGES => {
+            let label = match state.unread_messages {
+                0 => "Messages".to_string(),
+                n => format!("Messages ({})", n),
+            };
+            props.insert("label".to_string(), owned(label));
+        }
+        MENU_SEPARATOR => {
+            props.insert("type".to_string(), owned("separator"));
+        }
+        MENU_QUIT => {
+            props.insert("label".to_string(), owned("Quit"));
+        }
+        _ => {}
+    }
+    props
+}
+
+/// Filled red circle in ARGB32 (network byte order), as SNI expects
+fn badge_pixmap(size: i32) -> (i32, i32, Vec<u8>) {
+    let radius = size as f32 / 2.0;
+    let mut data = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let dx = x as f32 + 0.5 - radius;
+            let dy = y as f32 + 0.5 - radius;
+            let inside = dx * dx + dy * dy <= radius * radius;
+            if inside {
+                data.extend_from_slice(&[0xff, 0xef, 0x44, 0x44]);
+            } else {
+                data.extend_from_slice(&[0, 0, 0, 0]);
+            }
+        }
+    }
+    (size, size, data)
+}
+
+/// Register the tray icon with the session's StatusNotifierWatcher
+/// Fails when no tray host is running (e.g. GNOME without an SNI extension)
+pub fn start(handler: ActionHandler) -> Result<(), String> {
+    *HANDLER.write().unwrap() = Some(handler);
+    TRAY_RUNTIME.block_on(async {
+        let mut conn = CONNECTION.lock().await;
+        if conn.is_some() {
+            return Ok(());
+        }
+
+        let service_name = format!("org.kde.StatusNotifierItem-{}-1", std::process::id());
+        let c = ConnectionBuilder::session()
+            .and_then(|b| b.name(service_name.as_str()))
+            .and_then(|b| b.serve_at(ITEM_PATH, StatusNotifierItem))
+            .and_then(|b| b.serve_at(MENU_PATH, TrayMenu))
+            .map_err(|e| format!("D-Bus setup error: {}", e))?
+            .build()
+            .await
+            .map_err(|e| format!("D-Bus connection error: {}", e))?;
+
+        let watcher = Proxy::new(&c, WATCHER_SERVICE, WATCHER_PATH, WATCHER_INTERFACE)
+            .await
+            .map_err(|e| format!("D-Bus proxy error: {}", e))?;
+        watcher.call_method("RegisterStatusNotifierItem", &(service_name.as_str(),))
+            .await
+            .map_err(|e| format!("No system tray available: {}", e))?;
+
+        tracing::info!("Registered tray icon as {}", service_name);
+        *conn = Some(c);
+        Ok(())
+    })
+}
+
+/// Update the unread notification count shown on the tray icon
+pub fn set_unread_notifications(count: u32) {
+    update(|state| state.unread_notifications = count);
+}
+
+/// Update the unread DM count shown on the tray icon
+pub fn set_unread_messages(count: u32) {
+    update(|state| state.unread_messages = count);
+}
+
+/// Record whether the main window is shown (for the show/hide toggle)
+pub fn set_window_visible(visible: bool) {
+    update(|state| state.window_visible = visible);
+}
+
+/// Apply a state change and tell the tray host if anything changed
+fn update(change: impl FnOnce(&mut TrayState)) {
+    let (before, after) = {
+        let mut state = STATE.write().unwrap();
+        let before = *state;
+        change(&mut state);
+        (before, *state)
+    };
+    if before.unread_notifications == after.unread_notifications
+        && before.unread_messages == after.unread_messages
+        && before.window_visible == after.window_visible
+    {
+        return;
+    }
+
+    let revision = MENU_REVISION.fetch_add(1, Ordering::SeqCst) + 1;
+    TRAY_RUNTIME.spawn(async move {
+        if let Err(e) = emit_changes(after, revision).await {
+            tracing::debug!("Tray update failed: {}", e);
+        }
+    });
+}
+
+async fn emit_changes(state: TrayState, revision: u32) -> zbus::Result<()> {
+    let Some(conn) = CONNECTION.lock().await.clone() else {
+        return Ok(());
+    };
+    let server = conn.object_server();
+
+    let item = server.interface::<_, StatusNotifierItem>(ITEM_PATH).await?;
+    let emitter = item.signal_emitter();
+    StatusNotifierItem::new_title(emitter).await?;
+    StatusNotifierItem::new_tool_tip(emitter).await?;
+    StatusNotifierItem::new_overlay_icon(emitter).await?;
+    StatusNotifierItem::new_status(emitter, state.status()).await?;
+
+    let menu = server.interface::<_, TrayMenu>(MENU_PATH).await?;
+    TrayMenu::layout_updated(menu.signal_emitter(), revision, MENU_ROOT).await
+}
@@ -0,0 +1,89 @@
+//! Update checker - compares the running version against the latest release
+//!
+//! The endpoint is expected to return a GitHub-style release object
+//! (`tag_name`, `html_url`). Pre-releases and drafts are ignored.
+
+use serde::Deserialize;
+use std::time::Duration;
+
+/// Default release endpoint for update checks
+pub const DEFAULT_UPDATE_ENDPOINT: &str = "https://api.github.com/repos/PlebOne/pleb-client/releases/latest";
+
+/// Timeout for the release lookup
+const UPDATE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    #[serde(default)]
+    html_url: String,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+}
+
+/// A newer release than the one running
+#[derive(Debug, Clone)]
+pub struct UpdateInfo {
+    pub version: String,
+    pub notes_url: String,
+}
+
+/// Parse "v1.2.3" / "1.2.3-beta" into comparable numeric parts
+fn parse_version(version: &str) -> Option<Vec<u64>> {
+    let version = version.trim().trim_start_matches('v');
+    let core = version.split(['-', '+']).next()?;
+    core.split('.')
+        .map(|part| part.parse::<u64>().ok())
+        .collect()
+}
+
+/// True if `candidate` is a strictly newer version than `current`
+pub fn is_newer(candidate: &str, current: &str) -> bool {
+    match (parse_version(candidate), parse_version(current)) {
+        (Some(mut a), Some(mut b)) => {
+            let len = a.len().max(b.len());
+            a.resize(len, 0);
+            b.resize(len, 0);
+            a > b
+        }
+        _ => false,
+    }
+}
+
+/// Fetch the latest release and return it if it is newer than this build
+pub async fn check_for_update(endpoint: &str) -> Result<Option<UpdateInfo>, String> {
+    let client = super::network::http_client()
+        .timeout(UPDATE_TIMEOUT)
+        // GitHub's API rejects requests without a user agent
+        .user_agent(concat!("pleb-client/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let response = client.get(endpoint)
+        .send()
+        .await
+        .map_err(|e| format!("Update check failed: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Update check failed ({})", response.status()));
+    }
+
+    let release: Release = response.json()
+        .await
+        .map_err(|e| format!("Invalid release response: {}", e))?;
+
+    if release.draft || release.prerelease {
+        return Ok(None);
+    }
+
+    if !is_newer(&release.tag_name, env!("CARGO_PKG_VERSION")) {
+        return Ok(None);
+    }
+
+    Ok(Some(UpdateInfo {
+        version: release.tag_name.trim_start_matches('v').to_string(),
+        notes_url: release.html_url,
+    }))
+}
@@ -20,12 +20,39 @@ fn main() {
 
     tracing::info!("Starting PlebClient Qt...");
 
+    // nostr: links to open, and nostrconnect:// / nostrsigner: requests from other apps
+    let links: Vec<String> = std::env::args().skip(1).filter(|arg| core::deep_link::is_link(arg)).collect();
+    
+    // One client per session: a second launch hands over to the running one
+    if core::instance::forward(&links) {
+        tracing::info!("Pleb Client is already running, switched to it");
+        return;
+    }
+    if let Err(e) = core::instance::serve() {
+        // Lost a race with another launch that claimed the name first
+        if core::instance::forward(&links) {
+            tracing::info!("Pleb Client is already running, switched to it");
+            return;
+        }
+        // No session bus: run without the guard
+        tracing::warn!("{}", e);
+    }
+    // Decrypted DM attachments left behind by a run that didn't exit cleanly
+    nostr::dm_media::clear_session();
+    
+    // Opened after login
+    for link in &links {
+        core::deep_link::open(link);
+    }
+
     // Create Qt application
     let mut app = QGuiApplication::new();
     let mut engine = QQmlApplicationEngine::new();
 
     // Load main QML file
-    if let Some(engine) = engine.as_mut() {
+    if let Some(mut engine) = engine.as_mut() {
+        // image://media/... sources go through the on-disk media cache
+        bridge::image_provider::register_media_image_provider(engine.as_mut());
         engine.load(&QUrl::from("qrc:/qt/qml/com/plebclient/qml/Main.qml"));
     }
 
@@ -33,4 +60,6 @@ fn main() {
     if let Some(app) = app.as_mut() {
         app.exec();
     }
+    
+    nostr::dm_media::clear_session();
 }
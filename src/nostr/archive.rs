@@ -0,0 +1,341 @@
+//! Account archive - the user's own events, optional decrypted DMs and the
+//! app settings in one NDJSON file, for backups and moving to another machine
+//!
+//! The first line is a header naming the account; every other line is one
+//! record: `{"type":"event"}` with a signed event exactly as published,
+//! `{"type":"dm"}` with a decrypted message, or `{"type":"settings"}`.
+//! Importing verifies every event, stores it locally and can republish the
+//! account's own ones. Relays keep the newest version of replaceable events,
+//! so an old archive can't roll back a newer profile or contact list, and
+//! events the archive's deletions cover are never sent again.
+//!
+//! Archives aren't signed as a whole, so only UI preferences are restored
+//! from them; keys, signer permissions, relays, servers and proxy settings
+//! stay as they are on this machine.
+
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+use nostr_sdk::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::database::NostrDbManager;
+use super::dm::get_nip04_peer;
+use super::profile_store;
+use super::publish;
+use crate::core::config::Config;
+
+/// Version of the archive format
+const ARCHIVE_VERSION: u32 = 1;
+
+/// Kinds the account publishes that go into the archive
+const EXPORT_KINDS: &[u16] = &[
+    0,     // profile
+    1,     // notes
+    3,     // contact list
+    4,     // NIP-04 DMs (encrypted as sent)
+    5,     // deletions
+    6,     // reposts
+    7,     // reactions
+    16,    // generic reposts
+    10000, // mute list
+    10002, // relay list
+    10050, // DM relay list
+    30023, // articles
+];
+
+/// Events requested per page while walking back through history
+const PAGE_SIZE: usize = 500;
+
+/// Stop paging once a query has returned this many events
+const MAX_EVENTS: usize = 50_000;
+
+const PAGE_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Events republished at once, and the pause between batches so relays
+/// don't rate-limit the account
+const REPUBLISH_BATCH: usize = 20;
+const REPUBLISH_PAUSE: Duration = Duration::from_secs(2);
+
+/// Config fields carried in archives: display and notification preferences only
+const PORTABLE_SETTINGS: &[&str] = &[
+    "close_to_tray",
+    "auto_load_images",
+    "show_global_feed",
+    "home_feed_kinds",
+    "home_feed_kinds_customized",
+    "always_reveal_sensitive",
+    "web_viewer",
+    "desktop_notify_mentions",
+    "desktop_notify_dms",
+    "desktop_notify_zaps",
+    "dm_presence",
+    "dm_encrypt_attachments",
+    "background_poll_secs",
+    "fiat_currency",
+    "wot_threshold",
+    "wot_action",
+    "feed_preferences",
+    "feed_page_size",
+    "feed_window_hours",
+    "prefetch_on_startup",
+    "data_saver",
+    "client_tag",
+];
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum Record {
+    #[serde(rename_all = "camelCase")]
+    Header {
+        version: u32,
+        pubkey: String,
+        exported_at: i64,
+        app_version: String,
+    },
+    Event {
+        event: Event,
+    },
+    #[serde(rename_all = "camelCase")]
+    Dm {
+        id: String,
+        peer: String,
+        outgoing: bool,
+        created_at: i64,
+        content: String,
+    },
+    Settings {
+        settings: serde_json::Value,
+    },
+}
+
+/// What an export wrote
+#[derive(Debug, Clone, Default)]
+pub struct ExportSummary {
+    pub events: usize,
+    pub dms: usize,
+}
+
+/// What an import restored
+#[derive(Debug, Clone, Default)]
+pub struct ImportSummary {
+    pub events: usize,
+    pub republished: usize,
+    pub settings_restored: bool,
+}
+
+/// Fetch every page of a filter from the relays, newest first
+async fn fetch_all(client: &Client, filter: Filter) -> Result<Vec<Event>, String> {
+    let mut events = Vec::new();
+    let mut seen = HashSet::new();
+    let mut until: Option<Timestamp> = None;
+
+    while events.len() < MAX_EVENTS {
+        let mut page_filter = filter.clone().limit(PAGE_SIZE);
+        if let Some(until) = until {
+            page_filter = page_filter.until(until);
+        }
+        let page = {
+            let _permit = crate::core::limits::relay_fetch_permit().await;
+            client.fetch_events(page_filter, PAGE_TIMEOUT).await
+                .map_err(|e| format!("Failed to fetch events: {}", e))?
+        };
+
+        let mut oldest = None;
+        let mut added = 0;
+        for event in page {
+            oldest = Some(oldest.map_or(event.created_at, |t: Timestamp| t.min(event.created_at)));
+            if seen.insert(event.id) {
+                events.push(event);
+                added += 1;
+            }
+        }
+        // `until` is inclusive, so a page that only repeats known events is the end
+        match oldest {
+            Some(oldest) if added > 0 => until = Some(oldest),
+            _ => break,
+        }
+    }
+    Ok(events)
+}
+
+/// The PORTABLE_SETTINGS fields of a config
+fn portable_settings(config: &Config) -> serde_json::Value {
+    let settings = serde_json::to_value(config).unwrap_or_default();
+    let portable: serde_json::Map<String, serde_json::Value> = PORTABLE_SETTINGS.iter()
+        .filter_map(|key| settings.get(*key).map(|value| (key.to_string(), value.clone())))
+        .collect();
+    serde_json::Value::Object(portable)
+}
+
+/// Apply the PORTABLE_SETTINGS fields of archived settings; anything else in
+/// the archive is ignored
+fn restore_settings(settings: serde_json::Value) -> Result<(), String> {
+    let mut merged = serde_json::to_value(Config::load())
+        .map_err(|e| format!("Failed to read settings: {}", e))?;
+    if let (Some(merged), Some(archived)) = (merged.as_object_mut(), settings.as_object()) {
+        for key in PORTABLE_SETTINGS {
+            if let Some(value) = archived.get(*key) {
+                merged.insert(key.to_string(), value.clone());
+            }
+        }
+    }
+    let restored: Config = serde_json::from_value(merged)
+        .map_err(|e| format!("Invalid settings in archive: {}", e))?;
+    restored.save().map_err(|e| format!("Failed to save settings: {}", e))
+}
+
+/// Ids of events the user's deletions in `events` cover
+fn deleted_ids(events: &[Event], user: &PublicKey) -> HashSet<EventId> {
+    events.iter()
+        .filter(|e| e.kind == Kind::EventDeletion && e.pubkey == *user)
+        .flat_map(|e| e.tags.event_ids().copied())
+        .collect()
+}
+
+fn write_record(out: &mut impl Write, record: &Record) -> Result<(), String> {
+    let line = serde_json::to_string(record).map_err(|e| e.to_string())?;
+    writeln!(out, "{}", line).map_err(|e| format!("Failed to write archive: {}", e))
+}
+
+/// Fetch the account's events and write them with the settings to `path`
+/// With `keys`, NIP-04 DMs are also written decrypted; signer logins would
+/// need a prompt per message, so they only get the encrypted events
+pub async fn export(client: &Client, user: &PublicKey, keys: Option<&Keys>, path: &Path) -> Result<ExportSummary, String> {
+    let own = Filter::new()
+        .author(*user)
+        .kinds(EXPORT_KINDS.iter().map(|k| Kind::from(*k)));
+    // NIP-17 gift wraps are signed by throwaway keys, so they're found by recipient
+    let incoming = Filter::new()
+        .kinds([Kind::EncryptedDirectMessage, Kind::GiftWrap])
+        .pubkey(*user);
+
+    let mut events = fetch_all(client, own).await?;
+    let mut seen: HashSet<EventId> = events.iter().map(|e| e.id).collect();
+    events.extend(fetch_all(client, incoming).await?.into_iter().filter(|e| seen.insert(e.id)));
+    events.sort_by_key(|e| e.created_at);
+
+    let file = std::fs::File::create(path)
+        .map_err(|e| format!("Failed to create {}: {}", path.display(), e))?;
+    let mut out = BufWriter::new(file);
+
+    write_record(&mut out, &Record::Header {
+        version: ARCHIVE_VERSION,
+        pubkey: user.to_hex(),
+        exported_at: Timestamp::now().as_secs() as i64,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+    })?;
+
+    let mut summary = ExportSummary { events: events.len(), dms: 0 };
+    for event in &events {
+        write_record(&mut out, &Record::Event { event: event.clone() })?;
+    }
+
+    if let Some(keys) = keys {
+        for event in events.iter().filter(|e| e.kind == Kind::EncryptedDirectMessage) {
+            let Some(peer) = get_nip04_peer(event, user) else {
+                continue;
+            };
+            let content = match nip04::decrypt(keys.secret_key(), &peer, &event.content) {
+                Ok(content) => content,
+                Err(e) => {
+                    tracing::debug!("Skipping DM {} in export: {}", event.id, e);
+                    continue;
+                }
+            };
+            write_record(&mut out, &Record::Dm {
+                id: event.id.to_hex(),
+                peer: peer.to_hex(),
+                outgoing: event.pubkey == *user,
+                created_at: event.created_at.as_secs() as i64,
+                content,
+            })?;
+            summary.dms += 1;
+        }
+    }
+
+    write_record(&mut out, &Record::Settings { settings: portable_settings(&Config::load()) })?;
+    out.flush().map_err(|e| format!("Failed to write archive: {}", e))?;
+
+    tracing::info!("Exported {} events and {} DMs to {}", summary.events, summary.dms, path.display());
+    Ok(summary)
+}
+
+/// Read an archive of `user`'s account into the local database
+/// `republish` sends the account's own events to the write relays again;
+/// decrypted DM records are never imported, the encrypted events cover them
+pub async fn import(client: &Client, user: &PublicKey, path: &Path, republish: bool, restore: bool) -> Result<ImportSummary, String> {
+    let file = std::fs::File::open(path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let mut lines = BufReader::new(file).lines();
+
+    let header = lines.next()
+        .ok_or("Archive is empty")?
+        .map_err(|e| format!("Failed to read archive: {}", e))?;
+    match serde_json::from_str::<Record>(&header) {
+        Ok(Record::Header { version, pubkey, .. }) => {
+            if version > ARCHIVE_VERSION {
+                return Err(format!("Archive version {} is newer than this app supports", version));
+            }
+            if pubkey != user.to_hex() {
+                return Err("This archive belongs to another account".to_string());
+            }
+        }
+        _ => return Err("Not a Pleb Client archive".to_string()),
+    }
+
+    let mut events = Vec::new();
+    let mut settings = None;
+    for (index, line) in lines.enumerate() {
+        let line = line.map_err(|e| format!("Failed to read archive: {}", e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<Record>(&line) {
+            Ok(Record::Event { event }) => match event.verify() {
+                Ok(()) => events.push(event),
+                Err(e) => tracing::warn!("Skipping event {} with a bad signature: {}", event.id, e),
+            },
+            Ok(Record::Settings { settings: value }) => settings = Some(value),
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Skipping line {} of the archive: {}", index + 2, e),
+        }
+    }
+
+    if let Ok(db) = NostrDbManager::global() {
+        if let Err(e) = db.ingest_events(&events) {
+            tracing::warn!("Failed to store archived events: {}", e);
+        }
+    }
+    profile_store::ingest(&events);
+
+    let mut summary = ImportSummary { events: events.len(), ..Default::default() };
+    if republish {
+        let deleted = deleted_ids(&events, user);
+        let own: Vec<&Event> = events.iter()
+            .filter(|e| e.pubkey == *user && !deleted.contains(&e.id))
+            .collect();
+        for (index, batch) in own.chunks(REPUBLISH_BATCH).enumerate() {
+            if index > 0 {
+                tokio::time::sleep(REPUBLISH_PAUSE).await;
+            }
+            let results = futures::future::join_all(batch.iter().map(|event| publish::send(client, event))).await;
+            for (event, result) in batch.iter().zip(results) {
+                match result {
+                    Ok(_) => summary.republished += 1,
+                    Err(e) => tracing::debug!("Failed to republish {}: {}", event.id, e),
+                }
+            }
+        }
+    }
+    if restore {
+        if let Some(settings) = settings {
+            restore_settings(settings)?;
+            summary.settings_restored = true;
+        }
+    }
+
+    tracing::info!("Imported {} events ({} republished) from {}", summary.events, summary.republished, path.display());
+    Ok(summary)
+}
@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::path::Path;
 
+use crate::core::limits;
+
 /// Response from Blossom server after successful upload
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlossomUploadResponse {
@@ -41,12 +43,6 @@ pub async fn upload_media(
         .await
         .map_err(|e| format!("Failed to read file: {}", e))?;
     
-    // Calculate SHA256 hash
-    let mut hasher = Sha256::new();
-    hasher.update(&file_data);
-    let hash = hasher.finalize();
-    let hash_hex = hex::encode(hash);
-    
     // Detect MIME type
     let mime_type = mime_guess::from_path(path)
         .first()
@@ -55,12 +51,29 @@ pub async fn upload_media(
     
     tracing::info!("Uploading {} ({} bytes, {})", file_path, file_data.len(), mime_type);
     
-    // Create Blossom authorization event (kind 24242)
-    // The event content is "Upload <filename>" and tags include the hash
     let filename = path.file_name()
         .and_then(|n| n.to_str())
         .unwrap_or("file");
     
+    upload_bytes(server_url, file_data, &mime_type, filename, keys).await
+}
+
+/// Upload in-memory data to a Blossom server (e.g. an encrypted attachment)
+pub async fn upload_bytes(
+    server_url: &str,
+    file_data: Vec<u8>,
+    mime_type: &str,
+    filename: &str,
+    keys: &Keys,
+) -> Result<BlossomUploadResponse, String> {
+    // Calculate SHA256 hash
+    let mut hasher = Sha256::new();
+    hasher.update(&file_data);
+    let hash = hasher.finalize();
+    let hash_hex = hex::encode(hash);
+    
+    // Create Blossom authorization event (kind 24242)
+    // The event content is "Upload <filename>" and tags include the hash
     let now = Timestamp::now();
     let expiration = Timestamp::from(now.as_secs() + 300); // 5 minutes
     
@@ -76,29 +89,21 @@ pub async fn upload_media(
     .sign_with_keys(keys)
     .map_err(|e| format!("Failed to sign auth event: {}", e))?;
     
-    // Encode the event as base64 for Authorization header
-    let auth_json = serde_json::to_string(&auth_event)
-        .map_err(|e| format!("Failed to serialize auth event: {}", e))?;
-    let auth_base64 = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, auth_json);
-    
     // Build headers
     let mut headers = HeaderMap::new();
-    headers.insert(
-        AUTHORIZATION,
-        HeaderValue::from_str(&format!("Nostr {}", auth_base64))
-            .map_err(|e| format!("Invalid auth header: {}", e))?,
-    );
+    headers.insert(AUTHORIZATION, auth_header(&auth_event)?);
     headers.insert(
         CONTENT_TYPE,
-        HeaderValue::from_str(&mime_type)
+        HeaderValue::from_str(mime_type)
             .map_err(|e| format!("Invalid content type: {}", e))?,
     );
     
     // Upload endpoint
     let upload_url = format!("{}/upload", server_url.trim_end_matches('/'));
     
+    let _permit = limits::host_permit(&upload_url).await;
     // Make the upload request
-    let client = reqwest::Client::new();
+    let client = crate::core::network::http();
     let response = client
         .put(&upload_url)
         .headers(headers)
@@ -125,6 +130,144 @@ pub async fn upload_media(
     Ok(upload_response)
 }
 
+/// Blob descriptor returned by list endpoints (same shape as upload responses)
+pub type BlobDescriptor = BlossomUploadResponse;
+
+/// Build an unsigned authorization event (kind 24242) for a Blossom action
+/// `action` is the BUD verb ("upload", "list", "delete"); `hash` is the blob's sha256
+pub fn auth_event_builder(action: &str, content: &str, hash: Option<&str>) -> EventBuilder {
+    let expiration = Timestamp::from(Timestamp::now().as_secs() + 300); // 5 minutes
+    let mut builder = EventBuilder::new(Kind::Custom(24242), content)
+        .tag(Tag::custom(TagKind::Custom("t".into()), vec![action.to_string()]))
+        .tag(Tag::expiration(expiration));
+    if let Some(hash) = hash {
+        builder = builder.tag(Tag::custom(TagKind::Custom("x".into()), vec![hash.to_string()]));
+    }
+    builder
+}
+
+/// Encode a signed authorization event as a `Nostr <base64>` header value
+pub fn auth_header(auth_event: &Event) -> Result<HeaderValue, String> {
+    let auth_json = serde_json::to_string(auth_event)
+        .map_err(|e| format!("Failed to serialize auth event: {}", e))?;
+    let auth_base64 = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, auth_json);
+    HeaderValue::from_str(&format!("Nostr {}", auth_base64))
+        .map_err(|e| format!("Invalid auth header: {}", e))
+}
+
+/// List blobs uploaded by a pubkey (BUD-02 `GET /list/<pubkey>`)
+/// Some servers require a signed "list" authorization; pass it when available
+pub async fn list_blobs(
+    server_url: &str,
+    pubkey: &PublicKey,
+    auth_event: Option<&Event>,
+) -> Result<Vec<BlobDescriptor>, String> {
+    let list_url = format!("{}/list/{}", server_url.trim_end_matches('/'), pubkey.to_hex());
+
+    let _permit = limits::host_permit(&list_url).await;
+    let client = crate::core::network::http();
+    let mut request = client.get(&list_url)
+        .timeout(std::time::Duration::from_secs(30));
+    if let Some(event) = auth_event {
+        request = request.header(AUTHORIZATION, auth_header(event)?);
+    }
+
+    let response = request.send()
+        .await
+        .map_err(|e| format!("List request failed: {}", e))?;
+
+    let status = response.status();
+    let body = response.text().await
+        .map_err(|e| format!("Failed to read response: {}", e))?;
+
+    if !status.is_success() {
+        return Err(format!("List failed ({}): {}", status, body));
+    }
+
+    serde_json::from_str(&body)
+        .map_err(|e| format!("Failed to parse blob list: {} - Body: {}", e, body))
+}
+
+/// Delete a blob (BUD-02 `DELETE /<sha256>`) using a signed "delete" authorization
+pub async fn delete_blob(server_url: &str, sha256: &str, auth_event: &Event) -> Result<(), String> {
+    let delete_url = format!("{}/{}", server_url.trim_end_matches('/'), sha256);
+
+    let _permit = limits::host_permit(&delete_url).await;
+    let client = crate::core::network::http();
+    let response = client
+        .delete(&delete_url)
+        .header(AUTHORIZATION, auth_header(auth_event)?)
+        .timeout(std::time::Duration::from_secs(30))
+        .send()
+        .await
+        .map_err(|e| format!("Delete request failed: {}", e))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Delete failed ({}): {}", status, body));
+    }
+
+    tracing::info!("Deleted blob {} from {}", sha256, server_url);
+    Ok(())
+}
+
+/// A blob in the user's media library, merged across servers
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaBlob {
+    pub sha256: String,
+    pub url: String,
+    pub size: u64,
+    pub mime_type: Option<String>,
+    pub uploaded: Option<u64>,
+    /// Servers currently hosting this blob
+    pub servers: Vec<String>,
+    /// Number of the user's notes that reference this blob
+    pub note_count: usize,
+}
+
+impl MediaBlob {
+    /// Merge per-server listings into one entry per sha256, newest first
+    pub fn merge(listings: Vec<(String, Vec<BlobDescriptor>)>) -> Vec<MediaBlob> {
+        let mut blobs: Vec<MediaBlob> = Vec::new();
+        for (server, descriptors) in listings {
+            for descriptor in descriptors {
+                if let Some(existing) = blobs.iter_mut().find(|b| b.sha256 == descriptor.sha256) {
+                    if !existing.servers.contains(&server) {
+                        existing.servers.push(server.clone());
+                    }
+                    continue;
+                }
+                blobs.push(MediaBlob {
+                    sha256: descriptor.sha256,
+                    url: descriptor.url,
+                    size: descriptor.size,
+                    mime_type: descriptor.mime_type,
+                    uploaded: descriptor.uploaded,
+                    servers: vec![server.clone()],
+                    note_count: 0,
+                });
+            }
+        }
+        blobs.sort_by(|a, b| b.uploaded.cmp(&a.uploaded).then(a.sha256.cmp(&b.sha256)));
+        blobs
+    }
+
+    /// Count notes whose content references this blob (by hash, which covers
+    /// the same blob mirrored on any server)
+    pub fn count_usage(&mut self, notes: &[Event]) {
+        self.note_count = notes.iter()
+            .filter(|n| n.content.contains(&self.sha256))
+            .count();
+    }
+
+    /// Serialize to JSON for QML consumption
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
 /// Get the media type category from a MIME type
 pub fn get_media_category(mime_type: &str) -> &'static str {
     if mime_type.starts_with("image/") {
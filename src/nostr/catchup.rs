@@ -0,0 +1,253 @@
+//! Catch up summaries - what happened since the last session
+//!
+//! Aggregates locally synced events into a structured summary for the
+//! "Catch up" screen: top zapped posts from follows, new followers, and
+//! the busiest threads the user is part of.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+use crate::core::config::Config;
+use super::relay::extract_bolt11_amount;
+
+/// Window used when there is no previous session on record (24 hours)
+const DEFAULT_WINDOW_SECS: i64 = 24 * 60 * 60;
+
+/// Catch up never reaches further back than this (7 days)
+const MAX_WINDOW_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Number of entries kept in each section of the summary
+const SECTION_LIMIT: usize = 10;
+
+lazy_static::lazy_static! {
+    /// Start of the catch up window for the current session
+    static ref SESSION_SINCE: RwLock<Option<i64>> = RwLock::new(None);
+}
+
+/// A post from a followed user ranked by zaps received
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZappedPost {
+    pub note_id: String,
+    pub author_pubkey: String,
+    pub zap_total_sats: u64,
+    pub zap_count: u32,
+}
+
+/// Someone who started following the user
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewFollower {
+    pub pubkey: String,
+    pub followed_at: i64,
+}
+
+/// A thread the user took part in, ranked by reply activity
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveThread {
+    pub root_id: String,
+    pub reply_count: u32,
+    pub participant_count: u32,
+    pub last_activity: i64,
+}
+
+/// Structured summary of activity since the last session
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatchUpSummary {
+    pub since: i64,
+    pub until: i64,
+    pub top_zapped: Vec<ZappedPost>,
+    pub new_followers: Vec<NewFollower>,
+    pub active_threads: Vec<ActiveThread>,
+}
+
+impl CatchUpSummary {
+    /// Check if nothing happened in the window
+    pub fn is_empty(&self) -> bool {
+        self.top_zapped.is_empty() && self.new_followers.is_empty() && self.active_threads.is_empty()
+    }
+
+    /// Serialize to JSON for QML consumption
+    pub fn to_json(&self) -> String {
+        let mut value = serde_json::to_value(self).unwrap_or_default();
+        value["isEmpty"] = serde_json::json!(self.is_empty());
+        value.to_string()
+    }
+}
+
+/// Record the start of a new session and remember where the previous one began.
+/// Returns the start of the catch up window.
+pub fn begin_session() -> i64 {
+    let now = Timestamp::now().as_secs() as i64;
+    let mut config = Config::load();
+
+    let since = clamp_window(config.last_session_at, now);
+
+    config.last_session_at = Some(now);
+    if let Err(e) = config.save() {
+        tracing::warn!("Failed to save session timestamp: {}", e);
+    }
+
+    if let Ok(mut guard) = SESSION_SINCE.write() {
+        *guard = Some(since);
+    }
+
+    since
+}
+
+/// Start of the catch up window for the current session
+pub fn session_since() -> i64 {
+    let now = Timestamp::now().as_secs() as i64;
+    SESSION_SINCE.read().ok()
+        .and_then(|guard| *guard)
+        .unwrap_or_else(|| clamp_window(Config::load().last_session_at, now))
+}
+
+/// Bound the window between the previous session and the maximum look-back
+fn clamp_window(last_session_at: Option<i64>, now: i64) -> i64 {
+    match last_session_at {
+        Some(ts) if ts < now => ts.max(now - MAX_WINDOW_SECS),
+        _ => now - DEFAULT_WINDOW_SECS,
+    }
+}
+
+/// Contact lists naming the user as (author, created_at), for recording followers
+pub fn follower_lists(events: &[Event], user_pubkey: &str) -> Vec<(String, i64)> {
+    events.iter()
+        .filter(|e| e.kind == Kind::ContactList && e.pubkey.to_hex() != user_pubkey)
+        .filter(|e| tag_values(e, "p").any(|p| p == user_pubkey))
+        .map(|e| (e.pubkey.to_hex(), e.created_at.as_secs() as i64))
+        .collect()
+}
+
+/// Build a summary from synced events
+/// `known_followers` is the persisted follower -> first seen time (0 when they
+/// already followed when tracking started); only followers first seen inside
+/// the window count as new, so long-time followers editing their list don't
+pub fn summarize(
+    events: &[Event],
+    user_pubkey: &str,
+    following: &HashSet<String>,
+    known_followers: &HashMap<String, i64>,
+    since: i64,
+) -> CatchUpSummary {
+    let mut zaps: HashMap<String, ZappedPost> = HashMap::new();
+    let mut followers: HashMap<String, i64> = HashMap::new();
+    let mut threads: HashMap<String, (u32, HashSet<String>, i64, bool)> = HashMap::new();
+
+    for event in events.iter().filter(|e| e.created_at.as_secs() as i64 >= since) {
+        let created_at = event.created_at.as_secs() as i64;
+
+        match event.kind {
+            Kind::ZapReceipt => {
+                let Some(recipient) = first_tag_value(event, "p") else { continue };
+                if !following.contains(&recipient) {
+                    continue;
+                }
+                let Some(note_id) = first_tag_value(event, "e") else { continue };
+                let amount_msats = event.tags.iter()
+                    .find(|t| t.kind() == TagKind::Bolt11)
+                    .and_then(|t| t.content())
+                    .and_then(extract_bolt11_amount)
+                    .unwrap_or(0);
+
+                let entry = zaps.entry(note_id.clone()).or_insert_with(|| ZappedPost {
+                    note_id,
+                    author_pubkey: recipient,
+                    zap_total_sats: 0,
+                    zap_count: 0,
+                });
+                entry.zap_total_sats += amount_msats / 1000;
+                entry.zap_count += 1;
+            }
+            Kind::ContactList => {
+                let author = event.pubkey.to_hex();
+                if author == user_pubkey || !tag_values(event, "p").any(|p| p == user_pubkey) {
+                    continue;
+                }
+                if !known_followers.get(&author).is_some_and(|&first_seen| first_seen >= since) {
+                    continue;
+                }
+                // Keep the earliest contact list in the window as the follow time
+                let followed_at = followers.entry(author).or_insert(created_at);
+                *followed_at = (*followed_at).min(created_at);
+            }
+            Kind::TextNote => {
+                let Some(root_id) = thread_root(event) else { continue };
+                let author = event.pubkey.to_hex();
+                let involved = author == user_pubkey || tag_values(event, "p").any(|p| p == user_pubkey);
+
+                let entry = threads.entry(root_id).or_insert((0, HashSet::new(), 0, false));
+                entry.0 += 1;
+                entry.1.insert(author);
+                entry.2 = entry.2.max(created_at);
+                entry.3 |= involved;
+            }
+            _ => {}
+        }
+    }
+
+    let mut top_zapped: Vec<ZappedPost> = zaps.into_values().collect();
+    top_zapped.sort_by(|a, b| b.zap_total_sats.cmp(&a.zap_total_sats).then(b.zap_count.cmp(&a.zap_count)));
+    top_zapped.truncate(SECTION_LIMIT);
+
+    let mut new_followers: Vec<NewFollower> = followers.into_iter()
+        .map(|(pubkey, followed_at)| NewFollower { pubkey, followed_at })
+        .collect();
+    new_followers.sort_by(|a, b| b.followed_at.cmp(&a.followed_at));
+
+    let mut active_threads: Vec<ActiveThread> = threads.into_iter()
+        .filter(|(_, (_, _, _, involved))| *involved)
+        .map(|(root_id, (reply_count, participants, last_activity, _))| ActiveThread {
+            root_id,
+            reply_count,
+            participant_count: participants.len() as u32,
+            last_activity,
+        })
+        .collect();
+    active_threads.sort_by(|a, b| b.reply_count.cmp(&a.reply_count).then(b.last_activity.cmp(&a.last_activity)));
+    active_threads.truncate(SECTION_LIMIT);
+
+    CatchUpSummary {
+        since,
+        until: Timestamp::now().as_secs() as i64,
+        top_zapped,
+        new_followers,
+        active_threads,
+    }
+}
+
+/// Iterate over the first values of all tags with a given name
+fn tag_values<'a>(event: &'a Event, name: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    event.tags.iter().filter_map(move |tag| {
+        let values = tag.as_slice();
+        if values.first().map(|s| s.as_str()) == Some(name) {
+            values.get(1).map(|s| s.as_str())
+        } else {
+            None
+        }
+    })
+}
+
+fn first_tag_value(event: &Event, name: &str) -> Option<String> {
+    tag_values(event, name).next().map(|s| s.to_string())
+}
+
+/// Root event of a reply (NIP-10 marked "root", falling back to the first e tag)
+fn thread_root(event: &Event) -> Option<String> {
+    let e_tags: Vec<&[String]> = event.tags.iter()
+        .map(|t| t.as_slice())
+        .filter(|v| v.first().map(|s| s.as_str()) == Some("e") && v.len() > 1)
+        .collect();
+
+    e_tags.iter()
+        .find(|v| v.get(3).map(|s| s.as_str()) == Some("root"))
+        .or_else(|| e_tags.first())
+        .map(|v| v[1].clone())
+}
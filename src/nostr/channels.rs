@@ -0,0 +1,290 @@
+//! Public chat channels - NIP-28
+//!
+//! A channel is named by the id of the kind 40 event that created it; only its
+//! creator can update the name/about/picture with kind 41. Messages are kind 42
+//! events rooted at the channel id. Hiding a message (43) or muting a user (44)
+//! only affects the person who published it. Joined channels are kept in the
+//! user's NIP-51 public chats list (kind 10005).
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashSet;
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+/// Channel creation
+pub const KIND_CHANNEL_CREATE: u16 = 40;
+/// Channel metadata update
+pub const KIND_CHANNEL_METADATA: u16 = 41;
+/// Channel message
+pub const KIND_CHANNEL_MESSAGE: u16 = 42;
+/// Hide a channel message (for the publisher only)
+pub const KIND_CHANNEL_HIDE: u16 = 43;
+/// Mute a user in channels (for the publisher only)
+pub const KIND_CHANNEL_MUTE: u16 = 44;
+/// NIP-51 list of joined public chats
+pub const KIND_PUBLIC_CHATS: u16 = 10005;
+
+/// Messages fetched per history page
+pub const PAGE_SIZE: usize = 50;
+
+/// A channel as created (kind 40) and last updated by its creator (kind 41)
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Channel {
+    pub id: String,
+    pub creator: String,
+    pub name: Option<String>,
+    pub about: Option<String>,
+    pub picture: Option<String>,
+    /// Relays the creator suggested for the channel
+    pub relays: Vec<String>,
+    pub created_at: i64,
+    /// Time of the metadata currently applied
+    pub updated_at: i64,
+}
+
+impl Channel {
+    /// Parse a kind 40 creation event
+    pub fn from_creation(event: &Event) -> Option<Self> {
+        if event.kind.as_u16() != KIND_CHANNEL_CREATE {
+            return None;
+        }
+        let mut channel = Channel {
+            id: event.id.to_hex(),
+            creator: event.pubkey.to_hex(),
+            created_at: event.created_at.as_secs() as i64,
+            ..Default::default()
+        };
+        channel.apply_content(&event.content);
+        channel.updated_at = channel.created_at;
+        Some(channel)
+    }
+
+    /// Apply a kind 41 update if it comes from the creator and is newer
+    pub fn apply_metadata(&mut self, event: &Event) -> bool {
+        let created_at = event.created_at.as_secs() as i64;
+        if event.kind.as_u16() != KIND_CHANNEL_METADATA
+            || event.pubkey.to_hex() != self.creator
+            || created_at <= self.updated_at
+            || root_channel_id(event).map(|id| id.to_hex()) != Some(self.id.clone())
+        {
+            return false;
+        }
+        self.apply_content(&event.content);
+        self.updated_at = created_at;
+        true
+    }
+
+    /// Metadata is JSON in the content, like a profile
+    fn apply_content(&mut self, content: &str) {
+        let Ok(json) = serde_json::from_str::<serde_json::Value>(content) else { return };
+        let field = |name: &str| json.get(name)
+            .and_then(|v| v.as_str())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        self.name = field("name");
+        self.about = field("about");
+        self.picture = field("picture");
+        if let Some(relays) = json.get("relays").and_then(|v| v.as_array()) {
+            self.relays = relays.iter()
+                .filter_map(|r| r.as_str())
+                .filter(|r| RelayUrl::parse(r).is_ok())
+                .map(|r| r.to_string())
+                .collect();
+        }
+    }
+
+    /// First suggested relay, used as the hint on posted messages
+    pub fn relay_hint(&self) -> Option<RelayUrl> {
+        self.relays.iter().find_map(|r| RelayUrl::parse(r).ok())
+    }
+
+    /// Serialize to JSON for QML consumption
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// A kind 42 message in a channel
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelMessage {
+    pub id: String,
+    pub channel_id: String,
+    pub author: String,
+    pub content: String,
+    pub created_at: i64,
+    /// Message this one replies to, if any
+    pub reply_to: Option<String>,
+}
+
+impl ChannelMessage {
+    /// Parse a kind 42 event
+    pub fn from_event(event: &Event) -> Option<Self> {
+        if event.kind.as_u16() != KIND_CHANNEL_MESSAGE {
+            return None;
+        }
+        let channel_id = root_channel_id(event)?;
+        let reply_to = event.tags.iter()
+            .find_map(|t| match t.as_standardized() {
+                Some(TagStandard::Event { event_id, marker: Some(Marker::Reply), .. }) => Some(event_id.to_hex()),
+                _ => None,
+            });
+        Some(ChannelMessage {
+            id: event.id.to_hex(),
+            channel_id: channel_id.to_hex(),
+            author: event.pubkey.to_hex(),
+            content: event.content.clone(),
+            created_at: event.created_at.as_secs() as i64,
+            reply_to,
+        })
+    }
+}
+
+/// Channel id of a 41/42 event: the root-marked `e` tag, else the first `e` tag
+pub fn root_channel_id(event: &Event) -> Option<EventId> {
+    let mut first = None;
+    for tag in event.tags.iter() {
+        if let Some(TagStandard::Event { event_id, marker, .. }) = tag.as_standardized() {
+            if *marker == Some(Marker::Root) {
+                return Some(*event_id);
+            }
+            first.get_or_insert(*event_id);
+        }
+    }
+    first
+}
+
+/// What the user has hidden and muted (from their own 43/44 events)
+#[derive(Debug, Clone, Default)]
+pub struct Moderation {
+    pub hidden_messages: HashSet<String>,
+    pub muted_users: HashSet<String>,
+}
+
+impl Moderation {
+    pub fn from_events<'a>(events: impl IntoIterator<Item = &'a Event>) -> Self {
+        let mut moderation = Moderation::default();
+        for event in events {
+            moderation.apply(event);
+        }
+        moderation
+    }
+
+    /// Record a hide or mute event
+    pub fn apply(&mut self, event: &Event) {
+        match event.kind.as_u16() {
+            KIND_CHANNEL_HIDE => self.hidden_messages.extend(event.tags.event_ids().map(|id| id.to_hex())),
+            KIND_CHANNEL_MUTE => self.muted_users.extend(event.tags.public_keys().map(|pk| pk.to_hex())),
+            _ => {}
+        }
+    }
+
+    /// Whether a message should be left out of the view
+    pub fn hides(&self, message: &ChannelMessage) -> bool {
+        self.hidden_messages.contains(&message.id) || self.muted_users.contains(&message.author)
+    }
+}
+
+/// Channel ids in a kind 10005 public chats list
+pub fn joined_channel_ids(list: &Event) -> Vec<EventId> {
+    let mut ids = Vec::new();
+    for id in list.tags.event_ids() {
+        if !ids.contains(id) {
+            ids.push(*id);
+        }
+    }
+    ids
+}
+
+/// Rebuild the public chats list with a channel added or removed
+/// Other entries in the existing list are kept as they are
+pub fn public_chats_builder(existing: Option<&Event>, channel_id: &EventId, joined: bool) -> EventBuilder {
+    let mut tags: Vec<Tag> = existing
+        .map(|list| list.tags.iter()
+            .filter(|t| !matches!(t.as_standardized(), Some(TagStandard::Event { event_id, .. }) if event_id == channel_id))
+            .cloned()
+            .collect())
+        .unwrap_or_default();
+    if joined {
+        tags.push(Tag::event(*channel_id));
+    }
+    let content = existing.map(|list| list.content.clone()).unwrap_or_default();
+    EventBuilder::new(Kind::from(KIND_PUBLIC_CHATS), content).tags(tags)
+}
+
+/// Build a message, optionally replying to another message in the channel
+pub fn message_builder(channel: &Channel, content: &str, reply_to: Option<(EventId, PublicKey)>) -> Result<EventBuilder, String> {
+    let channel_id = EventId::from_hex(&channel.id).map_err(|e| format!("Invalid channel id: {}", e))?;
+    let relay_url = channel.relay_hint();
+    let mut tags = vec![Tag::from_standardized_without_cell(TagStandard::Event {
+        event_id: channel_id,
+        relay_url: relay_url.clone(),
+        marker: Some(Marker::Root),
+        public_key: None,
+        uppercase: false,
+    })];
+    if let Some((message_id, author)) = reply_to {
+        tags.push(Tag::from_standardized_without_cell(TagStandard::Event {
+            event_id: message_id,
+            relay_url,
+            marker: Some(Marker::Reply),
+            public_key: None,
+            uppercase: false,
+        }));
+        tags.push(Tag::public_key(author));
+    }
+    Ok(EventBuilder::new(Kind::from(KIND_CHANNEL_MESSAGE), content).tags(tags))
+}
+
+/// Build a hide-message event
+pub fn hide_message(message_id: EventId, reason: &str) -> EventBuilder {
+    EventBuilder::hide_channel_msg(message_id, Some(reason).filter(|r| !r.is_empty()))
+}
+
+/// Build a mute-user event
+pub fn mute_user(pubkey: PublicKey, reason: &str) -> EventBuilder {
+    EventBuilder::mute_channel_user(pubkey, Some(reason).filter(|r| !r.is_empty()))
+}
+
+/// Filter for the user's public chats list
+pub fn public_chats_filter(user: &PublicKey) -> Filter {
+    Filter::new()
+        .author(*user)
+        .kind(Kind::from(KIND_PUBLIC_CHATS))
+        .limit(1)
+}
+
+/// Filter for channel creation events
+pub fn channels_filter(ids: &[EventId]) -> Filter {
+    Filter::new()
+        .ids(ids.iter().copied())
+        .kind(Kind::from(KIND_CHANNEL_CREATE))
+}
+
+/// Filter for metadata updates to channels
+pub fn channel_metadata_filter(ids: &[EventId]) -> Filter {
+    Filter::new()
+        .events(ids.iter().copied())
+        .kind(Kind::from(KIND_CHANNEL_METADATA))
+}
+
+/// Filter for one page of channel history, older than `until` if given
+pub fn messages_filter(channel_id: &EventId, until: Option<Timestamp>) -> Filter {
+    let mut filter = Filter::new()
+        .event(*channel_id)
+        .kind(Kind::from(KIND_CHANNEL_MESSAGE))
+        .limit(PAGE_SIZE);
+    if let Some(until) = until {
+        filter = filter.until(until);
+    }
+    filter
+}
+
+/// Filter for the user's own hide/mute events
+pub fn moderation_filter(user: &PublicKey) -> Filter {
+    Filter::new()
+        .author(*user)
+        .kinds([Kind::from(KIND_CHANNEL_HIDE), Kind::from(KIND_CHANNEL_MUTE)])
+}
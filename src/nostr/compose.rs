@@ -0,0 +1,133 @@
+//! Composer helpers - turn composer text into note content and tags
+//!
+//! Extracts #hashtags into `t` tags and nostr:npub/nprofile mentions into
+//! `p` tags (NIP-27), after rewriting pasted npub/nprofile strings and plain
+//! @name mentions into nostr: URIs using the local profile cache.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashSet;
+use nostr_sdk::prelude::*;
+
+use super::database::NostrDbManager;
+
+lazy_static::lazy_static! {
+    // Hashtags must start a word; '/' and '&' exclude URL fragments and entities
+    static ref HASHTAG_RE: regex::Regex = regex::Regex::new(r"(?:^|[^\w/&#])#(\w+)").unwrap();
+    static ref AT_MENTION_RE: regex::Regex = regex::Regex::new(r"(^|\s)@([\w\-]+)").unwrap();
+    static ref NOSTR_URI_RE: regex::Regex = regex::Regex::new(r"nostr:((?:npub1|nprofile1)[a-z0-9]+)").unwrap();
+    // Pasted npub/nprofile not already in a URI or URL ("nostr:", "https://.../npub1...")
+    static ref BARE_ENTITY_RE: regex::Regex = regex::Regex::new(r"(^|[^\w:/@])@?((?:npub1|nprofile1)[a-z0-9]+)").unwrap();
+}
+
+/// Content and tags ready to be added to a note
+#[derive(Debug, Clone, Default)]
+pub struct PreparedNote {
+    pub content: String,
+    pub tags: Vec<Tag>,
+}
+
+/// Canonicalize pasted entities and rewrite @name mentions, then extract t and p tags from the result
+pub fn prepare_note(content: &str) -> PreparedNote {
+    let content = resolve_at_mentions(&canonicalize_mentions(content), lookup_cached_name);
+
+    let mut tags: Vec<Tag> = extract_hashtags(&content)
+        .into_iter()
+        .map(Tag::hashtag)
+        .collect();
+    tags.extend(extract_mentions(&content).into_iter().map(Tag::public_key));
+
+    PreparedNote { content, tags }
+}
+
+/// Lowercased, deduplicated hashtags in order of appearance
+/// Purely numeric tags ("#1") are ignored
+pub fn extract_hashtags(content: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    HASHTAG_RE.captures_iter(content)
+        .filter_map(|c| c.get(1))
+        .map(|m| m.as_str().to_lowercase())
+        .filter(|t| !t.chars().all(|c| c.is_ascii_digit()))
+        .filter(|t| seen.insert(t.clone()))
+        .collect()
+}
+
+/// Pubkey of an npub or nprofile, None if it doesn't decode
+pub fn parse_mention(bech32: &str) -> Option<PublicKey> {
+    if bech32.starts_with("nprofile1") {
+        Nip19Profile::from_bech32(bech32).ok().map(|p| p.public_key)
+    } else {
+        PublicKey::from_bech32(bech32).ok()
+    }
+}
+
+/// Pubkeys mentioned via nostr:npub / nostr:nprofile URIs, deduplicated
+pub fn extract_mentions(content: &str) -> Vec<PublicKey> {
+    mention_uris(content).into_iter().map(|(_, pk)| pk).collect()
+}
+
+/// nostr: URIs in the content with their pubkeys, deduplicated by pubkey
+pub fn mention_uris(content: &str) -> Vec<(String, PublicKey)> {
+    let mut seen = HashSet::new();
+    NOSTR_URI_RE.captures_iter(content)
+        .filter_map(|c| {
+            let uri = c.get(0)?.as_str().to_string();
+            parse_mention(c.get(1)?.as_str()).map(|pk| (uri, pk))
+        })
+        .filter(|(_, pk)| seen.insert(*pk))
+        .collect()
+}
+
+/// Rewrite pasted npub1.../nprofile1... (optionally @-prefixed) as nostr: URIs
+/// Strings that fail to decode are left as typed
+pub fn canonicalize_mentions(content: &str) -> String {
+    BARE_ENTITY_RE.replace_all(content, |caps: &regex::Captures| {
+        let prefix = &caps[1];
+        let bech32 = &caps[2];
+        if parse_mention(bech32).is_some() {
+            format!("{}nostr:{}", prefix, bech32)
+        } else {
+            caps[0].to_string()
+        }
+    }).into_owned()
+}
+
+/// Replace @name with nostr:npub... when `lookup` resolves the name
+/// Unresolved names are left untouched
+pub fn resolve_at_mentions<F>(content: &str, lookup: F) -> String
+where
+    F: Fn(&str) -> Option<PublicKey>,
+{
+    AT_MENTION_RE.replace_all(content, |caps: &regex::Captures| {
+        let prefix = &caps[1];
+        let name = &caps[2];
+        match lookup(name).and_then(|pk| pk.to_bech32().ok()) {
+            Some(npub) => format!("{}nostr:{}", prefix, npub),
+            None => caps[0].to_string(),
+        }
+    }).into_owned()
+}
+
+/// Resolve a name against cached profiles (exact, case-insensitive)
+/// Ambiguous names resolve to nothing rather than guessing
+fn lookup_cached_name(name: &str) -> Option<PublicKey> {
+    if !NostrDbManager::is_initialized() {
+        return None;
+    }
+    let db = NostrDbManager::global().ok()?;
+    let name_lower = name.to_lowercase();
+
+    let matches: HashSet<String> = db.search_profiles(name)
+        .into_iter()
+        .filter(|p| {
+            p.name.as_deref().map(|n| n.to_lowercase() == name_lower).unwrap_or(false)
+                || p.display_name.as_deref().map(|n| n.to_lowercase() == name_lower).unwrap_or(false)
+        })
+        .map(|p| p.pubkey)
+        .collect();
+
+    if matches.len() != 1 {
+        return None;
+    }
+    matches.into_iter().next().and_then(|pk| PublicKey::parse(&pk).ok())
+}
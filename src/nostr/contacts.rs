@@ -0,0 +1,296 @@
+//! Contact lists - kind 3 follow lists with petnames and relay hints
+//!
+//! Provides a serializable backup format so users can export their follows
+//! to a file and restore them later, and a ContactManager that holds the
+//! authoritative follow list with optimistic local updates.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use nostr_sdk::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Current version of the backup file format
+const BACKUP_VERSION: u32 = 1;
+
+/// Quiet period after the last follow toggle before the contact list is published
+pub const CONTACT_PUBLISH_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// A single followed user from a contact list
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactEntry {
+    pub pubkey: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relay_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub petname: Option<String>,
+}
+
+impl ContactEntry {
+    /// Build the ["p", pubkey, relay, petname] tag for this contact
+    pub fn to_tag(&self) -> Tag {
+        let mut values = vec!["p".to_string(), self.pubkey.clone()];
+        if self.relay_url.is_some() || self.petname.is_some() {
+            values.push(self.relay_url.clone().unwrap_or_default());
+        }
+        if let Some(petname) = &self.petname {
+            values.push(petname.clone());
+        }
+        Tag::parse(values).expect("p tag is always valid")
+    }
+}
+
+/// Exported contact list backup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactListBackup {
+    pub version: u32,
+    pub pubkey: String,
+    /// created_at of the exported kind 3 event
+    pub created_at: i64,
+    pub exported_at: i64,
+    /// Raw kind 3 content (legacy relay list JSON, often empty)
+    #[serde(default)]
+    pub content: String,
+    pub contacts: Vec<ContactEntry>,
+}
+
+impl ContactListBackup {
+    /// Build a backup from a kind 3 event
+    pub fn from_event(event: &Event) -> Self {
+        Self {
+            version: BACKUP_VERSION,
+            pubkey: event.pubkey.to_hex(),
+            created_at: event.created_at.as_secs() as i64,
+            exported_at: Timestamp::now().as_secs() as i64,
+            content: event.content.clone(),
+            contacts: contacts_from_event(event),
+        }
+    }
+
+    /// Parse a backup from JSON, validating every pubkey
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let backup: Self = serde_json::from_str(json)
+            .map_err(|e| format!("Invalid contact list backup: {}", e))?;
+
+        if backup.version > BACKUP_VERSION {
+            return Err(format!("Unsupported backup version: {}", backup.version));
+        }
+
+        for contact in &backup.contacts {
+            PublicKey::parse(&contact.pubkey)
+                .map_err(|_| format!("Invalid pubkey in backup: {}", contact.pubkey))?;
+        }
+
+        Ok(backup)
+    }
+
+    /// Serialize to pretty JSON for writing to disk
+    pub fn to_json_pretty(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Build an unsigned kind 3 event restoring this contact list
+    pub fn to_event_builder(&self) -> EventBuilder {
+        EventBuilder::new(Kind::ContactList, &self.content)
+            .tags(self.contacts.iter().map(|c| c.to_tag()))
+    }
+
+    /// Compare against the currently published contacts
+    /// Returns (added, removed) pubkey counts
+    pub fn diff(&self, current: &[ContactEntry]) -> (usize, usize) {
+        let ours: HashSet<&str> = self.contacts.iter().map(|c| c.pubkey.as_str()).collect();
+        let theirs: HashSet<&str> = current.iter().map(|c| c.pubkey.as_str()).collect();
+        (ours.difference(&theirs).count(), theirs.difference(&ours).count())
+    }
+}
+
+/// Extract contact entries (with relay hints and petnames) from a kind 3 event
+pub fn contacts_from_event(event: &Event) -> Vec<ContactEntry> {
+    let mut seen = HashSet::new();
+    event.tags.iter()
+        .filter_map(|tag| {
+            let values = tag.as_slice();
+            if values.first().map(|s| s.as_str()) != Some("p") {
+                return None;
+            }
+            let pubkey = PublicKey::parse(values.get(1)?).ok()?.to_hex();
+            if !seen.insert(pubkey.clone()) {
+                return None;
+            }
+            Some(ContactEntry {
+                pubkey,
+                relay_url: values.get(2).filter(|s| !s.is_empty()).cloned(),
+                petname: values.get(3).filter(|s| !s.is_empty()).cloned(),
+            })
+        })
+        .collect()
+}
+
+/// Authoritative contact list for the logged-in user
+///
+/// Follow/unfollow changes are applied optimistically and queued; a single
+/// kind 3 is published once toggling settles. Before publishing, the queued
+/// changes are replayed on top of the newest list from relays so follows made
+/// on other clients are not clobbered.
+#[derive(Debug, Default)]
+pub struct ContactManager {
+    owner: Option<PublicKey>,
+    contacts: Vec<ContactEntry>,
+    /// Latest known kind 3 event (published by us or fetched from relays)
+    base_event: Option<Event>,
+    /// Whether relays have been asked for the list: a successful fetch that
+    /// found none means the user has no follows yet
+    confirmed: bool,
+    /// Queued changes not yet published: pubkey -> true (follow) / false (unfollow)
+    pending: HashMap<String, bool>,
+    /// Bumped on every local change so debounced publishers can tell if they are stale
+    generation: u64,
+}
+
+impl ContactManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reset for a (possibly different) logged-in user
+    pub fn reset(&mut self, owner: Option<PublicKey>) {
+        *self = Self { owner, ..Self::default() };
+    }
+
+    pub fn owner(&self) -> Option<PublicKey> {
+        self.owner
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub fn has_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Whether the list on relays is known: fetched, published by us, or
+    /// confirmed not to exist
+    pub fn has_base(&self) -> bool {
+        self.confirmed
+    }
+
+    /// Record a successful fetch that found no contact list; queued changes
+    /// then apply to an empty list
+    pub fn confirm_none(&mut self) {
+        self.confirmed = true;
+    }
+
+    pub fn is_following(&self, pubkey: &PublicKey) -> bool {
+        let hex = pubkey.to_hex();
+        self.contacts.iter().any(|c| c.pubkey == hex)
+    }
+
+    /// Current (optimistic) follow list
+    pub fn following(&self) -> Vec<PublicKey> {
+        self.contacts.iter()
+            .filter_map(|c| PublicKey::parse(&c.pubkey).ok())
+            .collect()
+    }
+
+    /// Adopt a contact list event from relays if it is newer than what we have
+    /// Queued local changes are re-applied on top
+    pub fn merge_remote(&mut self, event: Event) {
+        if event.kind != Kind::ContactList || Some(event.pubkey) != self.owner {
+            return;
+        }
+        if let Some(base) = &self.base_event {
+            if base.created_at >= event.created_at {
+                return;
+            }
+        }
+
+        self.contacts = contacts_from_event(&event);
+        self.base_event = Some(event);
+        self.confirmed = true;
+
+        let pending: Vec<(String, bool)> = self.pending.iter().map(|(k, v)| (k.clone(), *v)).collect();
+        for (pubkey, follow) in pending {
+            self.apply(&pubkey, follow);
+        }
+    }
+
+    /// Optimistically follow a user
+    pub fn follow(&mut self, pubkey: &PublicKey) {
+        self.queue(pubkey.to_hex(), true);
+    }
+
+    /// Optimistically follow several users as one change
+    pub fn follow_all(&mut self, pubkeys: &[PublicKey]) {
+        for pubkey in pubkeys {
+            let hex = pubkey.to_hex();
+            self.apply(&hex, true);
+            self.pending.insert(hex, true);
+        }
+        self.generation += 1;
+    }
+
+    /// Optimistically unfollow a user
+    pub fn unfollow(&mut self, pubkey: &PublicKey) {
+        self.queue(pubkey.to_hex(), false);
+    }
+
+    fn queue(&mut self, pubkey: String, follow: bool) {
+        self.apply(&pubkey, follow);
+        self.pending.insert(pubkey, follow);
+        self.generation += 1;
+    }
+
+    fn apply(&mut self, pubkey: &str, follow: bool) {
+        let exists = self.contacts.iter().any(|c| c.pubkey == pubkey);
+        if follow && !exists {
+            self.contacts.push(ContactEntry {
+                pubkey: pubkey.to_string(),
+                relay_url: None,
+                petname: None,
+            });
+        } else if !follow && exists {
+            self.contacts.retain(|c| c.pubkey != pubkey);
+        }
+    }
+
+    /// Build the kind 3 event for the current list, preserving the
+    /// existing content (legacy relay list) and tag metadata
+    pub fn to_event_builder(&self) -> EventBuilder {
+        let content = self.base_event.as_ref().map(|e| e.content.clone()).unwrap_or_default();
+        EventBuilder::new(Kind::ContactList, content)
+            .tags(self.contacts.iter().map(|c| c.to_tag()))
+    }
+
+    /// Build the kind 3 for queued follow changes
+    /// Until relays have been asked for the list, publishing could replace the
+    /// user's real follows with only the queued ones, so it's refused and the
+    /// changes stay pending
+    pub fn update_builder(&self) -> Result<EventBuilder, String> {
+        if !self.confirmed {
+            return Err("Your follow list hasn't loaded from relays yet; the change will be published once it has".to_string());
+        }
+        Ok(self.to_event_builder())
+    }
+
+    /// Record a successful publish; changes queued after `generation` stay pending
+    pub fn mark_published(&mut self, event: Event, generation: u64) {
+        self.base_event = Some(event);
+        self.confirmed = true;
+        if self.generation == generation {
+            self.pending.clear();
+        }
+    }
+}
+
+/// Shared contact manager type
+pub type SharedContactManager = Arc<Mutex<ContactManager>>;
+
+// Global contact manager instance
+lazy_static::lazy_static! {
+    pub static ref GLOBAL_CONTACT_MANAGER: SharedContactManager = Arc::new(Mutex::new(ContactManager::new()));
+}
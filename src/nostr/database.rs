@@ -13,16 +13,65 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
-use nostrdb::{Config, Ndb};
+use nostrdb::{Config, Ndb, Transaction};
 use nostr_sdk::prelude::*;
 use parking_lot::RwLock;
 
+use super::outbox::OutboxEntry;
+use super::search_index::{SearchIndex, SEARCH_INDEX_DIR};
+use crate::core::cache_stats;
+
 /// Cache duration in seconds (24 hours)
 pub const CACHE_DURATION_SECS: u64 = 24 * 60 * 60;
 
 /// Maximum in-memory cache entries
 const MAX_MEMORY_CACHE_SIZE: usize = 1000;
 
+/// How long a successful NIP-05 verification is trusted (24 hours)
+const NIP05_VERIFIED_TTL_SECS: i64 = 24 * 60 * 60;
+
+/// How long a failed NIP-05 verification is remembered (1 hour)
+const NIP05_FAILED_TTL_SECS: i64 = 60 * 60;
+
+/// Maximum events pulled from nostrdb when building the search index from scratch
+const SEARCH_BACKFILL_LIMIT: usize = 20_000;
+
+/// Events read and committed per step of the search index backfill
+const SEARCH_BACKFILL_PAGE: usize = 1000;
+
+/// File (inside the database directory) written once the search index backfill has finished
+const SEARCH_BACKFILL_MARKER: &str = "search_backfill_complete";
+
+/// File (inside the database directory) holding cached NIP-05 results
+const NIP05_CACHE_FILE: &str = "nip05_cache.json";
+
+/// File (inside the database directory) holding notification read state
+const NOTIFICATION_STATE_FILE: &str = "notification_state.json";
+
+/// File (inside the database directory) holding how far each DM conversation was read
+const DM_READ_STATE_FILE: &str = "dm_read_state.json";
+
+/// File (inside the database directory) holding web-of-trust scores
+const TRUST_SCORES_FILE: &str = "trust_scores.json";
+
+/// File (inside the database directory) holding emoji reaction usage
+const REACTION_USAGE_FILE: &str = "reaction_usage.json";
+
+/// File (inside the database directory) holding content the user reported
+const REPORTS_FILE: &str = "reports.json";
+
+/// File (inside the database directory) holding follows waiting for review
+const FOLLOW_QUEUE_FILE: &str = "follow_queue.json";
+
+/// File (inside the database directory) holding posts waiting to be published
+const OUTBOX_FILE: &str = "outbox.json";
+
+/// Most distinct reactions remembered per user
+const MAX_REACTION_USAGE: usize = 200;
+
+/// Maximum individually-read notification ids remembered per user
+const MAX_READ_NOTIFICATION_IDS: usize = 2000;
+
 /// Global singleton for nostrdb - LMDB requires single instance
 static NOSTR_DB: OnceLock<Arc<NostrDbManager>> = OnceLock::new();
 
@@ -62,6 +111,162 @@ impl CachedProfile {
     }
 }
 
+/// Result of a NIP-05 verification check
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Nip05Status {
+    pub verified: bool,
+    pub checked_at: i64,
+}
+
+impl Nip05Status {
+    pub fn is_expired(&self, now: i64) -> bool {
+        let ttl = if self.verified { NIP05_VERIFIED_TTL_SECS } else { NIP05_FAILED_TTL_SECS };
+        now - self.checked_at > ttl
+    }
+}
+
+/// Which notifications a user has seen
+/// Everything at or before `last_seen` is read; newer ones are read if listed in `read_ids`
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct NotificationReadState {
+    #[serde(default)]
+    pub last_seen: i64,
+    /// Notification id -> created_at
+    #[serde(default)]
+    pub read_ids: HashMap<String, i64>,
+    /// Follower pubkey -> when they were first seen following (0 = already following at the first check)
+    #[serde(default)]
+    pub followers: HashMap<String, i64>,
+    /// Set once the first check has recorded the existing followers
+    #[serde(default)]
+    pub followers_seeded: bool,
+}
+
+impl NotificationReadState {
+    pub fn is_read(&self, id: &str, created_at: i64) -> bool {
+        created_at <= self.last_seen || self.read_ids.contains_key(id)
+    }
+    
+    /// Mark single notifications as read
+    pub fn mark_read(&mut self, ids: &[(String, i64)]) {
+        for (id, created_at) in ids {
+            if *created_at > self.last_seen {
+                self.read_ids.insert(id.clone(), *created_at);
+            }
+        }
+        
+        // Keep the newest entries when over the cap
+        if self.read_ids.len() > MAX_READ_NOTIFICATION_IDS {
+            let mut entries: Vec<(String, i64)> = self.read_ids.drain().collect();
+            entries.sort_by(|a, b| b.1.cmp(&a.1));
+            entries.truncate(MAX_READ_NOTIFICATION_IDS);
+            self.read_ids = entries.into_iter().collect();
+        }
+    }
+    
+    /// Mark everything up to a timestamp as read
+    pub fn mark_all_read(&mut self, up_to: i64) {
+        if up_to > self.last_seen {
+            self.last_seen = up_to;
+        }
+        let last_seen = self.last_seen;
+        self.read_ids.retain(|_, created_at| *created_at > last_seen);
+    }
+    
+    /// Record contact lists naming the user as (author, created_at)
+    /// Returns (follower, followed_at) for every follower seen after the first
+    /// check; the first check only records who already follows
+    pub fn record_followers(&mut self, lists: &[(String, i64)]) -> Vec<(String, i64)> {
+        let seeding = !self.followers_seeded;
+        self.followers_seeded = true;
+        let mut follows = Vec::new();
+        for (follower, created_at) in lists {
+            let followed_at = *self.followers.entry(follower.clone())
+                .or_insert(if seeding { 0 } else { *created_at });
+            if followed_at > 0 {
+                follows.push((follower.clone(), followed_at));
+            }
+        }
+        follows
+    }
+}
+
+/// How far a user has read each DM conversation
+/// Incoming messages at or before `baseline` or the peer's `read_up_to` are read
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct DmReadState {
+    /// When read tracking started; older messages count as read
+    #[serde(default)]
+    pub baseline: i64,
+    /// Peer pubkey -> created_at of the newest message read
+    #[serde(default)]
+    pub read_up_to: HashMap<String, i64>,
+}
+
+impl DmReadState {
+    /// Newest read message time for a conversation
+    pub fn last_read(&self, peer: &str) -> i64 {
+        self.read_up_to.get(peer).copied().unwrap_or(0).max(self.baseline)
+    }
+    
+    /// Mark a conversation read up to a message time (never moves backwards)
+    pub fn mark_read(&mut self, peer: &str, up_to: i64) {
+        if up_to > self.last_read(peer) {
+            self.read_up_to.insert(peer.to_string(), up_to);
+        }
+    }
+}
+
+/// How often and how recently the user reacted with an emoji
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReactionUsage {
+    /// Reaction content: a unicode emoji or a NIP-30 ":shortcode:"
+    pub emoji: String,
+    /// Image URL for custom emoji
+    #[serde(default)]
+    pub url: Option<String>,
+    pub count: u32,
+    pub last_used: i64,
+}
+
+/// Notes and users the user reported (NIP-56), hidden locally
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct ReportedContent {
+    /// Note id -> reported at
+    #[serde(default)]
+    pub notes: HashMap<String, i64>,
+    /// Pubkey -> reported at
+    #[serde(default)]
+    pub pubkeys: HashMap<String, i64>,
+}
+
+/// A suggested follow waiting in the user's review queue
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedFollow {
+    pub pubkey: String,
+    /// Where the suggestion came from ("search", "suggestions", "profile", ...)
+    pub source: String,
+    pub added_at: i64,
+}
+
+impl ReportedContent {
+    pub fn is_hidden(&self, note_id: &str, pubkey: &str) -> bool {
+        self.notes.contains_key(note_id) || self.pubkeys.contains_key(pubkey)
+    }
+}
+
+/// Web-of-trust hop distances computed for one user
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct TrustScores {
+    pub owner: String,
+    pub computed_at: i64,
+    /// Pubkey (hex) -> hops from the owner
+    #[serde(default)]
+    pub distances: HashMap<String, u8>,
+}
+
 /// In-memory hot cache layer
 struct MemoryCache {
     events: HashMap<String, CachedEvent>,  // event_id -> event
@@ -125,6 +330,15 @@ impl MemoryCache {
 pub struct NostrDbManager {
     ndb: Ndb,
     memory_cache: RwLock<MemoryCache>,
+    nip05_cache: RwLock<HashMap<String, Nip05Status>>,  // "nip05|pubkey" -> status
+    notification_state: RwLock<HashMap<String, NotificationReadState>>,  // user pubkey -> state
+    dm_read_state: RwLock<HashMap<String, DmReadState>>,  // user pubkey -> state
+    search_index: Option<SearchIndex>,  // None if the index couldn't be opened
+    trust_scores: RwLock<Option<TrustScores>>,
+    reaction_usage: RwLock<HashMap<String, HashMap<String, ReactionUsage>>>,  // user pubkey -> emoji -> usage
+    reports: RwLock<HashMap<String, ReportedContent>>,  // user pubkey -> reported content
+    follow_queue: RwLock<HashMap<String, Vec<QueuedFollow>>>,  // user pubkey -> queued follows, oldest first
+    outbox: RwLock<HashMap<String, Vec<OutboxEntry>>>,  // user pubkey -> pending posts, oldest first
     db_path: PathBuf,
 }
 
@@ -145,11 +359,78 @@ impl NostrDbManager {
         let ndb = Ndb::new(path.to_str().unwrap(), &config)
             .map_err(|e| format!("Failed to open nostrdb: {:?}", e))?;
         
-        Ok(Arc::new(Self {
+        // NIP-05 results are persisted alongside the database
+        let nip05_cache = std::fs::read_to_string(path.join(NIP05_CACHE_FILE))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        
+        let notification_state = std::fs::read_to_string(path.join(NOTIFICATION_STATE_FILE))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        
+        let dm_read_state = std::fs::read_to_string(path.join(DM_READ_STATE_FILE))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        
+        let trust_scores = std::fs::read_to_string(path.join(TRUST_SCORES_FILE))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok());
+        
+        let reaction_usage = std::fs::read_to_string(path.join(REACTION_USAGE_FILE))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        
+        let reports = std::fs::read_to_string(path.join(REPORTS_FILE))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        
+        let follow_queue = std::fs::read_to_string(path.join(FOLLOW_QUEUE_FILE))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        
+        let outbox = std::fs::read_to_string(path.join(OUTBOX_FILE))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        
+        // Search is a convenience - run without it rather than failing startup
+        let search_index = match SearchIndex::open(&path.join(SEARCH_INDEX_DIR)) {
+            Ok(index) => Some(index),
+            Err(e) => {
+                tracing::warn!("Full-text search unavailable: {}", e);
+                None
+            }
+        };
+        
+        let db = Arc::new(Self {
             ndb,
             memory_cache: RwLock::new(MemoryCache::new()),
+            nip05_cache: RwLock::new(nip05_cache),
+            notification_state: RwLock::new(notification_state),
+            dm_read_state: RwLock::new(dm_read_state),
+            search_index,
+            trust_scores: RwLock::new(trust_scores),
+            reaction_usage: RwLock::new(reaction_usage),
+            reports: RwLock::new(reports),
+            follow_queue: RwLock::new(follow_queue),
+            outbox: RwLock::new(outbox),
             db_path: path,
-        }))
+        });
+        // Indexing a large cache takes a while; don't hold up startup for it
+        let backfill = db.clone();
+        if let Err(e) = std::thread::Builder::new()
+            .name("search-backfill".into())
+            .spawn(move || backfill.backfill_search_index())
+        {
+            tracing::warn!("Failed to start search index backfill: {}", e);
+        }
+        Ok(db)
     }
     
     /// Get or initialize the global instance
@@ -212,6 +493,8 @@ impl NostrDbManager {
             cache.insert_event(cached);
         }
         
+        self.index_for_search(event);
+        
         Ok(true)  // New event ingested
     }
     
@@ -256,6 +539,8 @@ impl NostrDbManager {
             cache.insert_profile(profile);
         }
         
+        self.index_for_search(event);
+        
         Ok(())
     }
     
@@ -275,7 +560,9 @@ impl NostrDbManager {
     /// Get an event by ID from memory cache
     pub fn get_event(&self, event_id: &str) -> Option<CachedEvent> {
         let cache = self.memory_cache.read();
-        cache.get_event(event_id).cloned()
+        let event = cache.get_event(event_id).cloned();
+        cache_stats::record(cache_stats::DB_EVENTS, event.is_some());
+        event
     }
     
     /// Check if event exists in memory cache
@@ -287,7 +574,9 @@ impl NostrDbManager {
     /// Get a profile by pubkey from memory cache
     pub fn get_profile(&self, pubkey: &str) -> Option<CachedProfile> {
         let cache = self.memory_cache.read();
-        cache.get_profile(pubkey).cloned()
+        let profile = cache.get_profile(pubkey).cloned();
+        cache_stats::record(cache_stats::DB_PROFILES, profile.is_some());
+        profile
     }
     
     /// Check if we have a fresh profile for this pubkey
@@ -339,6 +628,467 @@ impl NostrDbManager {
         tracing::info!("Cleared in-memory cache");
     }
     
+    /// Query stored events of the given kinds created since a timestamp
+    pub fn query_events(&self, kinds: &[u16], since: i64, limit: usize) -> Result<Vec<Event>, String> {
+        let filter = nostrdb::Filter::new()
+            .kinds(kinds.iter().map(|k| *k as u64))
+            .since(since.max(0) as u64)
+            .limit(limit as u64)
+            .build();
+
+        let txn = Transaction::new(&self.ndb)
+            .map_err(|e| format!("Failed to open transaction: {:?}", e))?;
+
+        let results = self.ndb.query(&txn, &[filter], limit as i32)
+            .map_err(|e| format!("Failed to query events: {:?}", e))?;
+
+        Ok(results
+            .iter()
+            .filter_map(|r| r.note.json().ok())
+            .filter_map(|json| Event::from_json(&json).ok())
+            .collect())
+    }
+
+    /// Add an event to the full-text index (notes and profiles only)
+    fn index_for_search(&self, event: &Event) {
+        if let Some(index) = &self.search_index {
+            if let Err(e) = index.index_event(event) {
+                tracing::warn!("{}", e);
+            }
+        }
+    }
+
+    /// Populate the search index from events already in nostrdb, newest first
+    /// Covers caches created before the index existed. Commits after every page
+    /// and only marks the backfill done once it finished, so an interrupted run
+    /// starts over next launch (re-indexing an event replaces it)
+    fn backfill_search_index(&self) {
+        let Some(index) = &self.search_index else { return };
+        let marker = self.db_path.join(SEARCH_BACKFILL_MARKER);
+        if marker.exists() {
+            return;
+        }
+
+        let mut indexed = 0;
+        let mut until: Option<u64> = None;
+        while indexed < SEARCH_BACKFILL_LIMIT {
+            let page = SEARCH_BACKFILL_PAGE.min(SEARCH_BACKFILL_LIMIT - indexed);
+            let events = match self.query_events_until(&[0, 1], until, page) {
+                Ok(events) => events,
+                Err(e) => {
+                    tracing::warn!("Search index backfill failed: {}", e);
+                    return;
+                }
+            };
+            for event in &events {
+                self.index_for_search(event);
+            }
+            if let Err(e) = index.commit() {
+                tracing::warn!("Search index backfill failed: {}", e);
+                return;
+            }
+            indexed += events.len();
+            tracing::debug!("Search index backfill: {} events", indexed);
+
+            let Some(oldest) = events.iter().map(|e| e.created_at.as_secs()).min() else { break };
+            if events.len() < page {
+                break;
+            }
+            // Events sharing the oldest timestamp may continue on the next page;
+            // only step past it when the whole page had that one timestamp
+            until = Some(if until == Some(oldest) { oldest.saturating_sub(1) } else { oldest });
+        }
+
+        if let Err(e) = std::fs::write(&marker, indexed.to_string()) {
+            tracing::warn!("Failed to mark search index backfill complete: {}", e);
+        }
+        tracing::info!("Indexed {} cached events for search", indexed);
+    }
+
+    /// Stored events of the given kinds created at or before `until`, newest first
+    fn query_events_until(&self, kinds: &[u16], until: Option<u64>, limit: usize) -> Result<Vec<Event>, String> {
+        let mut filter = nostrdb::Filter::new()
+            .kinds(kinds.iter().map(|k| *k as u64))
+            .limit(limit as u64);
+        if let Some(until) = until {
+            filter = filter.until(until);
+        }
+        let filter = filter.build();
+
+        let txn = Transaction::new(&self.ndb)
+            .map_err(|e| format!("Failed to open transaction: {:?}", e))?;
+
+        let results = self.ndb.query(&txn, &[filter], limit as i32)
+            .map_err(|e| format!("Failed to query events: {:?}", e))?;
+
+        Ok(results
+            .iter()
+            .filter_map(|r| r.note.json().ok())
+            .filter_map(|json| Event::from_json(&json).ok())
+            .collect())
+    }
+
+    /// Whether full-text search over the local cache is available
+    pub fn has_search_index(&self) -> bool {
+        self.search_index.is_some()
+    }
+
+    /// Full-text search over cached text notes, newest first
+    /// `offset` skips results already shown for pagination
+    pub fn search_notes_indexed(&self, query: &str, since: Option<i64>, limit: usize, offset: usize) -> Result<Vec<Event>, String> {
+        let index = self.search_index.as_ref().ok_or("Search index unavailable")?;
+        index.search_notes(query, since, limit, offset)
+    }
+
+    /// Full-text search over cached profiles (name, display name, NIP-05, about)
+    pub fn search_profiles_indexed(&self, query: &str, limit: usize) -> Result<Vec<Event>, String> {
+        let index = self.search_index.as_ref().ok_or("Search index unavailable")?;
+        index.search_profiles(query, limit)
+    }
+
+    /// Query stored events of the given kinds by specific authors
+    pub fn query_author_events(&self, kinds: &[u16], authors: &[PublicKey], limit: usize) -> Result<Vec<Event>, String> {
+        if authors.is_empty() {
+            return Ok(Vec::new());
+        }
+        let filter = nostrdb::Filter::new()
+            .kinds(kinds.iter().map(|k| *k as u64))
+            .authors(authors.iter().map(|pk| pk.as_bytes()))
+            .limit(limit as u64)
+            .build();
+
+        let txn = Transaction::new(&self.ndb)
+            .map_err(|e| format!("Failed to open transaction: {:?}", e))?;
+
+        let results = self.ndb.query(&txn, &[filter], limit as i32)
+            .map_err(|e| format!("Failed to query events: {:?}", e))?;
+
+        Ok(results
+            .iter()
+            .filter_map(|r| r.note.json().ok())
+            .filter_map(|json| Event::from_json(&json).ok())
+            .collect())
+    }
+
+    /// Get a cached NIP-05 verification result, if still fresh
+    pub fn get_nip05_status(&self, nip05: &str, pubkey: &str) -> Option<bool> {
+        let now = chrono::Utc::now().timestamp();
+        let cache = self.nip05_cache.read();
+        cache.get(&nip05_cache_key(nip05, pubkey))
+            .filter(|s| !s.is_expired(now))
+            .map(|s| s.verified)
+    }
+    
+    /// Store a NIP-05 verification result (positive or negative)
+    pub fn set_nip05_status(&self, nip05: &str, pubkey: &str, verified: bool) {
+        let now = chrono::Utc::now().timestamp();
+        let json = {
+            let mut cache = self.nip05_cache.write();
+            cache.insert(nip05_cache_key(nip05, pubkey), Nip05Status { verified, checked_at: now });
+            // Drop expired entries so the file doesn't grow forever
+            cache.retain(|_, s| !s.is_expired(now));
+            serde_json::to_string(&*cache).unwrap_or_default()
+        };
+        
+        if let Err(e) = std::fs::write(self.db_path.join(NIP05_CACHE_FILE), json) {
+            tracing::warn!("Failed to persist NIP-05 cache: {}", e);
+        }
+    }
+    
+    /// Get a user's notification read state
+    pub fn get_notification_state(&self, pubkey: &str) -> NotificationReadState {
+        self.notification_state.read().get(pubkey).cloned().unwrap_or_default()
+    }
+    
+    /// Persist notifications the user opened
+    pub fn mark_notifications_read(&self, pubkey: &str, ids: &[(String, i64)]) {
+        self.update_notification_state(pubkey, |state| state.mark_read(ids));
+    }
+    
+    /// Persist "mark all read" up to the newest notification shown
+    pub fn mark_all_notifications_read(&self, pubkey: &str, up_to: i64) {
+        self.update_notification_state(pubkey, |state| state.mark_all_read(up_to));
+    }
+    
+    /// Record who has the user in their contact list; see `NotificationReadState::record_followers`
+    /// Only writes the state file when a follower was added
+    pub fn record_followers(&self, pubkey: &str, lists: &[(String, i64)]) -> Vec<(String, i64)> {
+        let state = self.get_notification_state(pubkey);
+        let changed = !state.followers_seeded
+            || lists.iter().any(|(follower, _)| !state.followers.contains_key(follower));
+        if !changed {
+            let mut state = state;
+            return state.record_followers(lists);
+        }
+        
+        let mut follows = Vec::new();
+        self.update_notification_state(pubkey, |state| follows = state.record_followers(lists));
+        follows
+    }
+    
+    fn update_notification_state(&self, pubkey: &str, f: impl FnOnce(&mut NotificationReadState)) {
+        let json = {
+            let mut states = self.notification_state.write();
+            f(states.entry(pubkey.to_string()).or_default());
+            serde_json::to_string(&*states).unwrap_or_default()
+        };
+        
+        let path = self.db_path.join(NOTIFICATION_STATE_FILE);
+        let tmp_path = path.with_extension("json.tmp");
+        let result = std::fs::write(&tmp_path, json)
+            .and_then(|_| std::fs::rename(&tmp_path, &path));
+        if let Err(e) = result {
+            tracing::warn!("Failed to persist notification state: {}", e);
+        }
+    }
+    
+    /// Get a user's DM read state
+    /// The first call for a user starts tracking from now, so existing messages aren't unread
+    pub fn get_dm_read_state(&self, pubkey: &str) -> DmReadState {
+        if let Some(state) = self.dm_read_state.read().get(pubkey) {
+            return state.clone();
+        }
+        
+        let mut seeded = DmReadState::default();
+        self.update_dm_read_state(pubkey, |state| {
+            if state.baseline == 0 {
+                state.baseline = chrono::Utc::now().timestamp();
+            }
+            seeded = state.clone();
+        });
+        seeded
+    }
+    
+    /// Persist that a conversation was read up to a message time
+    pub fn mark_dm_read(&self, pubkey: &str, peer: &str, up_to: i64) {
+        if up_to <= self.get_dm_read_state(pubkey).last_read(peer) {
+            return;
+        }
+        self.update_dm_read_state(pubkey, |state| state.mark_read(peer, up_to));
+    }
+    
+    fn update_dm_read_state(&self, pubkey: &str, f: impl FnOnce(&mut DmReadState)) {
+        let json = {
+            let mut states = self.dm_read_state.write();
+            f(states.entry(pubkey.to_string()).or_default());
+            serde_json::to_string(&*states).unwrap_or_default()
+        };
+        
+        let path = self.db_path.join(DM_READ_STATE_FILE);
+        let tmp_path = path.with_extension("json.tmp");
+        let result = std::fs::write(&tmp_path, json)
+            .and_then(|_| std::fs::rename(&tmp_path, &path));
+        if let Err(e) = result {
+            tracing::warn!("Failed to persist DM read state: {}", e);
+        }
+    }
+    
+    /// Count a reaction the user sent
+    pub fn record_reaction(&self, pubkey: &str, emoji: &str, url: Option<String>) {
+        let now = chrono::Utc::now().timestamp();
+        let json = {
+            let mut all = self.reaction_usage.write();
+            let usage = all.entry(pubkey.to_string()).or_default();
+            let entry = usage.entry(emoji.to_string()).or_insert_with(|| ReactionUsage {
+                emoji: emoji.to_string(),
+                ..Default::default()
+            });
+            entry.count += 1;
+            entry.last_used = now;
+            if url.is_some() {
+                entry.url = url;
+            }
+            
+            // Forget the least recently used reactions when over the cap
+            if usage.len() > MAX_REACTION_USAGE {
+                let mut entries: Vec<ReactionUsage> = usage.drain().map(|(_, u)| u).collect();
+                entries.sort_by(|a, b| b.last_used.cmp(&a.last_used));
+                entries.truncate(MAX_REACTION_USAGE);
+                *usage = entries.into_iter().map(|u| (u.emoji.clone(), u)).collect();
+            }
+            serde_json::to_string(&*all).unwrap_or_default()
+        };
+        
+        let path = self.db_path.join(REACTION_USAGE_FILE);
+        let tmp_path = path.with_extension("json.tmp");
+        let result = std::fs::write(&tmp_path, json)
+            .and_then(|_| std::fs::rename(&tmp_path, &path));
+        if let Err(e) = result {
+            tracing::warn!("Failed to persist reaction usage: {}", e);
+        }
+    }
+    
+    /// The user's reactions, most recently used first
+    pub fn recent_reactions(&self, pubkey: &str, limit: usize) -> Vec<ReactionUsage> {
+        let mut usage = self.reaction_usage_for(pubkey);
+        usage.sort_by(|a, b| b.last_used.cmp(&a.last_used));
+        usage.truncate(limit);
+        usage
+    }
+    
+    /// The user's reactions, most used first (ties broken by recency)
+    pub fn frequent_reactions(&self, pubkey: &str, limit: usize) -> Vec<ReactionUsage> {
+        let mut usage = self.reaction_usage_for(pubkey);
+        usage.sort_by(|a, b| b.count.cmp(&a.count).then(b.last_used.cmp(&a.last_used)));
+        usage.truncate(limit);
+        usage
+    }
+    
+    fn reaction_usage_for(&self, pubkey: &str) -> Vec<ReactionUsage> {
+        self.reaction_usage.read()
+            .get(pubkey)
+            .map(|usage| usage.values().cloned().collect())
+            .unwrap_or_default()
+    }
+    
+    /// Content a user reported
+    pub fn get_reported_content(&self, pubkey: &str) -> ReportedContent {
+        self.reports.read().get(pubkey).cloned().unwrap_or_default()
+    }
+    
+    /// Remember a reported note and/or user so it stays hidden
+    pub fn record_report(&self, pubkey: &str, note_id: Option<&str>, reported_pubkey: Option<&str>) {
+        let now = chrono::Utc::now().timestamp();
+        let json = {
+            let mut all = self.reports.write();
+            let reported = all.entry(pubkey.to_string()).or_default();
+            if let Some(id) = note_id {
+                reported.notes.insert(id.to_string(), now);
+            }
+            if let Some(pk) = reported_pubkey {
+                reported.pubkeys.insert(pk.to_string(), now);
+            }
+            serde_json::to_string(&*all).unwrap_or_default()
+        };
+        
+        let path = self.db_path.join(REPORTS_FILE);
+        let tmp_path = path.with_extension("json.tmp");
+        let result = std::fs::write(&tmp_path, json)
+            .and_then(|_| std::fs::rename(&tmp_path, &path));
+        if let Err(e) = result {
+            tracing::warn!("Failed to persist reports: {}", e);
+        }
+    }
+    
+    /// The user's queued follows, oldest first
+    pub fn get_follow_queue(&self, pubkey: &str) -> Vec<QueuedFollow> {
+        self.follow_queue.read().get(pubkey).cloned().unwrap_or_default()
+    }
+    
+    /// Add a follow to the user's review queue; false if it was already queued
+    pub fn queue_follow(&self, pubkey: &str, entry: QueuedFollow) -> bool {
+        {
+            let mut all = self.follow_queue.write();
+            let queue = all.entry(pubkey.to_string()).or_default();
+            if queue.iter().any(|q| q.pubkey == entry.pubkey) {
+                return false;
+            }
+            queue.push(entry);
+        }
+        self.persist_follow_queue();
+        true
+    }
+    
+    /// Drop queued follows (approved or dismissed); returns how many were removed
+    pub fn remove_queued_follows(&self, pubkey: &str, pubkeys: &[String]) -> usize {
+        let removed = {
+            let mut all = self.follow_queue.write();
+            let Some(queue) = all.get_mut(pubkey) else { return 0 };
+            let before = queue.len();
+            queue.retain(|q| !pubkeys.contains(&q.pubkey));
+            before - queue.len()
+        };
+        if removed > 0 {
+            self.persist_follow_queue();
+        }
+        removed
+    }
+    
+    fn persist_follow_queue(&self) {
+        let json = serde_json::to_string(&*self.follow_queue.read()).unwrap_or_default();
+        let path = self.db_path.join(FOLLOW_QUEUE_FILE);
+        let tmp_path = path.with_extension("json.tmp");
+        let result = std::fs::write(&tmp_path, json)
+            .and_then(|_| std::fs::rename(&tmp_path, &path));
+        if let Err(e) = result {
+            tracing::warn!("Failed to persist follow queue: {}", e);
+        }
+    }
+    
+    /// The user's pending posts, oldest first
+    pub fn get_outbox(&self, pubkey: &str) -> Vec<OutboxEntry> {
+        self.outbox.read().get(pubkey).cloned().unwrap_or_default()
+    }
+    
+    /// Add a pending post, or replace the stored copy with the same id
+    pub fn put_outbox_entry(&self, pubkey: &str, entry: OutboxEntry) {
+        {
+            let mut all = self.outbox.write();
+            let entries = all.entry(pubkey.to_string()).or_default();
+            match entries.iter_mut().find(|e| e.id == entry.id) {
+                Some(existing) => *existing = entry,
+                None => entries.push(entry),
+            }
+        }
+        self.persist_outbox();
+    }
+    
+    /// Drop a pending post (published or discarded); false if it wasn't there
+    pub fn remove_outbox_entry(&self, pubkey: &str, id: &str) -> bool {
+        let removed = {
+            let mut all = self.outbox.write();
+            let Some(entries) = all.get_mut(pubkey) else { return false };
+            let before = entries.len();
+            entries.retain(|e| e.id != id);
+            before != entries.len()
+        };
+        if removed {
+            self.persist_outbox();
+        }
+        removed
+    }
+    
+    fn persist_outbox(&self) {
+        let json = serde_json::to_string(&*self.outbox.read()).unwrap_or_default();
+        let path = self.db_path.join(OUTBOX_FILE);
+        let tmp_path = path.with_extension("json.tmp");
+        let result = std::fs::write(&tmp_path, json)
+            .and_then(|_| std::fs::rename(&tmp_path, &path));
+        if let Err(e) = result {
+            tracing::warn!("Failed to persist outbox: {}", e);
+        }
+    }
+    
+    /// Whether web-of-trust scores have been computed
+    pub fn has_trust_scores(&self) -> bool {
+        self.trust_scores.read().is_some()
+    }
+    
+    /// Hops from the user to this pubkey, None if outside the scored graph
+    pub fn trust_distance(&self, pubkey: &str) -> Option<u8> {
+        self.trust_scores.read().as_ref().and_then(|scores| scores.distances.get(pubkey).copied())
+    }
+    
+    /// Owner, computation time and number of scored accounts
+    pub fn trust_scores_info(&self) -> Option<(String, i64, usize)> {
+        self.trust_scores.read().as_ref()
+            .map(|scores| (scores.owner.clone(), scores.computed_at, scores.distances.len()))
+    }
+    
+    /// Replace and persist web-of-trust scores
+    pub fn set_trust_scores(&self, scores: TrustScores) {
+        let json = serde_json::to_string(&scores).unwrap_or_default();
+        *self.trust_scores.write() = Some(scores);
+        
+        let path = self.db_path.join(TRUST_SCORES_FILE);
+        let tmp_path = path.with_extension("json.tmp");
+        let result = std::fs::write(&tmp_path, json)
+            .and_then(|_| std::fs::rename(&tmp_path, &path));
+        if let Err(e) = result {
+            tracing::warn!("Failed to persist trust scores: {}", e);
+        }
+    }
+    
     /// Get database statistics
     pub fn stats(&self) -> String {
         let cache = self.memory_cache.read();
@@ -349,6 +1099,41 @@ impl NostrDbManager {
             cache.profiles.len()
         )
     }
+    
+    /// Memory cache stats for diagnostics (events, profiles)
+    pub fn cache_stats(&self) -> Vec<cache_stats::CacheStats> {
+        let cache = self.memory_cache.read();
+        let event_bytes: usize = cache.events.values()
+            .map(|e| e.content.len() + e.tags_json.len() + e.id.len() + e.pubkey.len())
+            .sum();
+        let profile_bytes: usize = cache.profiles.values()
+            .map(|p| {
+                p.pubkey.len()
+                    + [&p.name, &p.display_name, &p.picture, &p.nip05, &p.about].iter()
+                        .map(|f| f.as_ref().map_or(0, |v| v.len()))
+                        .sum::<usize>()
+            })
+            .sum();
+        vec![
+            cache_stats::CacheStats::new(cache_stats::DB_EVENTS, cache.events.len(), event_bytes as u64),
+            cache_stats::CacheStats::new(cache_stats::DB_PROFILES, cache.profiles.len(), profile_bytes as u64),
+        ]
+    }
+    
+    /// Database directory and (file count, bytes on disk)
+    pub fn disk_usage(&self) -> (PathBuf, usize, u64) {
+        let (files, bytes) = cache_stats::dir_usage(&self.db_path);
+        (self.db_path.clone(), files, bytes)
+    }
+    
+    /// Capacity of the in-memory event cache
+    pub fn memory_cache_capacity(&self) -> usize {
+        MAX_MEMORY_CACHE_SIZE
+    }
+}
+
+fn nip05_cache_key(nip05: &str, pubkey: &str) -> String {
+    format!("{}|{}", nip05.trim().to_lowercase(), pubkey)
 }
 
 // ============================================================================
@@ -408,4 +1193,57 @@ mod tests {
         assert!(cache.has_event("event_0"));
         assert!(cache.has_event("event_9"));
     }
+    
+    #[test]
+    fn test_notification_read_state() {
+        let mut state = NotificationReadState::default();
+        assert!(!state.is_read("a", 100));
+        
+        state.mark_read(&[("a".to_string(), 100), ("b".to_string(), 200)]);
+        assert!(state.is_read("a", 100));
+        assert!(!state.is_read("c", 150));
+        
+        // Mark all read covers older ids and prunes the explicit list
+        state.mark_all_read(150);
+        assert!(state.is_read("c", 150));
+        assert!(!state.is_read("d", 151));
+        assert!(state.is_read("b", 200));
+        assert_eq!(state.read_ids.len(), 1);
+        
+        // last_seen never moves backwards
+        state.mark_all_read(50);
+        assert_eq!(state.last_seen, 150);
+    }
+    
+    #[test]
+    fn test_record_followers() {
+        let mut state = NotificationReadState::default();
+        
+        // The first check only seeds existing followers
+        assert!(state.record_followers(&[("a".to_string(), 100)]).is_empty());
+        
+        // Later followers are reported with the time they were first seen
+        let follows = state.record_followers(&[("a".to_string(), 300), ("b".to_string(), 200)]);
+        assert_eq!(follows, vec![("b".to_string(), 200)]);
+        
+        // A follower updating their list keeps the original time
+        let follows = state.record_followers(&[("b".to_string(), 400)]);
+        assert_eq!(follows, vec![("b".to_string(), 200)]);
+    }
+    
+    #[test]
+    fn test_dm_read_state() {
+        let mut state = DmReadState { baseline: 100, ..Default::default() };
+        assert_eq!(state.last_read("a"), 100);
+        
+        state.mark_read("a", 200);
+        assert_eq!(state.last_read("a"), 200);
+        assert_eq!(state.last_read("b"), 100);
+        
+        // Never moves backwards, and nothing is stored below the baseline
+        state.mark_read("a", 150);
+        state.mark_read("b", 50);
+        assert_eq!(state.last_read("a"), 200);
+        assert!(!state.read_up_to.contains_key("b"));
+    }
 }
@@ -0,0 +1,183 @@
+//! Discovery - trending notes and hashtags from a sample of recent activity
+//!
+//! Samples the relays for recent notes, reactions (kind 7) and zap receipts
+//! (kind 9735) inside a time window, then ranks notes by how many different
+//! people reacted plus how many zapped and for how much. Counting reactors
+//! rather than reactions keeps one account liking a note a hundred times
+//! from pushing it up. Hashtags rank by the number of distinct authors using
+//! them, for the same reason.
+
+use std::collections::{HashMap, HashSet};
+use nostr_sdk::prelude::*;
+
+use super::relay::fetch_timeout;
+use super::report;
+use super::wot;
+use super::zap_history::{self, ZapDirection};
+
+/// Time windows offered, in hours
+pub const WINDOW_HOURS: &[u32] = &[1, 6, 24];
+
+pub const DEFAULT_WINDOW_HOURS: u32 = 6;
+
+/// Events sampled per kind
+const NOTE_SAMPLE: usize = 500;
+const REACTION_SAMPLE: usize = 2000;
+const ZAP_SAMPLE: usize = 1000;
+
+/// Ranked notes and hashtags kept
+const MAX_TRENDING_NOTES: usize = 50;
+const MAX_TRENDING_HASHTAGS: usize = 30;
+
+/// A hashtag needs this many authors to trend
+const MIN_HASHTAG_AUTHORS: usize = 2;
+
+/// A note with its engagement in the window
+#[derive(Debug, Clone)]
+pub struct TrendingNote {
+    pub event: Event,
+    /// Distinct pubkeys that reacted (dislikes excluded)
+    pub reactors: usize,
+    /// Distinct pubkeys that zapped
+    pub zappers: usize,
+    pub zap_sats: u64,
+    pub score: f64,
+}
+
+/// A hashtag used in the sampled notes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrendingHashtag {
+    /// Lowercase, without '#'
+    pub tag: String,
+    pub notes: usize,
+    pub authors: usize,
+}
+
+#[derive(Default)]
+struct Engagement {
+    reactors: HashSet<PublicKey>,
+    zappers: HashSet<String>,
+    zap_sats: u64,
+}
+
+impl Engagement {
+    /// Reactors count once each, zappers twice, and sats add slowly so a
+    /// single big zap can't outrank broad interest
+    fn score(&self) -> f64 {
+        self.reactors.len() as f64
+            + 2.0 * self.zappers.len() as f64
+            + (self.zap_sats as f64).sqrt() / 10.0
+    }
+}
+
+/// The note a reaction is for: the last `e` tag (NIP-25)
+fn reaction_target(event: &Event) -> Option<EventId> {
+    event.tags.iter()
+        .filter_map(|tag| {
+            let values = tag.as_slice();
+            (values.first().map(|s| s.as_str()) == Some("e"))
+                .then(|| values.get(1).and_then(|id| EventId::from_hex(id).ok()))
+                .flatten()
+        })
+        .last()
+}
+
+async fn fetch(client: &Client, filter: Filter) -> Events {
+    let _permit = crate::core::limits::relay_fetch_permit().await;
+    match client.fetch_events(filter, fetch_timeout()).await {
+        Ok(events) => events,
+        Err(e) => {
+            tracing::warn!("Discovery sample failed: {}", e);
+            Events::default()
+        }
+    }
+}
+
+fn visible(event: &Event) -> bool {
+    let pubkey = event.pubkey.to_hex();
+    !report::is_hidden(&event.id.to_hex(), &pubkey) && !wot::is_hidden(&pubkey)
+}
+
+/// Sample the last `window_hours` of activity and rank it
+pub async fn sample(client: &Client, window_hours: u32) -> (Vec<TrendingNote>, Vec<TrendingHashtag>) {
+    let since = Timestamp::from(Timestamp::now().as_secs().saturating_sub(window_hours as u64 * 3600));
+
+    let (notes, reactions, zaps) = tokio::join!(
+        fetch(client, Filter::new().kind(Kind::TextNote).since(since).limit(NOTE_SAMPLE)),
+        fetch(client, Filter::new().kind(Kind::Reaction).since(since).limit(REACTION_SAMPLE)),
+        fetch(client, Filter::new().kind(Kind::ZapReceipt).since(since).limit(ZAP_SAMPLE)),
+    );
+
+    let mut engagement: HashMap<EventId, Engagement> = HashMap::new();
+    for reaction in reactions.iter().filter(|e| e.content.trim() != "-") {
+        if let Some(target) = reaction_target(reaction) {
+            engagement.entry(target).or_default().reactors.insert(reaction.pubkey);
+        }
+    }
+    for receipt in zaps.iter() {
+        let Some(zap) = zap_history::from_receipt(receipt, ZapDirection::Incoming) else { continue };
+        let Some(target) = zap.note_id.as_deref().and_then(|id| EventId::from_hex(id).ok()) else { continue };
+        let entry = engagement.entry(target).or_default();
+        entry.zappers.insert(zap.counterparty.unwrap_or_else(|| receipt.id.to_hex()));
+        entry.zap_sats += zap.amount_sats;
+    }
+
+    // Engaged notes outside the note sample are fetched by id, best first
+    let mut by_id: HashMap<EventId, Event> = notes.iter().map(|e| (e.id, e.clone())).collect();
+    let mut ranked: Vec<(EventId, f64)> = engagement.iter().map(|(id, e)| (*id, e.score())).collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let missing: Vec<EventId> = ranked.iter()
+        .map(|(id, _)| *id)
+        .filter(|id| !by_id.contains_key(id))
+        .take(MAX_TRENDING_NOTES * 2)
+        .collect();
+    if !missing.is_empty() {
+        let found = fetch(client, Filter::new().ids(missing.clone()).kind(Kind::TextNote).limit(missing.len())).await;
+        by_id.extend(found.into_iter().map(|e| (e.id, e)));
+    }
+
+    let trending_notes: Vec<TrendingNote> = ranked.iter()
+        .filter_map(|(id, score)| {
+            let event = by_id.get(id).filter(|e| visible(e))?;
+            let stats = engagement.get(id)?;
+            Some(TrendingNote {
+                event: event.clone(),
+                reactors: stats.reactors.len(),
+                zappers: stats.zappers.len(),
+                zap_sats: stats.zap_sats,
+                score: *score,
+            })
+        })
+        .take(MAX_TRENDING_NOTES)
+        .collect();
+
+    (trending_notes, trending_hashtags(notes.iter().filter(|e| visible(e))))
+}
+
+/// Hashtags ranked by distinct authors, then by note count
+fn trending_hashtags<'a>(notes: impl Iterator<Item = &'a Event>) -> Vec<TrendingHashtag> {
+    let mut tags: HashMap<String, (usize, HashSet<PublicKey>)> = HashMap::new();
+    for note in notes {
+        let mut seen = HashSet::new();
+        for tag in note.tags.iter() {
+            let values = tag.as_slice();
+            if values.first().map(|s| s.as_str()) != Some("t") {
+                continue;
+            }
+            let Some(tag) = values.get(1).and_then(|v| super::interests::normalize(v)) else { continue };
+            if seen.insert(tag.clone()) {
+                let entry = tags.entry(tag).or_default();
+                entry.0 += 1;
+                entry.1.insert(note.pubkey);
+            }
+        }
+    }
+
+    let mut ranked: Vec<TrendingHashtag> = tags.into_iter()
+        .filter(|(_, (_, authors))| authors.len() >= MIN_HASHTAG_AUTHORS)
+        .map(|(tag, (notes, authors))| TrendingHashtag { tag, notes, authors: authors.len() })
+        .collect();
+    ranked.sort_by(|a, b| b.authors.cmp(&a.authors).then(b.notes.cmp(&a.notes)).then(a.tag.cmp(&b.tag)));
+    ranked.truncate(MAX_TRENDING_HASHTAGS);
+    ranked
+}
@@ -2,6 +2,12 @@
 //!
 //! NIP-04: Legacy encrypted direct messages (kind 4)
 //! NIP-17: Private Direct Messages with Gift Wrap (kind 1059)
+//!
+//! Typing indicators and read receipts are ephemeral presence events in the
+//! spirit of NIP-38 user statuses: kind 20315, content NIP-44 encrypted to the
+//! peer and an expiration tag so relays drop them quickly. Their `p` tag shows
+//! who is talking to whom, which NIP-17 conversations exist to hide, so they
+//! are only sent in NIP-04 conversations, whose kind 4 events show it anyway.
 
 #![allow(dead_code)]  // Planned infrastructure for future integration
 
@@ -10,6 +16,7 @@ use std::collections::HashMap;
 use std::time::Duration;
 use std::fs;
 use std::path::PathBuf;
+use crate::nostr::database::DmReadState;
 
 /// DM Protocol type
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -220,7 +227,7 @@ impl DmManager {
     /// Get all conversations sorted by last message time
     pub fn get_conversations(&self) -> Vec<&DmConversation> {
         let mut convos: Vec<&DmConversation> = self.conversations.values().collect();
-        convos.sort_by(|a, b| b.last_message_at.cmp(&a.last_message_at));
+        convos.sort_by(|a, b| b.last_message_at.cmp(&a.last_message_at).then_with(|| a.peer_pubkey.cmp(&b.peer_pubkey)));
         convos
     }
     
@@ -245,7 +252,7 @@ impl DmManager {
                 }
             })
             .collect();
-        convos.sort_by(|a, b| b.last_message_at.cmp(&a.last_message_at));
+        convos.sort_by(|a, b| b.last_message_at.cmp(&a.last_message_at).then_with(|| a.peer_pubkey.cmp(&b.peer_pubkey)));
         convos
     }
     
@@ -300,7 +307,8 @@ impl DmManager {
     }
     
     /// Add a message to a conversation
-    pub fn add_message(&mut self, msg: DmMessage) {
+    /// Returns whether the message was new
+    pub fn add_message(&mut self, msg: DmMessage) -> bool {
         let peer_pubkey = if msg.is_outgoing {
             msg.recipient_pubkey.clone()
         } else {
@@ -321,11 +329,13 @@ impl DmManager {
         }
         
         // Add message if not already present
-        if !convo.messages.iter().any(|m| m.id == msg.id) {
-            convo.messages.push(msg);
-            // Sort messages by time
-            convo.messages.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        if convo.messages.iter().any(|m| m.id == msg.id) {
+            return false;
         }
+        convo.messages.push(msg);
+        // Sort messages by time
+        convo.messages.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        true
     }
     
     /// Update profile info for a conversation
@@ -342,10 +352,41 @@ impl DmManager {
     }
     
     /// Mark conversation as read
-    pub fn mark_read(&mut self, peer_pubkey: &str) {
-        if let Some(convo) = self.conversations.get_mut(peer_pubkey) {
-            convo.unread_count = 0;
+    /// Returns the time of its newest message, to persist as read
+    pub fn mark_read(&mut self, peer_pubkey: &str) -> Option<i64> {
+        let convo = self.conversations.get_mut(peer_pubkey)?;
+        convo.unread_count = 0;
+        Some(convo.last_message_at)
+    }
+    
+    /// Recount unread messages from the persisted read state
+    /// Incoming messages older than our latest reply count as read too
+    /// Returns the peers whose count changed
+    pub fn recount_unread(&mut self, state: &DmReadState) -> Vec<String> {
+        let mut changed = Vec::new();
+        for convo in self.conversations.values_mut() {
+            let last_outgoing = convo.messages.iter()
+                .filter(|m| m.is_outgoing)
+                .map(|m| m.created_at)
+                .max()
+                .unwrap_or(0);
+            let last_read = state.last_read(&convo.peer_pubkey).max(last_outgoing);
+            let unread = convo.messages.iter()
+                .filter(|m| !m.is_outgoing && m.created_at > last_read)
+                .count() as u32;
+            if unread != convo.unread_count {
+                convo.unread_count = unread;
+                changed.push(convo.peer_pubkey.clone());
+            }
         }
+        changed
+    }
+    
+    /// Peer pubkeys of the conversations in a category, in list order
+    pub fn conversation_order(&self, category: Option<ConversationCategory>) -> Vec<String> {
+        self.get_conversations_by_category(category).iter()
+            .map(|c| c.peer_pubkey.clone())
+            .collect()
     }
     
     /// Clear all data
@@ -464,6 +505,72 @@ pub fn create_nip17_rumor(
         .build(PublicKey::from_slice(&[0; 32]).unwrap())
 }
 
+/// Ephemeral kind for DM typing/read presence (relays don't store 20000-29999)
+pub const PRESENCE_KIND: u16 = 20315;
+
+/// How long a presence event is valid; a typing indicator is shown no longer than this
+pub const PRESENCE_TTL_SECS: u64 = 15;
+
+/// Typing or read state sent to a conversation peer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresenceState {
+    Typing,
+    /// Read every message up to this time
+    Read(i64),
+}
+
+impl PresenceState {
+    fn to_json(self) -> String {
+        match self {
+            PresenceState::Typing => serde_json::json!({ "state": "typing" }),
+            PresenceState::Read(up_to) => serde_json::json!({ "state": "read", "until": up_to }),
+        }.to_string()
+    }
+    
+    fn from_json(json: &str) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_str(json).ok()?;
+        match value.get("state")?.as_str()? {
+            "typing" => Some(PresenceState::Typing),
+            "read" => Some(PresenceState::Read(value.get("until")?.as_i64()?)),
+            _ => None,
+        }
+    }
+}
+
+/// Build and sign a presence event for a peer
+pub fn create_presence_event(keys: &Keys, peer: &PublicKey, state: PresenceState) -> Result<Event, String> {
+    let content = nip44::encrypt(keys.secret_key(), peer, state.to_json(), nip44::Version::default())
+        .map_err(|e| format!("Encryption failed: {}", e))?;
+    let expiration = Timestamp::from(Timestamp::now().as_secs() + PRESENCE_TTL_SECS);
+    
+    EventBuilder::new(Kind::Custom(PRESENCE_KIND), content)
+        .tag(Tag::public_key(*peer))
+        .tag(Tag::expiration(expiration))
+        .sign_with_keys(keys)
+        .map_err(|e| format!("Failed to sign: {}", e))
+}
+
+/// Decrypt a presence event addressed to us: (sender, state)
+/// Expired events are ignored
+pub fn parse_presence_event(event: &Event, keys: &Keys) -> Option<(PublicKey, PresenceState)> {
+    if event.kind != Kind::Custom(PRESENCE_KIND) || event.is_expired() {
+        return None;
+    }
+    // Older builds sent NIP-04
+    let json = nip44::decrypt(keys.secret_key(), &event.pubkey, &event.content)
+        .or_else(|_| nip04::decrypt(keys.secret_key(), &event.pubkey, &event.content))
+        .ok()?;
+    Some((event.pubkey, PresenceState::from_json(&json)?))
+}
+
+/// Filter for presence events sent to the user from now on
+pub fn presence_filter(user_pubkey: &PublicKey) -> Filter {
+    Filter::new()
+        .kind(Kind::Custom(PRESENCE_KIND))
+        .pubkey(*user_pubkey)
+        .since(Timestamp::now())
+}
+
 /// Helper to truncate message for preview
 fn truncate_message(content: &str, max_len: usize) -> String {
     if content.len() <= max_len {
@@ -0,0 +1,340 @@
+//! DM attachments - images sent in direct messages
+//!
+//! Files are uploaded to the user's Blossom server and the DM carries a link.
+//! With encryption on, the file is sealed with ChaCha20-Poly1305 under a fresh
+//! per-message key before upload. Key, nonce and the original MIME type ride in
+//! the link's fragment, which is never sent to the server and only travels
+//! inside the encrypted DM, so the server stores an opaque blob. Clients that
+//! don't understand the fragment just show the link.
+//!
+//! Decrypted files never go into the shared media cache. They are written,
+//! readable only by the user, to a session directory (under the runtime dir
+//! when there is one) that is removed on logout, on exit and at startup.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use chacha20poly1305::{
+    aead::{Aead, KeyInit},
+    ChaCha20Poly1305, Nonce,
+};
+use nostr_sdk::prelude::*;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::core::media_cache;
+use crate::nostr::blossom;
+
+/// Fragment marker for encrypted attachments
+const ENCRYPTION_SCHEME: &str = "chacha20poly1305";
+
+/// Extensions shown inline when the link is not encrypted
+const IMAGE_EXTENSIONS: [&str; 6] = ["jpg", "jpeg", "png", "gif", "webp", "avif"];
+
+/// Key material for an encrypted attachment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Encryption {
+    key: [u8; 32],
+    nonce: [u8; 12],
+}
+
+/// An attachment link found in (or made for) a DM
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attachment {
+    /// Blob URL without the fragment
+    pub url: String,
+    pub encryption: Option<Encryption>,
+    /// Type of the original file (the encrypted blob itself is opaque)
+    pub mime_type: Option<String>,
+}
+
+impl Attachment {
+    /// Link to put in the DM text
+    pub fn to_link(&self) -> String {
+        let Some(enc) = &self.encryption else {
+            return self.url.clone();
+        };
+        let mut link = format!(
+            "{}#enc={}&key={}&nonce={}",
+            self.url, ENCRYPTION_SCHEME, hex::encode(enc.key), hex::encode(enc.nonce)
+        );
+        if let Some(mime) = &self.mime_type {
+            link.push_str(&format!("&m={}", urlencoding::encode(mime)));
+        }
+        link
+    }
+
+    /// Parse a link; plain links only count if they look like images
+    pub fn parse(link: &str) -> Option<Self> {
+        if !link.starts_with("https://") && !link.starts_with("http://") {
+            return None;
+        }
+        let (url, fragment) = match link.split_once('#') {
+            Some((url, fragment)) => (url, Some(fragment)),
+            None => (link, None),
+        };
+
+        let params: Vec<(&str, &str)> = fragment.unwrap_or("")
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .collect();
+        let param = |name: &str| params.iter().find(|(k, _)| *k == name).map(|(_, v)| *v);
+
+        if param("enc") == Some(ENCRYPTION_SCHEME) {
+            let key = hex::decode(param("key")?).ok()?.try_into().ok()?;
+            let nonce = hex::decode(param("nonce")?).ok()?.try_into().ok()?;
+            let mime_type = param("m")
+                .and_then(|m| urlencoding::decode(m).ok())
+                .map(|m| m.into_owned());
+            return Some(Self {
+                url: url.to_string(),
+                encryption: Some(Encryption { key, nonce }),
+                mime_type,
+            });
+        }
+
+        let ext = Path::new(url::Url::parse(url).ok()?.path())
+            .extension()?
+            .to_string_lossy()
+            .to_lowercase();
+        IMAGE_EXTENSIONS.contains(&ext.as_str()).then(|| Self {
+            url: url.to_string(),
+            encryption: None,
+            mime_type: mime_guess::from_ext(&ext).first().map(|m| m.to_string()),
+        })
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.encryption.is_some()
+    }
+
+    /// File extension for the decrypted file
+    fn extension(&self) -> String {
+        self.mime_type.as_deref()
+            .and_then(|m| m.split('/').nth(1))
+            .filter(|sub| !sub.is_empty() && sub.chars().all(|c| c.is_ascii_alphanumeric()))
+            .unwrap_or("bin")
+            .to_string()
+    }
+}
+
+/// Attachment links in a message, in order
+pub fn find_attachments(content: &str) -> Vec<(String, Attachment)> {
+    content.split_whitespace()
+        .filter_map(|word| Attachment::parse(word).map(|a| (word.to_string(), a)))
+        .collect()
+}
+
+/// Message text with attachment links removed
+pub fn strip_attachments(content: &str, attachments: &[(String, Attachment)]) -> String {
+    let mut text = content.to_string();
+    for (link, _) in attachments {
+        text = text.replace(link.as_str(), "");
+    }
+    text.trim().to_string()
+}
+
+fn encrypt(data: &[u8]) -> Result<(Vec<u8>, Encryption), String> {
+    let mut key = [0u8; 32];
+    let mut nonce = [0u8; 12];
+    rand::thread_rng().fill_bytes(&mut key);
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let cipher = ChaCha20Poly1305::new_from_slice(&key)
+        .map_err(|e| format!("Encryption failed: {}", e))?;
+    let sealed = cipher.encrypt(Nonce::from_slice(&nonce), data)
+        .map_err(|e| format!("Encryption failed: {}", e))?;
+    Ok((sealed, Encryption { key, nonce }))
+}
+
+fn decrypt(data: &[u8], enc: &Encryption) -> Result<Vec<u8>, String> {
+    let cipher = ChaCha20Poly1305::new_from_slice(&enc.key)
+        .map_err(|e| format!("Decryption failed: {}", e))?;
+    cipher.decrypt(Nonce::from_slice(&enc.nonce), data)
+        .map_err(|_| "Decryption failed: wrong key or damaged file".to_string())
+}
+
+/// Upload a file for a DM, encrypting it first if asked
+pub async fn upload(server_url: &str, file_path: &str, keys: &Keys, encrypt_file: bool) -> Result<Attachment, String> {
+    if !encrypt_file {
+        let response = blossom::upload_media(server_url, file_path, keys).await?;
+        return Ok(Attachment {
+            url: response.url,
+            encryption: None,
+            mime_type: response.mime_type,
+        });
+    }
+
+    let path = Path::new(file_path);
+    let data = tokio::fs::read(path)
+        .await
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+    let mime_type = mime_guess::from_path(path).first().map(|m| m.to_string());
+    let (sealed, encryption) = encrypt(&data)?;
+
+    // The server only sees an opaque blob
+    let response = blossom::upload_bytes(server_url, sealed, "application/octet-stream", "attachment", keys).await?;
+    Ok(Attachment {
+        url: response.url,
+        encryption: Some(encryption),
+        mime_type,
+    })
+}
+
+/// Directory for this session's decrypted attachments
+fn session_dir() -> PathBuf {
+    let dirs = directories::ProjectDirs::from("com", "plebclient", "PlebClient");
+    dirs.as_ref()
+        .and_then(|d| d.runtime_dir().map(Path::to_path_buf))
+        .or_else(|| dirs.as_ref().map(|d| d.cache_dir().to_path_buf()))
+        .unwrap_or_else(std::env::temp_dir)
+        .join("dm-attachments")
+}
+
+/// Where a decrypted attachment is kept for the session
+fn session_path(attachment: &Attachment) -> PathBuf {
+    // The link holds the key, so only its hash goes into the name
+    let hash = hex::encode(Sha256::digest(attachment.to_link().as_bytes()));
+    session_dir().join(format!("{}.{}", hash, attachment.extension()))
+}
+
+/// Write a decrypted attachment, readable only by the user
+fn store_plaintext(path: &Path, data: &[u8]) -> Result<(), String> {
+    let mut dir = std::fs::DirBuilder::new();
+    dir.recursive(true);
+    let mut file = std::fs::OpenOptions::new();
+    file.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{DirBuilderExt, OpenOptionsExt};
+        dir.mode(0o700);
+        file.mode(0o600);
+    }
+
+    if let Some(parent) = path.parent() {
+        dir.create(parent).map_err(|e| format!("Failed to create attachment directory: {}", e))?;
+    }
+    let tmp_path = path.with_extension("part");
+    file.open(&tmp_path)
+        .and_then(|mut f| f.write_all(data))
+        .and_then(|_| std::fs::rename(&tmp_path, path))
+        .map_err(|e| {
+            let _ = std::fs::remove_file(&tmp_path);
+            format!("Failed to save attachment: {}", e)
+        })
+}
+
+/// Remove every decrypted attachment (on logout and exit, and at startup in
+/// case the last run didn't get that far)
+pub fn clear_session() {
+    let dir = session_dir();
+    if dir.exists() {
+        if let Err(e) = std::fs::remove_dir_all(&dir) {
+            tracing::warn!("Failed to remove decrypted DM attachments: {}", e);
+        }
+    }
+}
+
+/// Local copy of an attachment ready to display (downloaded and decrypted)
+pub fn cached(attachment: &Attachment) -> Option<PathBuf> {
+    if attachment.is_encrypted() {
+        let path = session_path(attachment);
+        path.is_file().then_some(path)
+    } else {
+        media_cache::cached(&attachment.url)
+    }
+}
+
+/// Download an attachment into the media cache, or download and decrypt it
+/// into the session directory
+pub async fn fetch(attachment: &Attachment) -> Result<PathBuf, String> {
+    let Some(enc) = &attachment.encryption else {
+        return media_cache::fetch(&attachment.url).await;
+    };
+    if let Some(path) = cached(attachment) {
+        return Ok(path);
+    }
+
+    let sealed_path = media_cache::fetch(&attachment.url).await?;
+    let sealed = tokio::fs::read(&sealed_path)
+        .await
+        .map_err(|e| format!("Failed to read download: {}", e))?;
+    // The ciphertext is useless without the link; keep only the plaintext
+    let _ = std::fs::remove_file(&sealed_path);
+    let data = decrypt(&sealed, enc)?;
+    let path = session_path(attachment);
+    store_plaintext(&path, &data)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encrypted() -> Attachment {
+        Attachment {
+            url: "https://blossom.example/abc123".to_string(),
+            encryption: Some(Encryption { key: [7u8; 32], nonce: [9u8; 12] }),
+            mime_type: Some("image/png".to_string()),
+        }
+    }
+
+    #[test]
+    fn encrypted_link_round_trips() {
+        let attachment = encrypted();
+        let link = attachment.to_link();
+        assert!(link.starts_with("https://blossom.example/abc123#enc=chacha20poly1305&key="));
+        assert!(link.ends_with("&m=image%2Fpng"));
+        assert_eq!(Attachment::parse(&link), Some(attachment));
+    }
+
+    #[test]
+    fn encrypted_link_needs_valid_key_and_nonce() {
+        let short_key = format!("https://blossom.example/abc#enc=chacha20poly1305&key={}&nonce={}", "00".repeat(31), "00".repeat(12));
+        assert_eq!(Attachment::parse(&short_key), None);
+        let no_nonce = format!("https://blossom.example/abc#enc=chacha20poly1305&key={}", "00".repeat(32));
+        assert_eq!(Attachment::parse(&no_nonce), None);
+        let not_hex = format!("https://blossom.example/abc#enc=chacha20poly1305&key={}&nonce={}", "zz".repeat(32), "00".repeat(12));
+        assert_eq!(Attachment::parse(&not_hex), None);
+    }
+
+    #[test]
+    fn plain_links_only_count_as_images() {
+        let image = Attachment::parse("https://example.com/photo.JPG").unwrap();
+        assert_eq!(image.url, "https://example.com/photo.JPG");
+        assert!(!image.is_encrypted());
+        assert_eq!(image.mime_type.as_deref(), Some("image/jpeg"));
+
+        assert_eq!(Attachment::parse("https://example.com/page.html"), None);
+        assert_eq!(Attachment::parse("https://example.com/"), None);
+        assert_eq!(Attachment::parse("ftp://example.com/photo.png"), None);
+        assert_eq!(Attachment::parse("photo.png"), None);
+    }
+
+    #[test]
+    fn attachments_are_found_and_stripped() {
+        let link = encrypted().to_link();
+        let content = format!("look at this {} and https://example.com/a.png", link);
+        let found = find_attachments(&content);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].0, link);
+        assert_eq!(strip_attachments(&content, &found), "look at this  and");
+    }
+
+    #[test]
+    fn decrypted_files_stay_out_of_the_media_cache() {
+        let path = session_path(&encrypted());
+        assert!(path.starts_with(session_dir()));
+        assert!(!path.starts_with(crate::core::cache_stats::media_cache_dir()));
+        assert!(path.to_string_lossy().ends_with(".png"));
+    }
+
+    #[test]
+    fn sealed_data_decrypts_with_its_key_only() {
+        let (sealed, enc) = encrypt(b"picture").unwrap();
+        assert_eq!(decrypt(&sealed, &enc).unwrap(), b"picture");
+        let wrong = Encryption { key: [0u8; 32], nonce: enc.nonce };
+        assert!(decrypt(&sealed, &wrong).is_err());
+    }
+}
@@ -0,0 +1,211 @@
+//! Embedded note resolution - nostr:nevent/note/naddr references inside notes
+//!
+//! Quotes can nest (a note quoting a note quoting a note...) and can form
+//! cycles. Resolution stops at MAX_EMBED_DEPTH and at any reference already in
+//! the chain of ancestors, returning a placeholder the UI can expand on demand
+//! (expanding resolves that reference as a new root).
+//!
+//! References found on none of the user's relays are looked up again on the
+//! relays hinted in the nevent/naddr, so quotes from other communities resolve.
+//! In data saver mode only the root embed is fetched; nested ones are left as
+//! expandable placeholders.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+use nostr_sdk::prelude::*;
+
+use super::feed::DisplayNote;
+use super::profile::ProfileCache;
+use super::relay::RelayManager;
+
+/// Nested embeds resolved below the root embed
+pub const MAX_EMBED_DEPTH: usize = 2;
+
+/// Embeds resolved per note; further references are left as links
+const MAX_EMBEDS_PER_NOTE: usize = 3;
+
+/// Timeout for each embedded event fetch
+const EMBED_TIMEOUT: Duration = Duration::from_secs(3);
+
+lazy_static::lazy_static! {
+    static ref EMBED_URI_RE: regex::Regex = regex::Regex::new(r"nostr:((?:nevent1|note1|naddr1)[a-z0-9]+)").unwrap();
+}
+
+/// What an embed reference points at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EmbedTarget {
+    Id(EventId),
+    Coordinate(Coordinate),
+}
+
+impl EmbedTarget {
+    /// Key used for cycle detection
+    pub fn key(&self) -> String {
+        match self {
+            EmbedTarget::Id(id) => id.to_hex(),
+            EmbedTarget::Coordinate(c) => format!("{}:{}:{}", c.kind.as_u16(), c.public_key.to_hex(), c.identifier),
+        }
+    }
+}
+
+/// Relays hinted in a nostr:nevent/naddr/nprofile URI (prefix optional)
+pub fn relay_hints(uri: &str) -> Vec<RelayUrl> {
+    let bech32 = uri.strip_prefix("nostr:").unwrap_or(uri);
+    let hints = if bech32.starts_with("nevent") {
+        Nip19Event::from_bech32(bech32).map(|n| n.relays).ok()
+    } else if bech32.starts_with("naddr") {
+        Nip19Coordinate::from_bech32(bech32).map(|c| c.relays).ok()
+    } else if bech32.starts_with("nprofile") {
+        Nip19Profile::from_bech32(bech32).map(|p| p.relays).ok()
+    } else {
+        None
+    };
+    hints.unwrap_or_default()
+}
+
+/// Nested embed levels resolved, fewer in data saver mode
+fn max_depth() -> usize {
+    if crate::core::network::data_saver() { 0 } else { MAX_EMBED_DEPTH }
+}
+
+/// Parse a nostr:nevent/note/naddr URI (prefix optional)
+pub fn parse_embed(uri: &str) -> Option<EmbedTarget> {
+    let bech32 = uri.strip_prefix("nostr:").unwrap_or(uri);
+    if bech32.starts_with("nevent") {
+        Nip19Event::from_bech32(bech32).ok().map(|n| EmbedTarget::Id(n.event_id))
+    } else if bech32.starts_with("note") {
+        EventId::from_bech32(bech32).ok().map(EmbedTarget::Id)
+    } else if bech32.starts_with("naddr") {
+        Nip19Coordinate::from_bech32(bech32).ok().map(|c| EmbedTarget::Coordinate(c.coordinate))
+    } else {
+        None
+    }
+}
+
+/// Embed URIs in note content, in order and without duplicates
+pub fn embed_uris(content: &str) -> Vec<String> {
+    let mut uris: Vec<String> = Vec::new();
+    for cap in EMBED_URI_RE.captures_iter(content) {
+        let uri = format!("nostr:{}", &cap[1]);
+        if !uris.contains(&uri) {
+            uris.push(uri);
+        }
+    }
+    uris
+}
+
+/// Placeholder for an embed that wasn't resolved
+/// kind is "deep" (depth limit - expandable), "cycle" (already in the chain) or "missing"
+fn placeholder(uri: &str, target: Option<&EmbedTarget>, kind: &str) -> serde_json::Value {
+    serde_json::json!({
+        "uri": uri,
+        "placeholder": kind,
+        "id": match target {
+            Some(EmbedTarget::Id(id)) => id.to_hex(),
+            _ => String::new(),
+        },
+    })
+}
+
+/// Fetch the referenced event, trying the URI's relay hints when the pool
+/// doesn't have it
+async fn fetch_target(manager: &RelayManager, target: &EmbedTarget, uri: &str) -> Result<Option<Event>, String> {
+    let filter = match target {
+        EmbedTarget::Id(id) => Filter::new().id(*id).limit(1),
+        EmbedTarget::Coordinate(c) => Filter::new()
+            .kind(c.kind)
+            .author(c.public_key)
+            .identifier(&c.identifier)
+            .limit(1),
+    };
+    let events = manager.client().fetch_events(filter.clone(), EMBED_TIMEOUT)
+        .await
+        .map_err(|e| format!("Failed to fetch embedded event: {}", e))?;
+    if let Some(event) = events.into_iter().max_by_key(|e| e.created_at) {
+        return Ok(Some(event));
+    }
+
+    let hints = relay_hints(uri);
+    if hints.is_empty() {
+        return Ok(None);
+    }
+    tracing::debug!("Embedded event not on our relays, trying {} hinted relays", hints.len());
+    let events = manager.fetch_from_hints(&hints, filter, EMBED_TIMEOUT)
+        .await
+        .map_err(|e| format!("Failed to fetch embedded event from hinted relays: {}", e))?;
+    Ok(events.into_iter().max_by_key(|e| e.created_at))
+}
+
+/// Resolve an embed URI to note JSON with nested embeds under "embeds"
+/// `ancestors` holds the keys of the notes this one is nested in
+pub async fn resolve(manager: &RelayManager, uri: &str, ancestors: &[String]) -> Result<serde_json::Value, String> {
+    let target = parse_embed(uri).ok_or("Unsupported embed reference")?;
+    let event = fetch_target(manager, &target, uri).await?.ok_or("Event not found")?;
+
+    let mut chain = ancestors.to_vec();
+    chain.push(target.key());
+    chain.push(event.id.to_hex());
+    resolve_event(manager, event, chain, 0).await
+}
+
+/// Note JSON for an event, resolving its own embeds recursively
+fn resolve_event<'a>(
+    manager: &'a RelayManager,
+    event: Event,
+    chain: Vec<String>,
+    depth: usize,
+) -> Pin<Box<dyn Future<Output = Result<serde_json::Value, String>> + Send + 'a>> {
+    Box::pin(async move {
+        let profiles = manager.fetch_profiles(&[event.pubkey]).await.unwrap_or_default();
+        let profile = profiles.iter().next().and_then(|p| {
+            Metadata::from_json(&p.content).ok().map(|m| ProfileCache::from_metadata(&m))
+        });
+        let note = DisplayNote::from_event(&event, profile.as_ref());
+        let mut value = note.to_value();
+
+        let mut embeds = Vec::new();
+        for uri in embed_uris(&event.content).into_iter().take(MAX_EMBEDS_PER_NOTE) {
+            let target = parse_embed(&uri);
+            let Some(target) = target else { continue };
+
+            if chain.contains(&target.key()) {
+                embeds.push(placeholder(&uri, Some(&target), "cycle"));
+                continue;
+            }
+            if depth >= max_depth() {
+                embeds.push(placeholder(&uri, Some(&target), "deep"));
+                continue;
+            }
+
+            let nested = match fetch_target(manager, &target, &uri).await {
+                Ok(Some(nested)) => nested,
+                _ => {
+                    embeds.push(placeholder(&uri, Some(&target), "missing"));
+                    continue;
+                }
+            };
+            // An naddr can point back at an ancestor known only by id
+            if chain.contains(&nested.id.to_hex()) {
+                embeds.push(placeholder(&uri, Some(&target), "cycle"));
+                continue;
+            }
+
+            let mut nested_chain = chain.clone();
+            nested_chain.push(target.key());
+            nested_chain.push(nested.id.to_hex());
+            match resolve_event(manager, nested, nested_chain, depth + 1).await {
+                Ok(mut nested_value) => {
+                    nested_value["uri"] = serde_json::Value::from(uri);
+                    embeds.push(nested_value);
+                }
+                Err(_) => embeds.push(placeholder(&uri, Some(&target), "missing")),
+            }
+        }
+
+        value["embeds"] = serde_json::Value::from(embeds);
+        Ok(value)
+    })
+}
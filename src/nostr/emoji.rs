@@ -0,0 +1,178 @@
+//! Custom emoji (NIP-30) - the user's emoji list for the reaction picker
+//!
+//! A kind 10030 list carries `["emoji", shortcode, url]` tags and may point at
+//! kind 30030 emoji sets through `a` tags. The newest list and the sets it
+//! references are kept in nostrdb so the picker can show them offline.
+//! Notes and reactions declare the images for their own :shortcodes:; those
+//! seen in reactions are remembered so reaction counts can show the image.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+use super::database::NostrDbManager;
+use super::relay::RelayManager;
+
+/// NIP-51 user emoji list
+pub const KIND_EMOJI_LIST: u16 = 10030;
+
+/// NIP-51 emoji set
+pub const KIND_EMOJI_SET: u16 = 30030;
+
+/// Most reaction shortcodes remembered
+const MAX_KNOWN_EMOJI: usize = 2000;
+
+lazy_static::lazy_static! {
+    // ":shortcode:" -> image URL, from emoji tags on reactions
+    static ref KNOWN_EMOJI: RwLock<HashMap<String, String>> = RwLock::new(HashMap::new());
+}
+
+/// A custom emoji: reacting with it sends ":shortcode:"
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CustomEmoji {
+    pub shortcode: String,
+    pub url: String,
+}
+
+impl CustomEmoji {
+    /// Reaction/content form of the emoji
+    pub fn content(&self) -> String {
+        format!(":{}:", self.shortcode)
+    }
+}
+
+/// Shortcode of a ":shortcode:" reaction, None for unicode emoji
+pub fn shortcode(content: &str) -> Option<&str> {
+    let inner = content.strip_prefix(':')?.strip_suffix(':')?;
+    let valid = !inner.is_empty() && inner.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    valid.then_some(inner)
+}
+
+/// Custom emoji declared in an event's `emoji` tags
+pub fn emoji_tags(event: &Event) -> Vec<CustomEmoji> {
+    event.tags.iter()
+        .filter_map(|tag| {
+            let values = tag.as_slice();
+            if values.first().map(|s| s.as_str()) != Some("emoji") {
+                return None;
+            }
+            let shortcode = values.get(1)?;
+            let url = values.get(2)?;
+            shortcode(&format!(":{}:", shortcode))?;
+            Some(CustomEmoji { shortcode: shortcode.clone(), url: url.clone() })
+        })
+        .collect()
+}
+
+/// Shortcode -> image URL for the emoji an event declares
+pub fn emoji_map(event: &Event) -> HashMap<String, String> {
+    emoji_tags(event).into_iter().map(|e| (e.shortcode, e.url)).collect()
+}
+
+/// Reaction content as counted: ":shortcode:" for custom emoji, with its image
+/// remembered; None for anything else
+pub fn custom_reaction(event: &Event) -> Option<String> {
+    let content = event.content.trim();
+    let code = shortcode(content)?;
+    let url = emoji_tags(event).into_iter().find(|e| e.shortcode == code)?.url;
+    let mut known = KNOWN_EMOJI.write().unwrap();
+    if known.len() >= MAX_KNOWN_EMOJI && !known.contains_key(content) {
+        known.clear();
+    }
+    known.insert(content.to_string(), url);
+    Some(content.to_string())
+}
+
+/// Image URLs for the custom emoji among reaction keys
+pub fn reaction_urls<'a>(reactions: impl IntoIterator<Item = &'a String>) -> HashMap<String, String> {
+    let known = KNOWN_EMOJI.read().unwrap();
+    reactions.into_iter()
+        .filter_map(|r| known.get(r).map(|url| (r.clone(), url.clone())))
+        .collect()
+}
+
+/// Image URL remembered for a ":shortcode:" reaction
+pub fn known_url(content: &str) -> Option<String> {
+    KNOWN_EMOJI.read().unwrap().get(content.trim()).cloned()
+}
+
+/// Emoji sets an emoji list points at: (author, d-tag) from `a` tags
+pub fn set_refs(event: &Event) -> Vec<(PublicKey, String)> {
+    event.tags.iter()
+        .filter_map(|tag| {
+            let values = tag.as_slice();
+            if values.first().map(|s| s.as_str()) != Some("a") {
+                return None;
+            }
+            let mut parts = values.get(1)?.splitn(3, ':');
+            let kind: u16 = parts.next()?.parse().ok()?;
+            let author = PublicKey::parse(parts.next()?).ok()?;
+            let identifier = parts.next()?.to_string();
+            (kind == KIND_EMOJI_SET).then_some((author, identifier))
+        })
+        .collect()
+}
+
+/// The user's custom emoji: their stored emoji list plus the sets it references
+pub fn user_emoji(user: &PublicKey) -> Vec<CustomEmoji> {
+    let Ok(db) = NostrDbManager::global() else { return Vec::new() };
+    let Some(list) = db.query_author_events(&[KIND_EMOJI_LIST], &[*user], 5)
+        .unwrap_or_default()
+        .into_iter()
+        .max_by_key(|e| e.created_at)
+    else {
+        return Vec::new();
+    };
+    
+    let mut emoji = emoji_tags(&list);
+    let refs = set_refs(&list);
+    if !refs.is_empty() {
+        let authors: Vec<PublicKey> = refs.iter().map(|(pk, _)| *pk).collect();
+        let sets = db.query_author_events(&[KIND_EMOJI_SET], &authors, 200).unwrap_or_default();
+        for (author, identifier) in &refs {
+            let newest = sets.iter()
+                .filter(|e| e.pubkey == *author && e.tags.identifier() == Some(identifier.as_str()))
+                .max_by_key(|e| e.created_at);
+            if let Some(set) = newest {
+                emoji.extend(emoji_tags(set));
+            }
+        }
+    }
+    
+    // First declaration of a shortcode wins
+    let mut seen = std::collections::HashSet::new();
+    emoji.retain(|e| seen.insert(e.shortcode.clone()));
+    emoji
+}
+
+/// Image URL for a ":shortcode:" from the user's emoji list
+pub fn lookup(user: &PublicKey, content: &str) -> Option<String> {
+    let code = shortcode(content)?;
+    user_emoji(user).into_iter()
+        .find(|e| e.shortcode == code)
+        .map(|e| e.url)
+}
+
+/// Fetch the user's emoji list and the emoji sets it references, and store them
+/// Returns the number of custom emoji available
+pub async fn refresh_user_emoji(manager: &RelayManager, user: &PublicKey) -> Result<usize, String> {
+    let Some(event) = manager.fetch_emoji_list(user).await? else {
+        return Ok(0);
+    };
+    let db = NostrDbManager::global()?;
+    db.ingest_events(&[event.clone()])?;
+    
+    let refs = set_refs(&event);
+    if !refs.is_empty() {
+        match manager.fetch_emoji_sets(&refs).await {
+            Ok(sets) => {
+                db.ingest_events(&sets)?;
+            }
+            Err(e) => tracing::warn!("Failed to fetch emoji sets: {}", e),
+        }
+    }
+    Ok(user_emoji(user).len())
+}
@@ -6,8 +6,21 @@ use std::sync::Arc;
 use nostr_sdk::prelude::*;
 use tokio::sync::RwLock;
 use super::database::NostrDbManager;
-use super::relay::RelayManager;
+use super::emoji;
+use super::handlers;
+use super::nip05;
+use super::polls;
+use super::highlights;
+use super::report;
+use super::spam;
+use super::wot;
+use super::zap_goals;
+use super::relay::{RelayManager, KIND_PICTURE, KIND_SHORT_VIDEO};
 use super::profile::ProfileCache;
+use serde::{Deserialize, Serialize};
+use crate::core::config::Config;
+use crate::core::fiat;
+use crate::core::network;
 
 /// Feed types supported by the application
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,6 +35,8 @@ pub enum FeedType {
     ReadsFollowing,
     /// Global long-form posts
     ReadsGlobal,
+    /// Picture (kind 20) and short video (kind 22) events
+    Media,
 }
 
 impl FeedType {
@@ -32,6 +47,7 @@ impl FeedType {
             "global" => FeedType::Global,
             "reads_following" => FeedType::ReadsFollowing,
             "reads_global" => FeedType::ReadsGlobal,
+            "media" => FeedType::Media,
             _ => FeedType::Following,
         }
     }
@@ -43,10 +59,114 @@ impl FeedType {
             FeedType::Global => "global",
             FeedType::ReadsFollowing => "reads_following",
             FeedType::ReadsGlobal => "reads_global",
+            FeedType::Media => "media",
         }
     }
 }
 
+/// Per-feed visibility preferences, persisted in Config keyed by FeedType::as_str
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeedPreferences {
+    #[serde(default = "default_true")]
+    pub show_reposts: bool,
+    #[serde(default = "default_true")]
+    pub show_replies: bool,
+    /// Only notes with images or videos
+    #[serde(default)]
+    pub only_media: bool,
+    /// ISO 639-1 codes to show; empty shows every language
+    #[serde(default)]
+    pub languages: Vec<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for FeedPreferences {
+    fn default() -> Self {
+        Self {
+            show_reposts: true,
+            show_replies: true,
+            only_media: false,
+            languages: Vec::new(),
+        }
+    }
+}
+
+impl FeedPreferences {
+    /// Saved preferences for a feed ("following", "global", ...)
+    pub fn for_feed(feed: &str) -> Self {
+        Config::load().feed_preferences.get(feed).cloned().unwrap_or_default()
+    }
+
+    /// Whether a note passes these preferences
+    /// Notes whose language is unknown always pass the language filter
+    pub fn allows(&self, note: &DisplayNote) -> bool {
+        if !self.show_reposts && note.is_repost {
+            return false;
+        }
+        if !self.show_replies && note.is_reply {
+            return false;
+        }
+        if self.only_media && note.images.is_empty() && note.videos.is_empty() {
+            return false;
+        }
+        match (&note.language, self.languages.is_empty()) {
+            (Some(lang), false) => self.languages.iter().any(|l| l == lang),
+            _ => true,
+        }
+    }
+}
+
+/// Drop notes the feed's saved preferences hide
+/// The global feed also gets repetitive spam collapsed (see `spam`)
+pub fn apply_feed_preferences(feed: &str, notes: Vec<DisplayNote>) -> Vec<DisplayNote> {
+    let prefs = FeedPreferences::for_feed(feed);
+    let notes: Vec<DisplayNote> = if prefs == FeedPreferences::default() {
+        notes
+    } else {
+        notes.into_iter().filter(|n| prefs.allows(n)).collect()
+    };
+    if feed == "global" {
+        return spam::collapse(notes);
+    }
+    notes
+}
+
+/// ISO 639-3 (whatlang) to ISO 639-1 for the languages offered in feed filters
+const LANGUAGE_CODES: &[(&str, &str)] = &[
+    ("eng", "en"), ("spa", "es"), ("por", "pt"), ("fra", "fr"), ("deu", "de"),
+    ("ita", "it"), ("nld", "nl"), ("rus", "ru"), ("ukr", "uk"), ("pol", "pl"),
+    ("tur", "tr"), ("ara", "ar"), ("heb", "he"), ("pes", "fa"), ("hin", "hi"),
+    ("ben", "bn"), ("jpn", "ja"), ("kor", "ko"), ("cmn", "zh"), ("tha", "th"),
+    ("vie", "vi"), ("ind", "id"), ("swe", "sv"), ("ces", "cs"), ("ell", "el"),
+];
+
+/// ISO 639-1 codes offered in feed language filters
+pub fn filter_languages() -> Vec<&'static str> {
+    LANGUAGE_CODES.iter().map(|(_, iso1)| *iso1).collect()
+}
+
+/// Note language: a NIP-32 ISO-639-1 label if present, otherwise detected from
+/// the text when the detector is confident
+fn note_language(event: &Event, content: &str) -> Option<String> {
+    let labeled = event.tags.iter()
+        .map(|t| t.as_slice())
+        .find(|v| v.first().map(|s| s.as_str()) == Some("l") && v.get(2).map(|s| s.as_str()) == Some("ISO-639-1"))
+        .and_then(|v| v.get(1).map(|s| s.to_lowercase()));
+    if labeled.is_some() {
+        return labeled;
+    }
+
+    let info = whatlang::detect(content)?;
+    if !info.is_reliable() {
+        return None;
+    }
+    let code = info.lang().code();
+    LANGUAGE_CODES.iter().find(|(iso3, _)| *iso3 == code).map(|(_, iso1)| iso1.to_string())
+}
+
 /// A processed note ready for display
 #[derive(Debug, Clone)]
 pub struct DisplayNote {
@@ -56,6 +176,7 @@ pub struct DisplayNote {
     pub author_name: String,
     pub author_picture: Option<String>,
     pub author_nip05: Option<String>,
+    pub author_nip05_verified: bool,
     pub content: String,
     pub created_at: i64,
     pub likes: u32,
@@ -78,6 +199,11 @@ pub struct DisplayNote {
     pub image: Option<String>,
     pub published_at: Option<i64>,
     pub d_tag: Option<String>,  // NIP-23 unique identifier/slug
+    pub content_warning: Option<String>,  // NIP-36 reason (empty if none given)
+    pub language: Option<String>,  // ISO 639-1, from a NIP-32 label or detected
+    pub extras: std::collections::BTreeMap<String, serde_json::Value>,  // renderer hook name -> display data
+    pub emojis: std::collections::HashMap<String, String>,  // NIP-30 shortcode -> image URL
+    pub blurhashes: std::collections::HashMap<String, String>,  // media URL -> imeta blurhash placeholder
 }
 
 impl DisplayNote {
@@ -123,7 +249,23 @@ impl DisplayNote {
         };
         
         // Extract media URLs from content
-        let (images, videos) = extract_media_urls(&content);
+        let (mut images, mut videos) = extract_media_urls(&content);
+        
+        // Picture and short video events carry their media in imeta tags
+        let is_media_event = kind == KIND_PICTURE || kind == KIND_SHORT_VIDEO;
+        if is_media_event {
+            let (imeta_images, imeta_videos) = extract_imeta_media(event);
+            for url in imeta_images {
+                if !images.contains(&url) {
+                    images.push(url);
+                }
+            }
+            for url in imeta_videos {
+                if !videos.contains(&url) {
+                    videos.push(url);
+                }
+            }
+        }
         
         // Check if this is a reply (now also returns author pubkey)
         let (is_reply, reply_to, reply_to_author_pubkey) = check_reply_status(event);
@@ -143,6 +285,11 @@ impl DisplayNote {
             ))
             .unwrap_or_else(|| (format_npub(&pubkey), None, None));
         
+        // Only cached results here - lookups happen in the background
+        let author_nip05_verified = author_nip05.as_deref()
+            .and_then(|n| nip05::cached_status(n, &pubkey))
+            .unwrap_or(false);
+        
         // For reposts, the reposter info goes in repost_author fields
         let (repost_author_name, repost_author_picture) = if is_repost {
             (Some(author_name.clone()), author_picture.clone())
@@ -171,8 +318,24 @@ impl DisplayNote {
                     }
                 }
             }
+        } else if is_media_event {
+            title = event.tags.iter()
+                .map(|t| t.as_slice())
+                .find(|v| v.first().map(|s| s.as_str()) == Some("title"))
+                .and_then(|v| v.get(1).cloned());
         }
 
+        // NIP-36 content warning (reason is optional)
+        let content_warning = event.tags.iter()
+            .map(|t| t.as_slice())
+            .find(|v| v.first().map(|s| s.as_str()) == Some("content-warning"))
+            .map(|v| v.get(1).cloned().unwrap_or_default());
+
+        let language = note_language(event, &content);
+        let extras = render_extras(event);
+        let emojis = emoji::emoji_map(event);
+        let blurhashes = extract_imeta_blurhashes(event);
+
         Self {
             id,
             pubkey,
@@ -180,6 +343,7 @@ impl DisplayNote {
             author_name,
             author_picture,
             author_nip05,
+            author_nip05_verified,
             content,
             created_at,
             likes: 0,
@@ -201,23 +365,59 @@ impl DisplayNote {
             image,
             published_at,
             d_tag,
+            content_warning,
+            language,
+            extras,
+            emojis,
+            blurhashes,
+        }
+    }
+    
+    /// Take the author's name, picture and NIP-05 from an updated profile
+    /// Returns false if the note already shows them
+    pub fn apply_author_profile(&mut self, profile: &ProfileCache) -> bool {
+        let name = profile.name.clone().unwrap_or_else(|| format_npub(&self.pubkey));
+        if self.author_name == name && self.author_picture == profile.picture && self.author_nip05 == profile.nip05 {
+            return false;
+        }
+        
+        if self.author_nip05 != profile.nip05 {
+            self.author_nip05_verified = profile.nip05.as_deref()
+                .and_then(|n| nip05::cached_status(n, &self.pubkey))
+                .unwrap_or(false);
+        }
+        if self.is_repost {
+            self.repost_author_name = Some(name.clone());
+            self.repost_author_picture = profile.picture.clone();
         }
+        self.author_name = name;
+        self.author_picture = profile.picture.clone();
+        self.author_nip05 = profile.nip05.clone();
+        true
     }
     
     /// Serialize to JSON for QML consumption
     pub fn to_json(&self) -> String {
+        self.to_value().to_string()
+    }
+    
+    /// Fields as QML reads them
+    pub fn to_value(&self) -> serde_json::Value {
         serde_json::json!({
             "id": self.id,
             "pubkey": self.pubkey,
+            "kind": self.kind,
             "authorName": self.author_name,
             "authorPicture": self.author_picture,
             "authorNip05": self.author_nip05,
+            "authorNip05Verified": self.author_nip05_verified,
             "content": self.content,
             "createdAt": self.created_at,
             "likes": self.likes,
             "reposts": self.reposts,
             "replies": self.replies,
             "zapAmount": self.zap_amount,
+            "zapAmountFiat": fiat::fiat_label(self.zap_amount).unwrap_or_default(),
             "zapCount": self.zap_count,
             "reactions": self.reactions,
             "images": self.images,
@@ -232,10 +432,128 @@ impl DisplayNote {
             "summary": self.summary,
             "image": self.image,
             "publishedAt": self.published_at,
-        }).to_string()
+            "hasContentWarning": self.content_warning.is_some(),
+            "contentWarning": self.content_warning,
+            "reported": report::is_hidden(&self.id, &self.pubkey),
+            "language": self.language,
+            "outsideTrust": wot::policy().action == wot::TrustAction::Demote && wot::is_outside(&self.pubkey),
+            "extras": self.extras,
+            "emojis": self.emojis,
+            "blurhashes": self.blurhashes,
+            "autoLoadMedia": !network::data_saver(),
+        })
+    }
+    
+    /// Grid tile fields: the note's media as [{url, type, blurhash}] plus the
+    /// first item as thumbnail; images come before videos
+    pub fn to_media_value(&self) -> serde_json::Value {
+        let media: Vec<serde_json::Value> = self.images.iter()
+            .map(|url| (url, "image"))
+            .chain(self.videos.iter().map(|url| (url, "video")))
+            .map(|(url, kind)| serde_json::json!({
+                "url": url,
+                "type": kind,
+                "blurhash": self.blurhashes.get(url).cloned().unwrap_or_default(),
+            }))
+            .collect();
+        let first = media.first().cloned().unwrap_or_else(|| serde_json::json!({ "url": "", "type": "image", "blurhash": "" }));
+        serde_json::json!({
+            "id": self.id,
+            "pubkey": self.pubkey,
+            "authorName": self.author_name,
+            "authorPicture": self.author_picture,
+            "createdAt": self.created_at,
+            "content": self.content,
+            "hasContentWarning": self.content_warning.is_some(),
+            "media": media,
+            "mediaCount": media.len(),
+            "thumbnail": first["url"],
+            "thumbnailBlurhash": first["blurhash"],
+            "isVideo": first["type"] == "video",
+            "autoLoadMedia": !network::data_saver(),
+        })
+    }
+}
+
+/// What a renderer hook is interested in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderMatch {
+    /// Events of this kind
+    Kind(u16),
+    /// Events carrying at least one tag with this name
+    Tag(&'static str),
+    /// Every event; the hook decides by returning None
+    Any,
+}
+
+impl RenderMatch {
+    fn matches(&self, event: &Event) -> bool {
+        match self {
+            RenderMatch::Kind(kind) => event.kind.as_u16() == *kind,
+            RenderMatch::Tag(name) => event.tags.iter()
+                .any(|t| t.as_slice().first().map(|s| s.as_str()) == Some(*name)),
+            RenderMatch::Any => true,
+        }
     }
 }
 
+/// A compiled-in renderer for a NIP that DisplayNote doesn't model itself
+/// `render` turns a matching event into display data (None to skip it); the
+/// result appears in the note JSON under extras.<name>
+#[derive(Clone, Copy)]
+pub struct RenderHook {
+    pub name: &'static str,
+    pub matches: RenderMatch,
+    pub render: fn(&Event) -> Option<serde_json::Value>,
+}
+
+lazy_static::lazy_static! {
+    static ref RENDER_HOOKS: std::sync::RwLock<Vec<RenderHook>> = std::sync::RwLock::new(builtin_render_hooks());
+}
+
+/// Hooks available without registration
+fn builtin_render_hooks() -> Vec<RenderHook> {
+    vec![
+        RenderHook { name: "proxy", matches: RenderMatch::Tag("proxy"), render: render_proxy },
+        RenderHook { name: "poll", matches: RenderMatch::Kind(polls::KIND_POLL), render: polls::render_poll },
+        RenderHook { name: "highlight", matches: RenderMatch::Kind(highlights::KIND_HIGHLIGHT), render: highlights::render_highlight },
+        RenderHook { name: "zapGoal", matches: RenderMatch::Kind(zap_goals::KIND_ZAP_GOAL), render: zap_goals::render_zap_goal },
+        RenderHook { name: "handler", matches: RenderMatch::Any, render: handlers::render_unknown_kind },
+    ]
+}
+
+/// Register a renderer hook, replacing any hook with the same name
+pub fn register_render_hook(hook: RenderHook) {
+    let mut hooks = RENDER_HOOKS.write().unwrap();
+    hooks.retain(|h| h.name != hook.name);
+    hooks.push(hook);
+}
+
+/// Names of the registered hooks
+pub fn render_hook_names() -> Vec<&'static str> {
+    RENDER_HOOKS.read().unwrap().iter().map(|h| h.name).collect()
+}
+
+/// Display data from every hook that matches the event
+pub fn render_extras(event: &Event) -> std::collections::BTreeMap<String, serde_json::Value> {
+    let hooks = RENDER_HOOKS.read().unwrap();
+    hooks.iter()
+        .filter(|hook| hook.matches.matches(event))
+        .filter_map(|hook| (hook.render)(event).map(|data| (hook.name.to_string(), data)))
+        .collect()
+}
+
+/// NIP-48 proxy tag: the note was bridged from another protocol
+fn render_proxy(event: &Event) -> Option<serde_json::Value> {
+    let tag = event.tags.iter()
+        .map(|t| t.as_slice())
+        .find(|v| v.first().map(|s| s.as_str()) == Some("proxy"))?;
+    Some(serde_json::json!({
+        "id": tag.get(1)?,
+        "protocol": tag.get(2).cloned().unwrap_or_default(),
+    }))
+}
+
 /// Feed manager that coordinates fetching and caching
 pub struct FeedManager {
     db: Option<Arc<NostrDbManager>>,
@@ -302,6 +620,7 @@ impl FeedManager {
             FeedType::Global => manager.fetch_global_feed(limit, None).await?,
             FeedType::ReadsFollowing => manager.fetch_long_form_following(limit, None).await?,
             FeedType::ReadsGlobal => manager.fetch_long_form_global(limit, None).await?,
+            FeedType::Media => manager.fetch_media_feed(limit, None).await?,
         };
         
         // Collect unique pubkeys for profile fetching
@@ -338,7 +657,7 @@ impl FeedManager {
         }
         
         // Convert to display notes
-        self.notes = events
+        let notes: Vec<DisplayNote> = events
             .iter()
             .map(|e| {
                 let pubkey_hex = e.pubkey.to_hex();
@@ -346,6 +665,7 @@ impl FeedManager {
                 DisplayNote::from_event_with_profiles(e, profile, &self.profiles)
             })
             .collect();
+        self.notes = apply_feed_preferences(self.current_feed.as_str(), notes);
         
         // Sort by created_at descending
         self.notes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
@@ -376,6 +696,7 @@ impl FeedManager {
             FeedType::Global => manager.fetch_global_feed(limit, until).await?,
             FeedType::ReadsFollowing => manager.fetch_long_form_following(limit, until).await?,
             FeedType::ReadsGlobal => manager.fetch_long_form_global(limit, until).await?,
+            FeedType::Media => manager.fetch_media_feed(limit, until).await?,
         };
         
         // Fetch profiles for new authors
@@ -409,6 +730,7 @@ impl FeedManager {
                 DisplayNote::from_event_with_profiles(e, profile, &self.profiles)
             })
             .collect();
+        let new_notes = apply_feed_preferences(self.current_feed.as_str(), new_notes);
         
         let count = new_notes.len();
         self.notes.extend(new_notes);
@@ -449,6 +771,91 @@ fn extract_media_urls(content: &str) -> (Vec<String>, Vec<String>) {
     (images, videos)
 }
 
+/// Whether an event would show images or videos, matching what DisplayNote extracts
+pub fn has_media(event: &Event) -> bool {
+    let (images, videos) = extract_media_urls(&event.content);
+    if !images.is_empty() || !videos.is_empty() {
+        return true;
+    }
+    let kind = event.kind.as_u16();
+    if kind != KIND_PICTURE && kind != KIND_SHORT_VIDEO {
+        return false;
+    }
+    let (images, videos) = extract_imeta_media(event);
+    !images.is_empty() || !videos.is_empty()
+}
+
+/// Extract media from NIP-92 imeta tags as (images, videos)
+/// The "m" mime type decides the bucket; without one the URL extension is used
+pub fn extract_imeta_media(event: &Event) -> (Vec<String>, Vec<String>) {
+    let mut images = Vec::new();
+    let mut videos = Vec::new();
+    
+    for tag in event.tags.iter() {
+        let values = tag.as_slice();
+        if values.first().map(|s| s.as_str()) != Some("imeta") {
+            continue;
+        }
+        
+        let mut url = None;
+        let mut mime = None;
+        for entry in values.iter().skip(1) {
+            match entry.split_once(' ') {
+                Some(("url", v)) => url = Some(v.trim().to_string()),
+                Some(("m", v)) => mime = Some(v.trim().to_lowercase()),
+                _ => {}
+            }
+        }
+        
+        let Some(url) = url else { continue };
+        let is_video = match mime {
+            Some(m) => m.starts_with("video/"),
+            None => {
+                let (_, found_videos) = extract_media_urls(&url);
+                !found_videos.is_empty()
+            }
+        };
+        
+        if is_video {
+            videos.push(url);
+        } else {
+            images.push(url);
+        }
+    }
+    
+    (images, videos)
+}
+
+/// Blurhash placeholders from NIP-92 imeta tags, keyed by media URL
+pub fn extract_imeta_blurhashes(event: &Event) -> std::collections::HashMap<String, String> {
+    let mut blurhashes = std::collections::HashMap::new();
+    
+    for tag in event.tags.iter() {
+        let values = tag.as_slice();
+        if values.first().map(|s| s.as_str()) != Some("imeta") {
+            continue;
+        }
+        
+        let mut url = None;
+        let mut blurhash = None;
+        for entry in values.iter().skip(1) {
+            match entry.split_once(' ') {
+                Some(("url", v)) => url = Some(v.trim().to_string()),
+                Some(("blurhash", v)) => blurhash = Some(v.trim().to_string()),
+                _ => {}
+            }
+        }
+        
+        if let (Some(url), Some(blurhash)) = (url, blurhash) {
+            if !blurhash.is_empty() {
+                blurhashes.insert(url, blurhash);
+            }
+        }
+    }
+    
+    blurhashes
+}
+
 /// Check if event is a reply and get the reply-to ID and author pubkey
 fn check_reply_status(event: &Event) -> (bool, Option<String>, Option<String>) {
     // First look for proper NIP-10 reply markers
@@ -0,0 +1,64 @@
+//! Follow review queue - suggested follows that wait for approval
+//!
+//! For users who curate their follow list: suggestions from search, the
+//! suggestions list or other people's profiles are parked here instead of
+//! being followed right away. Approving a batch updates the contact list
+//! once, so reviewing twenty suggestions publishes one kind 3, not twenty.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use nostr_sdk::prelude::*;
+
+use super::contacts::GLOBAL_CONTACT_MANAGER;
+use super::database::{NostrDbManager, QueuedFollow};
+
+/// The logged-in user, as known to the contact manager
+fn owner() -> Result<PublicKey, String> {
+    GLOBAL_CONTACT_MANAGER.lock().unwrap().owner().ok_or_else(|| "Not logged in".to_string())
+}
+
+/// Queue a suggested follow; false if already followed, queued, or it's the user
+pub fn queue(pubkey: &str, source: &str) -> Result<bool, String> {
+    let user = owner()?;
+    let target = PublicKey::parse(pubkey.trim()).map_err(|e| format!("Invalid public key: {}", e))?;
+    if target == user || GLOBAL_CONTACT_MANAGER.lock().unwrap().is_following(&target) {
+        return Ok(false);
+    }
+    let entry = QueuedFollow {
+        pubkey: target.to_hex(),
+        source: source.to_string(),
+        added_at: chrono::Utc::now().timestamp(),
+    };
+    Ok(NostrDbManager::global()?.queue_follow(&user.to_hex(), entry))
+}
+
+/// Queued follows, oldest first; anyone followed meanwhile is left out
+pub fn list() -> Vec<QueuedFollow> {
+    let Ok(user) = owner() else { return Vec::new() };
+    let Ok(db) = NostrDbManager::global() else { return Vec::new() };
+    let contacts = GLOBAL_CONTACT_MANAGER.lock().unwrap();
+    db.get_follow_queue(&user.to_hex())
+        .into_iter()
+        .filter(|q| PublicKey::parse(&q.pubkey).map_or(false, |pk| !contacts.is_following(&pk)))
+        .collect()
+}
+
+/// Number of follows waiting for review
+pub fn len() -> usize {
+    list().len()
+}
+
+/// Take the given queued follows (hex or npub) out of the queue
+/// Returns the parsed keys that were queued
+pub fn take(pubkeys: &[String]) -> Vec<PublicKey> {
+    let Ok(user) = owner() else { return Vec::new() };
+    let Ok(db) = NostrDbManager::global() else { return Vec::new() };
+    let queued = db.get_follow_queue(&user.to_hex());
+    let keys: Vec<PublicKey> = pubkeys.iter()
+        .filter_map(|pk| PublicKey::parse(pk.trim()).ok())
+        .filter(|pk| queued.iter().any(|q| q.pubkey == pk.to_hex()))
+        .collect();
+    let hex: Vec<String> = keys.iter().map(|pk| pk.to_hex()).collect();
+    db.remove_queued_follows(&user.to_hex(), &hex);
+    keys
+}
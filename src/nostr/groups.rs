@@ -0,0 +1,177 @@
+//! Relay-based groups - NIP-29
+//!
+//! Groups live on a single relay which enforces membership. Admins manage them
+//! by publishing moderation events (kinds 9000-9020) tagged with the group id;
+//! the relay publishes the resulting group state as kind 39000+ events, signed
+//! with the key in its NIP-11 document. State signed by anyone else is ignored.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+/// Moderation: add a user / change their roles
+pub const KIND_PUT_USER: u16 = 9000;
+/// Moderation: remove a user
+pub const KIND_REMOVE_USER: u16 = 9001;
+/// Moderation: edit group metadata
+pub const KIND_EDIT_METADATA: u16 = 9002;
+/// Moderation: delete an event from the group
+pub const KIND_DELETE_EVENT: u16 = 9005;
+/// Moderation: create a group
+pub const KIND_CREATE_GROUP: u16 = 9007;
+/// Relay-published group metadata
+pub const KIND_GROUP_METADATA: u16 = 39000;
+/// Relay-published group admins
+pub const KIND_GROUP_ADMINS: u16 = 39001;
+/// Relay-published group members
+pub const KIND_GROUP_MEMBERS: u16 = 39002;
+
+/// Group metadata as published by the relay (kind 39000)
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupMetadata {
+    pub id: String,
+    pub relay_url: String,
+    pub name: Option<String>,
+    pub about: Option<String>,
+    pub picture: Option<String>,
+    pub is_private: bool,
+    pub is_closed: bool,
+    pub admins: Vec<String>,
+    pub members: Vec<String>,
+}
+
+impl GroupMetadata {
+    /// Parse a kind 39000 metadata event
+    pub fn from_event(event: &Event, relay_url: &str) -> Option<Self> {
+        if event.kind.as_u16() != KIND_GROUP_METADATA {
+            return None;
+        }
+
+        let mut group = GroupMetadata {
+            relay_url: relay_url.to_string(),
+            ..Default::default()
+        };
+
+        for tag in event.tags.iter() {
+            let values = tag.as_slice();
+            match (values.first().map(|s| s.as_str()), values.get(1)) {
+                (Some("d"), Some(id)) => group.id = id.clone(),
+                (Some("name"), Some(v)) => group.name = Some(v.clone()),
+                (Some("about"), Some(v)) => group.about = Some(v.clone()),
+                (Some("picture"), Some(v)) => group.picture = Some(v.clone()),
+                (Some("private"), _) => group.is_private = true,
+                (Some("closed"), _) => group.is_closed = true,
+                _ => {}
+            }
+        }
+
+        if group.id.is_empty() { None } else { Some(group) }
+    }
+
+    /// Apply a kind 39001 (admins) or 39002 (members) list for this group
+    pub fn apply_member_list(&mut self, event: &Event) {
+        let pubkeys: Vec<String> = event.tags.iter()
+            .map(|t| t.as_slice())
+            .filter(|v| v.first().map(|s| s.as_str()) == Some("p"))
+            .filter_map(|v| v.get(1).cloned())
+            .collect();
+
+        match event.kind.as_u16() {
+            KIND_GROUP_ADMINS => self.admins = pubkeys,
+            KIND_GROUP_MEMBERS => self.members = pubkeys,
+            _ => {}
+        }
+    }
+
+    /// Serialize to JSON for QML consumption
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Editable group metadata fields
+#[derive(Debug, Clone, Default)]
+pub struct GroupSettings {
+    pub name: String,
+    pub about: String,
+    pub picture: String,
+    pub is_private: bool,
+    pub is_closed: bool,
+}
+
+fn group_tag(group_id: &str) -> Tag {
+    Tag::custom(TagKind::SingleLetter(SingleLetterTag::lowercase(Alphabet::H)), [group_id])
+}
+
+/// Build a create-group moderation event
+pub fn create_group(group_id: &str) -> EventBuilder {
+    EventBuilder::new(Kind::from(KIND_CREATE_GROUP), "")
+        .tag(group_tag(group_id))
+}
+
+/// Build an edit-metadata moderation event
+pub fn edit_metadata(group_id: &str, settings: &GroupSettings) -> EventBuilder {
+    let mut tags = vec![
+        group_tag(group_id),
+        Tag::custom(TagKind::Name, [settings.name.as_str()]),
+        Tag::custom(TagKind::custom("about"), [settings.about.as_str()]),
+    ];
+    if !settings.picture.is_empty() {
+        tags.push(Tag::custom(TagKind::custom("picture"), [settings.picture.as_str()]));
+    }
+    tags.push(Tag::custom(
+        TagKind::custom(if settings.is_private { "private" } else { "public" }),
+        Vec::<String>::new(),
+    ));
+    tags.push(Tag::custom(
+        TagKind::custom(if settings.is_closed { "closed" } else { "open" }),
+        Vec::<String>::new(),
+    ));
+
+    EventBuilder::new(Kind::from(KIND_EDIT_METADATA), "").tags(tags)
+}
+
+/// Build a put-user moderation event (add a member, optionally with a role)
+pub fn put_user(group_id: &str, pubkey: &PublicKey, role: Option<&str>) -> EventBuilder {
+    let mut values = vec!["p".to_string(), pubkey.to_hex()];
+    if let Some(role) = role.filter(|r| !r.is_empty()) {
+        values.push(role.to_string());
+    }
+    EventBuilder::new(Kind::from(KIND_PUT_USER), "")
+        .tag(group_tag(group_id))
+        .tag(Tag::parse(values).expect("p tag is always valid"))
+}
+
+/// Build a remove-user moderation event
+pub fn remove_user(group_id: &str, pubkey: &PublicKey) -> EventBuilder {
+    EventBuilder::new(Kind::from(KIND_REMOVE_USER), "")
+        .tag(group_tag(group_id))
+        .tag(Tag::public_key(*pubkey))
+}
+
+/// Build a delete-event moderation event
+pub fn delete_event(group_id: &str, event_id: &EventId, reason: &str) -> EventBuilder {
+    EventBuilder::new(Kind::from(KIND_DELETE_EVENT), reason)
+        .tag(group_tag(group_id))
+        .tag(Tag::event(*event_id))
+}
+
+/// Filter for the group state a relay publishes (metadata, admins, members)
+pub fn group_state_filter(relay_pubkey: &PublicKey) -> Filter {
+    Filter::new()
+        .kinds([
+            Kind::from(KIND_GROUP_METADATA),
+            Kind::from(KIND_GROUP_ADMINS),
+            Kind::from(KIND_GROUP_MEMBERS),
+        ])
+        .author(*relay_pubkey)
+        .limit(500)
+}
+
+/// Whether a group state event was signed by the relay itself
+/// Relays may ignore the author filter, so this is checked on receipt too
+pub fn is_relay_signed(event: &Event, relay_pubkey: &PublicKey) -> bool {
+    event.pubkey == *relay_pubkey
+}
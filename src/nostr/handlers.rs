@@ -0,0 +1,296 @@
+//! App handlers - NIP-89 (kinds 31989, 31990)
+//!
+//! A handler information event (kind 31990) lists the event kinds an app can
+//! show in `k` tags, and in `web` tags the URLs that open an event in it, with
+//! `<bech32>` standing in for the event's nevent or naddr. People recommend an
+//! app for a kind with a kind 31989 event whose `d` tag is that kind and whose
+//! `a` tags point at handler events.
+//!
+//! PlebClient advertises the kinds it renders the same way, from the app's
+//! own key rather than any user's account, and events the user publishes can
+//! carry a `client` tag naming it and that advertisement (off by default). Notes
+//! of kinds PlebClient doesn't render get `extras.handler` so QML can offer to
+//! open them in a recommended app instead.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashMap;
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+use crate::core::config::Config;
+use super::relay::{RelayManager, KIND_PICTURE, KIND_SHORT_VIDEO};
+
+/// Handler recommendation event
+pub const KIND_HANDLER_RECOMMENDATION: u16 = 31989;
+/// Handler information event
+pub const KIND_HANDLER_INFO: u16 = 31990;
+
+/// Name used in client tags and our handler advertisement
+pub const CLIENT_NAME: &str = "PlebClient";
+
+/// `d` tag of the handler advertisement
+const HANDLER_ID: &str = "plebclient";
+
+/// Hex pubkey of the app key that signs the handler advertisement, fixed at
+/// build time; without it client tags carry only the name
+const HANDLER_PUBKEY: Option<&str> = option_env!("PLEBCLIENT_HANDLER_PUBKEY");
+
+const CLIENT_ABOUT: &str = "Desktop Nostr client";
+const CLIENT_WEBSITE: &str = "https://github.com/PlebOne/pleb-client";
+
+/// Handlers offered per unknown kind
+const MAX_HANDLERS: usize = 3;
+
+/// Handler and recommendation events fetched per kind
+const HANDLER_LIMIT: usize = 200;
+
+/// How long looked-up handlers for a kind are reused
+const HANDLER_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Kinds PlebClient renders natively
+pub fn supported_kinds() -> Vec<u16> {
+    vec![
+        0,
+        1,
+        6,
+        KIND_PICTURE,
+        KIND_SHORT_VIDEO,
+        super::polls::KIND_POLL,
+        super::zap_goals::KIND_ZAP_GOAL,
+        super::highlights::KIND_HIGHLIGHT,
+        30023,
+        super::live::KIND_LIVE_EVENT,
+    ]
+}
+
+lazy_static::lazy_static! {
+    /// Whether published events are tagged with the client
+    static ref CLIENT_TAG: RwLock<bool> = RwLock::new(Config::load().client_tag);
+
+    /// Kind -> ranked handlers and when they were looked up
+    static ref HANDLER_CACHE: Mutex<HashMap<u16, (Instant, Vec<Handler>)>> = Mutex::new(HashMap::new());
+}
+
+/// Whether published events carry a client tag
+pub fn client_tag_enabled() -> bool {
+    *CLIENT_TAG.read().unwrap()
+}
+
+/// Tag events published from now on with the client, or stop doing so
+pub fn set_client_tag_enabled(enabled: bool) {
+    tracing::info!("Client tag: {}", if enabled { "on" } else { "off" });
+    *CLIENT_TAG.write().unwrap() = enabled;
+}
+
+/// The `client` tag for published events, None while it's turned off
+pub fn client_tag() -> Option<Tag> {
+    if !client_tag_enabled() {
+        return None;
+    }
+    let mut values = vec![CLIENT_NAME.to_string()];
+    values.extend(handler_address());
+    Some(Tag::custom(TagKind::custom("client"), values))
+}
+
+/// Add the client tag to an event about to be signed, if it's turned on
+pub fn tag_client(builder: EventBuilder) -> EventBuilder {
+    match client_tag() {
+        Some(tag) => builder.tag(tag),
+        None => builder,
+    }
+}
+
+/// `31990:<app pubkey>:<d>` of PlebClient's handler advertisement, None if
+/// the build has no app key
+pub fn handler_address() -> Option<String> {
+    let pubkey = PublicKey::from_hex(HANDLER_PUBKEY?).ok()?;
+    Some(format!("{}:{}:{}", KIND_HANDLER_INFO, pubkey.to_hex(), HANDLER_ID))
+}
+
+/// Build the handler advertisement for the kinds PlebClient renders
+/// Signed with the app key when releasing, never from a user's account
+pub fn handler_info_builder() -> EventBuilder {
+    let metadata = serde_json::json!({
+        "name": CLIENT_NAME,
+        "about": CLIENT_ABOUT,
+        "website": CLIENT_WEBSITE,
+    });
+    let mut tags = vec![Tag::identifier(HANDLER_ID)];
+    tags.extend(supported_kinds().into_iter().map(|kind| Tag::custom(TagKind::custom("k"), [kind.to_string()])));
+    EventBuilder::new(Kind::from(KIND_HANDLER_INFO), metadata.to_string()).tags(tags)
+}
+
+/// An app that can open a kind, parsed from its kind 31990 event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Handler {
+    pub address: String,
+    pub name: String,
+    pub picture: Option<String>,
+    /// NIP-19 entity ("nevent", "naddr", ...; "" when unspecified) -> URL template
+    #[serde(skip)]
+    templates: HashMap<String, String>,
+    /// People the user follows who recommend it for the kind
+    pub recommendations: u32,
+    #[serde(skip)]
+    created_at: Timestamp,
+}
+
+impl Handler {
+    /// Parse a kind 31990 event; None if it isn't one or has no web URLs
+    pub fn from_event(event: &Event) -> Option<Self> {
+        if event.kind.as_u16() != KIND_HANDLER_INFO {
+            return None;
+        }
+        let identifier = event.tags.identifier().unwrap_or_default().to_string();
+        let metadata: serde_json::Value = serde_json::from_str(&event.content).unwrap_or_default();
+        let text = |key: &str| metadata.get(key)
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string);
+
+        let mut templates = HashMap::new();
+        for tag in event.tags.iter() {
+            let values = tag.as_slice();
+            if values.first().map(|s| s.as_str()) != Some("web") {
+                continue;
+            }
+            let Some(template) = values.get(1).filter(|t| t.starts_with("https://") && t.contains("<bech32>")) else {
+                continue;
+            };
+            let entity = values.get(2).cloned().unwrap_or_default();
+            templates.entry(entity).or_insert_with(|| template.clone());
+        }
+        if templates.is_empty() {
+            return None;
+        }
+
+        let name = text("display_name").or_else(|| text("name"))
+            .or_else(|| (!identifier.is_empty()).then(|| identifier.clone()))?;
+        Some(Handler {
+            address: format!("{}:{}:{}", KIND_HANDLER_INFO, event.pubkey.to_hex(), identifier),
+            name,
+            picture: text("picture").filter(|p| p.starts_with("http")),
+            templates,
+            recommendations: 0,
+            created_at: event.created_at,
+        })
+    }
+
+    /// URL opening `bech32` (an nevent or naddr) in this app
+    pub fn url_for(&self, bech32: &str) -> Option<String> {
+        let entity = bech32.split('1').next().unwrap_or_default();
+        let template = self.templates.get(entity).or_else(|| self.templates.get(""))?;
+        Some(template.replace("<bech32>", bech32))
+    }
+}
+
+/// Newest handler per address, most recommended first
+pub fn rank(handler_events: &[Event], recommendations: &[Event], kind: u16) -> Vec<Handler> {
+    let mut handlers: HashMap<String, Handler> = HashMap::new();
+    for handler in handler_events.iter().filter_map(Handler::from_event) {
+        match handlers.get(&handler.address) {
+            Some(existing) if existing.created_at >= handler.created_at => {}
+            _ => {
+                handlers.insert(handler.address.clone(), handler);
+            }
+        }
+    }
+
+    // One vote per recommender and handler
+    let kind = kind.to_string();
+    for recommendation in recommendations {
+        if recommendation.kind.as_u16() != KIND_HANDLER_RECOMMENDATION
+            || recommendation.tags.identifier() != Some(kind.as_str()) {
+            continue;
+        }
+        let mut voted: Vec<&str> = Vec::new();
+        for tag in recommendation.tags.iter() {
+            let values = tag.as_slice();
+            if values.first().map(|s| s.as_str()) != Some("a") {
+                continue;
+            }
+            let Some(address) = values.get(1) else { continue };
+            if voted.contains(&address.as_str()) {
+                continue;
+            }
+            if let Some(handler) = handlers.get_mut(address) {
+                handler.recommendations += 1;
+                voted.push(address.as_str());
+            }
+        }
+    }
+
+    let mut ranked: Vec<Handler> = handlers.into_values().collect();
+    ranked.sort_by(|a, b| b.recommendations.cmp(&a.recommendations).then_with(|| a.name.cmp(&b.name)));
+    ranked
+}
+
+/// Apps that can open `kind`, ranked by recommendations from people the user follows
+/// Looked up once per HANDLER_TTL
+pub async fn handlers_for_kind(manager: &RelayManager, kind: u16) -> Result<Vec<Handler>, String> {
+    if let Some((fetched, handlers)) = HANDLER_CACHE.lock().unwrap().get(&kind) {
+        if fetched.elapsed() < HANDLER_TTL {
+            return Ok(handlers.clone());
+        }
+    }
+
+    let handler_filter = Filter::new()
+        .kind(Kind::from(KIND_HANDLER_INFO))
+        .custom_tag(SingleLetterTag::lowercase(Alphabet::K), kind.to_string())
+        .limit(HANDLER_LIMIT);
+    let mut recommendation_filter = Filter::new()
+        .kind(Kind::from(KIND_HANDLER_RECOMMENDATION))
+        .identifier(kind.to_string())
+        .limit(HANDLER_LIMIT);
+    if !manager.following().is_empty() {
+        recommendation_filter = recommendation_filter.authors(manager.following().iter().copied());
+    }
+
+    let timeout = super::relay::fetch_timeout();
+    let (handler_events, recommendations) = tokio::join!(
+        manager.client().fetch_events(handler_filter, timeout),
+        manager.client().fetch_events(recommendation_filter, timeout),
+    );
+    let handler_events: Vec<Event> = handler_events
+        .map_err(|e| format!("Failed to fetch handlers: {}", e))?
+        .into_iter()
+        .collect();
+    let recommendations: Vec<Event> = recommendations
+        .map(|events| events.into_iter().collect())
+        .unwrap_or_default();
+
+    let mut handlers = rank(&handler_events, &recommendations, kind);
+    handlers.truncate(MAX_HANDLERS);
+    tracing::debug!("{} handlers for kind {}", handlers.len(), kind);
+    HANDLER_CACHE.lock().unwrap().insert(kind, (Instant::now(), handlers.clone()));
+    Ok(handlers)
+}
+
+/// Renderer hook: for kinds PlebClient doesn't render, the kind and the
+/// nevent/naddr a handler app opens, under extras.handler
+pub fn render_unknown_kind(event: &Event) -> Option<serde_json::Value> {
+    let kind = event.kind.as_u16();
+    if supported_kinds().contains(&kind) {
+        return None;
+    }
+    let bech32 = if event.kind.is_addressable() {
+        let identifier = event.tags.identifier().unwrap_or_default();
+        let coordinate = Coordinate::new(event.kind, event.pubkey).identifier(identifier);
+        Nip19Coordinate::new(coordinate, Vec::<RelayUrl>::new()).to_bech32().ok()?
+    } else {
+        let mut nevent = Nip19Event::new(event.id);
+        nevent.author = Some(event.pubkey);
+        nevent.kind = Some(event.kind);
+        nevent.to_bech32().ok()?
+    };
+    Some(serde_json::json!({
+        "kind": kind,
+        "bech32": bech32,
+    }))
+}
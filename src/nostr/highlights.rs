@@ -0,0 +1,119 @@
+//! Highlights - NIP-84 (kind 9802)
+//!
+//! A highlight's content is the excerpt itself. The source is referenced with
+//! an `e` tag (plus an `a` tag for addressable sources such as articles), its
+//! author with a `p` tag marked "author", and the surrounding paragraph goes in
+//! a `context` tag when the excerpt is only part of it.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+/// Highlight event
+pub const KIND_HIGHLIGHT: u16 = 9802;
+
+/// Longest excerpt accepted when highlighting
+pub const MAX_HIGHLIGHT_CHARS: usize = 2000;
+
+/// A parsed kind 9802 event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Highlight {
+    pub text: String,
+    pub context: Option<String>,
+    pub comment: Option<String>,
+    pub source_event_id: Option<String>,
+    pub source_address: Option<String>,
+    pub source_url: Option<String>,
+    /// Hex pubkeys of the source's authors
+    pub authors: Vec<String>,
+}
+
+impl Highlight {
+    /// Parse a kind 9802 event; None if it isn't one or is empty
+    pub fn from_event(event: &Event) -> Option<Self> {
+        if event.kind.as_u16() != KIND_HIGHLIGHT || event.content.trim().is_empty() {
+            return None;
+        }
+        let mut highlight = Highlight {
+            text: event.content.clone(),
+            context: None,
+            comment: None,
+            source_event_id: None,
+            source_address: None,
+            source_url: None,
+            authors: Vec::new(),
+        };
+        for tag in event.tags.iter() {
+            let values = tag.as_slice();
+            let (Some(name), Some(value)) = (values.first(), values.get(1)) else { continue };
+            if value.is_empty() {
+                continue;
+            }
+            match name.as_str() {
+                "e" if EventId::from_hex(value).is_ok() => {
+                    highlight.source_event_id.get_or_insert_with(|| value.clone());
+                }
+                "a" => {
+                    highlight.source_address.get_or_insert_with(|| value.clone());
+                }
+                "r" if value.starts_with("http") => {
+                    highlight.source_url.get_or_insert_with(|| value.clone());
+                }
+                "p" if PublicKey::from_hex(value).is_ok() => {
+                    // Mentions in the highlighted text carry "mention"; anything else is a source author
+                    if values.get(3).map(|r| r.as_str()) != Some("mention") && !highlight.authors.contains(value) {
+                        highlight.authors.push(value.clone());
+                    }
+                }
+                "context" => highlight.context = Some(value.clone()),
+                "comment" => highlight.comment = Some(value.clone()),
+                _ => {}
+            }
+        }
+        Some(highlight)
+    }
+}
+
+/// Renderer hook: the excerpt and its source under extras.highlight
+pub fn render_highlight(event: &Event) -> Option<serde_json::Value> {
+    let highlight = Highlight::from_event(event)?;
+    serde_json::to_value(&highlight).ok()
+}
+
+/// The paragraph of `content` containing `excerpt`, if it is more than the excerpt
+pub fn context_for(content: &str, excerpt: &str) -> Option<String> {
+    let start = content.find(excerpt)?;
+    let end = start + excerpt.len();
+    let para_start = content[..start].rfind("\n\n").map(|i| i + 2).unwrap_or(0);
+    let para_end = content[end..].find("\n\n").map(|i| end + i).unwrap_or(content.len());
+    let paragraph = content[para_start..para_end].trim();
+    (paragraph != excerpt).then(|| paragraph.to_string())
+}
+
+/// Build a highlight of `text` taken from `source`
+pub fn highlight_builder(source: &Event, text: &str) -> Result<EventBuilder, String> {
+    // Qt selections separate paragraphs with U+2029
+    let text = text.replace('\u{2029}', "\n");
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Select some text to highlight".to_string());
+    }
+    if text.chars().count() > MAX_HIGHLIGHT_CHARS {
+        return Err(format!("Highlights are limited to {} characters", MAX_HIGHLIGHT_CHARS));
+    }
+
+    let mut tags = vec![Tag::event(source.id)];
+    if source.kind.is_addressable() {
+        if let Some(identifier) = source.tags.identifier() {
+            let coordinate = Coordinate::new(source.kind, source.pubkey).identifier(identifier);
+            tags.push(Tag::coordinate(coordinate, None));
+        }
+    }
+    tags.push(Tag::custom(TagKind::p(), [source.pubkey.to_hex(), String::new(), "author".to_string()]));
+    if let Some(context) = context_for(&source.content, text) {
+        tags.push(Tag::custom(TagKind::custom("context"), [context]));
+    }
+    Ok(EventBuilder::new(Kind::from(KIND_HIGHLIGHT), text).tags(tags))
+}
@@ -0,0 +1,86 @@
+//! Followed hashtags - the NIP-51 interest list (kind 10015)
+//!
+//! The list is replaceable, so following or unfollowing a tag republishes the
+//! whole list. Tags other than `t` (e.g. `a` references to interest sets made
+//! by other clients) are carried over untouched. The "hashtags" feed shows
+//! recent notes tagged with any followed hashtag.
+
+use nostr_sdk::prelude::*;
+
+use super::database::NostrDbManager;
+use super::relay::RelayManager;
+
+/// NIP-51 interest list
+pub const KIND_INTEREST_LIST: u16 = 10015;
+
+/// Feed name of the followed hashtags timeline
+pub const HASHTAG_FEED: &str = "hashtags";
+
+/// Stored versions read per lookup
+const MAX_STORED_LISTS: usize = 10;
+
+/// Hashtag as stored in `t` tags: no leading '#', lowercase, trimmed
+/// None if nothing is left
+pub fn normalize(tag: &str) -> Option<String> {
+    let tag = tag.trim().trim_start_matches('#').trim().to_lowercase();
+    (!tag.is_empty() && !tag.contains(char::is_whitespace)).then_some(tag)
+}
+
+/// Hashtags of an interest list event, in list order without duplicates
+pub fn hashtags(event: &Event) -> Vec<String> {
+    let mut tags = Vec::new();
+    for tag in event.tags.iter() {
+        let values = tag.as_slice();
+        if values.first().map(|s| s.as_str()) != Some("t") {
+            continue;
+        }
+        if let Some(tag) = values.get(1).and_then(|v| normalize(v)) {
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+    }
+    tags
+}
+
+/// Newest stored interest list of the user
+pub fn latest(user: &PublicKey) -> Option<Event> {
+    let db = NostrDbManager::global().ok()?;
+    db.query_author_events(&[KIND_INTEREST_LIST], &[*user], MAX_STORED_LISTS)
+        .ok()?
+        .into_iter()
+        .max_by_key(|e| e.created_at)
+}
+
+/// The user's followed hashtags from nostrdb
+pub fn user_hashtags(user: &PublicKey) -> Vec<String> {
+    latest(user).map(|event| hashtags(&event)).unwrap_or_default()
+}
+
+/// Unsigned interest list with `tags` as its hashtags, keeping anything else
+/// the previous version had
+pub fn list_builder(previous: Option<&Event>, tags: &[String]) -> EventBuilder {
+    let mut list_tags: Vec<Tag> = previous
+        .map(|event| event.tags.iter()
+            .filter(|tag| tag.as_slice().first().map(|s| s.as_str()) != Some("t"))
+            .cloned()
+            .collect())
+        .unwrap_or_default();
+    list_tags.extend(tags.iter().map(|tag| Tag::hashtag(tag)));
+    let content = previous.map(|event| event.content.clone()).unwrap_or_default();
+    EventBuilder::new(Kind::Custom(KIND_INTEREST_LIST), content).tags(list_tags)
+}
+
+/// Store a published list so lookups see it immediately
+pub fn store(event: &Event) -> Result<(), String> {
+    NostrDbManager::global()?.ingest_events(&[event.clone()])?;
+    Ok(())
+}
+
+/// Fetch the user's interest list from relays and store it
+/// Returns the followed hashtags now known
+pub async fn refresh(manager: &RelayManager, user: &PublicKey) -> Result<Vec<String>, String> {
+    let events = manager.fetch_latest_replaceable(user, &[Kind::Custom(KIND_INTEREST_LIST)]).await?;
+    NostrDbManager::global()?.ingest_events(&events)?;
+    Ok(user_hashtags(user))
+}
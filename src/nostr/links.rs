@@ -0,0 +1,71 @@
+//! Web viewer links - shareable https URLs for notes
+//!
+//! Builds nevent-based URLs (with author, kind and relay hints) for a web
+//! viewer such as njump or primal, or a custom template from the config.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use nostr_sdk::prelude::*;
+
+/// Web viewer used when the config doesn't name one
+pub const DEFAULT_WEB_VIEWER: &str = "njump";
+
+/// Maximum relay hints embedded in a link (keeps URLs short)
+const MAX_RELAY_HINTS: usize = 3;
+
+/// URL template for a viewer; `{nevent}` is replaced with the bech32 reference
+/// Unknown values are treated as custom templates
+fn viewer_template(viewer: &str) -> String {
+    match viewer.trim().to_lowercase().as_str() {
+        "" | "njump" => "https://njump.me/{nevent}".to_string(),
+        "primal" => "https://primal.net/e/{nevent}".to_string(),
+        _ => viewer.trim().to_string(),
+    }
+}
+
+/// Build a NIP-19 nevent with author, kind and up to MAX_RELAY_HINTS relays
+pub fn nevent_with_hints(
+    event_id: EventId,
+    author: Option<PublicKey>,
+    kind: Option<Kind>,
+    relays: &[String],
+) -> Result<String, String> {
+    let mut nevent = Nip19Event::new(event_id);
+    nevent.author = author;
+    nevent.kind = kind;
+    nevent.relays = relays.iter()
+        .filter_map(|r| RelayUrl::parse(r).ok())
+        .take(MAX_RELAY_HINTS)
+        .collect();
+
+    nevent.to_bech32()
+        .map_err(|e| format!("Failed to encode nevent: {}", e))
+}
+
+/// Web viewer URL for a note
+pub fn note_web_url(
+    viewer: &str,
+    event_id: EventId,
+    author: Option<PublicKey>,
+    kind: Option<Kind>,
+    relays: &[String],
+) -> Result<String, String> {
+    let nevent = nevent_with_hints(event_id, author, kind, relays)?;
+    let template = viewer_template(viewer);
+
+    if template.contains("{nevent}") {
+        Ok(template.replace("{nevent}", &nevent))
+    } else {
+        // Custom base URL without a placeholder
+        Ok(format!("{}/{}", template.trim_end_matches('/'), nevent))
+    }
+}
+
+/// Open a URL in the user's default browser
+pub fn open_in_browser(url: &str) -> Result<(), String> {
+    std::process::Command::new("xdg-open")
+        .arg(url)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to open browser: {}", e))
+}
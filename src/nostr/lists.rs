@@ -0,0 +1,199 @@
+//! Follow sets (NIP-51) - kind 30000 named lists of people
+//!
+//! Each set is an addressable event keyed by its `d` tag, so editing a set
+//! republishes the whole list. Sets are kept in nostrdb; a list feed is named
+//! "list:<d-tag>" and shows notes from the set's members.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashMap;
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+use super::database::NostrDbManager;
+use super::relay::RelayManager;
+
+/// NIP-51 follow set
+pub const KIND_FOLLOW_SET: u16 = 30000;
+
+/// Feed name prefix for list feeds
+pub const LIST_FEED_PREFIX: &str = "list:";
+
+/// Stored sets read per lookup; plenty for any real user
+const MAX_STORED_SETS: usize = 200;
+
+/// A named follow set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FollowSet {
+    /// The `d` tag
+    pub identifier: String,
+    pub title: String,
+    pub description: String,
+    pub image: Option<String>,
+    /// Member pubkeys (hex)
+    pub pubkeys: Vec<String>,
+    pub created_at: i64,
+}
+
+impl FollowSet {
+    /// Parse a kind 30000 event; None if it isn't one or lacks a `d` tag
+    pub fn from_event(event: &Event) -> Option<Self> {
+        if event.kind.as_u16() != KIND_FOLLOW_SET {
+            return None;
+        }
+        let mut set = FollowSet {
+            identifier: event.tags.identifier()?.to_string(),
+            title: String::new(),
+            description: String::new(),
+            image: None,
+            pubkeys: Vec::new(),
+            created_at: event.created_at.as_secs() as i64,
+        };
+        for tag in event.tags.iter() {
+            let values = tag.as_slice();
+            let (Some(name), Some(value)) = (values.first(), values.get(1)) else { continue };
+            match name.as_str() {
+                "title" | "name" if set.title.is_empty() => set.title = value.clone(),
+                "description" => set.description = value.clone(),
+                "image" => set.image = Some(value.clone()),
+                "p" => {
+                    if let Ok(pk) = PublicKey::parse(value) {
+                        let hex = pk.to_hex();
+                        if !set.pubkeys.contains(&hex) {
+                            set.pubkeys.push(hex);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        Some(set)
+    }
+
+    /// Title to show, falling back to the identifier
+    pub fn display_title(&self) -> &str {
+        if self.title.is_empty() { &self.identifier } else { &self.title }
+    }
+
+    /// Feed name that loads this set's timeline
+    pub fn feed_name(&self) -> String {
+        format!("{}{}", LIST_FEED_PREFIX, self.identifier)
+    }
+
+    /// Member pubkeys that parse
+    pub fn members(&self) -> Vec<PublicKey> {
+        self.pubkeys.iter().filter_map(|pk| PublicKey::parse(pk).ok()).collect()
+    }
+
+    /// Unsigned kind 30000 event publishing this set
+    pub fn to_event_builder(&self) -> EventBuilder {
+        let mut tags = vec![Tag::identifier(&self.identifier)];
+        if !self.title.is_empty() {
+            tags.push(Tag::custom(TagKind::Title, [self.title.clone()]));
+        }
+        if !self.description.is_empty() {
+            tags.push(Tag::custom(TagKind::Description, [self.description.clone()]));
+        }
+        if let Some(image) = &self.image {
+            tags.push(Tag::custom(TagKind::Image, [image.clone()]));
+        }
+        tags.extend(self.members().into_iter().map(Tag::public_key));
+        EventBuilder::new(Kind::Custom(KIND_FOLLOW_SET), "").tags(tags)
+    }
+}
+
+/// `d` tag for a new set: a slug of the title plus a timestamp so titles can repeat
+pub fn new_identifier(title: &str) -> String {
+    let slug: String = title.trim().to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .split('-')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    let stamp = Timestamp::now().as_secs();
+    if slug.is_empty() { format!("list-{}", stamp) } else { format!("{}-{}", slug, stamp) }
+}
+
+/// The set identifier of a "list:<d-tag>" feed name
+pub fn list_identifier(feed: &str) -> Option<&str> {
+    feed.strip_prefix(LIST_FEED_PREFIX).filter(|id| !id.is_empty())
+}
+
+/// Coordinate of one of the user's sets
+pub fn coordinate(user: &PublicKey, identifier: &str) -> Coordinate {
+    Coordinate::new(Kind::Custom(KIND_FOLLOW_SET), *user).identifier(identifier)
+}
+
+/// Unsigned NIP-09 deletion request for one of the user's sets
+pub fn deletion_builder(user: &PublicKey, identifier: &str) -> EventBuilder {
+    EventBuilder::delete(EventDeletionRequest::new().coordinate(coordinate(user, identifier)))
+}
+
+/// The user's follow sets from nostrdb, newest version of each, sorted by title
+/// Sets with a later deletion request are left out
+pub fn user_sets(user: &PublicKey) -> Vec<FollowSet> {
+    let Ok(db) = NostrDbManager::global() else { return Vec::new() };
+    let events = db.query_author_events(&[KIND_FOLLOW_SET, Kind::EventDeletion.as_u16()], &[*user], MAX_STORED_SETS)
+        .unwrap_or_default();
+
+    // Coordinate key -> newest deletion time
+    let mut deleted: HashMap<String, i64> = HashMap::new();
+    for event in events.iter().filter(|e| e.kind == Kind::EventDeletion) {
+        for tag in event.tags.iter() {
+            let values = tag.as_slice();
+            if values.first().map(|s| s.as_str()) == Some("a") {
+                if let Some(coord) = values.get(1) {
+                    let at = deleted.entry(coord.clone()).or_insert(0);
+                    *at = (*at).max(event.created_at.as_secs() as i64);
+                }
+            }
+        }
+    }
+
+    let mut newest: HashMap<String, FollowSet> = HashMap::new();
+    for set in events.iter().filter_map(FollowSet::from_event) {
+        if newest.get(&set.identifier).map_or(true, |s| s.created_at < set.created_at) {
+            newest.insert(set.identifier.clone(), set);
+        }
+    }
+
+    let mut sets: Vec<FollowSet> = newest.into_values()
+        .filter(|set| {
+            let key = format!("{}:{}:{}", KIND_FOLLOW_SET, user.to_hex(), set.identifier);
+            deleted.get(&key).map_or(true, |at| *at < set.created_at)
+        })
+        .collect();
+    sets.sort_by_key(|s| s.display_title().to_lowercase());
+    sets
+}
+
+/// One of the user's follow sets by identifier
+pub fn get(user: &PublicKey, identifier: &str) -> Option<FollowSet> {
+    user_sets(user).into_iter().find(|s| s.identifier == identifier)
+}
+
+/// Authors of a list feed; empty if the set is unknown or has no members
+pub fn feed_authors(user: &PublicKey, feed: &str) -> Vec<PublicKey> {
+    list_identifier(feed)
+        .and_then(|id| get(user, id))
+        .map(|set| set.members())
+        .unwrap_or_default()
+}
+
+/// Store a published set or deletion so lookups see it immediately
+pub fn store(event: &Event) -> Result<(), String> {
+    NostrDbManager::global()?.ingest_events(&[event.clone()])?;
+    Ok(())
+}
+
+/// Fetch the user's sets and deletions from relays and store them
+/// Returns the number of sets now known
+pub async fn refresh(manager: &RelayManager, user: &PublicKey) -> Result<usize, String> {
+    let events = manager.fetch_follow_sets(user).await?;
+    let events: Vec<Event> = events.into_iter().collect();
+    NostrDbManager::global()?.ingest_events(&events)?;
+    Ok(user_sets(user).len())
+}
@@ -0,0 +1,213 @@
+//! Live streams - NIP-53 live events
+//!
+//! A stream is an addressable kind 30311 event the host keeps replacing while
+//! it runs: title, streaming URL, status (planned/live/ended) and participant
+//! counts. Hosts don't always mark a stream ended, so a "live" event that
+//! hasn't been updated for an hour is treated as ended.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::{HashMap, HashSet};
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+/// Live event (addressable)
+pub const KIND_LIVE_EVENT: u16 = 30311;
+
+/// Relays queried for streams beyond the user's own, where most streaming
+/// clients publish
+pub const POPULAR_LIVE_RELAYS: &[&str] = &[
+    "wss://relay.damus.io",
+    "wss://nos.lol",
+    "wss://relay.primal.net",
+    "wss://relay.snort.social",
+];
+
+/// How far back to look for streams
+pub const LOOKBACK_SECS: u64 = 24 * 60 * 60;
+
+/// A live event not updated for this long is considered over
+pub const STALE_AFTER_SECS: i64 = 60 * 60;
+
+/// Streams fetched per query
+const FETCH_LIMIT: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LiveStatus {
+    Planned,
+    Live,
+    Ended,
+}
+
+impl LiveStatus {
+    fn parse(s: &str) -> Self {
+        match s {
+            "live" => LiveStatus::Live,
+            "ended" => LiveStatus::Ended,
+            _ => LiveStatus::Planned,
+        }
+    }
+}
+
+/// Someone taking part in a stream (`p` tag)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Participant {
+    pub pubkey: String,
+    /// Host, Speaker, Participant, ... as given by the host
+    pub role: Option<String>,
+}
+
+/// A parsed kind 30311 event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveStream {
+    /// Address "30311:<pubkey>:<d>"
+    pub address: String,
+    pub event_id: String,
+    /// Publisher of the event (often a streaming service's key)
+    pub author: String,
+    /// Host participant if named, otherwise the author
+    pub host: String,
+    pub title: Option<String>,
+    pub summary: Option<String>,
+    pub image: Option<String>,
+    pub streaming_url: Option<String>,
+    pub recording_url: Option<String>,
+    pub status: LiveStatus,
+    pub starts: Option<i64>,
+    pub ends: Option<i64>,
+    pub current_participants: Option<u64>,
+    pub total_participants: Option<u64>,
+    pub participants: Vec<Participant>,
+    pub hashtags: Vec<String>,
+    pub created_at: i64,
+}
+
+impl LiveStream {
+    /// Parse a kind 30311 event; None if it isn't one or lacks a `d` tag
+    pub fn from_event(event: &Event) -> Option<Self> {
+        if event.kind.as_u16() != KIND_LIVE_EVENT {
+            return None;
+        }
+        let identifier = event.tags.identifier()?;
+        let author = event.pubkey.to_hex();
+        let mut stream = LiveStream {
+            address: format!("{}:{}:{}", KIND_LIVE_EVENT, author, identifier),
+            event_id: event.id.to_hex(),
+            author: author.clone(),
+            host: author,
+            title: None,
+            summary: None,
+            image: None,
+            streaming_url: None,
+            recording_url: None,
+            status: LiveStatus::Planned,
+            starts: None,
+            ends: None,
+            current_participants: None,
+            total_participants: None,
+            participants: Vec::new(),
+            hashtags: Vec::new(),
+            created_at: event.created_at.as_secs() as i64,
+        };
+
+        for tag in event.tags.iter() {
+            let values = tag.as_slice();
+            let (Some(name), Some(value)) = (values.first(), values.get(1)) else { continue };
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            match name.as_str() {
+                "title" => stream.title = Some(value.to_string()),
+                "summary" => stream.summary = Some(value.to_string()),
+                "image" => stream.image = Some(value.to_string()),
+                "streaming" => stream.streaming_url = Some(value.to_string()),
+                "recording" => stream.recording_url = Some(value.to_string()),
+                "status" => stream.status = LiveStatus::parse(value),
+                "starts" => stream.starts = value.parse().ok(),
+                "ends" => stream.ends = value.parse().ok(),
+                "current_participants" => stream.current_participants = value.parse().ok(),
+                "total_participants" => stream.total_participants = value.parse().ok(),
+                "t" => stream.hashtags.push(value.to_lowercase()),
+                "p" if PublicKey::from_hex(value).is_ok() => {
+                    let role = values.get(3).filter(|r| !r.is_empty()).cloned();
+                    if role.as_deref().is_some_and(|r| r.eq_ignore_ascii_case("host")) {
+                        stream.host = value.to_string();
+                    }
+                    stream.participants.push(Participant { pubkey: value.to_string(), role });
+                }
+                _ => {}
+            }
+        }
+
+        Some(stream)
+    }
+
+    /// Live right now (marked live and updated recently)
+    pub fn is_live(&self, now: i64) -> bool {
+        self.status == LiveStatus::Live && now - self.created_at < STALE_AFTER_SECS
+    }
+
+    /// Whether any of `pubkeys` (hex) publishes or takes part in the stream
+    pub fn involves_any(&self, pubkeys: &HashSet<String>) -> bool {
+        pubkeys.contains(&self.author) || self.participants.iter().any(|p| pubkeys.contains(&p.pubkey))
+    }
+
+    /// Serialize to JSON for QML consumption, with the computed live flag
+    pub fn to_json(&self, now: i64) -> serde_json::Value {
+        let mut json = serde_json::to_value(self).unwrap_or_default();
+        json["isLive"] = serde_json::json!(self.is_live(now));
+        json
+    }
+}
+
+/// Newest version of each stream, live ones first, then by participants and recency
+pub fn collect_streams<'a>(events: impl IntoIterator<Item = &'a Event>, now: i64) -> Vec<LiveStream> {
+    let mut by_address: HashMap<String, LiveStream> = HashMap::new();
+    for stream in events.into_iter().filter_map(LiveStream::from_event) {
+        match by_address.get(&stream.address) {
+            Some(existing) if existing.created_at >= stream.created_at => {}
+            _ => {
+                by_address.insert(stream.address.clone(), stream);
+            }
+        }
+    }
+
+    let mut streams: Vec<LiveStream> = by_address.into_values().collect();
+    streams.sort_by(|a, b| {
+        b.is_live(now).cmp(&a.is_live(now))
+            .then(b.current_participants.unwrap_or(0).cmp(&a.current_participants.unwrap_or(0)))
+            .then(b.created_at.cmp(&a.created_at))
+    });
+    streams
+}
+
+/// Recent streams published by any of `authors`
+pub fn authored_filter(authors: &[PublicKey]) -> Filter {
+    Filter::new()
+        .kind(Kind::from(KIND_LIVE_EVENT))
+        .authors(authors.iter().copied())
+        .since(Timestamp::now() - LOOKBACK_SECS)
+        .limit(FETCH_LIMIT)
+}
+
+/// Recent streams naming any of `pubkeys` as a participant (streaming services
+/// publish with their own key and tag the host)
+pub fn participant_filter(pubkeys: &[PublicKey]) -> Filter {
+    Filter::new()
+        .kind(Kind::from(KIND_LIVE_EVENT))
+        .pubkeys(pubkeys.iter().copied())
+        .since(Timestamp::now() - LOOKBACK_SECS)
+        .limit(FETCH_LIMIT)
+}
+
+/// Recent streams from anyone
+pub fn recent_filter() -> Filter {
+    Filter::new()
+        .kind(Kind::from(KIND_LIVE_EVENT))
+        .since(Timestamp::now() - LOOKBACK_SECS)
+        .limit(FETCH_LIMIT)
+}
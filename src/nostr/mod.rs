@@ -2,12 +2,50 @@
 
 pub mod database;
 pub mod relay;
+pub mod relay_service;
+pub mod relay_info;
+pub mod relay_health;
+pub mod relay_payment;
+pub mod relay_watch;
+pub mod publish;
+pub mod search;
+pub mod search_index;
+pub mod snapshot;
+pub mod catchup;
+pub mod compose;
+pub mod links;
+pub mod embeds;
+pub mod thread;
+pub mod contacts;
+pub mod follow_queue;
+pub mod outbox;
+pub mod lists;
+pub mod interests;
+pub mod social_graph;
+pub mod wot;
+pub mod spam;
 pub mod feed;
 pub mod profile;
+pub mod profile_store;
+pub mod archive;
 pub mod dm;
+pub mod dm_media;
+pub mod groups;
+pub mod channels;
+pub mod live;
+pub mod discovery;
+pub mod polls;
+pub mod highlights;
+pub mod onboarding;
+pub mod nip05;
+pub mod report;
 pub mod nwc;
 pub mod blossom;
+pub mod emoji;
 pub mod zap;
+pub mod zap_history;
+pub mod zap_goals;
+pub mod handlers;
 pub mod tenor;
 
 pub use zap::GLOBAL_NWC_MANAGER;
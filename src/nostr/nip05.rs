@@ -0,0 +1,115 @@
+//! NIP-05 verification - maps internet identifiers to pubkeys
+//!
+//! Resolves `https://<domain>/.well-known/nostr.json?name=<local>` and checks
+//! that the returned pubkey matches the profile. Results (positive and
+//! negative) are cached in the NostrDb manager.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashMap;
+use futures::StreamExt;
+use std::time::Duration;
+use serde::Deserialize;
+
+use super::database::NostrDbManager;
+
+/// Timeout for well-known lookups
+const NIP05_TIMEOUT: Duration = Duration::from_secs(8);
+
+/// Well-known lookups run at once by `check_all`
+const MAX_CONCURRENT_LOOKUPS: usize = 6;
+
+#[derive(Debug, Deserialize)]
+struct NostrJson {
+    #[serde(default)]
+    names: HashMap<String, String>,
+}
+
+/// Split a NIP-05 identifier into (local part, domain)
+/// A bare domain is treated as `_@domain`
+pub fn parse_identifier(nip05: &str) -> Option<(String, String)> {
+    let nip05 = nip05.trim().to_lowercase();
+    let (local, domain) = match nip05.split_once('@') {
+        Some((local, domain)) => (local.to_string(), domain.to_string()),
+        None => ("_".to_string(), nip05),
+    };
+
+    if local.is_empty() || domain.is_empty() || !domain.contains('.') {
+        return None;
+    }
+    Some((local, domain))
+}
+
+/// Resolve a NIP-05 identifier and check it points at the given pubkey (hex)
+pub async fn verify(nip05: &str, pubkey: &str) -> Result<bool, String> {
+    let (local, domain) = parse_identifier(nip05)
+        .ok_or_else(|| format!("Invalid NIP-05 identifier: {}", nip05))?;
+
+    let client = crate::core::network::http_client()
+        .timeout(NIP05_TIMEOUT)
+        // NIP-05: fetchers must ignore redirects
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let mut url = url::Url::parse(&format!("https://{}/.well-known/nostr.json", domain))
+        .map_err(|e| format!("Invalid NIP-05 domain {}: {}", domain, e))?;
+    url.query_pairs_mut().append_pair("name", &local);
+    let response = client.get(url.clone())
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch {}: {}", url, e))?;
+
+    if !response.status().is_success() {
+        return Ok(false);
+    }
+
+    let json: NostrJson = response.json()
+        .await
+        .map_err(|e| format!("Invalid nostr.json from {}: {}", domain, e))?;
+
+    Ok(json.names.get(&local)
+        .map(|pk| pk.eq_ignore_ascii_case(pubkey))
+        .unwrap_or(false))
+}
+
+/// Cached verification result, without doing any network work
+pub fn cached_status(nip05: &str, pubkey: &str) -> Option<bool> {
+    if !NostrDbManager::is_initialized() {
+        return None;
+    }
+    NostrDbManager::global().ok()?.get_nip05_status(nip05, pubkey)
+}
+
+/// Verify using the cache, falling back to a lookup and caching the result
+/// Network errors count as unverified but are not cached
+pub async fn check(nip05: &str, pubkey: &str) -> bool {
+    if let Some(verified) = cached_status(nip05, pubkey) {
+        return verified;
+    }
+
+    match verify(nip05, pubkey).await {
+        Ok(verified) => {
+            if let Ok(db) = NostrDbManager::global() {
+                db.set_nip05_status(nip05, pubkey, verified);
+            }
+            verified
+        }
+        Err(e) => {
+            tracing::debug!("NIP-05 check failed for {}: {}", nip05, e);
+            false
+        }
+    }
+}
+
+/// Check (pubkey, identifier) pairs a few at a time, returning (pubkey, verified)
+pub async fn check_all(checks: Vec<(String, String)>) -> Vec<(String, bool)> {
+    futures::stream::iter(checks)
+        .map(|(pubkey, nip05)| async move {
+            let verified = check(&nip05, &pubkey).await;
+            (pubkey, verified)
+        })
+        .buffer_unordered(MAX_CONCURRENT_LOOKUPS)
+        .collect()
+        .await
+}
@@ -5,6 +5,7 @@
 #![allow(dead_code)]  // Planned infrastructure for future integration
 
 use nostr_sdk::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
@@ -82,6 +83,89 @@ impl NwcConnection {
     }
 }
 
+/// A wallet transaction as returned by list_transactions / lookup_invoice
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NwcTransaction {
+    /// "incoming" or "outgoing"
+    #[serde(rename = "type", default)]
+    pub transaction_type: String,
+    #[serde(default)]
+    pub invoice: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub preimage: Option<String>,
+    #[serde(default)]
+    pub payment_hash: Option<String>,
+    #[serde(default)]
+    pub amount: i64,      // millisats
+    #[serde(default)]
+    pub fees_paid: i64,   // millisats
+    #[serde(default)]
+    pub created_at: i64,
+    #[serde(default)]
+    pub settled_at: Option<i64>,
+}
+
+impl NwcTransaction {
+    /// Amount in sats
+    pub fn amount_sats(&self) -> i64 {
+        self.amount / 1000
+    }
+    
+    /// Check if the payment has settled
+    pub fn is_settled(&self) -> bool {
+        self.settled_at.is_some()
+    }
+    
+    /// Check if this is an incoming payment
+    pub fn is_incoming(&self) -> bool {
+        self.transaction_type == "incoming"
+    }
+    
+    /// Serialize to JSON for QML consumption
+    pub fn to_json(&self) -> String {
+        serde_json::json!({
+            "type": self.transaction_type,
+            "isIncoming": self.is_incoming(),
+            "amountSats": self.amount_sats(),
+            "feesSats": self.fees_paid / 1000,
+            "description": self.description,
+            "invoice": self.invoice,
+            "preimage": self.preimage,
+            "paymentHash": self.payment_hash,
+            "createdAt": self.created_at,
+            "settledAt": self.settled_at,
+        }).to_string()
+    }
+}
+
+/// Direction filter for list_transactions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransactionFilter {
+    All,
+    Incoming,
+    Outgoing,
+}
+
+impl TransactionFilter {
+    pub fn from_str(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "incoming" => TransactionFilter::Incoming,
+            "outgoing" => TransactionFilter::Outgoing,
+            _ => TransactionFilter::All,
+        }
+    }
+    
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionFilter::All => "all",
+            TransactionFilter::Incoming => "incoming",
+            TransactionFilter::Outgoing => "outgoing",
+        }
+    }
+}
+
 /// NWC Manager for wallet operations
 pub struct NwcManager {
     connection: Option<NwcConnection>,
@@ -113,9 +197,7 @@ impl NwcManager {
         let keys = Keys::new(connection.secret.clone());
         
         // Create client with the NWC keys
-        let client = Client::builder()
-            .signer(keys.clone())
-            .build();
+        let client = crate::core::network::nostr_client(Some(keys.clone()));
         
         // Add relay
         client.add_relay(&connection.relay_url).await
@@ -389,6 +471,146 @@ impl NwcManager {
         
         Err("No response from NWC".to_string())
     }
+    
+    /// List wallet transactions (paginated, newest first)
+    pub async fn list_transactions(
+        &self,
+        filter: TransactionFilter,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<NwcTransaction>, String> {
+        let mut params = serde_json::json!({
+            "limit": limit,
+            "offset": offset,
+        });
+        if filter != TransactionFilter::All {
+            params["type"] = serde_json::json!(filter.as_str());
+        }
+        
+        let request = serde_json::json!({
+            "method": "list_transactions",
+            "params": params
+        });
+        
+        let result = self.send_request(&request).await?;
+        
+        let transactions: Vec<NwcTransaction> = result.get("transactions")
+            .cloned()
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| format!("Failed to parse transactions: {}", e))?
+            .unwrap_or_default();
+        
+        Ok(transactions)
+    }
+    
+    /// Look up an invoice by its bolt11 string
+    pub async fn lookup_invoice(&self, invoice: &str) -> Result<NwcTransaction, String> {
+        self.session().ok_or("Not connected to NWC")?.lookup_invoice(invoice).await
+    }
+    
+    /// A handle for talking to the connected wallet without holding the manager
+    /// Lets long-running callers (invoice polling) release the shared lock
+    pub fn session(&self) -> Option<NwcSession> {
+        match (&self.client, &self.connection, &self.keys) {
+            (Some(client), Some(connection), Some(keys)) => Some(NwcSession {
+                client: client.clone(),
+                connection: connection.clone(),
+                keys: keys.clone(),
+            }),
+            _ => None,
+        }
+    }
+    
+    /// Send a request to the wallet and wait for its result object
+    async fn send_request(&self, request: &serde_json::Value) -> Result<serde_json::Value, String> {
+        self.session().ok_or("Not connected to NWC")?.send_request(request).await
+    }
+}
+
+/// Connection to a wallet, detached from the NwcManager it came from
+#[derive(Clone)]
+pub struct NwcSession {
+    client: Client,
+    connection: NwcConnection,
+    keys: Keys,
+}
+
+impl NwcSession {
+    /// Look up an invoice by its bolt11 string
+    pub async fn lookup_invoice(&self, invoice: &str) -> Result<NwcTransaction, String> {
+        let request = serde_json::json!({
+            "method": "lookup_invoice",
+            "params": {
+                "invoice": invoice
+            }
+        });
+        
+        let result = self.send_request(&request).await?;
+        
+        serde_json::from_value(result)
+            .map_err(|e| format!("Failed to parse invoice lookup: {}", e))
+    }
+    
+    /// Send a request to the wallet and wait for its result object
+    async fn send_request(&self, request: &serde_json::Value) -> Result<serde_json::Value, String> {
+        let (client, connection, keys) = (&self.client, &self.connection, &self.keys);
+        
+        // Encrypt request content
+        let encrypted_content = nip04::encrypt(
+            keys.secret_key(),
+            &connection.wallet_pubkey,
+            &request.to_string()
+        ).map_err(|e| format!("Failed to encrypt NWC request: {}", e))?;
+        
+        // Build and sign the event
+        let event = EventBuilder::new(Kind::WalletConnectRequest, encrypted_content)
+            .tag(Tag::public_key(connection.wallet_pubkey.clone()))
+            .sign_with_keys(keys)
+            .map_err(|e| format!("Failed to sign NWC request: {}", e))?;
+        
+        let event_id = event.id.clone();
+        
+        // Send request
+        client.send_event(&event).await
+            .map_err(|e| format!("Failed to send NWC request: {}", e))?;
+        
+        // Wait for response
+        let filter = Filter::new()
+            .kind(Kind::WalletConnectResponse)
+            .author(connection.wallet_pubkey.clone())
+            .custom_tag(SingleLetterTag::lowercase(Alphabet::E), event_id.to_hex())
+            .limit(1);
+        
+        let events = client.fetch_events(filter, std::time::Duration::from_secs(30)).await
+            .map_err(|e| format!("Failed to fetch NWC response: {}", e))?;
+        
+        let Some(response_event) = events.into_iter().next() else {
+            return Err("No response from NWC".to_string());
+        };
+        
+        // Decrypt and parse response
+        let decrypted = nip04::decrypt(
+            keys.secret_key(),
+            &response_event.pubkey,
+            &response_event.content
+        ).map_err(|e| format!("Failed to decrypt NWC response: {}", e))?;
+        
+        let response: serde_json::Value = serde_json::from_str(&decrypted)
+            .map_err(|e| format!("Failed to parse NWC response: {}", e))?;
+        
+        if let Some(error) = response.get("error").filter(|e| !e.is_null()) {
+            let message = error.get("message")
+                .and_then(|m| m.as_str())
+                .map(|m| m.to_string())
+                .unwrap_or_else(|| format!("{:?}", error));
+            return Err(format!("NWC error: {}", message));
+        }
+        
+        response.get("result")
+            .cloned()
+            .ok_or_else(|| "NWC response missing result".to_string())
+    }
 }
 
 impl Default for NwcManager {
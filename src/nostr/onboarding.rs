@@ -0,0 +1,88 @@
+//! Onboarding - first-run setup for newly created accounts
+//!
+//! A fresh key has no profile, relay list or follows, so the feed would be
+//! empty. Onboarding publishes a kind 0 profile, the NIP-65 relay list and a
+//! kind 3 contact list seeded from the starter packs the user picks.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashSet;
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+/// A curated set of accounts to follow
+#[derive(Debug, Clone, Serialize)]
+pub struct StarterPack {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    /// Hex pubkeys
+    pub pubkeys: &'static [&'static str],
+}
+
+/// Packs offered on the onboarding screen
+pub const STARTER_PACKS: &[StarterPack] = &[
+    StarterPack {
+        id: "nostr-builders",
+        title: "Nostr builders",
+        description: "Developers of the protocol and its clients",
+        pubkeys: &[
+            "3bf0c63fcb93463407af97a5e5ee64fa883d107ef9e558472c4eb9aaaefa459d",
+            "32e1827635450ebb3c5a7d12c1f8e7b2b514439ac10a67eef3d9fd9c5c68e245",
+            "fa984bd7dbb282f07e16e7ae87b26a2a7b9b90b7246a44771f0cf5ae58018f52",
+            "97c70a44366a6535c145b333f973ea86dfdc2d7a99da618c40c64705ad98e322",
+            "460c25e682fda7832b52d1f22d3d22b3176d972f60dcdc3212ed8c92ef85065c",
+            "63fe6318dc58583cfe16810f86dd09e18bfd76aabc24a0081ce2856f330504ed",
+        ],
+    },
+    StarterPack {
+        id: "bitcoin",
+        title: "Bitcoin",
+        description: "Bitcoiners, writers and podcasters",
+        pubkeys: &[
+            "04c915daefee38317fa734444acee390a8269fe5810b2241e5e6dd343dfbecc9",
+            "6e468422dfb74a5738702a8823b9b28168abab8655faacb6853cd0ee15deee93",
+            "eab0e756d32b80bcd464f3d844b8040303075a13eabc3599a762c9ac7ab91f4f",
+        ],
+    },
+    StarterPack {
+        id: "freedom-tech",
+        title: "Freedom tech",
+        description: "Privacy, free speech and open source",
+        pubkeys: &[
+            "82341f882b6eabcd2ba7f1ef90aad961cf074af15b9ef44a09f9d2a8fbfbe6a2",
+            "84dee6e676e5bb67b4ad4e042cf70cbd8681155db535942fcc6a0533858a7240",
+            "3f770d65d3a764a9c5cb503ae123e62ec7598ad035d836e2a810f3877a745b24",
+        ],
+    },
+];
+
+/// Pubkeys from the chosen packs, deduplicated; unknown ids are ignored
+pub fn pack_pubkeys(pack_ids: &[String]) -> Vec<PublicKey> {
+    let mut seen = HashSet::new();
+    STARTER_PACKS.iter()
+        .filter(|pack| pack_ids.iter().any(|id| id == pack.id))
+        .flat_map(|pack| pack.pubkeys.iter())
+        .filter_map(|hex| PublicKey::from_hex(hex).ok())
+        .filter(|pk| seen.insert(*pk))
+        .collect()
+}
+
+/// Kind 0 for a new account; empty fields are left out
+pub fn profile_builder(name: &str, about: &str, picture: &str) -> Result<EventBuilder, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Pick a username".to_string());
+    }
+    let mut metadata = Metadata::new().name(name).display_name(name);
+    let about = about.trim();
+    if !about.is_empty() {
+        metadata = metadata.about(about);
+    }
+    let picture = picture.trim();
+    if !picture.is_empty() {
+        let url = Url::parse(picture).map_err(|e| format!("Invalid avatar URL: {}", e))?;
+        metadata = metadata.picture(url);
+    }
+    Ok(EventBuilder::metadata(&metadata))
+}
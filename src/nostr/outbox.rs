@@ -0,0 +1,302 @@
+//! Outbox - posts, reactions and zaps made offline, published once relays are reachable
+//!
+//! A note can be written with local files attached while there is no
+//! connection. The note and its pending uploads are stored alongside the
+//! database; when connectivity returns each file is uploaded, its imeta tag is
+//! filled in with the final URL and only then is the note signed (so
+//! created_at is the publish time) and sent. Upload results are saved as they
+//! arrive, so a retry never uploads the same file twice.
+//!
+//! Other actions (reactions, reposts) are signed right away and stored as the
+//! signed event, which is sent unchanged. A zap needs an invoice from the
+//! recipient's lightning service, so it is stored as the request and paid
+//! once online.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use nostr_sdk::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::compose;
+use super::database::NostrDbManager;
+
+/// Failed attempts before an entry waits for a manual retry
+pub const MAX_OUTBOX_ATTEMPTS: u32 = 10;
+
+/// Where an entry is in its way out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutboxStatus {
+    /// Waiting for connectivity (or for the next retry)
+    #[default]
+    Waiting,
+    Uploading,
+    Publishing,
+    /// Gave up after MAX_OUTBOX_ATTEMPTS; retried only on request
+    Failed,
+}
+
+impl OutboxStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutboxStatus::Waiting => "waiting",
+            OutboxStatus::Uploading => "uploading",
+            OutboxStatus::Publishing => "publishing",
+            OutboxStatus::Failed => "failed",
+        }
+    }
+}
+
+/// An attachment; `url` is set once the file is on the media server
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingMedia {
+    /// Local file path (empty for media that was uploaded before queueing)
+    #[serde(default)]
+    pub path: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub sha256: Option<String>,
+    #[serde(default)]
+    pub mime_type: Option<String>,
+    #[serde(default)]
+    pub size: Option<u64>,
+}
+
+impl PendingMedia {
+    /// An http(s) URL is taken as already uploaded, anything else as a local path
+    pub fn from_source(source: &str) -> Self {
+        let source = source.trim();
+        if source.starts_with("http://") || source.starts_with("https://") {
+            Self { url: Some(source.to_string()), ..Default::default() }
+        } else {
+            let path = source.strip_prefix("file://").unwrap_or(source);
+            Self {
+                path: path.to_string(),
+                mime_type: mime_guess::from_path(path).first().map(|m| m.to_string()),
+                ..Default::default()
+            }
+        }
+    }
+
+    pub fn is_uploaded(&self) -> bool {
+        self.url.is_some()
+    }
+
+    /// NIP-92 imeta tag; None until uploaded
+    pub fn imeta_tag(&self) -> Option<Tag> {
+        let url = self.url.as_ref()?;
+        let mime = self.mime_type.clone().unwrap_or_else(|| {
+            mime_guess::from_path(url).first().map(|m| m.to_string())
+                .unwrap_or_else(|| "image/jpeg".to_string())
+        });
+        let mut values = vec![format!("url {}", url), format!("m {}", mime)];
+        if let Some(sha256) = &self.sha256 {
+            values.push(format!("x {}", sha256));
+        }
+        if let Some(size) = self.size {
+            values.push(format!("size {}", size));
+        }
+        Some(Tag::custom(TagKind::Custom("imeta".into()), values))
+    }
+}
+
+/// A kind 1 note with attachments
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingNote {
+    pub content: String,
+    #[serde(default)]
+    pub content_warning: String,
+    #[serde(default)]
+    pub protected: bool,
+    #[serde(default)]
+    pub media: Vec<PendingMedia>,
+}
+
+impl PendingNote {
+    /// Attachments still to be uploaded
+    pub fn pending_uploads(&self) -> usize {
+        self.media.iter().filter(|m| !m.is_uploaded()).count()
+    }
+
+    /// The unsigned note; fails while uploads are outstanding
+    pub fn builder(&self) -> Result<EventBuilder, String> {
+        if self.pending_uploads() > 0 {
+            return Err(format!("{} attachment(s) not uploaded yet", self.pending_uploads()));
+        }
+
+        // NIP-27: @name -> nostr: URIs, plus t/p tags for hashtags and mentions
+        let prepared = compose::prepare_note(&self.content);
+        let urls: Vec<&str> = self.media.iter().filter_map(|m| m.url.as_deref()).collect();
+        let content = if urls.is_empty() {
+            prepared.content
+        } else {
+            format!("{}\n\n{}", prepared.content, urls.join("\n"))
+        };
+
+        let mut builder = EventBuilder::text_note(&content)
+            .tags(prepared.tags)
+            .tags(self.media.iter().filter_map(|m| m.imeta_tag()));
+        if self.protected {
+            builder = builder.tag(Tag::protected());
+        }
+        let reason = self.content_warning.trim();
+        if !reason.is_empty() {
+            if let Ok(tag) = Tag::parse(["content-warning", reason]) {
+                builder = builder.tag(tag);
+            }
+        }
+        Ok(builder)
+    }
+}
+
+/// An event signed while offline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingEvent {
+    /// What the user did, for display ("Like", "Repost", ...)
+    pub label: String,
+    pub event_json: String,
+}
+
+impl PendingEvent {
+    pub fn new(label: &str, event: &Event) -> Self {
+        Self { label: label.to_string(), event_json: event.as_json() }
+    }
+
+    pub fn event(&self) -> Result<Event, String> {
+        Event::from_json(&self.event_json).map_err(|e| format!("Invalid queued event: {}", e))
+    }
+}
+
+/// A zap to pay once online
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingZap {
+    pub note_id: String,
+    pub amount_sats: u64,
+    #[serde(default)]
+    pub comment: String,
+}
+
+/// What an outbox entry publishes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum OutboxItem {
+    Note(PendingNote),
+    Event(PendingEvent),
+    Zap(PendingZap),
+}
+
+impl OutboxItem {
+    /// Short description for the outbox list
+    pub fn preview(&self) -> String {
+        match self {
+            OutboxItem::Note(note) => note.content.chars().take(140).collect(),
+            OutboxItem::Event(pending) => pending.label.clone(),
+            OutboxItem::Zap(zap) => format!("Zap {} sats", zap.amount_sats),
+        }
+    }
+
+    /// Attachments still to be uploaded (notes only)
+    pub fn pending_uploads(&self) -> usize {
+        match self {
+            OutboxItem::Note(note) => note.pending_uploads(),
+            _ => 0,
+        }
+    }
+}
+
+/// One queued post
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboxEntry {
+    pub id: String,
+    pub created_at: i64,
+    #[serde(default)]
+    pub status: OutboxStatus,
+    #[serde(default)]
+    pub attempts: u32,
+    #[serde(default)]
+    pub last_error: Option<String>,
+    pub item: OutboxItem,
+}
+
+impl OutboxEntry {
+    pub fn new(item: OutboxItem) -> Self {
+        Self {
+            id: format!("outbox-{}", uuid::Uuid::new_v4()),
+            created_at: chrono::Utc::now().timestamp(),
+            status: OutboxStatus::Waiting,
+            attempts: 0,
+            last_error: None,
+            item,
+        }
+    }
+
+    /// Record a failed attempt; stops automatic retries after MAX_OUTBOX_ATTEMPTS
+    pub fn fail(&mut self, error: &str) {
+        self.attempts += 1;
+        self.last_error = Some(error.to_string());
+        self.status = if self.attempts >= MAX_OUTBOX_ATTEMPTS {
+            OutboxStatus::Failed
+        } else {
+            OutboxStatus::Waiting
+        };
+    }
+
+    /// Entry JSON plus a short preview and the number of uploads left
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut value = serde_json::to_value(self).unwrap_or_else(|_| serde_json::json!({}));
+        value["preview"] = serde_json::Value::from(self.item.preview());
+        value["pendingUploads"] = serde_json::Value::from(self.item.pending_uploads());
+        value
+    }
+}
+
+/// Store a new entry for the user
+pub fn add(user: &PublicKey, item: OutboxItem) -> Result<OutboxEntry, String> {
+    let entry = OutboxEntry::new(item);
+    NostrDbManager::global()?.put_outbox_entry(&user.to_hex(), entry.clone());
+    Ok(entry)
+}
+
+/// The user's entries, oldest first
+pub fn list(user: &PublicKey) -> Vec<OutboxEntry> {
+    NostrDbManager::global()
+        .map(|db| db.get_outbox(&user.to_hex()))
+        .unwrap_or_default()
+}
+
+/// Save changes to an entry (upload results, status)
+pub fn update(user: &PublicKey, entry: &OutboxEntry) {
+    if let Ok(db) = NostrDbManager::global() {
+        db.put_outbox_entry(&user.to_hex(), entry.clone());
+    }
+}
+
+/// Remove an entry (published or discarded)
+pub fn remove(user: &PublicKey, id: &str) -> bool {
+    NostrDbManager::global()
+        .map(|db| db.remove_outbox_entry(&user.to_hex(), id))
+        .unwrap_or(false)
+}
+
+/// Number of entries waiting for the user
+pub fn len(user: &PublicKey) -> usize {
+    list(user).len()
+}
+
+/// Make failed entries eligible for the next flush again
+pub fn reset_failed(user: &PublicKey) -> usize {
+    let mut reset = 0;
+    for mut entry in list(user).into_iter().filter(|e| e.status == OutboxStatus::Failed) {
+        entry.status = OutboxStatus::Waiting;
+        entry.attempts = 0;
+        update(user, &entry);
+        reset += 1;
+    }
+    reset
+}
@@ -0,0 +1,221 @@
+//! Polls - NIP-88 (kind 1068)
+//!
+//! A poll is a kind 1068 event whose content is the question and whose
+//! `option` tags list the choices by id. Votes are kind 1018 responses naming
+//! the poll in an `e` tag and the chosen option ids in `response` tags. Each
+//! voter counts once, with their latest response before the poll closes.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashMap;
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+/// Poll event
+pub const KIND_POLL: u16 = 1068;
+/// Vote on a poll
+pub const KIND_POLL_RESPONSE: u16 = 1018;
+
+/// Most options a poll may be created with
+pub const MAX_OPTIONS: usize = 12;
+
+/// Responses fetched per poll
+const RESPONSE_LIMIT: usize = 1000;
+
+/// One choice in a poll
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PollOption {
+    pub id: String,
+    pub label: String,
+}
+
+/// A parsed kind 1068 event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Poll {
+    pub id: String,
+    pub question: String,
+    pub options: Vec<PollOption>,
+    pub multiple_choice: bool,
+    pub ends_at: Option<i64>,
+    /// Relays the author asked votes to be sent to
+    pub relays: Vec<String>,
+}
+
+impl Poll {
+    /// Parse a kind 1068 event; None if it isn't one or has no options
+    pub fn from_event(event: &Event) -> Option<Self> {
+        if event.kind.as_u16() != KIND_POLL {
+            return None;
+        }
+        let mut poll = Poll {
+            id: event.id.to_hex(),
+            question: event.content.clone(),
+            options: Vec::new(),
+            multiple_choice: false,
+            ends_at: None,
+            relays: Vec::new(),
+        };
+        for tag in event.tags.iter() {
+            let values = tag.as_slice();
+            let (Some(name), Some(value)) = (values.first(), values.get(1)) else { continue };
+            match name.as_str() {
+                "option" => {
+                    let label = values.get(2).cloned().unwrap_or_default();
+                    if !value.is_empty() && !poll.options.iter().any(|o| o.id == *value) {
+                        poll.options.push(PollOption { id: value.clone(), label });
+                    }
+                }
+                "polltype" => poll.multiple_choice = value == "multiplechoice",
+                "endsAt" => poll.ends_at = value.parse().ok(),
+                "relay" if RelayUrl::parse(value).is_ok() => poll.relays.push(value.clone()),
+                _ => {}
+            }
+        }
+        if poll.options.is_empty() { None } else { Some(poll) }
+    }
+
+    /// Whether voting has ended
+    pub fn is_closed(&self, now: i64) -> bool {
+        self.ends_at.is_some_and(|ends| now >= ends)
+    }
+
+    /// The option ids a response actually votes for
+    fn choices(&self, response: &Event) -> Vec<String> {
+        let mut chosen: Vec<String> = Vec::new();
+        for tag in response.tags.iter() {
+            let values = tag.as_slice();
+            if values.first().map(|s| s.as_str()) != Some("response") {
+                continue;
+            }
+            let Some(id) = values.get(1) else { continue };
+            if self.options.iter().any(|o| o.id == *id) && !chosen.contains(id) {
+                chosen.push(id.clone());
+            }
+        }
+        // Single choice polls only count the first pick
+        if !self.multiple_choice {
+            chosen.truncate(1);
+        }
+        chosen
+    }
+}
+
+/// Vote counts for a poll
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollResults {
+    pub poll_id: String,
+    /// Option id -> votes
+    pub counts: HashMap<String, u32>,
+    /// People who voted (a multiple choice vote counts once here)
+    pub voters: u32,
+    /// The user's own choices, empty if they haven't voted
+    pub my_votes: Vec<String>,
+    pub closed: bool,
+}
+
+/// Tally responses: latest valid response per voter, ignoring late ones
+pub fn tally(poll: &Poll, responses: &[Event], user: Option<&PublicKey>, now: i64) -> PollResults {
+    let mut latest: HashMap<PublicKey, &Event> = HashMap::new();
+    for response in responses {
+        if response.kind.as_u16() != KIND_POLL_RESPONSE {
+            continue;
+        }
+        if poll.ends_at.is_some_and(|ends| response.created_at.as_secs() as i64 > ends) {
+            continue;
+        }
+        match latest.get(&response.pubkey) {
+            Some(existing) if existing.created_at >= response.created_at => {}
+            _ => {
+                latest.insert(response.pubkey, response);
+            }
+        }
+    }
+
+    let mut results = PollResults {
+        poll_id: poll.id.clone(),
+        counts: poll.options.iter().map(|o| (o.id.clone(), 0)).collect(),
+        closed: poll.is_closed(now),
+        ..Default::default()
+    };
+    for (voter, response) in latest {
+        let choices = poll.choices(response);
+        if choices.is_empty() {
+            continue;
+        }
+        results.voters += 1;
+        for id in &choices {
+            *results.counts.entry(id.clone()).or_insert(0) += 1;
+        }
+        if Some(&voter) == user {
+            results.my_votes = choices;
+        }
+    }
+    results
+}
+
+/// Renderer hook: the poll's options and settings under extras.poll
+pub fn render_poll(event: &Event) -> Option<serde_json::Value> {
+    let poll = Poll::from_event(event)?;
+    serde_json::to_value(&poll).ok()
+}
+
+/// Build a poll; options get short sequential ids
+pub fn poll_builder(question: &str, options: &[String], ends_at: Option<Timestamp>, multiple_choice: bool) -> Result<EventBuilder, String> {
+    let question = question.trim();
+    if question.is_empty() {
+        return Err("A poll needs a question".to_string());
+    }
+    let options: Vec<&str> = options.iter().map(|o| o.trim()).filter(|o| !o.is_empty()).collect();
+    if options.len() < 2 {
+        return Err("A poll needs at least two options".to_string());
+    }
+    if options.len() > MAX_OPTIONS {
+        return Err(format!("A poll can have at most {} options", MAX_OPTIONS));
+    }
+
+    let mut tags: Vec<Tag> = options.iter().enumerate()
+        .map(|(i, label)| Tag::custom(TagKind::custom("option"), [i.to_string(), label.to_string()]))
+        .collect();
+    tags.push(Tag::custom(
+        TagKind::custom("polltype"),
+        [if multiple_choice { "multiplechoice" } else { "singlechoice" }],
+    ));
+    if let Some(ends_at) = ends_at {
+        tags.push(Tag::custom(TagKind::custom("endsAt"), [ends_at.as_secs().to_string()]));
+    }
+    Ok(EventBuilder::new(Kind::from(KIND_POLL), question).tags(tags))
+}
+
+/// Build a vote for the given options
+pub fn response_builder(poll: &Poll, option_ids: &[String]) -> Result<EventBuilder, String> {
+    let poll_id = EventId::from_hex(&poll.id).map_err(|e| format!("Invalid poll id: {}", e))?;
+    let mut chosen: Vec<&String> = option_ids.iter()
+        .filter(|id| poll.options.iter().any(|o| o.id == **id))
+        .collect();
+    chosen.dedup();
+    if chosen.is_empty() {
+        return Err("Pick an option to vote".to_string());
+    }
+    if !poll.multiple_choice && chosen.len() > 1 {
+        return Err("This poll allows only one choice".to_string());
+    }
+
+    let mut tags = vec![Tag::event(poll_id)];
+    tags.extend(chosen.into_iter().map(|id| Tag::custom(TagKind::custom("response"), [id.as_str()])));
+    Ok(EventBuilder::new(Kind::from(KIND_POLL_RESPONSE), "").tags(tags))
+}
+
+/// Filter for the responses to a poll
+pub fn responses_filter(poll: &Poll) -> Option<Filter> {
+    let poll_id = EventId::from_hex(&poll.id).ok()?;
+    let mut filter = Filter::new()
+        .kind(Kind::from(KIND_POLL_RESPONSE))
+        .event(poll_id)
+        .limit(RESPONSE_LIMIT);
+    if let Some(ends_at) = poll.ends_at {
+        filter = filter.until(Timestamp::from(ends_at.max(0) as u64));
+    }
+    Some(filter)
+}
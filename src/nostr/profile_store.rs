@@ -0,0 +1,362 @@
+//! Profile store - kind 0 metadata shared by every bridge
+//!
+//! Feeds, threads, notifications and DMs keep asking for the same authors.
+//! Profiles are kept in memory and in nostrdb, so a lookup only goes to the
+//! relays for authors it hasn't seen, and concurrent lookups of an author
+//! share one request. Entries older than PROFILE_TTL are returned as they are
+//! and refreshed in the background; profile edits by followed users arrive
+//! over a live subscription.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+use nostr_sdk::prelude::*;
+use tokio::sync::{broadcast, watch};
+
+use super::database::NostrDbManager;
+use super::relay::{fetch_timeout, RelayManager};
+use super::relay_service;
+use crate::core::cache_stats;
+
+/// How long a fetched profile is used before it is refreshed
+const PROFILE_TTL: Duration = Duration::from_secs(30 * 60);
+
+/// How long an author with no profile on the relays isn't asked for again
+const MISSING_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Most profiles kept in memory; evicted ones are reloaded from nostrdb
+const MAX_PROFILES: usize = 5000;
+
+/// How often the follow watcher checks whether it has been superseded
+const WATCH_POLL: Duration = Duration::from_secs(5);
+
+struct StoredProfile {
+    event: Event,
+    /// When relays last confirmed this version; None if only loaded from nostrdb
+    checked: Option<Instant>,
+}
+
+impl StoredProfile {
+    fn is_fresh(&self) -> bool {
+        self.checked.is_some_and(|at| at.elapsed() < PROFILE_TTL)
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref PROFILES: RwLock<HashMap<PublicKey, StoredProfile>> = RwLock::new(HashMap::new());
+
+    /// Authors the relays had no profile for, and when they were asked
+    static ref MISSING: Mutex<HashMap<PublicKey, Instant>> = Mutex::new(HashMap::new());
+
+    /// Authors being fetched; the receiver returns once that fetch is done
+    static ref IN_FLIGHT: Mutex<HashMap<PublicKey, watch::Receiver<()>>> = Mutex::new(HashMap::new());
+
+    static ref UPDATES: broadcast::Sender<PublicKey> = broadcast::channel(256).0;
+}
+
+/// Bumped to stop the running follow watcher
+static WATCH_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Authors one fetch is responsible for; waiting lookups are released on drop
+struct Claim {
+    pubkeys: Vec<PublicKey>,
+    _done: watch::Sender<()>,
+}
+
+impl Drop for Claim {
+    fn drop(&mut self) {
+        let mut in_flight = IN_FLIGHT.lock().unwrap();
+        for pk in &self.pubkeys {
+            in_flight.remove(pk);
+        }
+    }
+}
+
+/// Claim the authors nobody is fetching yet and collect waits for the rest
+fn claim(pubkeys: &[PublicKey]) -> (Claim, Vec<watch::Receiver<()>>) {
+    let (done, receiver) = watch::channel(());
+    let mut claimed = Vec::new();
+    let mut waiting = Vec::new();
+
+    let mut in_flight = IN_FLIGHT.lock().unwrap();
+    for pk in pubkeys {
+        match in_flight.get(pk) {
+            Some(other) => waiting.push(other.clone()),
+            None => {
+                in_flight.insert(*pk, receiver.clone());
+                claimed.push(*pk);
+            }
+        }
+    }
+    (Claim { pubkeys: claimed, _done: done }, waiting)
+}
+
+/// Keep the newest version of each profile; returns the authors whose profile changed
+fn store(events: impl IntoIterator<Item = Event>) -> Vec<PublicKey> {
+    let now = Instant::now();
+    let mut changed = Vec::new();
+    let mut updated = Vec::new();
+    let mut new_events = Vec::new();
+    {
+        let mut profiles = PROFILES.write().unwrap();
+        for event in events {
+            if event.kind != Kind::Metadata {
+                continue;
+            }
+            match profiles.get_mut(&event.pubkey) {
+                Some(stored) if stored.event.created_at >= event.created_at => {
+                    stored.checked = Some(now);
+                    continue;
+                }
+                Some(_) => updated.push(event.pubkey),
+                None => {}
+            }
+            changed.push(event.pubkey);
+            new_events.push(event.clone());
+            profiles.insert(event.pubkey, StoredProfile { event, checked: Some(now) });
+        }
+        evict(&mut profiles);
+    }
+
+    if !changed.is_empty() {
+        let mut missing = MISSING.lock().unwrap();
+        for pk in &changed {
+            missing.remove(pk);
+        }
+    }
+    if !new_events.is_empty() && NostrDbManager::is_initialized() {
+        if let Ok(db) = NostrDbManager::global() {
+            let _ = db.ingest_profiles(&new_events);
+        }
+    }
+    for pk in updated {
+        let _ = UPDATES.send(pk);
+    }
+    changed
+}
+
+/// Drop the least recently confirmed profiles once the store is over capacity
+fn evict(profiles: &mut HashMap<PublicKey, StoredProfile>) {
+    if profiles.len() <= MAX_PROFILES {
+        return;
+    }
+    // Evict a tenth at a time so this doesn't run on every insert
+    let excess = profiles.len() - MAX_PROFILES * 9 / 10;
+    let mut by_age: Vec<(PublicKey, Option<Instant>)> = profiles.iter()
+        .map(|(pk, stored)| (*pk, stored.checked))
+        .collect();
+    by_age.sort_by_key(|(_, checked)| *checked);
+    for (pk, _) in by_age.into_iter().take(excess) {
+        profiles.remove(&pk);
+    }
+}
+
+/// Load profiles missing from memory out of nostrdb; they count as unconfirmed
+fn load_from_disk(pubkeys: &[PublicKey]) {
+    let absent: Vec<PublicKey> = {
+        let profiles = PROFILES.read().unwrap();
+        pubkeys.iter().filter(|pk| !profiles.contains_key(pk)).copied().collect()
+    };
+    if absent.is_empty() || !NostrDbManager::is_initialized() {
+        return;
+    }
+    let Ok(db) = NostrDbManager::global() else { return };
+    // nostrdb may hold several versions of a profile; keep the newest
+    let events = db.query_author_events(&[Kind::Metadata.as_u16()], &absent, absent.len() * 4)
+        .unwrap_or_default();
+    if events.is_empty() {
+        return;
+    }
+    // Lookups that read nostrdb's memory cache (search results) see them too
+    let _ = db.ingest_profiles(&events);
+
+    let mut profiles = PROFILES.write().unwrap();
+    for event in events {
+        match profiles.get(&event.pubkey) {
+            Some(stored) if stored.event.created_at >= event.created_at => {}
+            _ => {
+                profiles.insert(event.pubkey, StoredProfile { event, checked: None });
+            }
+        }
+    }
+}
+
+/// Fetch profiles from the relays, sharing requests already under way
+async fn fetch(client: &Client, pubkeys: &[PublicKey]) {
+    let (claim, waiting) = claim(pubkeys);
+
+    if !claim.pubkeys.is_empty() {
+        let filter = Filter::new()
+            .kind(Kind::Metadata)
+            .authors(claim.pubkeys.clone());
+        let result = {
+            let _permit = crate::core::limits::relay_fetch_permit().await;
+            client.fetch_events(filter, fetch_timeout()).await
+        };
+
+        match result {
+            Ok(events) => {
+                let found: HashSet<PublicKey> = events.iter().map(|e| e.pubkey).collect();
+                store(events);
+
+                // Cached profiles the relays didn't return stay as they are
+                let now = Instant::now();
+                let mut profiles = PROFILES.write().unwrap();
+                let mut missing = MISSING.lock().unwrap();
+                for pk in claim.pubkeys.iter().filter(|pk| !found.contains(pk)) {
+                    match profiles.get_mut(pk) {
+                        Some(stored) => stored.checked = Some(now),
+                        None => {
+                            missing.insert(*pk, now);
+                        }
+                    }
+                }
+            }
+            Err(e) => tracing::warn!("Failed to fetch profiles: {}", e),
+        }
+    }
+
+    drop(claim);
+    for mut receiver in waiting {
+        // Errors once the other fetch is done, which is all we wait for
+        let _ = receiver.changed().await;
+    }
+}
+
+/// The stored profile events of these authors
+fn collect(pubkeys: &[PublicKey]) -> Events {
+    let profiles = PROFILES.read().unwrap();
+    let mut events = Events::default();
+    for pk in pubkeys {
+        if let Some(stored) = profiles.get(pk) {
+            events.insert(stored.event.clone());
+        }
+    }
+    events
+}
+
+/// Profile events for these authors, going to the relays only for unknown ones
+/// Profiles past their TTL are returned now and refreshed in the background
+pub async fn get(client: &Client, pubkeys: &[PublicKey]) -> Events {
+    let mut seen = HashSet::new();
+    let wanted: Vec<PublicKey> = pubkeys.iter().filter(|pk| seen.insert(**pk)).copied().collect();
+    if wanted.is_empty() {
+        return Events::default();
+    }
+    load_from_disk(&wanted);
+
+    let mut unknown = Vec::new();
+    let mut stale = Vec::new();
+    {
+        let profiles = PROFILES.read().unwrap();
+        let missing = MISSING.lock().unwrap();
+        for pk in &wanted {
+            match profiles.get(pk) {
+                Some(stored) => {
+                    cache_stats::hit(cache_stats::PROFILES);
+                    if !stored.is_fresh() {
+                        stale.push(*pk);
+                    }
+                }
+                None => {
+                    cache_stats::miss(cache_stats::PROFILES);
+                    if !missing.get(pk).is_some_and(|at| at.elapsed() < MISSING_TTL) {
+                        unknown.push(*pk);
+                    }
+                }
+            }
+        }
+    }
+
+    if !stale.is_empty() {
+        let client = client.clone();
+        tokio::spawn(async move {
+            fetch(&client, &stale).await;
+        });
+    }
+    if !unknown.is_empty() {
+        fetch(client, &unknown).await;
+    }
+    collect(&wanted)
+}
+
+/// Profile events for these authors straight from the relays (e.g. when a
+/// profile is opened), falling back to the stored versions
+pub async fn refresh(client: &Client, pubkeys: &[PublicKey]) -> Events {
+    fetch(client, pubkeys).await;
+    collect(pubkeys)
+}
+
+/// The stored profile event of an author, without going to the relays
+pub fn cached(pubkey: &PublicKey) -> Option<Event> {
+    load_from_disk(std::slice::from_ref(pubkey));
+    PROFILES.read().unwrap().get(pubkey).map(|stored| stored.event.clone())
+}
+
+/// Add profile events received elsewhere (search, own edits); returns the
+/// authors whose profile changed
+pub fn ingest(events: &[Event]) -> Vec<PublicKey> {
+    store(events.iter().cloned())
+}
+
+/// Receive the authors whose stored profile is replaced by a newer version from now on
+/// First-time loads aren't announced; whoever asked for them already has them
+pub fn subscribe() -> broadcast::Receiver<PublicKey> {
+    UPDATES.subscribe()
+}
+
+/// Follow kind 0 updates of these users until stopped or superseded by another watch
+pub async fn watch_follows(manager: &RelayManager, follows: Vec<PublicKey>) -> Result<(), String> {
+    let generation = WATCH_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    if follows.is_empty() {
+        return Ok(());
+    }
+
+    let count = follows.len();
+    let filter = Filter::new()
+        .kind(Kind::Metadata)
+        .authors(follows)
+        .since(Timestamp::now());
+    let handle = relay_service::subscribe(manager, filter).await?;
+    let mut notifications = manager.client().notifications();
+    tracing::info!("Watching profile updates of {} follows", count);
+
+    while WATCH_GENERATION.load(Ordering::SeqCst) == generation {
+        let notification = match tokio::time::timeout(WATCH_POLL, notifications.recv()).await {
+            Ok(Ok(notification)) => notification,
+            Ok(Err(tokio::sync::broadcast::error::RecvError::Lagged(_))) => continue,
+            Ok(Err(_)) => break,
+            Err(_) => continue,
+        };
+        let RelayPoolNotification::Event { subscription_id, event, .. } = notification else { continue };
+        if subscription_id != *handle.id() {
+            continue;
+        }
+        if !store(std::iter::once(*event)).is_empty() {
+            tracing::debug!("Profile updated over the follow subscription");
+        }
+    }
+
+    drop(handle);
+    Ok(())
+}
+
+/// Stop the follow watcher (logout)
+pub fn stop_watching() {
+    WATCH_GENERATION.fetch_add(1, Ordering::SeqCst);
+}
+
+/// Entry count, rough size and hit ratio of the in-memory store
+pub fn cache_stats() -> cache_stats::CacheStats {
+    let profiles = PROFILES.read().unwrap();
+    let bytes: usize = profiles.values().map(|stored| stored.event.content.len()).sum();
+    cache_stats::CacheStats::new(cache_stats::PROFILES, profiles.len(), bytes as u64)
+        .with_capacity(MAX_PROFILES)
+}
+
+/// Forget in-memory profiles; nostrdb keeps its copies
+pub fn clear() {
+    PROFILES.write().unwrap().clear();
+    MISSING.lock().unwrap().clear();
+}
@@ -0,0 +1,284 @@
+//! Publish tracking - per-relay OK results and a retry queue for failed relays
+//!
+//! `send` publishes through the shared pool and turns nostr-sdk's output into
+//! a `PublishReport` with one outcome per relay. Relays that failed for a
+//! transient reason (not connected, timeout, `rate-limited:`, `error:`) keep
+//! the signed event in a retry queue; `retry` resends it to just those relays
+//! once they are back. Rejections a relay will repeat (`blocked:`, `invalid:`,
+//! `pow:`, `restricted:`, `auth-required:`) are reported but not retried.
+//! Rejections asking for payment are held per relay instead, and
+//! `release_paid` sends them once the relay has been paid.
+//!
+//! Every report is also broadcast so the bridge can forward it to QML.
+//! Relays answering `duplicate:` count as accepted, as nostr-sdk reports them.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Instant;
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+use super::relay_payment;
+use crate::core::network;
+
+/// Resend attempts per event before it is dropped from the queue
+pub const MAX_PUBLISH_ATTEMPTS: u32 = 8;
+
+/// NIP-01 OK prefixes that mean "don't send this again"
+const PERMANENT_PREFIXES: &[&str] = &["blocked:", "invalid:", "pow:", "restricted:", "auth-required:", "payment-required:"];
+
+/// Events held per relay until it is paid; older ones are dropped
+const MAX_HELD_PER_RELAY: usize = 50;
+
+/// How one relay answered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RelayOutcomeStatus {
+    /// OK true (including duplicates)
+    Ok,
+    /// Failed for a transient reason; queued for retry
+    Queued,
+    /// Rejected for good
+    Rejected,
+}
+
+/// One relay's answer to a publish
+#[derive(Debug, Clone, Serialize)]
+pub struct RelayOutcome {
+    pub url: String,
+    pub status: RelayOutcomeStatus,
+    pub message: String,
+    /// The relay wants paying before it takes the event
+    #[serde(rename = "paymentRequired")]
+    pub payment_required: bool,
+}
+
+/// Per-relay results for one event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishReport {
+    pub event_id: String,
+    pub kind: u16,
+    pub relays: Vec<RelayOutcome>,
+    /// True when this report comes from a retry rather than the first send
+    pub retry: bool,
+}
+
+impl PublishReport {
+    pub fn accepted(&self) -> usize {
+        self.relays.iter().filter(|r| r.status == RelayOutcomeStatus::Ok).count()
+    }
+
+    pub fn queued(&self) -> usize {
+        self.relays.iter().filter(|r| r.status == RelayOutcomeStatus::Queued).count()
+    }
+
+    /// JSON for QML: the report plus accepted/queued/rejected counts
+    pub fn to_json(&self) -> String {
+        let mut value = serde_json::to_value(self).unwrap_or_else(|_| serde_json::json!({}));
+        value["accepted"] = serde_json::Value::from(self.accepted());
+        value["queued"] = serde_json::Value::from(self.queued());
+        value["rejected"] = serde_json::Value::from(self.relays.len() - self.accepted() - self.queued());
+        value["paymentRequired"] = serde_json::Value::from(self.relays.iter().filter(|r| r.payment_required).count());
+        value.to_string()
+    }
+}
+
+struct QueuedPublish {
+    event: Event,
+    relays: HashSet<RelayUrl>,
+    attempts: u32,
+    next_attempt: Instant,
+}
+
+lazy_static::lazy_static! {
+    static ref QUEUE: Mutex<HashMap<EventId, QueuedPublish>> = Mutex::new(HashMap::new());
+    static ref REPORTS: broadcast::Sender<PublishReport> = broadcast::channel(64).0;
+    /// Events rejected until the relay is paid, oldest first
+    static ref HELD_FOR_PAYMENT: Mutex<HashMap<RelayUrl, Vec<Event>>> = Mutex::new(HashMap::new());
+}
+
+/// Receive every publish report from now on
+pub fn subscribe() -> broadcast::Receiver<PublishReport> {
+    REPORTS.subscribe()
+}
+
+fn is_permanent(message: &str) -> bool {
+    let message = message.trim_start().to_lowercase();
+    PERMANENT_PREFIXES.iter().any(|prefix| message.starts_with(prefix))
+}
+
+/// Turn nostr-sdk output into outcomes, queueing transient failures
+/// `untried` are queued relays this send didn't go to; they stay queued
+fn record(event: &Event, output: &Output<EventId>, retry: bool, untried: HashSet<RelayUrl>) -> PublishReport {
+    let mut relays: Vec<RelayOutcome> = output.success.iter()
+        .map(|url| RelayOutcome { url: url.to_string(), status: RelayOutcomeStatus::Ok, message: String::new(), payment_required: false })
+        .collect();
+
+    let mut retry_relays = untried;
+    for (url, message) in output.failed.iter() {
+        let payment_required = relay_payment::is_payment_rejection(message);
+        if payment_required {
+            relay_payment::note_payment_rejection(url.as_str());
+            hold_for_payment(url, event);
+        }
+        let status = if payment_required || is_permanent(message) {
+            RelayOutcomeStatus::Rejected
+        } else {
+            retry_relays.insert(url.clone());
+            RelayOutcomeStatus::Queued
+        };
+        relays.push(RelayOutcome { url: url.to_string(), status, message: message.clone(), payment_required });
+    }
+    relays.sort_by(|a, b| a.url.cmp(&b.url));
+
+    {
+        let mut queue = QUEUE.lock().unwrap();
+        if retry_relays.is_empty() {
+            queue.remove(&event.id);
+        } else {
+            let entry = queue.entry(event.id).or_insert_with(|| QueuedPublish {
+                event: event.clone(),
+                relays: HashSet::new(),
+                attempts: 0,
+                next_attempt: Instant::now(),
+            });
+            if retry {
+                entry.attempts += 1;
+            }
+            entry.relays = retry_relays;
+            entry.next_attempt = Instant::now() + network::reconnect_delay(entry.attempts + 1);
+            if entry.attempts >= MAX_PUBLISH_ATTEMPTS {
+                tracing::warn!("Giving up on publishing {} after {} retries", event.id, entry.attempts);
+                queue.remove(&event.id);
+            }
+        }
+    }
+
+    let report = PublishReport {
+        event_id: event.id.to_hex(),
+        kind: event.kind.as_u16(),
+        relays,
+        retry,
+    };
+    let _ = REPORTS.send(report.clone());
+    report
+}
+
+/// Publish an event to the client's write relays and track each relay's answer
+/// Fails only if nothing could be sent at all (the event is then kept for retry)
+pub async fn send(client: &Client, event: &Event) -> Result<PublishReport, String> {
+    match client.send_event(event).await {
+        Ok(output) => Ok(record(event, &output, false, HashSet::new())),
+        Err(e) => {
+            // Nothing went out (e.g. no relay connected): retry on every write relay
+            let relays: HashSet<RelayUrl> = client.relays().await.into_iter()
+                .filter(|(_, relay)| relay.flags().has_write())
+                .map(|(url, _)| url)
+                .collect();
+            if !relays.is_empty() {
+                QUEUE.lock().unwrap().insert(event.id, QueuedPublish {
+                    event: event.clone(),
+                    relays,
+                    attempts: 0,
+                    next_attempt: Instant::now(),
+                });
+            }
+            Err(format!("Failed to send: {}", e))
+        }
+    }
+}
+
+fn hold_for_payment(url: &RelayUrl, event: &Event) {
+    let mut held = HELD_FOR_PAYMENT.lock().unwrap();
+    let events = held.entry(url.clone()).or_default();
+    if events.iter().any(|e| e.id == event.id) {
+        return;
+    }
+    events.push(event.clone());
+    if events.len() > MAX_HELD_PER_RELAY {
+        events.remove(0);
+    }
+}
+
+/// Send the events a relay turned down for payment, now that it's paid
+/// Events it still rejects for payment are held again
+pub async fn release_paid(client: &Client, relay_url: &str) -> Vec<PublishReport> {
+    let Ok(url) = RelayUrl::parse(relay_url) else {
+        return Vec::new();
+    };
+    let events = HELD_FOR_PAYMENT.lock().unwrap().remove(&url).unwrap_or_default();
+    let mut reports = Vec::new();
+    for event in events {
+        let untried: HashSet<RelayUrl> = QUEUE.lock().unwrap().get(&event.id)
+            .map(|q| q.relays.clone())
+            .unwrap_or_default();
+        match client.send_event_to([url.clone()], &event).await {
+            Ok(output) => reports.push(record(&event, &output, true, untried)),
+            Err(e) => {
+                tracing::warn!("Resending {} to paid relay {} failed: {}", event.id, url, e);
+                hold_for_payment(&url, &event);
+            }
+        }
+    }
+    if !reports.is_empty() {
+        tracing::info!("Resent {} held events to paid relay {}", reports.len(), url);
+    }
+    reports
+}
+
+/// Drop an event from the retry queue (e.g. when the caller retries it itself)
+pub fn forget(event_id: &EventId) {
+    QUEUE.lock().unwrap().remove(event_id);
+}
+
+/// Drop every queued and held event (on logout; they belong to the previous account)
+pub fn reset() {
+    QUEUE.lock().unwrap().clear();
+    HELD_FOR_PAYMENT.lock().unwrap().clear();
+}
+
+/// Events waiting for at least one relay
+pub fn queued_count() -> usize {
+    QUEUE.lock().unwrap().len()
+}
+
+/// Resend queued events to their failed relays that are connected now
+/// `reconnected` forces a retry for that relay even if its backoff hasn't passed
+pub async fn retry(client: &Client, reconnected: Option<&str>) -> Vec<PublishReport> {
+    let connected: HashSet<RelayUrl> = client.relays().await.into_iter()
+        .filter(|(_, relay)| relay.status() == RelayStatus::Connected)
+        .map(|(url, _)| url)
+        .collect();
+    let now = Instant::now();
+
+    let due: Vec<(Event, Vec<RelayUrl>)> = QUEUE.lock().unwrap().values()
+        .filter_map(|queued| {
+            let forced = reconnected.is_some_and(|url| queued.relays.iter().any(|r| r.as_str() == url));
+            if !forced && now < queued.next_attempt {
+                return None;
+            }
+            let targets: Vec<RelayUrl> = queued.relays.intersection(&connected).cloned().collect();
+            (!targets.is_empty()).then(|| (queued.event.clone(), targets))
+        })
+        .collect();
+
+    let mut reports = Vec::new();
+    for (event, targets) in due {
+        let untried: HashSet<RelayUrl> = QUEUE.lock().unwrap().get(&event.id)
+            .map(|q| q.relays.iter().filter(|url| !targets.contains(url)).cloned().collect())
+            .unwrap_or_default();
+        match client.send_event_to(targets.clone(), &event).await {
+            Ok(output) => {
+                let report = record(&event, &output, true, untried);
+                tracing::info!("Retried {}: {} of {} relays accepted", event.id, report.accepted(), targets.len());
+                reports.push(report);
+            }
+            Err(e) => tracing::warn!("Retrying {} failed: {}", event.id, e),
+        }
+    }
+    reports
+}
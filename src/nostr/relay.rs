@@ -5,9 +5,12 @@
 use std::sync::Arc;
 use std::time::Duration;
 use nostr_sdk::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::sync::RwLock;
 use futures::future::join_all;
 
+use super::thread::{ReplyPage, NESTED_REPLY_ROUNDS, REPLY_PAGE_SIZE};
+
 /// Default relays for initial connection
 pub const DEFAULT_RELAYS: &[&str] = &[
     "wss://relay.pleb.one",
@@ -24,39 +27,252 @@ pub const DISCOVERY_RELAYS: &[&str] = &[
     "wss://relay.nostr.band",
 ];
 
-/// Default timeout for relay operations
-pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+/// What a configured relay is used for: reading (fetching), writing
+/// (publishing) and receiving DMs (NIP-17 inbox)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelayRoles {
+    #[serde(default = "default_role")]
+    pub read: bool,
+    #[serde(default = "default_role")]
+    pub write: bool,
+    #[serde(default)]
+    pub dm: bool,
+}
+
+fn default_role() -> bool {
+    true
+}
+
+impl Default for RelayRoles {
+    fn default() -> Self {
+        Self { read: true, write: true, dm: false }
+    }
+}
+
+impl RelayRoles {
+    /// Whether the relay belongs in the main pool (DM-only relays don't)
+    pub fn in_pool(&self) -> bool {
+        self.read || self.write
+    }
+    
+    /// The NIP-65 marker: None for read+write
+    fn metadata(&self) -> Option<RelayMetadata> {
+        match (self.read, self.write) {
+            (true, false) => Some(RelayMetadata::Read),
+            (false, true) => Some(RelayMetadata::Write),
+            _ => None,
+        }
+    }
+}
+
+/// The user's relays from settings, or the defaults if none are configured
+pub fn configured_relays() -> Vec<String> {
+    let relays = crate::core::config::Config::load().relays;
+    if relays.is_empty() {
+        DEFAULT_RELAYS.iter().map(|s| s.to_string()).collect()
+    } else {
+        relays
+    }
+}
+
+/// Whether the relay list is still the one the app ships with
+pub fn uses_default_relays() -> bool {
+    let config = crate::core::config::Config::load();
+    config.relay_roles.is_empty()
+        && config.relays.len() == DEFAULT_RELAYS.len()
+        && config.relays.iter().all(|url| DEFAULT_RELAYS.contains(&url.as_str()))
+}
+
+/// Relays and read/write roles from a NIP-65 relay list (kind 10002)
+pub fn parse_relay_list(event: &Event) -> Vec<(String, RelayRoles)> {
+    let mut relays: Vec<(String, RelayRoles)> = Vec::new();
+    for tag in event.tags.iter() {
+        let values = tag.as_slice();
+        if values.len() < 2 || values[0] != "r" {
+            continue;
+        }
+        let Ok(url) = RelayUrl::parse(values[1].as_str()) else { continue };
+        let url = url.to_string();
+        if relays.iter().any(|(existing, _)| *existing == url) {
+            continue;
+        }
+        let roles = match values.get(2).map(|m| m.as_str()) {
+            Some("read") => RelayRoles { read: true, write: false, dm: false },
+            Some("write") => RelayRoles { read: false, write: true, dm: false },
+            _ => RelayRoles::default(),
+        };
+        relays.push((url, roles));
+    }
+    relays
+}
+
+/// Relays from a NIP-17 DM inbox relay list (kind 10050)
+pub fn parse_dm_relay_list(event: &Event) -> Vec<String> {
+    let mut relays: Vec<String> = Vec::new();
+    for tag in event.tags.iter() {
+        let values = tag.as_slice();
+        if values.len() < 2 || values[0] != "relay" {
+            continue;
+        }
+        let Ok(url) = RelayUrl::parse(values[1].as_str()) else { continue };
+        let url = url.to_string();
+        if !relays.contains(&url) {
+            relays.push(url);
+        }
+    }
+    relays
+}
+
+/// Local relays plus those from the published list that aren't configured
+/// here (added from another client) and weren't just removed
+pub fn merge_relay_lists<T: Clone>(
+    local: &[T],
+    remote: &[T],
+    url: impl Fn(&T) -> &str,
+    configured: &[String],
+    removed: &[String],
+) -> Vec<T> {
+    let mut merged = local.to_vec();
+    for entry in remote {
+        let entry_url = url(entry);
+        let known = configured.iter().any(|c| c == entry_url)
+            || removed.iter().any(|r| r == entry_url)
+            || merged.iter().any(|m| url(m) == entry_url);
+        if !known {
+            merged.push(entry.clone());
+        }
+    }
+    merged
+}
+
+/// Configured relays with their roles (unlisted relays read and write)
+pub fn configured_relay_roles() -> Vec<(String, RelayRoles)> {
+    let config = crate::core::config::Config::load();
+    configured_relays().into_iter()
+        .map(|url| {
+            let roles = config.relay_roles.get(&url).copied().unwrap_or_default();
+            (url, roles)
+        })
+        .collect()
+}
+
+/// Roles of one relay
+pub fn relay_roles(url: &str) -> RelayRoles {
+    crate::core::config::Config::load().relay_roles.get(url).copied().unwrap_or_default()
+}
+
+/// Configured relays marked as DM inboxes
+pub fn dm_relays() -> Vec<String> {
+    configured_relay_roles().into_iter()
+        .filter(|(_, roles)| roles.dm)
+        .map(|(url, _)| url)
+        .collect()
+}
+
+/// NIP-65 relay list (kind 10002) with each relay's read/write marker;
+/// DM-only relays are left out
+pub fn relay_list_builder(relays: &[(String, RelayRoles)]) -> EventBuilder {
+    EventBuilder::relay_list(
+        relays.iter()
+            .filter(|(_, roles)| roles.in_pool())
+            .filter_map(|(url, roles)| Some((RelayUrl::parse(url).ok()?, roles.metadata()))),
+    )
+}
+
+/// NIP-17 DM inbox relay list (kind 10050)
+pub fn dm_relay_list_builder(relays: &[String]) -> EventBuilder {
+    let tags: Vec<Tag> = relays.iter()
+        .filter_map(|url| RelayUrl::parse(url).ok())
+        .map(Tag::relay)
+        .collect();
+    EventBuilder::new(Kind::InboxRelays, "").tags(tags)
+}
+
+/// Timeout for relay queries (configurable in settings)
+pub fn fetch_timeout() -> Duration {
+    crate::core::network::timeouts().fetch
+}
+
+/// Picture-first event (NIP-68)
+pub const KIND_PICTURE: u16 = 20;
+
+/// Short-form portrait video event (NIP-71)
+pub const KIND_SHORT_VIDEO: u16 = 22;
+
+/// Notes per feed page unless configured otherwise
+pub const DEFAULT_FEED_PAGE_SIZE: u32 = 50;
+
+/// Allowed range for the feed page size setting
+pub const MIN_FEED_PAGE_SIZE: u32 = 10;
+pub const MAX_FEED_PAGE_SIZE: u32 = 500;
+
+/// Text notes requested per media note wanted, since most have no media
+const MEDIA_OVERFETCH: u64 = 3;
+
+/// Events requested from relays for a media page of `limit` notes
+pub fn media_fetch_limit(limit: u64) -> u64 {
+    (limit * MEDIA_OVERFETCH).min(MAX_FEED_PAGE_SIZE as u64)
+}
+
+/// Kinds requested for the media feed; text notes without media are dropped
+pub const MEDIA_FEED_KINDS: [u16; 3] = [1, KIND_PICTURE, KIND_SHORT_VIDEO];
+
+/// Relay hints of a nostr: reference tried per lookup
+const MAX_HINT_RELAYS: usize = 3;
+
+/// Time to wait for a hinted relay outside the pool to connect
+const HINT_CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Kinds shown in the following feed unless configured otherwise
+pub fn default_home_feed_kinds() -> Vec<Kind> {
+    vec![
+        Kind::TextNote,
+        Kind::Repost,
+        Kind::from(super::polls::KIND_POLL),
+        Kind::from(super::highlights::KIND_HIGHLIGHT),
+        Kind::from(super::zap_goals::KIND_ZAP_GOAL),
+    ]
+}
 
 /// Manages relay connections
+/// Clones share the underlying nostr-sdk client and its relay pool
+#[derive(Clone)]
 pub struct RelayManager {
     client: Client,
     connected: bool,
     user_pubkey: Option<PublicKey>,
     following: Vec<PublicKey>,
+    home_feed_kinds: Vec<Kind>,
+    /// How far back the first page of a feed reaches; None for no limit
+    feed_window: Option<Duration>,
 }
 
 impl RelayManager {
     /// Create a new relay manager
     pub fn new() -> Self {
-        let client = Client::default();
+        let client = crate::core::network::nostr_client(None);
         
         Self {
             client,
             connected: false,
             user_pubkey: None,
             following: Vec::new(),
+            home_feed_kinds: default_home_feed_kinds(),
+            feed_window: None,
         }
     }
     
     /// Create relay manager with a signer (for posting)
     pub fn with_keys(keys: Keys) -> Self {
-        let client = Client::new(keys);
+        let client = crate::core::network::nostr_client(Some(keys));
         
         Self {
             client,
             connected: false,
             user_pubkey: None,
             following: Vec::new(),
+            home_feed_kinds: default_home_feed_kinds(),
+            feed_window: None,
         }
     }
     
@@ -65,9 +281,29 @@ impl RelayManager {
         &self.client
     }
     
+    /// Query relays, waiting for a relay fetch slot first
+    async fn fetch_events(&self, filter: Filter, timeout: Duration) -> Result<Events, String> {
+        let _permit = crate::core::limits::relay_fetch_permit().await;
+        self.client.fetch_events(filter, timeout).await.map_err(|e| e.to_string())
+    }
+    
+    /// Whether connect() has been called since the last disconnect
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+    
+    /// Number of relays with a live connection right now
+    pub async fn connected_relay_count(&self) -> usize {
+        self.client.relays().await
+            .values()
+            .filter(|relay| relay.status() == RelayStatus::Connected)
+            .count()
+    }
+    
     /// Set the current user's pubkey
     pub fn set_user_pubkey(&mut self, pubkey: PublicKey) {
         self.user_pubkey = Some(pubkey);
+        super::wot::set_owner(Some(&pubkey));
     }
     
     /// Get following list
@@ -80,13 +316,141 @@ impl RelayManager {
         self.following = following;
     }
     
-    /// Connect to default relays
+    /// Get the event kinds shown in the following feed
+    pub fn home_feed_kinds(&self) -> &[Kind] {
+        &self.home_feed_kinds
+    }
+    
+    /// Set the event kinds shown in the following feed
+    pub fn set_home_feed_kinds(&mut self, kinds: Vec<Kind>) {
+        self.home_feed_kinds = if kinds.is_empty() { default_home_feed_kinds() } else { kinds };
+    }
+    
+    /// Set how many hours back the first page of a feed reaches (0 = no limit)
+    pub fn set_feed_window_hours(&mut self, hours: u32) {
+        self.feed_window = (hours > 0).then(|| Duration::from_secs(hours as u64 * 3600));
+    }
+    
+    /// Bound a feed filter to its page: older than `until` when paging, or
+    /// within the feed window for the first page
+    fn page_bounds(&self, filter: Filter, until: Option<Timestamp>) -> Filter {
+        match (until, self.feed_window) {
+            (Some(ts), _) => filter.until(ts),
+            (None, Some(window)) => filter.since(Timestamp::from(Timestamp::now().as_u64().saturating_sub(window.as_secs()))),
+            (None, None) => filter,
+        }
+    }
+    
+    /// Add a relay to the pool with the proxy settings and without nostr-sdk's
+    /// reconnect loop; dropped relays are reconnected by the relay watch
+    async fn add_relay(&self, url: &str) -> Result<bool, String> {
+        self.add_relay_with_roles(url, RelayRoles::default()).await
+    }
+    
+    /// Add a relay that is only fetched from or only published to, per its roles
+    async fn add_relay_with_roles(&self, url: &str, roles: RelayRoles) -> Result<bool, String> {
+        let relay_url = RelayUrl::parse(url).map_err(|e| e.to_string())?;
+        let opts = crate::core::network::relay_options(&relay_url)
+            .read(roles.read)
+            .write(roles.write);
+        self.client.pool().add_relay(relay_url, opts).await.map_err(|e| e.to_string())
+    }
+    
+    /// Add a relay to the running pool and connect to it right away
+    pub async fn add_live_relay(&self, url: &str, roles: RelayRoles) -> Result<(), String> {
+        if !roles.in_pool() {
+            return Ok(());
+        }
+        self.add_relay_with_roles(url, roles).await?;
+        self.client.connect_relay(url).await.map_err(|e| e.to_string())
+    }
+    
+    /// Disconnect a relay and drop it from the running pool; discovery relays
+    /// stay for NIP-65 lookups
+    pub async fn remove_live_relay(&self, url: &str) -> Result<(), String> {
+        if DISCOVERY_RELAYS.contains(&url) {
+            return Ok(());
+        }
+        self.client.remove_relay(url).await.map_err(|e| e.to_string())
+    }
+
+    /// Query the relays hinted in a nostr: reference (nevent, nprofile, naddr)
+    /// Hints outside the pool are added read-only for the query and dropped
+    /// afterwards, unless another lookup is still using them
+    pub async fn fetch_from_hints(&self, hints: &[RelayUrl], filter: Filter, timeout: Duration) -> Result<Events, String> {
+        let hints: Vec<RelayUrl> = hints.iter().take(MAX_HINT_RELAYS).cloned().collect();
+        if hints.is_empty() {
+            return Ok(Events::default());
+        }
+
+        let pool = self.client.relays().await;
+        let mut added = Vec::new();
+        for url in &hints {
+            let borrowed = {
+                let mut temporary = HINT_RELAYS.lock().unwrap();
+                match temporary.get_mut(url) {
+                    Some(users) => {
+                        *users += 1;
+                        true
+                    }
+                    None if pool.contains_key(url) => false,
+                    None => {
+                        temporary.insert(url.clone(), 1);
+                        true
+                    }
+                }
+            };
+            if !borrowed {
+                continue;
+            }
+            added.push(url.clone());
+            let opts = crate::core::network::relay_options(url).read(true).write(false);
+            if let Err(e) = self.client.pool().add_relay(url.clone(), opts).await {
+                tracing::debug!("Failed to add hinted relay {}: {}", url, e);
+                continue;
+            }
+            if let Err(e) = self.client.try_connect_relay(url.clone(), HINT_CONNECT_TIMEOUT).await {
+                tracing::debug!("Hinted relay {} unreachable: {}", url, e);
+            }
+        }
+
+        let result = {
+            let _permit = crate::core::limits::relay_fetch_permit().await;
+            self.client.fetch_events_from(hints, filter, timeout).await.map_err(|e| e.to_string())
+        };
+
+        for url in added {
+            let last = {
+                let mut temporary = HINT_RELAYS.lock().unwrap();
+                let users = temporary.get_mut(&url).map(|users| {
+                    *users -= 1;
+                    *users
+                });
+                if users == Some(0) {
+                    temporary.remove(&url);
+                }
+                users == Some(0)
+            };
+            if last {
+                if let Err(e) = self.client.remove_relay(url.as_str()).await {
+                    tracing::debug!("Failed to drop hinted relay {}: {}", url, e);
+                }
+            }
+        }
+        result
+    }
+
+    /// Connect to the configured relays
     pub async fn connect(&mut self) -> Result<(), String> {
-        tracing::info!("Connecting to {} default relays...", DEFAULT_RELAYS.len());
+        let relays: Vec<(String, RelayRoles)> = configured_relay_roles().into_iter()
+            .filter(|(_, roles)| roles.in_pool())
+            .collect();
+        tracing::info!("Connecting to {} configured relays...", relays.len());
         
-        // Add default read/write relays
-        for relay_url in DEFAULT_RELAYS {
-            if let Err(e) = self.client.add_relay(*relay_url).await {
+        // Add the user's relays, fetching only from read relays and
+        // publishing only to write relays
+        for (relay_url, roles) in &relays {
+            if let Err(e) = self.add_relay_with_roles(relay_url, *roles).await {
                 tracing::warn!("Failed to add relay {}: {}", relay_url, e);
             }
         }
@@ -95,7 +459,7 @@ impl RelayManager {
         // These help us find users' preferred relays
         tracing::info!("Adding {} discovery relays for NIP-65 lookups...", DISCOVERY_RELAYS.len());
         for relay_url in DISCOVERY_RELAYS {
-            if let Err(e) = self.client.add_relay(*relay_url).await {
+            if let Err(e) = self.add_relay(relay_url).await {
                 tracing::warn!("Failed to add discovery relay {}: {}", relay_url, e);
             }
         }
@@ -110,7 +474,7 @@ impl RelayManager {
     /// Connect to specific relays
     pub async fn connect_to(&mut self, relay_urls: &[String]) -> Result<(), String> {
         for url in relay_urls {
-            if let Err(e) = self.client.add_relay(url.as_str()).await {
+            if let Err(e) = self.add_relay(url).await {
                 tracing::warn!("Failed to add relay {}: {}", url, e);
             }
         }
@@ -133,8 +497,8 @@ impl RelayManager {
             .author(*pubkey)
             .limit(1);
         
-        let events = self.client
-            .fetch_events(filter, DEFAULT_TIMEOUT)
+        let events = self
+            .fetch_events(filter, fetch_timeout())
             .await
             .map_err(|e| format!("Failed to fetch contact list: {}", e))?;
         
@@ -160,6 +524,45 @@ impl RelayManager {
         Ok(following)
     }
     
+    /// Fetch the latest raw kind 3 contact list event for a user
+    pub async fn fetch_contact_list_event(&self, pubkey: &PublicKey) -> Result<Option<Event>, String> {
+        let filter = Filter::new()
+            .kind(Kind::ContactList)
+            .author(*pubkey)
+            .limit(1);
+        
+        let events = self
+            .fetch_events(filter, fetch_timeout())
+            .await
+            .map_err(|e| format!("Failed to fetch contact list: {}", e))?;
+        
+        // Relays may return older replaceable versions - keep the newest
+        Ok(events.into_iter().max_by_key(|e| e.created_at))
+    }
+
+    /// Fetch a user's Blossom server list (kind 10063, BUD-03)
+    pub async fn fetch_blossom_servers(&self, pubkey: &PublicKey) -> Result<Vec<String>, String> {
+        let filter = Filter::new()
+            .kind(Kind::from(10063u16))
+            .author(*pubkey)
+            .limit(1);
+
+        let events = self
+            .fetch_events(filter, fetch_timeout())
+            .await
+            .map_err(|e| format!("Failed to fetch Blossom server list: {}", e))?;
+
+        let Some(event) = events.into_iter().max_by_key(|e| e.created_at) else {
+            return Ok(Vec::new());
+        };
+
+        Ok(event.tags.iter()
+            .map(|t| t.as_slice())
+            .filter(|v| v.first().map(|s| s.as_str()) == Some("server"))
+            .filter_map(|v| v.get(1).cloned())
+            .collect())
+    }
+
     /// Fetch followers of a user (users who follow this pubkey)
     /// This searches for contact lists that include the target pubkey
     pub async fn fetch_followers(&self, pubkey: &PublicKey) -> Result<Vec<PublicKey>, String> {
@@ -170,7 +573,7 @@ impl RelayManager {
             .pubkey(*pubkey)  // Filter for events that tag this pubkey
             .limit(500);  // Limit to prevent overload
         
-        let events = self.client
+        let events = self
             .fetch_events(filter, Duration::from_secs(15))  // Longer timeout for this query
             .await
             .map_err(|e| format!("Failed to fetch followers: {}", e))?;
@@ -197,49 +600,82 @@ impl RelayManager {
             return Ok(Events::default());
         }
         
-        // Fetch text notes (kind 1) from following - includes posts and replies
+        let events = self.fetch_authors_feed(&self.following, limit, until).await?;
+        tracing::info!("Fetched {} total events for following feed", events.len());
+        Ok(events)
+    }
+    
+    /// Fetch all notes from a set of authors, honoring the enabled home feed kinds
+    pub async fn fetch_authors_feed(&self, authors: &[PublicKey], limit: u64, until: Option<Timestamp>) -> Result<Events, String> {
+        if authors.is_empty() {
+            return Ok(Events::default());
+        }
+        
+        let kinds = &self.home_feed_kinds;
+        
+        // Fetch text notes (kind 1) from the authors - includes posts and replies
         let mut text_filter = Filter::new()
             .kind(Kind::TextNote)
-            .authors(self.following.clone())
+            .authors(authors.to_vec())
             .limit(limit as usize);
         
-        if let Some(ts) = until {
-            text_filter = text_filter.until(ts);
-        }
+        text_filter = self.page_bounds(text_filter, until);
         
-        // Fetch reposts (kind 6) from following
+        // Fetch reposts (kind 6) from the authors
         let mut repost_filter = Filter::new()
             .kind(Kind::Repost)
-            .authors(self.following.clone())
+            .authors(authors.to_vec())
             .limit((limit / 2) as usize);
         
-        if let Some(ts) = until {
-            repost_filter = repost_filter.until(ts);
-        }
+        repost_filter = self.page_bounds(repost_filter, until);
         
-        // Fetch both in parallel
-        let (text_result, repost_result) = tokio::join!(
-            self.client.fetch_events(text_filter, DEFAULT_TIMEOUT),
-            self.client.fetch_events(repost_filter, DEFAULT_TIMEOUT)
-        );
+        // Any other kinds the user enabled (articles, highlights, ...)
+        let extra_kinds: Vec<Kind> = kinds.iter()
+            .copied()
+            .filter(|k| *k != Kind::TextNote && *k != Kind::Repost)
+            .collect();
+        let mut extra_filter = Filter::new()
+            .kinds(extra_kinds.clone())
+            .authors(authors.to_vec())
+            .limit((limit / 2) as usize);
         
-        let mut combined = Events::default();
+        extra_filter = self.page_bounds(extra_filter, until);
         
-        // Add text notes
-        if let Ok(events) = text_result {
-            for event in events.into_iter() {
-                combined.insert(event);
+        // Fetch all in parallel, skipping disabled kinds
+        let (text_result, repost_result, extra_result) = tokio::join!(
+            async {
+                if kinds.contains(&Kind::TextNote) {
+                    self.fetch_events(text_filter, fetch_timeout()).await
+                } else {
+                    Ok(Events::default())
+                }
+            },
+            async {
+                if kinds.contains(&Kind::Repost) {
+                    self.fetch_events(repost_filter, fetch_timeout()).await
+                } else {
+                    Ok(Events::default())
+                }
+            },
+            async {
+                if extra_kinds.is_empty() {
+                    Ok(Events::default())
+                } else {
+                    self.fetch_events(extra_filter, fetch_timeout()).await
+                }
             }
-        }
+        );
         
-        // Add reposts
-        if let Ok(events) = repost_result {
-            for event in events.into_iter() {
-                combined.insert(event);
+        let mut combined = Events::default();
+        
+        for events_result in [text_result, repost_result, extra_result] {
+            if let Ok(events) = events_result {
+                for event in events.into_iter() {
+                    combined.insert(event);
+                }
             }
         }
         
-        tracing::info!("Fetched {} total events for following feed", combined.len());
         Ok(combined)
     }
     
@@ -256,12 +692,10 @@ impl RelayManager {
             .authors(self.following.clone())
             .limit(limit as usize);
         
-        if let Some(ts) = until {
-            posts_filter = posts_filter.until(ts);
-        }
+        posts_filter = self.page_bounds(posts_filter, until);
         
-        let posts = self.client
-            .fetch_events(posts_filter, DEFAULT_TIMEOUT)
+        let posts = self
+            .fetch_events(posts_filter, fetch_timeout())
             .await
             .map_err(|e| format!("Failed to fetch posts: {}", e))?;
         
@@ -285,7 +719,7 @@ impl RelayManager {
                 reply_filter = reply_filter.until(ts);
             }
             
-            if let Ok(replies) = self.client.fetch_events(reply_filter, DEFAULT_TIMEOUT).await {
+            if let Ok(replies) = self.fetch_events(reply_filter, fetch_timeout()).await {
                 for event in replies.iter() {
                     combined.insert(event.clone());
                 }
@@ -307,8 +741,8 @@ impl RelayManager {
             .authors(self.following.clone())
             .limit(100);
         
-        let posts = self.client
-            .fetch_events(posts_filter, DEFAULT_TIMEOUT)
+        let posts = self
+            .fetch_events(posts_filter, fetch_timeout())
             .await
             .map_err(|e| format!("Failed to fetch posts: {}", e))?;
         
@@ -329,8 +763,8 @@ impl RelayManager {
             reply_filter = reply_filter.until(ts);
         }
         
-        self.client
-            .fetch_events(reply_filter, DEFAULT_TIMEOUT)
+        self
+            .fetch_events(reply_filter, fetch_timeout())
             .await
             .map_err(|e| format!("Failed to fetch replies: {}", e))
     }
@@ -346,11 +780,9 @@ impl RelayManager {
             .authors(self.following.clone())
             .limit(limit as usize);
             
-        if let Some(ts) = until {
-            filter = filter.until(ts);
-        }
+        filter = self.page_bounds(filter, until);
         
-        self.client.fetch_events(filter, DEFAULT_TIMEOUT).await.map_err(|e| e.to_string())
+        self.fetch_events(filter, fetch_timeout()).await
     }
 
     /// Fetch global long-form notes
@@ -359,11 +791,73 @@ impl RelayManager {
             .kind(Kind::LongFormTextNote)
             .limit(limit as usize);
             
+        filter = self.page_bounds(filter, until);
+        
+        self.fetch_events(filter, fetch_timeout()).await
+    }
+    
+    /// Fetch picture (kind 20) and short video (kind 22) events plus text notes with media
+    /// From followed users when there are any, otherwise global
+    pub async fn fetch_media_feed(&self, limit: u64, until: Option<Timestamp>) -> Result<Events, String> {
+        let events = self.fetch_media_notes(&self.following, limit, until).await?;
+        if self.following.is_empty() {
+            return Ok(super::wot::filter_hidden(events));
+        }
+        Ok(events)
+    }
+    
+    /// Fetch only events with images or videos (imeta tags or media URLs)
+    /// Relays can't filter on content, so text notes are over-fetched and
+    /// the ones without media dropped; pages may come back short
+    /// An empty author list fetches globally
+    pub async fn fetch_media_notes(&self, authors: &[PublicKey], limit: u64, until: Option<Timestamp>) -> Result<Events, String> {
+        self.fetch_media_page(authors, limit, until).await.map(|(events, _)| events)
+    }
+    
+    /// `fetch_media_notes` plus the oldest timestamp in the relays' page before
+    /// notes without media were dropped (None if the relays returned nothing),
+    /// so paging doesn't skip or stop at a page that happened to have no media
+    pub async fn fetch_media_page(&self, authors: &[PublicKey], limit: u64, until: Option<Timestamp>) -> Result<(Events, Option<Timestamp>), String> {
+        let mut filter = Filter::new()
+            .kinds(MEDIA_FEED_KINDS.map(Kind::from))
+            .limit(media_fetch_limit(limit) as usize);
+        
+        if !authors.is_empty() {
+            filter = filter.authors(authors.to_vec());
+        }
+        
+        filter = self.page_bounds(filter, until);
+        
+        let events = self
+            .fetch_events(filter, fetch_timeout())
+            .await
+            .map_err(|e| format!("Failed to fetch media notes: {}", e))?;
+        
+        let oldest = events.iter().map(|e| e.created_at).min();
+        let mut kept = Events::default();
+        for event in events.into_iter() {
+            if super::feed::has_media(&event) {
+                kept.insert(event);
+            }
+        }
+        Ok((kept, oldest))
+    }
+    
+    /// Fetch notes and reposts authored by a single user (profile timeline)
+    pub async fn fetch_user_notes(&self, pubkey: &PublicKey, limit: u64, until: Option<Timestamp>) -> Result<Events, String> {
+        let mut filter = Filter::new()
+            .kinds([Kind::TextNote, Kind::Repost])
+            .author(*pubkey)
+            .limit(limit as usize);
+        
         if let Some(ts) = until {
             filter = filter.until(ts);
         }
         
-        self.client.fetch_events(filter, DEFAULT_TIMEOUT).await.map_err(|e| e.to_string())
+        self
+            .fetch_events(filter, fetch_timeout())
+            .await
+            .map_err(|e| format!("Failed to fetch user notes: {}", e))
     }
     
     /// Fetch global feed (all text notes)
@@ -372,30 +866,165 @@ impl RelayManager {
             .kind(Kind::TextNote)
             .limit(limit as usize);
         
-        if let Some(ts) = until {
-            filter = filter.until(ts);
+        filter = self.page_bounds(filter, until);
+        
+        let events = self
+            .fetch_events(filter, fetch_timeout())
+            .await
+            .map_err(|e| format!("Failed to fetch global feed: {}", e))?;
+        
+        // Keep trust scores fresh in the background; this page uses whatever is cached
+        if let Some(user) = self.user_pubkey {
+            if super::wot::policy().enabled() && super::wot::needs_refresh(&user) {
+                let manager = self.clone();
+                tokio::spawn(async move {
+                    let following = manager.following.clone();
+                    if let Err(e) = super::wot::refresh(&manager, &user, &following).await {
+                        tracing::warn!("Web of trust refresh failed: {}", e);
+                    }
+                });
+            }
         }
         
-        self.client
-            .fetch_events(filter, DEFAULT_TIMEOUT)
+        Ok(super::wot::filter_hidden(events))
+    }
+    
+    /// Fetch recent notes tagged with any of the hashtags (lowercase, without '#')
+    pub async fn fetch_hashtag_feed(&self, hashtags: &[String], limit: u64, until: Option<Timestamp>) -> Result<Events, String> {
+        if hashtags.is_empty() {
+            return Ok(Events::default());
+        }
+        
+        let mut filter = Filter::new()
+            .kind(Kind::TextNote)
+            .hashtags(hashtags.iter().cloned())
+            .limit(limit as usize);
+        
+        filter = self.page_bounds(filter, until);
+        
+        let events = self
+            .fetch_events(filter, fetch_timeout())
             .await
-            .map_err(|e| format!("Failed to fetch global feed: {}", e))
+            .map_err(|e| format!("Failed to fetch hashtag feed: {}", e))?;
+        
+        // Topics bring in strangers, so the trust filter applies as on global
+        Ok(super::wot::filter_hidden(events))
     }
     
-    /// Fetch profile metadata for pubkeys
+    /// Fetch profile metadata for pubkeys through the shared profile store
+    /// Cached profiles are returned without a relay request
     pub async fn fetch_profiles(&self, pubkeys: &[PublicKey]) -> Result<Events, String> {
+        Ok(super::profile_store::get(&self.client, pubkeys).await)
+    }
+    
+    /// Fetch the latest contact lists (kind 3) of several users
+    /// Relays may return older replaceable versions; callers keep the newest per author
+    pub async fn fetch_contact_lists(&self, pubkeys: &[PublicKey]) -> Result<Events, String> {
         if pubkeys.is_empty() {
             return Ok(Events::default());
         }
         
         let filter = Filter::new()
-            .kind(Kind::Metadata)
+            .kind(Kind::ContactList)
             .authors(pubkeys.to_vec());
         
-        self.client
-            .fetch_events(filter, DEFAULT_TIMEOUT)
+        self
+            .fetch_events(filter, Duration::from_secs(15))
+            .await
+            .map_err(|e| format!("Failed to fetch contact lists: {}", e))
+    }
+    
+    /// Fetch a user's NIP-65 relay list (kind 10002)
+    pub async fn fetch_relay_list(&self, pubkey: &PublicKey) -> Result<Option<Event>, String> {
+        let filter = Filter::new()
+            .kind(Kind::RelayList)
+            .author(*pubkey)
+            .limit(1);
+        
+        let events = self
+            .fetch_events(filter, fetch_timeout())
+            .await
+            .map_err(|e| format!("Failed to fetch relay list: {}", e))?;
+        Ok(events.into_iter().max_by_key(|e| e.created_at))
+    }
+    
+    /// Fetch a user's NIP-30 emoji list (kind 10030)
+    pub async fn fetch_emoji_list(&self, pubkey: &PublicKey) -> Result<Option<Event>, String> {
+        let filter = Filter::new()
+            .kind(Kind::Custom(super::emoji::KIND_EMOJI_LIST))
+            .author(*pubkey)
+            .limit(1);
+        
+        let events = self
+            .fetch_events(filter, fetch_timeout())
+            .await
+            .map_err(|e| format!("Failed to fetch emoji list: {}", e))?;
+        Ok(events.into_iter().max_by_key(|e| e.created_at))
+    }
+    
+    /// Newest event of each replaceable kind by a user, from the relays and the local database
+    pub async fn fetch_latest_replaceable(&self, pubkey: &PublicKey, kinds: &[Kind]) -> Result<Vec<Event>, String> {
+        let filter = Filter::new()
+            .kinds(kinds.iter().copied())
+            .author(*pubkey)
+            .limit(kinds.len() * 4);
+        
+        let mut events: Vec<Event> = self
+            .fetch_events(filter, fetch_timeout())
+            .await
+            .map_err(|e| format!("Failed to fetch replaceable events: {}", e))?
+            .into_iter()
+            .collect();
+        if let Ok(db) = super::database::NostrDbManager::global() {
+            let kind_numbers: Vec<u16> = kinds.iter().map(|k| k.as_u16()).collect();
+            if let Ok(cached) = db.query_author_events(&kind_numbers, &[*pubkey], kinds.len() * 4) {
+                events.extend(cached);
+            }
+        }
+        
+        let mut latest: std::collections::HashMap<Kind, Event> = std::collections::HashMap::new();
+        for event in events {
+            match latest.get(&event.kind) {
+                Some(current) if current.created_at >= event.created_at => {}
+                _ => {
+                    latest.insert(event.kind, event);
+                }
+            }
+        }
+        Ok(latest.into_values().collect())
+    }
+    
+    /// Fetch NIP-51 emoji sets (kind 30030) by author and d-tag
+    pub async fn fetch_emoji_sets(&self, refs: &[(PublicKey, String)]) -> Result<Vec<Event>, String> {
+        if refs.is_empty() {
+            return Ok(Vec::new());
+        }
+        let filter = Filter::new()
+            .kind(Kind::Custom(super::emoji::KIND_EMOJI_SET))
+            .authors(refs.iter().map(|(pk, _)| *pk))
+            .identifiers(refs.iter().map(|(_, d)| d.clone()));
+        
+        let events = self
+            .fetch_events(filter, fetch_timeout())
+            .await
+            .map_err(|e| format!("Failed to fetch emoji sets: {}", e))?;
+        
+        // Authors and d-tags were matched independently - keep the requested pairs
+        Ok(events.into_iter()
+            .filter(|e| refs.iter().any(|(pk, d)| e.pubkey == *pk && e.tags.identifier() == Some(d.as_str())))
+            .collect())
+    }
+    
+    /// Fetch a user's NIP-51 follow sets (kind 30000) and deletion requests
+    pub async fn fetch_follow_sets(&self, pubkey: &PublicKey) -> Result<Events, String> {
+        let filter = Filter::new()
+            .kinds([Kind::Custom(super::lists::KIND_FOLLOW_SET), Kind::EventDeletion])
+            .author(*pubkey)
+            .limit(200);
+        
+        self.fetch_events(filter, fetch_timeout())
             .await
-            .map_err(|e| format!("Failed to fetch profiles: {}", e))
+            .map_err(|e| format!("Failed to fetch follow sets: {}", e))
     }
     
     /// Fetch a single event by ID
@@ -404,8 +1033,8 @@ impl RelayManager {
             .id(*event_id)
             .limit(1);
         
-        let events = self.client
-            .fetch_events(filter, DEFAULT_TIMEOUT)
+        let events = self
+            .fetch_events(filter, fetch_timeout())
             .await
             .map_err(|e| format!("Failed to fetch event: {}", e))?;
         
@@ -413,8 +1042,8 @@ impl RelayManager {
     }
     
     /// Fetch thread for a note (parents + replies)
-    /// Returns (parent_chain, target_note, replies)
-    pub async fn fetch_thread(&self, event_id: &EventId) -> Result<(Vec<Event>, Option<Event>, Vec<Event>), String> {
+    /// Returns (parent_chain, target_note, first page of replies)
+    pub async fn fetch_thread(&self, event_id: &EventId) -> Result<(Vec<Event>, Option<Event>, ReplyPage), String> {
         // First fetch the target event
         let target = self.fetch_event(event_id).await?;
         
@@ -439,12 +1068,7 @@ impl RelayManager {
             .collect();
         
         // Start fetching replies in parallel with parents
-        let reply_filter = Filter::new()
-            .kind(Kind::TextNote)
-            .event(*event_id)
-            .limit(50);
-        
-        let replies_future = self.client.fetch_events(reply_filter, DEFAULT_TIMEOUT);
+        let replies_future = self.fetch_reply_page(event_id, REPLY_PAGE_SIZE, None);
         
         // Wait for both parent fetches and replies concurrently
         let (parent_results, replies_result) = futures::future::join(
@@ -489,17 +1113,63 @@ impl RelayManager {
         // Sort parents by timestamp (oldest first for display)
         parents.sort_by(|a, b| a.created_at.cmp(&b.created_at));
         
-        // Handle replies result
-        let replies = replies_result
-            .map_err(|e| format!("Failed to fetch replies: {}", e))?;
+        Ok((parents, Some(target_event), replies_result?))
+    }
+    
+    /// Fetch a page of replies below a note: notes referencing it (up to `limit`,
+    /// older than `until`) plus a few rounds of replies to those replies
+    pub async fn fetch_reply_page(&self, event_id: &EventId, limit: usize, until: Option<Timestamp>) -> Result<ReplyPage, String> {
+        let mut filter = Filter::new()
+            .kind(Kind::TextNote)
+            .event(*event_id)
+            .limit(limit);
+        if let Some(until) = until {
+            filter = filter.until(until);
+        }
+        
+        let first: Vec<Event> = self.fetch_events(filter, fetch_timeout())
+            .await
+            .map_err(|e| format!("Failed to fetch replies: {}", e))?
+            .into_iter()
+            .collect();
+        
+        let oldest = first.iter().map(|e| e.created_at).min();
+        let more = first.len() >= limit;
         
-        let mut reply_vec: Vec<Event> = replies.into_iter().collect();
-        reply_vec.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        let mut seen: std::collections::HashSet<EventId> = first.iter().map(|e| e.id).collect();
+        seen.insert(*event_id);
+        let mut frontier: Vec<EventId> = first.iter().map(|e| e.id).collect();
+        let mut events = first;
         
-        Ok((parents, Some(target_event), reply_vec))
+        // Replies deeper in the tree may only tag their own parent
+        for _ in 0..NESTED_REPLY_ROUNDS {
+            if frontier.is_empty() {
+                break;
+            }
+            let filter = Filter::new()
+                .kind(Kind::TextNote)
+                .events(frontier.clone())
+                .limit(limit);
+            let nested = match self.fetch_events(filter, fetch_timeout()).await {
+                Ok(nested) => nested,
+                Err(e) => {
+                    tracing::warn!("Failed to fetch nested replies: {}", e);
+                    break;
+                }
+            };
+            frontier.clear();
+            for event in nested {
+                if seen.insert(event.id) {
+                    frontier.push(event.id);
+                    events.push(event);
+                }
+            }
+        }
+        
+        Ok(ReplyPage { events, oldest, more })
     }
     
-    /// Fetch notifications for the user (mentions, reactions, zaps, reposts)
+    /// Fetch notifications for the user (mentions, reactions, zaps, reposts, follows)
     pub async fn fetch_notifications(&self, user_pubkey: &PublicKey, limit: u64, until: Option<Timestamp>) -> Result<Events, String> {
         // Mentions: text notes that tag this user
         let mut mention_filter = Filter::new()
@@ -541,18 +1211,30 @@ impl RelayManager {
             repost_filter = repost_filter.until(ts);
         }
         
+        // Followers: contact lists (kind 3) that include this user
+        // One list per author, so fetch more of them to see most followers
+        let mut follow_filter = Filter::new()
+            .kind(Kind::ContactList)
+            .pubkey(*user_pubkey)
+            .limit((limit as usize * 5).min(500));
+        
+        if let Some(ts) = until {
+            follow_filter = follow_filter.until(ts);
+        }
+        
         // Fetch all in parallel
-        let (mentions, reactions, zaps, reposts) = tokio::join!(
-            self.client.fetch_events(mention_filter, DEFAULT_TIMEOUT),
-            self.client.fetch_events(reaction_filter, DEFAULT_TIMEOUT),
-            self.client.fetch_events(zap_filter, DEFAULT_TIMEOUT),
-            self.client.fetch_events(repost_filter, DEFAULT_TIMEOUT)
+        let (mentions, reactions, zaps, reposts, follows) = tokio::join!(
+            self.fetch_events(mention_filter, fetch_timeout()),
+            self.fetch_events(reaction_filter, fetch_timeout()),
+            self.fetch_events(zap_filter, fetch_timeout()),
+            self.fetch_events(repost_filter, fetch_timeout()),
+            self.fetch_events(follow_filter, fetch_timeout())
         );
         
         let mut combined = Events::default();
         
         // Filter out self-interactions and add to combined
-        for events_result in [mentions, reactions, zaps, reposts] {
+        for events_result in [mentions, reactions, zaps, reposts, follows] {
             if let Ok(events) = events_result {
                 for event in events.into_iter() {
                     // Skip events from the user themselves
@@ -566,125 +1248,203 @@ impl RelayManager {
         tracing::info!("Fetched {} notifications", combined.len());
         Ok(combined)
     }
+
+    /// Fetch events needed for a "catch up" summary since a timestamp:
+    /// zap receipts to followed users, new contact lists tagging the user,
+    /// and notes the user wrote or was tagged in
+    pub async fn fetch_catch_up_events(&self, user_pubkey: &PublicKey, since: Timestamp) -> Result<Events, String> {
+        let zap_filter = Filter::new()
+            .kind(Kind::ZapReceipt)
+            .pubkeys(self.following.clone())
+            .since(since)
+            .limit(500);
+
+        let follower_filter = Filter::new()
+            .kind(Kind::ContactList)
+            .pubkey(*user_pubkey)
+            .since(since)
+            .limit(200);
+
+        let mention_filter = Filter::new()
+            .kind(Kind::TextNote)
+            .pubkey(*user_pubkey)
+            .since(since)
+            .limit(500);
+
+        let own_filter = Filter::new()
+            .kind(Kind::TextNote)
+            .author(*user_pubkey)
+            .since(since)
+            .limit(200);
+
+        let (zaps, followers, mentions, own) = tokio::join!(
+            async {
+                if self.following.is_empty() {
+                    Ok(Events::default())
+                } else {
+                    self.fetch_events(zap_filter, fetch_timeout()).await
+                }
+            },
+            self.fetch_events(follower_filter, Duration::from_secs(15)),
+            self.fetch_events(mention_filter, fetch_timeout()),
+            self.fetch_events(own_filter, fetch_timeout())
+        );
+
+        let mut combined = Events::default();
+        for events_result in [zaps, followers, mentions, own] {
+            if let Ok(events) = events_result {
+                for event in events.into_iter() {
+                    combined.insert(event);
+                }
+            }
+        }
+
+        tracing::info!("Fetched {} catch-up events", combined.len());
+        Ok(combined)
+    }
+
+    /// Fetch zap receipts sent to the user (#p) and sent by the user (#P)
+    /// Returns (incoming, outgoing)
+    pub async fn fetch_zap_receipts(&self, user_pubkey: &PublicKey, since: Option<Timestamp>, limit: usize) -> Result<(Events, Events), String> {
+        let mut incoming_filter = Filter::new()
+            .kind(Kind::ZapReceipt)
+            .pubkey(*user_pubkey)
+            .limit(limit);
+
+        let mut outgoing_filter = Filter::new()
+            .kind(Kind::ZapReceipt)
+            .custom_tag(SingleLetterTag::uppercase(Alphabet::P), user_pubkey.to_hex())
+            .limit(limit);
+
+        if let Some(ts) = since {
+            incoming_filter = incoming_filter.since(ts);
+            outgoing_filter = outgoing_filter.since(ts);
+        }
+
+        let (incoming, outgoing) = tokio::join!(
+            self.fetch_events(incoming_filter, Duration::from_secs(20)),
+            self.fetch_events(outgoing_filter, Duration::from_secs(20))
+        );
+
+        let incoming = incoming?;
+        let outgoing = outgoing.unwrap_or_default();
+        tracing::info!("Fetched {} incoming and {} outgoing zap receipts", incoming.len(), outgoing.len());
+        Ok((incoming, outgoing))
+    }
     
-    /// Fetch reactions, zaps, replies, and reposts for specific note IDs
-    /// Returns a map of note_id -> (reactions_map, zap_total, zap_count, reply_count, repost_count)
-    /// where reactions_map is emoji -> count
-    pub async fn fetch_note_stats(&self, note_ids: &[EventId]) -> Result<std::collections::HashMap<String, (std::collections::HashMap<String, u32>, u64, u32, u32, u32)>, String> {
+    /// Fetch reactions, zaps, replies, quotes and reposts for specific note IDs
+    /// Returns a map of note_id -> NoteStats
+    pub async fn fetch_note_stats(&self, note_ids: &[EventId]) -> Result<std::collections::HashMap<String, NoteStats>, String> {
         if note_ids.is_empty() {
             return Ok(std::collections::HashMap::new());
         }
         
-        // Fetch reactions (kind 7) for these notes
-        let reaction_filter = Filter::new()
-            .kind(Kind::Reaction)
-            .events(note_ids.to_vec())
-            .limit(500);
-        
-        // Fetch zap receipts (kind 9735) for these notes
-        let zap_filter = Filter::new()
-            .kind(Kind::ZapReceipt)
+        // Reactions (kind 7), zap receipts (kind 9735) and reposts (kind 6) in one filter
+        // for the whole batch; the limit grows with the number of notes
+        let interaction_filter = Filter::new()
+            .kinds([Kind::Reaction, Kind::ZapReceipt, Kind::Repost])
             .events(note_ids.to_vec())
-            .limit(200);
+            .limit((note_ids.len() * 50).clamp(500, 5000));
         
         // Fetch replies (kind 1 that tag these notes)
         let reply_filter = Filter::new()
             .kind(Kind::TextNote)
             .events(note_ids.to_vec())
-            .limit(500);
+            .limit((note_ids.len() * 50).clamp(500, 5000));
         
-        // Fetch reposts (kind 6) for these notes
-        let repost_filter = Filter::new()
-            .kind(Kind::Repost)
-            .events(note_ids.to_vec())
-            .limit(200);
+        // Fetch quotes (kind 1 with a NIP-18 q tag naming these notes)
+        let quote_filter = Filter::new()
+            .kind(Kind::TextNote)
+            .custom_tags(SingleLetterTag::lowercase(Alphabet::Q), note_ids.iter().map(|id| id.to_hex()))
+            .limit((note_ids.len() * 20).clamp(200, 2000));
         
-        let (reactions_result, zaps_result, replies_result, reposts_result) = tokio::join!(
-            self.client.fetch_events(reaction_filter, DEFAULT_TIMEOUT),
-            self.client.fetch_events(zap_filter, DEFAULT_TIMEOUT),
-            self.client.fetch_events(reply_filter, DEFAULT_TIMEOUT),
-            self.client.fetch_events(repost_filter, DEFAULT_TIMEOUT)
+        let (interactions_result, replies_result, quotes_result) = tokio::join!(
+            self.fetch_events(interaction_filter, fetch_timeout()),
+            self.fetch_events(reply_filter, fetch_timeout()),
+            self.fetch_events(quote_filter, fetch_timeout())
         );
-        
-        // stats: (reactions_map, zap_total, zap_count, reply_count, repost_count)
-        let mut stats: std::collections::HashMap<String, (std::collections::HashMap<String, u32>, u64, u32, u32, u32)> = std::collections::HashMap::new();
+        let interactions: Vec<Event> = match interactions_result {
+            Ok(events) => events.into_iter().collect(),
+            Err(e) => {
+                tracing::warn!("Failed to fetch reactions, zaps and reposts: {}", e);
+                Vec::new()
+            }
+        };
         
         // Initialize stats for all requested note IDs
-        for note_id in note_ids {
-            stats.insert(note_id.to_hex(), (std::collections::HashMap::new(), 0, 0, 0, 0));
-        }
+        let mut stats: std::collections::HashMap<String, NoteStats> = note_ids.iter()
+            .map(|note_id| (note_id.to_hex(), NoteStats::default()))
+            .collect();
         
         // Process reactions
-        if let Ok(reactions) = reactions_result {
-            for event in reactions.iter() {
-                // Find which note this reaction is for
-                for tag in event.tags.iter() {
-                    if let Some(TagStandard::Event { event_id, .. }) = tag.as_standardized() {
-                        let note_id_hex = event_id.to_hex();
-                        if let Some((reactions_map, _, _, _, _)) = stats.get_mut(&note_id_hex) {
-                            // The emoji is in the content - if empty or "+", use "❤️"
-                            let emoji = if event.content.is_empty() || event.content == "+" {
-                                "❤️".to_string()
-                            } else if event.content == "-" {
-                                "👎".to_string()
-                            } else {
-                                // Take first grapheme cluster (emoji) or first few chars
-                                let content = event.content.trim();
-                                // Get first emoji or character (handle multi-byte)
-                                content.chars().take(2).collect::<String>()
-                            };
-                            *reactions_map.entry(emoji).or_insert(0) += 1;
-                        }
-                        break; // Only count once per event
+        for event in interactions.iter().filter(|e| e.kind == Kind::Reaction) {
+            // Find which note this reaction is for
+            for tag in event.tags.iter() {
+                if let Some(TagStandard::Event { event_id, .. }) = tag.as_standardized() {
+                    let note_id_hex = event_id.to_hex();
+                    if let Some(note_stats) = stats.get_mut(&note_id_hex) {
+                        // The emoji is in the content - if empty or "+", use "❤️"
+                        let emoji = if event.content.is_empty() || event.content == "+" {
+                            "❤️".to_string()
+                        } else if event.content == "-" {
+                            "👎".to_string()
+                        } else if let Some(custom) = super::emoji::custom_reaction(event) {
+                            // NIP-30 ":shortcode:" with its image in an emoji tag
+                            custom
+                        } else {
+                            // Take first grapheme cluster (emoji) or first few chars
+                            let content = event.content.trim();
+                            // Get first emoji or character (handle multi-byte)
+                            content.chars().take(2).collect::<String>()
+                        };
+                        *note_stats.reactions.entry(emoji).or_insert(0) += 1;
                     }
+                    break; // Only count once per event
                 }
             }
         }
         
         // Process zaps
-        if let Ok(zaps) = zaps_result {
-            tracing::debug!("Processing {} zap events", zaps.len());
-            for event in zaps.iter() {
-                // Find which note this zap is for and extract amount
-                let mut target_note: Option<String> = None;
-                let mut amount_msats: u64 = 0;
-                
-                for tag in event.tags.iter() {
-                    match tag.as_standardized() {
-                        Some(TagStandard::Event { event_id, .. }) => {
-                            target_note = Some(event_id.to_hex());
-                        }
-                        Some(TagStandard::Bolt11(invoice)) => {
-                            // Try to extract amount from bolt11 invoice
-                            // The amount is in the invoice string after "lnbc" or "lntb"
-                            if let Some(amount) = extract_bolt11_amount(&invoice.to_string()) {
-                                amount_msats = amount;
-                                tracing::debug!("Extracted {} msats from bolt11", amount);
-                            }
-                        }
-                        _ => {}
+        for event in interactions.iter().filter(|e| e.kind == Kind::ZapReceipt) {
+            // Find which note this zap is for and extract amount
+            let mut target_note: Option<String> = None;
+            let mut amount_msats: u64 = 0;
+            
+            for tag in event.tags.iter() {
+                match tag.as_standardized() {
+                    Some(TagStandard::Event { event_id, .. }) => {
+                        target_note = Some(event_id.to_hex());
                     }
-                    
-                    // Also check for "amount" tag (some implementations use this)
-                    if tag.kind() == TagKind::Amount {
-                        if let Some(amount_str) = tag.content() {
-                            if let Ok(amt) = amount_str.parse::<u64>() {
-                                amount_msats = amt;
-                                tracing::debug!("Found amount tag: {} msats", amt);
-                            }
+                    Some(TagStandard::Bolt11(invoice)) => {
+                        // Try to extract amount from bolt11 invoice
+                        // The amount is in the invoice string after "lnbc" or "lntb"
+                        if let Some(amount) = extract_bolt11_amount(&invoice.to_string()) {
+                            amount_msats = amount;
+                            tracing::debug!("Extracted {} msats from bolt11", amount);
                         }
                     }
+                    _ => {}
                 }
                 
-                if let Some(note_id_hex) = target_note {
-                    if let Some((_, zap_total, zap_count, _, _)) = stats.get_mut(&note_id_hex) {
-                        let sats = amount_msats / 1000;
-                        tracing::debug!("Adding {} sats to note {}", sats, &note_id_hex[..8]);
-                        *zap_total += sats;
-                        *zap_count += 1;
+                // Also check for "amount" tag (some implementations use this)
+                if tag.kind() == TagKind::Amount {
+                    if let Some(amount_str) = tag.content() {
+                        if let Ok(amt) = amount_str.parse::<u64>() {
+                            amount_msats = amt;
+                            tracing::debug!("Found amount tag: {} msats", amt);
+                        }
                     }
                 }
             }
+            
+            if let Some(note_id_hex) = target_note {
+                if let Some(note_stats) = stats.get_mut(&note_id_hex) {
+                    let sats = amount_msats / 1000;
+                    tracing::debug!("Adding {} sats to note {}", sats, &note_id_hex[..8]);
+                    note_stats.zap_amount += sats;
+                    note_stats.zap_count += 1;
+                }
+            }
         }
         
         // Process replies
@@ -701,8 +1461,8 @@ impl RelayManager {
                         };
                         if is_direct_reply {
                             let note_id_hex = event_id.to_hex();
-                            if let Some((_, _, _, reply_count, _)) = stats.get_mut(&note_id_hex) {
-                                *reply_count += 1;
+                            if let Some(note_stats) = stats.get_mut(&note_id_hex) {
+                                note_stats.reply_count += 1;
                                 tracing::debug!("Added reply to note {}", &note_id_hex[..8]);
                             }
                             break; // Only count once per event
@@ -713,16 +1473,31 @@ impl RelayManager {
         }
         
         // Process reposts
-        if let Ok(reposts) = reposts_result {
-            for event in reposts.iter() {
-                // Find which note was reposted
-                for tag in event.tags.iter() {
-                    if let Some(TagStandard::Event { event_id, .. }) = tag.as_standardized() {
-                        let note_id_hex = event_id.to_hex();
-                        if let Some((_, _, _, _, repost_count)) = stats.get_mut(&note_id_hex) {
-                            *repost_count += 1;
-                        }
-                        break; // Only count once per event
+        for event in interactions.iter().filter(|e| e.kind == Kind::Repost) {
+            // Find which note was reposted
+            for tag in event.tags.iter() {
+                if let Some(TagStandard::Event { event_id, .. }) = tag.as_standardized() {
+                    let note_id_hex = event_id.to_hex();
+                    if let Some(note_stats) = stats.get_mut(&note_id_hex) {
+                        note_stats.repost_count += 1;
+                    }
+                    break; // Only count once per event
+                }
+            }
+        }
+        
+        // Process quotes; a note quoting several of these counts for each
+        if let Ok(quotes) = quotes_result {
+            for event in quotes.iter() {
+                let quoted: std::collections::HashSet<String> = event.tags.iter()
+                    .filter_map(|tag| match tag.as_standardized() {
+                        Some(TagStandard::Quote { event_id, .. }) => Some(event_id.to_hex()),
+                        _ => None,
+                    })
+                    .collect();
+                for note_id_hex in quoted {
+                    if let Some(note_stats) = stats.get_mut(&note_id_hex) {
+                        note_stats.quote_count += 1;
                     }
                 }
             }
@@ -731,6 +1506,65 @@ impl RelayManager {
         Ok(stats)
     }
     
+    /// Fetch the user's own reactions, reposts and zaps for specific note IDs
+    /// Notes the user hasn't touched are left out of the map
+    pub async fn fetch_my_interactions(&self, note_ids: &[EventId], me: &PublicKey) -> Result<std::collections::HashMap<String, MyInteractions>, String> {
+        let mut mine: std::collections::HashMap<String, MyInteractions> = std::collections::HashMap::new();
+        if note_ids.is_empty() {
+            return Ok(mine);
+        }
+        
+        // Own reactions and reposts
+        let own_filter = Filter::new()
+            .kinds([Kind::Reaction, Kind::Repost])
+            .author(*me)
+            .events(note_ids.to_vec());
+        
+        // Zap receipts name the sender in an uppercase P tag
+        let zap_filter = Filter::new()
+            .kind(Kind::ZapReceipt)
+            .events(note_ids.to_vec())
+            .custom_tag(SingleLetterTag::uppercase(Alphabet::P), me.to_hex());
+        
+        let (own_result, zaps_result) = tokio::join!(
+            self.fetch_events(own_filter, fetch_timeout()),
+            self.fetch_events(zap_filter, fetch_timeout())
+        );
+        let own = own_result?;
+        
+        let target = |event: &Event| -> Option<String> {
+            event.tags.iter().find_map(|tag| match tag.as_standardized() {
+                Some(TagStandard::Event { event_id, .. }) => Some(event_id.to_hex()),
+                _ => None,
+            })
+        };
+        
+        for event in own.iter() {
+            let Some(note_id) = target(event).filter(|id| note_ids.iter().any(|n| n.to_hex() == *id)) else {
+                continue;
+            };
+            let entry = mine.entry(note_id).or_default();
+            if event.kind == Kind::Repost {
+                entry.reposted = true;
+            } else if event.content != "-" {
+                entry.reaction = Some(if event.content.is_empty() { "+".to_string() } else { event.content.clone() });
+            }
+        }
+        
+        if let Ok(zaps) = zaps_result {
+            for event in zaps.iter() {
+                let Some(note_id) = target(event) else { continue };
+                let amount_msats = event.tags.iter().find_map(|tag| match tag.as_standardized() {
+                    Some(TagStandard::Bolt11(invoice)) => extract_bolt11_amount(&invoice.to_string()),
+                    _ => None,
+                }).unwrap_or(0);
+                mine.entry(note_id).or_default().zapped_sats += amount_msats / 1000;
+            }
+        }
+        
+        Ok(mine)
+    }
+    
     /// Subscribe to new events (real-time updates)
     pub async fn subscribe_feed(&self, following: &[PublicKey]) -> Result<(), String> {
         // Build filter for text notes from following
@@ -747,6 +1581,29 @@ impl RelayManager {
     }
 }
 
+/// Totals for a note from `fetch_note_stats`
+#[derive(Debug, Clone, Default)]
+pub struct NoteStats {
+    /// Emoji (or custom emoji) -> count
+    pub reactions: std::collections::HashMap<String, u32>,
+    pub zap_amount: u64,
+    pub zap_count: u32,
+    /// Direct replies
+    pub reply_count: u32,
+    pub repost_count: u32,
+    /// Notes quoting this one with a q tag
+    pub quote_count: u32,
+}
+
+/// The current user's own interactions with a note
+#[derive(Debug, Clone, Default)]
+pub struct MyInteractions {
+    /// Content of the user's reaction ("+" for a like), if any
+    pub reaction: Option<String>,
+    pub reposted: bool,
+    pub zapped_sats: u64,
+}
+
 /// Check if an event is a direct reply to another note
 /// We want to filter out actual replies but keep:
 /// - Quote posts (notes that embed/mention other notes)
@@ -809,10 +1666,14 @@ pub fn create_shared_relay_manager() -> SharedRelayManager {
 lazy_static::lazy_static! {
     /// Global relay manager instance shared by all bridges
     pub static ref GLOBAL_RELAY_MANAGER: SharedRelayManager = create_shared_relay_manager();
+
+    /// Hinted relays added to the pool for lookups, with the number of lookups using each
+    static ref HINT_RELAYS: std::sync::Mutex<std::collections::HashMap<RelayUrl, usize>> =
+        std::sync::Mutex::new(std::collections::HashMap::new());
 }
 
 /// Extract amount in millisatoshis from a BOLT11 invoice string
-fn extract_bolt11_amount(invoice: &str) -> Option<u64> {
+pub fn extract_bolt11_amount(invoice: &str) -> Option<u64> {
     // BOLT11 format: ln[tb|bc][amount][multiplier][rest]
     // Amount is optional and followed by multiplier: m (milli), u (micro), n (nano), p (pico)
     let invoice_lower = invoice.to_lowercase();
@@ -0,0 +1,144 @@
+//! Relay health probe - checks a relay before it is added to the config
+//!
+//! A probe connects to the relay, fetches its NIP-11 document, and performs a
+//! small read and write test so dead or read-only relays can be spotted up front.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::time::{Duration, Instant};
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+use super::relay_info::{fetch_relay_info, RelayInformation};
+
+/// Timeout for each step of the probe
+const PROBE_TIMEOUT: Duration = Duration::from_secs(8);
+
+/// Result of probing a single relay
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayHealthReport {
+    pub url: String,
+    pub connected: bool,
+    pub connect_ms: Option<u64>,
+    pub can_read: bool,
+    pub read_ms: Option<u64>,
+    pub can_write: bool,
+    pub write_error: Option<String>,
+    #[serde(skip)]
+    pub info: Option<RelayInformation>,
+    pub info_error: Option<String>,
+    pub error: Option<String>,
+}
+
+impl RelayHealthReport {
+    /// A relay is considered healthy if we could connect and read from it
+    pub fn is_healthy(&self) -> bool {
+        self.connected && self.can_read
+    }
+
+    /// Serialize to JSON for QML consumption
+    pub fn to_json(&self) -> String {
+        let mut value = serde_json::to_value(self).unwrap_or_default();
+        value["healthy"] = serde_json::json!(self.is_healthy());
+        value["info"] = self.info.as_ref()
+            .map(|i| i.to_json_value())
+            .unwrap_or(serde_json::Value::Null);
+        value.to_string()
+    }
+}
+
+/// Probe a relay: connect, fetch NIP-11, and run a read/write test
+pub async fn probe_relay(relay_url: &str) -> RelayHealthReport {
+    let url = relay_url.trim().to_string();
+    let mut report = RelayHealthReport {
+        url: url.clone(),
+        ..Default::default()
+    };
+
+    if RelayUrl::parse(&url).is_err() {
+        report.error = Some(format!("Invalid relay URL: {}", url));
+        return report;
+    }
+
+    // NIP-11 runs over plain HTTP, so do it alongside the websocket checks
+    let info_future = fetch_relay_info(&url);
+
+    // Throwaway keys - the write test must not publish anything as the user
+    let keys = Keys::generate();
+    let client = crate::core::network::nostr_client(Some(keys.clone()));
+
+    let socket_future = async {
+        if let Err(e) = client.add_relay(url.as_str()).await {
+            return Err(format!("Failed to add relay: {}", e));
+        }
+
+        let started = Instant::now();
+        client.connect().await;
+        client.wait_for_connection(PROBE_TIMEOUT).await;
+
+        let connected = client.relay(url.as_str()).await
+            .map(|r| r.is_connected())
+            .unwrap_or(false);
+        if !connected {
+            return Err("Could not connect to relay".to_string());
+        }
+        let connect_ms = started.elapsed().as_millis() as u64;
+
+        // Read test: any single recent note
+        let started = Instant::now();
+        let read = client
+            .fetch_events(Filter::new().kind(Kind::TextNote).limit(1), PROBE_TIMEOUT)
+            .await;
+        let read_ms = started.elapsed().as_millis() as u64;
+
+        // Write test: an ephemeral event, which relays relay but never store
+        let write = match EventBuilder::new(Kind::Custom(20_000), "pleb-client relay probe")
+            .sign_with_keys(&keys)
+        {
+            Ok(event) => client.send_event(&event).await
+                .map_err(|e| e.to_string())
+                .and_then(|output| {
+                    if output.success.is_empty() {
+                        Err(output.failed.values().next().cloned()
+                            .unwrap_or_else(|| "Event rejected".to_string()))
+                    } else {
+                        Ok(())
+                    }
+                }),
+            Err(e) => Err(e.to_string()),
+        };
+
+        Ok((connect_ms, read.is_ok(), read_ms, write))
+    };
+
+    let (info_result, socket_result) = tokio::join!(info_future, socket_future);
+
+    match info_result {
+        Ok(info) => report.info = Some(info),
+        Err(e) => report.info_error = Some(e),
+    }
+
+    match socket_result {
+        Ok((connect_ms, can_read, read_ms, write)) => {
+            report.connected = true;
+            report.connect_ms = Some(connect_ms);
+            report.can_read = can_read;
+            report.read_ms = Some(read_ms);
+            match write {
+                Ok(()) => report.can_write = true,
+                Err(e) => report.write_error = Some(e),
+            }
+        }
+        Err(e) => report.error = Some(e),
+    }
+
+    client.disconnect().await;
+
+    tracing::info!(
+        "Probed relay {}: connected={}, read={}, write={}",
+        report.url, report.connected, report.can_read, report.can_write
+    );
+
+    report
+}
@@ -0,0 +1,250 @@
+//! Relay information documents - NIP-11
+//!
+//! Fetches a relay's self-description (name, software, supported NIPs, limitations)
+//! over HTTP using the `application/nostr+json` accept header.
+//!
+//! Documents are cached for an hour so the relay settings can show every
+//! relay's details without refetching them, and `capability_warnings` points
+//! out limits that get in the way of the queries the client makes.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use crate::core::config::Config;
+
+/// Timeout for NIP-11 HTTP requests
+const NIP11_TIMEOUT: Duration = Duration::from_secs(8);
+
+/// How long a fetched document is reused
+const INFO_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Fewer concurrent subscriptions than this and feeds, threads and
+/// notifications start pushing each other out
+const MIN_SUBSCRIPTIONS: u64 = 10;
+
+/// NIPs the client's queries depend on
+const NIP_BASIC: u16 = 1;
+const NIP_SEARCH: u16 = 50;
+
+lazy_static::lazy_static! {
+    /// Relay URL -> (document, fetched at)
+    static ref INFO_CACHE: RwLock<HashMap<String, (RelayInformation, Instant)>> = RwLock::new(HashMap::new());
+}
+
+/// Server limitations advertised in a NIP-11 document
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RelayLimitation {
+    #[serde(default)]
+    pub max_message_length: Option<u64>,
+    #[serde(default)]
+    pub max_subscriptions: Option<u64>,
+    #[serde(default)]
+    pub max_limit: Option<u64>,
+    #[serde(default)]
+    pub auth_required: bool,
+    #[serde(default)]
+    pub payment_required: bool,
+    #[serde(default)]
+    pub restricted_writes: bool,
+    #[serde(default)]
+    pub max_event_tags: Option<u64>,
+    #[serde(default)]
+    pub max_content_length: Option<u64>,
+    #[serde(default)]
+    pub min_pow_difficulty: Option<u64>,
+}
+
+/// A single fee entry from a NIP-11 `fees` object
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RelayFee {
+    pub amount: u64,
+    /// "msats" or "sats"
+    #[serde(default)]
+    pub unit: String,
+    /// Subscription length in seconds
+    #[serde(default)]
+    pub period: Option<u64>,
+}
+
+impl RelayFee {
+    /// Fee amount in sats, rounding millisats up
+    pub fn amount_sats(&self) -> u64 {
+        match self.unit.as_str() {
+            "msat" | "msats" => self.amount.div_ceil(1000),
+            _ => self.amount,
+        }
+    }
+}
+
+/// Fee schedule advertised in a NIP-11 document
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RelayFees {
+    #[serde(default)]
+    pub admission: Vec<RelayFee>,
+    #[serde(default)]
+    pub subscription: Vec<RelayFee>,
+    #[serde(default)]
+    pub publication: Vec<RelayFee>,
+}
+
+/// A relay information document (NIP-11)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RelayInformation {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub pubkey: Option<String>,
+    #[serde(default)]
+    pub contact: Option<String>,
+    #[serde(default)]
+    pub supported_nips: Vec<u16>,
+    #[serde(default)]
+    pub software: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub limitation: Option<RelayLimitation>,
+    #[serde(default)]
+    pub payments_url: Option<String>,
+    #[serde(default)]
+    pub fees: Option<RelayFees>,
+}
+
+impl RelayInformation {
+    /// Check if the relay advertises support for a NIP
+    pub fn supports_nip(&self, nip: u16) -> bool {
+        self.supported_nips.contains(&nip)
+    }
+
+    /// Check if the relay requires payment before accepting events
+    pub fn payment_required(&self) -> bool {
+        self.limitation.as_ref().map(|l| l.payment_required).unwrap_or(false)
+    }
+
+    /// Check if the relay requires NIP-42 authentication
+    pub fn auth_required(&self) -> bool {
+        self.limitation.as_ref().map(|l| l.auth_required).unwrap_or(false)
+    }
+
+    /// Serialize to JSON for QML consumption
+    pub fn to_json_value(&self) -> serde_json::Value {
+        serde_json::json!({
+            "name": self.name,
+            "description": self.description,
+            "pubkey": self.pubkey,
+            "contact": self.contact,
+            "supportedNips": self.supported_nips,
+            "software": self.software,
+            "version": self.version,
+            "icon": self.icon,
+            "paymentRequired": self.payment_required(),
+            "authRequired": self.auth_required(),
+            "paymentsUrl": self.payments_url,
+            "fees": self.fees,
+            "limitation": self.limitation,
+        })
+    }
+
+    /// Ways the relay falls short of what the client asks of it, as sentences
+    /// for the relay settings
+    pub fn capability_warnings(&self, relay_url: &str) -> Vec<String> {
+        let config = Config::load();
+        let mut warnings = Vec::new();
+
+        // An empty list says nothing; many relays leave it out
+        if !self.supported_nips.is_empty() && !self.supports_nip(NIP_BASIC) {
+            warnings.push("Doesn't list NIP-01, so basic queries may not work".to_string());
+        }
+        if config.search_relays.iter().any(|url| url == relay_url) && !self.supports_nip(NIP_SEARCH) {
+            warnings.push("Used for search but doesn't support NIP-50 search filters".to_string());
+        }
+        if let Some(limitation) = &self.limitation {
+            if let Some(max_limit) = limitation.max_limit.filter(|&max| max < config.feed_page_size as u64) {
+                warnings.push(format!(
+                    "Returns at most {} events per query (feed pages ask for {}), so pages from it come back short",
+                    max_limit, config.feed_page_size
+                ));
+            }
+            if let Some(max_subs) = limitation.max_subscriptions.filter(|&max| max < MIN_SUBSCRIPTIONS) {
+                warnings.push(format!("Allows only {} subscriptions at once; some queries will be refused", max_subs));
+            }
+            if limitation.auth_required {
+                warnings.push("Requires signing in (NIP-42) before it answers queries".to_string());
+            }
+            if limitation.restricted_writes {
+                warnings.push("Only accepts notes from approved accounts".to_string());
+            }
+            if let Some(pow) = limitation.min_pow_difficulty.filter(|&pow| pow > 0) {
+                warnings.push(format!("Requires proof of work (difficulty {}) to publish", pow));
+            }
+        }
+        warnings
+    }
+}
+
+/// Convert a relay websocket URL into its HTTP(S) equivalent
+pub fn relay_http_url(relay_url: &str) -> Result<String, String> {
+    let url = relay_url.trim();
+    if let Some(rest) = url.strip_prefix("wss://") {
+        Ok(format!("https://{}", rest))
+    } else if let Some(rest) = url.strip_prefix("ws://") {
+        Ok(format!("http://{}", rest))
+    } else {
+        Err(format!("Invalid relay URL (must start with wss:// or ws://): {}", url))
+    }
+}
+
+/// Fetch the NIP-11 information document for a relay
+pub async fn fetch_relay_info(relay_url: &str) -> Result<RelayInformation, String> {
+    let http_url = relay_http_url(relay_url)?;
+
+    let client = crate::core::network::http_client()
+        .timeout(NIP11_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let response = client.get(&http_url)
+        .header("Accept", "application/nostr+json")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch relay info: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Relay info request failed with status: {}", response.status()));
+    }
+
+    let text = response.text().await
+        .map_err(|e| format!("Failed to read relay info: {}", e))?;
+
+    let info: RelayInformation = serde_json::from_str(&text)
+        .map_err(|e| format!("Failed to parse relay info: {}", e))?;
+    INFO_CACHE.write().unwrap().insert(relay_url.trim().to_string(), (info.clone(), Instant::now()));
+    Ok(info)
+}
+
+/// The relay's NIP-11 document, from the cache if fetched within the last hour
+pub async fn cached_relay_info(relay_url: &str) -> Result<RelayInformation, String> {
+    let cached = INFO_CACHE.read().unwrap().get(relay_url.trim())
+        .filter(|(_, fetched)| fetched.elapsed() < INFO_TTL)
+        .map(|(info, _)| info.clone());
+    match cached {
+        Some(info) => Ok(info),
+        None => fetch_relay_info(relay_url).await,
+    }
+}
+
+/// Fetch NIP-11 documents for several relays concurrently
+pub async fn fetch_relay_infos(relay_urls: &[String]) -> Vec<(String, Result<RelayInformation, String>)> {
+    let futures = relay_urls.iter().map(|url| async move {
+        (url.clone(), fetch_relay_info(url).await)
+    });
+    futures::future::join_all(futures).await
+}
@@ -0,0 +1,259 @@
+//! Paid relays - NIP-11 fee schedules, Lightning invoices and paid-until records
+//!
+//! A relay that sets `limitation.payment_required` advertises its fees and a
+//! `payments_url` in NIP-11. Some relays answer that URL with a bolt11 invoice
+//! when given the user's pubkey; those can be paid via NWC. Others only offer a
+//! web page, which the UI opens in the browser instead. After paying, a signed
+//! ephemeral event is sent to check the relay now accepts our writes, and the
+//! paid-until date is kept locally so the UI can warn before it lapses.
+//!
+//! Relays that don't say so in NIP-11 may still turn a publish down with an OK
+//! message asking for payment; those are remembered for the session so the
+//! relay settings offer to pay them too, and the rejected events are held by
+//! `publish` until the payment goes through.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::Duration;
+use nostr_sdk::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::relay_info::RelayInformation;
+use crate::core::config::Config;
+use crate::core::limits;
+
+/// Local record of paid relays
+const PAID_RELAYS_FILE: &str = "paid_relays.json";
+
+/// Timeout for invoice requests and the write retest
+const PAYMENT_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Kind used for the write retest - ephemeral, so relays never store it
+const WRITE_TEST_KIND: u16 = 20_000;
+
+/// Words in an OK rejection that mean the relay wants paying
+const PAYMENT_WORDS: &[&str] = &["pay", "subscri", "admission", "invoice"];
+
+lazy_static::lazy_static! {
+    /// Relays that turned down a publish asking for payment this session
+    static ref PAYMENT_REJECTIONS: RwLock<HashSet<String>> = RwLock::new(HashSet::new());
+}
+
+/// Whether a relay's OK message rejects the event until the user pays
+/// (`payment-required:`, or a `restricted:`/`blocked:` reason about paying)
+pub fn is_payment_rejection(message: &str) -> bool {
+    let message = message.trim_start().to_lowercase();
+    if message.starts_with("payment-required:") {
+        return true;
+    }
+    ["restricted:", "blocked:", "auth-required:"].iter().any(|prefix| message.starts_with(prefix))
+        && PAYMENT_WORDS.iter().any(|word| message.contains(word))
+}
+
+/// Remember that a relay asked for payment
+pub fn note_payment_rejection(relay_url: &str) {
+    let added = PAYMENT_REJECTIONS.write().unwrap().insert(relay_url.to_string());
+    if added {
+        tracing::info!("Relay {} asks for payment before accepting events", relay_url);
+    }
+}
+
+/// Whether a relay turned down a publish asking for payment this session
+pub fn asked_for_payment(relay_url: &str) -> bool {
+    PAYMENT_REJECTIONS.read().unwrap().contains(relay_url)
+}
+
+/// What a relay asks for before accepting writes
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentRequirement {
+    pub url: String,
+    pub payments_url: Option<String>,
+    /// One-time admission fee, if any
+    pub admission_sats: Option<u64>,
+    /// Cheapest subscription, if any
+    pub subscription_sats: Option<u64>,
+    pub subscription_period_secs: Option<u64>,
+    /// Locally recorded paid-until (unix seconds); None if never paid
+    pub paid_until: Option<i64>,
+}
+
+impl PaymentRequirement {
+    /// Amount to pay now: admission if advertised, otherwise the cheapest subscription
+    pub fn amount_sats(&self) -> Option<u64> {
+        self.admission_sats.or(self.subscription_sats)
+    }
+}
+
+/// Payment requirement from a NIP-11 document, None if the relay is free
+/// A relay that rejected a publish asking for payment counts as paid even if
+/// its document doesn't say so
+pub fn payment_requirement(url: &str, info: &RelayInformation) -> Option<PaymentRequirement> {
+    if !info.payment_required() && !asked_for_payment(url) {
+        return None;
+    }
+
+    let fees = info.fees.clone().unwrap_or_default();
+    let admission_sats = fees.admission.iter().map(|f| f.amount_sats()).min();
+    let subscription = fees.subscription.iter().min_by_key(|f| f.amount_sats());
+
+    Some(PaymentRequirement {
+        url: url.to_string(),
+        payments_url: info.payments_url.clone(),
+        admission_sats,
+        subscription_sats: subscription.map(|f| f.amount_sats()),
+        subscription_period_secs: subscription.and_then(|f| f.period),
+        paid_until: paid_until(url),
+    })
+}
+
+/// Extract a bolt11 invoice from a payments endpoint response
+fn find_invoice(body: &str) -> Option<String> {
+    let trimmed = body.trim();
+    if trimmed.to_lowercase().starts_with("lnbc") {
+        return Some(trimmed.to_string());
+    }
+
+    let value: serde_json::Value = serde_json::from_str(trimmed).ok()?;
+    ["invoice", "pr", "bolt11", "payment_request"].iter()
+        .find_map(|key| value.get(*key).and_then(|v| v.as_str()))
+        .filter(|s| s.to_lowercase().starts_with("lnbc"))
+        .map(|s| s.to_string())
+}
+
+/// Ask the relay's payments URL for an invoice for this pubkey
+/// Ok(None) means the relay only offers a web page (open payments_url instead)
+pub async fn request_invoice(payments_url: &str, pubkey: &PublicKey, amount_sats: Option<u64>) -> Result<Option<String>, String> {
+    let mut url = url::Url::parse(payments_url)
+        .map_err(|e| format!("Invalid payments URL: {}", e))?;
+    url.query_pairs_mut().append_pair("pubkey", &pubkey.to_hex());
+    if let Some(amount) = amount_sats {
+        url.query_pairs_mut().append_pair("amount", &amount.to_string());
+    }
+
+    let _permit = limits::host_permit(payments_url).await;
+    let client = crate::core::network::http_client()
+        .timeout(PAYMENT_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let response = client.get(url.as_str())
+        .header("Accept", "application/json")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to request relay invoice: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("Invoice request failed with status: {}", response.status()));
+    }
+
+    let body = response.text().await
+        .map_err(|e| format!("Failed to read invoice response: {}", e))?;
+    Ok(find_invoice(&body))
+}
+
+/// Check a relay's invoice is for exactly the fee it advertised, returning the
+/// amount in sats. Amountless invoices and relays without a stated fee are
+/// refused: the relay could otherwise charge anything
+pub fn check_invoice_amount(invoice: &str, expected_sats: Option<u64>) -> Result<u64, String> {
+    let expected = expected_sats.ok_or("Relay did not state its fee; pay through its payments page instead")?;
+    let invoice_msats = super::relay::extract_bolt11_amount(invoice)
+        .ok_or("Relay invoice has no amount")?;
+    if invoice_msats != expected * 1000 {
+        return Err(format!(
+            "Relay invoice is for {} sats but the advertised fee is {} sats",
+            invoice_msats / 1000, expected,
+        ));
+    }
+    Ok(expected)
+}
+
+/// Send an already-signed event to a single relay and report whether it was accepted
+/// Uses a throwaway client so the relay doesn't have to be in the main pool
+pub async fn test_write(relay_url: &str, event: &Event) -> Result<(), String> {
+    let client = crate::core::network::nostr_client(None);
+    client.add_relay(relay_url).await
+        .map_err(|e| format!("Failed to add relay: {}", e))?;
+    client.connect().await;
+    client.wait_for_connection(PAYMENT_TIMEOUT).await;
+
+    let result = client.send_event_to([relay_url], event).await
+        .map_err(|e| e.to_string())
+        .and_then(|output| {
+            if output.success.is_empty() {
+                Err(output.failed.values().next().cloned()
+                    .unwrap_or_else(|| "Event rejected".to_string()))
+            } else {
+                Ok(())
+            }
+        });
+
+    client.disconnect().await;
+    result
+}
+
+/// Unsigned event for the write retest
+pub fn write_test_builder() -> EventBuilder {
+    EventBuilder::new(Kind::Custom(WRITE_TEST_KIND), "pleb-client relay write test")
+}
+
+/// A relay we've paid
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaidRelay {
+    pub paid_at: i64,
+    /// None for one-time admission without expiry
+    #[serde(default)]
+    pub paid_until: Option<i64>,
+    pub amount_sats: u64,
+    #[serde(default)]
+    pub preimage: Option<String>,
+}
+
+fn paid_relays_path() -> PathBuf {
+    Config::config_dir().join(PAID_RELAYS_FILE)
+}
+
+/// All locally recorded relay payments, keyed by relay URL
+pub fn load_paid_relays() -> HashMap<String, PaidRelay> {
+    std::fs::read_to_string(paid_relays_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+/// Record a payment; `period_secs` extends from the later of now and the current paid-until
+pub fn record_payment(relay_url: &str, amount_sats: u64, period_secs: Option<u64>, preimage: Option<String>) -> Result<PaidRelay, String> {
+    let mut paid = load_paid_relays();
+    let now = Timestamp::now().as_secs() as i64;
+
+    let start = paid.get(relay_url)
+        .and_then(|p| p.paid_until)
+        .filter(|until| *until > now)
+        .unwrap_or(now);
+    let record = PaidRelay {
+        paid_at: now,
+        paid_until: period_secs.map(|p| start + p as i64),
+        amount_sats,
+        preimage,
+    };
+    paid.insert(relay_url.to_string(), record.clone());
+    PAYMENT_REJECTIONS.write().unwrap().remove(relay_url);
+
+    let json = serde_json::to_string_pretty(&paid)
+        .map_err(|e| format!("Failed to serialize paid relays: {}", e))?;
+    std::fs::create_dir_all(Config::config_dir())
+        .map_err(|e| format!("Failed to create config directory: {}", e))?;
+    std::fs::write(paid_relays_path(), json)
+        .map_err(|e| format!("Failed to save paid relays: {}", e))?;
+
+    Ok(record)
+}
+
+/// Recorded paid-until for a relay (unix seconds)
+pub fn paid_until(relay_url: &str) -> Option<i64> {
+    load_paid_relays().get(relay_url).and_then(|p| p.paid_until)
+}
@@ -0,0 +1,164 @@
+//! Relay service - one pooled relay connection shared by every bridge
+//!
+//! Bridges get a clone of the global RelayManager instead of building and
+//! connecting their own per operation, so the websocket handshake and NIP-42
+//! auth happen once. nostr-sdk clients are reference counted, so clones share
+//! the same relay pool. Subscriptions are reference counted as well: identical
+//! filters share one REQ, which is closed when the last handle is dropped.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use nostr_sdk::prelude::*;
+
+use super::relay::{RelayManager, GLOBAL_RELAY_MANAGER};
+
+lazy_static::lazy_static! {
+    /// Serializes lazy connects so concurrent callers don't each open a pool
+    static ref CONNECT_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::new(());
+    
+    /// Filter JSON -> (subscription id, number of handles)
+    static ref SUBSCRIPTIONS: Mutex<HashMap<String, (SubscriptionId, usize)>> = Mutex::new(HashMap::new());
+}
+
+/// The pooled manager, if one is connected
+fn pooled() -> Option<RelayManager> {
+    GLOBAL_RELAY_MANAGER.read().unwrap()
+        .as_ref()
+        .filter(|m| m.is_connected())
+        .cloned()
+}
+
+/// Get the shared relay manager, connecting one with `create` if none exists yet
+pub async fn acquire<F>(create: F) -> Result<RelayManager, String>
+where
+    F: FnOnce() -> RelayManager,
+{
+    if let Some(manager) = pooled() {
+        return Ok(manager);
+    }
+    
+    let _guard = CONNECT_LOCK.lock().await;
+    if let Some(manager) = pooled() {
+        return Ok(manager);
+    }
+    
+    tracing::info!("Relay service: opening shared relay pool");
+    let mut manager = create();
+    manager.connect().await?;
+    
+    // The feed may have installed its own manager while we were connecting
+    let existing = {
+        let mut global = GLOBAL_RELAY_MANAGER.write().unwrap();
+        match global.as_ref().filter(|m| m.is_connected()) {
+            Some(existing) => Some(existing.clone()),
+            None => {
+                *global = Some(manager.clone());
+                None
+            }
+        }
+    };
+    
+    match existing {
+        Some(existing) => {
+            manager.disconnect().await;
+            Ok(existing)
+        }
+        None => Ok(manager),
+    }
+}
+
+/// Replace the pooled manager (e.g. after the feed configured user state on it)
+pub fn install(manager: RelayManager) {
+    *GLOBAL_RELAY_MANAGER.write().unwrap() = Some(manager);
+}
+
+/// Forget all tracked subscriptions (the pool itself is disconnected by the caller)
+pub fn reset() {
+    SUBSCRIPTIONS.lock().unwrap().clear();
+}
+
+/// A share of a relay subscription; the REQ is closed when the last handle drops
+pub struct SubscriptionHandle {
+    key: String,
+    id: SubscriptionId,
+    client: Client,
+}
+
+impl SubscriptionHandle {
+    pub fn id(&self) -> &SubscriptionId {
+        &self.id
+    }
+}
+
+impl Drop for SubscriptionHandle {
+    fn drop(&mut self) {
+        let last = {
+            let mut subs = SUBSCRIPTIONS.lock().unwrap();
+            match subs.get_mut(&self.key) {
+                Some((id, holders)) if *id == self.id && *holders > 1 => {
+                    *holders -= 1;
+                    false
+                }
+                Some((id, _)) if *id == self.id => {
+                    subs.remove(&self.key);
+                    true
+                }
+                _ => false,
+            }
+        };
+        
+        if !last {
+            return;
+        }
+        
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                let client = self.client.clone();
+                let id = self.id.clone();
+                handle.spawn(async move {
+                    client.unsubscribe(&id).await;
+                });
+            }
+            Err(_) => {
+                tracing::debug!("Subscription {} dropped outside a runtime; left open until disconnect", self.id);
+            }
+        }
+    }
+}
+
+/// Subscribe through the shared pool, reusing an existing REQ for an identical filter
+pub async fn subscribe(manager: &RelayManager, filter: Filter) -> Result<SubscriptionHandle, String> {
+    let key = filter.as_json();
+    let client = manager.client().clone();
+    
+    let existing = {
+        let mut subs = SUBSCRIPTIONS.lock().unwrap();
+        subs.get_mut(&key).map(|(id, holders)| {
+            *holders += 1;
+            id.clone()
+        })
+    };
+    if let Some(id) = existing {
+        return Ok(SubscriptionHandle { key, id, client });
+    }
+    
+    let output = client.subscribe(filter, None)
+        .await
+        .map_err(|e| format!("Failed to subscribe: {}", e))?;
+    let new_id = output.val;
+    
+    // Another caller may have opened the same filter while we were waiting
+    let id = {
+        let mut subs = SUBSCRIPTIONS.lock().unwrap();
+        let entry = subs.entry(key.clone()).or_insert((new_id.clone(), 0));
+        entry.1 += 1;
+        entry.0.clone()
+    };
+    if id != new_id {
+        client.unsubscribe(&new_id).await;
+    }
+    
+    Ok(SubscriptionHandle { key, id, client })
+}
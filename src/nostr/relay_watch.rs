@@ -0,0 +1,117 @@
+//! Relay watch - reconnects dropped relays with exponential backoff
+//!
+//! Relays are added without nostr-sdk's own reconnect loop (see
+//! `core::network::relay_options`). `RelayWatch::check` runs periodically:
+//! relays that are down are retried once their backoff has passed, and every
+//! failed attempt and every recovery is reported so the UI can show degraded
+//! connectivity instead of failing silently.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use futures::future::join_all;
+use nostr_sdk::prelude::*;
+
+use crate::core::network;
+
+/// A change in a relay's connectivity
+#[derive(Debug, Clone)]
+pub enum RelayEvent {
+    /// The relay is connected again after `attempts` failed attempts
+    Reconnected { url: String, attempts: u32 },
+    /// A reconnect attempt failed; the next one is in `retry_in`
+    Failed { url: String, attempts: u32, retry_in: Duration, error: String },
+}
+
+struct RetryState {
+    failures: u32,
+    next_attempt: Instant,
+}
+
+/// Backoff state for the relays of one client
+#[derive(Default)]
+pub struct RelayWatch {
+    retries: HashMap<RelayUrl, RetryState>,
+}
+
+impl RelayWatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retry relays that are down and due, and report what changed
+    pub async fn check(&mut self, client: &Client) -> Vec<RelayEvent> {
+        let relays = client.relays().await;
+        let now = Instant::now();
+        let mut events = Vec::new();
+
+        // Relays removed from the pool are no longer watched
+        self.retries.retain(|url, _| relays.contains_key(url));
+
+        let mut due = Vec::new();
+        for (url, relay) in relays.iter() {
+            match relay.status() {
+                RelayStatus::Connected => {
+                    // Came back on its own (e.g. a reconnect started elsewhere)
+                    if let Some(state) = self.retries.remove(url) {
+                        events.push(RelayEvent::Reconnected { url: url.to_string(), attempts: state.failures });
+                    }
+                }
+                RelayStatus::Disconnected | RelayStatus::Terminated => {
+                    let state = self.retries.entry(url.clone())
+                        .or_insert(RetryState { failures: 0, next_attempt: now });
+                    if now >= state.next_attempt {
+                        due.push(url.clone());
+                    }
+                }
+                // Still connecting, sleeping or banned: leave it alone
+                _ => {}
+            }
+        }
+
+        if due.is_empty() {
+            return events;
+        }
+
+        let timeout = network::timeouts().connect;
+        let attempts = due.iter().map(|url| client.try_connect_relay(url.clone(), timeout));
+        let results = join_all(attempts).await;
+
+        for (url, result) in due.into_iter().zip(results) {
+            match result {
+                Ok(()) => {
+                    let attempts = self.retries.remove(&url).map(|s| s.failures).unwrap_or(0);
+                    if attempts > 0 {
+                        tracing::info!("Relay {} reconnected after {} failed attempts", url, attempts);
+                    }
+                    events.push(RelayEvent::Reconnected { url: url.to_string(), attempts });
+                }
+                Err(e) => {
+                    let state = self.retries.entry(url.clone())
+                        .or_insert(RetryState { failures: 0, next_attempt: now });
+                    state.failures += 1;
+                    let retry_in = network::reconnect_delay(state.failures);
+                    state.next_attempt = Instant::now() + retry_in;
+                    tracing::warn!(
+                        "Relay {} reconnect attempt {} failed: {} (retrying in {}s)",
+                        url, state.failures, e, retry_in.as_secs()
+                    );
+                    events.push(RelayEvent::Failed {
+                        url: url.to_string(),
+                        attempts: state.failures,
+                        retry_in,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        events
+    }
+
+    /// Relays currently waiting for a retry
+    pub fn failing(&self) -> usize {
+        self.retries.values().filter(|s| s.failures > 0).count()
+    }
+}
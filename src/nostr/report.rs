@@ -0,0 +1,101 @@
+//! Reports (NIP-56) - kind 1984 events flagging notes or users
+//!
+//! Reported content is also hidden locally for the reporting user. The set is
+//! persisted in the database directory and cached here so feed rendering can
+//! check it per note without locking the database.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::sync::RwLock;
+use nostr_sdk::prelude::*;
+
+use super::database::{NostrDbManager, ReportedContent};
+
+/// NIP-56 report types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportType {
+    Nudity,
+    Malware,
+    Profanity,
+    Illegal,
+    Spam,
+    Impersonation,
+    Other,
+}
+
+impl ReportType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportType::Nudity => "nudity",
+            ReportType::Malware => "malware",
+            ReportType::Profanity => "profanity",
+            ReportType::Illegal => "illegal",
+            ReportType::Spam => "spam",
+            ReportType::Impersonation => "impersonation",
+            ReportType::Other => "other",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "nudity" => Some(ReportType::Nudity),
+            "malware" => Some(ReportType::Malware),
+            "profanity" => Some(ReportType::Profanity),
+            "illegal" => Some(ReportType::Illegal),
+            "spam" => Some(ReportType::Spam),
+            "impersonation" => Some(ReportType::Impersonation),
+            "other" => Some(ReportType::Other),
+            _ => None,
+        }
+    }
+}
+
+lazy_static::lazy_static! {
+    /// Reported content of the logged-in user
+    static ref HIDDEN: RwLock<ReportedContent> = RwLock::new(ReportedContent::default());
+}
+
+/// Unsigned report of a note; the author is tagged too, as NIP-56 requires
+pub fn note_report_builder(note_id: &EventId, author: &PublicKey, report_type: ReportType, reason: &str) -> Result<EventBuilder, String> {
+    let tags = vec![
+        Tag::parse(["e", &note_id.to_hex(), report_type.as_str()])
+            .map_err(|e| format!("Invalid report tag: {}", e))?,
+        Tag::public_key(*author),
+    ];
+    Ok(EventBuilder::new(Kind::Reporting, reason).tags(tags))
+}
+
+/// Unsigned report of a user
+pub fn user_report_builder(pubkey: &PublicKey, report_type: ReportType, reason: &str) -> Result<EventBuilder, String> {
+    let tag = Tag::parse(["p", &pubkey.to_hex(), report_type.as_str()])
+        .map_err(|e| format!("Invalid report tag: {}", e))?;
+    Ok(EventBuilder::new(Kind::Reporting, reason).tag(tag))
+}
+
+/// Load the user's reported content into the cache (call on login)
+pub fn load_hidden(user_pubkey: &str) {
+    let reported = NostrDbManager::global()
+        .map(|db| db.get_reported_content(user_pubkey))
+        .unwrap_or_default();
+    *HIDDEN.write().unwrap() = reported;
+}
+
+/// Hide a note and/or user locally and persist it
+pub fn hide(user_pubkey: &str, note_id: Option<&str>, pubkey: Option<&str>) {
+    if let Ok(db) = NostrDbManager::global() {
+        db.record_report(user_pubkey, note_id, pubkey);
+    }
+    let mut hidden = HIDDEN.write().unwrap();
+    let now = Timestamp::now().as_secs() as i64;
+    if let Some(id) = note_id {
+        hidden.notes.insert(id.to_string(), now);
+    }
+    if let Some(pk) = pubkey {
+        hidden.pubkeys.insert(pk.to_string(), now);
+    }
+}
+
+/// Whether a note (or its author) was reported by the user
+pub fn is_hidden(note_id: &str, pubkey: &str) -> bool {
+    HIDDEN.read().unwrap().is_hidden(note_id, pubkey)
+}
@@ -0,0 +1,122 @@
+//! Relay-side full-text search - NIP-50
+//!
+//! Search-capable relays are found by checking the configured search relays'
+//! NIP-11 documents for NIP-50 support (cached for a day). Queries go through a
+//! dedicated client so search relays never join the main relay pool.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use nostr_sdk::prelude::*;
+
+use super::relay_info::fetch_relay_info;
+
+/// Relays known to implement NIP-50
+pub const DEFAULT_SEARCH_RELAYS: &[&str] = &[
+    "wss://relay.nostr.band",
+    "wss://search.nos.today",
+];
+
+/// NIP number for search capability
+const NIP_SEARCH: u16 = 50;
+
+/// How long a relay's NIP-50 support is remembered
+const CAPABILITY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Timeout for connecting to search relays
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Timeout for a search query
+const SEARCH_TIMEOUT: Duration = Duration::from_secs(15);
+
+lazy_static::lazy_static! {
+    /// Relay URL -> (supports NIP-50, checked at)
+    static ref CAPABILITIES: RwLock<HashMap<String, (bool, Instant)>> = RwLock::new(HashMap::new());
+    static ref SEARCH_CLIENT: tokio::sync::Mutex<Option<Client>> = tokio::sync::Mutex::new(None);
+}
+
+/// Check (and cache) whether a relay advertises NIP-50
+/// Relays whose NIP-11 can't be fetched are treated as unsupported until the TTL passes
+pub async fn supports_search(relay_url: &str) -> bool {
+    if let Some((supported, checked_at)) = CAPABILITIES.read().unwrap().get(relay_url) {
+        if checked_at.elapsed() < CAPABILITY_TTL {
+            return *supported;
+        }
+    }
+
+    let supported = match fetch_relay_info(relay_url).await {
+        Ok(info) => info.supports_nip(NIP_SEARCH),
+        Err(e) => {
+            tracing::debug!("NIP-11 lookup failed for {}: {}", relay_url, e);
+            false
+        }
+    };
+
+    CAPABILITIES.write().unwrap().insert(relay_url.to_string(), (supported, Instant::now()));
+    supported
+}
+
+/// Candidate relays that advertise NIP-50
+pub async fn search_relays(candidates: &[String]) -> Vec<String> {
+    let checks = candidates.iter().map(|url| async move {
+        supports_search(url).await.then(|| url.clone())
+    });
+    futures::future::join_all(checks).await.into_iter().flatten().collect()
+}
+
+/// Connected client for the given search relays
+/// Returns None if none of them could be reached
+async fn connected_client(relays: &[String]) -> Option<Client> {
+    let mut guard = SEARCH_CLIENT.lock().await;
+    let client = guard.get_or_insert_with(Client::default).clone();
+    drop(guard);
+
+    for url in relays {
+        if let Err(e) = client.add_relay(url.as_str()).await {
+            tracing::warn!("Failed to add search relay {}: {}", url, e);
+        }
+    }
+    client.connect().await;
+    client.wait_for_connection(CONNECT_TIMEOUT).await;
+
+    let mut any_connected = false;
+    for url in relays {
+        if let Ok(relay) = client.relay(url.as_str()).await {
+            any_connected |= relay.is_connected();
+        }
+    }
+    any_connected.then_some(client)
+}
+
+/// Search text notes on NIP-50 relays
+/// Ok(None) means no search relay was reachable and the caller should fall back
+pub async fn search_notes(
+    candidates: &[String],
+    query: &str,
+    since: Timestamp,
+    limit: usize,
+) -> Result<Option<Vec<Event>>, String> {
+    let relays = search_relays(candidates).await;
+    if relays.is_empty() {
+        return Ok(None);
+    }
+
+    let Some(client) = connected_client(&relays).await else {
+        return Ok(None);
+    };
+
+    let filter = Filter::new()
+        .kind(Kind::TextNote)
+        .search(query)
+        .since(since)
+        .limit(limit);
+
+    let events = client.fetch_events_from(relays, filter, SEARCH_TIMEOUT)
+        .await
+        .map_err(|e| format!("Search request failed: {}", e))?;
+
+    tracing::info!("NIP-50 search for '{}' returned {} notes", query, events.len());
+    Ok(Some(events.into_iter().collect()))
+}
@@ -0,0 +1,215 @@
+//! Full-text search index over locally cached notes and profiles
+//!
+//! A tantivy index stored next to nostrdb. Notes are indexed on content,
+//! profiles on name, display name, NIP-05 and about. The original event JSON
+//! is stored with each document so results don't need a second lookup.
+//! Writes are buffered and committed in batches; a search commits anything
+//! pending first so fresh events are always findable.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+use nostr_sdk::prelude::*;
+use parking_lot::Mutex;
+use tantivy::collector::TopDocs;
+use tantivy::directory::MmapDirectory;
+use tantivy::query::{BooleanQuery, Occur, Query, QueryParser, RangeQuery, TermQuery};
+use tantivy::schema::{Field, IndexRecordOption, Schema, Value, FAST, INDEXED, STORED, STRING, TEXT};
+use tantivy::{doc, Index, IndexReader, IndexWriter, Order, ReloadPolicy, TantivyDocument, Term};
+
+/// Subdirectory of the database directory holding the index
+pub const SEARCH_INDEX_DIR: &str = "search_index";
+
+/// Memory budget for the index writer
+const WRITER_HEAP_BYTES: usize = 32 * 1024 * 1024;
+
+/// Commit once this many documents are pending...
+const COMMIT_BATCH: usize = 256;
+
+/// ...or once this much time has passed since the last commit
+const COMMIT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Document types (stored in the `kind` field)
+const DOC_NOTE: u64 = 1;
+const DOC_PROFILE: u64 = 0;
+
+struct Fields {
+    /// Unique key: event id for notes, "p:<pubkey>" for profiles
+    key: Field,
+    kind: Field,
+    pubkey: Field,
+    created_at: Field,
+    text: Field,
+    json: Field,
+}
+
+struct PendingWrites {
+    count: usize,
+    last_commit: Instant,
+}
+
+/// Tantivy-backed search index
+pub struct SearchIndex {
+    index: Index,
+    reader: IndexReader,
+    writer: Mutex<IndexWriter>,
+    pending: Mutex<PendingWrites>,
+    fields: Fields,
+}
+
+fn build_schema() -> (Schema, Fields) {
+    let mut builder = Schema::builder();
+    let fields = Fields {
+        key: builder.add_text_field("key", STRING),
+        kind: builder.add_u64_field("kind", INDEXED | FAST),
+        pubkey: builder.add_text_field("pubkey", STRING),
+        created_at: builder.add_i64_field("created_at", INDEXED | FAST),
+        text: builder.add_text_field("text", TEXT),
+        json: builder.add_text_field("json", STORED),
+    };
+    (builder.build(), fields)
+}
+
+impl SearchIndex {
+    /// Open (or create) the index in the given directory
+    pub fn open(dir: &Path) -> Result<Self, String> {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create search index directory: {}", e))?;
+
+        let (schema, fields) = build_schema();
+        let directory = MmapDirectory::open(dir)
+            .map_err(|e| format!("Failed to open search index directory: {}", e))?;
+        let index = Index::open_or_create(directory, schema)
+            .map_err(|e| format!("Failed to open search index: {}", e))?;
+
+        let writer = index.writer(WRITER_HEAP_BYTES)
+            .map_err(|e| format!("Failed to create search index writer: {}", e))?;
+        let reader = index.reader_builder()
+            .reload_policy(ReloadPolicy::Manual)
+            .try_into()
+            .map_err(|e| format!("Failed to create search index reader: {}", e))?;
+
+        Ok(Self {
+            index,
+            reader,
+            writer: Mutex::new(writer),
+            pending: Mutex::new(PendingWrites { count: 0, last_commit: Instant::now() }),
+            fields,
+        })
+    }
+
+    /// Number of searchable documents (as of the last commit)
+    pub fn num_docs(&self) -> u64 {
+        self.reader.searcher().num_docs()
+    }
+
+    /// Add or replace an event in the index
+    /// Text notes and profiles are indexed; other kinds are ignored
+    pub fn index_event(&self, event: &Event) -> Result<(), String> {
+        let (key, kind, text) = match event.kind {
+            Kind::TextNote => (event.id.to_hex(), DOC_NOTE, event.content.clone()),
+            Kind::Metadata => (format!("p:{}", event.pubkey.to_hex()), DOC_PROFILE, profile_text(&event.content)),
+            _ => return Ok(()),
+        };
+
+        let f = &self.fields;
+        {
+            let writer = self.writer.lock();
+            // Profiles are replaceable; notes are immutable but re-ingest must not duplicate
+            writer.delete_term(Term::from_field_text(f.key, &key));
+            writer.add_document(doc!(
+                f.key => key,
+                f.kind => kind,
+                f.pubkey => event.pubkey.to_hex(),
+                f.created_at => event.created_at.as_secs() as i64,
+                f.text => text,
+                f.json => event.as_json(),
+            )).map_err(|e| format!("Failed to index event: {}", e))?;
+        }
+
+        let due = {
+            let mut pending = self.pending.lock();
+            pending.count += 1;
+            pending.count >= COMMIT_BATCH || pending.last_commit.elapsed() >= COMMIT_INTERVAL
+        };
+        if due {
+            self.commit()?;
+        }
+        Ok(())
+    }
+
+    /// Commit pending writes and make them visible to searches
+    pub fn commit(&self) -> Result<(), String> {
+        {
+            let mut pending = self.pending.lock();
+            if pending.count == 0 {
+                return Ok(());
+            }
+            pending.count = 0;
+            pending.last_commit = Instant::now();
+        }
+
+        self.writer.lock().commit()
+            .map_err(|e| format!("Failed to commit search index: {}", e))?;
+        self.reader.reload()
+            .map_err(|e| format!("Failed to reload search index: {}", e))
+    }
+
+    /// Search notes, newest first
+    /// `offset` pages through results for the same query
+    pub fn search_notes(&self, query: &str, since: Option<i64>, limit: usize, offset: usize) -> Result<Vec<Event>, String> {
+        let mut clauses = vec![(Occur::Must, self.kind_query(DOC_NOTE))];
+        if let Some(since) = since {
+            clauses.push((Occur::Must, Box::new(RangeQuery::new_i64("created_at".to_string(), since..i64::MAX)) as Box<dyn Query>));
+        }
+        self.search(query, clauses, limit, offset)
+    }
+
+    /// Search profiles (kind 0 events), newest first
+    pub fn search_profiles(&self, query: &str, limit: usize) -> Result<Vec<Event>, String> {
+        let clauses = vec![(Occur::Must, self.kind_query(DOC_PROFILE))];
+        self.search(query, clauses, limit, 0)
+    }
+
+    fn kind_query(&self, kind: u64) -> Box<dyn Query> {
+        Box::new(TermQuery::new(
+            Term::from_field_u64(self.fields.kind, kind),
+            IndexRecordOption::Basic,
+        ))
+    }
+
+    fn search(&self, query: &str, mut clauses: Vec<(Occur, Box<dyn Query>)>, limit: usize, offset: usize) -> Result<Vec<Event>, String> {
+        self.commit()?;
+
+        // Lenient parsing: stray quotes or colons in user input shouldn't fail the search
+        let mut parser = QueryParser::for_index(&self.index, vec![self.fields.text]);
+        parser.set_conjunction_by_default();
+        let (text_query, _errors) = parser.parse_query_lenient(query);
+        clauses.push((Occur::Must, text_query));
+
+        let searcher = self.reader.searcher();
+        let collector = TopDocs::with_limit(limit.max(1))
+            .and_offset(offset)
+            .order_by_fast_field::<i64>("created_at", Order::Desc);
+        let top = searcher.search(&BooleanQuery::new(clauses), &collector)
+            .map_err(|e| format!("Search failed: {}", e))?;
+
+        Ok(top.into_iter()
+            .filter_map(|(_, address)| searcher.doc::<TantivyDocument>(address).ok())
+            .filter_map(|doc| doc.get_first(self.fields.json).and_then(|v| v.as_str()).map(|s| s.to_string()))
+            .filter_map(|json| Event::from_json(&json).ok())
+            .collect())
+    }
+}
+
+/// Searchable text of a profile's metadata
+fn profile_text(content: &str) -> String {
+    let Ok(metadata) = serde_json::from_str::<serde_json::Value>(content) else {
+        return String::new();
+    };
+    ["name", "display_name", "nip05", "about"].iter()
+        .filter_map(|field| metadata.get(*field).and_then(|v| v.as_str()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,118 @@
+//! Feed snapshots - deterministic dumps of feed state for bug reports
+//!
+//! Captures the order of notes exactly as displayed along with where each
+//! event was seen, so ordering and dedup bugs can be reproduced. Output is
+//! stable for the same input: fields are emitted in a fixed order, relay
+//! lists are sorted, and redacted pubkeys map to sequential aliases.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashMap;
+use serde::Serialize;
+
+use super::feed::DisplayNote;
+
+/// Version of the snapshot format
+const SNAPSHOT_VERSION: u32 = 1;
+
+/// Filters that produced the feed
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotFilters {
+    pub feed_type: String,
+    pub kinds: Vec<u16>,
+    pub authors_count: usize,
+    pub limit: u64,
+}
+
+/// A single note in the snapshot
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotNote {
+    pub index: usize,
+    pub id: String,
+    pub pubkey: String,
+    pub kind: u16,
+    pub created_at: i64,
+    pub is_reply: bool,
+    pub reply_to: Option<String>,
+    pub is_repost: bool,
+    pub relays: Vec<String>,
+}
+
+/// Complete feed snapshot
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedSnapshot {
+    pub version: u32,
+    pub generated_at: i64,
+    pub app_version: String,
+    pub pubkeys_redacted: bool,
+    pub filters: SnapshotFilters,
+    pub note_count: usize,
+    pub duplicate_ids: Vec<String>,
+    pub notes: Vec<SnapshotNote>,
+}
+
+impl FeedSnapshot {
+    /// Build a snapshot from displayed notes and the relays each was seen on
+    pub fn build(
+        notes: &[DisplayNote],
+        seen_on: &HashMap<String, Vec<String>>,
+        filters: SnapshotFilters,
+        redact_pubkeys: bool,
+    ) -> Self {
+        let mut aliases: HashMap<String, String> = HashMap::new();
+        let mut seen_ids: HashMap<&str, usize> = HashMap::new();
+
+        let snapshot_notes = notes.iter().enumerate().map(|(index, note)| {
+            *seen_ids.entry(note.id.as_str()).or_insert(0) += 1;
+
+            let pubkey = if redact_pubkeys {
+                let next = aliases.len() + 1;
+                aliases.entry(note.pubkey.clone())
+                    .or_insert_with(|| format!("pubkey-{}", next))
+                    .clone()
+            } else {
+                note.pubkey.clone()
+            };
+
+            let mut relays = seen_on.get(&note.id).cloned().unwrap_or_default();
+            relays.sort();
+
+            SnapshotNote {
+                index,
+                id: note.id.clone(),
+                pubkey,
+                kind: note.kind,
+                created_at: note.created_at,
+                is_reply: note.is_reply,
+                reply_to: note.reply_to.clone(),
+                is_repost: note.is_repost,
+                relays,
+            }
+        }).collect();
+
+        let mut duplicate_ids: Vec<String> = seen_ids.into_iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(id, _)| id.to_string())
+            .collect();
+        duplicate_ids.sort();
+
+        Self {
+            version: SNAPSHOT_VERSION,
+            generated_at: chrono::Utc::now().timestamp(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            pubkeys_redacted: redact_pubkeys,
+            filters,
+            note_count: notes.len(),
+            duplicate_ids,
+            notes: snapshot_notes,
+        }
+    }
+
+    /// Serialize to pretty JSON
+    pub fn to_json_pretty(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
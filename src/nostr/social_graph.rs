@@ -0,0 +1,139 @@
+//! Social graph - ranks users by how many of the people you follow follow them
+//!
+//! Built from the contact lists (kind 3) of the user's follows as stored in
+//! nostrdb. Lists missing locally are fetched from relays and ingested, so
+//! ranking and suggestions keep working offline afterwards.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::{HashMap, HashSet};
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+use nostr_sdk::prelude::*;
+
+use super::contacts::contacts_from_event;
+use super::database::NostrDbManager;
+use super::relay::RelayManager;
+
+/// How often contact lists of follows are re-fetched even if cached
+const REFRESH_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Authors per contact list request
+const FETCH_CHUNK: usize = 200;
+
+/// Suggestions need at least this many follows in common
+const MIN_SUGGESTION_PROOF: usize = 2;
+
+lazy_static::lazy_static! {
+    static ref LAST_REFRESH: RwLock<Option<Instant>> = RwLock::new(None);
+}
+
+/// Second-degree follow graph around the logged-in user
+#[derive(Debug, Clone, Default)]
+pub struct SocialGraph {
+    following: HashSet<PublicKey>,
+    /// Target -> the user's follows who follow the target
+    followed_by: HashMap<PublicKey, HashSet<PublicKey>>,
+}
+
+impl SocialGraph {
+    /// Build from the contact lists of the user's follows (newest list per author wins)
+    pub fn build(user: &PublicKey, following: &[PublicKey], contact_lists: &[Event]) -> Self {
+        let following: HashSet<PublicKey> = following.iter().copied().collect();
+
+        let mut newest: HashMap<PublicKey, &Event> = HashMap::new();
+        for event in contact_lists.iter().filter(|e| e.kind == Kind::ContactList && following.contains(&e.pubkey)) {
+            let entry = newest.entry(event.pubkey).or_insert(event);
+            if event.created_at > entry.created_at {
+                *entry = event;
+            }
+        }
+
+        let mut followed_by: HashMap<PublicKey, HashSet<PublicKey>> = HashMap::new();
+        for (author, event) in newest {
+            for contact in contacts_from_event(event) {
+                let Ok(target) = PublicKey::parse(&contact.pubkey) else { continue };
+                if target != *user && target != author {
+                    followed_by.entry(target).or_default().insert(author);
+                }
+            }
+        }
+
+        Self { following, followed_by }
+    }
+
+    /// Build from contact lists stored in nostrdb
+    pub fn load(user: &PublicKey, following: &[PublicKey]) -> Self {
+        let lists = NostrDbManager::global()
+            .and_then(|db| db.query_author_events(&[Kind::ContactList.as_u16()], following, following.len() * 2))
+            .unwrap_or_default();
+        Self::build(user, following, &lists)
+    }
+
+    pub fn is_following(&self, pubkey: &PublicKey) -> bool {
+        self.following.contains(pubkey)
+    }
+
+    /// Number of the user's follows who follow this pubkey
+    pub fn proximity(&self, pubkey: &PublicKey) -> usize {
+        self.followed_by.get(pubkey).map(|s| s.len()).unwrap_or(0)
+    }
+
+    /// Up to `limit` of the user's follows who follow this pubkey (for "followed by" labels)
+    pub fn followed_by(&self, pubkey: &PublicKey, limit: usize) -> Vec<PublicKey> {
+        let mut mutuals: Vec<PublicKey> = self.followed_by.get(pubkey)
+            .map(|s| s.iter().copied().collect())
+            .unwrap_or_default();
+        mutuals.sort();
+        mutuals.truncate(limit);
+        mutuals
+    }
+
+    /// Users not yet followed, ranked by how many follows follow them
+    pub fn suggestions(&self, limit: usize) -> Vec<(PublicKey, usize)> {
+        let mut ranked: Vec<(PublicKey, usize)> = self.followed_by.iter()
+            .filter(|(pk, _)| !self.following.contains(pk))
+            .map(|(pk, by)| (*pk, by.len()))
+            .filter(|(_, count)| *count >= MIN_SUGGESTION_PROOF)
+            .collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked.truncate(limit);
+        ranked
+    }
+}
+
+/// Fetch contact lists of follows that aren't stored locally (or all of them
+/// if the last full refresh is older than REFRESH_INTERVAL) and ingest them
+/// Returns the number of lists fetched
+pub async fn refresh(manager: &RelayManager, following: &[PublicKey]) -> Result<usize, String> {
+    let db = NostrDbManager::global()?;
+
+    let full = LAST_REFRESH.read().unwrap()
+        .map(|t| t.elapsed() >= REFRESH_INTERVAL)
+        .unwrap_or(true);
+
+    let to_fetch: Vec<PublicKey> = if full {
+        following.to_vec()
+    } else {
+        let have: HashSet<PublicKey> = db
+            .query_author_events(&[Kind::ContactList.as_u16()], following, following.len() * 2)?
+            .into_iter()
+            .map(|e| e.pubkey)
+            .collect();
+        following.iter().filter(|pk| !have.contains(pk)).copied().collect()
+    };
+
+    let mut fetched = 0;
+    for chunk in to_fetch.chunks(FETCH_CHUNK) {
+        let events = manager.fetch_contact_lists(chunk).await?;
+        let events: Vec<Event> = events.into_iter().collect();
+        fetched += events.len();
+        db.ingest_events(&events)?;
+    }
+
+    if full {
+        *LAST_REFRESH.write().unwrap() = Some(Instant::now());
+    }
+    tracing::info!("Social graph refresh fetched {} contact lists", fetched);
+    Ok(fetched)
+}
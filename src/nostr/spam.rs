@@ -0,0 +1,194 @@
+//! Spam collapsing for the global feed
+//!
+//! Bots flood global with the same text from many keys, the same link over
+//! and over, or bursts of notes from one key. Instead of hiding them, each
+//! group is folded into its newest note, which carries `extras.collapsed`
+//! ({count, reason, authors}) so QML can show one row with a count.
+//!
+//! Groups are found within one page of notes; a later page can start a new
+//! group for the same spam.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+use super::feed::DisplayNote;
+
+/// Shorter texts ("gm", "🤙") are left alone when posted by different people
+const MIN_DUPLICATE_CHARS: usize = 20;
+
+/// Notes linking the same URL before they're collapsed
+const MIN_URL_REPEATS: usize = 3;
+
+/// Notes from one author, each within BURST_GAP_SECS of the next, that make a burst
+const MIN_BURST_NOTES: usize = 4;
+const BURST_GAP_SECS: i64 = 120;
+
+lazy_static::lazy_static! {
+    static ref URL_RE: regex::Regex = regex::Regex::new(r"https?://[^\s<>\[\]]+").unwrap();
+}
+
+/// Why notes were folded together, most specific first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Reason {
+    Duplicate,
+    Link,
+    Burst,
+}
+
+impl Reason {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Reason::Duplicate => "duplicate",
+            Reason::Link => "link",
+            Reason::Burst => "burst",
+        }
+    }
+}
+
+/// Case- and whitespace-insensitive hash of the note text
+fn content_hash(content: &str) -> Option<u64> {
+    let normalized = content.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    if normalized.is_empty() {
+        return None;
+    }
+    let mut hasher = DefaultHasher::new();
+    normalized.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
+fn link_urls(note: &DisplayNote) -> HashSet<String> {
+    URL_RE.find_iter(&note.content)
+        .map(|m| m.as_str().trim_end_matches(['.', ',', ')', '!', '?']).to_string())
+        .filter(|url| !note.images.contains(url) && !note.videos.contains(url))
+        .collect()
+}
+
+/// Keys a note can be grouped by, with the reason each stands for
+fn group_keys(notes: &[DisplayNote]) -> Vec<Vec<(String, Reason)>> {
+    let mut keys: Vec<Vec<(String, Reason)>> = notes.iter().map(|note| {
+        let mut keys = Vec::new();
+        if let Some(hash) = content_hash(&note.content) {
+            // The same author repeating any text is a duplicate; across authors only longer ones
+            keys.push((format!("text:{}:{:x}", note.pubkey, hash), Reason::Duplicate));
+            if note.content.chars().count() >= MIN_DUPLICATE_CHARS {
+                keys.push((format!("text:{:x}", hash), Reason::Duplicate));
+            }
+        }
+        for url in link_urls(note) {
+            keys.push((format!("url:{}", url), Reason::Link));
+        }
+        keys
+    }).collect();
+
+    // Bursts: runs of one author's notes close together in time
+    let mut by_author: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (i, note) in notes.iter().enumerate() {
+        by_author.entry(note.pubkey.as_str()).or_default().push(i);
+    }
+    for (author, mut indices) in by_author {
+        if indices.len() < MIN_BURST_NOTES {
+            continue;
+        }
+        indices.sort_by_key(|&i| std::cmp::Reverse(notes[i].created_at));
+        let mut run: Vec<usize> = Vec::new();
+        let mut runs = 0;
+        for i in indices.into_iter().chain(std::iter::once(usize::MAX)) {
+            let continues = i != usize::MAX
+                && run.last().is_some_and(|&prev| notes[prev].created_at - notes[i].created_at <= BURST_GAP_SECS);
+            if continues {
+                run.push(i);
+                continue;
+            }
+            if run.len() >= MIN_BURST_NOTES {
+                for &member in &run {
+                    keys[member].push((format!("burst:{}:{}", author, runs), Reason::Burst));
+                }
+                runs += 1;
+            }
+            run = if i == usize::MAX { Vec::new() } else { vec![i] };
+        }
+    }
+    keys
+}
+
+/// Fold duplicate, repeated-link and burst notes into the newest of each group
+/// Returns the notes newest first
+pub fn collapse(mut notes: Vec<DisplayNote>) -> Vec<DisplayNote> {
+    notes.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    let keys = group_keys(&notes);
+
+    // Only keys shared by enough notes group anything
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for note_keys in &keys {
+        for (key, _) in note_keys {
+            *counts.entry(key.as_str()).or_default() += 1;
+        }
+    }
+    let qualifies = |key: &str, reason: Reason| {
+        let count = counts.get(key).copied().unwrap_or(0);
+        match reason {
+            Reason::Link => count >= MIN_URL_REPEATS,
+            Reason::Duplicate | Reason::Burst => count >= 2,
+        }
+    };
+
+    // Group of each key; notes are newest first, so a group's first note is its newest
+    let mut key_group: HashMap<&str, usize> = HashMap::new();
+    let mut groups: Vec<(usize, Vec<usize>, Reason)> = Vec::new();
+    for (i, note_keys) in keys.iter().enumerate() {
+        let shared: Vec<(&str, Reason)> = note_keys.iter()
+            .filter(|(key, reason)| qualifies(key, *reason))
+            .map(|(key, reason)| (key.as_str(), *reason))
+            .collect();
+        if shared.is_empty() {
+            continue;
+        }
+        let existing = shared.iter().find_map(|(key, reason)| key_group.get(key).map(|&g| (g, *reason)));
+        let group = match existing {
+            Some((g, reason)) => {
+                groups[g].1.push(i);
+                groups[g].2 = groups[g].2.min(reason);
+                g
+            }
+            None => {
+                let reason = shared.iter().map(|(_, r)| *r).min().expect("shared is not empty");
+                groups.push((i, Vec::new(), reason));
+                groups.len() - 1
+            }
+        };
+        for (key, _) in shared {
+            key_group.entry(key).or_insert(group);
+        }
+    }
+
+    let folded: HashSet<usize> = groups.iter().flat_map(|(_, members, _)| members.iter().copied()).collect();
+    let mut summaries: HashMap<usize, serde_json::Value> = HashMap::new();
+    for (head, members, reason) in &groups {
+        if members.is_empty() {
+            continue;
+        }
+        let authors: HashSet<&str> = std::iter::once(*head).chain(members.iter().copied())
+            .map(|i| notes[i].pubkey.as_str())
+            .collect();
+        summaries.insert(*head, serde_json::json!({
+            "count": members.len(),
+            "reason": reason.as_str(),
+            "authors": authors.len(),
+        }));
+    }
+    if !folded.is_empty() {
+        tracing::debug!("Collapsed {} repetitive notes into {} rows", folded.len(), summaries.len());
+    }
+
+    notes.into_iter()
+        .enumerate()
+        .filter(|(i, _)| !folded.contains(i))
+        .map(|(i, mut note)| {
+            if let Some(summary) = summaries.remove(&i) {
+                note.extras.insert("collapsed".to_string(), summary);
+            }
+            note
+        })
+        .collect()
+}
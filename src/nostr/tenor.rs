@@ -8,10 +8,16 @@
 //! This ensures users' privacy - Tenor never sees the Nostr post,
 //! and Nostr relays never see Tenor URLs.
 
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use nostr_sdk::prelude::*;
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
 use serde::{Deserialize, Serialize};
 
+use crate::core::cache_stats;
+use crate::core::limits;
+use crate::core::lru::LruCache;
+
 /// GIF result from Tenor search
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GifResult {
@@ -27,29 +33,36 @@ pub struct GifResult {
     pub id: String,
 }
 
-/// Response from Tenor API
-#[derive(Debug, Deserialize)]
-struct TenorSearchResponse {
-    results: Vec<TenorResult>,
+/// One page of GIF results
+#[derive(Debug, Clone, Serialize)]
+pub struct GifPage {
+    pub results: Vec<GifResult>,
+    /// Position token for the next page; None on the last page
+    pub next: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
-struct TenorResult {
-    id: String,
-    media_formats: TenorMediaFormats,
+/// A featured search category (e.g. "#happy") with its preview GIF
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GifCategory {
+    /// Term to search for when the category is picked
+    pub searchterm: String,
+    /// Display name
+    pub name: String,
+    /// Preview GIF URL
+    pub image: String,
 }
 
+/// Response from Tenor API
 #[derive(Debug, Deserialize)]
-struct TenorMediaFormats {
-    gif: Option<TenorMedia>,
-    tinygif: Option<TenorMedia>,
-    mediumgif: Option<TenorMedia>,
+struct TenorSearchResponse {
+    results: Vec<TenorResult>,
+    #[serde(default)]
+    next: String,
 }
 
-#[derive(Debug, Clone, Deserialize)]
-struct TenorMedia {
-    url: String,
-    dims: Vec<u32>,
+#[derive(Debug, Deserialize)]
+struct TenorCategoriesResponse {
+    tags: Vec<GifCategory>,
 }
 
 /// NIP-96 server info from .well-known
@@ -74,21 +87,38 @@ struct Nip94Event {
     tags: Vec<Vec<String>>,
 }
 
+/// Results requested per page
+pub const PAGE_SIZE: u32 = 24;
+
+/// How long fetched pages and categories are reused
+const CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Pages kept in the cache before the oldest are dropped
+const MAX_CACHED_PAGES: usize = 64;
+
+lazy_static::lazy_static! {
+    // "search|query|pos" / "featured||pos" -> (fetched at, page)
+    static ref PAGE_CACHE: LruCache<String, GifPage> = LruCache::new(MAX_CACHED_PAGES, Some(CACHE_TTL));
+    static ref CATEGORY_CACHE: Mutex<Option<(Instant, Vec<GifCategory>)>> = Mutex::new(None);
+}
+
 /// Search Tenor for GIFs
 /// 
 /// # Arguments
 /// * `api_key` - Google Cloud API key with Tenor API enabled
 /// * `query` - Search term
-/// * `limit` - Maximum number of results (default 20)
+/// * `limit` - Maximum number of results per page
+/// * `pos` - Position token from the previous page's `next`, None for the first page
 /// 
 /// # Returns
-/// List of GIF results with URLs and dimensions
+/// One page of GIF results with URLs and dimensions
 pub async fn search_gifs(
     api_key: &str,
     query: &str,
     limit: u32,
-) -> Result<Vec<GifResult>, String> {
-    let client = reqwest::Client::new();
+    pos: Option<&str>,
+) -> Result<GifPage, String> {
+    tracing::debug!("Searching Tenor: {}", query);
     
     let url = format!(
         "https://tenor.googleapis.com/v2/search?q={}&key={}&client_key=PlebClient&limit={}&media_filter=gif,tinygif,mediumgif",
@@ -96,26 +126,78 @@ pub async fn search_gifs(
         api_key,
         limit
     );
+    let page = fetch_page("search", query, &url, pos).await?;
     
-    tracing::debug!("Searching Tenor: {}", query);
+    tracing::info!("Found {} GIFs for query: {}", page.results.len(), query);
     
-    let response = client
-        .get(&url)
-        .timeout(std::time::Duration::from_secs(10))
-        .send()
-        .await
-        .map_err(|e| format!("Tenor request failed: {}", e))?;
+    Ok(page)
+}
+
+/// Get trending GIFs from Tenor
+pub async fn get_trending_gifs(
+    api_key: &str,
+    limit: u32,
+    pos: Option<&str>,
+) -> Result<GifPage, String> {
+    let url = format!(
+        "https://tenor.googleapis.com/v2/featured?key={}&client_key=PlebClient&limit={}&media_filter=gif,tinygif,mediumgif",
+        api_key,
+        limit
+    );
+    fetch_page("featured", "", &url, pos).await
+}
+
+/// Get Tenor's featured search categories
+pub async fn get_categories(api_key: &str) -> Result<Vec<GifCategory>, String> {
+    if let Some((fetched, categories)) = CATEGORY_CACHE.lock().unwrap().as_ref() {
+        if fetched.elapsed() < CACHE_TTL {
+            cache_stats::hit(cache_stats::GIFS);
+            return Ok(categories.clone());
+        }
+    }
+    cache_stats::miss(cache_stats::GIFS);
     
-    if !response.status().is_success() {
-        let status = response.status();
-        let body = response.text().await.unwrap_or_default();
-        return Err(format!("Tenor API error ({}): {}", status, body));
+    let url = format!(
+        "https://tenor.googleapis.com/v2/categories?key={}&client_key=PlebClient&type=featured",
+        api_key
+    );
+    let data: TenorCategoriesResponse = get_json(&url).await?;
+    
+    *CATEGORY_CACHE.lock().unwrap() = Some((Instant::now(), data.tags.clone()));
+    Ok(data.tags)
+}
+
+/// (cached pages, approximate bytes) for diagnostics
+pub fn cache_usage() -> (usize, u64) {
+    PAGE_CACHE.usage(|_, page| {
+        page.results.iter()
+            .map(|gif| gif.url.len() + gif.preview_url.len() + gif.id.len())
+            .sum()
+    })
+}
+
+/// Forget cached pages and categories
+pub fn clear_cache() {
+    PAGE_CACHE.clear();
+    *CATEGORY_CACHE.lock().unwrap() = None;
+}
+
+/// Fetch one page of a search or featured listing, from the cache when fresh
+async fn fetch_page(endpoint: &str, query: &str, url: &str, pos: Option<&str>) -> Result<GifPage, String> {
+    let pos = pos.filter(|p| !p.is_empty());
+    let key = format!("{}|{}|{}", endpoint, query, pos.unwrap_or(""));
+    
+    if let Some(page) = PAGE_CACHE.get(&key) {
+        cache_stats::hit(cache_stats::GIFS);
+        return Ok(page);
     }
+    cache_stats::miss(cache_stats::GIFS);
     
-    let data: TenorSearchResponse = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse Tenor response: {}", e))?;
+    let url = match pos {
+        Some(pos) => format!("{}&pos={}", url, urlencoding::encode(pos)),
+        None => url.to_string(),
+    };
+    let data: TenorSearchResponse = get_json(&url).await?;
     
     let results: Vec<GifResult> = data.results
         .into_iter()
@@ -134,26 +216,22 @@ pub async fn search_gifs(
         })
         .collect();
     
-    tracing::info!("Found {} GIFs for query: {}", results.len(), query);
+    // Tenor sends an empty (or "0") position once there is nothing more
+    let next = Some(data.next).filter(|n| !n.is_empty() && n != "0" && !results.is_empty());
+    let page = GifPage { results, next };
+    
+    PAGE_CACHE.insert(key, page.clone());
     
-    Ok(results)
+    Ok(page)
 }
 
-/// Get trending GIFs from Tenor
-pub async fn get_trending_gifs(
-    api_key: &str,
-    limit: u32,
-) -> Result<Vec<GifResult>, String> {
-    let client = reqwest::Client::new();
-    
-    let url = format!(
-        "https://tenor.googleapis.com/v2/featured?key={}&client_key=PlebClient&limit={}&media_filter=gif,tinygif,mediumgif",
-        api_key,
-        limit
-    );
+/// GET a Tenor API URL and parse the JSON body
+async fn get_json<T: serde::de::DeserializeOwned>(url: &str) -> Result<T, String> {
+    let client = crate::core::network::http();
     
+    let _permit = limits::host_permit(url).await;
     let response = client
-        .get(&url)
+        .get(url)
         .timeout(std::time::Duration::from_secs(10))
         .send()
         .await
@@ -165,28 +243,10 @@ pub async fn get_trending_gifs(
         return Err(format!("Tenor API error ({}): {}", status, body));
     }
     
-    let data: TenorSearchResponse = response
+    response
         .json()
         .await
-        .map_err(|e| format!("Failed to parse Tenor response: {}", e))?;
-    
-    let results: Vec<GifResult> = data.results
-        .into_iter()
-        .filter_map(|r| {
-            let gif = r.media_formats.mediumgif.or(r.media_formats.gif)?;
-            let preview = r.media_formats.tinygif.unwrap_or_else(|| gif.clone());
-            
-            Some(GifResult {
-                url: gif.url,
-                preview_url: preview.url,
-                width: gif.dims.first().copied().unwrap_or(0),
-                height: gif.dims.get(1).copied().unwrap_or(0),
-                id: r.id,
-            })
-        })
-        .collect();
-    
-    Ok(results)
+        .map_err(|e| format!("Failed to parse Tenor response: {}", e))
 }
 
 /// Download a GIF from Tenor and re-upload to a NIP-96 server
@@ -206,11 +266,12 @@ pub async fn bridge_gif_to_nostr(
     nip96_server: &str,
     keys: &Keys,
 ) -> Result<String, String> {
-    let client = reqwest::Client::new();
+    let client = crate::core::network::http();
     
     // Step 1: Download the GIF from Tenor
     tracing::info!("Downloading GIF from Tenor: {}", tenor_url);
     
+    let download_permit = limits::host_permit(tenor_url).await;
     let gif_response = client
         .get(tenor_url)
         .timeout(std::time::Duration::from_secs(30))
@@ -226,6 +287,7 @@ pub async fn bridge_gif_to_nostr(
         .bytes()
         .await
         .map_err(|e| format!("Failed to read GIF bytes: {}", e))?;
+    drop(download_permit);
     
     tracing::info!("Downloaded {} bytes", gif_bytes.len());
     
@@ -284,6 +346,7 @@ pub async fn bridge_gif_to_nostr(
             .map_err(|e| format!("Invalid auth header: {}", e))?,
     );
     
+    let _permit = limits::host_permit(&upload_url).await;
     let upload_response = client
         .post(&upload_url)
         .headers(headers)
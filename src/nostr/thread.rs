@@ -0,0 +1,290 @@
+//! Thread view - reply tree built from NIP-10 tags
+//!
+//! A thread is the chain of parents above the opened note, the note itself and
+//! the replies below it. Replies are arranged into a tree by their direct
+//! parent and flattened depth-first, so each entry carries its depth and the
+//! id of the note it answers. Replies are loaded a page at a time; each page
+//! brings its replies-to-replies along.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::{HashMap, HashSet};
+use nostr_sdk::prelude::*;
+
+use super::feed::DisplayNote;
+use super::profile::ProfileCache;
+
+/// Direct replies fetched per page
+pub const REPLY_PAGE_SIZE: usize = 50;
+
+/// Rounds of replies-to-replies fetched below each page
+pub const NESTED_REPLY_ROUNDS: usize = 3;
+
+/// One page of replies
+#[derive(Debug, Clone, Default)]
+pub struct ReplyPage {
+    /// Notes referencing the thread note plus nested replies to them
+    pub events: Vec<Event>,
+    /// Oldest note referencing the thread note, for the next page
+    pub oldest: Option<Timestamp>,
+    /// Whether the relays may have older replies
+    pub more: bool,
+}
+
+/// Reply ordering among siblings
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThreadSort {
+    /// Oldest first, like a conversation
+    #[default]
+    Time,
+    /// Most replied-to branches first
+    Engagement,
+}
+
+impl ThreadSort {
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "engagement" => ThreadSort::Engagement,
+            _ => ThreadSort::Time,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ThreadSort::Time => "time",
+            ThreadSort::Engagement => "engagement",
+        }
+    }
+}
+
+/// The note an event directly replies to (NIP-10)
+/// Prefers the "reply" marker, then "root" (a direct reply to the root),
+/// then the last unmarked `e` tag (deprecated positional scheme)
+pub fn reply_parent(event: &Event) -> Option<EventId> {
+    let mut root = None;
+    let mut positional = None;
+    for tag in event.tags.iter() {
+        if let Some(TagStandard::Event { event_id, marker, .. }) = tag.as_standardized() {
+            match marker {
+                Some(Marker::Reply) => return Some(*event_id),
+                Some(Marker::Root) => root = Some(*event_id),
+                _ => positional = Some(*event_id),
+            }
+        }
+    }
+    root.or(positional)
+}
+
+/// A note placed in the thread
+#[derive(Debug, Clone)]
+pub struct ThreadEntry {
+    pub note: DisplayNote,
+    /// 0 for the parents and the opened note, 1 for direct replies, and so on
+    pub depth: u32,
+    /// Note this one replies to, if any
+    pub parent_id: Option<String>,
+    /// Loaded replies directly below this note
+    pub child_count: usize,
+    /// Loaded replies anywhere below this note
+    pub descendant_count: usize,
+}
+
+impl ThreadEntry {
+    /// Note JSON plus depth, parentId, childCount and descendantCount
+    pub fn to_json(&self) -> String {
+        self.to_value().to_string()
+    }
+    
+    /// Note fields plus depth, parentId, childCount and descendantCount
+    pub fn to_value(&self) -> serde_json::Value {
+        let mut value = self.note.to_value();
+        value["depth"] = serde_json::Value::from(self.depth);
+        value["parentId"] = serde_json::Value::from(self.parent_id.clone());
+        value["childCount"] = serde_json::Value::from(self.child_count);
+        value["descendantCount"] = serde_json::Value::from(self.descendant_count);
+        value
+    }
+}
+
+/// Loaded thread state: parents, the opened note and every reply fetched so far
+#[derive(Debug, Clone, Default)]
+pub struct ThreadView {
+    pub target_id: String,
+    head: Vec<Event>,
+    replies: HashMap<EventId, Event>,
+    profiles: HashMap<String, ProfileCache>,
+    pub sort: ThreadSort,
+    /// Upper bound for the next page of replies
+    pub until: Option<Timestamp>,
+    pub has_more: bool,
+}
+
+impl ThreadView {
+    /// `head` is the parents (oldest first) followed by the opened note
+    pub fn new(target_id: &str, head: Vec<Event>, page: ReplyPage, profiles: HashMap<String, ProfileCache>, sort: ThreadSort) -> Self {
+        let mut view = Self {
+            target_id: target_id.to_string(),
+            head,
+            sort,
+            ..Default::default()
+        };
+        view.add_page(page, profiles);
+        view
+    }
+
+    /// Merge a page of replies; returns how many were new
+    pub fn add_page(&mut self, page: ReplyPage, profiles: HashMap<String, ProfileCache>) -> usize {
+        self.profiles.extend(profiles);
+        self.until = page.oldest.map(|t| Timestamp::from(t.as_secs().saturating_sub(1)));
+        self.has_more = page.more && page.oldest.is_some();
+
+        let head_ids: HashSet<EventId> = self.head.iter().map(|e| e.id).collect();
+        let mut added = 0;
+        for event in page.events {
+            if head_ids.contains(&event.id) {
+                continue;
+            }
+            if self.replies.insert(event.id, event).is_none() {
+                added += 1;
+            }
+        }
+        added
+    }
+
+    /// Add a reply that arrived live; only replies to the opened note or to
+    /// loaded replies are taken (others belong to other branches of the root)
+    pub fn add_live(&mut self, event: Event, profiles: HashMap<String, ProfileCache>) -> bool {
+        if self.head.iter().any(|e| e.id == event.id) || self.replies.contains_key(&event.id) {
+            return false;
+        }
+        let target = self.head.last().map(|e| e.id);
+        let fits = reply_parent(&event)
+            .map_or(false, |p| Some(p) == target || self.replies.contains_key(&p));
+        if !fits {
+            return false;
+        }
+        self.profiles.extend(profiles);
+        self.replies.insert(event.id, event);
+        true
+    }
+
+    /// Notes whose new replies belong in this thread: the root of the
+    /// conversation (replies to anything in it tag the root) and the opened note
+    pub fn watch_ids(&self) -> Vec<EventId> {
+        let mut ids: Vec<EventId> = self.head.first().map(|e| e.id).into_iter().collect();
+        if let Some(target) = self.head.last() {
+            if !ids.contains(&target.id) {
+                ids.push(target.id);
+            }
+        }
+        ids
+    }
+
+    /// Pubkeys we have no profile for yet
+    pub fn missing_profiles<'a>(&self, events: impl IntoIterator<Item = &'a Event>) -> Vec<PublicKey> {
+        let mut missing: Vec<PublicKey> = events.into_iter()
+            .map(|e| e.pubkey)
+            .filter(|pk| !self.profiles.contains_key(&pk.to_hex()))
+            .collect();
+        missing.sort();
+        missing.dedup();
+        missing
+    }
+
+    pub fn reply_count(&self) -> usize {
+        self.replies.len()
+    }
+
+    fn display(&self, event: &Event) -> DisplayNote {
+        let profile = self.profiles.get(&event.pubkey.to_hex());
+        DisplayNote::from_event_with_profiles(event, profile, &self.profiles)
+    }
+
+    /// The flattened thread: parents, the opened note, then the reply tree depth-first
+    pub fn entries(&self) -> Vec<ThreadEntry> {
+        let target = self.head.last().map(|e| e.id);
+
+        // Parent -> direct replies; replies whose parent wasn't loaded hang off the opened note
+        let mut children: HashMap<EventId, Vec<&Event>> = HashMap::new();
+        for event in self.replies.values() {
+            let parent = reply_parent(event)
+                .filter(|p| *p != event.id && (Some(*p) == target || self.replies.contains_key(p)))
+                .or(target);
+            if let Some(parent) = parent {
+                children.entry(parent).or_default().push(event);
+            }
+        }
+
+        let mut descendants: HashMap<EventId, usize> = HashMap::new();
+        if let Some(target) = target {
+            count_descendants(target, &children, &mut descendants, &mut HashSet::new());
+        }
+
+        let mut entries: Vec<ThreadEntry> = self.head.iter()
+            .map(|event| ThreadEntry {
+                note: self.display(event),
+                depth: 0,
+                parent_id: reply_parent(event).map(|p| p.to_hex()),
+                child_count: children.get(&event.id).map_or(0, |c| c.len()),
+                descendant_count: descendants.get(&event.id).copied().unwrap_or(0),
+            })
+            .collect();
+
+        if let Some(target) = target {
+            let mut visited = HashSet::from([target]);
+            self.push_children(target, 1, &children, &descendants, &mut visited, &mut entries);
+        }
+        entries
+    }
+
+    fn push_children(
+        &self,
+        parent: EventId,
+        depth: u32,
+        children: &HashMap<EventId, Vec<&Event>>,
+        descendants: &HashMap<EventId, usize>,
+        visited: &mut HashSet<EventId>,
+        entries: &mut Vec<ThreadEntry>,
+    ) {
+        let Some(replies) = children.get(&parent) else { return };
+        let mut replies = replies.clone();
+        match self.sort {
+            ThreadSort::Time => replies.sort_by_key(|e| e.created_at),
+            ThreadSort::Engagement => replies.sort_by(|a, b| {
+                let count = |e: &Event| descendants.get(&e.id).copied().unwrap_or(0);
+                count(b).cmp(&count(a)).then(a.created_at.cmp(&b.created_at))
+            }),
+        }
+
+        for event in replies {
+            if !visited.insert(event.id) {
+                continue;
+            }
+            entries.push(ThreadEntry {
+                note: self.display(event),
+                depth,
+                parent_id: Some(parent.to_hex()),
+                child_count: children.get(&event.id).map_or(0, |c| c.len()),
+                descendant_count: descendants.get(&event.id).copied().unwrap_or(0),
+            });
+            self.push_children(event.id, depth + 1, children, descendants, visited, entries);
+        }
+    }
+}
+
+/// Fill in how many replies sit below each note
+fn count_descendants(
+    id: EventId,
+    children: &HashMap<EventId, Vec<&Event>>,
+    counts: &mut HashMap<EventId, usize>,
+    visited: &mut HashSet<EventId>,
+) -> usize {
+    if !visited.insert(id) {
+        return 0;
+    }
+    let total = children.get(&id).map_or(0, |replies| {
+        replies.iter().map(|e| 1 + count_descendants(e.id, children, counts, visited)).sum()
+    });
+    counts.insert(id, total);
+    total
+}
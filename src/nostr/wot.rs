@@ -0,0 +1,183 @@
+//! Web of trust - hop distance from the user's follow graph
+//!
+//! The user is 0 hops away, their follows 1, and follows-of-follows 2. Scores
+//! are computed from contact lists stored in nostrdb and cached alongside it,
+//! so the global feed and search can hide or demote notes from accounts
+//! outside the configured trust radius without recomputing per note.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+use nostr_sdk::prelude::*;
+
+use super::contacts::contacts_from_event;
+use super::database::{NostrDbManager, TrustScores};
+use super::relay::RelayManager;
+use super::social_graph;
+use crate::core::config::Config;
+
+/// Furthest hop distance that gets a score; anyone further is outside
+pub const MAX_HOPS: u8 = 2;
+
+/// Scores older than this are recomputed
+const SCORE_TTL_SECS: i64 = 6 * 60 * 60;
+
+/// What to do with notes from accounts outside the trust radius
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustAction {
+    Hide,
+    Demote,
+}
+
+impl TrustAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrustAction::Hide => "hide",
+            TrustAction::Demote => "demote",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "hide" => TrustAction::Hide,
+            _ => TrustAction::Demote,
+        }
+    }
+}
+
+/// Trust radius and what happens outside it
+#[derive(Debug, Clone, Copy)]
+pub struct TrustPolicy {
+    /// Maximum hop distance considered trusted; 0 turns filtering off
+    pub threshold: u8,
+    pub action: TrustAction,
+}
+
+impl TrustPolicy {
+    pub fn enabled(&self) -> bool {
+        self.threshold > 0
+    }
+}
+
+lazy_static::lazy_static! {
+    /// Cached so per-note checks don't hit the config file
+    static ref POLICY: RwLock<TrustPolicy> = RwLock::new({
+        let config = Config::load();
+        TrustPolicy {
+            threshold: config.wot_threshold.min(MAX_HOPS),
+            action: TrustAction::from_str(&config.wot_action),
+        }
+    });
+
+    /// Logged-in user (hex) whose scores apply; scores computed for another
+    /// account are ignored
+    static ref OWNER: RwLock<Option<String>> = RwLock::new(None);
+}
+
+/// Current trust policy
+pub fn policy() -> TrustPolicy {
+    *POLICY.read().unwrap()
+}
+
+/// Change the trust policy
+/// The caller is responsible for saving it to the config
+pub fn set_policy(threshold: u8, action: TrustAction) {
+    *POLICY.write().unwrap() = TrustPolicy { threshold: threshold.min(MAX_HOPS), action };
+}
+
+/// Set the account scores apply to (None after logout)
+pub fn set_owner(owner: Option<&PublicKey>) {
+    *OWNER.write().unwrap() = owner.map(|pk| pk.to_hex());
+}
+
+/// Whether the stored scores belong to the logged-in user and reach beyond
+/// the user themselves; with no follows there is no graph to filter by
+fn has_usable_scores(db: &NostrDbManager) -> bool {
+    let Some(owner) = OWNER.read().unwrap().clone() else { return false };
+    db.trust_scores_info()
+        .is_some_and(|(scored_for, _, count)| scored_for == owner && count > 1)
+}
+
+/// Hop distances from the user, using contact lists stored in nostrdb
+pub fn compute(user: &PublicKey, following: &[PublicKey]) -> HashMap<String, u8> {
+    let mut distances = HashMap::new();
+    distances.insert(user.to_hex(), 0);
+    for pk in following {
+        distances.insert(pk.to_hex(), 1);
+    }
+
+    let lists = NostrDbManager::global()
+        .and_then(|db| db.query_author_events(&[Kind::ContactList.as_u16()], following, following.len() * 2))
+        .unwrap_or_default();
+    for event in &lists {
+        for contact in contacts_from_event(event) {
+            distances.entry(contact.pubkey).or_insert(2);
+        }
+    }
+
+    distances
+}
+
+/// True if there are no scores for this user or they're older than SCORE_TTL_SECS
+pub fn needs_refresh(user: &PublicKey) -> bool {
+    let Ok(db) = NostrDbManager::global() else { return false };
+    let now = Timestamp::now().as_secs() as i64;
+    db.trust_scores_info()
+        .map_or(true, |(owner, computed_at, _)| owner != user.to_hex() || now - computed_at >= SCORE_TTL_SECS)
+}
+
+/// Fetch missing contact lists of follows, recompute scores and store them
+/// Returns the number of scored accounts
+pub async fn refresh(manager: &RelayManager, user: &PublicKey, following: &[PublicKey]) -> Result<usize, String> {
+    if let Err(e) = social_graph::refresh(manager, following).await {
+        tracing::warn!("Contact list refresh failed, scoring from cache: {}", e);
+    }
+
+    let distances = compute(user, following);
+    let count = distances.len();
+    NostrDbManager::global()?.set_trust_scores(TrustScores {
+        owner: user.to_hex(),
+        computed_at: Timestamp::now().as_secs() as i64,
+        distances,
+    });
+    tracing::info!("Web of trust scored {} accounts", count);
+    Ok(count)
+}
+
+/// Whether notes from this author fall outside the trust radius
+/// Everyone counts as trusted while filtering is off or the logged-in user
+/// has no scored follow graph yet
+pub fn is_outside(pubkey_hex: &str) -> bool {
+    let policy = policy();
+    if !policy.enabled() {
+        return false;
+    }
+    let Ok(db) = NostrDbManager::global() else { return false };
+    if !has_usable_scores(&db) {
+        return false;
+    }
+    db.trust_distance(pubkey_hex).map_or(true, |d| d > policy.threshold)
+}
+
+/// Whether notes from this author should be dropped entirely
+pub fn is_hidden(pubkey_hex: &str) -> bool {
+    policy().action == TrustAction::Hide && is_outside(pubkey_hex)
+}
+
+/// Drop events from hidden authors (no-op unless the action is Hide)
+pub fn filter_hidden(events: Events) -> Events {
+    if policy().action != TrustAction::Hide || !policy().enabled() {
+        return events;
+    }
+    if !NostrDbManager::global().is_ok_and(|db| has_usable_scores(&db)) {
+        return events;
+    }
+    let mut kept = Events::default();
+    for event in events.into_iter() {
+        if !is_outside(&event.pubkey.to_hex()) {
+            kept.insert(event);
+        }
+    }
+    kept
+}
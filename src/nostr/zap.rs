@@ -23,6 +23,9 @@ pub struct LnurlPayResponse {
     pub allows_nostr: bool,
     #[serde(default)]
     pub nostr_pubkey: Option<String>,
+    /// Maximum comment length accepted by the service (LUD-12)
+    #[serde(default)]
+    pub comment_allowed: Option<u32>,
 }
 
 /// Invoice response from LNURL callback
@@ -67,7 +70,7 @@ pub async fn resolve_lnurl(lud16: &str) -> Result<LnurlPayResponse, String> {
     
     tracing::info!("Resolving LNURL: {}", url);
     
-    let client = reqwest::Client::builder()
+    let client = crate::core::network::http_client()
         .timeout(std::time::Duration::from_secs(10))
         .build()
         .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
@@ -136,6 +139,16 @@ pub async fn get_zap_invoice(
     lnurl_response: &LnurlPayResponse,
     amount_msats: u64,
     zap_request: Option<&Event>,
+) -> Result<String, String> {
+    request_invoice(lnurl_response, amount_msats, zap_request, None).await
+}
+
+/// Get an invoice from LNURL callback, optionally with a zap request or a LUD-12 comment
+pub async fn request_invoice(
+    lnurl_response: &LnurlPayResponse,
+    amount_msats: u64,
+    zap_request: Option<&Event>,
+    comment: Option<&str>,
 ) -> Result<String, String> {
     // Validate amount
     if amount_msats < lnurl_response.min_sendable {
@@ -164,9 +177,18 @@ pub async fn get_zap_invoice(
         }
     }
     
+    // Add plain comment if the service accepts one (LUD-12)
+    if let Some(comment) = comment.filter(|c| !c.is_empty()) {
+        let max_len = lnurl_response.comment_allowed.unwrap_or(0) as usize;
+        if max_len > 0 {
+            let truncated: String = comment.chars().take(max_len).collect();
+            url = format!("{}&comment={}", url, urlencoding::encode(&truncated));
+        }
+    }
+    
     tracing::info!("Fetching invoice from: {}", url);
     
-    let client = reqwest::Client::builder()
+    let client = crate::core::network::http_client()
         .timeout(std::time::Duration::from_secs(30))
         .build()
         .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
@@ -242,6 +264,31 @@ pub async fn zap(
     Ok(preimage)
 }
 
+/// Pay a lightning address directly (plain LNURL-pay, no zap request)
+pub async fn pay_lightning_address(
+    nwc_manager: &mut NwcManager,
+    lud16: &str,
+    amount_sats: u64,
+    comment: &str,
+) -> Result<String, String> {
+    let amount_msats = amount_sats * 1000;
+    
+    tracing::info!("Paying {} sats to lightning address {}", amount_sats, lud16);
+    
+    // Step 1: Resolve LNURL
+    let lnurl_response = resolve_lnurl(lud16).await?;
+    
+    // Step 2: Get invoice, passing the comment along if supported
+    let invoice = request_invoice(&lnurl_response, amount_msats, None, Some(comment)).await?;
+    tracing::info!("Got invoice: {}...", &invoice[..50.min(invoice.len())]);
+    
+    // Step 3: Pay via NWC
+    let preimage = nwc_manager.pay_invoice(&invoice).await?;
+    tracing::info!("Lightning address payment successful");
+    
+    Ok(preimage)
+}
+
 /// Zap result for QML
 #[derive(Debug, Clone, Serialize)]
 pub struct ZapResult {
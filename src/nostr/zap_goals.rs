@@ -0,0 +1,172 @@
+//! Zap goals - NIP-75 (kind 9041)
+//!
+//! A zap goal is a fundraiser: its content describes what the money is for,
+//! the `amount` tag holds the target in millisats and the `relays` tag lists
+//! where zap receipts for it should be published. Progress is the sum of the
+//! zap receipts (kind 9735) that tag the goal, up to its `closed_at` time.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::HashSet;
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+use super::relay::extract_bolt11_amount;
+
+/// Zap goal event
+pub const KIND_ZAP_GOAL: u16 = 9041;
+
+/// Zap receipts fetched per goal
+const RECEIPT_LIMIT: usize = 2000;
+
+/// A parsed kind 9041 event
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZapGoal {
+    pub id: String,
+    pub description: String,
+    pub summary: Option<String>,
+    pub image: Option<String>,
+    /// Target in sats
+    pub target_sats: u64,
+    pub closed_at: Option<i64>,
+    /// Relays zap receipts are published to
+    pub relays: Vec<String>,
+    /// Link to what the goal is raising money for (URL or naddr coordinate)
+    pub link: Option<String>,
+}
+
+impl ZapGoal {
+    /// Parse a kind 9041 event; None if it isn't one or has no target amount
+    pub fn from_event(event: &Event) -> Option<Self> {
+        if event.kind.as_u16() != KIND_ZAP_GOAL {
+            return None;
+        }
+        let mut goal = ZapGoal {
+            id: event.id.to_hex(),
+            description: event.content.clone(),
+            summary: None,
+            image: None,
+            target_sats: 0,
+            closed_at: None,
+            relays: Vec::new(),
+            link: None,
+        };
+        for tag in event.tags.iter() {
+            let values = tag.as_slice();
+            let (Some(name), Some(value)) = (values.first(), values.get(1)) else { continue };
+            match name.as_str() {
+                "amount" => goal.target_sats = value.parse::<u64>().unwrap_or(0) / 1000,
+                "relays" => {
+                    goal.relays = values[1..].iter()
+                        .filter(|url| RelayUrl::parse(url).is_ok())
+                        .cloned()
+                        .collect();
+                }
+                "closed_at" => goal.closed_at = value.parse().ok(),
+                "summary" if !value.is_empty() => goal.summary = Some(value.clone()),
+                "image" if value.starts_with("http") => goal.image = Some(value.clone()),
+                "r" | "a" if !value.is_empty() => {
+                    goal.link.get_or_insert_with(|| value.clone());
+                }
+                _ => {}
+            }
+        }
+        if goal.target_sats == 0 { None } else { Some(goal) }
+    }
+
+    /// Whether the goal no longer counts zaps
+    pub fn is_closed(&self, now: i64) -> bool {
+        self.closed_at.is_some_and(|closed| now >= closed)
+    }
+
+    /// The goal's relays that parse as relay URLs
+    pub fn relay_urls(&self) -> Vec<RelayUrl> {
+        self.relays.iter().filter_map(|url| RelayUrl::parse(url).ok()).collect()
+    }
+}
+
+/// Money raised towards a goal
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalProgress {
+    pub goal_id: String,
+    pub raised_sats: u64,
+    pub target_sats: u64,
+    /// Distinct zappers (the zap request's author, not the LNURL server)
+    pub contributors: u32,
+    pub zaps: u32,
+    /// Raised / target, capped at 1
+    pub fraction: f64,
+    pub closed: bool,
+}
+
+/// Sum the zap receipts for a goal, counting each receipt once and ignoring late ones
+pub fn tally(goal: &ZapGoal, receipts: &[Event], now: i64) -> GoalProgress {
+    let mut seen: HashSet<EventId> = HashSet::new();
+    let mut zappers: HashSet<String> = HashSet::new();
+    let mut progress = GoalProgress {
+        goal_id: goal.id.clone(),
+        target_sats: goal.target_sats,
+        closed: goal.is_closed(now),
+        ..Default::default()
+    };
+    for receipt in receipts {
+        if receipt.kind != Kind::ZapReceipt || !seen.insert(receipt.id) {
+            continue;
+        }
+        if goal.closed_at.is_some_and(|closed| receipt.created_at.as_secs() as i64 > closed) {
+            continue;
+        }
+        let tagged = receipt.tags.iter()
+            .map(|t| t.as_slice())
+            .any(|v| v.first().map(|s| s.as_str()) == Some("e") && v.get(1) == Some(&goal.id));
+        if !tagged {
+            continue;
+        }
+        let amount_msats = receipt.tags.iter()
+            .find(|t| t.kind() == TagKind::Bolt11)
+            .and_then(|t| t.content())
+            .and_then(extract_bolt11_amount)
+            .unwrap_or(0);
+        if amount_msats == 0 {
+            continue;
+        }
+        progress.raised_sats += amount_msats / 1000;
+        progress.zaps += 1;
+        if let Some(zapper) = zapper(receipt) {
+            zappers.insert(zapper);
+        }
+    }
+    progress.contributors = zappers.len() as u32;
+    progress.fraction = (progress.raised_sats as f64 / goal.target_sats.max(1) as f64).min(1.0);
+    progress
+}
+
+/// Author of the zap request embedded in a receipt's description tag
+fn zapper(receipt: &Event) -> Option<String> {
+    let description = receipt.tags.iter()
+        .find(|t| t.kind() == TagKind::Description)
+        .and_then(|t| t.content())?;
+    let request: serde_json::Value = serde_json::from_str(description).ok()?;
+    request.get("pubkey")?.as_str().map(str::to_string)
+}
+
+/// Renderer hook: the goal's target and details under extras.zapGoal
+pub fn render_zap_goal(event: &Event) -> Option<serde_json::Value> {
+    let goal = ZapGoal::from_event(event)?;
+    serde_json::to_value(&goal).ok()
+}
+
+/// Filter for the zap receipts of a goal
+pub fn receipts_filter(goal: &ZapGoal) -> Option<Filter> {
+    let goal_id = EventId::from_hex(&goal.id).ok()?;
+    let mut filter = Filter::new()
+        .kind(Kind::ZapReceipt)
+        .event(goal_id)
+        .limit(RECEIPT_LIMIT);
+    if let Some(closed_at) = goal.closed_at {
+        filter = filter.until(Timestamp::from(closed_at.max(0) as u64));
+    }
+    Some(filter)
+}
@@ -0,0 +1,251 @@
+//! Zap history - incoming and outgoing zaps for accounting exports
+//!
+//! Combines zap receipts (kind 9735) from relays with the NWC wallet's
+//! transaction list. Receipts name the counterparty and zapped note; the
+//! wallet supplies preimages and outgoing zaps whose receipt never reached a
+//! relay we read. The two are matched on the bolt11 invoice.
+
+#![allow(dead_code)]  // Planned infrastructure for future integration
+
+use std::collections::{HashMap, HashSet};
+use nostr_sdk::prelude::*;
+use serde::Serialize;
+
+use super::nwc::NwcTransaction;
+use super::relay::extract_bolt11_amount;
+
+/// Direction of a zap relative to the user
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ZapDirection {
+    Incoming,
+    Outgoing,
+}
+
+impl ZapDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ZapDirection::Incoming => "incoming",
+            ZapDirection::Outgoing => "outgoing",
+        }
+    }
+}
+
+/// Export file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "csv" => Some(ExportFormat::Csv),
+            "json" => Some(ExportFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+/// A single zap, sent or received
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZapRecord {
+    pub direction: ZapDirection,
+    /// Unix timestamp of the receipt or wallet settlement
+    pub timestamp: i64,
+    /// Sender for incoming zaps, recipient for outgoing (hex pubkey)
+    pub counterparty: Option<String>,
+    /// Zapped note (hex event id), None for profile zaps
+    pub note_id: Option<String>,
+    pub amount_sats: u64,
+    pub preimage: Option<String>,
+    pub invoice: Option<String>,
+    /// Comment from the zap request
+    pub comment: String,
+    /// Zap receipt event id, None if only the wallet knew about it
+    pub receipt_id: Option<String>,
+}
+
+/// The zap request (kind 9734) embedded in a receipt or invoice description
+struct ZapRequest {
+    sender: Option<String>,
+    recipient: Option<String>,
+    note_id: Option<String>,
+    amount_msats: Option<u64>,
+    comment: String,
+}
+
+fn parse_zap_request(json: &str) -> Option<ZapRequest> {
+    let value: serde_json::Value = serde_json::from_str(json).ok()?;
+    if value.get("kind").and_then(|k| k.as_u64()) != Some(9734) {
+        return None;
+    }
+
+    let tags: Vec<Vec<String>> = value.get("tags")
+        .and_then(|t| serde_json::from_value(t.clone()).ok())
+        .unwrap_or_default();
+    let tag = |name: &str| tags.iter()
+        .find(|t| t.first().map(|n| n == name).unwrap_or(false))
+        .and_then(|t| t.get(1).cloned());
+
+    Some(ZapRequest {
+        sender: value.get("pubkey").and_then(|p| p.as_str()).map(|p| p.to_string()),
+        recipient: tag("p"),
+        note_id: tag("e"),
+        amount_msats: tag("amount").and_then(|a| a.parse().ok()),
+        comment: value.get("content").and_then(|c| c.as_str()).unwrap_or_default().to_string(),
+    })
+}
+
+fn tag_value(event: &Event, name: &str) -> Option<String> {
+    event.tags.iter()
+        .map(|t| t.as_slice())
+        .find(|v| v.first().map(|s| s.as_str()) == Some(name))
+        .and_then(|v| v.get(1).cloned())
+}
+
+/// Build a record from a zap receipt
+pub fn from_receipt(event: &Event, direction: ZapDirection) -> Option<ZapRecord> {
+    if event.kind != Kind::ZapReceipt {
+        return None;
+    }
+
+    let request = tag_value(event, "description").and_then(|d| parse_zap_request(&d));
+    let invoice = tag_value(event, "bolt11");
+    let amount_msats = invoice.as_deref()
+        .and_then(extract_bolt11_amount)
+        .or_else(|| request.as_ref().and_then(|r| r.amount_msats))
+        .unwrap_or(0);
+
+    let counterparty = match direction {
+        ZapDirection::Incoming => request.as_ref().and_then(|r| r.sender.clone()).or_else(|| tag_value(event, "P")),
+        ZapDirection::Outgoing => tag_value(event, "p"),
+    };
+
+    Some(ZapRecord {
+        direction,
+        timestamp: event.created_at.as_secs() as i64,
+        counterparty,
+        note_id: tag_value(event, "e"),
+        amount_sats: amount_msats / 1000,
+        preimage: tag_value(event, "preimage"),
+        invoice,
+        comment: request.map(|r| r.comment).unwrap_or_default(),
+        receipt_id: Some(event.id.to_hex()),
+    })
+}
+
+/// Build a record from a settled wallet transaction whose description is a zap request
+pub fn from_transaction(tx: &NwcTransaction) -> Option<ZapRecord> {
+    if !tx.is_settled() {
+        return None;
+    }
+    let request = parse_zap_request(tx.description.as_deref()?)?;
+
+    let direction = if tx.is_incoming() { ZapDirection::Incoming } else { ZapDirection::Outgoing };
+    let counterparty = match direction {
+        ZapDirection::Incoming => request.sender,
+        ZapDirection::Outgoing => request.recipient,
+    };
+
+    Some(ZapRecord {
+        direction,
+        timestamp: tx.settled_at.unwrap_or(tx.created_at),
+        counterparty,
+        note_id: request.note_id,
+        amount_sats: tx.amount_sats().max(0) as u64,
+        preimage: tx.preimage.clone(),
+        invoice: tx.invoice.clone(),
+        comment: request.comment,
+        receipt_id: None,
+    })
+}
+
+/// Merge receipts with wallet transactions, newest first
+/// Wallet entries fill in missing preimages for matching receipts and add
+/// zaps with no receipt on our relays
+pub fn merge(incoming: &Events, outgoing: &Events, transactions: &[NwcTransaction]) -> Vec<ZapRecord> {
+    let mut records: Vec<ZapRecord> = Vec::new();
+    let mut seen: HashSet<(String, ZapDirection)> = HashSet::new();
+
+    let receipts = incoming.iter().map(|e| (e, ZapDirection::Incoming))
+        .chain(outgoing.iter().map(|e| (e, ZapDirection::Outgoing)));
+    for (event, direction) in receipts {
+        if !seen.insert((event.id.to_hex(), direction)) {
+            continue;
+        }
+        if let Some(record) = from_receipt(event, direction) {
+            records.push(record);
+        }
+    }
+
+    let by_invoice: HashMap<(String, ZapDirection), usize> = records.iter()
+        .enumerate()
+        .filter_map(|(i, r)| r.invoice.as_ref().map(|inv| ((inv.to_lowercase(), r.direction), i)))
+        .collect();
+
+    for tx in transactions {
+        let direction = if tx.is_incoming() { ZapDirection::Incoming } else { ZapDirection::Outgoing };
+        let matched = tx.invoice.as_ref()
+            .and_then(|inv| by_invoice.get(&(inv.to_lowercase(), direction)).copied());
+
+        match matched {
+            Some(index) => {
+                let record = &mut records[index];
+                if record.preimage.is_none() {
+                    record.preimage = tx.preimage.clone();
+                }
+            }
+            None => {
+                if let Some(record) = from_transaction(tx) {
+                    records.push(record);
+                }
+            }
+        }
+    }
+
+    records.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+    records
+}
+
+/// Escape a CSV field (RFC 4180)
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Render records as CSV with a header row
+pub fn to_csv(records: &[ZapRecord]) -> String {
+    let mut out = String::from("timestamp,date,direction,counterparty,note_id,amount_sats,preimage,invoice,comment,receipt_id\n");
+    for r in records {
+        let date = chrono::DateTime::from_timestamp(r.timestamp, 0)
+            .map(|d| d.to_rfc3339())
+            .unwrap_or_default();
+        let fields = [
+            r.timestamp.to_string(),
+            date,
+            r.direction.as_str().to_string(),
+            r.counterparty.clone().unwrap_or_default(),
+            r.note_id.clone().unwrap_or_default(),
+            r.amount_sats.to_string(),
+            r.preimage.clone().unwrap_or_default(),
+            r.invoice.clone().unwrap_or_default(),
+            r.comment.clone(),
+            r.receipt_id.clone().unwrap_or_default(),
+        ];
+        let row: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Render records as a pretty-printed JSON array
+pub fn to_json(records: &[ZapRecord]) -> String {
+    serde_json::to_string_pretty(records).unwrap_or_else(|_| "[]".to_string())
+}
@@ -2,9 +2,12 @@
 //!
 //! This module provides:
 //! 1. A client to communicate with an external Pleb Signer instance
-//! 2. An integrated signer that can act as a signer for other Nostr apps
+//! 2. An integrated signer that can act as a signer for other Nostr apps,
+//!    over D-Bus or through nostrconnect:// and nostrsigner: URIs
 
 pub mod client;
+pub mod policy;
 pub mod service;
+pub mod uri;
 
 pub use client::SignerClient;
@@ -0,0 +1,241 @@
+//! Signing policies and audit log for the signer service
+//!
+//! Policies decide per account, requesting app, method and (for sign_event)
+//! event kind whether a request is allowed, rejected or asked about. They live
+//! in the config. Apps are identified by something the caller can't choose
+//! (the executable behind a D-Bus connection, the key of a nostrconnect
+//! client); requests from anything else are always asked about. The audit log records every request the service answered and
+//! is kept in a JSON file in the user's data directory, newest last.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use serde::{Deserialize, Serialize};
+
+use super::service::SignerMethod;
+use crate::core::config::Config;
+
+const AUDIT_FILE: &str = "signer_audit.json";
+
+/// Audit entries kept on disk
+const MAX_AUDIT_ENTRIES: usize = 1000;
+
+/// Serializes read-modify-write of the audit file across concurrent requests
+static AUDIT_LOCK: Mutex<()> = Mutex::new(());
+
+/// What to do with a matching request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PolicyDecision {
+    Allow,
+    Ask,
+    Reject,
+}
+
+impl PolicyDecision {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "allow" => Some(PolicyDecision::Allow),
+            "ask" => Some(PolicyDecision::Ask),
+            "reject" => Some(PolicyDecision::Reject),
+            _ => None,
+        }
+    }
+}
+
+/// Identity prefixes policies can be stored for
+const REMEMBERED_PREFIXES: [&str; 3] = ["exe:", "nostrconnect:", "callback:"];
+
+/// Whether answers for this app id can be remembered: only stable identities
+/// the caller can't claim for itself, never D-Bus unique names
+pub fn can_remember(app_id: &str) -> bool {
+    REMEMBERED_PREFIXES.iter()
+        .any(|prefix| app_id.strip_prefix(prefix).is_some_and(|rest| !rest.is_empty()))
+}
+
+/// A stored decision for one account, app and method
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerPolicy {
+    /// Hex pubkey of the account the policy applies to
+    #[serde(default)]
+    pub pubkey: String,
+    pub app_id: String,
+    pub method: SignerMethod,
+    /// Event kind for sign_event; None matches every kind
+    #[serde(default)]
+    pub kind: Option<u16>,
+    pub decision: PolicyDecision,
+}
+
+impl SignerPolicy {
+    fn is_for(&self, pubkey: &str, app_id: &str, method: SignerMethod, kind: Option<u16>) -> bool {
+        self.pubkey == pubkey && self.app_id == app_id && self.method == method && self.kind == kind
+    }
+}
+
+/// Decision for a request: a kind-specific policy wins over an any-kind one;
+/// without a policy, or for an app that can't be remembered, the user is asked
+pub fn decide(policies: &[SignerPolicy], pubkey: &str, app_id: &str, method: SignerMethod, kind: Option<u16>) -> PolicyDecision {
+    if pubkey.is_empty() || !can_remember(app_id) {
+        return PolicyDecision::Ask;
+    }
+    let exact = kind.and_then(|_| policies.iter().find(|p| p.is_for(pubkey, app_id, method, kind)));
+    exact
+        .or_else(|| policies.iter().find(|p| p.is_for(pubkey, app_id, method, None)))
+        .map(|p| p.decision)
+        .unwrap_or(PolicyDecision::Ask)
+}
+
+/// Add or replace the policy for an account, app, method and kind; Ask removes it
+/// Returns false for apps whose answers can't be remembered
+pub fn set_policy(config: &mut Config, policy: SignerPolicy) -> bool {
+    if policy.pubkey.is_empty() || !can_remember(&policy.app_id) {
+        return false;
+    }
+    config.signer_policies.retain(|p| !p.is_for(&policy.pubkey, &policy.app_id, policy.method, policy.kind));
+    if policy.decision != PolicyDecision::Ask {
+        config.signer_policies.push(policy);
+    }
+    true
+}
+
+/// Policies of one account
+pub fn policies_for(config: &Config, pubkey: &str) -> Vec<SignerPolicy> {
+    config.signer_policies.iter().filter(|p| p.pubkey == pubkey).cloned().collect()
+}
+
+/// How a request was answered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditOutcome {
+    /// Allowed by a policy without asking
+    Allowed,
+    /// Approved by the user
+    Approved,
+    /// Rejected by the user or timed out
+    Rejected,
+    /// Rejected by a policy without asking
+    Blocked,
+}
+
+/// One answered signer request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub at: i64,
+    pub app_id: String,
+    pub method: SignerMethod,
+    #[serde(default)]
+    pub kind: Option<u16>,
+    /// Id of the signed event for sign_event requests
+    #[serde(default)]
+    pub event_id: Option<String>,
+    pub outcome: AuditOutcome,
+}
+
+fn audit_path() -> PathBuf {
+    directories::ProjectDirs::from("", "", "pleb-client")
+        .map(|dirs| dirs.data_dir().join(AUDIT_FILE))
+        .unwrap_or_else(|| PathBuf::from(AUDIT_FILE))
+}
+
+/// The audit log, oldest first
+pub fn audit_log() -> Vec<AuditEntry> {
+    fs::read_to_string(audit_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_audit_log(entries: &[AuditEntry]) -> Result<(), String> {
+    let path = audit_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create data directory: {}", e))?;
+    }
+    let json = serde_json::to_string(entries)
+        .map_err(|e| format!("Failed to serialize audit log: {}", e))?;
+    let tmp_path = path.with_extension("json.tmp");
+    fs::write(&tmp_path, json)
+        .and_then(|_| fs::rename(&tmp_path, &path))
+        .map_err(|e| format!("Failed to write audit log: {}", e))
+}
+
+/// Append an entry, dropping the oldest beyond the cap
+pub fn record(entry: AuditEntry) {
+    let _guard = AUDIT_LOCK.lock().unwrap();
+    let mut entries = audit_log();
+    entries.push(entry);
+    if entries.len() > MAX_AUDIT_ENTRIES {
+        let excess = entries.len() - MAX_AUDIT_ENTRIES;
+        entries.drain(..excess);
+    }
+    if let Err(e) = save_audit_log(&entries) {
+        tracing::warn!("{}", e);
+    }
+}
+
+/// Delete the audit log
+pub fn clear_audit_log() -> Result<(), String> {
+    let _guard = AUDIT_LOCK.lock().unwrap();
+    match fs::remove_file(audit_path()) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(format!("Failed to clear audit log: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALICE: &str = "alice";
+    const APP: &str = "exe:/usr/bin/app";
+
+    fn policy(pubkey: &str, app_id: &str, method: SignerMethod, kind: Option<u16>, decision: PolicyDecision) -> SignerPolicy {
+        SignerPolicy { pubkey: pubkey.to_string(), app_id: app_id.to_string(), method, kind, decision }
+    }
+
+    #[test]
+    fn kind_policy_wins_over_any_kind() {
+        let policies = vec![
+            policy(ALICE, APP, SignerMethod::SignEvent, None, PolicyDecision::Allow),
+            policy(ALICE, APP, SignerMethod::SignEvent, Some(4), PolicyDecision::Reject),
+        ];
+        assert_eq!(decide(&policies, ALICE, APP, SignerMethod::SignEvent, Some(4)), PolicyDecision::Reject);
+        assert_eq!(decide(&policies, ALICE, APP, SignerMethod::SignEvent, Some(1)), PolicyDecision::Allow);
+    }
+
+    #[test]
+    fn kind_policy_only_covers_its_kind() {
+        let policies = vec![policy(ALICE, APP, SignerMethod::SignEvent, Some(1), PolicyDecision::Allow)];
+        assert_eq!(decide(&policies, ALICE, APP, SignerMethod::SignEvent, Some(1)), PolicyDecision::Allow);
+        assert_eq!(decide(&policies, ALICE, APP, SignerMethod::SignEvent, Some(7)), PolicyDecision::Ask);
+        assert_eq!(decide(&policies, ALICE, APP, SignerMethod::Nip44Decrypt, None), PolicyDecision::Ask);
+    }
+
+    #[test]
+    fn policies_are_scoped_to_account_and_app() {
+        let policies = vec![policy(ALICE, APP, SignerMethod::Nip04Decrypt, None, PolicyDecision::Allow)];
+        assert_eq!(decide(&policies, "bob", APP, SignerMethod::Nip04Decrypt, None), PolicyDecision::Ask);
+        assert_eq!(decide(&policies, ALICE, "exe:/usr/bin/other", SignerMethod::Nip04Decrypt, None), PolicyDecision::Ask);
+        assert_eq!(decide(&policies, "", APP, SignerMethod::Nip04Decrypt, None), PolicyDecision::Ask);
+    }
+
+    #[test]
+    fn unstable_identities_are_never_remembered() {
+        assert!(can_remember(APP));
+        assert!(can_remember("nostrconnect:abcd"));
+        assert!(!can_remember(":1.42"));
+        assert!(!can_remember("Damus"));
+        assert!(!can_remember("exe:"));
+
+        let policies = vec![policy(ALICE, ":1.42", SignerMethod::SignEvent, None, PolicyDecision::Allow)];
+        assert_eq!(decide(&policies, ALICE, ":1.42", SignerMethod::SignEvent, Some(1)), PolicyDecision::Ask);
+
+        let mut config = Config::default();
+        assert!(!set_policy(&mut config, policy(ALICE, ":1.42", SignerMethod::SignEvent, None, PolicyDecision::Allow)));
+        assert!(config.signer_policies.is_empty());
+    }
+}
@@ -2,18 +2,35 @@
 //!
 //! This exposes the same D-Bus interface as Pleb Signer, allowing other Nostr
 //! applications to use Pleb-Client for signing when it's running.
+//!
+//! Callers are identified by the executable behind their bus connection,
+//! never by the app id they pass. Requests are checked against the active
+//! account's signing policies first; those without a policy are held until the user approves or rejects them through the
+//! registered request handler (the SignerRequestsController in the UI).
+//! Every answered request is written to the audit log.
 
-#![allow(dead_code)]  // Planned infrastructure for future integration
-
+use nostr_sdk::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-use tokio::sync::RwLock;
-use zbus::{interface, connection::Builder as ConnectionBuilder, Connection};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+use tokio::sync::{oneshot, RwLock};
+use zbus::{interface, connection::Builder as ConnectionBuilder, message::Header, Connection};
+
+use super::client::{DecryptResult, EncryptResult, SignedEventResult};
+use super::policy::{self, AuditEntry, AuditOutcome, PolicyDecision, SignerPolicy};
+use crate::core::config::Config;
 
 /// D-Bus service name for Pleb-Client signer
 pub const DBUS_NAME: &str = "com.plebclient.Signer";
 pub const DBUS_PATH: &str = "/com/plebclient/Signer";
 
+/// How long a request waits for the user before it is rejected
+const APPROVAL_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Characters of event content or plaintext shown in a prompt
+const PREVIEW_CHARS: usize = 280;
+
 /// Response structure for D-Bus calls
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DbusResponse {
@@ -50,6 +67,7 @@ pub struct SignerState {
     pub is_locked: bool,
     pub public_key: Option<String>,
     pub npub: Option<String>,
+    keys: Option<Keys>,
 }
 
 impl Default for SignerState {
@@ -58,8 +76,279 @@ impl Default for SignerState {
             is_locked: true,
             public_key: None,
             npub: None,
+            keys: None,
+        }
+    }
+}
+
+impl SignerState {
+    /// Serve requests with these keys
+    pub fn unlock(&mut self, keys: Keys) {
+        self.public_key = Some(keys.public_key().to_hex());
+        self.npub = keys.public_key().to_bech32().ok();
+        self.keys = Some(keys);
+        self.is_locked = false;
+    }
+
+    /// Forget the keys; requests fail until unlocked again
+    pub fn lock(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// What an app asked the signer to do
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignerMethod {
+    GetPublicKey,
+    SignEvent,
+    Nip04Encrypt,
+    Nip04Decrypt,
+    Nip44Encrypt,
+    Nip44Decrypt,
+}
+
+impl SignerMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignerMethod::GetPublicKey => "get_public_key",
+            SignerMethod::SignEvent => "sign_event",
+            SignerMethod::Nip04Encrypt => "nip04_encrypt",
+            SignerMethod::Nip04Decrypt => "nip04_decrypt",
+            SignerMethod::Nip44Encrypt => "nip44_encrypt",
+            SignerMethod::Nip44Decrypt => "nip44_decrypt",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "get_public_key" => Some(SignerMethod::GetPublicKey),
+            "sign_event" => Some(SignerMethod::SignEvent),
+            "nip04_encrypt" => Some(SignerMethod::Nip04Encrypt),
+            "nip04_decrypt" => Some(SignerMethod::Nip04Decrypt),
+            "nip44_encrypt" => Some(SignerMethod::Nip44Encrypt),
+            "nip44_decrypt" => Some(SignerMethod::Nip44Decrypt),
+            _ => None,
+        }
+    }
+}
+
+/// A request waiting for the user's decision
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerRequest {
+    pub id: String,
+    pub app_id: String,
+    /// Hex pubkey of the account asked to sign
+    #[serde(skip)]
+    pub pubkey: String,
+    pub method: SignerMethod,
+    /// Event kind for sign_event requests
+    pub kind: Option<u16>,
+    /// Event content, plaintext or peer pubkey to show in the prompt
+    pub preview: String,
+    pub created_at: i64,
+}
+
+/// Called on the service thread for each request that needs approval
+pub type RequestHandler = Box<dyn Fn(SignerRequest) + Send + Sync>;
+
+lazy_static::lazy_static! {
+    static ref HANDLER: std::sync::RwLock<Option<RequestHandler>> = std::sync::RwLock::new(None);
+    /// Request id -> request and the channel its caller waits on
+    static ref PENDING: StdMutex<HashMap<String, (SignerRequest, oneshot::Sender<bool>)>> = StdMutex::new(HashMap::new());
+}
+
+/// Register the handler that shows approval prompts
+pub fn set_request_handler(handler: RequestHandler) {
+    *HANDLER.write().unwrap() = Some(handler);
+}
+
+/// Requests still waiting for a decision, oldest first
+pub fn pending_requests() -> Vec<SignerRequest> {
+    let mut requests: Vec<SignerRequest> = PENDING.lock().unwrap()
+        .values()
+        .map(|(request, _)| request.clone())
+        .collect();
+    requests.sort_by_key(|r| r.created_at);
+    requests
+}
+
+/// Approve or reject a pending request; `remember` stores the answer as a
+/// policy for this account, app, method and event kind (ignored for apps that
+/// can't be remembered). Returns false if the request is gone (answered or
+/// timed out).
+pub fn respond(request_id: &str, approved: bool, remember: bool) -> bool {
+    let Some((request, sender)) = PENDING.lock().unwrap().remove(request_id) else {
+        return false;
+    };
+    if remember && policy::can_remember(&request.app_id) {
+        let mut config = Config::load();
+        policy::set_policy(&mut config, SignerPolicy {
+            pubkey: request.pubkey.clone(),
+            app_id: request.app_id.clone(),
+            method: request.method,
+            kind: request.kind,
+            decision: if approved { PolicyDecision::Allow } else { PolicyDecision::Reject },
+        });
+        if let Err(e) = config.save() {
+            tracing::error!("Failed to save signer policy: {}", e);
+        }
+    }
+    sender.send(approved).is_ok()
+}
+
+/// Reject everything pending
+pub fn reset_approvals() {
+    PENDING.lock().unwrap().clear();
+}
+
+/// Write an answered request to the audit log
+fn audit(app_id: &str, method: SignerMethod, kind: Option<u16>, event_id: Option<String>, outcome: AuditOutcome) {
+    policy::record(AuditEntry {
+        at: chrono::Utc::now().timestamp(),
+        app_id: app_id.to_string(),
+        method,
+        kind,
+        event_id,
+        outcome,
+    });
+}
+
+/// Apply the signing policy, asking the user if it says to
+/// Refusals are audited here; the caller audits the outcome of allowed requests
+async fn authorize(keys: &Keys, app_id: &str, method: SignerMethod, kind: Option<u16>, preview: &str) -> Result<AuditOutcome, String> {
+    let pubkey = keys.public_key().to_hex();
+    match policy::decide(&Config::load().signer_policies, &pubkey, app_id, method, kind) {
+        PolicyDecision::Allow => return Ok(AuditOutcome::Allowed),
+        PolicyDecision::Reject => {
+            audit(app_id, method, kind, None, AuditOutcome::Blocked);
+            return Err("Blocked by signer policy".to_string());
+        }
+        PolicyDecision::Ask => {}
+    }
+
+    let request = SignerRequest {
+        id: uuid::Uuid::new_v4().to_string(),
+        app_id: app_id.to_string(),
+        pubkey,
+        method,
+        kind,
+        preview: preview.chars().take(PREVIEW_CHARS).collect(),
+        created_at: chrono::Utc::now().timestamp(),
+    };
+
+    let (sender, receiver) = oneshot::channel();
+    {
+        let handler = HANDLER.read().unwrap();
+        let Some(handler) = handler.as_ref() else {
+            return Err("Pleb-Client cannot show approval prompts right now".to_string());
+        };
+        PENDING.lock().unwrap().insert(request.id.clone(), (request.clone(), sender));
+        handler(request.clone());
+    }
+
+    // zbus may run this on its own executor, so time out without tokio's timer
+    let request_id = request.id.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(APPROVAL_TIMEOUT);
+        if let Some((_, sender)) = PENDING.lock().unwrap().remove(&request_id) {
+            let _ = sender.send(false);
+        }
+    });
+
+    match receiver.await {
+        Ok(true) => Ok(AuditOutcome::Approved),
+        _ => {
+            audit(app_id, method, kind, None, AuditOutcome::Rejected);
+            Err("Request rejected or timed out".to_string())
+        }
+    }
+}
+
+/// Hand out the public key once the app is allowed to see it
+/// Shared by the D-Bus interface and nostrconnect sessions
+pub(crate) async fn approve_public_key(keys: &Keys, app_id: &str) -> Result<PublicKey, String> {
+    let public_key = keys.public_key();
+    let npub = public_key.to_bech32().unwrap_or_else(|_| public_key.to_hex());
+    let outcome = authorize(keys, app_id, SignerMethod::GetPublicKey, None, &npub).await?;
+    audit(app_id, SignerMethod::GetPublicKey, None, None, outcome);
+    Ok(public_key)
+}
+
+/// Sign unsigned event JSON for an app after approval
+/// A missing pubkey is filled in; a different one is refused
+pub(crate) async fn sign_event_json(keys: &Keys, app_id: &str, event_json: &str) -> Result<Event, String> {
+    let mut value: serde_json::Value = serde_json::from_str(event_json)
+        .map_err(|e| format!("Invalid event JSON: {}", e))?;
+    let object = value.as_object_mut().ok_or("Event must be a JSON object")?;
+    let own_pubkey = keys.public_key().to_hex();
+    match object.get("pubkey").and_then(|p| p.as_str()) {
+        Some(pubkey) if pubkey != own_pubkey => {
+            return Err("Event pubkey does not match the signer's key".to_string());
+        }
+        Some(_) => {}
+        None => {
+            object.insert("pubkey".to_string(), serde_json::Value::String(own_pubkey));
         }
     }
+    object.entry("created_at").or_insert_with(|| Timestamp::now().as_u64().into());
+    object.entry("tags").or_insert_with(|| serde_json::json!([]));
+    // The id is recomputed when signing
+    object.remove("id");
+    object.remove("sig");
+
+    let unsigned = UnsignedEvent::from_json(value.to_string())
+        .map_err(|e| format!("Invalid event: {}", e))?;
+    let kind = unsigned.kind.as_u16();
+    let outcome = authorize(keys, app_id, SignerMethod::SignEvent, Some(kind), &unsigned.content).await?;
+
+    let event = unsigned.sign_with_keys(keys)
+        .map_err(|e| format!("Failed to sign: {}", e))?;
+    audit(app_id, SignerMethod::SignEvent, Some(kind), Some(event.id.to_hex()), outcome);
+    Ok(event)
+}
+
+/// Run a NIP-04/NIP-44 method for an app after approval, returning the
+/// ciphertext or plaintext
+pub(crate) async fn run_crypto(keys: &Keys, method: SignerMethod, app_id: &str, input: &str, peer: &str) -> Result<String, String> {
+    let peer = PublicKey::parse(peer).map_err(|e| format!("Invalid pubkey: {}", e))?;
+
+    // Only plaintext going out is previewed; ciphertext means nothing to the user
+    let preview = match method {
+        SignerMethod::Nip04Encrypt | SignerMethod::Nip44Encrypt => input.to_string(),
+        _ => format!("Messages with {}", peer.to_bech32().unwrap_or_else(|_| peer.to_hex())),
+    };
+    let outcome = authorize(keys, app_id, method, None, &preview).await?;
+    audit(app_id, method, None, None, outcome);
+
+    let secret_key = keys.secret_key();
+    match method {
+        SignerMethod::Nip04Encrypt => nip04::encrypt(secret_key, &peer, input).map_err(|e| e.to_string()),
+        SignerMethod::Nip04Decrypt => nip04::decrypt(secret_key, &peer, input).map_err(|e| e.to_string()),
+        SignerMethod::Nip44Encrypt => nip44::encrypt(secret_key, &peer, input, nip44::Version::default())
+            .map_err(|e| e.to_string()),
+        SignerMethod::Nip44Decrypt => nip44::decrypt(secret_key, &peer, input).map_err(|e| e.to_string()),
+        _ => Err("unsupported method".to_string()),
+    }.map_err(|e| format!("{} failed: {}", method.as_str(), e))
+}
+
+/// The calling app as `exe:<path>`, from the process behind its bus connection
+/// The app id callers pass is only logged: any client can claim any name,
+/// and unique bus names change with every connection
+async fn caller_identity(connection: &Connection, header: &Header<'_>, app_id: &str) -> Result<String, String> {
+    let sender = header.sender().ok_or("Caller has no bus name")?;
+    let dbus = zbus::fdo::DBusProxy::new(connection).await
+        .map_err(|e| format!("Failed to reach the bus: {}", e))?;
+    let pid = dbus.get_connection_unix_process_id(sender.clone().into()).await
+        .map_err(|e| format!("Failed to identify caller {}: {}", sender, e))?;
+    let exe = std::fs::read_link(format!("/proc/{}/exe", pid))
+        .map_err(|e| format!("Failed to identify caller {}: {}", sender, e))?;
+    let identity = format!("exe:{}", exe.display());
+    if !app_id.is_empty() {
+        tracing::debug!("{} calls itself {}", identity, app_id);
+    }
+    Ok(identity)
 }
 
 /// D-Bus interface implementation for Pleb-Client as a signer
@@ -71,6 +360,47 @@ impl SignerService {
     pub fn new(state: Arc<RwLock<SignerState>>) -> Self {
         Self { state }
     }
+
+    /// Keys to serve a request with, if unlocked
+    async fn keys(&self) -> Result<Keys, String> {
+        let state = self.state.read().await;
+        match (&state.keys, state.is_locked) {
+            (Some(keys), false) => Ok(keys.clone()),
+            _ => Err("Signer is locked".to_string()),
+        }
+    }
+
+    /// Run an encryption method after approval
+    async fn crypto(
+        &self,
+        method: SignerMethod,
+        app_id: Result<String, String>,
+        input: &str,
+        peer: &str,
+    ) -> String {
+        let id = uuid::Uuid::new_v4().to_string();
+        let app_id = match app_id {
+            Ok(app_id) => app_id,
+            Err(e) => return DbusResponse::error(id, e),
+        };
+        let result: Result<serde_json::Value, String> = async {
+            let keys = self.keys().await?;
+            let output = run_crypto(&keys, method, &app_id, input, peer).await?;
+            let value = match method {
+                SignerMethod::Nip04Encrypt | SignerMethod::Nip44Encrypt => serde_json::to_value(EncryptResult { ciphertext: output }),
+                _ => serde_json::to_value(DecryptResult { plaintext: output }),
+            };
+            value.map_err(|e| format!("Serialization failed: {}", e))
+        }.await;
+
+        match result {
+            Ok(value) => DbusResponse::success(id, value),
+            Err(e) => {
+                tracing::info!("Signer {} for {} failed: {}", method.as_str(), app_id, e);
+                DbusResponse::error(id, e)
+            }
+        }
+    }
 }
 
 #[interface(name = "com.plebclient.Signer1")]
@@ -87,125 +417,139 @@ impl SignerService {
     }
 
     /// Get the active public key
-    async fn get_public_key(&self, _key_id: &str) -> String {
-        let state = self.state.read().await;
-        
-        if state.is_locked {
-            return DbusResponse::error(
-                uuid::Uuid::new_v4().to_string(),
-                "Signer is locked",
-            );
-        }
-        
-        match (&state.npub, &state.public_key) {
-            (Some(npub), Some(pubkey)) => {
-                #[derive(Serialize)]
-                struct PubKeyResult {
-                    npub: String,
-                    pubkey_hex: String,
-                }
-                
-                DbusResponse::success(
-                    uuid::Uuid::new_v4().to_string(),
-                    PubKeyResult {
-                        npub: npub.clone(),
-                        pubkey_hex: pubkey.clone(),
-                    },
-                )
-            }
-            _ => DbusResponse::error(
-                uuid::Uuid::new_v4().to_string(),
-                "No key available",
-            ),
+    async fn get_public_key(
+        &self,
+        _key_id: &str,
+        #[zbus(connection)] connection: &Connection,
+        #[zbus(header)] header: Header<'_>,
+    ) -> String {
+        let id = uuid::Uuid::new_v4().to_string();
+        let keys = match self.keys().await {
+            Ok(keys) => keys,
+            Err(e) => return DbusResponse::error(id, e),
+        };
+
+        let app_id = match caller_identity(connection, &header, "").await {
+            Ok(app_id) => app_id,
+            Err(e) => return DbusResponse::error(id, e),
+        };
+        let public_key = match approve_public_key(&keys, &app_id).await {
+            Ok(public_key) => public_key,
+            Err(e) => return DbusResponse::error(id, e),
+        };
+
+        #[derive(Serialize)]
+        struct PubKeyResult {
+            npub: String,
+            pubkey_hex: String,
         }
+
+        DbusResponse::success(id, PubKeyResult {
+            npub: public_key.to_bech32().unwrap_or_default(),
+            pubkey_hex: public_key.to_hex(),
+        })
     }
 
-    /// Sign a Nostr event (placeholder - actual implementation would use nostr-sdk)
-    async fn sign_event(&self, _event_json: &str, _key_id: &str, _app_id: &str) -> String {
-        let state = self.state.read().await;
-        
-        if state.is_locked {
-            return DbusResponse::error(
-                uuid::Uuid::new_v4().to_string(),
-                "Signer is locked",
-            );
+    /// Sign a Nostr event given as unsigned event JSON
+    async fn sign_event(
+        &self,
+        event_json: &str,
+        _key_id: &str,
+        app_id: &str,
+        #[zbus(connection)] connection: &Connection,
+        #[zbus(header)] header: Header<'_>,
+    ) -> String {
+        let id = uuid::Uuid::new_v4().to_string();
+        let app_id = match caller_identity(connection, &header, app_id).await {
+            Ok(app_id) => app_id,
+            Err(e) => return DbusResponse::error(id, e),
+        };
+
+        let result: Result<SignedEventResult, String> = async {
+            let keys = self.keys().await?;
+            let event = sign_event_json(&keys, &app_id, event_json).await?;
+            Ok(SignedEventResult {
+                event_json: event.as_json(),
+                event_id: event.id.to_hex(),
+            })
+        }.await;
+
+        match result {
+            Ok(signed) => {
+                tracing::info!("Signed event {} for {}", signed.event_id, app_id);
+                DbusResponse::success(id, signed)
+            }
+            Err(e) => {
+                tracing::info!("Signing for {} failed: {}", app_id, e);
+                DbusResponse::error(id, e)
+            }
         }
-        
-        // TODO: Implement actual signing using stored keys
-        DbusResponse::error(
-            uuid::Uuid::new_v4().to_string(),
-            "Signing not yet implemented in Pleb-Client signer service",
-        )
     }
 
-    /// NIP-04 encrypt (placeholder)
+    /// NIP-04 encrypt
     async fn nip04_encrypt(
         &self,
-        _plaintext: &str,
-        _recipient_pubkey: &str,
+        plaintext: &str,
+        recipient_pubkey: &str,
         _key_id: &str,
-        _app_id: &str,
+        app_id: &str,
+        #[zbus(connection)] connection: &Connection,
+        #[zbus(header)] header: Header<'_>,
     ) -> String {
-        DbusResponse::error(
-            uuid::Uuid::new_v4().to_string(),
-            "NIP-04 encryption not yet implemented",
-        )
+        self.crypto(SignerMethod::Nip04Encrypt, caller_identity(connection, &header, app_id).await, plaintext, recipient_pubkey).await
     }
 
-    /// NIP-04 decrypt (placeholder)
+    /// NIP-04 decrypt
     async fn nip04_decrypt(
         &self,
-        _ciphertext: &str,
-        _sender_pubkey: &str,
+        ciphertext: &str,
+        sender_pubkey: &str,
         _key_id: &str,
-        _app_id: &str,
+        app_id: &str,
+        #[zbus(connection)] connection: &Connection,
+        #[zbus(header)] header: Header<'_>,
     ) -> String {
-        DbusResponse::error(
-            uuid::Uuid::new_v4().to_string(),
-            "NIP-04 decryption not yet implemented",
-        )
+        self.crypto(SignerMethod::Nip04Decrypt, caller_identity(connection, &header, app_id).await, ciphertext, sender_pubkey).await
     }
 
-    /// NIP-44 encrypt (placeholder)
+    /// NIP-44 encrypt
     async fn nip44_encrypt(
         &self,
-        _plaintext: &str,
-        _recipient_pubkey: &str,
+        plaintext: &str,
+        recipient_pubkey: &str,
         _key_id: &str,
-        _app_id: &str,
+        app_id: &str,
+        #[zbus(connection)] connection: &Connection,
+        #[zbus(header)] header: Header<'_>,
     ) -> String {
-        DbusResponse::error(
-            uuid::Uuid::new_v4().to_string(),
-            "NIP-44 encryption not yet implemented",
-        )
+        self.crypto(SignerMethod::Nip44Encrypt, caller_identity(connection, &header, app_id).await, plaintext, recipient_pubkey).await
     }
 
-    /// NIP-44 decrypt (placeholder)
+    /// NIP-44 decrypt
     async fn nip44_decrypt(
         &self,
-        _ciphertext: &str,
-        _sender_pubkey: &str,
+        ciphertext: &str,
+        sender_pubkey: &str,
         _key_id: &str,
-        _app_id: &str,
+        app_id: &str,
+        #[zbus(connection)] connection: &Connection,
+        #[zbus(header)] header: Header<'_>,
     ) -> String {
-        DbusResponse::error(
-            uuid::Uuid::new_v4().to_string(),
-            "NIP-44 decryption not yet implemented",
-        )
+        self.crypto(SignerMethod::Nip44Decrypt, caller_identity(connection, &header, app_id).await, ciphertext, sender_pubkey).await
     }
 }
 
 /// Start the D-Bus signer service
 pub async fn start_signer_service(state: Arc<RwLock<SignerState>>) -> Result<Connection, zbus::Error> {
     let signer = SignerService::new(state);
-    
+
     let connection = ConnectionBuilder::session()?
         .name(DBUS_NAME)?
         .serve_at(DBUS_PATH, signer)?
         .build()
         .await?;
-    
+
     tracing::info!("Started D-Bus signer service at {}", DBUS_NAME);
-    
+
     Ok(connection)
 }
@@ -0,0 +1,502 @@
+//! Signer URIs - lets other apps and web pages use Pleb-Client as a signer
+//! without the D-Bus interface
+//!
+//! `nostrconnect://` URIs (NIP-46) start a session over the relays named in
+//! the URI: requests arrive as kind 24133 events and are answered the same
+//! way until the session is disconnected or the user logs out.
+//! `nostrsigner:` URIs (NIP-55) carry a single request whose result is sent
+//! to the callback URL, or handed back to the UI when there is none.
+//!
+//! Both go through the signer service's policies, approval prompts and
+//! audit log. Names an app gives itself in the URI are only shown to the user:
+//! policies are kept per nostrconnect client key (`nostrconnect:<pubkey>`) or
+//! per callback host (`callback:<host>`), and nostrsigner requests without a
+//! callback are always asked about.
+
+use nostr_sdk::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex as StdMutex;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+use super::service::{self, SignerMethod};
+use crate::core::network;
+
+/// NIP-46 request and response events
+const NOSTR_CONNECT_KIND: u16 = 24133;
+
+/// How long to wait for the session relays
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Running nostrconnect sessions
+static SESSIONS: StdMutex<Vec<Session>> = StdMutex::new(Vec::new());
+
+/// Whether this is a URI the signer handles
+pub fn is_signer_uri(uri: &str) -> bool {
+    let uri = uri.trim();
+    uri.starts_with("nostrconnect://") || uri.starts_with("nostrsigner:")
+}
+
+/// What handling a signer URI produced
+pub enum UriOutcome {
+    /// A nostrconnect session is now serving the app
+    Connected(SessionInfo),
+    /// A nostrsigner request was answered
+    Answered(SignerUriResult),
+}
+
+/// Handle a nostrconnect:// or nostrsigner: URI with the user's keys
+/// Waits for the user if the request needs approval
+pub async fn handle_uri(uri: &str, keys: Keys) -> Result<UriOutcome, String> {
+    let uri = uri.trim();
+    if uri.starts_with("nostrconnect://") {
+        connect(uri, keys).await.map(UriOutcome::Connected)
+    } else if uri.starts_with("nostrsigner:") {
+        answer_nostrsigner(uri, &keys).await.map(UriOutcome::Answered)
+    } else {
+        Err("Not a signer URI".to_string())
+    }
+}
+
+// ============================================================================
+// nostrconnect:// (NIP-46)
+// ============================================================================
+
+/// Parsed nostrconnect:// URI
+#[derive(Debug, Clone)]
+pub struct NostrConnectUri {
+    pub client_pubkey: PublicKey,
+    pub relays: Vec<String>,
+    pub secret: Option<String>,
+    pub name: Option<String>,
+}
+
+impl NostrConnectUri {
+    /// Parse nostrconnect://<client-pubkey>?relay=...&secret=...&name=...
+    pub fn parse(uri: &str) -> Result<Self, String> {
+        let url = url::Url::parse(uri.trim())
+            .map_err(|e| format!("Invalid nostrconnect URI: {}", e))?;
+        if url.scheme() != "nostrconnect" {
+            return Err("Invalid nostrconnect URI scheme".to_string());
+        }
+        let client_pubkey = url.host_str()
+            .ok_or("Missing client pubkey in nostrconnect URI")
+            .and_then(|host| PublicKey::parse(host).map_err(|_| "Invalid client pubkey in nostrconnect URI"))?;
+
+        let mut relays = Vec::new();
+        let mut secret = None;
+        let mut name = None;
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "relay" => relays.push(value.to_string()),
+                "secret" => secret = Some(value.to_string()),
+                "name" => name = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        if relays.is_empty() {
+            return Err("Missing relay in nostrconnect URI".to_string());
+        }
+
+        Ok(Self { client_pubkey, relays, secret, name })
+    }
+
+    /// App id used for policies and the audit log: the client key, which
+    /// only the app holds the secret for
+    pub fn app_id(&self) -> String {
+        format!("nostrconnect:{}", self.client_pubkey.to_hex())
+    }
+
+    /// Name the app gave itself, for display only
+    pub fn display_name(&self) -> Option<String> {
+        self.name.as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+    }
+}
+
+/// A session as shown in settings
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionInfo {
+    pub id: String,
+    pub app_id: String,
+    /// Name the app gave itself, unverified
+    pub name: Option<String>,
+    pub relays: Vec<String>,
+    pub started_at: i64,
+}
+
+struct Session {
+    info: SessionInfo,
+    client: Client,
+    listener: JoinHandle<()>,
+}
+
+/// NIP-46 request payload
+#[derive(Debug, Deserialize)]
+struct Request {
+    id: String,
+    method: String,
+    #[serde(default)]
+    params: Vec<String>,
+}
+
+/// NIP-46 response payload
+#[derive(Debug, Serialize)]
+struct Response {
+    id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Payload encryption the app used; replies use the same
+#[derive(Debug, Clone, Copy)]
+enum Cipher {
+    Nip04,
+    Nip44,
+}
+
+/// Running sessions, oldest first
+pub fn sessions() -> Vec<SessionInfo> {
+    SESSIONS.lock().unwrap().iter().map(|s| s.info.clone()).collect()
+}
+
+/// Answer the app behind a nostrconnect URI and serve its requests
+/// Connecting reveals the user's key, so it needs the same approval as get_public_key
+async fn connect(uri: &str, keys: Keys) -> Result<SessionInfo, String> {
+    let parsed = NostrConnectUri::parse(uri)?;
+    let app_id = parsed.app_id();
+    service::approve_public_key(&keys, &app_id).await?;
+
+    let client = network::nostr_client(None);
+    for relay in &parsed.relays {
+        if let Err(e) = client.add_relay(relay.as_str()).await {
+            tracing::warn!("Failed to add nostrconnect relay {}: {}", relay, e);
+        }
+    }
+    client.connect().await;
+    client.wait_for_connection(CONNECT_TIMEOUT).await;
+
+    let filter = Filter::new()
+        .kind(Kind::Custom(NOSTR_CONNECT_KIND))
+        .author(parsed.client_pubkey)
+        .pubkey(keys.public_key())
+        .since(Timestamp::now());
+    let sub_id = client.subscribe(filter, None)
+        .await
+        .map_err(|e| format!("Failed to subscribe on nostrconnect relays: {}", e))?
+        .val;
+
+    // The app waits for its secret to come back before sending requests
+    let ack = Response {
+        id: uuid::Uuid::new_v4().to_string(),
+        result: Some(parsed.secret.clone().unwrap_or_else(|| "ack".to_string())),
+        error: None,
+    };
+    if let Err(e) = reply(&client, &keys, &parsed.client_pubkey, Cipher::Nip44, &ack).await {
+        client.shutdown().await;
+        return Err(e);
+    }
+
+    let info = SessionInfo {
+        id: uuid::Uuid::new_v4().to_string(),
+        app_id: app_id.clone(),
+        name: parsed.display_name(),
+        relays: parsed.relays.clone(),
+        started_at: chrono::Utc::now().timestamp(),
+    };
+
+    let listener = {
+        let client = client.clone();
+        let mut notifications = client.notifications();
+        tokio::spawn(async move {
+            loop {
+                let notification = match notifications.recv().await {
+                    Ok(notification) => notification,
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(_) => break,
+                };
+                let RelayPoolNotification::Event { subscription_id, event, .. } = notification else { continue };
+                if subscription_id != sub_id {
+                    continue;
+                }
+                // Requests can wait on the user, so each gets its own task
+                tokio::spawn(handle_request(client.clone(), keys.clone(), app_id.clone(), *event));
+            }
+        })
+    };
+
+    tracing::info!("nostrconnect session for {} on {:?}", info.app_id, info.relays);
+    SESSIONS.lock().unwrap().push(Session { info: info.clone(), client, listener });
+    Ok(info)
+}
+
+/// Decrypt, answer and reply to one request event
+async fn handle_request(client: Client, keys: Keys, app_id: String, event: Event) {
+    // NIP-04 payloads carry their IV after "?iv="
+    let cipher = if event.content.contains("?iv=") { Cipher::Nip04 } else { Cipher::Nip44 };
+    let decrypted = match cipher {
+        Cipher::Nip04 => nip04::decrypt(keys.secret_key(), &event.pubkey, &event.content).map_err(|e| e.to_string()),
+        Cipher::Nip44 => nip44::decrypt(keys.secret_key(), &event.pubkey, &event.content).map_err(|e| e.to_string()),
+    };
+    let request: Request = match decrypted.and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string())) {
+        Ok(request) => request,
+        Err(e) => {
+            tracing::debug!("Ignoring unreadable nostrconnect request from {}: {}", app_id, e);
+            return;
+        }
+    };
+
+    let response = match answer(&keys, &app_id, &request).await {
+        Ok(result) => Response { id: request.id, result: Some(result), error: None },
+        Err(e) => {
+            tracing::info!("nostrconnect {} for {} failed: {}", request.method, app_id, e);
+            Response { id: request.id, result: None, error: Some(e) }
+        }
+    };
+    if let Err(e) = reply(&client, &keys, &event.pubkey, cipher, &response).await {
+        tracing::warn!("{}", e);
+    }
+}
+
+/// Result string for a NIP-46 method
+async fn answer(keys: &Keys, app_id: &str, request: &Request) -> Result<String, String> {
+    let param = |index: usize| {
+        request.params.get(index)
+            .map(String::as_str)
+            .ok_or_else(|| format!("Missing parameter for {}", request.method))
+    };
+
+    match request.method.as_str() {
+        "connect" => Ok("ack".to_string()),
+        "ping" => Ok("pong".to_string()),
+        "get_public_key" => service::approve_public_key(keys, app_id).await.map(|pk| pk.to_hex()),
+        "sign_event" => service::sign_event_json(keys, app_id, param(0)?).await.map(|event| event.as_json()),
+        method => match SignerMethod::from_str(method) {
+            // Encryption params are [peer pubkey, text]
+            Some(method @ (SignerMethod::Nip04Encrypt | SignerMethod::Nip04Decrypt
+                | SignerMethod::Nip44Encrypt | SignerMethod::Nip44Decrypt)) => {
+                service::run_crypto(keys, method, app_id, param(1)?, param(0)?).await
+            }
+            _ => Err(format!("Unsupported method: {}", method)),
+        },
+    }
+}
+
+/// Send an encrypted response to the app
+async fn reply(client: &Client, keys: &Keys, app: &PublicKey, cipher: Cipher, response: &Response) -> Result<(), String> {
+    let json = serde_json::to_string(response)
+        .map_err(|e| format!("Failed to serialize nostrconnect response: {}", e))?;
+    let content = match cipher {
+        Cipher::Nip04 => nip04::encrypt(keys.secret_key(), app, json).map_err(|e| e.to_string()),
+        Cipher::Nip44 => nip44::encrypt(keys.secret_key(), app, json, nip44::Version::default()).map_err(|e| e.to_string()),
+    }.map_err(|e| format!("Failed to encrypt nostrconnect response: {}", e))?;
+
+    let event = EventBuilder::new(Kind::Custom(NOSTR_CONNECT_KIND), content)
+        .tag(Tag::public_key(*app))
+        .sign_with_keys(keys)
+        .map_err(|e| format!("Failed to sign nostrconnect response: {}", e))?;
+    client.send_event(&event)
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("Failed to send nostrconnect response: {}", e))
+}
+
+/// Stop one session; returns false if it was already gone
+pub async fn disconnect(session_id: &str) -> bool {
+    let session = {
+        let mut sessions = SESSIONS.lock().unwrap();
+        sessions.iter()
+            .position(|s| s.info.id == session_id)
+            .map(|index| sessions.remove(index))
+    };
+    match session {
+        Some(session) => {
+            stop(session).await;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Stop every session (logout)
+pub async fn disconnect_all() {
+    let sessions = std::mem::take(&mut *SESSIONS.lock().unwrap());
+    for session in sessions {
+        stop(session).await;
+    }
+}
+
+async fn stop(session: Session) {
+    session.listener.abort();
+    session.client.shutdown().await;
+    tracing::info!("nostrconnect session for {} ended", session.info.app_id);
+}
+
+// ============================================================================
+// nostrsigner: (NIP-55)
+// ============================================================================
+
+/// Answer to a nostrsigner: request
+#[derive(Debug, Clone)]
+pub struct SignerUriResult {
+    pub app_id: String,
+    pub result: String,
+    /// Callback URL with the result appended, if the app gave one
+    pub callback_url: Option<String>,
+}
+
+/// Parsed nostrsigner:<content>?type=...&callbackUrl=... URI
+#[derive(Debug, Clone)]
+pub struct NostrSignerUri {
+    pub content: String,
+    pub method: SignerMethod,
+    pub peer: Option<String>,
+    /// Web callback the result is appended to
+    pub callback: Option<url::Url>,
+    pub return_type: Option<String>,
+}
+
+impl NostrSignerUri {
+    pub fn parse(uri: &str) -> Result<Self, String> {
+        let rest = uri.trim().strip_prefix("nostrsigner:").ok_or("Invalid nostrsigner URI scheme")?;
+        let (content, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let content = urlencoding::decode(content)
+            .map_err(|e| format!("Invalid nostrsigner content: {}", e))?
+            .to_string();
+
+        let mut request_type = None;
+        let mut peer = None;
+        let mut callback = None;
+        let mut return_type = None;
+        for (key, value) in url::form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "type" => request_type = Some(value.to_string()),
+                "pubkey" => peer = Some(value.to_string()),
+                "callbackUrl" => callback = Some(value.to_string()),
+                "returnType" => return_type = Some(value.to_string()),
+                "compressionType" if value != "none" => {
+                    return Err("Compressed nostrsigner results are not supported".to_string());
+                }
+                _ => {}
+            }
+        }
+
+        // Only web callbacks; anything else could launch arbitrary handlers
+        let callback = callback
+            .map(|callback| url::Url::parse(&callback).map_err(|e| format!("Invalid callback URL: {}", e)))
+            .transpose()?;
+        if let Some(callback) = &callback {
+            if !matches!(callback.scheme(), "https" | "http") || callback.host_str().is_none() {
+                return Err("Callback URL must be http or https".to_string());
+            }
+        }
+
+        let method = request_type.as_deref()
+            .and_then(SignerMethod::from_str)
+            .ok_or("Missing or unsupported nostrsigner type")?;
+
+        Ok(Self { content, method, peer, callback, return_type })
+    }
+
+    /// App id used for policies and the audit log: the callback host, or
+    /// "nostrsigner" without one, which is never remembered so every request
+    /// is asked about. A self-declared appName is ignored.
+    pub fn app_id(&self) -> String {
+        self.callback.as_ref()
+            .and_then(|callback| callback.host_str())
+            .map(|host| format!("callback:{}", host))
+            .unwrap_or_else(|| "nostrsigner".to_string())
+    }
+}
+
+/// Answer a nostrsigner: request
+async fn answer_nostrsigner(uri: &str, keys: &Keys) -> Result<SignerUriResult, String> {
+    let request = NostrSignerUri::parse(uri)?;
+    let app_id = request.app_id();
+    let NostrSignerUri { content, method, peer, callback, return_type } = request;
+
+    let result = match method {
+        SignerMethod::GetPublicKey => service::approve_public_key(keys, &app_id).await?.to_hex(),
+        SignerMethod::SignEvent => {
+            let event = service::sign_event_json(keys, &app_id, &content).await?;
+            match return_type.as_deref() {
+                Some("event") => event.as_json(),
+                _ => event.sig.to_string(),
+            }
+        }
+        _ => {
+            let peer = peer.ok_or("Missing pubkey for nostrsigner encryption")?;
+            service::run_crypto(keys, method, &app_id, &content, &peer).await?
+        }
+    };
+
+    Ok(SignerUriResult {
+        app_id,
+        callback_url: callback.map(|callback| format!("{}{}", callback, urlencoding::encode(&result))),
+        result,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLIENT: &str = "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798";
+
+    #[test]
+    fn parses_nostrconnect_uri() {
+        let uri = format!("nostrconnect://{}?relay=wss%3A%2F%2Frelay.example.com&relay=wss://nos.lol&secret=s3&name=Damus", CLIENT);
+        let parsed = NostrConnectUri::parse(&uri).unwrap();
+        assert_eq!(parsed.relays, vec!["wss://relay.example.com", "wss://nos.lol"]);
+        assert_eq!(parsed.secret.as_deref(), Some("s3"));
+        assert_eq!(parsed.display_name().as_deref(), Some("Damus"));
+        // The self-declared name never becomes the policy identity
+        assert_eq!(parsed.app_id(), format!("nostrconnect:{}", CLIENT));
+    }
+
+    #[test]
+    fn rejects_bad_nostrconnect_uris() {
+        assert!(NostrConnectUri::parse(&format!("nostrconnect://{}", CLIENT)).is_err());
+        assert!(NostrConnectUri::parse("nostrconnect://notakey?relay=wss://nos.lol").is_err());
+        assert!(NostrConnectUri::parse(&format!("https://{}?relay=wss://nos.lol", CLIENT)).is_err());
+    }
+
+    #[test]
+    fn parses_nostrsigner_uri() {
+        let uri = "nostrsigner:%7B%22kind%22%3A1%7D?type=sign_event&returnType=event&appName=Damus&callbackUrl=https%3A%2F%2Fapp.example.com%2Fcb%3Fevent%3D";
+        let parsed = NostrSignerUri::parse(uri).unwrap();
+        assert_eq!(parsed.content, "{\"kind\":1}");
+        assert_eq!(parsed.method, SignerMethod::SignEvent);
+        assert_eq!(parsed.return_type.as_deref(), Some("event"));
+        assert_eq!(parsed.app_id(), "callback:app.example.com");
+
+        // Without a callback the appName is ignored
+        let parsed = NostrSignerUri::parse("nostrsigner:?type=get_public_key&appName=Damus").unwrap();
+        assert_eq!(parsed.app_id(), "nostrsigner");
+        assert!(!crate::signer::policy::can_remember(&parsed.app_id()));
+    }
+
+    #[test]
+    fn rejects_bad_nostrsigner_uris() {
+        assert!(NostrSignerUri::parse("nostrsigner:abc").is_err());
+        assert!(NostrSignerUri::parse("nostrsigner:abc?type=delete_everything").is_err());
+        assert!(NostrSignerUri::parse("nostrsigner:abc?type=sign_event&callbackUrl=javascript%3Aalert(1)").is_err());
+        assert!(NostrSignerUri::parse("nostrsigner:abc?type=sign_event&compressionType=gzip").is_err());
+        assert!(NostrSignerUri::parse("nostrconnect:abc?type=sign_event").is_err());
+    }
+
+    #[tokio::test]
+    async fn nostrsigner_without_callback_needs_a_prompt() {
+        // No prompt is registered in tests, so a request that must be asked about fails
+        let keys = Keys::generate();
+        let result = answer_nostrsigner("nostrsigner:?type=get_public_key&appName=Damus", &keys).await;
+        assert!(result.is_err());
+        let result = answer_nostrsigner("nostrsigner:x?type=sign_event&callbackUrl=file%3A%2F%2F%2Fetc", &keys).await;
+        assert_eq!(result.err().as_deref(), Some("Callback URL must be http or https"));
+    }
+}